@@ -6,7 +6,7 @@
 
 use super::{
     linting, AnalysisConfig, DialogueInfo, LintConfig, ScriptAnalysis, ScriptAnalysisOptions,
-    StyleAnalyzer,
+    StyleAnalyzer, StyleUsageReport,
 };
 #[cfg(feature = "plugins")]
 use crate::plugin::ExtensionRegistry;
@@ -86,12 +86,14 @@ impl<'a> ScriptAnalysis<'a> {
             lint_issues: Vec::new(),
             resolved_styles: Vec::new(),
             dialogue_info: Vec::new(),
+            style_usage: StyleUsageReport::default(),
             config,
             registry,
         };
 
         analysis.resolve_all_styles();
         analysis.analyze_events();
+        analysis.style_usage = StyleUsageReport::compute(script);
         analysis.run_linting();
 
         analysis
@@ -105,11 +107,13 @@ impl<'a> ScriptAnalysis<'a> {
             lint_issues: Vec::new(),
             resolved_styles: Vec::new(),
             dialogue_info: Vec::new(),
+            style_usage: StyleUsageReport::default(),
             config,
         };
 
         analysis.resolve_all_styles();
         analysis.analyze_events();
+        analysis.style_usage = StyleUsageReport::compute(script);
         analysis.run_linting();
 
         analysis
@@ -124,7 +128,7 @@ impl<'a> ScriptAnalysis<'a> {
         );
 
         let mut issues = Vec::new();
-        let rules = linting::rules::BuiltinRules::all_rules();
+        let rules = linting::rules::BuiltinRules::configured_rules(&lint_config);
 
         for rule in rules {
             if !lint_config.is_rule_enabled(rule.id()) {
@@ -5,6 +5,8 @@
 
 use alloc::{string::String, vec::Vec};
 
+use crate::parser::ast::WrapMode;
+
 /// Type of line break in ASS text
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum LineBreakType {
@@ -114,9 +116,101 @@ impl TextWithLineBreaks {
     }
 }
 
+/// A piece of event text split at the `\N`, `\n` and `\h` escapes
+///
+/// `\N` always breaks the line, `\n` breaks it only under
+/// [`WrapMode::NoWrap`] (`WrapStyle: 2` or `\q2`) and is a plain space
+/// otherwise, and `\h` is a space that never breaks.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TextRun<'a> {
+    /// Text between escapes, other `\x` sequences kept as written
+    Literal(&'a str),
+    /// `\N`
+    HardLineBreak,
+    /// `\n`
+    SoftLineBreak,
+    /// `\h`
+    NonBreakingSpace,
+}
+
+impl<'a> TextRun<'a> {
+    /// Split event text into runs, dropping `{...}` override blocks
+    ///
+    /// An unterminated `{` is kept as literal text. Drawing mode is not
+    /// tracked, so callers handling `\p` should split only text blocks.
+    #[must_use]
+    pub fn parse(text: &'a str) -> Vec<Self> {
+        let bytes = text.as_bytes();
+        let mut runs = Vec::new();
+        let mut literal_start = 0;
+        let mut i = 0;
+
+        while i < bytes.len() {
+            let skip = match bytes[i] {
+                b'{' => text[i..].find('}').map(|end| (None, end + 1)),
+                b'\\' => bytes
+                    .get(i + 1)
+                    .and_then(|&b| Self::from_escape(char::from(b)))
+                    .map(|run| (Some(run), 2)),
+                _ => None,
+            };
+            if let Some((run, len)) = skip {
+                if literal_start < i {
+                    runs.push(Self::Literal(&text[literal_start..i]));
+                }
+                runs.extend(run);
+                i += len;
+                literal_start = i;
+            } else {
+                i += 1;
+            }
+        }
+        if literal_start < text.len() {
+            runs.push(Self::Literal(&text[literal_start..]));
+        }
+        runs
+    }
+
+    /// Run for the escape `\<escape>`, `None` when it is not a line-break
+    /// or space escape
+    #[must_use]
+    pub const fn from_escape(escape: char) -> Option<Self> {
+        match escape {
+            'N' => Some(Self::HardLineBreak),
+            'n' => Some(Self::SoftLineBreak),
+            'h' => Some(Self::NonBreakingSpace),
+            _ => None,
+        }
+    }
+
+    /// Text to lay out for this run under `wrap_mode`
+    ///
+    /// Breaks become `\n`, except soft breaks outside
+    /// [`WrapMode::NoWrap`], which become a space as in libass.
+    #[must_use]
+    pub fn as_str(&self, wrap_mode: WrapMode) -> &'a str {
+        match self {
+            Self::Literal(text) => text,
+            Self::HardLineBreak => "\n",
+            Self::SoftLineBreak if wrap_mode == WrapMode::NoWrap => "\n",
+            Self::SoftLineBreak => " ",
+            Self::NonBreakingSpace => "\u{00A0}",
+        }
+    }
+
+    /// Plain text of this run with every break type as `\n`, for
+    /// character counts such as CPS
+    #[must_use]
+    pub fn to_plain_string(&self) -> String {
+        String::from(self.as_str(WrapMode::NoWrap))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    #[cfg(not(feature = "std"))]
+    use alloc::{format, vec};
 
     #[test]
     fn test_hard_line_break() {
@@ -170,4 +264,47 @@ mod tests {
         assert_eq!(processed.line_breaks.len(), 0);
         assert_eq!(processed.nbsp_positions.len(), 0);
     }
+
+    #[test]
+    fn text_runs_split_escapes_and_drop_overrides() {
+        let runs = TextRun::parse(r"{\b1}One\NTwo\nThree\hFour\i{x");
+        assert_eq!(
+            runs,
+            vec![
+                TextRun::Literal("One"),
+                TextRun::HardLineBreak,
+                TextRun::Literal("Two"),
+                TextRun::SoftLineBreak,
+                TextRun::Literal("Three"),
+                TextRun::NonBreakingSpace,
+                TextRun::Literal(r"Four\i{x"),
+            ]
+        );
+    }
+
+    #[test]
+    fn text_runs_under_each_wrap_mode() {
+        for mode in [
+            WrapMode::Smart,
+            WrapMode::EndOfLine,
+            WrapMode::NoWrap,
+            WrapMode::SmartBottom,
+        ] {
+            let text: String = TextRun::parse(r"A\NB\nC\hD")
+                .iter()
+                .map(|run| run.as_str(mode))
+                .collect();
+            let soft = if mode == WrapMode::NoWrap { "\n" } else { " " };
+            assert_eq!(text, format!("A\nB{soft}C\u{00A0}D"), "{mode:?}");
+        }
+    }
+
+    #[test]
+    fn text_runs_plain_string_breaks_everything() {
+        let plain: String = TextRun::parse(r"A\NB\nC\hD")
+            .iter()
+            .map(TextRun::to_plain_string)
+            .collect();
+        assert_eq!(plain, "A\nB\nC\u{00A0}D");
+    }
 }
@@ -57,7 +57,7 @@ pub mod unicode_wrap;
 pub mod utils;
 
 pub use dialogue_info::{DialogueInfo, TimingRelation};
-pub use line_breaks::{LineBreakInfo, LineBreakType, TextWithLineBreaks};
+pub use line_breaks::{LineBreakInfo, LineBreakType, TextRun, TextWithLineBreaks};
 pub use overlap::{count_overlapping_events, find_overlapping_event_refs, find_overlapping_events};
 pub use scoring::{
     calculate_animation_score, calculate_complexity_score, get_performance_impact,
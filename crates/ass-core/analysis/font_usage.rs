@@ -0,0 +1,192 @@
+//! Font usage report across styles and inline override tags
+//!
+//! Collects every font family a script references, either through a style's
+//! `Fontname` field or an inline `\fn` override, so authors can check which
+//! font files need to be bundled with the subtitles.
+//!
+//! # Example
+//!
+//! ```rust
+//! use ass_core::{analysis::FontUsageReport, Script};
+//!
+//! let script = Script::parse("[V4+ Styles]
+//! Format: Name, Fontname, Fontsize
+//! Style: Default,Arial,20
+//!
+//! [Events]
+//! Format: Layer, Start, End, Style, Name, MarginL, MarginR, MarginV, Effect, Text
+//! Dialogue: 0,0:00:00.00,0:00:05.00,Default,,0,0,0,,{\\fnComic Sans MS}Hi")?;
+//!
+//! let report = FontUsageReport::compute(&script);
+//! assert_eq!(report.missing_fonts(&["arial"]), vec!["Comic Sans MS"]);
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+
+use alloc::vec::Vec;
+use core::fmt;
+
+use crate::analysis::events::text_analysis::TextAnalysis;
+use crate::parser::{Script, Section};
+
+/// Where a font is referenced
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FontReferenceKind {
+    /// Only as a style `Fontname`
+    StyleOnly,
+    /// Only in inline `\fn` overrides
+    InlineOnly,
+    /// Both in styles and inline overrides
+    Both,
+}
+
+impl fmt::Display for FontReferenceKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::StyleOnly => "style",
+            Self::InlineOnly => "inline",
+            Self::Both => "style+inline",
+        })
+    }
+}
+
+/// Usage of a single font family
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FontUsage<'a> {
+    /// Font family name as first spelled in the script
+    pub name: &'a str,
+    /// Styles whose `Fontname` is this font
+    pub styles: Vec<&'a str>,
+    /// Whether an inline `\fn` override names this font
+    pub inline: bool,
+    /// Indices (in document order) of events using this font, sorted
+    pub events: Vec<usize>,
+}
+
+impl FontUsage<'_> {
+    /// Classify where the font is referenced
+    #[must_use]
+    pub fn kind(&self) -> FontReferenceKind {
+        match (self.styles.is_empty(), self.inline) {
+            (false, true) => FontReferenceKind::Both,
+            (true, _) => FontReferenceKind::InlineOnly,
+            (false, false) => FontReferenceKind::StyleOnly,
+        }
+    }
+}
+
+/// Fonts referenced by a script's styles and event override tags
+///
+/// Font names are matched ASCII case-insensitively, as font lookup is.
+/// An event uses a font when its style names it or its text contains a
+/// `\fn` override for it.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct FontUsageReport<'a> {
+    /// Fonts in first-reference order
+    fonts: Vec<FontUsage<'a>>,
+}
+
+impl<'a> FontUsageReport<'a> {
+    /// Compute the report for `script`
+    #[must_use]
+    pub fn compute(script: &Script<'a>) -> Self {
+        let mut report = Self::default();
+
+        for section in script.sections() {
+            if let Section::Styles(styles) = section {
+                for style in styles {
+                    let font = style.fontname.trim();
+                    if !font.is_empty() {
+                        report.entry(font).styles.push(style.name);
+                    }
+                }
+            }
+        }
+
+        let events = script
+            .sections()
+            .iter()
+            .filter_map(|section| match section {
+                Section::Events(events) => Some(events),
+                _ => None,
+            })
+            .flatten();
+        for (index, event) in events.enumerate() {
+            for usage in &mut report.fonts {
+                if usage.styles.contains(&event.style) {
+                    usage.events.push(index);
+                }
+            }
+            let Ok(analysis) = TextAnalysis::analyze(event.text) else {
+                continue;
+            };
+            for tag in analysis.override_tags() {
+                let font = tag.args().trim();
+                if tag.name() == "fn" && !font.is_empty() {
+                    let usage = report.entry(font);
+                    usage.inline = true;
+                    if usage.events.last() != Some(&index) {
+                        usage.events.push(index);
+                    }
+                }
+            }
+        }
+
+        report
+    }
+
+    /// All referenced fonts in first-reference order
+    #[must_use]
+    pub fn fonts(&self) -> &[FontUsage<'a>] {
+        &self.fonts
+    }
+
+    /// Look up a font by name (ASCII case-insensitive)
+    #[must_use]
+    pub fn get(&self, name: &str) -> Option<&FontUsage<'a>> {
+        self.fonts
+            .iter()
+            .find(|f| f.name.eq_ignore_ascii_case(name))
+    }
+
+    /// Referenced fonts absent from `available` (ASCII case-insensitive)
+    #[must_use]
+    pub fn missing_fonts(&self, available: &[&str]) -> Vec<&'a str> {
+        self.fonts
+            .iter()
+            .filter(|f| !available.iter().any(|a| a.eq_ignore_ascii_case(f.name)))
+            .map(|f| f.name)
+            .collect()
+    }
+
+    /// Usage entry for `name`, created on first reference
+    fn entry(&mut self, name: &'a str) -> &mut FontUsage<'a> {
+        let index = self
+            .fonts
+            .iter()
+            .position(|f| f.name.eq_ignore_ascii_case(name))
+            .unwrap_or_else(|| {
+                self.fonts.push(FontUsage {
+                    name,
+                    styles: Vec::new(),
+                    inline: false,
+                    events: Vec::new(),
+                });
+                self.fonts.len() - 1
+            });
+        &mut self.fonts[index]
+    }
+}
+
+impl fmt::Display for FontUsageReport<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "{} font(s) referenced", self.fonts.len())?;
+        for font in &self.fonts {
+            write!(f, "  {} [{}]", font.name, font.kind())?;
+            if !font.styles.is_empty() {
+                write!(f, " styles: {}", font.styles.join(", "))?;
+            }
+            writeln!(f, "; events: {}", font.events.len())?;
+        }
+        Ok(())
+    }
+}
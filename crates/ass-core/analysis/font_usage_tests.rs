@@ -0,0 +1,74 @@
+//! Tests for the font usage report.
+
+use super::*;
+use crate::parser::Script;
+#[cfg(not(feature = "std"))]
+use alloc::{string::ToString, vec};
+
+const SCRIPT: &str = r"[Script Info]
+Title: Fonts
+
+[V4+ Styles]
+Format: Name, Fontname, Fontsize
+Style: Default,Arial,20
+Style: Sign,Verdana,30
+
+[Events]
+Format: Layer, Start, End, Style, Name, MarginL, MarginR, MarginV, Effect, Text
+Dialogue: 0,0:00:00.00,0:00:02.00,Default,,0,0,0,,Plain
+Dialogue: 0,0:00:02.00,0:00:04.00,Sign,,0,0,0,,Sign text
+Dialogue: 0,0:00:04.00,0:00:06.00,Default,,0,0,0,,{\fnGeorgia}Inline {\fnverdana}mixed
+";
+
+#[test]
+fn font_usage_classifies_styles_and_inline_overrides() {
+    let script = Script::parse(SCRIPT).unwrap();
+    let report = FontUsageReport::compute(&script);
+
+    let names: Vec<&str> = report.fonts().iter().map(|f| f.name).collect();
+    assert_eq!(names, ["Arial", "Verdana", "Georgia"]);
+
+    let arial = report.get("arial").unwrap();
+    assert_eq!(arial.kind(), FontReferenceKind::StyleOnly);
+    assert_eq!(arial.styles, ["Default"]);
+    assert_eq!(arial.events, [0, 2]);
+
+    let verdana = report.get("Verdana").unwrap();
+    assert_eq!(verdana.kind(), FontReferenceKind::Both);
+    assert_eq!(verdana.events, [1, 2]);
+
+    let georgia = report.get("Georgia").unwrap();
+    assert_eq!(georgia.kind(), FontReferenceKind::InlineOnly);
+    assert!(georgia.styles.is_empty());
+    assert_eq!(georgia.events, [2]);
+}
+
+#[test]
+fn font_usage_missing_fonts() {
+    let script = Script::parse(SCRIPT).unwrap();
+    let report = FontUsageReport::compute(&script);
+
+    assert_eq!(report.missing_fonts(&["ARIAL", "Georgia"]), vec!["Verdana"]);
+    assert!(report
+        .missing_fonts(&["Arial", "Verdana", "Georgia"])
+        .is_empty());
+}
+
+#[test]
+fn font_usage_display_lists_each_font() {
+    let script = Script::parse(SCRIPT).unwrap();
+    let text = FontUsageReport::compute(&script).to_string();
+
+    assert!(text.starts_with("3 font(s) referenced"));
+    assert!(text.contains("Verdana [style+inline] styles: Sign; events: 2"));
+    assert!(text.contains("Georgia [inline]; events: 1"));
+}
+
+#[test]
+fn font_usage_ignores_empty_fn_reset() {
+    let script = Script::parse(
+        "[Events]\nFormat: Layer, Start, End, Style, Name, MarginL, MarginR, MarginV, Effect, Text\nDialogue: 0,0:00:00.00,0:00:01.00,Default,,0,0,0,,{\\fn}reset",
+    )
+    .unwrap();
+    assert!(FontUsageReport::compute(&script).fonts().is_empty());
+}
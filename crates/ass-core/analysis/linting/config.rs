@@ -19,6 +19,10 @@ pub struct LintConfig {
     pub enabled_rules: Vec<&'static str>,
     /// Disabled rule IDs
     pub disabled_rules: Vec<&'static str>,
+    /// Maximum characters per rendered line before `line-length` warns
+    pub max_line_chars: usize,
+    /// Minimum visible characters per event before `short-event` reports
+    pub min_chars: usize,
 }
 
 impl Default for LintConfig {
@@ -29,6 +33,8 @@ impl Default for LintConfig {
             strict_mode: false,
             enabled_rules: Vec::new(),
             disabled_rules: Vec::new(),
+            max_line_chars: 60,
+            min_chars: 1,
         }
     }
 }
@@ -55,6 +61,20 @@ impl LintConfig {
         self
     }
 
+    /// Set maximum characters per rendered line.
+    #[must_use]
+    pub const fn with_max_line_chars(mut self, max: usize) -> Self {
+        self.max_line_chars = max;
+        self
+    }
+
+    /// Set minimum visible characters per event.
+    #[must_use]
+    pub const fn with_min_chars(mut self, min: usize) -> Self {
+        self.min_chars = min;
+        self
+    }
+
     /// Check if a rule is enabled.
     #[must_use]
     pub fn is_rule_enabled(&self, rule_id: &str) -> bool {
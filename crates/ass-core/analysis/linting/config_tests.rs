@@ -10,6 +10,8 @@ fn lint_config_default() {
     assert!(!config.strict_mode);
     assert!(config.enabled_rules.is_empty());
     assert!(config.disabled_rules.is_empty());
+    assert_eq!(config.max_line_chars, 60);
+    assert_eq!(config.min_chars, 1);
 }
 
 #[test]
@@ -58,3 +60,12 @@ fn lint_config_should_report_severity() {
     assert!(config.should_report_severity(IssueSeverity::Error));
     assert!(config.should_report_severity(IssueSeverity::Critical));
 }
+
+#[test]
+fn lint_config_with_text_limits() {
+    let config = LintConfig::default()
+        .with_max_line_chars(42)
+        .with_min_chars(3);
+    assert_eq!(config.max_line_chars, 42);
+    assert_eq!(config.min_chars, 3);
+}
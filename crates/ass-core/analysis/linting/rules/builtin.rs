@@ -6,10 +6,11 @@
 use alloc::{boxed::Box, vec, vec::Vec};
 
 use super::{
-    AccessibilityRule, EncodingRule, InvalidColorRule, InvalidTagRule, MissingStyleRule,
-    NegativeDurationRule, PerformanceRule, TimingOverlapRule,
+    AccessibilityRule, EncodingRule, InvalidColorRule, InvalidTagRule, LineLengthRule,
+    MissingStyleRule, NegativeDurationRule, PerformanceRule, ShortEventRule, TimingOverlapRule,
+    UnusedStyleRule,
 };
-use crate::analysis::linting::{IssueCategory, LintRule};
+use crate::analysis::linting::{IssueCategory, LintConfig, LintRule};
 
 /// Built-in lint rules registry
 ///
@@ -32,6 +33,9 @@ use crate::analysis::linting::{IssueCategory, LintRule};
 /// - `PerformanceRule`: Identifies performance-impacting patterns
 /// - `EncodingRule`: Validates text encoding and character usage
 /// - `AccessibilityRule`: Ensures readability and compatibility
+/// - `UnusedStyleRule`: Flags styles no event references
+/// - `LineLengthRule`: Flags lines too long to fit on screen
+/// - `ShortEventRule`: Flags events with too little visible text
 pub struct BuiltinRules;
 
 impl BuiltinRules {
@@ -47,10 +51,30 @@ impl BuiltinRules {
     /// use ass_core::analysis::linting::rules::BuiltinRules;
     ///
     /// let rules = BuiltinRules::all_rules();
-    /// assert_eq!(rules.len(), 8); // All built-in rules
+    /// assert_eq!(rules.len(), 11); // All built-in rules
     /// ```
     #[must_use]
     pub fn all_rules() -> Vec<Box<dyn LintRule>> {
+        Self::configured_rules(&LintConfig::default())
+    }
+
+    /// Get all built-in linting rules with thresholds from `config`
+    ///
+    /// Rules with tunable limits, such as `line-length` and `short-event`,
+    /// take them from the config; the rest match [`BuiltinRules::all_rules`].
+    /// Rule enablement is not applied here.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ass_core::analysis::linting::{rules::BuiltinRules, LintConfig};
+    ///
+    /// let config = LintConfig::default().with_max_line_chars(42);
+    /// let rules = BuiltinRules::configured_rules(&config);
+    /// assert_eq!(rules.len(), BuiltinRules::all_rules().len());
+    /// ```
+    #[must_use]
+    pub fn configured_rules(config: &LintConfig) -> Vec<Box<dyn LintRule>> {
         vec![
             Box::new(TimingOverlapRule),
             Box::new(NegativeDurationRule),
@@ -60,6 +84,9 @@ impl BuiltinRules {
             Box::new(PerformanceRule),
             Box::new(EncodingRule),
             Box::new(AccessibilityRule),
+            Box::new(UnusedStyleRule),
+            Box::new(LineLengthRule::new(config.max_line_chars)),
+            Box::new(ShortEventRule::new(config.min_chars)),
         ]
     }
 
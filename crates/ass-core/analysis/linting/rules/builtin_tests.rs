@@ -5,7 +5,7 @@ use super::*;
 #[test]
 fn all_rules_count_correct() {
     let rules = BuiltinRules::all_rules();
-    assert_eq!(rules.len(), 8);
+    assert_eq!(rules.len(), 11);
 }
 
 #[test]
@@ -42,6 +42,9 @@ fn all_rule_ids_complete() {
         "performance",
         "encoding",
         "accessibility",
+        "unused-styles",
+        "line-length",
+        "short-event",
     ];
 
     for expected_id in expected_ids {
@@ -0,0 +1,239 @@
+//! Long line detection rule for ASS script linting.
+//!
+//! Detects rendered lines with more characters than fit comfortably on
+//! screen, which overflow the video frame unless split with `\N`.
+
+use crate::{
+    analysis::{
+        events::text_analysis::TextAnalysis,
+        linting::{IssueCategory, IssueSeverity, LintIssue, LintRule},
+        ScriptAnalysis,
+    },
+    parser::Section,
+};
+use alloc::{format, string::ToString, vec::Vec};
+
+/// `PlayResX` at which the configured limit applies unscaled
+const REFERENCE_PLAY_RES_X: u32 = 1920;
+
+/// Rule for detecting event lines that are too long to display
+///
+/// Override tags are stripped and the text is split at `\N` and `\n`
+/// before counting Unicode characters per line. The limit grows in
+/// proportion to `PlayResX` above 1920, as wider scripts fit more text;
+/// smaller resolutions keep the configured limit.
+///
+/// # Performance
+///
+/// - Time complexity: O(n) in the total event text length
+/// - Memory: O(m) for the longest event's plain text
+///
+/// # Example
+///
+/// ```rust
+/// use ass_core::analysis::linting::rules::line_length::LineLengthRule;
+/// use ass_core::analysis::linting::LintRule;
+/// use ass_core::{Script, ScriptAnalysis};
+///
+/// let script = Script::parse(r#"
+/// [Events]
+/// Format: Layer, Start, End, Style, Name, MarginL, MarginR, MarginV, Effect, Text
+/// Dialogue: 0,0:00:00.00,0:00:05.00,Default,,0,0,0,,{\b1}A line that is far too long\Nshort
+/// "#)?;
+///
+/// let analysis = ScriptAnalysis::analyze(&script)?;
+/// let issues = LineLengthRule::new(20).check_script(&analysis);
+/// assert_eq!(issues.len(), 1);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LineLengthRule {
+    /// Maximum characters per line at the reference resolution
+    max_line_chars: usize,
+}
+
+impl LineLengthRule {
+    /// Create the rule with a per-line character limit
+    #[must_use]
+    pub const fn new(max_line_chars: usize) -> Self {
+        Self { max_line_chars }
+    }
+
+    /// Configured per-line character limit
+    #[must_use]
+    pub const fn max_line_chars(&self) -> usize {
+        self.max_line_chars
+    }
+
+    /// Character limit for a script with the given `PlayResX`
+    #[must_use]
+    pub fn limit_for(&self, play_res_x: u32) -> usize {
+        if play_res_x <= REFERENCE_PLAY_RES_X {
+            return self.max_line_chars;
+        }
+        let scaled =
+            self.max_line_chars as u64 * u64::from(play_res_x) / u64::from(REFERENCE_PLAY_RES_X);
+        usize::try_from(scaled).unwrap_or(usize::MAX)
+    }
+}
+
+impl Default for LineLengthRule {
+    fn default() -> Self {
+        Self::new(60)
+    }
+}
+
+impl LintRule for LineLengthRule {
+    fn id(&self) -> &'static str {
+        "line-length"
+    }
+
+    fn name(&self) -> &'static str {
+        "Line Length"
+    }
+
+    fn description(&self) -> &'static str {
+        "Detects event lines too long to fit on screen"
+    }
+
+    fn default_severity(&self) -> IssueSeverity {
+        IssueSeverity::Warning
+    }
+
+    fn category(&self) -> IssueCategory {
+        IssueCategory::Content
+    }
+
+    fn check_script(&self, analysis: &ScriptAnalysis) -> Vec<LintIssue> {
+        let mut issues = Vec::new();
+        let script = analysis.script();
+        let (play_res_x, _) = script.info().play_resolution();
+        let limit = self.limit_for(play_res_x);
+
+        let Some(Section::Events(events)) = script
+            .sections()
+            .iter()
+            .find(|s| matches!(s, Section::Events(_)))
+        else {
+            return issues;
+        };
+
+        for event in events.iter().filter(|event| event.is_dialogue()) {
+            let Ok(text) = TextAnalysis::analyze(event.text) else {
+                continue;
+            };
+            for (index, line) in text.plain_text().split('\n').enumerate() {
+                let chars = line.chars().count();
+                if chars > limit {
+                    issues.push(
+                        LintIssue::new(
+                            self.default_severity(),
+                            IssueCategory::Content,
+                            self.id(),
+                            format!(
+                                "Line {} of event at {} has {chars} characters (limit {limit})",
+                                index + 1,
+                                event.start
+                            ),
+                        )
+                        .with_description(
+                            "Long lines overflow the video frame when rendered".to_string(),
+                        )
+                        .with_suggested_fix("Split the line with \\N".to_string()),
+                    );
+                }
+            }
+        }
+
+        issues
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::string::String;
+
+    fn script_text(play_res_x: Option<u32>, text: &str) -> String {
+        let info = play_res_x.map_or_else(String::new, |x| format!("PlayResX: {x}\n"));
+        format!(
+            "[Script Info]\n{info}\n[Events]\nFormat: Layer, Start, End, Style, Name, MarginL, MarginR, MarginV, Effect, Text\nDialogue: 0,0:00:00.00,0:00:05.00,Default,,0,0,0,,{text}\n"
+        )
+    }
+
+    fn check(rule: LineLengthRule, play_res_x: Option<u32>, text: &str) -> Vec<LintIssue> {
+        let source = script_text(play_res_x, text);
+        let script = crate::parser::Script::parse(&source).unwrap();
+        let analysis = ScriptAnalysis::analyze(&script).unwrap();
+        rule.check_script(&analysis)
+    }
+
+    #[test]
+    fn rule_metadata_correct() {
+        let rule = LineLengthRule::default();
+        assert_eq!(rule.id(), "line-length");
+        assert_eq!(rule.max_line_chars(), 60);
+        assert_eq!(rule.default_severity(), IssueSeverity::Warning);
+        assert_eq!(rule.category(), IssueCategory::Content);
+    }
+
+    #[test]
+    fn exactly_at_limit_passes() {
+        let text = "a".repeat(60);
+        assert!(check(LineLengthRule::default(), None, &text).is_empty());
+
+        let text = "a".repeat(61);
+        let issues = check(LineLengthRule::default(), None, &text);
+        assert_eq!(issues.len(), 1);
+        assert!(issues[0].message().contains("61 characters (limit 60)"));
+    }
+
+    #[test]
+    fn override_tags_not_counted() {
+        let text = format!("{{\\b1\\c&H0000FF&}}{}{{\\b0}}", "a".repeat(10));
+        assert!(check(LineLengthRule::new(10), None, &text).is_empty());
+    }
+
+    #[test]
+    fn counts_characters_not_bytes() {
+        let text = "é".repeat(10);
+        assert!(check(LineLengthRule::new(10), None, &text).is_empty());
+    }
+
+    #[test]
+    fn each_line_checked_separately() {
+        let text = format!(
+            "{}\\N{}\\n{}",
+            "a".repeat(10),
+            "b".repeat(11),
+            "c".repeat(12)
+        );
+        let issues = check(LineLengthRule::new(10), None, &text);
+        assert_eq!(issues.len(), 2);
+        assert!(issues[0].message().starts_with("Line 2 "));
+        assert!(issues[1].message().starts_with("Line 3 "));
+    }
+
+    #[test]
+    fn limit_scales_with_play_res_x() {
+        let rule = LineLengthRule::new(60);
+        assert_eq!(rule.limit_for(384), 60);
+        assert_eq!(rule.limit_for(1920), 60);
+        assert_eq!(rule.limit_for(3840), 120);
+
+        let text = "a".repeat(100);
+        assert_eq!(check(rule, Some(1920), &text).len(), 1);
+        assert!(check(rule, Some(3840), &text).is_empty());
+    }
+
+    #[test]
+    fn comments_ignored() {
+        let source = format!(
+            "[Events]\nFormat: Layer, Start, End, Style, Name, MarginL, MarginR, MarginV, Effect, Text\nComment: 0,0:00:00.00,0:00:05.00,Default,,0,0,0,,{}\n",
+            "a".repeat(100)
+        );
+        let script = crate::parser::Script::parse(&source).unwrap();
+        let analysis = ScriptAnalysis::analyze(&script).unwrap();
+        assert!(LineLengthRule::default().check_script(&analysis).is_empty());
+    }
+}
@@ -36,19 +36,25 @@ pub mod accessibility;
 pub mod encoding;
 pub mod invalid_color;
 pub mod invalid_tag;
+pub mod line_length;
 pub mod missing_style;
 pub mod negative_duration;
 pub mod performance;
+pub mod short_event;
 pub mod timing_overlap;
+pub mod unused_style;
 
 pub use accessibility::AccessibilityRule;
 pub use encoding::EncodingRule;
 pub use invalid_color::InvalidColorRule;
 pub use invalid_tag::InvalidTagRule;
+pub use line_length::LineLengthRule;
 pub use missing_style::MissingStyleRule;
 pub use negative_duration::NegativeDurationRule;
 pub use performance::PerformanceRule;
+pub use short_event::ShortEventRule;
 pub use timing_overlap::TimingOverlapRule;
+pub use unused_style::UnusedStyleRule;
 
 mod builtin;
 
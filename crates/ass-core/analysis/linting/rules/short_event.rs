@@ -0,0 +1,185 @@
+//! Short event detection rule for ASS script linting.
+//!
+//! Detects dialogue events with little or no visible text, which are
+//! usually leftovers from editing.
+
+use crate::{
+    analysis::{
+        events::text_analysis::TextAnalysis,
+        linting::{IssueCategory, IssueSeverity, LintIssue, LintRule},
+        ScriptAnalysis,
+    },
+    parser::Section,
+};
+use alloc::{format, string::ToString, vec::Vec};
+
+/// Rule for detecting events with fewer visible characters than a minimum
+///
+/// Characters are counted after stripping override tags and surrounding
+/// whitespace. Events made up only of override tags, such as positioning
+/// or drawing setup lines, are not reported.
+///
+/// # Performance
+///
+/// - Time complexity: O(n) in the total event text length
+/// - Memory: O(m) for the longest event's plain text
+///
+/// # Example
+///
+/// ```rust
+/// use ass_core::analysis::linting::rules::short_event::ShortEventRule;
+/// use ass_core::analysis::linting::LintRule;
+/// use ass_core::{Script, ScriptAnalysis};
+///
+/// let script = Script::parse(r#"
+/// [Events]
+/// Format: Layer, Start, End, Style, Name, MarginL, MarginR, MarginV, Effect, Text
+/// Dialogue: 0,0:00:00.00,0:00:05.00,Default,,0,0,0,,
+/// Dialogue: 0,0:00:05.00,0:00:06.00,Default,,0,0,0,,{\pos(10,10)}
+/// "#)?;
+///
+/// let analysis = ScriptAnalysis::analyze(&script)?;
+/// let issues = ShortEventRule::default().check_script(&analysis);
+/// assert_eq!(issues.len(), 1);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ShortEventRule {
+    /// Minimum visible characters per event
+    min_chars: usize,
+}
+
+impl ShortEventRule {
+    /// Create the rule with a minimum visible character count
+    #[must_use]
+    pub const fn new(min_chars: usize) -> Self {
+        Self { min_chars }
+    }
+
+    /// Configured minimum visible character count
+    #[must_use]
+    pub const fn min_chars(&self) -> usize {
+        self.min_chars
+    }
+}
+
+impl Default for ShortEventRule {
+    fn default() -> Self {
+        Self::new(1)
+    }
+}
+
+impl LintRule for ShortEventRule {
+    fn id(&self) -> &'static str {
+        "short-event"
+    }
+
+    fn name(&self) -> &'static str {
+        "Short Event"
+    }
+
+    fn description(&self) -> &'static str {
+        "Detects events with too little visible text"
+    }
+
+    fn default_severity(&self) -> IssueSeverity {
+        IssueSeverity::Info
+    }
+
+    fn category(&self) -> IssueCategory {
+        IssueCategory::Content
+    }
+
+    fn check_script(&self, analysis: &ScriptAnalysis) -> Vec<LintIssue> {
+        let mut issues = Vec::new();
+
+        let Some(Section::Events(events)) = analysis
+            .script()
+            .sections()
+            .iter()
+            .find(|s| matches!(s, Section::Events(_)))
+        else {
+            return issues;
+        };
+
+        for event in events.iter().filter(|event| event.is_dialogue()) {
+            let Ok(text) = TextAnalysis::analyze(event.text) else {
+                continue;
+            };
+            let plain = text.plain_text();
+            if plain.is_empty() && !text.override_tags().is_empty() {
+                continue;
+            }
+
+            let chars = plain.trim().chars().count();
+            if chars < self.min_chars {
+                issues.push(
+                    LintIssue::new(
+                        self.default_severity(),
+                        IssueCategory::Content,
+                        self.id(),
+                        format!(
+                            "Event at {} has {chars} visible characters (minimum {})",
+                            event.start, self.min_chars
+                        ),
+                    )
+                    .with_description("Events without text display nothing".to_string())
+                    .with_suggested_fix("Add text or remove the event".to_string()),
+                );
+            }
+        }
+
+        issues
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn check(rule: ShortEventRule, texts: &[&str]) -> Vec<LintIssue> {
+        let events: Vec<_> = texts
+            .iter()
+            .map(|text| format!("Dialogue: 0,0:00:00.00,0:00:05.00,Default,,0,0,0,,{text}\n"))
+            .collect();
+        let source = format!(
+            "[Events]\nFormat: Layer, Start, End, Style, Name, MarginL, MarginR, MarginV, Effect, Text\n{}",
+            events.concat()
+        );
+        let script = crate::parser::Script::parse(&source).unwrap();
+        let analysis = ScriptAnalysis::analyze(&script).unwrap();
+        rule.check_script(&analysis)
+    }
+
+    #[test]
+    fn rule_metadata_correct() {
+        let rule = ShortEventRule::default();
+        assert_eq!(rule.id(), "short-event");
+        assert_eq!(rule.min_chars(), 1);
+        assert_eq!(rule.default_severity(), IssueSeverity::Info);
+        assert_eq!(rule.category(), IssueCategory::Content);
+    }
+
+    #[test]
+    fn empty_event_detected() {
+        let issues = check(ShortEventRule::default(), &["", "   ", "a"]);
+        assert_eq!(issues.len(), 2);
+        assert!(issues[0].message().contains("0 visible characters"));
+    }
+
+    #[test]
+    fn override_only_event_ignored() {
+        let issues = check(
+            ShortEventRule::default(),
+            &["{\\pos(10,10)}", "{\\an8}{\\b1}"],
+        );
+        assert!(issues.is_empty());
+    }
+
+    #[test]
+    fn exactly_at_minimum_passes() {
+        let rule = ShortEventRule::new(3);
+        assert!(check(rule, &["abc", "{\\b1}a{\\b0}bc"]).is_empty());
+        assert_eq!(check(rule, &["ab", "{\\i1} ab "]).len(), 2);
+    }
+}
@@ -0,0 +1,98 @@
+//! Unused style detection rule for ASS script linting.
+//!
+//! Detects styles defined in `[V4+ Styles]` that no event references, which
+//! usually indicates leftovers from editing or template scripts.
+
+use crate::{
+    analysis::{
+        linting::{IssueCategory, IssueSeverity, LintIssue, LintRule},
+        ScriptAnalysis,
+    },
+    parser::Section,
+};
+use alloc::{format, vec::Vec};
+
+/// Rule for detecting styles that no event uses
+///
+/// Unused styles do not affect rendering but bloat scripts and make style
+/// lists harder to navigate. Scripts without an `[Events\]` section (such as
+/// style libraries) are skipped. Built on the analysis
+/// [`StyleUsageReport`](crate::analysis::StyleUsageReport).
+///
+/// # Performance
+///
+/// - Time complexity: O(n) over the precomputed style usage report
+/// - Memory: O(1) additional space
+///
+/// # Example
+///
+/// ```rust
+/// use ass_core::analysis::linting::rules::unused_style::UnusedStyleRule;
+/// use ass_core::analysis::linting::LintRule;
+/// use ass_core::{Script, ScriptAnalysis};
+///
+/// let script = Script::parse(r#"
+/// [V4+ Styles]
+/// Format: Name, Fontname, Fontsize
+/// Style: Default,Arial,20
+/// Style: Orphan,Arial,20
+///
+/// [Events]
+/// Format: Layer, Start, End, Style, Name, MarginL, MarginR, MarginV, Effect, Text
+/// Dialogue: 0,0:00:00.00,0:00:05.00,Default,,0,0,0,,Text
+/// "#)?;
+///
+/// let analysis = ScriptAnalysis::analyze(&script)?;
+/// let issues = UnusedStyleRule.check_script(&analysis);
+/// assert_eq!(issues.len(), 1);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub struct UnusedStyleRule;
+
+impl LintRule for UnusedStyleRule {
+    fn id(&self) -> &'static str {
+        "unused-styles"
+    }
+
+    fn name(&self) -> &'static str {
+        "Unused Styles"
+    }
+
+    fn description(&self) -> &'static str {
+        "Detects styles that are defined but never referenced by an event"
+    }
+
+    fn default_severity(&self) -> IssueSeverity {
+        IssueSeverity::Info
+    }
+
+    fn category(&self) -> IssueCategory {
+        IssueCategory::Styling
+    }
+
+    fn check_script(&self, analysis: &ScriptAnalysis) -> Vec<LintIssue> {
+        let has_events = analysis
+            .script()
+            .sections()
+            .iter()
+            .any(|s| matches!(s, Section::Events(_)));
+        if !has_events {
+            return Vec::new();
+        }
+
+        analysis
+            .style_usage_report()
+            .unused_styles
+            .iter()
+            .map(|name| {
+                LintIssue::new(
+                    self.default_severity(),
+                    IssueCategory::Styling,
+                    self.id(),
+                    format!("Style '{name}' is never used"),
+                )
+                .with_suggested_fix(format!("Remove style '{name}' or assign it to an event"))
+            })
+            .collect()
+    }
+}
@@ -6,7 +6,7 @@
 
 use super::{rules::BuiltinRules, LintConfig, LintIssue};
 use crate::{
-    analysis::{AnalysisConfig, ScriptAnalysis},
+    analysis::{AnalysisConfig, ScriptAnalysis, StyleUsageReport},
     parser::Script,
     Result,
 };
@@ -27,7 +27,7 @@ pub fn lint_script_with_analysis(
     config: &LintConfig,
 ) -> Result<Vec<LintIssue>> {
     let mut issues = Vec::new();
-    let rules = BuiltinRules::all_rules();
+    let rules = BuiltinRules::configured_rules(config);
 
     for rule in rules {
         if !config.is_rule_enabled(rule.id()) {
@@ -64,6 +64,7 @@ pub fn lint_script(script: &Script, config: &LintConfig) -> Result<Vec<LintIssue
         lint_issues: Vec::new(),
         resolved_styles: Vec::new(),
         dialogue_info: Vec::new(),
+        style_usage: StyleUsageReport::compute(script),
         config: AnalysisConfig::default(),
         #[cfg(feature = "plugins")]
         registry: None,
@@ -1,7 +1,7 @@
 //! Unit tests for the linting entry points.
 
 use super::*;
-use crate::analysis::{AnalysisConfig, ScriptAnalysis};
+use crate::analysis::{AnalysisConfig, ScriptAnalysis, StyleUsageReport};
 use crate::parser::Script;
 use alloc::vec::Vec;
 
@@ -26,6 +26,7 @@ fn lint_script_with_analysis_empty() {
         lint_issues: Vec::new(),
         resolved_styles: Vec::new(),
         dialogue_info: Vec::new(),
+        style_usage: StyleUsageReport::default(),
         config: AnalysisConfig::default(),
         #[cfg(feature = "plugins")]
         registry: None,
@@ -61,6 +62,7 @@ fn lint_script_with_disabled_rule() {
         lint_issues: Vec::new(),
         resolved_styles: Vec::new(),
         dialogue_info: Vec::new(),
+        style_usage: StyleUsageReport::default(),
         config: AnalysisConfig::default(),
         #[cfg(feature = "plugins")]
         registry: None,
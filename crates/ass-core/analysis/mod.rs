@@ -11,6 +11,7 @@
 //! - Performance analysis: Identify rendering bottlenecks
 //! - Unicode handling: Bidirectional text and linebreak analysis
 //! - Timing validation: Overlap detection and duration checks
+//! - Timing statistics: On-screen time, durations, and gaps between dialogue
 //!
 //! # Performance
 //!
@@ -74,19 +75,31 @@ bitflags::bitflags! {
 
 mod construction;
 pub mod events;
+mod font_usage;
 pub mod linting;
 mod queries;
+mod style_usage;
 pub mod styles;
+mod timing_stats;
 
 #[cfg(test)]
 mod analysis_tests;
+#[cfg(test)]
+mod font_usage_tests;
+#[cfg(test)]
+mod style_usage_tests;
+#[cfg(test)]
+mod timing_stats_tests;
 
 pub use events::{
     count_overlapping_dialogue_events, count_overlapping_events, find_overlapping_dialogue_events,
     find_overlapping_events, DialogueInfo,
 };
+pub use font_usage::{FontReferenceKind, FontUsage, FontUsageReport};
 pub use linting::{lint_script, LintConfig, LintIssue, LintRule};
-pub use styles::{ResolvedStyle, StyleAnalyzer};
+pub use style_usage::StyleUsageReport;
+pub use styles::{ResolvedStyle, StyleAnalyzer, StyleProperty, StyleResolver};
+pub use timing_stats::TimingStats;
 
 /// Comprehensive analysis of an ASS script
 ///
@@ -106,6 +119,9 @@ pub struct ScriptAnalysis<'a> {
     /// Dialogue analysis results
     dialogue_info: Vec<DialogueInfo<'a>>,
 
+    /// Style usage cross-reference
+    style_usage: StyleUsageReport<'a>,
+
     /// Analysis configuration
     config: AnalysisConfig,
 
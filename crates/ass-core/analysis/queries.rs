@@ -5,7 +5,7 @@
 
 use super::{
     count_overlapping_dialogue_events, linting, DialogueInfo, LintIssue, PerformanceSummary,
-    ResolvedStyle, ScriptAnalysis,
+    ResolvedStyle, ScriptAnalysis, StyleUsageReport,
 };
 use crate::parser::Script;
 
@@ -28,6 +28,12 @@ impl<'a> ScriptAnalysis<'a> {
         &self.dialogue_info
     }
 
+    /// Get the used/unused/undefined style cross-reference
+    #[must_use]
+    pub const fn style_usage_report(&self) -> &StyleUsageReport<'a> {
+        &self.style_usage
+    }
+
     /// Get reference to the analyzed script
     #[must_use]
     pub const fn script(&self) -> &'a Script<'a> {
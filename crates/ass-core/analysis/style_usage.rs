@@ -0,0 +1,176 @@
+//! Style usage report: used, unused, and undefined style references
+//!
+//! Cross-references the styles defined in `[V4+ Styles]` with the `Style`
+//! field of every event and the `\r<Style>` overrides in its text, so
+//! authors can spot orphaned definitions and references to styles that do
+//! not exist.
+
+use alloc::vec::Vec;
+use core::fmt;
+
+use ahash::RandomState;
+#[cfg(not(feature = "std"))]
+use hashbrown::{HashMap, HashSet};
+#[cfg(feature = "std")]
+use std::collections::{HashMap, HashSet};
+
+use super::events::text_analysis::TextAnalysis;
+use super::FontUsageReport;
+use crate::parser::{Script, Section};
+use crate::utils::create_hash_map;
+
+/// Which styles a script's events use
+///
+/// # Example
+///
+/// ```rust
+/// use ass_core::{analysis::StyleUsageReport, Script};
+///
+/// let script = Script::parse("[V4+ Styles]
+/// Format: Name, Fontname, Fontsize
+/// Style: Default,Arial,20
+/// Style: Orphan,Arial,20
+///
+/// [Events]
+/// Format: Layer, Start, End, Style, Name, MarginL, MarginR, MarginV, Effect, Text
+/// Dialogue: 0,0:00:00.00,0:00:05.00,Default,,0,0,0,,Hello")?;
+///
+/// let report = StyleUsageReport::compute(&script);
+/// assert_eq!(report.used_styles["Default"], 1);
+/// assert_eq!(report.unused_styles, ["Orphan"]);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Debug, Clone)]
+pub struct StyleUsageReport<'a> {
+    /// Defined styles referenced by events, with their event counts
+    ///
+    /// An event references its `Style` field and every style named by a
+    /// `\r<Style>` override in its text.
+    pub used_styles: HashMap<&'a str, usize, RandomState>,
+    /// Defined styles no event references, in definition order
+    pub unused_styles: Vec<&'a str>,
+    /// Referenced but undefined styles with event counts, in first-use order
+    pub undefined_styles: Vec<(&'a str, usize)>,
+}
+
+impl<'a> StyleUsageReport<'a> {
+    /// Compute the report for `script`
+    #[must_use]
+    pub fn compute(script: &Script<'a>) -> Self {
+        let defined: Vec<&'a str> = script
+            .sections()
+            .iter()
+            .filter_map(|section| match section {
+                Section::Styles(styles) => Some(styles),
+                _ => None,
+            })
+            .flatten()
+            .map(|style| style.name)
+            .collect();
+
+        let mut defined_set = HashSet::with_hasher(RandomState::new());
+        defined_set.extend(defined.iter().copied());
+
+        let mut used_styles = create_hash_map();
+        let mut undefined_styles: Vec<(&'a str, usize)> = Vec::new();
+        let mut undefined_slots: HashMap<&'a str, usize, RandomState> = create_hash_map();
+        let events = script
+            .sections()
+            .iter()
+            .filter_map(|section| match section {
+                Section::Events(events) => Some(events),
+                _ => None,
+            })
+            .flatten();
+        let mut referenced: Vec<&'a str> = Vec::new();
+        let mut seen = HashSet::with_hasher(RandomState::new());
+        for event in events {
+            referenced.clear();
+            seen.clear();
+            referenced.push(event.style);
+            seen.insert(event.style);
+            if let Ok(analysis) = TextAnalysis::analyze(event.text) {
+                for tag in analysis.override_tags() {
+                    let style = tag.args().trim();
+                    if tag.name() == "r" && !style.is_empty() && seen.insert(style) {
+                        referenced.push(style);
+                    }
+                }
+            }
+
+            for &style in &referenced {
+                if defined_set.contains(style) {
+                    *used_styles.entry(style).or_insert(0) += 1;
+                } else if let Some(&slot) = undefined_slots.get(style) {
+                    undefined_styles[slot].1 += 1;
+                } else {
+                    undefined_slots.insert(style, undefined_styles.len());
+                    undefined_styles.push((style, 1));
+                }
+            }
+        }
+
+        seen.clear();
+        let unused_styles = defined
+            .into_iter()
+            .filter(|name| !used_styles.contains_key(name) && seen.insert(name))
+            .collect();
+
+        Self {
+            used_styles,
+            unused_styles,
+            undefined_styles,
+        }
+    }
+
+    /// Fonts named by inline `\fn` overrides that no style defines and that
+    /// are missing from `available`
+    ///
+    /// This optional pass complements the style checks: a style's own font
+    /// is covered by the style definition, but an inline override can name
+    /// any family.
+    #[must_use]
+    pub fn undefined_inline_fonts(script: &Script<'a>, available: &[&str]) -> Vec<&'a str> {
+        let fonts = FontUsageReport::compute(script);
+        fonts
+            .missing_fonts(available)
+            .into_iter()
+            .filter(|name| fonts.get(name).is_some_and(|f| f.styles.is_empty()))
+            .collect()
+    }
+
+    /// Whether every event references a defined style and every style is used
+    #[must_use]
+    pub fn is_clean(&self) -> bool {
+        self.unused_styles.is_empty() && self.undefined_styles.is_empty()
+    }
+}
+
+impl Default for StyleUsageReport<'_> {
+    fn default() -> Self {
+        Self {
+            used_styles: create_hash_map(),
+            unused_styles: Vec::new(),
+            undefined_styles: Vec::new(),
+        }
+    }
+}
+
+impl fmt::Display for StyleUsageReport<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "{} used, {} unused, {} undefined style(s)",
+            self.used_styles.len(),
+            self.unused_styles.len(),
+            self.undefined_styles.len()
+        )?;
+        for name in &self.unused_styles {
+            writeln!(f, "  unused: {name}")?;
+        }
+        for (name, count) in &self.undefined_styles {
+            writeln!(f, "  undefined: {name} ({count} event(s))")?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,95 @@
+//! Tests for the style usage report.
+
+use super::*;
+use crate::parser::Script;
+
+const SCRIPT: &str = r"[Script Info]
+Title: Styles
+
+[V4+ Styles]
+Format: Name, Fontname, Fontsize
+Style: Default,Arial,20
+Style: Sign,Verdana,30
+Style: Orphan,Arial,20
+
+[Events]
+Format: Layer, Start, End, Style, Name, MarginL, MarginR, MarginV, Effect, Text
+Dialogue: 0,0:00:00.00,0:00:02.00,Default,,0,0,0,,One
+Dialogue: 0,0:00:02.00,0:00:04.00,Default,,0,0,0,,Two
+Dialogue: 0,0:00:04.00,0:00:06.00,Sign,,0,0,0,,{\fnGeorgia}Sign
+Dialogue: 0,0:00:06.00,0:00:08.00,Missing,,0,0,0,,Three
+Comment: 0,0:00:08.00,0:00:10.00,Missing,,0,0,0,,Four
+";
+
+#[test]
+fn style_usage_reports_all_categories() {
+    let script = Script::parse(SCRIPT).unwrap();
+    let report = StyleUsageReport::compute(&script);
+
+    assert_eq!(report.used_styles.len(), 2);
+    assert_eq!(report.used_styles["Default"], 2);
+    assert_eq!(report.used_styles["Sign"], 1);
+    assert_eq!(report.unused_styles, ["Orphan"]);
+    assert_eq!(report.undefined_styles, [("Missing", 2)]);
+    assert!(!report.is_clean());
+}
+
+#[test]
+fn style_usage_clean_script() {
+    let script = Script::parse(
+        "[V4+ Styles]\nFormat: Name, Fontname, Fontsize\nStyle: Default,Arial,20\n\n[Events]\nFormat: Layer, Start, End, Style, Name, MarginL, MarginR, MarginV, Effect, Text\nDialogue: 0,0:00:00.00,0:00:01.00,Default,,0,0,0,,Hi",
+    )
+    .unwrap();
+    assert!(StyleUsageReport::compute(&script).is_clean());
+}
+
+#[test]
+fn style_usage_exposed_from_analysis_and_lints() {
+    let script = Script::parse(SCRIPT).unwrap();
+    let analysis = ScriptAnalysis::analyze(&script).unwrap();
+
+    assert_eq!(analysis.style_usage_report().unused_styles, ["Orphan"]);
+    let unused: Vec<_> = analysis
+        .lint_issues()
+        .iter()
+        .filter(|issue| issue.rule_id() == "unused-styles")
+        .collect();
+    assert_eq!(unused.len(), 1);
+    assert!(unused[0].message().contains("Orphan"));
+}
+
+#[test]
+fn style_usage_undefined_inline_fonts() {
+    let script = Script::parse(SCRIPT).unwrap();
+    let fonts = StyleUsageReport::undefined_inline_fonts(&script, &[]);
+    assert_eq!(fonts, ["Georgia"]);
+    assert!(StyleUsageReport::undefined_inline_fonts(&script, &["georgia"]).is_empty());
+}
+
+#[test]
+fn style_usage_counts_inline_reset_overrides() {
+    let script = Script::parse(
+        r"[V4+ Styles]
+Format: Name, Fontname, Fontsize
+Style: Default,Arial,20
+Style: Shout,Impact,40
+
+[Events]
+Format: Layer, Start, End, Style, Name, MarginL, MarginR, MarginV, Effect, Text
+Dialogue: 0,0:00:00.00,0:00:02.00,Default,,0,0,0,,Calm {\rShout}LOUD{\r} calm {\rShout}again
+Dialogue: 0,0:00:02.00,0:00:04.00,Default,,0,0,0,,{\rDefault\rGone}Plain",
+    )
+    .unwrap();
+    let report = StyleUsageReport::compute(&script);
+
+    assert_eq!(report.used_styles["Default"], 2);
+    assert_eq!(report.used_styles["Shout"], 1);
+    assert!(report.unused_styles.is_empty());
+    assert_eq!(report.undefined_styles, [("Gone", 1)]);
+
+    let analysis = ScriptAnalysis::analyze(&script).unwrap();
+    assert!(!analysis
+        .lint_issues()
+        .iter()
+        .any(|issue| issue.rule_id() == "unused-styles"));
+}
@@ -57,15 +57,21 @@
 //! # Module Organization
 //!
 //! - [`resolved_style`] - Fully resolved style representation with computed values
+//! - [`resolver`] - Style cascade from script styles and inline override tags
 //! - [`validation`] - Style validation, conflict detection, and issue reporting
 //! - [`analyzer`] - Main analysis interface orchestrating all style operations
 
 pub mod analyzer;
 pub mod resolved_style;
+pub mod resolver;
 pub mod validation;
 
+#[cfg(test)]
+mod resolver_tests;
+
 pub use analyzer::{PerformanceThresholds, StyleAnalysisConfig, StyleAnalyzer};
-pub use resolved_style::ResolvedStyle;
+pub use resolved_style::{ResolvedStyle, StyleProperty};
+pub use resolver::StyleResolver;
 pub use validation::{
     ConflictType, StyleConflict, StyleInheritance, StyleValidationIssue, ValidationSeverity,
 };
@@ -125,4 +125,40 @@ impl ResolvedStyle<'_> {
     pub const fn angle(&self) -> f32 {
         self.angle
     }
+
+    /// Get background (shadow) color as RGBA bytes
+    #[must_use]
+    pub const fn back_color(&self) -> [u8; 4] {
+        self.back_color
+    }
+
+    /// Get horizontal scale percentage
+    #[must_use]
+    pub const fn scale_x(&self) -> f32 {
+        self.scale_x
+    }
+
+    /// Get vertical scale percentage
+    #[must_use]
+    pub const fn scale_y(&self) -> f32 {
+        self.scale_y
+    }
+
+    /// Get border style (`1` outline and drop shadow, `3` opaque box)
+    #[must_use]
+    pub const fn border_style(&self) -> u8 {
+        self.border_style
+    }
+
+    /// Get numpad alignment (1-9)
+    #[must_use]
+    pub const fn alignment(&self) -> u8 {
+        self.alignment
+    }
+
+    /// Get font encoding
+    #[must_use]
+    pub const fn encoding(&self) -> u8 {
+        self.encoding
+    }
 }
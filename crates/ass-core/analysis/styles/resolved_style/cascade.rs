@@ -0,0 +1,264 @@
+//! Lenient style cascading and override-tag layering for `ResolvedStyle`.
+//!
+//! Unlike `from_style`, which rejects malformed fields, the cascade keeps the
+//! parent's value for every field that is unset or unparsable, so any style
+//! line resolves. Override tags are then layered on top one at a time.
+
+use super::{ResolvedStyle, TextFormatting};
+use crate::override_parser::OverrideTag;
+use crate::parser::Style;
+use alloc::string::{String, ToString};
+
+impl Default for ResolvedStyle<'_> {
+    /// The built-in style libass falls back to when a script defines none
+    fn default() -> Self {
+        let style = Self {
+            name: "Default",
+            font_name: String::from("Arial"),
+            font_size: 18.0,
+            primary_color: [255, 255, 255, 0],
+            secondary_color: [255, 0, 0, 0],
+            outline_color: [0, 0, 0, 0],
+            back_color: [0, 0, 0, 0x80],
+            formatting: TextFormatting::empty(),
+            scale_x: 100.0,
+            scale_y: 100.0,
+            spacing: 0.0,
+            angle: 0.0,
+            border_style: 1,
+            outline: 2.0,
+            shadow: 2.0,
+            alignment: 2,
+            margin_l: 20,
+            margin_r: 20,
+            margin_t: 20,
+            margin_b: 20,
+            encoding: 1,
+            complexity_score: 0,
+        };
+        Self {
+            complexity_score: Self::calculate_complexity(&style),
+            ..style
+        }
+    }
+}
+
+impl<'a> ResolvedStyle<'a> {
+    /// Resolve `style` on top of `parent`, field by field
+    ///
+    /// Every field of `style` that is empty or fails to parse keeps the
+    /// parent's value instead of failing the whole style. Boolean flags follow
+    /// ASS semantics: any non-zero value (usually `-1`) is true, and `Bold`
+    /// also accepts a font weight, counting 700 and above as bold.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use ass_core::analysis::styles::resolved_style::ResolvedStyle;
+    /// # use ass_core::parser::Style;
+    /// let style = Style { name: "Sign", fontsize: "32", bold: "-1", outline: "oops", ..Default::default() };
+    /// let resolved = ResolvedStyle::cascade(&style, &ResolvedStyle::default());
+    /// assert_eq!(resolved.font_size(), 32.0);
+    /// assert!(resolved.is_bold());
+    /// assert_eq!(resolved.outline(), 2.0);
+    /// ```
+    #[must_use]
+    pub fn cascade(style: &Style<'a>, parent: &Self) -> Self {
+        let mut resolved = parent.clone();
+        resolved.name = style.name;
+
+        if !style.fontname.trim().is_empty() {
+            resolved.font_name = style.fontname.trim().to_string();
+        }
+        if let Some(size) = number(style.fontsize).filter(|size| *size > 0.0) {
+            resolved.font_size = size;
+        }
+        for (field, color) in [
+            (style.primary_colour, &mut resolved.primary_color),
+            (style.secondary_colour, &mut resolved.secondary_color),
+            (style.outline_colour, &mut resolved.outline_color),
+            (style.back_colour, &mut resolved.back_color),
+        ] {
+            if let Ok(parsed) = crate::utils::parse_bgr_color(field) {
+                *color = parsed;
+            }
+        }
+
+        if let Some(weight) = integer(style.bold) {
+            resolved.set_flag(TextFormatting::BOLD, weight_is_bold(weight));
+        }
+        for (field, flag) in [
+            (style.italic, TextFormatting::ITALIC),
+            (style.underline, TextFormatting::UNDERLINE),
+            (style.strikeout, TextFormatting::STRIKE_OUT),
+        ] {
+            if let Some(value) = integer(field) {
+                resolved.set_flag(flag, value != 0);
+            }
+        }
+
+        if let Some(scale) = number(style.scale_x).filter(|scale| *scale >= 0.0) {
+            resolved.scale_x = scale;
+        }
+        if let Some(scale) = number(style.scale_y).filter(|scale| *scale >= 0.0) {
+            resolved.scale_y = scale;
+        }
+        if let Some(spacing) = number(style.spacing) {
+            resolved.spacing = spacing;
+        }
+        if let Some(angle) = number(style.angle) {
+            resolved.angle = angle;
+        }
+        if let Ok(border_style) = style.border_style.trim().parse() {
+            resolved.border_style = border_style;
+        }
+        if let Some(outline) = number(style.outline).filter(|outline| *outline >= 0.0) {
+            resolved.outline = outline;
+        }
+        if let Some(shadow) = number(style.shadow).filter(|shadow| *shadow >= 0.0) {
+            resolved.shadow = shadow;
+        }
+        if let Some(alignment) = style
+            .alignment
+            .trim()
+            .parse()
+            .ok()
+            .filter(|a| (1..=9).contains(a))
+        {
+            resolved.alignment = alignment;
+        }
+
+        if let Some(margin) = margin(style.margin_l) {
+            resolved.margin_l = margin;
+        }
+        if let Some(margin) = margin(style.margin_r) {
+            resolved.margin_r = margin;
+        }
+        let (top, bottom) = match (style.margin_t, style.margin_b) {
+            (Some(top), Some(bottom)) => (margin(top), margin(bottom)),
+            _ => (margin(style.margin_v), margin(style.margin_v)),
+        };
+        if let Some(top) = top {
+            resolved.margin_t = top;
+        }
+        if let Some(bottom) = bottom {
+            resolved.margin_b = bottom;
+        }
+        if let Ok(encoding) = style.encoding.trim().parse() {
+            resolved.encoding = encoding;
+        }
+
+        resolved.complexity_score = Self::calculate_complexity(&resolved);
+        resolved
+    }
+
+    /// Layer a single override tag on top of the style
+    ///
+    /// Only static style properties are affected: positioning, clipping,
+    /// karaoke, fades and `\t` animations are ignored, as are `\r` resets,
+    /// which need the other styles of the script (see
+    /// [`StyleResolver`](crate::analysis::styles::StyleResolver)). The
+    /// per-axis `\xbord`/`\ybord`/`\xshad`/`\yshad` tags have no equivalent
+    /// here and are ignored too. Colors keep their alpha; alpha tags keep the
+    /// color.
+    pub fn apply_override(&mut self, tag: &OverrideTag) {
+        match tag {
+            OverrideTag::Bold(on) => self.set_flag(TextFormatting::BOLD, *on),
+            OverrideTag::FontWeight(weight) => {
+                self.set_flag(TextFormatting::BOLD, weight_is_bold(i64::from(*weight)));
+            }
+            OverrideTag::Italic(on) => self.set_flag(TextFormatting::ITALIC, *on),
+            OverrideTag::Underline(on) => self.set_flag(TextFormatting::UNDERLINE, *on),
+            OverrideTag::StrikeOut(on) => self.set_flag(TextFormatting::STRIKE_OUT, *on),
+            OverrideTag::Border(width) => self.outline = width.max(0.0),
+            OverrideTag::Shadow(depth) => self.shadow = depth.max(0.0),
+            OverrideTag::FontName(name) if !name.trim().is_empty() => {
+                self.font_name = name.trim().to_string();
+            }
+            OverrideTag::FontSize(size) if *size > 0.0 => self.font_size = *size,
+            OverrideTag::FontScaleX(scale) => self.scale_x = scale.max(0.0),
+            OverrideTag::FontScaleY(scale) => self.scale_y = scale.max(0.0),
+            OverrideTag::Spacing(spacing) => self.spacing = *spacing,
+            OverrideTag::RotationZ(degrees) => self.angle = *degrees,
+            OverrideTag::Encoding(charset) => {
+                self.encoding = u8::try_from(*charset).unwrap_or(self.encoding);
+            }
+            OverrideTag::PrimaryColor(rgba) => set_rgb(&mut self.primary_color, *rgba),
+            OverrideTag::SecondaryColor(rgba) => set_rgb(&mut self.secondary_color, *rgba),
+            OverrideTag::OutlineColor(rgba) => set_rgb(&mut self.outline_color, *rgba),
+            OverrideTag::BackColor(rgba) => set_rgb(&mut self.back_color, *rgba),
+            OverrideTag::Alpha(alpha) => {
+                for color in [
+                    &mut self.primary_color,
+                    &mut self.secondary_color,
+                    &mut self.outline_color,
+                    &mut self.back_color,
+                ] {
+                    color[3] = *alpha;
+                }
+            }
+            OverrideTag::PrimaryAlpha(alpha) => self.primary_color[3] = *alpha,
+            OverrideTag::SecondaryAlpha(alpha) => self.secondary_color[3] = *alpha,
+            OverrideTag::OutlineAlpha(alpha) => self.outline_color[3] = *alpha,
+            OverrideTag::BackAlpha(alpha) => self.back_color[3] = *alpha,
+            OverrideTag::Alignment(alignment) if (1..=9).contains(alignment) => {
+                self.alignment = *alignment;
+            }
+            OverrideTag::LegacyAlignment(legacy) => {
+                if let Some(alignment) = numpad_alignment(*legacy) {
+                    self.alignment = alignment;
+                }
+            }
+            _ => return,
+        }
+        self.complexity_score = Self::calculate_complexity(self);
+    }
+
+    /// Set or clear a formatting flag
+    fn set_flag(&mut self, flag: TextFormatting, on: bool) {
+        self.formatting.set(flag, on);
+    }
+}
+
+/// Convert a legacy SSA `\a` alignment (1-3 bottom, +4 top, +8 middle) to
+/// numpad layout
+const fn numpad_alignment(legacy: u8) -> Option<u8> {
+    match legacy {
+        1..=3 => Some(legacy),
+        5..=7 => Some(legacy + 2),
+        9..=11 => Some(legacy - 5),
+        _ => None,
+    }
+}
+
+/// `-1`/`1` and weights of 700 and above are bold
+const fn weight_is_bold(weight: i64) -> bool {
+    weight == -1 || weight == 1 || weight >= 700
+}
+
+/// Replace the color channels of `color`, keeping its alpha
+fn set_rgb(color: &mut [u8; 4], rgba: [u8; 4]) {
+    color[..3].copy_from_slice(&rgba[..3]);
+}
+
+/// Finite float field, `None` when unset or malformed
+fn number(field: &str) -> Option<f32> {
+    field
+        .trim()
+        .parse::<f32>()
+        .ok()
+        .filter(|value| value.is_finite())
+}
+
+/// Integer field, `None` when unset or malformed
+fn integer(field: &str) -> Option<i64> {
+    field.trim().parse().ok()
+}
+
+/// Margins may be fractional after `LayoutRes` scaling; round to pixels
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+fn margin(field: &str) -> Option<u16> {
+    number(field)
+        .filter(|margin| *margin >= 0.0)
+        .map(|margin| margin.round().min(f32::from(u16::MAX)) as u16)
+}
@@ -0,0 +1,87 @@
+//! Unit tests for lenient cascading, override layering and `diff`.
+
+use super::test_support::create_test_style;
+use super::*;
+use crate::override_parser::{OverrideBlock, OverrideTag};
+
+#[cfg(not(feature = "std"))]
+use alloc::vec;
+
+#[test]
+fn cascade_keeps_parent_value_for_unset_or_malformed_fields() {
+    let mut style = create_test_style();
+    style.fontname = "";
+    style.fontsize = "big";
+    style.bold = "-1";
+    style.outline = "3.5";
+    style.margin_v = "12.6";
+    let parent = ResolvedStyle::default();
+
+    let resolved = ResolvedStyle::cascade(&style, &parent);
+    assert_eq!(resolved.name, "Test");
+    assert_eq!(resolved.font_name(), "Arial");
+    assert!((resolved.font_size() - 18.0).abs() < f32::EPSILON);
+    assert!(resolved.is_bold());
+    assert!((resolved.outline() - 3.5).abs() < f32::EPSILON);
+    assert_eq!((resolved.margin_t(), resolved.margin_b()), (13, 13));
+    assert_eq!(resolved.secondary_color(), [255, 0, 0, 0]);
+}
+
+#[test]
+fn bold_accepts_font_weights() {
+    let mut style = create_test_style();
+    style.bold = "700";
+    assert!(ResolvedStyle::cascade(&style, &ResolvedStyle::default()).is_bold());
+    style.bold = "400";
+    assert!(!ResolvedStyle::cascade(&style, &ResolvedStyle::default()).is_bold());
+}
+
+#[test]
+fn overrides_layer_on_top_of_style() {
+    let style = create_test_style();
+    let mut resolved = ResolvedStyle::cascade(&style, &ResolvedStyle::default());
+    let block = OverrideBlock::parse(r"{\i1\fnVerdana\fs40\c&H0000FF&\1a&H80&\bord0\frz15\a6}");
+    for tag in &block.tags {
+        resolved.apply_override(tag);
+    }
+
+    assert!(resolved.is_italic());
+    assert_eq!(resolved.font_name(), "Verdana");
+    assert!((resolved.font_size() - 40.0).abs() < f32::EPSILON);
+    assert_eq!(resolved.primary_color(), [255, 0, 0, 0x80]);
+    assert!(resolved.outline().abs() < f32::EPSILON);
+    assert!((resolved.angle() - 15.0).abs() < f32::EPSILON);
+    // Legacy `\a6` is top center
+    assert_eq!(resolved.alignment(), 8);
+}
+
+#[test]
+fn animated_and_positional_tags_are_ignored() {
+    let style = create_test_style();
+    let base = ResolvedStyle::cascade(&style, &ResolvedStyle::default());
+    let mut resolved = base.clone();
+    for tag in &OverrideBlock::parse(r"{\pos(10,10)\t(0,500,\fs80)\k20\xbord4}").tags {
+        resolved.apply_override(tag);
+    }
+    assert_eq!(resolved, base);
+}
+
+#[test]
+fn diff_lists_changed_properties() {
+    let style = create_test_style();
+    let base = ResolvedStyle::cascade(&style, &ResolvedStyle::default());
+    assert!(base.diff(&base).is_empty());
+
+    let mut changed = base.clone();
+    changed.apply_override(&OverrideTag::Bold(true));
+    changed.apply_override(&OverrideTag::FontSize(32.0));
+    changed.apply_override(&OverrideTag::BackAlpha(0xFF));
+    assert_eq!(
+        base.diff(&changed),
+        vec![
+            StyleProperty::FontSize,
+            StyleProperty::BackColor,
+            StyleProperty::Bold
+        ]
+    );
+}
@@ -0,0 +1,117 @@
+//! Property-level comparison of two `ResolvedStyle` values.
+
+use super::{ResolvedStyle, TextFormatting};
+use alloc::vec::Vec;
+
+/// A renderable property of a [`ResolvedStyle`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum StyleProperty {
+    /// Font family name
+    FontName,
+    /// Font size
+    FontSize,
+    /// Primary fill color and alpha
+    PrimaryColor,
+    /// Secondary (karaoke) color and alpha
+    SecondaryColor,
+    /// Outline color and alpha
+    OutlineColor,
+    /// Shadow/background color and alpha
+    BackColor,
+    /// Bold flag
+    Bold,
+    /// Italic flag
+    Italic,
+    /// Underline flag
+    Underline,
+    /// Strike-out flag
+    StrikeOut,
+    /// Horizontal scale percentage
+    ScaleX,
+    /// Vertical scale percentage
+    ScaleY,
+    /// Character spacing
+    Spacing,
+    /// Z rotation angle
+    Angle,
+    /// Border style (outline or opaque box)
+    BorderStyle,
+    /// Outline width
+    Outline,
+    /// Shadow depth
+    Shadow,
+    /// Numpad alignment
+    Alignment,
+    /// Left margin
+    MarginL,
+    /// Right margin
+    MarginR,
+    /// Top margin
+    MarginT,
+    /// Bottom margin
+    MarginB,
+    /// Font encoding
+    Encoding,
+}
+
+impl ResolvedStyle<'_> {
+    /// List the properties whose values differ between `self` and `other`
+    ///
+    /// Properties are reported in declaration order of [`StyleProperty`]; the
+    /// style name and complexity score are not compared.
+    #[must_use]
+    pub fn diff(&self, other: &ResolvedStyle<'_>) -> Vec<StyleProperty> {
+        let flag = |flag| self.formatting.contains(flag) != other.formatting.contains(flag);
+        [
+            (StyleProperty::FontName, self.font_name != other.font_name),
+            (
+                StyleProperty::FontSize,
+                differs(self.font_size, other.font_size),
+            ),
+            (
+                StyleProperty::PrimaryColor,
+                self.primary_color != other.primary_color,
+            ),
+            (
+                StyleProperty::SecondaryColor,
+                self.secondary_color != other.secondary_color,
+            ),
+            (
+                StyleProperty::OutlineColor,
+                self.outline_color != other.outline_color,
+            ),
+            (
+                StyleProperty::BackColor,
+                self.back_color != other.back_color,
+            ),
+            (StyleProperty::Bold, flag(TextFormatting::BOLD)),
+            (StyleProperty::Italic, flag(TextFormatting::ITALIC)),
+            (StyleProperty::Underline, flag(TextFormatting::UNDERLINE)),
+            (StyleProperty::StrikeOut, flag(TextFormatting::STRIKE_OUT)),
+            (StyleProperty::ScaleX, differs(self.scale_x, other.scale_x)),
+            (StyleProperty::ScaleY, differs(self.scale_y, other.scale_y)),
+            (StyleProperty::Spacing, differs(self.spacing, other.spacing)),
+            (StyleProperty::Angle, differs(self.angle, other.angle)),
+            (
+                StyleProperty::BorderStyle,
+                self.border_style != other.border_style,
+            ),
+            (StyleProperty::Outline, differs(self.outline, other.outline)),
+            (StyleProperty::Shadow, differs(self.shadow, other.shadow)),
+            (StyleProperty::Alignment, self.alignment != other.alignment),
+            (StyleProperty::MarginL, self.margin_l != other.margin_l),
+            (StyleProperty::MarginR, self.margin_r != other.margin_r),
+            (StyleProperty::MarginT, self.margin_t != other.margin_t),
+            (StyleProperty::MarginB, self.margin_b != other.margin_b),
+            (StyleProperty::Encoding, self.encoding != other.encoding),
+        ]
+        .into_iter()
+        .filter_map(|(property, changed)| changed.then_some(property))
+        .collect()
+    }
+}
+
+/// Whether two float properties differ beyond rounding noise
+fn differs(a: f32, b: f32) -> bool {
+    (a - b).abs() > f32::EPSILON
+}
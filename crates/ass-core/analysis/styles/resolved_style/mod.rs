@@ -21,11 +21,15 @@
 use alloc::string::String;
 
 mod accessors;
+mod cascade;
+mod diff;
 mod from_style;
 mod inheritance;
 mod parsing;
 mod scaling;
 
+#[cfg(test)]
+mod cascade_tests;
 #[cfg(test)]
 mod formatting_tests;
 #[cfg(test)]
@@ -37,6 +41,8 @@ mod scaling_tests;
 #[cfg(test)]
 mod test_support;
 
+pub use diff::StyleProperty;
+
 bitflags::bitflags! {
     /// Text formatting options for resolved styles
     #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -0,0 +1,164 @@
+//! Style cascade from script styles and inline override tags
+//!
+//! [`StyleResolver`] resolves every style of a script once, following
+//! `parent` inheritance, and then layers the override tags of an event on
+//! top to produce the [`ResolvedStyle`] a renderer draws with.
+//!
+//! # Example
+//!
+//! ```rust
+//! use ass_core::analysis::styles::StyleResolver;
+//! use ass_core::override_parser::OverrideBlock;
+//! use ass_core::parser::Script;
+//!
+//! let script = Script::parse(
+//!     "[V4+ Styles]\nFormat: Name, Fontname, Fontsize, Bold\nStyle: Default,Arial,20,0",
+//! )?;
+//! let resolver = StyleResolver::from_script(&script);
+//! let resolved = resolver.resolve("Default", &OverrideBlock::parse(r"{\b1\fs40}"));
+//! assert!(resolved.is_bold());
+//! assert_eq!(resolved.font_size(), 40.0);
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+
+use super::ResolvedStyle;
+use crate::override_parser::{OverrideBlock, OverrideTag};
+use crate::parser::{Script, Section, Style};
+use alloc::{collections::BTreeMap, vec::Vec};
+
+/// Resolves named styles and override blocks into [`ResolvedStyle`] values
+///
+/// Styles are resolved leniently with [`ResolvedStyle::cascade`]: a style
+/// with a `parent` inherits every field it leaves unset, and a style without
+/// one inherits from the built-in [`ResolvedStyle::default`]. Unknown style
+/// names fall back to the script's `Default` style, then to its first style,
+/// then to the built-in default, matching the renderer's lookup.
+#[derive(Debug, Clone)]
+pub struct StyleResolver<'a> {
+    /// Resolved styles by name
+    styles: BTreeMap<&'a str, ResolvedStyle<'a>>,
+    /// Style used for unknown names
+    fallback: ResolvedStyle<'a>,
+}
+
+impl<'a> StyleResolver<'a> {
+    /// Resolve every style of `script`
+    #[must_use]
+    pub fn from_script(script: &Script<'a>) -> Self {
+        let styles: Vec<&Style<'a>> = script
+            .sections()
+            .iter()
+            .filter_map(|section| match section {
+                Section::Styles(styles) => Some(styles),
+                _ => None,
+            })
+            .flatten()
+            .collect();
+        Self::new(&styles)
+    }
+
+    /// Resolve `styles`, which may list children before their parents
+    ///
+    /// Styles whose parent is missing or part of an inheritance cycle
+    /// inherit from the built-in default instead.
+    #[must_use]
+    pub fn new(styles: &[&Style<'a>]) -> Self {
+        let base = ResolvedStyle::default();
+        let mut resolved: BTreeMap<&'a str, ResolvedStyle<'a>> = BTreeMap::new();
+        let mut pending: Vec<&Style<'a>> = styles.to_vec();
+
+        while !pending.is_empty() {
+            let before = pending.len();
+            pending.retain(|style| {
+                let parent = match style.parent {
+                    None => &base,
+                    Some(name) if !styles.iter().any(|s| s.name == name) => &base,
+                    Some(name) => match resolved.get(name) {
+                        Some(parent) => parent,
+                        None => return true,
+                    },
+                };
+                let style_resolved = ResolvedStyle::cascade(style, parent);
+                resolved.insert(style.name, style_resolved);
+                false
+            });
+            if pending.len() == before {
+                // Only cycles remain
+                for style in core::mem::take(&mut pending) {
+                    resolved.insert(style.name, ResolvedStyle::cascade(style, &base));
+                }
+            }
+        }
+
+        let fallback = resolved
+            .get("Default")
+            .or_else(|| styles.first().and_then(|style| resolved.get(style.name)))
+            .cloned()
+            .unwrap_or(base);
+        Self {
+            styles: resolved,
+            fallback,
+        }
+    }
+
+    /// Resolved style named `name`, ignoring a leading `*` as libass does
+    #[must_use]
+    pub fn style(&self, name: &str) -> Option<&ResolvedStyle<'a>> {
+        let name = name.trim();
+        self.styles
+            .get(name)
+            .or_else(|| self.styles.get(name.strip_prefix('*')?))
+    }
+
+    /// Resolved style named `name`, or the fallback style for unknown names
+    #[must_use]
+    pub fn base_style(&self, name: &str) -> &ResolvedStyle<'a> {
+        self.style(name).unwrap_or(&self.fallback)
+    }
+
+    /// Resolve `style_name` and layer `overrides` on top
+    ///
+    /// Tags apply in order with [`ResolvedStyle::apply_override`]. `\r`
+    /// resets to the named style, or to `style_name` when the name is empty
+    /// or unknown, and as in libass only the first `\an`/`\a` of the block
+    /// takes effect.
+    #[must_use]
+    pub fn resolve(&self, style_name: &str, overrides: &OverrideBlock) -> ResolvedStyle<'a> {
+        let line_style = self.base_style(style_name);
+        let mut resolved = line_style.clone();
+        let mut alignment_set = false;
+
+        for tag in &overrides.tags {
+            match tag {
+                OverrideTag::Reset(name) => {
+                    resolved = self.style(name).unwrap_or(line_style).clone();
+                }
+                OverrideTag::Alignment(alignment) | OverrideTag::LegacyAlignment(alignment) => {
+                    let valid = match tag {
+                        OverrideTag::Alignment(_) => (1..=9).contains(alignment),
+                        _ => matches!(alignment, 1..=3 | 5..=7 | 9..=11),
+                    };
+                    if valid && !alignment_set {
+                        resolved.apply_override(tag);
+                        alignment_set = true;
+                    }
+                }
+                _ => resolved.apply_override(tag),
+            }
+        }
+
+        resolved
+    }
+
+    /// Number of resolved styles
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.styles.len()
+    }
+
+    /// Whether the script defined no styles
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.styles.is_empty()
+    }
+}
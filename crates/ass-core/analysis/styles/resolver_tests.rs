@@ -0,0 +1,101 @@
+//! Tests for the style cascade resolver.
+
+use super::*;
+use crate::override_parser::OverrideBlock;
+use crate::parser::{Script, Style};
+
+const SCRIPT: &str = r"[V4+ Styles]
+Format: Name, Fontname, Fontsize, PrimaryColour, SecondaryColour, OutlineColour, BackColour, Bold, Italic, Underline, StrikeOut, ScaleX, ScaleY, Spacing, Angle, BorderStyle, Outline, Shadow, Alignment, MarginL, MarginR, MarginV, Encoding
+Style: Default,Arial,20,&H00FFFFFF,&H000000FF,&H00000000,&H00000000,0,0,0,0,100,100,0,0,1,2,2,2,10,10,10,1
+Style: Sign,Verdana,30,&H0000FFFF,&H000000FF,&H00000000,&H00000000,-1,0,0,0,100,100,0,0,3,1,0,8,10,10,10,1
+";
+
+#[test]
+fn resolves_style_and_override_block() {
+    let script = Script::parse(SCRIPT).unwrap();
+    let resolver = StyleResolver::from_script(&script);
+    assert_eq!(resolver.len(), 2);
+
+    let sign = resolver.resolve("Sign", &OverrideBlock::default());
+    assert_eq!(sign.font_name(), "Verdana");
+    assert!(sign.is_bold());
+    assert_eq!(sign.border_style(), 3);
+    assert_eq!(sign.primary_color(), [255, 255, 0, 0]);
+
+    let overridden = resolver.resolve(
+        "Sign",
+        &OverrideBlock::parse(r"{\b0\fs48\an1\an9\3c&HFF0000&}"),
+    );
+    assert!(!overridden.is_bold());
+    assert!((overridden.font_size() - 48.0).abs() < f32::EPSILON);
+    // Only the first alignment tag counts
+    assert_eq!(overridden.alignment(), 1);
+    assert_eq!(overridden.outline_color(), [0, 0, 255, 0]);
+    assert_eq!(
+        sign.diff(&overridden),
+        [
+            StyleProperty::FontSize,
+            StyleProperty::OutlineColor,
+            StyleProperty::Bold,
+            StyleProperty::Alignment
+        ]
+    );
+}
+
+#[test]
+fn reset_returns_to_named_or_line_style() {
+    let script = Script::parse(SCRIPT).unwrap();
+    let resolver = StyleResolver::from_script(&script);
+
+    let reset_line = resolver.resolve("Sign", &OverrideBlock::parse(r"{\fs60\r}"));
+    assert_eq!(&reset_line, resolver.base_style("Sign"));
+
+    let reset_named = resolver.resolve("Sign", &OverrideBlock::parse(r"{\rDefault\i1}"));
+    assert_eq!(reset_named.font_name(), "Arial");
+    assert!(reset_named.is_italic());
+    assert!(!reset_named.is_bold());
+}
+
+#[test]
+fn unknown_styles_fall_back_to_default() {
+    let script = Script::parse(SCRIPT).unwrap();
+    let resolver = StyleResolver::from_script(&script);
+    assert_eq!(resolver.base_style("Missing").name, "Default");
+    assert_eq!(resolver.base_style("*Sign").name, "Sign");
+
+    let empty = StyleResolver::new(&[]);
+    assert!(empty.is_empty());
+    assert_eq!(empty.base_style("Default"), &ResolvedStyle::default());
+}
+
+#[test]
+fn children_inherit_unset_fields_from_parents() {
+    let parent = Style {
+        name: "Base",
+        fontname: "Georgia",
+        fontsize: "26",
+        italic: "-1",
+        ..Style::default()
+    };
+    let child = Style {
+        name: "Child",
+        parent: Some("Base"),
+        fontname: "",
+        fontsize: "32",
+        italic: "",
+        ..Style::default()
+    };
+    let orphan = Style {
+        name: "Orphan",
+        parent: Some("Nowhere"),
+        ..Style::default()
+    };
+    // Children may come before their parents
+    let resolver = StyleResolver::new(&[&child, &parent, &orphan]);
+
+    let inherited = resolver.base_style("Child");
+    assert_eq!(inherited.font_name(), "Georgia");
+    assert!((inherited.font_size() - 32.0).abs() < f32::EPSILON);
+    assert!(inherited.is_italic());
+    assert_eq!(resolver.base_style("Orphan").font_name(), "Arial");
+}
@@ -0,0 +1,144 @@
+//! Timing statistics: on-screen time, durations, and gaps between dialogue
+//!
+//! Summarizes a script's dialogue events in a single pass over them sorted
+//! by start time, the figures subtitle distributors ask for when checking
+//! a delivery: how long dialogue is shown, how long events last, and how
+//! long the silences between them are.
+
+use alloc::vec::Vec;
+
+use crate::parser::Script;
+
+/// Centiseconds per minute
+const CS_PER_MINUTE: f64 = 6000.0;
+
+/// Aggregate timing of a script's dialogue events
+///
+/// Only `Dialogue` events with parsable times are measured. Overlapping
+/// events count once toward on-screen time, and a gap is a stretch between
+/// two events during which no dialogue is shown. The script duration runs
+/// from `0:00:00.00` to the last event end.
+///
+/// # Example
+///
+/// ```rust
+/// use ass_core::{analysis::TimingStats, Script};
+///
+/// let script = Script::parse("[Events]
+/// Format: Layer, Start, End, Style, Name, MarginL, MarginR, MarginV, Effect, Text
+/// Dialogue: 0,0:00:00.00,0:00:02.00,Default,,0,0,0,,One
+/// Dialogue: 0,0:00:03.00,0:00:04.00,Default,,0,0,0,,Two")?;
+///
+/// let stats = TimingStats::compute(&script);
+/// assert_eq!(stats.total_dialogue_centiseconds, 300);
+/// assert_eq!(stats.max_gap_cs, 100);
+/// assert_eq!(stats.dialogue_density_percent, 75.0);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct TimingStats {
+    /// Number of dialogue events measured
+    pub event_count: usize,
+    /// Time during which at least one dialogue event is shown
+    pub total_dialogue_centiseconds: u32,
+    /// Mean event duration
+    pub average_duration_cs: f32,
+    /// Longest event duration
+    pub max_duration_cs: u32,
+    /// Shortest non-zero event duration, `0` if every event is zero-length
+    pub min_nonzero_duration_cs: u32,
+    /// Longest silence between two events
+    pub max_gap_cs: u32,
+    /// Mean silence over the gaps between events, `0.0` without gaps
+    pub average_gap_cs: f32,
+    /// Time from the script start to the last event end
+    pub script_duration_cs: u32,
+    /// Share of the script duration covered by dialogue, in percent
+    pub dialogue_density_percent: f32,
+}
+
+impl TimingStats {
+    /// Compute the statistics for `script`
+    #[must_use]
+    pub fn compute(script: &Script) -> Self {
+        let mut spans: Vec<(u32, u32)> = script
+            .dialogue_events()
+            .filter_map(|event| Some((event.start_time_cs().ok()?, event.end_time_cs().ok()?)))
+            .map(|(start, end)| (start, end.max(start)))
+            .collect();
+        spans.sort_unstable();
+        let Some(&(mut run_start, mut run_end)) = spans.first() else {
+            return Self::default();
+        };
+
+        let mut stats = Self {
+            event_count: spans.len(),
+            ..Self::default()
+        };
+        let mut duration_sum = 0u64;
+        let mut covered = 0u64;
+        let mut gap_sum = 0u64;
+        let mut gap_count = 0usize;
+        for &(start, end) in &spans {
+            let duration = end - start;
+            duration_sum += u64::from(duration);
+            stats.max_duration_cs = stats.max_duration_cs.max(duration);
+            if duration > 0
+                && (stats.min_nonzero_duration_cs == 0 || duration < stats.min_nonzero_duration_cs)
+            {
+                stats.min_nonzero_duration_cs = duration;
+            }
+
+            // A start past everything shown so far closes the current run
+            if start > run_end {
+                covered += u64::from(run_end - run_start);
+                let gap = start - run_end;
+                gap_sum += u64::from(gap);
+                gap_count += 1;
+                stats.max_gap_cs = stats.max_gap_cs.max(gap);
+                run_start = start;
+            }
+            run_end = run_end.max(end);
+        }
+        covered += u64::from(run_end - run_start);
+
+        stats.total_dialogue_centiseconds = u32::try_from(covered).unwrap_or(u32::MAX);
+        stats.average_duration_cs = mean(duration_sum, spans.len());
+        stats.average_gap_cs = mean(gap_sum, gap_count);
+        stats.script_duration_cs = run_end;
+        stats.dialogue_density_percent = percent(covered, run_end);
+        stats
+    }
+
+    /// Dialogue events per minute of script duration, `0.0` for an empty
+    /// script
+    #[must_use]
+    pub fn events_per_minute(&self) -> f64 {
+        if self.script_duration_cs == 0 {
+            return 0.0;
+        }
+        #[allow(clippy::cast_precision_loss)]
+        let count = self.event_count as f64;
+        count * CS_PER_MINUTE / f64::from(self.script_duration_cs)
+    }
+}
+
+/// `sum / count` as `f32`, `0.0` when `count` is zero
+#[allow(clippy::cast_precision_loss)]
+fn mean(sum: u64, count: usize) -> f32 {
+    if count == 0 {
+        0.0
+    } else {
+        sum as f32 / count as f32
+    }
+}
+
+/// `part` as a percentage of `whole`, `0.0` when `whole` is zero
+#[allow(clippy::cast_precision_loss)]
+fn percent(part: u64, whole: u32) -> f32 {
+    if whole == 0 {
+        0.0
+    } else {
+        part as f32 * 100.0 / whole as f32
+    }
+}
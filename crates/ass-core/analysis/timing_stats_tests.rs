@@ -0,0 +1,75 @@
+//! Tests for dialogue timing statistics.
+
+use super::*;
+use crate::parser::Script;
+
+const SCRIPT: &str = r"[Script Info]
+Title: Timing
+
+[Events]
+Format: Layer, Start, End, Style, Name, MarginL, MarginR, MarginV, Effect, Text
+Dialogue: 0,0:00:02.00,0:00:04.00,Default,,0,0,0,,Overlaps the first
+Dialogue: 0,0:00:01.00,0:00:03.00,Default,,0,0,0,,First
+Dialogue: 0,0:00:02.50,0:00:02.50,Default,,0,0,0,,Zero length
+Comment: 0,0:00:05.00,0:00:20.00,Default,,0,0,0,,Not dialogue
+Dialogue: 0,0:00:06.00,0:00:07.50,Default,,0,0,0,,After a gap
+Dialogue: 0,0:00:09.00,0:00:10.00,Default,,0,0,0,,Last
+";
+
+/// Assert two floats are equal within rounding
+fn assert_close(actual: f64, expected: f64) {
+    assert!(
+        (actual - expected).abs() < 1e-4,
+        "expected {expected}, got {actual}"
+    );
+}
+
+#[test]
+fn timing_stats_known_script() {
+    let script = Script::parse(SCRIPT).unwrap();
+    let stats = TimingStats::compute(&script);
+
+    assert_eq!(stats.event_count, 5);
+    // 1.00-4.00, 6.00-7.50 and 9.00-10.00 on screen
+    assert_eq!(stats.total_dialogue_centiseconds, 550);
+    assert_close(f64::from(stats.average_duration_cs), 130.0);
+    assert_eq!(stats.max_duration_cs, 200);
+    assert_eq!(stats.min_nonzero_duration_cs, 100);
+    assert_eq!(stats.max_gap_cs, 200);
+    assert_close(f64::from(stats.average_gap_cs), 175.0);
+    assert_eq!(stats.script_duration_cs, 1000);
+    assert_close(f64::from(stats.dialogue_density_percent), 55.0);
+    assert_close(stats.events_per_minute(), 30.0);
+}
+
+#[test]
+fn timing_stats_contiguous_events_have_no_gaps() {
+    let script = Script::parse(
+        "[Events]
+Format: Layer, Start, End, Style, Name, MarginL, MarginR, MarginV, Effect, Text
+Dialogue: 0,0:00:00.00,0:00:30.00,Default,,0,0,0,,One
+Dialogue: 0,0:00:30.00,0:01:00.00,Default,,0,0,0,,Two",
+    )
+    .unwrap();
+    let stats = TimingStats::compute(&script);
+
+    assert_eq!(stats.total_dialogue_centiseconds, 6000);
+    assert_eq!(stats.max_gap_cs, 0);
+    assert_close(f64::from(stats.average_gap_cs), 0.0);
+    assert_close(f64::from(stats.dialogue_density_percent), 100.0);
+    assert_close(stats.events_per_minute(), 2.0);
+}
+
+#[test]
+fn timing_stats_without_dialogue_is_empty() {
+    let script = Script::parse(
+        "[Events]
+Format: Layer, Start, End, Style, Name, MarginL, MarginR, MarginV, Effect, Text
+Comment: 0,0:00:00.00,0:00:05.00,Default,,0,0,0,,Note",
+    )
+    .unwrap();
+    let stats = TimingStats::compute(&script);
+
+    assert_eq!(stats, TimingStats::default());
+    assert_close(stats.events_per_minute(), 0.0);
+}
@@ -30,10 +30,14 @@ mod real_world;
 #[path = "incremental_benchmarks/large_scale.rs"]
 mod large_scale;
 
+#[path = "incremental_benchmarks/patching.rs"]
+mod patching;
+
 criterion_group!(
     incremental_benches,
     parsing::bench_incremental_parsing,
     parsing::bench_incremental_vs_full,
+    patching::bench_apply_patch,
     editing::bench_editor_simulation,
     editing::bench_memory_usage,
     real_world::bench_real_world_files,
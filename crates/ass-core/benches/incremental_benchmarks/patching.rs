@@ -0,0 +1,43 @@
+//! Line patch benchmark group.
+//!
+//! Measures [`OwnedScript::apply_patch`] on a single dialogue line of a large
+//! script, which is expected to stay under 500µs for 5000 events.
+
+use ass_core::{
+    parser::{LineRange, OwnedScript},
+    utils::ScriptGenerator,
+};
+use criterion::{black_box, BenchmarkId, Criterion};
+
+/// Benchmark single-line patches against script size
+pub fn bench_apply_patch(c: &mut Criterion) {
+    let mut group = c.benchmark_group("apply_patch");
+
+    for event_count in [500, 5000] {
+        let text = ScriptGenerator::moderate(event_count).generate();
+        let mut script = OwnedScript::parse(text.as_str()).expect("generated script parses");
+
+        // Patch the middle dialogue line, alternating between two texts so
+        // the script keeps its size across iterations
+        let (line, original) = text
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| line.starts_with("Dialogue:"))
+            .nth(event_count / 2)
+            .expect("generated script has events");
+        let edited = format!("{original} (edited)");
+        let region = LineRange::line(line);
+        script.apply_patch(region, original).expect("patch applies");
+
+        group.bench_function(BenchmarkId::new("single_line", event_count), |b| {
+            let mut toggle = false;
+            b.iter(|| {
+                toggle = !toggle;
+                let content = if toggle { edited.as_str() } else { original };
+                black_box(script.apply_patch(black_box(region), black_box(content)))
+            });
+        });
+    }
+
+    group.finish();
+}
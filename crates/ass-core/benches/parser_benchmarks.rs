@@ -19,9 +19,16 @@ mod analysis;
 #[path = "parser_benchmarks/memory.rs"]
 mod memory;
 
+#[path = "parser_benchmarks/line_index.rs"]
+mod line_index;
+
 #[path = "parser_benchmarks/styles.rs"]
 mod styles;
 
+#[cfg(feature = "simd")]
+#[path = "parser_benchmarks/tokenizer.rs"]
+mod tokenizer;
+
 criterion_group!(
     benches,
     parsing::bench_parsing,
@@ -32,6 +39,19 @@ criterion_group!(
     memory::bench_memory_usage,
     memory::bench_uu_decoding,
     styles::bench_style_resolution,
-    styles::bench_overlap_detection
+    styles::bench_overlap_detection,
+    line_index::bench_line_lookup,
+    line_index::bench_issue_heavy_parsing
+);
+
+#[cfg(feature = "simd")]
+criterion_group!(
+    tokenizer_benches,
+    tokenizer::bench_delimiter_scanning,
+    tokenizer::bench_color_parsing
 );
+
+#[cfg(feature = "simd")]
+criterion_main!(benches, tokenizer_benches);
+#[cfg(not(feature = "simd"))]
 criterion_main!(benches);
@@ -0,0 +1,67 @@
+//! Line lookup benchmark functions for `parser_benchmarks`.
+//!
+//! Compares rescanning [`Spans`] lookups against a precomputed [`LineIndex`]
+//! on a 10,000-line script, and measures parsing a script of that size whose
+//! every event raises an issue.
+
+use ass_core::{
+    parser::Script,
+    utils::{LineIndex, ScriptGenerator, Spans},
+};
+use criterion::{black_box, BenchmarkId, Criterion, Throughput};
+
+/// Number of event lines in the benchmarked scripts
+const EVENT_LINES: usize = 10_000;
+
+/// Benchmark offset to line/column lookups
+pub fn bench_line_lookup(c: &mut Criterion) {
+    let mut group = c.benchmark_group("line_lookup");
+    let script = ScriptGenerator::simple(EVENT_LINES).generate();
+    // One offset at the start of every event line
+    let offsets: Vec<usize> = script
+        .match_indices("\nDialogue:")
+        .map(|(offset, _)| offset + 1)
+        .collect();
+    group.throughput(Throughput::Elements(offsets.len() as u64));
+
+    group.bench_function(BenchmarkId::new("spans", EVENT_LINES), |b| {
+        let spans = Spans::new(&script);
+        b.iter(|| {
+            for &offset in &offsets {
+                let span = &script[offset..];
+                black_box((spans.span_line(span), spans.span_column(span)));
+            }
+        });
+    });
+
+    group.bench_function(BenchmarkId::new("line_index", EVENT_LINES), |b| {
+        let index = LineIndex::new(&script);
+        b.iter(|| {
+            for &offset in &offsets {
+                black_box((index.line_of_offset(offset), index.column_of_offset(offset)));
+            }
+        });
+    });
+
+    group.bench_function(BenchmarkId::new("build_index", EVENT_LINES), |b| {
+        b.iter(|| black_box(LineIndex::new(black_box(&script))));
+    });
+
+    group.finish();
+}
+
+/// Benchmark parsing a script where every event line is reported as an issue
+pub fn bench_issue_heavy_parsing(c: &mut Criterion) {
+    let mut group = c.benchmark_group("issue_heavy_parsing");
+    let script = ScriptGenerator::simple(EVENT_LINES)
+        .generate()
+        // Dropping the layer leaves each event a field short
+        .replace("Dialogue: 0,", "Dialogue: ");
+
+    group.throughput(Throughput::Bytes(script.len() as u64));
+    group.bench_function(BenchmarkId::new("missing_fields", EVENT_LINES), |b| {
+        b.iter(|| black_box(Script::parse(black_box(&script))));
+    });
+
+    group.finish();
+}
@@ -0,0 +1,144 @@
+//! Delimiter scanning, tokenization and hex color benchmark functions for
+//! `parser_benchmarks`.
+//!
+//! Compares the SIMD delimiter scan against a byte-at-a-time loop on a
+//! ~100KB script dominated by long field values, and SIMD hex color
+//! decoding against `u32::from_str_radix` on 100,000 color strings.
+
+use ass_core::tokenizer::AssTokenizer;
+use criterion::{black_box, Criterion, Throughput};
+use std::fmt::Write;
+
+/// Approximate size of the generated script in bytes
+const TARGET_SIZE: usize = 100 * 1024;
+
+/// Generate a script whose events carry long field values
+fn field_value_heavy_script() -> String {
+    let mut script = String::with_capacity(TARGET_SIZE + 256);
+    script.push_str("[Script Info]\nTitle: Field value benchmark\nScriptType: v4.00+\n\n");
+    script.push_str("[Events]\nFormat: Layer, Start, End, Style, Name, MarginL, MarginR, MarginV, Effect, Text\n");
+
+    let mut index = 0;
+    while script.len() < TARGET_SIZE {
+        let seconds = index % 60;
+        writeln!(
+            script,
+            "Dialogue: 0,0:00:{seconds:02}.00,0:00:{seconds:02}.50,Default,Narrator {index},0,0,0,,\
+             This is a deliberately long line of dialogue number {index} that keeps the scanner busy"
+        )
+        .unwrap();
+        index += 1;
+    }
+    script
+}
+
+/// Count delimiters by stepping through the buffer one byte at a time
+fn count_delimiters_scalar(bytes: &[u8]) -> usize {
+    bytes
+        .iter()
+        .filter(|byte| ass_core::tokenizer::simd::DELIMITERS.contains(byte))
+        .count()
+}
+
+/// Count delimiters by repeatedly calling the SIMD scan
+fn count_delimiters_simd(bytes: &[u8]) -> usize {
+    let mut count = 0;
+    let mut offset = 0;
+    while offset < bytes.len() {
+        offset += ass_core::tokenizer::simd::scan_to_delimiter(&bytes[offset..]);
+        if offset < bytes.len() {
+            count += 1;
+            offset += 1;
+        }
+    }
+    count
+}
+
+/// Benchmark delimiter scanning and full tokenization throughput
+pub fn bench_delimiter_scanning(c: &mut Criterion) {
+    let mut group = c.benchmark_group("delimiter_scanning");
+    let script = field_value_heavy_script();
+    let bytes = script.as_bytes();
+    assert_eq!(count_delimiters_scalar(bytes), count_delimiters_simd(bytes));
+
+    group.throughput(Throughput::Bytes(bytes.len() as u64));
+    group.bench_function("scalar", |b| {
+        b.iter(|| count_delimiters_scalar(black_box(bytes)));
+    });
+    group.bench_function("simd", |b| {
+        b.iter(|| count_delimiters_simd(black_box(bytes)));
+    });
+    group.bench_function("tokenize", |b| {
+        b.iter(|| {
+            let mut tokenizer = AssTokenizer::new(black_box(&script));
+            let mut count = 0;
+            while let Ok(Some(_)) = tokenizer.next_token() {
+                count += 1;
+            }
+            count
+        });
+    });
+
+    group.finish();
+}
+
+/// Number of color strings parsed per iteration
+const COLOR_COUNT: usize = 100_000;
+
+/// Generate `&HAABBGGRR&` colors, every fourth in the 6-digit form
+fn color_strings() -> Vec<String> {
+    (0..u32::try_from(COLOR_COUNT).unwrap_or(u32::MAX))
+        .map(|index| {
+            let value = index.wrapping_mul(2_654_435_761);
+            if index % 4 == 0 {
+                format!("&H{:06X}&", value & 0x00FF_FFFF)
+            } else {
+                format!("&H{value:08x}&")
+            }
+        })
+        .collect()
+}
+
+/// Benchmark hex color decoding with the SIMD path against `from_str_radix`
+pub fn bench_color_parsing(c: &mut Criterion) {
+    let mut group = c.benchmark_group("color_parsing");
+    let colors = color_strings();
+    let digits: Vec<&str> = colors
+        .iter()
+        .map(|color| &color[2..color.len() - 1])
+        .collect();
+    for hex in &digits {
+        assert_eq!(
+            ass_core::tokenizer::simd::decode_hex_digits(hex),
+            u32::from_str_radix(hex, 16).ok()
+        );
+    }
+
+    group.throughput(Throughput::Elements(COLOR_COUNT as u64));
+    group.bench_function("scalar_digits", |b| {
+        b.iter(|| {
+            black_box(&digits)
+                .iter()
+                .filter_map(|hex| u32::from_str_radix(hex, 16).ok())
+                .fold(0, u32::wrapping_add)
+        });
+    });
+    group.bench_function("simd_digits", |b| {
+        b.iter(|| {
+            black_box(&digits)
+                .iter()
+                .filter_map(|hex| ass_core::tokenizer::simd::decode_hex_digits(hex))
+                .fold(0, u32::wrapping_add)
+        });
+    });
+    group.bench_function("parse_bgr_color", |b| {
+        b.iter(|| {
+            black_box(&colors)
+                .iter()
+                .filter_map(|color| ass_core::utils::parse_bgr_color(color).ok())
+                .fold(0u32, |sum, rgba| sum.wrapping_add(u32::from_le_bytes(rgba)))
+        });
+    });
+
+    group.finish();
+}
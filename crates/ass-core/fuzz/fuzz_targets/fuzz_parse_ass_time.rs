@@ -0,0 +1,15 @@
+//! Fuzz `parse_ass_time`, checking that accepted times format back cleanly.
+
+#![no_main]
+
+use ass_core::utils::{format_ass_time, parse_ass_time};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    if let Ok(time) = core::str::from_utf8(data) {
+        if let Ok(cs) = parse_ass_time(time) {
+            let formatted = format_ass_time(cs);
+            assert_eq!(parse_ass_time(&formatted).ok(), Some(cs));
+        }
+    }
+});
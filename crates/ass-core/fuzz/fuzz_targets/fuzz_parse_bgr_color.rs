@@ -0,0 +1,12 @@
+//! Fuzz `parse_bgr_color` on arbitrary UTF-8 input.
+
+#![no_main]
+
+use ass_core::utils::parse_bgr_color;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    if let Ok(color) = core::str::from_utf8(data) {
+        let _ = parse_bgr_color(color);
+    }
+});
@@ -0,0 +1,29 @@
+//! Fuzz the full script parser and its serialize/re-parse roundtrip.
+//!
+//! Arbitrary bytes are decoded the way callers decode untrusted files
+//! (lossy UTF-8 recovery), parsed, serialized, and parsed again. The
+//! serialized form must never introduce issues worse than a warning.
+
+#![no_main]
+
+use ass_core::parser::{IssueSeverity, Script};
+use ass_core::utils::recover_utf8;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    let (source, _) = recover_utf8(data);
+    let Ok(script) = Script::parse(&source) else {
+        return;
+    };
+    let _ = script.issues().len();
+
+    let serialized = script.to_ass_string();
+    if let Ok(reparsed) = Script::parse(&serialized) {
+        for issue in reparsed.issues() {
+            assert!(
+                matches!(issue.severity, IssueSeverity::Info | IssueSeverity::Warning),
+                "re-parsing serialized output raised {issue:?}"
+            );
+        }
+    }
+});
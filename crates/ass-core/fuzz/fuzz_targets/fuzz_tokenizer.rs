@@ -0,0 +1,12 @@
+//! Fuzz the tokenizer on arbitrary UTF-8 input.
+
+#![no_main]
+
+use ass_core::AssTokenizer;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    if let Ok(source) = core::str::from_utf8(data) {
+        let _ = AssTokenizer::new(source).tokenize_all();
+    }
+});
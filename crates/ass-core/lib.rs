@@ -53,6 +53,7 @@
 // Always make alloc available, whether in std or no_std mode
 extern crate alloc;
 
+pub mod override_parser;
 pub mod parser;
 pub mod tokenizer;
 
@@ -0,0 +1,123 @@
+//! Structured parsing of ASS override blocks.
+//!
+//! Turns the contents of an override block such as `{\b1\pos(10,20)}` into a
+//! typed [`OverrideBlock`] tree. Every known tag gets its own
+//! [`OverrideTag`] variant with parsed arguments, `\t` transforms are parsed
+//! recursively, and anything unrecognized is kept verbatim as
+//! [`OverrideTag::Unknown`] so that serializing a block loses no tags.
+//! Known tags with missing or invalid arguments are recovered as
+//! [`OverrideTag::Malformed`] and parsing resumes at the next backslash;
+//! [`OverrideBlock::issues`] reports them as warnings.
+//!
+//! Unlike [`analysis::events::tags`](crate::analysis::events::tags), which
+//! borrows tag names and raw arguments for fast analysis, this tree owns its
+//! data and interprets every argument.
+//!
+//! # Example
+//!
+//! ```rust
+//! use ass_core::override_parser::{OverrideBlock, OverrideTag};
+//!
+//! let block = OverrideBlock::parse(r"{\b1\t(0,500,\fs40)}");
+//! assert_eq!(block.tags[0], OverrideTag::Bold(true));
+//! assert!(matches!(block.tags[1], OverrideTag::Transform { .. }));
+//! assert_eq!(block.to_string(), r"{\b1\t(0,500,\fs40)}");
+//! ```
+
+use core::fmt;
+
+use crate::parser::errors::{IssueCategory, ParseIssue};
+
+#[cfg(not(feature = "std"))]
+use alloc::{format, vec::Vec};
+#[cfg(feature = "std")]
+use std::{format, vec::Vec};
+
+mod parse;
+mod tag;
+
+#[cfg(test)]
+mod tests;
+
+pub use tag::{DrawingMode, OverrideTag};
+
+/// Parsed tags of a single override block
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct OverrideBlock {
+    /// Tags in source order
+    pub tags: Vec<OverrideTag>,
+}
+
+impl OverrideBlock {
+    /// Parse an override block, with or without its surrounding braces
+    ///
+    /// Parsing never fails: text between tags (comments) is skipped, unknown
+    /// tags become [`OverrideTag::Unknown`] and known tags with malformed
+    /// arguments become [`OverrideTag::Malformed`].
+    #[must_use]
+    pub fn parse(text: &str) -> Self {
+        let text = text.trim();
+        let text = text.strip_prefix('{').unwrap_or(text);
+        let text = text.strip_suffix('}').unwrap_or(text);
+        Self {
+            tags: parse::parse_tags(text),
+        }
+    }
+
+    /// Whether the block contains no tags
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.tags.is_empty()
+    }
+
+    /// Whether any tag, including those inside `\t`, is malformed
+    #[must_use]
+    pub fn has_malformed(&self) -> bool {
+        self.malformed().next().is_some()
+    }
+
+    /// One warning per malformed tag, naming the tag and the arguments it
+    /// expects, located at `line` of the script
+    #[must_use]
+    pub fn issues(&self, line: usize) -> Vec<ParseIssue> {
+        self.malformed()
+            .map(|(name, args)| {
+                let expected = parse::expected_args(name).unwrap_or("valid arguments");
+                ParseIssue::warning(
+                    IssueCategory::Event,
+                    format!("Malformed override tag \\{name}{args}: expected {expected}"),
+                    line,
+                )
+                .with_suggestion(format!("Write \\{name} followed by {expected}"))
+            })
+            .collect()
+    }
+
+    /// Name and arguments of every malformed tag in source order
+    fn malformed(&self) -> impl Iterator<Item = (&str, &str)> {
+        let mut pending: Vec<&OverrideTag> = self.tags.iter().rev().collect();
+        core::iter::from_fn(move || {
+            while let Some(tag) = pending.pop() {
+                match tag {
+                    OverrideTag::Malformed { name, args } => {
+                        return Some((name.as_str(), args.as_str()))
+                    }
+                    OverrideTag::Transform { tags, .. } => pending.extend(tags.iter().rev()),
+                    _ => {}
+                }
+            }
+            None
+        })
+    }
+}
+
+impl fmt::Display for OverrideBlock {
+    /// Serialize as `{...}` with every tag in canonical form
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("{")?;
+        for tag in &self.tags {
+            write!(f, "{tag}")?;
+        }
+        f.write_str("}")
+    }
+}
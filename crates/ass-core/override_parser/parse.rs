@@ -0,0 +1,313 @@
+//! Tag scanning and argument parsing for override blocks.
+
+use core::str::FromStr;
+
+#[cfg(not(feature = "std"))]
+use alloc::{string::String, vec::Vec};
+#[cfg(feature = "std")]
+use std::{string::String, vec::Vec};
+
+use super::tag::{DrawingMode, OverrideTag};
+use crate::utils::parse_bgr_color;
+
+/// Deepest `\t` nesting parsed; deeper transforms become malformed tags so
+/// hostile input cannot exhaust the stack
+const MAX_TRANSFORM_DEPTH: usize = 16;
+
+/// Parse every tag in `text`, skipping characters outside tags
+pub(super) fn parse_tags(text: &str) -> Vec<OverrideTag> {
+    parse_tags_at(text, 0)
+}
+
+/// Parse every tag in `text` inside `depth` enclosing transforms
+fn parse_tags_at(text: &str, depth: usize) -> Vec<OverrideTag> {
+    let mut tags = Vec::new();
+    let mut rest = text;
+    while let Some(start) = rest.find('\\') {
+        let after = &rest[start + 1..];
+        let (name, tail) = after.split_at(tag_name_len(after));
+        if name.is_empty() {
+            rest = after;
+            continue;
+        }
+        let (args, remaining) = tail.split_at(args_len(tail));
+        tags.push(parse_tag(name, args, depth));
+        rest = remaining;
+    }
+    tags
+}
+
+/// Length of the tag name at the start of `text`
+///
+/// A name is an optional leading digit (`\1c`, `\3a`) followed by ASCII
+/// letters. Scanning stops after `r` and `fn`, the only tags whose
+/// arguments may start with a letter (`\rDefault`, `\fnArial`).
+fn tag_name_len(text: &str) -> usize {
+    let bytes = text.as_bytes();
+    let mut len = usize::from(bytes.first().is_some_and(u8::is_ascii_digit));
+    while len < bytes.len() && bytes[len].is_ascii_alphabetic() {
+        len += 1;
+        if matches!(&text[..len], "r" | "fn") {
+            break;
+        }
+    }
+    len
+}
+
+/// Length of a tag's arguments: up to the next backslash outside parentheses
+fn args_len(text: &str) -> usize {
+    let mut depth = 0usize;
+    for (index, c) in text.char_indices() {
+        match c {
+            '(' => depth += 1,
+            ')' => depth = depth.saturating_sub(1),
+            '\\' if depth == 0 => return index,
+            _ => {}
+        }
+    }
+    text.len()
+}
+
+/// Parse one tag, keeping it as [`OverrideTag::Malformed`] when its
+/// arguments are malformed and as [`OverrideTag::Unknown`] when it is not
+/// recognized
+fn parse_tag(name: &str, args: &str, depth: usize) -> OverrideTag {
+    parse_known(name, args, depth).unwrap_or_else(|| {
+        if expected_args(name).is_some() {
+            OverrideTag::Malformed {
+                name: name.into(),
+                args: args.into(),
+            }
+        } else {
+            OverrideTag::Unknown(name.into(), args.into())
+        }
+    })
+}
+
+/// Description of the arguments a recognized tag expects, or `None` for
+/// unknown tag names
+pub(super) fn expected_args(name: &str) -> Option<&'static str> {
+    Some(match name {
+        "b" => "0, 1 or a font weight",
+        "i" | "u" | "s" => "0 or 1",
+        "bord" | "xbord" | "ybord" | "shad" | "xshad" | "yshad" | "be" | "blur" | "fs" | "fscx"
+        | "fscy" | "fsp" | "frx" | "fry" | "frz" | "fr" | "fax" | "fay" | "pbo" => "a number",
+        "fe" | "k" | "kf" | "K" | "ko" | "p" => "a whole number",
+        "fn" => "a font name",
+        "r" => "an optional style name",
+        "c" | "1c" | "2c" | "3c" | "4c" => "a color such as &HBBGGRR&",
+        "alpha" | "1a" | "2a" | "3a" | "4a" => "an alpha value such as &HAA&",
+        "an" => "an alignment from 1 to 9",
+        "a" => "a legacy alignment from 1 to 11",
+        "q" => "a wrap style from 0 to 3",
+        "pos" | "org" => "(x,y)",
+        "move" => "(x1,y1,x2,y2[,t1,t2])",
+        "fad" => "(in,out)",
+        "fade" => "(a1,a2,a3,t1,t2,t3,t4)",
+        "clip" | "iclip" => "(x1,y1,x2,y2) or ([scale,]drawing)",
+        "t" => "([t1,t2,][accel,]tags)",
+        _ => return None,
+    })
+}
+
+/// Parse a recognized tag with well-formed arguments
+fn parse_known(name: &str, args: &str, depth: usize) -> Option<OverrideTag> {
+    use OverrideTag as Tag;
+
+    Some(match name {
+        "b" => match number(args)? {
+            0 => Tag::Bold(false),
+            1 => Tag::Bold(true),
+            weight => Tag::FontWeight(weight),
+        },
+        "i" => Tag::Italic(flag(args)?),
+        "u" => Tag::Underline(flag(args)?),
+        "s" => Tag::StrikeOut(flag(args)?),
+        "bord" => Tag::Border(number(args)?),
+        "xbord" => Tag::BorderX(number(args)?),
+        "ybord" => Tag::BorderY(number(args)?),
+        "shad" => Tag::Shadow(number(args)?),
+        "xshad" => Tag::ShadowX(number(args)?),
+        "yshad" => Tag::ShadowY(number(args)?),
+        "be" => Tag::BlurEdges(number(args)?),
+        "blur" => Tag::Blur(number(args)?),
+        "fn" => Tag::FontName(args.into()),
+        "fs" => Tag::FontSize(number(args)?),
+        "fscx" => Tag::FontScaleX(number(args)?),
+        "fscy" => Tag::FontScaleY(number(args)?),
+        "fsp" => Tag::Spacing(number(args)?),
+        "frx" => Tag::RotationX(number(args)?),
+        "fry" => Tag::RotationY(number(args)?),
+        "frz" | "fr" => Tag::RotationZ(number(args)?),
+        "fax" => Tag::ShearX(number(args)?),
+        "fay" => Tag::ShearY(number(args)?),
+        "fe" => Tag::Encoding(number(args)?),
+        "c" | "1c" => Tag::PrimaryColor(color(args)?),
+        "2c" => Tag::SecondaryColor(color(args)?),
+        "3c" => Tag::OutlineColor(color(args)?),
+        "4c" => Tag::BackColor(color(args)?),
+        "alpha" => Tag::Alpha(alpha(args)?),
+        "1a" => Tag::PrimaryAlpha(alpha(args)?),
+        "2a" => Tag::SecondaryAlpha(alpha(args)?),
+        "3a" => Tag::OutlineAlpha(alpha(args)?),
+        "4a" => Tag::BackAlpha(alpha(args)?),
+        "an" => Tag::Alignment(number(args).filter(|an| (1..=9).contains(an))?),
+        "a" => Tag::LegacyAlignment(number(args).filter(|a| (1..=11).contains(a))?),
+        "k" => Tag::Karaoke(number(args)?),
+        "kf" | "K" => Tag::KaraokeFill(number(args)?),
+        "ko" => Tag::KaraokeOutline(number(args)?),
+        "q" => Tag::WrapStyle(number(args).filter(|q| *q <= 3)?),
+        "r" => Tag::Reset(args.into()),
+        "pos" => {
+            let [x, y] = numbers(args)?;
+            Tag::Position(x, y)
+        }
+        "org" => {
+            let [x, y] = numbers(args)?;
+            Tag::Origin(x, y)
+        }
+        "move" => parse_move(args)?,
+        "fad" => {
+            let [fade_in, fade_out] = numbers(args)?;
+            Tag::Fade { fade_in, fade_out }
+        }
+        "fade" => {
+            let [a1, a2, a3, t1, t2, t3, t4] = numbers::<u32, 7>(args)?;
+            Tag::ComplexFade {
+                alphas: [
+                    u8::try_from(a1).ok()?,
+                    u8::try_from(a2).ok()?,
+                    u8::try_from(a3).ok()?,
+                ],
+                times: [t1, t2, t3, t4],
+            }
+        }
+        "clip" => parse_clip(args, false)?,
+        "iclip" => parse_clip(args, true)?,
+        "p" => Tag::Drawing(DrawingMode::Shape(number(args)?), String::new()),
+        "pbo" => Tag::DrawingBaseline(number(args)?),
+        "t" if depth < MAX_TRANSFORM_DEPTH => parse_transform(args, depth)?,
+        _ => return None,
+    })
+}
+
+/// `\move` with either four coordinates or coordinates plus two times
+fn parse_move(args: &str) -> Option<OverrideTag> {
+    let parts = parenthesized(args)?;
+    let coordinate = |index: usize| parts[index].parse::<f32>().ok();
+    let (t1, t2) = match parts.len() {
+        4 => (None, None),
+        6 => (Some(parts[4].parse().ok()?), Some(parts[5].parse().ok()?)),
+        _ => return None,
+    };
+    Some(OverrideTag::Move {
+        x1: coordinate(0)?,
+        y1: coordinate(1)?,
+        x2: coordinate(2)?,
+        y2: coordinate(3)?,
+        t1,
+        t2,
+    })
+}
+
+/// Rectangular or vector `\clip`/`\iclip`
+fn parse_clip(args: &str, inverse: bool) -> Option<OverrideTag> {
+    let parts = parenthesized(args)?;
+    let mode = |scale| {
+        if inverse {
+            DrawingMode::InverseClip(scale)
+        } else {
+            DrawingMode::Clip(scale)
+        }
+    };
+    match parts.as_slice() {
+        [x1, y1, x2, y2] => Some(OverrideTag::Clip {
+            x1: x1.parse().ok()?,
+            y1: y1.parse().ok()?,
+            x2: x2.parse().ok()?,
+            y2: y2.parse().ok()?,
+            inverse,
+        }),
+        [commands] => Some(OverrideTag::Drawing(mode(1), (*commands).into())),
+        [scale, commands] => Some(OverrideTag::Drawing(
+            mode(scale.parse().ok()?),
+            (*commands).into(),
+        )),
+        _ => None,
+    }
+}
+
+/// `\t`, parsing the animated tags recursively
+///
+/// Everything before the first backslash is the optional `t1,t2,` and
+/// `accel,` prefix; the rest is parsed as a tag sequence.
+fn parse_transform(args: &str, depth: usize) -> Option<OverrideTag> {
+    let inner = args.trim().strip_prefix('(')?.strip_suffix(')')?;
+    let (head, body) = inner.split_at(inner.find('\\')?);
+    let params: Vec<&str> = head
+        .split(',')
+        .map(str::trim)
+        .filter(|param| !param.is_empty())
+        .collect();
+    let (t1, t2, accel) = match params.as_slice() {
+        [] => (None, None, None),
+        [accel] => (None, None, Some(accel.parse().ok()?)),
+        [t1, t2] => (Some(t1.parse().ok()?), Some(t2.parse().ok()?), None),
+        [t1, t2, accel] => (
+            Some(t1.parse().ok()?),
+            Some(t2.parse().ok()?),
+            Some(accel.parse().ok()?),
+        ),
+        _ => return None,
+    };
+    Some(OverrideTag::Transform {
+        t1,
+        t2,
+        accel,
+        tags: parse_tags_at(body, depth + 1),
+    })
+}
+
+/// A single numeric argument
+fn number<T: FromStr>(args: &str) -> Option<T> {
+    args.trim().parse().ok()
+}
+
+/// A `0`/`1` toggle argument
+fn flag(args: &str) -> Option<bool> {
+    match args.trim() {
+        "0" => Some(false),
+        "1" => Some(true),
+        _ => None,
+    }
+}
+
+/// Exactly `N` comma-separated numbers in parentheses
+fn numbers<T: FromStr, const N: usize>(args: &str) -> Option<[T; N]> {
+    let values: Vec<T> = parenthesized(args)?
+        .into_iter()
+        .map(|part| part.parse().ok())
+        .collect::<Option<_>>()?;
+    values.try_into().ok()
+}
+
+/// Trimmed comma-separated parts of a `(...)` argument list
+fn parenthesized(args: &str) -> Option<Vec<&str>> {
+    let inner = args.trim().strip_prefix('(')?.strip_suffix(')')?;
+    Some(inner.split(',').map(str::trim).collect())
+}
+
+/// A color argument, tolerating stray characters before the `&H` introducer
+fn color(args: &str) -> Option<[u8; 4]> {
+    let start = args.find("&H").or_else(|| args.find("&h")).unwrap_or(0);
+    parse_bgr_color(&args[start..]).ok()
+}
+
+/// An `&HAA&` alpha argument
+fn alpha(args: &str) -> Option<u8> {
+    let trimmed = args.trim();
+    let hex = trimmed
+        .strip_prefix("&H")
+        .or_else(|| trimmed.strip_prefix("&h"))?;
+    u8::from_str_radix(hex.strip_suffix('&').unwrap_or(hex), 16).ok()
+}
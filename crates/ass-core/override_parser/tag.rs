@@ -0,0 +1,323 @@
+//! Typed override tag values and their ASS serialization.
+
+use core::fmt;
+
+#[cfg(not(feature = "std"))]
+use alloc::{string::String, vec::Vec};
+#[cfg(feature = "std")]
+use std::{string::String, vec::Vec};
+
+use crate::utils::format_bgr_color;
+
+/// What the vector commands of a [`OverrideTag::Drawing`] describe
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DrawingMode {
+    /// `\p<scale>`: text after the block is drawn as vector commands at
+    /// `2^(scale - 1)` resolution; `0` switches back to text. The tag
+    /// itself carries no commands.
+    Shape(u32),
+    /// `\clip([scale,]commands)`: only the inside of the shape is visible
+    Clip(u32),
+    /// `\iclip([scale,]commands)`: only the outside of the shape is visible
+    InverseClip(u32),
+}
+
+/// A single override tag with typed arguments
+///
+/// Times of `\move`, `\t` and `\fad`/`\fade` are in milliseconds relative to
+/// the event start, karaoke durations in centiseconds. Colors are RGBA as
+/// returned by [`parse_bgr_color`](crate::utils::parse_bgr_color); alpha
+/// values keep ASS semantics (`0` is opaque).
+#[derive(Debug, Clone, PartialEq)]
+pub enum OverrideTag {
+    /// `\b0` / `\b1`
+    Bold(bool),
+    /// `\b<weight>` with an explicit weight such as `700`
+    FontWeight(u32),
+    /// `\i0` / `\i1`
+    Italic(bool),
+    /// `\u0` / `\u1`
+    Underline(bool),
+    /// `\s0` / `\s1`
+    StrikeOut(bool),
+    /// `\bord`
+    Border(f32),
+    /// `\xbord`
+    BorderX(f32),
+    /// `\ybord`
+    BorderY(f32),
+    /// `\shad`
+    Shadow(f32),
+    /// `\xshad`
+    ShadowX(f32),
+    /// `\yshad`
+    ShadowY(f32),
+    /// `\be`
+    BlurEdges(f32),
+    /// `\blur`
+    Blur(f32),
+    /// `\fn`
+    FontName(String),
+    /// `\fs`
+    FontSize(f32),
+    /// `\fscx`
+    FontScaleX(f32),
+    /// `\fscy`
+    FontScaleY(f32),
+    /// `\fsp`
+    Spacing(f32),
+    /// `\frx`
+    RotationX(f32),
+    /// `\fry`
+    RotationY(f32),
+    /// `\frz` or `\fr`
+    RotationZ(f32),
+    /// `\fax`
+    ShearX(f32),
+    /// `\fay`
+    ShearY(f32),
+    /// `\fe`
+    Encoding(u32),
+    /// `\c` or `\1c`
+    PrimaryColor([u8; 4]),
+    /// `\2c`
+    SecondaryColor([u8; 4]),
+    /// `\3c`
+    OutlineColor([u8; 4]),
+    /// `\4c`
+    BackColor([u8; 4]),
+    /// `\alpha`, applied to all four colors
+    Alpha(u8),
+    /// `\1a`
+    PrimaryAlpha(u8),
+    /// `\2a`
+    SecondaryAlpha(u8),
+    /// `\3a`
+    OutlineAlpha(u8),
+    /// `\4a`
+    BackAlpha(u8),
+    /// `\an` numpad alignment (1-9)
+    Alignment(u8),
+    /// `\a` legacy SSA alignment (1-11)
+    LegacyAlignment(u8),
+    /// `\k`
+    Karaoke(u32),
+    /// `\kf` or `\K`
+    KaraokeFill(u32),
+    /// `\ko`
+    KaraokeOutline(u32),
+    /// `\q` wrap style (0-3)
+    WrapStyle(u8),
+    /// `\r` with an optional style name (empty resets to the line's style)
+    Reset(String),
+    /// `\pos(x,y)`
+    Position(f32, f32),
+    /// `\move(x1,y1,x2,y2[,t1,t2])`
+    Move {
+        /// Start x
+        x1: f32,
+        /// Start y
+        y1: f32,
+        /// End x
+        x2: f32,
+        /// End y
+        y2: f32,
+        /// Movement start time
+        t1: Option<u32>,
+        /// Movement end time
+        t2: Option<u32>,
+    },
+    /// `\org(x,y)`
+    Origin(f32, f32),
+    /// `\fad(in,out)`
+    Fade {
+        /// Fade-in duration
+        fade_in: u32,
+        /// Fade-out duration
+        fade_out: u32,
+    },
+    /// `\fade(a1,a2,a3,t1,t2,t3,t4)`
+    ComplexFade {
+        /// Alpha before `t1`, between `t2` and `t3`, and after `t4`
+        alphas: [u8; 3],
+        /// Transition boundaries
+        times: [u32; 4],
+    },
+    /// `\clip(x1,y1,x2,y2)` or `\iclip(x1,y1,x2,y2)`
+    Clip {
+        /// Left edge
+        x1: f32,
+        /// Top edge
+        y1: f32,
+        /// Right edge
+        x2: f32,
+        /// Bottom edge
+        y2: f32,
+        /// Whether the rectangle is hidden rather than kept (`\iclip`)
+        inverse: bool,
+    },
+    /// `\p`, or a vector `\clip`/`\iclip` with its drawing commands
+    Drawing(DrawingMode, String),
+    /// `\pbo`
+    DrawingBaseline(f32),
+    /// `\t([t1,t2,][accel,]tags)`
+    Transform {
+        /// Animation start time
+        t1: Option<u32>,
+        /// Animation end time
+        t2: Option<u32>,
+        /// Acceleration exponent
+        accel: Option<f32>,
+        /// Tags animated towards, which may include nested `\t`
+        tags: Vec<Self>,
+    },
+    /// A recognized tag whose arguments could not be interpreted, such as
+    /// `\frz` without an angle, kept verbatim
+    Malformed {
+        /// Tag name without the backslash
+        name: String,
+        /// Raw arguments as written
+        args: String,
+    },
+    /// Tag name and raw arguments of an unrecognized tag
+    Unknown(String, String),
+}
+
+impl fmt::Display for OverrideTag {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Bold(on) => write!(f, "\\b{}", u8::from(*on)),
+            Self::FontWeight(weight) => write!(f, "\\b{weight}"),
+            Self::Italic(on) => write!(f, "\\i{}", u8::from(*on)),
+            Self::Underline(on) => write!(f, "\\u{}", u8::from(*on)),
+            Self::StrikeOut(on) => write!(f, "\\s{}", u8::from(*on)),
+            Self::Border(width) => write!(f, "\\bord{width}"),
+            Self::BorderX(width) => write!(f, "\\xbord{width}"),
+            Self::BorderY(width) => write!(f, "\\ybord{width}"),
+            Self::Shadow(depth) => write!(f, "\\shad{depth}"),
+            Self::ShadowX(depth) => write!(f, "\\xshad{depth}"),
+            Self::ShadowY(depth) => write!(f, "\\yshad{depth}"),
+            Self::BlurEdges(strength) => write!(f, "\\be{strength}"),
+            Self::Blur(strength) => write!(f, "\\blur{strength}"),
+            Self::FontName(name) => write!(f, "\\fn{name}"),
+            Self::FontSize(size) => write!(f, "\\fs{size}"),
+            Self::FontScaleX(scale) => write!(f, "\\fscx{scale}"),
+            Self::FontScaleY(scale) => write!(f, "\\fscy{scale}"),
+            Self::Spacing(spacing) => write!(f, "\\fsp{spacing}"),
+            Self::RotationX(degrees) => write!(f, "\\frx{degrees}"),
+            Self::RotationY(degrees) => write!(f, "\\fry{degrees}"),
+            Self::RotationZ(degrees) => write!(f, "\\frz{degrees}"),
+            Self::ShearX(factor) => write!(f, "\\fax{factor}"),
+            Self::ShearY(factor) => write!(f, "\\fay{factor}"),
+            Self::Encoding(charset) => write!(f, "\\fe{charset}"),
+            Self::PrimaryColor(rgba) => write_color(f, "c", *rgba),
+            Self::SecondaryColor(rgba) => write_color(f, "2c", *rgba),
+            Self::OutlineColor(rgba) => write_color(f, "3c", *rgba),
+            Self::BackColor(rgba) => write_color(f, "4c", *rgba),
+            Self::Alpha(alpha) => write!(f, "\\alpha&H{alpha:02X}&"),
+            Self::PrimaryAlpha(alpha) => write!(f, "\\1a&H{alpha:02X}&"),
+            Self::SecondaryAlpha(alpha) => write!(f, "\\2a&H{alpha:02X}&"),
+            Self::OutlineAlpha(alpha) => write!(f, "\\3a&H{alpha:02X}&"),
+            Self::BackAlpha(alpha) => write!(f, "\\4a&H{alpha:02X}&"),
+            Self::Alignment(alignment) => write!(f, "\\an{alignment}"),
+            Self::LegacyAlignment(alignment) => write!(f, "\\a{alignment}"),
+            Self::Karaoke(duration) => write!(f, "\\k{duration}"),
+            Self::KaraokeFill(duration) => write!(f, "\\kf{duration}"),
+            Self::KaraokeOutline(duration) => write!(f, "\\ko{duration}"),
+            Self::WrapStyle(style) => write!(f, "\\q{style}"),
+            Self::Reset(style) => write!(f, "\\r{style}"),
+            Self::Position(x, y) => write!(f, "\\pos({x},{y})"),
+            Self::Move {
+                x1,
+                y1,
+                x2,
+                y2,
+                t1,
+                t2,
+            } => {
+                write!(f, "\\move({x1},{y1},{x2},{y2}")?;
+                if let (Some(t1), Some(t2)) = (t1, t2) {
+                    write!(f, ",{t1},{t2}")?;
+                }
+                f.write_str(")")
+            }
+            Self::Origin(x, y) => write!(f, "\\org({x},{y})"),
+            Self::Fade { fade_in, fade_out } => write!(f, "\\fad({fade_in},{fade_out})"),
+            Self::ComplexFade {
+                alphas: [a1, a2, a3],
+                times: [t1, t2, t3, t4],
+            } => write!(f, "\\fade({a1},{a2},{a3},{t1},{t2},{t3},{t4})"),
+            Self::Clip {
+                x1,
+                y1,
+                x2,
+                y2,
+                inverse,
+            } => {
+                let name = if *inverse { "iclip" } else { "clip" };
+                write!(f, "\\{name}({x1},{y1},{x2},{y2})")
+            }
+            Self::Drawing(DrawingMode::Shape(scale), _) => write!(f, "\\p{scale}"),
+            Self::Drawing(DrawingMode::Clip(scale), commands) => {
+                write_vector_clip(f, "clip", *scale, commands)
+            }
+            Self::Drawing(DrawingMode::InverseClip(scale), commands) => {
+                write_vector_clip(f, "iclip", *scale, commands)
+            }
+            Self::DrawingBaseline(offset) => write!(f, "\\pbo{offset}"),
+            Self::Transform {
+                t1,
+                t2,
+                accel,
+                tags,
+            } => write_transform(f, (*t1, *t2), *accel, tags),
+            Self::Malformed { name, args } | Self::Unknown(name, args) => {
+                write!(f, "\\{name}{args}")
+            }
+        }
+    }
+}
+
+/// Write a color tag, using the short `&HBBGGRR&` form when alpha is zero
+fn write_color(f: &mut fmt::Formatter<'_>, name: &str, rgba: [u8; 4]) -> fmt::Result {
+    let [red, green, blue, alpha] = rgba;
+    if alpha == 0 {
+        write!(f, "\\{name}&H{blue:02X}{green:02X}{red:02X}&")
+    } else {
+        write!(f, "\\{name}{}", format_bgr_color(rgba))
+    }
+}
+
+/// Write a `\t` transform, omitting absent times and acceleration
+fn write_transform(
+    f: &mut fmt::Formatter<'_>,
+    times: (Option<u32>, Option<u32>),
+    accel: Option<f32>,
+    tags: &[OverrideTag],
+) -> fmt::Result {
+    f.write_str("\\t(")?;
+    if let (Some(t1), Some(t2)) = times {
+        write!(f, "{t1},{t2},")?;
+    }
+    if let Some(accel) = accel {
+        write!(f, "{accel},")?;
+    }
+    for tag in tags {
+        write!(f, "{tag}")?;
+    }
+    f.write_str(")")
+}
+
+/// Write a vector clip, omitting the default scale of 1
+fn write_vector_clip(
+    f: &mut fmt::Formatter<'_>,
+    name: &str,
+    scale: u32,
+    commands: &str,
+) -> fmt::Result {
+    if scale == 1 {
+        write!(f, "\\{name}({commands})")
+    } else {
+        write!(f, "\\{name}({scale},{commands})")
+    }
+}
@@ -0,0 +1,316 @@
+//! Tests for structured override block parsing and serialization.
+
+use super::*;
+
+#[cfg(not(feature = "std"))]
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec,
+};
+
+/// Parse a block holding a single tag
+fn single(text: &str) -> OverrideTag {
+    let block = OverrideBlock::parse(text);
+    assert_eq!(block.tags.len(), 1, "{text}: {:?}", block.tags);
+    block.tags.into_iter().next().unwrap()
+}
+
+/// Check that each text parses to its tag and serializes back unchanged
+fn assert_round_trips<const N: usize>(cases: [(&str, OverrideTag); N]) {
+    for (text, expected) in cases {
+        let tag = single(text);
+        assert_eq!(tag, expected, "{text}");
+        assert_eq!(tag.to_string(), text, "{text}");
+    }
+}
+
+#[test]
+fn parses_every_style_tag() {
+    use OverrideTag as Tag;
+
+    let cases = [
+        (r"\b1", Tag::Bold(true)),
+        (r"\b0", Tag::Bold(false)),
+        (r"\b700", Tag::FontWeight(700)),
+        (r"\i1", Tag::Italic(true)),
+        (r"\u1", Tag::Underline(true)),
+        (r"\s0", Tag::StrikeOut(false)),
+        (r"\bord2.5", Tag::Border(2.5)),
+        (r"\xbord1", Tag::BorderX(1.0)),
+        (r"\ybord3", Tag::BorderY(3.0)),
+        (r"\shad2", Tag::Shadow(2.0)),
+        (r"\xshad-1", Tag::ShadowX(-1.0)),
+        (r"\yshad4", Tag::ShadowY(4.0)),
+        (r"\be1", Tag::BlurEdges(1.0)),
+        (r"\blur0.8", Tag::Blur(0.8)),
+        (r"\fnArial Black", Tag::FontName("Arial Black".into())),
+        (r"\fs48", Tag::FontSize(48.0)),
+        (r"\fscx120", Tag::FontScaleX(120.0)),
+        (r"\fscy80", Tag::FontScaleY(80.0)),
+        (r"\fsp2", Tag::Spacing(2.0)),
+        (r"\frx30", Tag::RotationX(30.0)),
+        (r"\fry-15", Tag::RotationY(-15.0)),
+        (r"\frz45", Tag::RotationZ(45.0)),
+        (r"\fax0.2", Tag::ShearX(0.2)),
+        (r"\fay-0.1", Tag::ShearY(-0.1)),
+        (r"\fe128", Tag::Encoding(128)),
+        (r"\c&H0000FF&", Tag::PrimaryColor([255, 0, 0, 0])),
+        (r"\2c&H00FF00&", Tag::SecondaryColor([0, 255, 0, 0])),
+        (r"\3c&HFF0000&", Tag::OutlineColor([0, 0, 255, 0])),
+        (r"\4c&H80102030&", Tag::BackColor([0x30, 0x20, 0x10, 0x80])),
+        (r"\alpha&H80&", Tag::Alpha(0x80)),
+        (r"\1a&H00&", Tag::PrimaryAlpha(0)),
+        (r"\2a&H40&", Tag::SecondaryAlpha(0x40)),
+        (r"\3a&HFF&", Tag::OutlineAlpha(0xFF)),
+        (r"\4a&H7F&", Tag::BackAlpha(0x7F)),
+        (r"\an7", Tag::Alignment(7)),
+        (r"\a10", Tag::LegacyAlignment(10)),
+        (r"\k25", Tag::Karaoke(25)),
+        (r"\kf40", Tag::KaraokeFill(40)),
+        (r"\ko15", Tag::KaraokeOutline(15)),
+        (r"\q2", Tag::WrapStyle(2)),
+        (r"\rSign", Tag::Reset("Sign".into())),
+        (r"\r", Tag::Reset(String::new())),
+    ];
+    assert_round_trips(cases);
+}
+
+#[test]
+fn parses_every_positional_and_animation_tag() {
+    use OverrideTag as Tag;
+
+    let cases = [
+        (r"\pos(320,240.5)", Tag::Position(320.0, 240.5)),
+        (r"\org(10,-20)", Tag::Origin(10.0, -20.0)),
+        (
+            r"\move(0,0,100,50)",
+            Tag::Move {
+                x1: 0.0,
+                y1: 0.0,
+                x2: 100.0,
+                y2: 50.0,
+                t1: None,
+                t2: None,
+            },
+        ),
+        (
+            r"\move(0,0,100,50,200,800)",
+            Tag::Move {
+                x1: 0.0,
+                y1: 0.0,
+                x2: 100.0,
+                y2: 50.0,
+                t1: Some(200),
+                t2: Some(800),
+            },
+        ),
+        (
+            r"\fad(300,500)",
+            Tag::Fade {
+                fade_in: 300,
+                fade_out: 500,
+            },
+        ),
+        (
+            r"\fade(255,0,255,0,500,1500,2000)",
+            Tag::ComplexFade {
+                alphas: [255, 0, 255],
+                times: [0, 500, 1500, 2000],
+            },
+        ),
+        (
+            r"\clip(10,20,300,400)",
+            Tag::Clip {
+                x1: 10.0,
+                y1: 20.0,
+                x2: 300.0,
+                y2: 400.0,
+                inverse: false,
+            },
+        ),
+        (
+            r"\iclip(0,0,50,50)",
+            Tag::Clip {
+                x1: 0.0,
+                y1: 0.0,
+                x2: 50.0,
+                y2: 50.0,
+                inverse: true,
+            },
+        ),
+        (
+            r"\clip(m 0 0 l 100 0 100 100)",
+            Tag::Drawing(DrawingMode::Clip(1), "m 0 0 l 100 0 100 100".into()),
+        ),
+        (
+            r"\iclip(2,m 0 0 l 10 10)",
+            Tag::Drawing(DrawingMode::InverseClip(2), "m 0 0 l 10 10".into()),
+        ),
+        (r"\p1", Tag::Drawing(DrawingMode::Shape(1), String::new())),
+        (r"\pbo-5", Tag::DrawingBaseline(-5.0)),
+        (
+            r"\t(0,1000,0.5,\frz360)",
+            Tag::Transform {
+                t1: Some(0),
+                t2: Some(1000),
+                accel: Some(0.5),
+                tags: vec![Tag::RotationZ(360.0)],
+            },
+        ),
+        (r"\xyz(1,2)", Tag::Unknown("xyz".into(), "(1,2)".into())),
+    ];
+    assert_round_trips(cases);
+}
+
+#[test]
+fn aliases_parse_to_canonical_variants() {
+    assert_eq!(single(r"\1c&H0000FF&"), single(r"\c&H0000FF&"));
+    assert_eq!(single(r"\fr90"), OverrideTag::RotationZ(90.0));
+    assert_eq!(single(r"\K30"), OverrideTag::KaraokeFill(30));
+    assert_eq!(single(r"\fr90").to_string(), r"\frz90");
+}
+
+/// A malformed tag with the given name and raw arguments
+fn malformed(name: &str, args: &str) -> OverrideTag {
+    OverrideTag::Malformed {
+        name: name.into(),
+        args: args.into(),
+    }
+}
+
+#[test]
+fn malformed_arguments_are_recovered() {
+    assert_eq!(single(r"\fs1x"), malformed("fs", "1x"));
+    assert_eq!(single(r"\pos(1)"), malformed("pos", "(1)"));
+    assert_eq!(single(r"\an12"), malformed("an", "12"));
+    assert_eq!(single(r"\i2").to_string(), r"\i2");
+    assert!(!OverrideBlock::parse(r"\xyz(1,2)").has_malformed());
+}
+
+#[test]
+fn missing_argument_keeps_surrounding_tags() {
+    let text = r"{\b1\frz\c&HFF0000&}";
+    let block = OverrideBlock::parse(text);
+    assert_eq!(
+        block.tags,
+        vec![
+            OverrideTag::Bold(true),
+            malformed("frz", ""),
+            OverrideTag::PrimaryColor([0, 0, 255, 0]),
+        ]
+    );
+    assert!(block.has_malformed());
+    assert_eq!(block.to_string(), text);
+
+    let issues = block.issues(7);
+    assert_eq!(issues.len(), 1);
+    assert_eq!(issues[0].severity, crate::parser::IssueSeverity::Warning);
+    assert_eq!(issues[0].line, 7);
+    assert!(issues[0].message.contains(r"\frz"));
+    assert!(issues[0].message.contains("expected a number"));
+}
+
+#[test]
+fn malformed_tags_inside_transforms_are_reported() {
+    let block = OverrideBlock::parse(r"{\t(0,500,\fs\bord2)\pos(x,y)}");
+    assert!(block.has_malformed());
+    let messages: Vec<String> = block.issues(1).into_iter().map(|i| i.message).collect();
+    assert_eq!(messages.len(), 2);
+    assert!(messages[0].contains(r"\fs"));
+    assert!(messages[1].contains(r"\pos(x,y)"));
+
+    assert!(!OverrideBlock::parse(r"{\t(\fs20)\b1}").has_malformed());
+    assert!(OverrideBlock::parse(r"{\b1}").issues(1).is_empty());
+}
+
+#[test]
+fn deeply_nested_transforms_do_not_overflow() {
+    let depth = 10_000;
+    let text = format!("{}{}", r"\t(".repeat(depth), ")".repeat(depth));
+    let block = OverrideBlock::parse(&text);
+    assert_eq!(block.tags.len(), 1);
+    assert!(block.has_malformed());
+    assert_eq!(block.to_string(), format!("{{{text}}}"));
+}
+
+/// Bytes that make up override tags, so generated input reaches the
+/// argument parsers rather than being skipped as comment text
+const TAG_ALPHABET: &[u8] = b"\\\\\\(),&&Hh0123456789.-bfrstcqkpaniomlxyzdeu{} ";
+
+proptest::proptest! {
+    #[test]
+    fn parsing_random_bytes_never_panics(bytes in proptest::collection::vec(proptest::prelude::any::<u8>(), 0..256)) {
+        let text = String::from_utf8_lossy(&bytes);
+        let block = OverrideBlock::parse(&text);
+        let _ = block.to_string();
+        proptest::prop_assert_eq!(block.has_malformed(), !block.issues(1).is_empty());
+    }
+
+    #[test]
+    fn parsing_tag_soup_never_panics(indices in proptest::collection::vec(0..TAG_ALPHABET.len(), 0..256)) {
+        let text: String = indices.iter().map(|&index| char::from(TAG_ALPHABET[index])).collect();
+        let block = OverrideBlock::parse(&text);
+        let reparsed = OverrideBlock::parse(&block.to_string());
+        proptest::prop_assert_eq!(block.has_malformed(), !block.issues(1).is_empty());
+        proptest::prop_assert_eq!(reparsed.tags.len(), block.tags.len());
+    }
+}
+
+#[test]
+fn transform_variants_round_trip() {
+    for text in [
+        r"\t(\fs20)",
+        r"\t(2,\fscx150)",
+        r"\t(100,200,\c&H0000FF&\alpha&H80&)",
+        r"\t(0,500,\clip(0,0,10,10))",
+    ] {
+        assert_eq!(single(text).to_string(), text);
+    }
+}
+
+#[test]
+fn nested_transforms_parse_recursively() {
+    let tag = single(r"\t(0,1000,\fs40\t(200,400,2,\bord5)\blur1)");
+    let OverrideTag::Transform { tags, .. } = &tag else {
+        panic!("expected transform, got {tag:?}");
+    };
+    assert_eq!(tags.len(), 3);
+    assert_eq!(
+        tags[1],
+        OverrideTag::Transform {
+            t1: Some(200),
+            t2: Some(400),
+            accel: Some(2.0),
+            tags: vec![OverrideTag::Border(5.0)],
+        }
+    );
+    assert_eq!(tags[2], OverrideTag::Blur(1.0));
+}
+
+#[test]
+fn block_parses_with_and_without_braces() {
+    let text = r"\an8\fnComic Sans MS\fs30\pos(10,20)";
+    let bare = OverrideBlock::parse(text);
+    let braced = OverrideBlock::parse(&format!("{{{text}}}"));
+    assert_eq!(bare, braced);
+    assert_eq!(bare.tags.len(), 4);
+    assert_eq!(bare.to_string(), format!("{{{text}}}"));
+}
+
+#[test]
+fn comments_and_stray_backslashes_are_skipped() {
+    let block = OverrideBlock::parse(r"{note \b1\\i1}");
+    assert_eq!(
+        block.tags,
+        vec![OverrideTag::Bold(true), OverrideTag::Italic(true)]
+    );
+    assert!(OverrideBlock::parse("{just a comment}").is_empty());
+    assert!(OverrideBlock::parse("{}").is_empty());
+}
+
+#[test]
+fn unclosed_parentheses_consume_rest_of_block() {
+    let block = OverrideBlock::parse(r"{\pos(1,2\b1}");
+    assert_eq!(block.tags, vec![malformed("pos", r"(1,2\b1")]);
+}
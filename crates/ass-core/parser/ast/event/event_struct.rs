@@ -27,7 +27,7 @@ use super::{EventType, Span};
 ///
 /// assert!(event.is_dialogue());
 /// ```
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct Event<'a> {
     /// Event type (Dialogue, Comment, etc.)
@@ -90,6 +90,18 @@ impl Event<'_> {
         matches!(self.event_type, EventType::Comment)
     }
 
+    /// Switch between dialogue and comment in place
+    ///
+    /// Dialogue events become comments and comments become dialogue; other
+    /// event types are left unchanged.
+    pub fn toggle_comment(&mut self) {
+        self.event_type = match self.event_type {
+            EventType::Dialogue => EventType::Comment,
+            EventType::Comment => EventType::Dialogue,
+            other => other,
+        };
+    }
+
     /// Parse start time to centiseconds
     ///
     /// Converts the start time string to centiseconds for timing calculations.
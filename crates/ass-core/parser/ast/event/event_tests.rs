@@ -1,4 +1,4 @@
-//! Tests for [`Event`] construction, predicates, and equality.
+//! Tests for [`Event`] construction, predicates, comment toggling, and equality.
 
 use super::*;
 #[cfg(not(feature = "std"))]
@@ -21,6 +21,22 @@ fn event_dialogue_check() {
     assert!(comment.is_comment());
 }
 
+#[test]
+fn event_toggle_comment() {
+    let mut event = Event::default();
+    event.toggle_comment();
+    assert!(event.is_comment());
+    event.toggle_comment();
+    assert!(event.is_dialogue());
+
+    let mut sound = Event {
+        event_type: EventType::Sound,
+        ..Event::default()
+    };
+    sound.toggle_comment();
+    assert_eq!(sound.event_type, EventType::Sound);
+}
+
 #[test]
 fn event_default() {
     let event = Event::default();
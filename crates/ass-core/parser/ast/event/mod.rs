@@ -5,6 +5,7 @@
 
 mod event_struct;
 mod event_type;
+mod owned;
 mod serialization;
 
 #[cfg(test)]
@@ -18,5 +19,6 @@ mod timing_tests;
 
 pub use event_struct::Event;
 pub use event_type::EventType;
+pub use owned::OwnedEvent;
 
 use super::Span;
@@ -0,0 +1,125 @@
+//! Owned counterpart of the zero-copy [`Event`]
+//!
+//! Defines [`OwnedEvent`], which holds every event field as a `String` so it
+//! can be edited in place inside an [`OwnedScript`] without borrowing from a
+//! source buffer.
+//!
+//! [`OwnedScript`]: crate::parser::OwnedScript
+
+use alloc::string::String;
+
+use super::{Event, EventType, Span};
+
+/// Event with owned field text
+///
+/// Mirrors [`Event`] field for field. Convert from a parsed event with
+/// [`From`], and borrow it back as an [`Event`] with [`OwnedEvent::as_event`].
+///
+/// # Examples
+///
+/// ```rust
+/// use ass_core::parser::ast::{Event, OwnedEvent};
+///
+/// let mut event = OwnedEvent::from(&Event {
+///     text: "Hello",
+///     ..Event::default()
+/// });
+/// event.text.push_str(", world!");
+/// assert_eq!(event.as_event().text, "Hello, world!");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct OwnedEvent {
+    /// Event type (Dialogue, Comment, etc.)
+    pub event_type: EventType,
+
+    /// Layer for drawing order (higher layers drawn on top)
+    pub layer: String,
+
+    /// Start time in ASS time format (H:MM:SS.CS)
+    pub start: String,
+
+    /// End time in ASS time format (H:MM:SS.CS)
+    pub end: String,
+
+    /// Style name reference
+    pub style: String,
+
+    /// Character name or speaker
+    pub name: String,
+
+    /// Left margin override (pixels)
+    pub margin_l: String,
+
+    /// Right margin override (pixels)
+    pub margin_r: String,
+
+    /// Vertical margin override (pixels) (V4+)
+    pub margin_v: String,
+
+    /// Top margin override (pixels) (V4++)
+    pub margin_t: Option<String>,
+
+    /// Bottom margin override (pixels) (V4++)
+    pub margin_b: Option<String>,
+
+    /// Effect specification for special rendering
+    pub effect: String,
+
+    /// Text content with possible style overrides
+    pub text: String,
+
+    /// Span in source text where this event is defined
+    pub span: Span,
+}
+
+impl OwnedEvent {
+    /// Borrow this event as a zero-copy [`Event`]
+    #[must_use]
+    pub fn as_event(&self) -> Event<'_> {
+        Event {
+            event_type: self.event_type,
+            layer: &self.layer,
+            start: &self.start,
+            end: &self.end,
+            style: &self.style,
+            name: &self.name,
+            margin_l: &self.margin_l,
+            margin_r: &self.margin_r,
+            margin_v: &self.margin_v,
+            margin_t: self.margin_t.as_deref(),
+            margin_b: self.margin_b.as_deref(),
+            effect: &self.effect,
+            text: &self.text,
+            span: self.span,
+        }
+    }
+}
+
+impl From<&Event<'_>> for OwnedEvent {
+    fn from(event: &Event<'_>) -> Self {
+        Self {
+            event_type: event.event_type,
+            layer: event.layer.into(),
+            start: event.start.into(),
+            end: event.end.into(),
+            style: event.style.into(),
+            name: event.name.into(),
+            margin_l: event.margin_l.into(),
+            margin_r: event.margin_r.into(),
+            margin_v: event.margin_v.into(),
+            margin_t: event.margin_t.map(String::from),
+            margin_b: event.margin_b.map(String::from),
+            effect: event.effect.into(),
+            text: event.text.into(),
+            span: event.span,
+        }
+    }
+}
+
+impl Default for OwnedEvent {
+    /// Owned copy of [`Event::default`]
+    fn default() -> Self {
+        Self::from(&Event::default())
+    }
+}
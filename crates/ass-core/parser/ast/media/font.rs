@@ -32,7 +32,7 @@ use core::ops::Range;
 /// let decoded = font.decode_data()?;
 /// # Ok::<(), Box<dyn std::error::Error>>(())
 /// ```
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct Font<'a> {
     /// Font filename as it appears in the `[Fonts\]` section
@@ -31,7 +31,7 @@ use core::ops::Range;
 /// let decoded = graphic.decode_data()?;
 /// # Ok::<(), Box<dyn std::error::Error>>(())
 /// ```
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct Graphic<'a> {
     /// Graphic filename as it appears in the `[Graphics\]` section
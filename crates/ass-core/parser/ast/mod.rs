@@ -47,9 +47,10 @@ mod style;
 mod span_tests;
 
 // Re-export all public types to maintain API compatibility
-pub use event::{Event, EventType};
+pub use event::{Event, EventType, OwnedEvent};
 pub use media::{Font, Graphic};
-pub use script_info::ScriptInfo;
-pub use section::{Section, SectionType};
+pub(crate) use script_info::METADATA_KEYS;
+pub use script_info::{ScriptInfo, ScriptMetadata, WrapMode, YCbCrMatrix};
+pub use section::{CustomSection, Section, SectionType};
 pub use span::Span;
-pub use style::Style;
+pub use style::{OwnedStyle, Style};
@@ -0,0 +1,120 @@
+//! Typed values of enumerated `[Script Info]` headers
+//!
+//! Defines [`WrapMode`] for `WrapStyle` (and the `\q` override tag) and
+//! [`YCbCrMatrix`] for `YCbCr Matrix`.
+
+/// Line wrapping mode selected by `\q` or the `WrapStyle` header
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum WrapMode {
+    /// `\q0`: smart wrapping, lines balanced with the upper line wider
+    #[default]
+    Smart = 0,
+    /// `\q1`: end-of-line wrapping, each line filled greedily
+    EndOfLine = 1,
+    /// `\q2`: no wrapping, only explicit `\N` breaks
+    NoWrap = 2,
+    /// `\q3`: smart wrapping with the lower line wider
+    SmartBottom = 3,
+}
+
+impl WrapMode {
+    /// Convert a `\q` / `WrapStyle` value, returning `None` outside `0..=3`
+    #[must_use]
+    pub const fn from_value(value: u8) -> Option<Self> {
+        match value {
+            0 => Some(Self::Smart),
+            1 => Some(Self::EndOfLine),
+            2 => Some(Self::NoWrap),
+            3 => Some(Self::SmartBottom),
+            _ => None,
+        }
+    }
+
+    /// Numeric `\q` / `WrapStyle` value
+    #[must_use]
+    pub const fn value(self) -> u8 {
+        self as u8
+    }
+
+    /// Whether text is broken to fit the available width
+    #[must_use]
+    pub fn wraps(self) -> bool {
+        self != Self::NoWrap
+    }
+
+    /// Whether wrapped lines are balanced rather than greedily filled
+    #[must_use]
+    pub const fn balances(self) -> bool {
+        matches!(self, Self::Smart | Self::SmartBottom)
+    }
+}
+
+/// Color matrix named by the `YCbCr Matrix` header
+///
+/// Tells the renderer which matrix and range the video was encoded with so
+/// subtitle colors can be converted to match it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum YCbCrMatrix {
+    /// `None`: colors are used as written
+    None,
+    /// `TV.601`: BT.601, limited range
+    Tv601,
+    /// `PC.601`: BT.601, full range
+    Pc601,
+    /// `TV.709`: BT.709, limited range
+    Tv709,
+    /// `PC.709`: BT.709, full range
+    Pc709,
+    /// `TV.FCC`: FCC, limited range
+    TvFcc,
+    /// `PC.FCC`: FCC, full range
+    PcFcc,
+    /// `TV.240M`: SMPTE 240M, limited range
+    Tv240m,
+    /// `PC.240M`: SMPTE 240M, full range
+    Pc240m,
+}
+
+impl YCbCrMatrix {
+    /// Parse a `YCbCr Matrix` header value (case-insensitive)
+    #[must_use]
+    pub fn from_value(value: &str) -> Option<Self> {
+        [
+            Self::None,
+            Self::Tv601,
+            Self::Pc601,
+            Self::Tv709,
+            Self::Pc709,
+            Self::TvFcc,
+            Self::PcFcc,
+            Self::Tv240m,
+            Self::Pc240m,
+        ]
+        .into_iter()
+        .find(|matrix| matrix.as_str().eq_ignore_ascii_case(value.trim()))
+    }
+
+    /// Header value as written by Aegisub
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::None => "None",
+            Self::Tv601 => "TV.601",
+            Self::Pc601 => "PC.601",
+            Self::Tv709 => "TV.709",
+            Self::Pc709 => "PC.709",
+            Self::TvFcc => "TV.FCC",
+            Self::PcFcc => "PC.FCC",
+            Self::Tv240m => "TV.240M",
+            Self::Pc240m => "PC.240M",
+        }
+    }
+
+    /// Whether the matrix uses the full 0-255 range
+    #[must_use]
+    pub const fn is_full_range(self) -> bool {
+        matches!(self, Self::Pc601 | Self::Pc709 | Self::PcFcc | Self::Pc240m)
+    }
+}
@@ -29,7 +29,7 @@ use core::ops::Range;
 /// assert_eq!(info.title(), "Test Script");
 /// assert_eq!(info.script_type(), Some("v4.00+"));
 /// ```
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct ScriptInfo<'a> {
     /// Key-value pairs as zero-copy spans
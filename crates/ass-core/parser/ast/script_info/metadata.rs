@@ -0,0 +1,180 @@
+//! Typed view of the standard `[Script Info]` headers
+//!
+//! Defines [`ScriptMetadata`], which holds the headers renderers and tools
+//! commonly need as parsed values instead of raw strings.
+
+use alloc::{format, string::String};
+
+#[cfg(not(feature = "std"))]
+extern crate alloc;
+
+use super::{ScriptInfo, WrapMode, YCbCrMatrix};
+use crate::ScriptVersion;
+
+/// Header names covered by [`ScriptMetadata`], in serialization order
+pub const METADATA_KEYS: [&str; 10] = [
+    "Title",
+    "Original Script",
+    "ScriptType",
+    "PlayResX",
+    "PlayResY",
+    "PlayDepth",
+    "Timer",
+    "WrapStyle",
+    "ScaledBorderAndShadow",
+    "YCbCr Matrix",
+];
+
+/// Typed values of the standard `[Script Info]` headers
+///
+/// Missing or unparsable headers are `None`, except `script_type`, which
+/// falls back to the version the parser assumed.
+///
+/// # Examples
+///
+/// ```rust
+/// use ass_core::parser::{ast::WrapMode, Script};
+///
+/// let script = Script::parse("[Script Info]\nPlayResX: 1280\nWrapStyle: 2\n")?;
+/// assert_eq!(script.info().play_res_x, Some(1280));
+/// assert_eq!(script.info().wrap_style, Some(WrapMode::NoWrap));
+/// assert_eq!(script.info().play_resolution(), (1280, 1024));
+/// # Ok::<(), ass_core::CoreError>(())
+/// ```
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct ScriptMetadata {
+    /// `Title`
+    pub title: Option<String>,
+    /// `Original Script`
+    pub original_script: Option<String>,
+    /// `ScriptType`
+    pub script_type: ScriptVersion,
+    /// `PlayResX`
+    pub play_res_x: Option<u32>,
+    /// `PlayResY`
+    pub play_res_y: Option<u32>,
+    /// `PlayDepth`
+    pub play_depth: Option<u32>,
+    /// `Timer`, the playback speed in percent
+    pub timer: Option<f64>,
+    /// `WrapStyle`
+    pub wrap_style: Option<WrapMode>,
+    /// `ScaledBorderAndShadow`
+    pub scaled_border_and_shadow: Option<bool>,
+    /// `YCbCr Matrix`
+    pub ycbcr_matrix: Option<YCbCrMatrix>,
+}
+
+impl ScriptMetadata {
+    /// Read the typed headers from a parsed `[Script Info]` section
+    ///
+    /// `script_type` is used when the section has no valid `ScriptType`.
+    #[must_use]
+    pub fn from_script_info(info: &ScriptInfo<'_>, script_type: ScriptVersion) -> Self {
+        let number = |key| info.get_field(key).and_then(|v| v.trim().parse().ok());
+        Self {
+            title: info.get_field("Title").map(String::from),
+            original_script: info.get_field("Original Script").map(String::from),
+            script_type: info
+                .script_type()
+                .and_then(|v| ScriptVersion::from_header(v.trim()))
+                .unwrap_or(script_type),
+            play_res_x: number("PlayResX"),
+            play_res_y: number("PlayResY"),
+            play_depth: number("PlayDepth"),
+            timer: info
+                .get_field("Timer")
+                .and_then(|v| v.trim().parse::<f64>().ok())
+                .filter(|timer| timer.is_finite()),
+            wrap_style: info
+                .get_field("WrapStyle")
+                .and_then(|v| v.trim().parse().ok())
+                .and_then(WrapMode::from_value),
+            scaled_border_and_shadow: info.get_field("ScaledBorderAndShadow").and_then(parse_flag),
+            ycbcr_matrix: info
+                .get_field("YCbCr Matrix")
+                .and_then(YCbCrMatrix::from_value),
+        }
+    }
+
+    /// Play resolution used for rendering, with libass defaults
+    ///
+    /// A missing dimension is derived from the other at 4:3 (with 1280x1024
+    /// as the special case), and 384x288 is used when both are missing.
+    #[must_use]
+    pub const fn play_resolution(&self) -> (u32, u32) {
+        match (self.play_res_x, self.play_res_y) {
+            (Some(x), Some(y)) => (x, y),
+            (Some(1280), None) | (None, Some(1024)) => (1280, 1024),
+            (Some(x), None) => (x, x.saturating_mul(3) / 4),
+            (None, Some(y)) => (y.saturating_mul(4) / 3, y),
+            (None, None) => (384, 288),
+        }
+    }
+
+    /// Header values in [`METADATA_KEYS`] order, `None` for unset headers
+    pub(crate) fn header_values(&self) -> [Option<String>; 10] {
+        [
+            self.title.clone(),
+            self.original_script.clone(),
+            Some(String::from(self.script_type.as_header_str())),
+            self.play_res_x.map(|v| format!("{v}")),
+            self.play_res_y.map(|v| format!("{v}")),
+            self.play_depth.map(|v| format!("{v}")),
+            self.timer.map(|v| format!("{v:.4}")),
+            self.wrap_style.map(|v| format!("{}", v.value())),
+            self.scaled_border_and_shadow
+                .map(|v| String::from(if v { "yes" } else { "no" })),
+            self.ycbcr_matrix.map(|v| String::from(v.as_str())),
+        ]
+    }
+}
+
+impl Default for ScriptMetadata {
+    /// No headers set, for an ASS v4.00+ script
+    fn default() -> Self {
+        Self {
+            title: None,
+            original_script: None,
+            script_type: ScriptVersion::AssV4,
+            play_res_x: None,
+            play_res_y: None,
+            play_depth: None,
+            timer: None,
+            wrap_style: None,
+            scaled_border_and_shadow: None,
+            ycbcr_matrix: None,
+        }
+    }
+}
+
+impl PartialEq for ScriptMetadata {
+    fn eq(&self, other: &Self) -> bool {
+        self.title == other.title
+            && self.original_script == other.original_script
+            && self.script_type == other.script_type
+            && self.play_res_x == other.play_res_x
+            && self.play_res_y == other.play_res_y
+            && self.play_depth == other.play_depth
+            && self.timer.map(f64::to_bits) == other.timer.map(f64::to_bits)
+            && self.wrap_style == other.wrap_style
+            && self.scaled_border_and_shadow == other.scaled_border_and_shadow
+            && self.ycbcr_matrix == other.ycbcr_matrix
+    }
+}
+
+// Timers compare bitwise, which is reflexive
+impl Eq for ScriptMetadata {}
+
+/// Parse a yes/no header flag the way libass does
+fn parse_flag(value: &str) -> Option<bool> {
+    let value = value.trim();
+    if value.eq_ignore_ascii_case("yes") {
+        Some(true)
+    } else if value.eq_ignore_ascii_case("no") {
+        Some(false)
+    } else {
+        value.parse::<i64>().ok().map(|v| v != 0)
+    }
+}
@@ -0,0 +1,88 @@
+//! Tests for typed `[Script Info]` header parsing.
+
+use super::*;
+use crate::parser::ast::Span;
+use crate::ScriptVersion;
+#[cfg(not(feature = "std"))]
+use alloc::vec;
+
+fn metadata(fields: &[(&'static str, &'static str)]) -> ScriptMetadata {
+    let info = ScriptInfo {
+        fields: fields.to_vec(),
+        span: Span::new(0, 0, 0, 0),
+    };
+    ScriptMetadata::from_script_info(&info, ScriptVersion::AssV4)
+}
+
+#[test]
+fn invalid_values_are_unset() {
+    let parsed = metadata(&[
+        ("PlayResX", "wide"),
+        ("Timer", "NaN"),
+        ("WrapStyle", "7"),
+        ("ScaledBorderAndShadow", "maybe"),
+        ("YCbCr Matrix", "TV.2020"),
+        ("ScriptType", "v5"),
+    ]);
+
+    assert_eq!(parsed.play_res_x, None);
+    assert_eq!(parsed.timer, None);
+    assert_eq!(parsed.wrap_style, None);
+    assert_eq!(parsed.scaled_border_and_shadow, None);
+    assert_eq!(parsed.ycbcr_matrix, None);
+    assert_eq!(parsed.script_type, ScriptVersion::AssV4);
+}
+
+#[test]
+fn flags_and_matrices_follow_libass() {
+    let parsed = metadata(&[
+        ("ScaledBorderAndShadow", "YES"),
+        ("YCbCr Matrix", " pc.fcc "),
+        ("ScriptType", "v4.00"),
+    ]);
+    assert_eq!(parsed.scaled_border_and_shadow, Some(true));
+    assert_eq!(parsed.ycbcr_matrix, Some(YCbCrMatrix::PcFcc));
+    assert_eq!(parsed.script_type, ScriptVersion::SsaV4);
+    assert!(YCbCrMatrix::PcFcc.is_full_range());
+    assert!(!YCbCrMatrix::Tv601.is_full_range());
+
+    assert_eq!(
+        metadata(&[("ScaledBorderAndShadow", "0")]).scaled_border_and_shadow,
+        Some(false)
+    );
+}
+
+#[test]
+fn play_resolution_derives_missing_dimension() {
+    assert_eq!(
+        metadata(&[("PlayResX", "640")]).play_resolution(),
+        (640, 480)
+    );
+    assert_eq!(
+        metadata(&[("PlayResX", "1280")]).play_resolution(),
+        (1280, 1024)
+    );
+    assert_eq!(
+        metadata(&[("PlayResY", "720")]).play_resolution(),
+        (960, 720)
+    );
+    assert_eq!(
+        metadata(&[("PlayResY", "1024")]).play_resolution(),
+        (1280, 1024)
+    );
+    assert_eq!(metadata(&[]).play_resolution(), (384, 288));
+}
+
+#[test]
+fn wrap_mode_values_round_trip() {
+    for value in 0..4 {
+        assert_eq!(
+            WrapMode::from_value(value).map(WrapMode::value),
+            Some(value)
+        );
+    }
+    assert!(!WrapMode::NoWrap.wraps());
+    assert!(WrapMode::SmartBottom.balances());
+    assert!(!WrapMode::EndOfLine.balances());
+    assert_eq!(vec![WrapMode::default()], [WrapMode::Smart]);
+}
@@ -2,13 +2,21 @@
 //!
 //! Contains the `ScriptInfo` struct representing the [Script Info] section
 //! of ASS files with zero-copy design and convenient accessor methods
-//! for common metadata fields.
+//! for common metadata fields, and the typed `ScriptMetadata` view of its
+//! standard headers.
 
 use super::Span;
 
+mod header_values;
 mod info;
+mod metadata;
 
+#[cfg(test)]
+mod metadata_tests;
 #[cfg(test)]
 mod tests;
 
+pub use header_values::{WrapMode, YCbCrMatrix};
 pub use info::ScriptInfo;
+pub use metadata::ScriptMetadata;
+pub use metadata::METADATA_KEYS;
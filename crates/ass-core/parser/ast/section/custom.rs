@@ -0,0 +1,112 @@
+//! Custom section AST node for non-standard ASS sections.
+//!
+//! Defines [`CustomSection`], the content of sections such as
+//! `[Aegisub Project Garbage]` that are kept by a registered
+//! [`CustomSectionHandler`](crate::parser::CustomSectionHandler) instead of
+//! being dropped with a warning.
+
+use alloc::{string::String, vec::Vec};
+
+#[cfg(not(feature = "std"))]
+extern crate alloc;
+
+use crate::parser::ast::Span;
+#[cfg(debug_assertions)]
+use core::ops::Range;
+
+/// Non-standard section kept by a custom section handler
+///
+/// Stores the raw content lines, so the section is written back unchanged,
+/// together with the key-value pairs the handler extracted from them.
+///
+/// # Examples
+///
+/// ```rust
+/// use ass_core::parser::ast::CustomSection;
+///
+/// let section = CustomSection::from_key_values(
+///     "Aegisub Project Garbage",
+///     "Video File: clip.mkv\nActive Line: 3\n",
+/// );
+/// assert_eq!(section.get_field("Active Line"), Some("3"));
+/// assert_eq!(section.lines.len(), 2);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct CustomSection<'a> {
+    /// Section name from the header, without brackets
+    pub name: &'a str,
+    /// Key-value pairs extracted from the content
+    pub fields: Vec<(&'a str, &'a str)>,
+    /// Non-empty content lines in source order
+    pub lines: Vec<&'a str>,
+    /// Span in source text where this section's content is defined
+    pub span: Span,
+}
+
+impl<'a> CustomSection<'a> {
+    /// Build a section from `Key: Value` lines
+    ///
+    /// Every non-empty line is kept in [`lines`](Self::lines); lines with a
+    /// colon that are not `;` comments also become a trimmed field. The span
+    /// is left empty for the parser to fill in.
+    #[must_use]
+    pub fn from_key_values(name: &'a str, content: &'a str) -> Self {
+        let lines: Vec<&'a str> = content
+            .lines()
+            .map(|line| line.trim_end_matches('\r'))
+            .filter(|line| !line.trim().is_empty())
+            .collect();
+        let fields = lines
+            .iter()
+            .filter(|line| !line.trim_start().starts_with(';'))
+            .filter_map(|line| line.split_once(':'))
+            .map(|(key, value)| (key.trim(), value.trim()))
+            .collect();
+
+        Self {
+            name,
+            fields,
+            lines,
+            span: Span::new(0, 0, 0, 0),
+        }
+    }
+
+    /// Get field value by key (case-sensitive)
+    #[must_use]
+    pub fn get_field(&self, key: &str) -> Option<&'a str> {
+        self.fields.iter().find(|(k, _)| *k == key).map(|(_, v)| *v)
+    }
+
+    /// Convert section to ASS string representation
+    ///
+    /// Writes the header followed by the raw content lines.
+    #[must_use]
+    pub fn to_ass_string(&self) -> String {
+        let mut result = String::with_capacity(self.name.len() + 3);
+        result.push('[');
+        result.push_str(self.name);
+        result.push_str("]\n");
+        for line in &self.lines {
+            result.push_str(line);
+            result.push('\n');
+        }
+        result
+    }
+
+    /// Validate all spans in this section reference valid source
+    ///
+    /// Debug helper to ensure zero-copy invariants are maintained.
+    /// Only available in debug builds to avoid performance overhead.
+    #[cfg(debug_assertions)]
+    #[must_use]
+    pub fn validate_spans(&self, source_range: &Range<usize>) -> bool {
+        let in_range = |text: &str| source_range.contains(&(text.as_ptr() as usize));
+        in_range(self.name)
+            && self.lines.iter().all(|line| in_range(line))
+            && self
+                .fields
+                .iter()
+                .all(|(key, value)| in_range(key) && in_range(value))
+    }
+}
@@ -4,11 +4,13 @@
 //! of an ASS script ([Script Info], [V4+ Styles], [Events], etc.) with
 //! zero-copy design and span validation for debugging.
 
+mod custom;
 mod section_enum;
 mod section_type;
 
 #[cfg(test)]
 mod section_tests;
 
+pub use custom::CustomSection;
 pub use section_enum::Section;
 pub use section_type::SectionType;
@@ -9,6 +9,7 @@ use alloc::vec::Vec;
 #[cfg(not(feature = "std"))]
 extern crate alloc;
 
+use super::CustomSection;
 use super::SectionType;
 use crate::parser::ast::{Event, Font, Graphic, ScriptInfo, Span, Style};
 #[cfg(debug_assertions)]
@@ -29,7 +30,7 @@ use core::ops::Range;
 /// let section = Section::ScriptInfo(info);
 /// assert_eq!(section.section_type(), SectionType::ScriptInfo);
 /// ```
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum Section<'a> {
     /// [Script Info] section with metadata
@@ -62,6 +63,13 @@ pub enum Section<'a> {
     /// Contains UU-encoded image files embedded in the script.
     /// Used for logos, textures, and other graphical elements.
     Graphics(Vec<Graphic<'a>>),
+
+    /// Non-standard section such as `[Aegisub Project Garbage]`
+    ///
+    /// Only produced when a registered
+    /// [`CustomSectionHandler`](crate::parser::CustomSectionHandler)
+    /// accepts the section; otherwise unknown sections are skipped.
+    Custom(CustomSection<'a>),
 }
 
 impl Section<'_> {
@@ -113,6 +121,7 @@ impl Section<'_> {
                     Some(Span::new(first.start, last.end, first.line, first.column))
                 }
             }
+            Section::Custom(custom) => Some(custom.span),
         }
     }
 
@@ -136,6 +145,28 @@ impl Section<'_> {
             Section::Events(_) => SectionType::Events,
             Section::Fonts(_) => SectionType::Fonts,
             Section::Graphics(_) => SectionType::Graphics,
+            Section::Custom(_) => SectionType::Custom,
+        }
+    }
+
+    /// Get the header name written between the brackets
+    ///
+    /// Standard sections use their canonical [`SectionType::header_name`];
+    /// custom sections return the name they were parsed with.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ass_core::parser::ast::{CustomSection, Section};
+    /// let custom = Section::Custom(CustomSection::from_key_values("Aegisub Extradata", ""));
+    /// assert_eq!(custom.header_name(), "Aegisub Extradata");
+    /// assert_eq!(Section::Events(Vec::new()).header_name(), "Events");
+    /// ```
+    #[must_use]
+    pub const fn header_name(&self) -> &str {
+        match self {
+            Section::Custom(custom) => custom.name,
+            _ => self.section_type().header_name(),
         }
     }
 
@@ -164,6 +195,7 @@ impl Section<'_> {
             Section::Events(events) => events.iter().all(|e| e.validate_spans(source_range)),
             Section::Fonts(fonts) => fonts.iter().all(|f| f.validate_spans(source_range)),
             Section::Graphics(graphics) => graphics.iter().all(|g| g.validate_spans(source_range)),
+            Section::Custom(custom) => custom.validate_spans(source_range),
         }
     }
 }
@@ -31,6 +31,8 @@ pub enum SectionType {
     Fonts,
     /// `[Graphics\]` section identifier
     Graphics,
+    /// Non-standard section kept by a custom section handler
+    Custom,
 }
 
 impl SectionType {
@@ -54,6 +56,8 @@ impl SectionType {
             Self::Events => "Events",
             Self::Fonts => "Fonts",
             Self::Graphics => "Graphics",
+            // The actual header is stored in `CustomSection::name`
+            Self::Custom => "Custom",
         }
     }
 
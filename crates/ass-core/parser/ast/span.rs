@@ -4,7 +4,7 @@
 //! position information for nodes referencing the original source text.
 
 /// Represents a span in the source text with position information
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct Span {
     /// Byte offset in source where span starts
@@ -27,7 +27,7 @@ use super::super::Span;
 /// assert_eq!(style.name, "Default");
 /// assert_eq!(style.fontname, "Arial");
 /// ```
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct Style<'a> {
     /// Style name (must be unique within script)
@@ -5,6 +5,7 @@
 
 mod conversion;
 mod definition;
+mod owned;
 
 #[cfg(test)]
 mod construction_tests;
@@ -20,3 +21,4 @@ mod field_tests;
 mod validation_tests;
 
 pub use definition::Style;
+pub use owned::OwnedStyle;
@@ -0,0 +1,193 @@
+//! Owned counterpart of the zero-copy [`Style`]
+//!
+//! Defines [`OwnedStyle`], which holds every style field as a `String` so it
+//! can be edited in place inside an [`OwnedScript`] without borrowing from a
+//! source buffer.
+//!
+//! [`OwnedScript`]: crate::parser::OwnedScript
+
+use alloc::string::String;
+
+use super::super::Span;
+use super::Style;
+
+/// Style with owned field text
+///
+/// Mirrors [`Style`] field for field. Convert from a parsed style with
+/// [`From`], and borrow it back as a [`Style`] with [`OwnedStyle::as_style`].
+///
+/// # Examples
+///
+/// ```rust
+/// use ass_core::parser::ast::{OwnedStyle, Style};
+///
+/// let mut style = OwnedStyle::from(&Style::default());
+/// style.fontname = "Verdana".into();
+/// assert_eq!(style.as_style().fontname, "Verdana");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct OwnedStyle {
+    /// Style name (must be unique within script)
+    pub name: String,
+
+    /// Parent style name for inheritance (None if no inheritance)
+    pub parent: Option<String>,
+
+    /// Font name for text rendering
+    pub fontname: String,
+
+    /// Font size in points
+    pub fontsize: String,
+
+    /// Primary color in BGR format (&HBBGGRR)
+    pub primary_colour: String,
+
+    /// Secondary color for collision effects
+    pub secondary_colour: String,
+
+    /// Outline color
+    pub outline_colour: String,
+
+    /// Shadow/background color
+    pub back_colour: String,
+
+    /// Bold flag (-1/0 or weight)
+    pub bold: String,
+
+    /// Italic flag (0/1)
+    pub italic: String,
+
+    /// Underline flag (0/1)
+    pub underline: String,
+
+    /// Strikeout flag (0/1)
+    pub strikeout: String,
+
+    /// Horizontal scale percentage
+    pub scale_x: String,
+
+    /// Vertical scale percentage
+    pub scale_y: String,
+
+    /// Character spacing in pixels
+    pub spacing: String,
+
+    /// Rotation angle in degrees
+    pub angle: String,
+
+    /// Border style (1=outline+shadow, 3=opaque box)
+    pub border_style: String,
+
+    /// Outline width in pixels
+    pub outline: String,
+
+    /// Shadow depth in pixels
+    pub shadow: String,
+
+    /// Alignment (1-3 + 4/8 for vertical positioning)
+    pub alignment: String,
+
+    /// Left margin in pixels
+    pub margin_l: String,
+
+    /// Right margin in pixels
+    pub margin_r: String,
+
+    /// Vertical margin in pixels (V4+)
+    pub margin_v: String,
+
+    /// Top margin in pixels (V4++)
+    pub margin_t: Option<String>,
+
+    /// Bottom margin in pixels (V4++)
+    pub margin_b: Option<String>,
+
+    /// Font encoding identifier
+    pub encoding: String,
+
+    /// Positioning context (V4++)
+    pub relative_to: Option<String>,
+
+    /// Span in source text where this style is defined
+    pub span: Span,
+}
+
+impl OwnedStyle {
+    /// Borrow this style as a zero-copy [`Style`]
+    #[must_use]
+    pub fn as_style(&self) -> Style<'_> {
+        Style {
+            name: &self.name,
+            parent: self.parent.as_deref(),
+            fontname: &self.fontname,
+            fontsize: &self.fontsize,
+            primary_colour: &self.primary_colour,
+            secondary_colour: &self.secondary_colour,
+            outline_colour: &self.outline_colour,
+            back_colour: &self.back_colour,
+            bold: &self.bold,
+            italic: &self.italic,
+            underline: &self.underline,
+            strikeout: &self.strikeout,
+            scale_x: &self.scale_x,
+            scale_y: &self.scale_y,
+            spacing: &self.spacing,
+            angle: &self.angle,
+            border_style: &self.border_style,
+            outline: &self.outline,
+            shadow: &self.shadow,
+            alignment: &self.alignment,
+            margin_l: &self.margin_l,
+            margin_r: &self.margin_r,
+            margin_v: &self.margin_v,
+            margin_t: self.margin_t.as_deref(),
+            margin_b: self.margin_b.as_deref(),
+            encoding: &self.encoding,
+            relative_to: self.relative_to.as_deref(),
+            span: self.span,
+        }
+    }
+}
+
+impl From<&Style<'_>> for OwnedStyle {
+    fn from(style: &Style<'_>) -> Self {
+        Self {
+            name: style.name.into(),
+            parent: style.parent.map(String::from),
+            fontname: style.fontname.into(),
+            fontsize: style.fontsize.into(),
+            primary_colour: style.primary_colour.into(),
+            secondary_colour: style.secondary_colour.into(),
+            outline_colour: style.outline_colour.into(),
+            back_colour: style.back_colour.into(),
+            bold: style.bold.into(),
+            italic: style.italic.into(),
+            underline: style.underline.into(),
+            strikeout: style.strikeout.into(),
+            scale_x: style.scale_x.into(),
+            scale_y: style.scale_y.into(),
+            spacing: style.spacing.into(),
+            angle: style.angle.into(),
+            border_style: style.border_style.into(),
+            outline: style.outline.into(),
+            shadow: style.shadow.into(),
+            alignment: style.alignment.into(),
+            margin_l: style.margin_l.into(),
+            margin_r: style.margin_r.into(),
+            margin_v: style.margin_v.into(),
+            margin_t: style.margin_t.map(String::from),
+            margin_b: style.margin_b.map(String::from),
+            encoding: style.encoding.into(),
+            relative_to: style.relative_to.map(String::from),
+            span: style.span,
+        }
+    }
+}
+
+impl Default for OwnedStyle {
+    /// Owned copy of [`Style::default`]
+    fn default() -> Self {
+        Self::from(&Style::default())
+    }
+}
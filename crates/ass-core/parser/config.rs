@@ -0,0 +1,55 @@
+//! Resource limits applied while parsing.
+//!
+//! [`ParserConfig`] bounds how much input the parser accepts and how many
+//! styles and events it keeps, and controls whether embedded `[Fonts]` and
+//! `[Graphics]` data is retained. The defaults suit desktop use; hosts
+//! parsing untrusted input in constrained environments should tighten them.
+
+/// Limits and switches for [`Script::parse_with_config`]
+///
+/// Input larger than `max_input_bytes` is rejected with a security issue and
+/// an empty script. Styles and events past their limits are dropped with a
+/// security issue, and disallowed embedded sections are skipped with a
+/// warning.
+///
+/// [`Script::parse_with_config`]: crate::parser::Script::parse_with_config
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ParserConfig {
+    /// Largest accepted input, in bytes
+    pub max_input_bytes: usize,
+    /// Most events kept across all `[Events]` sections
+    pub max_events: usize,
+    /// Most styles kept across all style sections
+    pub max_styles: usize,
+    /// Whether `[Fonts]` sections are parsed
+    pub allow_embedded_fonts: bool,
+    /// Whether `[Graphics]` sections are parsed
+    pub allow_embedded_graphics: bool,
+}
+
+impl ParserConfig {
+    /// Default input size limit (50MB)
+    pub const DEFAULT_MAX_INPUT_BYTES: usize = 50 * 1024 * 1024;
+    /// Default event limit
+    pub const DEFAULT_MAX_EVENTS: usize = 100_000;
+    /// Default style limit
+    pub const DEFAULT_MAX_STYLES: usize = 10_000;
+
+    /// Create the default configuration
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            max_input_bytes: Self::DEFAULT_MAX_INPUT_BYTES,
+            max_events: Self::DEFAULT_MAX_EVENTS,
+            max_styles: Self::DEFAULT_MAX_STYLES,
+            allow_embedded_fonts: true,
+            allow_embedded_graphics: true,
+        }
+    }
+}
+
+impl Default for ParserConfig {
+    fn default() -> Self {
+        Self::new()
+    }
+}
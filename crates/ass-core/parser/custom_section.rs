@@ -0,0 +1,55 @@
+//! Handlers for non-standard ASS sections.
+//!
+//! Defines [`CustomSectionHandler`], which lets downstream crates keep
+//! proprietary sections such as `[Aegisub Project Garbage]` or
+//! `[Aegisub Extradata]` as [`Section::Custom`] instead of having the parser
+//! skip them with an "Unknown section" warning.
+//!
+//! # Example
+//!
+//! ```rust
+//! use ass_core::parser::{ast::CustomSection, CustomSectionHandler, Script, Section};
+//!
+//! struct GarbageHandler;
+//!
+//! impl CustomSectionHandler for GarbageHandler {
+//!     fn handles(&self, name: &str) -> bool {
+//!         name == "Aegisub Project Garbage"
+//!     }
+//!
+//!     fn parse<'a>(&self, name: &'a str, content: &'a str) -> Section<'a> {
+//!         Section::Custom(CustomSection::from_key_values(name, content))
+//!     }
+//! }
+//!
+//! let source = "[Script Info]\nTitle: Test\n\n[Aegisub Project Garbage]\nActive Line: 2\n";
+//! let script = Script::builder()
+//!     .with_section_handler(Box::new(GarbageHandler))
+//!     .parse(source)?;
+//! assert!(script.issues().is_empty());
+//! # Ok::<(), ass_core::CoreError>(())
+//! ```
+
+use crate::parser::ast::Section;
+
+/// Parser for sections the core parser does not know
+///
+/// Register handlers with
+/// [`ScriptBuilder::with_section_handler`](crate::parser::script::ScriptBuilder::with_section_handler).
+/// When the parser meets a section header it does not recognize, it asks
+/// each handler in registration order and lets the first one that
+/// [`handles`](Self::handles) the name parse the section. Sections no
+/// handler accepts are skipped with a warning, as without handlers.
+pub trait CustomSectionHandler: Send + Sync {
+    /// Whether this handler parses sections named `name`
+    ///
+    /// `name` is the header text between the brackets, trimmed.
+    fn handles(&self, name: &str) -> bool;
+
+    /// Parse the content of a section accepted by [`handles`](Self::handles)
+    ///
+    /// `content` spans from the line after the header up to the next
+    /// section header or end of input. The parser fills in the span of a
+    /// returned [`Section::Custom`].
+    fn parse<'a>(&self, name: &'a str, content: &'a str) -> Section<'a>;
+}
@@ -0,0 +1,48 @@
+//! Line-based addressing for incremental script patches
+
+use core::ops::Range;
+
+/// Half-open range of 0-based line indices in a script
+///
+/// Used by [`OwnedScript::apply_patch`](crate::parser::OwnedScript::apply_patch) to
+/// name the lines an edit replaces. `start == end` describes an insertion
+/// before line `start`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct LineRange {
+    /// First line covered by the range (0-based)
+    pub start: usize,
+    /// Line after the last line covered by the range (0-based, exclusive)
+    pub end: usize,
+}
+
+impl LineRange {
+    /// Create a new line range
+    #[must_use]
+    pub const fn new(start: usize, end: usize) -> Self {
+        Self { start, end }
+    }
+
+    /// Range covering the single line `line`
+    #[must_use]
+    pub const fn line(line: usize) -> Self {
+        Self::new(line, line + 1)
+    }
+
+    /// Number of lines covered
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.end.saturating_sub(self.start)
+    }
+
+    /// Whether the range covers no lines
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.end <= self.start
+    }
+}
+
+impl From<Range<usize>> for LineRange {
+    fn from(range: Range<usize>) -> Self {
+        Self::new(range.start, range.end)
+    }
+}
@@ -1,6 +1,7 @@
 //! Incremental parsing utilities for efficient text updates
 
 mod line_calc;
+mod line_range;
 mod range_adjust;
 mod section_bounds;
 
@@ -8,5 +9,6 @@ mod section_bounds;
 mod tests;
 
 pub use line_calc::{calculate_line_number, calculate_line_range};
+pub use line_range::LineRange;
 pub use range_adjust::{adjust_range_for_change, TextChange};
 pub use section_bounds::{find_section_end, find_section_header_start};
@@ -20,6 +20,8 @@ pub fn find_section_header_start(
         SectionType::Events => "[Events]",
         SectionType::Fonts => "[Fonts]",
         SectionType::Graphics => "[Graphics]",
+        // Custom section headers vary and are not known from the type alone
+        SectionType::Custom => return Err(ParseError::SectionNotFound),
     };
 
     // Search backwards from start_hint for the header
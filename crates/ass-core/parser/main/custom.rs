@@ -0,0 +1,43 @@
+//! Dispatch of unknown sections to registered custom section handlers.
+//!
+//! Implements the `Parser::parse_custom_section` helper, which hands the
+//! content of an unknown section to the first
+//! [`CustomSectionHandler`](crate::parser::CustomSectionHandler) accepting
+//! its name.
+
+use super::Parser;
+use crate::parser::ast::{Section, Span};
+
+impl<'a> Parser<'a> {
+    /// Parse an unknown section with the first handler accepting its name
+    ///
+    /// Returns `None`, without consuming input, if no handler accepts it.
+    pub(super) fn parse_custom_section(
+        &mut self,
+        section_name: &'a str,
+        start_line: usize,
+    ) -> Option<Section<'a>> {
+        let name = section_name.trim();
+        let index = self
+            .section_handlers
+            .iter()
+            .position(|handler| handler.handles(name))?;
+
+        let start = self.position;
+        while self.position < self.source.len() && !self.at_next_section() {
+            self.skip_line();
+        }
+        let content = &self.source[start..self.position];
+
+        let mut section = self.section_handlers[index].parse(name, content);
+        if let Section::Custom(custom) = &mut section {
+            custom.span = Span::new(
+                start,
+                self.position,
+                u32::try_from(start_line).unwrap_or(u32::MAX),
+                1,
+            );
+        }
+        Some(section)
+    }
+}
@@ -8,6 +8,11 @@ use super::Parser;
 use alloc::string::{String, ToString};
 
 impl Parser<'_> {
+    /// Line number (1-based) of the current position
+    pub(super) fn line(&self) -> usize {
+        self.line_index.line_of_offset(self.position)
+    }
+
     /// Check if at start of next section
     pub(super) fn at_next_section(&self) -> bool {
         let remaining = self.source[self.position..].trim_start();
@@ -26,7 +31,6 @@ impl Parser<'_> {
     pub(super) fn skip_line(&mut self) {
         if let Some(newline_pos) = self.source[self.position..].find('\n') {
             self.position += newline_pos + 1;
-            self.line += 1;
         } else {
             self.position = self.source.len();
         }
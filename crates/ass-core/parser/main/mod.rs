@@ -3,15 +3,19 @@
 //! Contains the core `Parser` struct that orchestrates parsing of different
 //! ASS script sections and handles error recovery.
 
+mod custom;
 mod helpers;
 mod parse;
 mod registry;
 mod section;
 mod state;
+mod text_issues;
 
 #[cfg(test)]
 mod tests_basic;
 #[cfg(test)]
+mod tests_custom;
+#[cfg(test)]
 mod tests_edge;
 #[cfg(test)]
 mod tests_errors;
@@ -1,11 +1,14 @@
 //! Top-level script parsing driver.
 //!
 //! Implements [`Parser::parse`], which validates input limits and BOM handling,
-//! then iterates over sections, recording issues and recovering from errors.
+//! then iterates over sections, recording issues and recovering from errors
+//! while enforcing the configured [`ParserConfig`](crate::parser::ParserConfig)
+//! limits, and finally adds the tokenizer issues of every event's text.
 
 use super::Parser;
 use crate::{
     parser::{
+        ast::Section,
         errors::{IssueCategory, IssueSeverity, ParseIssue},
         script::Script,
     },
@@ -16,14 +19,13 @@ use alloc::{format, string::ToString, vec::Vec};
 impl<'a> Parser<'a> {
     /// Parse complete script
     pub fn parse(mut self) -> Script<'a> {
-        // Check input size limit to prevent DoS attacks (50MB limit)
-        const MAX_INPUT_SIZE: usize = 50 * 1024 * 1024; // 50MB
-        if let Err(e) = check_input_size_limit(self.source.len(), MAX_INPUT_SIZE) {
+        // Check input size limit to prevent DoS attacks
+        if let Err(e) = check_input_size_limit(self.source.len(), self.config.max_input_bytes) {
             self.issues.push(ParseIssue::new(
                 IssueSeverity::Error,
                 IssueCategory::Security,
                 format!("Input size limit exceeded: {e}"),
-                self.line,
+                self.line(),
             ));
             // Return early with empty script for security
             return Script::from_parts(
@@ -33,7 +35,8 @@ impl<'a> Parser<'a> {
                 self.issues,
                 self.styles_format,
                 self.events_format,
-            );
+            )
+            .with_parse_config(self.config);
         }
 
         // Validate and handle BOM if present
@@ -42,7 +45,7 @@ impl<'a> Parser<'a> {
                 IssueSeverity::Warning,
                 IssueCategory::Format,
                 format!("BOM validation warning: {e}"),
-                self.line,
+                self.line(),
             ));
         }
 
@@ -59,7 +62,11 @@ impl<'a> Parser<'a> {
             }
 
             match self.parse_section() {
-                Ok(section) => self.sections.push(section),
+                Ok(section) => {
+                    if let Some(section) = self.apply_limits(section) {
+                        self.sections.push(section);
+                    }
+                }
                 Err(e) => {
                     let (severity, message) = if e.to_string().contains("Unknown section") {
                         (IssueSeverity::Warning, e.to_string())
@@ -74,13 +81,14 @@ impl<'a> Parser<'a> {
                         severity,
                         IssueCategory::Structure,
                         message,
-                        self.line,
+                        self.line(),
                     ));
 
                     self.skip_to_next_section();
                 }
             }
         }
+        self.collect_text_issues();
 
         Script::from_parts(
             self.source,
@@ -90,5 +98,91 @@ impl<'a> Parser<'a> {
             self.styles_format,
             self.events_format,
         )
+        .with_parse_config(self.config)
+    }
+
+    /// Enforce the configured style, event and embedded data limits on a
+    /// freshly parsed section
+    ///
+    /// Returns `None` when the section is dropped entirely.
+    fn apply_limits(&mut self, section: Section<'a>) -> Option<Section<'a>> {
+        let line = self.line();
+        match section {
+            Section::Styles(mut styles) => {
+                let kept = self.count_styles();
+                let allowed = self.config.max_styles.saturating_sub(kept);
+                if styles.len() > allowed {
+                    styles.truncate(allowed);
+                    self.issues.push(ParseIssue::new(
+                        IssueSeverity::Error,
+                        IssueCategory::Security,
+                        format!(
+                            "Style limit of {} exceeded, extra styles dropped",
+                            self.config.max_styles
+                        ),
+                        line,
+                    ));
+                }
+                Some(Section::Styles(styles))
+            }
+            Section::Events(mut events) => {
+                let kept = self.count_events();
+                let allowed = self.config.max_events.saturating_sub(kept);
+                if events.len() > allowed {
+                    events.truncate(allowed);
+                    self.issues.push(ParseIssue::new(
+                        IssueSeverity::Error,
+                        IssueCategory::Security,
+                        format!(
+                            "Event limit of {} exceeded, extra events dropped",
+                            self.config.max_events
+                        ),
+                        line,
+                    ));
+                }
+                Some(Section::Events(events))
+            }
+            Section::Fonts(_) if !self.config.allow_embedded_fonts => {
+                self.issues.push(ParseIssue::new(
+                    IssueSeverity::Warning,
+                    IssueCategory::Security,
+                    "Embedded fonts are disabled, [Fonts] section skipped".to_string(),
+                    line,
+                ));
+                None
+            }
+            Section::Graphics(_) if !self.config.allow_embedded_graphics => {
+                self.issues.push(ParseIssue::new(
+                    IssueSeverity::Warning,
+                    IssueCategory::Security,
+                    "Embedded graphics are disabled, [Graphics] section skipped".to_string(),
+                    line,
+                ));
+                None
+            }
+            section => Some(section),
+        }
+    }
+
+    /// Number of styles in the sections parsed so far
+    fn count_styles(&self) -> usize {
+        self.sections
+            .iter()
+            .map(|section| match section {
+                Section::Styles(styles) => styles.len(),
+                _ => 0,
+            })
+            .sum()
+    }
+
+    /// Number of events in the sections parsed so far
+    fn count_events(&self) -> usize {
+        self.sections
+            .iter()
+            .map(|section| match section {
+                Section::Events(events) => events.len(),
+                _ => 0,
+            })
+            .sum()
     }
 }
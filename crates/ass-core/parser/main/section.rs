@@ -22,24 +22,24 @@ impl<'a> Parser<'a> {
     pub(super) fn parse_section(&mut self) -> Result<Section<'a>> {
         if !self.source[self.position..].starts_with('[') {
             return Err(CoreError::from(ParseError::ExpectedSectionHeader {
-                line: self.line,
+                line: self.line(),
             }));
         }
 
         let header_end = self.source[self.position..].find(']').ok_or_else(|| {
-            CoreError::from(ParseError::UnclosedSectionHeader { line: self.line })
+            CoreError::from(ParseError::UnclosedSectionHeader { line: self.line() })
         })? + self.position;
 
         let section_name = &self.source[self.position + 1..header_end];
         self.position = header_end + 1;
         self.skip_line();
 
-        let start_line = self.line;
+        let start_line = self.line();
 
         match section_name.trim() {
             "Script Info" => {
                 let parser = ScriptInfoParser::new(self.source, self.position, start_line);
-                let (section, detected_version, issues, final_position, final_line) =
+                let (section, detected_version, issues, final_position, _) =
                     parser.parse().map_err(CoreError::from)?;
 
                 // Update parser state
@@ -48,57 +48,56 @@ impl<'a> Parser<'a> {
                 }
                 self.issues.extend(issues);
                 self.position = final_position;
-                self.line = final_line;
 
                 Ok(section)
             }
             "V4+ Styles" | "V4 Styles" | "V4++ Styles" => {
                 let parser = StylesParser::new(self.source, self.position, start_line);
-                let (section, format, issues, final_position, final_line) =
+                let (section, format, issues, final_position, _) =
                     parser.parse().map_err(CoreError::from)?;
 
                 // Update parser state
                 self.styles_format = format;
                 self.issues.extend(issues);
                 self.position = final_position;
-                self.line = final_line;
 
                 Ok(section)
             }
             "Events" => {
                 let parser = EventsParser::new(self.source, self.position, start_line);
-                let (section, format, issues, final_position, final_line) =
+                let (section, format, issues, final_position, _) =
                     parser.parse().map_err(CoreError::from)?;
 
                 // Update parser state
                 self.events_format = format;
                 self.issues.extend(issues);
                 self.position = final_position;
-                self.line = final_line;
 
                 Ok(section)
             }
             "Fonts" => {
-                let (section, final_position, final_line) =
+                let (section, final_position, _) =
                     FontsParser::parse(self.source, self.position, start_line);
 
                 // Update parser state
                 self.position = final_position;
-                self.line = final_line;
 
                 Ok(section)
             }
             "Graphics" => {
-                let (section, final_position, final_line) =
+                let (section, final_position, _) =
                     GraphicsParser::parse(self.source, self.position, start_line);
 
                 // Update parser state
                 self.position = final_position;
-                self.line = final_line;
 
                 Ok(section)
             }
             _ => {
+                if let Some(section) = self.parse_custom_section(section_name, start_line) {
+                    return Ok(section);
+                }
+
                 #[cfg(feature = "plugins")]
                 if self.registry.is_some() {
                     // Try to process unknown section with registered processors
@@ -110,7 +109,7 @@ impl<'a> Parser<'a> {
                 let suggestion = self.skip_to_next_section();
                 let error = ParseError::UnknownSection {
                     section: section_name.to_string(),
-                    line: self.line,
+                    line: self.line(),
                 };
 
                 // Add suggestion to issues if we found one
@@ -119,7 +118,7 @@ impl<'a> Parser<'a> {
                         severity: IssueSeverity::Info,
                         category: IssueCategory::Structure,
                         message: suggestion_text,
-                        line: self.line,
+                        line: self.line(),
                         column: Some(0),
                         span: None,
                         suggestion: None,
@@ -5,10 +5,11 @@
 //! construction entry points.
 
 use crate::{
-    parser::{ast::Section, errors::ParseIssue},
+    parser::{ast::Section, errors::ParseIssue, CustomSectionHandler, ParserConfig},
+    utils::LineIndex,
     ScriptVersion,
 };
-use alloc::vec::Vec;
+use alloc::{boxed::Box, vec::Vec};
 
 #[cfg(feature = "plugins")]
 use crate::plugin::ExtensionRegistry;
@@ -19,8 +20,8 @@ pub(in crate::parser) struct Parser<'a> {
     pub(super) source: &'a str,
     /// Current byte position in source
     pub(super) position: usize,
-    /// Current line number for error reporting
-    pub(super) line: usize,
+    /// Newline positions of the source, for locating issues
+    pub(super) line_index: LineIndex,
     /// Detected script version
     pub(super) version: ScriptVersion,
     /// Parsed sections accumulated so far
@@ -34,15 +35,19 @@ pub(in crate::parser) struct Parser<'a> {
     /// Extension registry for custom tag handlers and section processors
     #[cfg(feature = "plugins")]
     pub(super) registry: Option<&'a ExtensionRegistry>,
+    /// Handlers for sections the parser does not know
+    pub(super) section_handlers: Vec<Box<dyn CustomSectionHandler>>,
+    /// Resource limits applied while parsing
+    pub(super) config: ParserConfig,
 }
 
 impl<'a> Parser<'a> {
     /// Create new parser for source text
-    pub const fn new(source: &'a str) -> Self {
+    pub fn new(source: &'a str) -> Self {
         Self {
             source,
             position: 0,
-            line: 1,
+            line_index: LineIndex::new(source),
             version: ScriptVersion::AssV4, // Default, updated when ScriptType found
             sections: Vec::new(),
             issues: Vec::new(),
@@ -50,25 +55,40 @@ impl<'a> Parser<'a> {
             events_format: None,
             #[cfg(feature = "plugins")]
             registry: None,
+            section_handlers: Vec::new(),
+            config: ParserConfig::new(),
         }
     }
 
     /// Create new parser with extension registry
     #[cfg(feature = "plugins")]
-    pub const fn new_with_registry(
-        source: &'a str,
-        registry: Option<&'a ExtensionRegistry>,
-    ) -> Self {
+    pub fn new_with_registry(source: &'a str, registry: Option<&'a ExtensionRegistry>) -> Self {
         Self {
             source,
             position: 0,
-            line: 1,
+            line_index: LineIndex::new(source),
             version: ScriptVersion::AssV4, // Default, updated when ScriptType found
             sections: Vec::new(),
             issues: Vec::new(),
             styles_format: None,
             events_format: None,
             registry,
+            section_handlers: Vec::new(),
+            config: ParserConfig::new(),
         }
     }
+
+    /// Set the handlers consulted for unknown sections, in priority order
+    #[must_use]
+    pub fn with_section_handlers(mut self, handlers: Vec<Box<dyn CustomSectionHandler>>) -> Self {
+        self.section_handlers = handlers;
+        self
+    }
+
+    /// Set the resource limits applied while parsing
+    #[must_use]
+    pub const fn with_config(mut self, config: ParserConfig) -> Self {
+        self.config = config;
+        self
+    }
 }
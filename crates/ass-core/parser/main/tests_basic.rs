@@ -12,7 +12,7 @@ fn parser_new() {
     let parser = Parser::new(source);
     assert_eq!(parser.source, source);
     assert_eq!(parser.position, 0);
-    assert_eq!(parser.line, 1);
+    assert_eq!(parser.line(), 1);
     assert_eq!(parser.version, ScriptVersion::AssV4);
     assert!(parser.sections.is_empty());
     assert!(parser.issues.is_empty());
@@ -0,0 +1,77 @@
+//! Tests for dispatching unknown sections to custom section handlers.
+
+use super::*;
+use crate::parser::ast::{CustomSection, Section};
+use crate::parser::CustomSectionHandler;
+use alloc::{boxed::Box, vec};
+
+const SOURCE: &str = "[Script Info]
+Title: Custom
+
+[Aegisub Project Garbage]
+Video File: clip.mkv
+Active Line: 3
+
+[Events]
+Format: Layer, Start, End, Style, Name, MarginL, MarginR, MarginV, Effect, Text
+Dialogue: 0,0:00:00.00,0:00:01.00,Default,,0,0,0,,Hi
+";
+
+/// Keeps `[Aegisub Project Garbage]` as key-value pairs
+struct GarbageHandler;
+
+impl CustomSectionHandler for GarbageHandler {
+    fn handles(&self, name: &str) -> bool {
+        name == "Aegisub Project Garbage"
+    }
+
+    fn parse<'a>(&self, name: &'a str, content: &'a str) -> Section<'a> {
+        Section::Custom(CustomSection::from_key_values(name, content))
+    }
+}
+
+#[test]
+fn unknown_section_is_skipped_without_handler() {
+    let script = Parser::new(SOURCE).parse();
+    assert_eq!(script.sections().len(), 2);
+    assert_eq!(script.issues().len(), 1);
+    assert_eq!(script.issues()[0].severity, IssueSeverity::Warning);
+}
+
+#[test]
+fn handler_keeps_custom_section_fields() {
+    let script = Parser::new(SOURCE)
+        .with_section_handlers(vec![Box::new(GarbageHandler)])
+        .parse();
+    assert!(script.issues().is_empty());
+    assert_eq!(script.sections().len(), 3);
+
+    let Section::Custom(custom) = &script.sections()[1] else {
+        panic!("expected custom section, got {:?}", script.sections()[1]);
+    };
+    assert_eq!(custom.name, "Aegisub Project Garbage");
+    assert_eq!(custom.get_field("Video File"), Some("clip.mkv"));
+    assert_eq!(custom.get_field("Active Line"), Some("3"));
+    assert_eq!(custom.span.line, 5);
+    assert!(SOURCE[custom.span.start..custom.span.end].starts_with("Video File"));
+    assert!(matches!(script.sections()[2], Section::Events(_)));
+}
+
+#[test]
+fn handler_sections_round_trip() {
+    let script = Parser::new(SOURCE)
+        .with_section_handlers(vec![Box::new(GarbageHandler)])
+        .parse();
+    let text = script.to_ass_string();
+    assert!(text.contains("[Aegisub Project Garbage]\nVideo File: clip.mkv\nActive Line: 3\n"));
+}
+
+#[test]
+fn handler_declining_name_keeps_warning() {
+    let source = SOURCE.replace("Aegisub Project Garbage", "Aegisub Extradata");
+    let script = Parser::new(&source)
+        .with_section_handlers(vec![Box::new(GarbageHandler)])
+        .parse();
+    assert_eq!(script.sections().len(), 2);
+    assert!(script.issues()[0].message.contains("Unknown section"));
+}
@@ -169,3 +169,32 @@ fn parser_version_detection_edge_cases() {
     // Should handle malformed script type gracefully
     assert!(!script.sections().is_empty());
 }
+
+#[test]
+fn parser_collects_tokenizer_issues_from_event_text() {
+    let content = "[Events]\nFormat: Start, End, Text\nDialogue: 0:00:00.00,0:00:01.00,{\\b1}Fine\nDialogue: 0:00:01.00,0:00:02.00,Bad\u{1}{\\i1 open\n";
+    let script = Parser::new(content).parse();
+    let issues: alloc::vec::Vec<_> = script
+        .issues()
+        .iter()
+        .filter(|issue| issue.category == crate::parser::errors::IssueCategory::Event)
+        .collect();
+    assert_eq!(issues.len(), 2);
+
+    let control = issues
+        .iter()
+        .find(|issue| issue.message.contains("Non-printable character U+0001"))
+        .unwrap();
+    assert_eq!(control.severity, IssueSeverity::Warning);
+    assert_eq!(control.line, 4);
+    assert_eq!(control.column, Some(36));
+    let (start, end) = control.span.unwrap();
+    assert_eq!(&content[start..end], "\u{1}");
+
+    let unclosed = issues
+        .iter()
+        .find(|issue| issue.message.contains("Unclosed override block"))
+        .unwrap();
+    assert_eq!(unclosed.severity, IssueSeverity::Warning);
+    assert_eq!(unclosed.line, 4);
+}
@@ -0,0 +1,94 @@
+//! Tokenizer diagnostics for event text.
+//!
+//! Runs the [`AssTokenizer`] over the text of every parsed event and turns
+//! the [`TokenIssue`]s it reports, such as unclosed override blocks or
+//! non-printable characters, into [`ParseIssue`]s located in the script.
+
+use super::Parser;
+use crate::{
+    parser::{
+        ast::Section,
+        errors::{IssueCategory, IssueSeverity, ParseIssue},
+    },
+    tokenizer::{AssTokenizer, IssueLevel, TokenIssue, MAX_OVERRIDE_BLOCK_BYTES},
+};
+use alloc::vec::Vec;
+
+impl Parser<'_> {
+    /// Record the tokenizer issues of all event texts parsed so far
+    pub(super) fn collect_text_issues(&mut self) {
+        let base = self.source.as_ptr() as usize;
+        let source_end = base + self.source.len();
+        let texts: Vec<&str> = self
+            .sections
+            .iter()
+            .filter_map(|section| match section {
+                Section::Events(events) => Some(events.iter().map(|event| event.text)),
+                _ => None,
+            })
+            .flatten()
+            .filter(|text| may_have_issues(text))
+            .collect();
+
+        for text in texts {
+            let offset = text.as_ptr() as usize;
+            if !(base..source_end).contains(&offset) {
+                continue;
+            }
+            let offset = offset - base;
+
+            let mut tokenizer = AssTokenizer::for_fragment(text);
+            // Issues found before a tokenizer error are still reported
+            while let Ok(Some(_)) = tokenizer.next_token() {}
+            for issue in tokenizer.issues() {
+                let issue = self.text_issue(issue, offset, text);
+                self.issues.push(issue);
+            }
+        }
+    }
+
+    /// Locate a tokenizer `issue` of the event text at byte `offset`
+    fn text_issue(&self, issue: &TokenIssue<'_>, offset: usize, text: &str) -> ParseIssue {
+        let severity = match issue.level {
+            IssueLevel::Info => IssueSeverity::Info,
+            IssueLevel::Warning => IssueSeverity::Warning,
+            IssueLevel::Error => IssueSeverity::Error,
+            IssueLevel::Critical => IssueSeverity::Critical,
+        };
+        let start = offset + (issue.span.as_ptr() as usize - text.as_ptr() as usize);
+        let line_start = self.source[..offset].rfind('\n').map_or(0, |pos| pos + 1);
+        let column = self.source[line_start..start].chars().count() + 1;
+
+        ParseIssue::with_location(
+            severity,
+            IssueCategory::Event,
+            issue.message.clone(),
+            self.line_index.line_of_offset(offset),
+            column,
+            (start, start + issue.span.len()),
+        )
+    }
+}
+
+/// Cheap check that rules out texts the tokenizer has nothing to report on
+///
+/// Most event texts are short, closed and printable; tokenizing only the
+/// rest keeps parsing fast.
+fn may_have_issues(text: &str) -> bool {
+    if text.len() > MAX_OVERRIDE_BLOCK_BYTES {
+        return true;
+    }
+    let mut open = false;
+    for byte in text.bytes() {
+        match byte {
+            b'{' => open = true,
+            b'}' => open = false,
+            b'\t' => {}
+            // Control characters, including C1 controls behind a 0xC2 lead
+            // byte
+            0x00..=0x1F | 0x7F | 0xC2 => return true,
+            _ => {}
+        }
+    }
+    open
+}
@@ -32,6 +32,8 @@
 
 pub mod ast;
 pub mod binary_data;
+pub mod config;
+pub mod custom_section;
 pub mod errors;
 pub mod incremental;
 pub mod main;
@@ -44,10 +46,13 @@ pub mod streaming;
 
 // Re-export public API
 pub use ast::{Event, ScriptInfo, Section, SectionType, Style};
+pub use config::ParserConfig;
+pub use custom_section::CustomSectionHandler;
 pub use errors::{IssueCategory, IssueSeverity, ParseError, ParseIssue, ParseResult};
-pub use script::Script;
+pub use incremental::LineRange;
 #[cfg(feature = "stream")]
 pub use script::{calculate_delta, ScriptDelta, ScriptDeltaOwned};
+pub use script::{EmbeddedFont, FontRequirement, OwnedScript, Script};
 
 #[cfg(feature = "stream")]
 pub use streaming::build_modified_source;
@@ -1,10 +1,15 @@
 //! Configurable builder for [`Script`] parsing.
 //!
 //! Provides [`ScriptBuilder`], a fluent entry point that optionally wires an
-//! extension registry of custom tag handlers and section processors into the
-//! parser before producing a [`Script`].
+//! extension registry of custom tag handlers and section processors,
+//! handlers for custom sections, and resource limits into the parser before
+//! producing a [`Script`].
+
+use alloc::{boxed::Box, vec::Vec};
+use core::fmt;
 
 use crate::parser::main::Parser;
+use crate::parser::{CustomSectionHandler, ParserConfig};
 use crate::Result;
 
 #[cfg(feature = "plugins")]
@@ -16,11 +21,14 @@ use super::Script;
 ///
 /// Provides a fluent API for setting up parsing configuration including
 /// extension registry for custom tag handlers and section processors.
-#[derive(Debug)]
 pub struct ScriptBuilder<'a> {
     /// Extension registry for custom handlers
     #[cfg(feature = "plugins")]
     registry: Option<&'a ExtensionRegistry>,
+    /// Handlers for sections the parser does not know
+    section_handlers: Vec<Box<dyn CustomSectionHandler>>,
+    /// Resource limits applied while parsing
+    config: ParserConfig,
 }
 
 impl<'a> ScriptBuilder<'a> {
@@ -30,6 +38,8 @@ impl<'a> ScriptBuilder<'a> {
         Self {
             #[cfg(feature = "plugins")]
             registry: None,
+            section_handlers: Vec::new(),
+            config: ParserConfig::new(),
         }
     }
 
@@ -44,6 +54,30 @@ impl<'a> ScriptBuilder<'a> {
         self
     }
 
+    /// Add a handler for sections the parser does not know
+    ///
+    /// Handlers are consulted in the order they were added; the first one
+    /// accepting a section's name parses it into the script. Sections no
+    /// handler accepts are still skipped with a warning.
+    ///
+    /// # Arguments
+    /// * `handler` - Boxed custom section handler
+    #[must_use]
+    pub fn with_section_handler(mut self, handler: Box<dyn CustomSectionHandler>) -> Self {
+        self.section_handlers.push(handler);
+        self
+    }
+
+    /// Set the resource limits applied while parsing
+    ///
+    /// # Arguments
+    /// * `config` - Input size, style and event limits
+    #[must_use]
+    pub const fn with_config(mut self, config: ParserConfig) -> Self {
+        self.config = config;
+        self
+    }
+
     /// Parse ASS script with configured options
     ///
     /// # Arguments
@@ -60,7 +94,22 @@ impl<'a> ScriptBuilder<'a> {
         #[cfg(not(feature = "plugins"))]
         let parser = Parser::new(source);
 
-        Ok(parser.parse())
+        Ok(parser
+            .with_section_handlers(self.section_handlers)
+            .with_config(self.config)
+            .parse())
+    }
+}
+
+impl fmt::Debug for ScriptBuilder<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut debug = f.debug_struct("ScriptBuilder");
+        #[cfg(feature = "plugins")]
+        debug.field("registry", &self.registry);
+        debug
+            .field("section_handlers", &self.section_handlers.len())
+            .field("config", &self.config)
+            .finish()
     }
 }
 
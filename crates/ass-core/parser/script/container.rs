@@ -1,13 +1,14 @@
 //! `Script` container definition and fundamental accessors.
 //!
 //! Holds the zero-copy [`Script`] struct together with its version, section,
-//! issue, format, and source accessors plus the internal `from_parts`
+//! issue, format, event, and source accessors plus the internal `from_parts`
 //! constructor. Mutation, parsing, and serialization live in sibling modules.
 
 use alloc::vec::Vec;
 
-use crate::parser::ast::{Section, SectionType};
+use crate::parser::ast::{Event, ScriptMetadata, Section, SectionType};
 use crate::parser::errors::ParseIssue;
+use crate::parser::ParserConfig;
 use crate::ScriptVersion;
 
 use super::types::ChangeTracker;
@@ -28,6 +29,9 @@ pub struct Script<'a> {
     /// Parsed sections in document order
     pub(super) sections: Vec<Section<'a>>,
 
+    /// Typed values of the `[Script Info\]` headers
+    pub(super) info: ScriptMetadata,
+
     /// Parse warnings and recoverable errors
     ///
     /// Transient parse diagnostics are not part of the canonical script
@@ -47,6 +51,10 @@ pub struct Script<'a> {
     /// Internal incremental-edit state; reset to default on deserialization.
     #[cfg_attr(feature = "serde", serde(skip))]
     pub(super) change_tracker: ChangeTracker<'a>,
+
+    /// Resource limits the script was parsed with, reused when reparsing
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub(super) config: ParserConfig,
 }
 
 impl<'a> Script<'a> {
@@ -96,6 +104,27 @@ impl<'a> Script<'a> {
             .find(|s| s.section_type() == section_type)
     }
 
+    /// Iterate over `Dialogue` events across all `[Events\]` sections
+    pub fn dialogue_events(&self) -> impl Iterator<Item = &Event<'a>> {
+        self.events().filter(|event| event.is_dialogue())
+    }
+
+    /// Iterate over `Comment` events across all `[Events\]` sections
+    pub fn commented_events(&self) -> impl Iterator<Item = &Event<'a>> {
+        self.events().filter(|event| event.is_comment())
+    }
+
+    /// Iterate over every event in document order
+    fn events(&self) -> impl Iterator<Item = &Event<'a>> {
+        self.sections
+            .iter()
+            .filter_map(|section| match section {
+                Section::Events(events) => Some(events.iter()),
+                _ => None,
+            })
+            .flatten()
+    }
+
     /// Create script from parsed components (internal constructor)
     pub(in crate::parser) fn from_parts(
         source: &'a str,
@@ -105,14 +134,24 @@ impl<'a> Script<'a> {
         styles_format: Option<Vec<&'a str>>,
         events_format: Option<Vec<&'a str>>,
     ) -> Self {
+        let info = Self::metadata_for(&sections, version);
         Self {
             source,
             version,
             sections,
+            info,
             issues,
             styles_format,
             events_format,
             change_tracker: ChangeTracker::default(),
+            config: ParserConfig::new(),
         }
     }
+
+    /// Record the resource limits the script was parsed with
+    #[must_use]
+    pub(in crate::parser) const fn with_parse_config(mut self, config: ParserConfig) -> Self {
+        self.config = config;
+        self
+    }
 }
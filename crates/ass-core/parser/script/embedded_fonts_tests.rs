@@ -0,0 +1,93 @@
+//! Tests for extracting UU-encoded fonts from `[Fonts]` sections.
+
+use super::*;
+#[cfg(not(feature = "std"))]
+use alloc::{string::String, vec, vec::Vec};
+
+/// Minimal synthetic TrueType payload: an sfnt header with zero tables
+/// followed by filler bytes so the encoding spans several UU lines.
+fn synthetic_ttf() -> Vec<u8> {
+    let mut data = vec![0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x10, 0x00, 0x00];
+    data.extend((0u8..100).map(|b| b.wrapping_mul(37)));
+    data
+}
+
+/// UU-encode `data` into lines understood by `decode_uu_data`.
+fn uu_encode(data: &[u8]) -> Vec<String> {
+    let encode = |bits: u8| char::from(b' ' + (bits & 0x3F));
+    data.chunks(45)
+        .map(|chunk| {
+            let mut line = String::new();
+            line.push(char::from(b' ' + u8::try_from(chunk.len()).unwrap()));
+            for group in chunk.chunks(3) {
+                let b = [
+                    group[0],
+                    group.get(1).copied().unwrap_or(0),
+                    group.get(2).copied().unwrap_or(0),
+                ];
+                line.push(encode(b[0] >> 2));
+                line.push(encode((b[0] << 4) | (b[1] >> 4)));
+                line.push(encode((b[1] << 2) | (b[2] >> 6)));
+                line.push(encode(b[2]));
+            }
+            line
+        })
+        .collect()
+}
+
+fn script_with_fonts(fonts: &[(&str, &[u8])]) -> String {
+    let mut source = String::from("[Script Info]\nTitle: Fonts\n\n[Fonts]\n");
+    for (name, data) in fonts {
+        source.push_str("fontname: ");
+        source.push_str(name);
+        source.push('\n');
+        for line in uu_encode(data) {
+            source.push_str(&line);
+            source.push('\n');
+        }
+        source.push('\n');
+    }
+    source
+}
+
+#[test]
+fn embedded_fonts_roundtrip_synthetic_ttf() {
+    let ttf = synthetic_ttf();
+    let source = script_with_fonts(&[("synthetic.ttf", &ttf)]);
+    let script = Script::parse(&source).unwrap();
+
+    assert!(script.has_embedded_fonts());
+    let fonts = script.embedded_fonts();
+    assert_eq!(fonts.len(), 1);
+    assert_eq!(fonts[0].name, "synthetic.ttf");
+    assert_eq!(fonts[0].data, ttf);
+}
+
+#[test]
+fn embedded_fonts_preserves_document_order() {
+    let first = synthetic_ttf();
+    let second: Vec<u8> = first.iter().rev().copied().collect();
+    let source = script_with_fonts(&[("first.ttf", &first), ("second.otf", &second)]);
+    let script = Script::parse(&source).unwrap();
+
+    let fonts = script.embedded_fonts();
+    assert_eq!(fonts.len(), 2);
+    assert_eq!(fonts[0].name, "first.ttf");
+    assert_eq!(fonts[0].data, first);
+    assert_eq!(fonts[1].name, "second.otf");
+    assert_eq!(fonts[1].data, second);
+}
+
+#[test]
+fn embedded_fonts_empty_without_fonts_section() {
+    let script = Script::parse("[Script Info]\nTitle: No fonts\n").unwrap();
+    assert!(!script.has_embedded_fonts());
+    assert!(script.embedded_fonts().is_empty());
+}
+
+#[test]
+fn embedded_fonts_skips_entries_without_data() {
+    let script =
+        Script::parse("[Script Info]\nTitle: Empty\n\n[Fonts]\nfontname: empty.ttf\n\n").unwrap();
+    assert!(script.embedded_fonts().is_empty());
+}
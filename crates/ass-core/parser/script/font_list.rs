@@ -0,0 +1,247 @@
+//! Font requirements of the [`Script`] container.
+//!
+//! Implements [`Script::font_list`], which walks every event with the style
+//! it references, follows the `\fn`, `\b`, `\i` and `\r` tags of its parsed
+//! override blocks through the text, and records each font face the rendered
+//! text needs together with the non-ASCII characters drawn in it.
+
+use alloc::{string::String, vec::Vec};
+
+use crate::override_parser::{DrawingMode, OverrideBlock, OverrideTag};
+use crate::parser::ast::{Section, Style};
+
+use super::Script;
+
+/// A font face needed to render a script
+///
+/// Produced by [`Script::font_list`]. Renderers can load these faces before
+/// the first frame and check they cover `codepoints`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FontRequirement {
+    /// Font family name from a style or a `\fn` override
+    pub font_family: String,
+
+    /// Whether the face is bold
+    pub bold: bool,
+
+    /// Whether the face is italic
+    pub italic: bool,
+
+    /// Index of the first event drawing text in this face
+    pub first_event_index: usize,
+
+    /// Non-ASCII characters drawn in this face, sorted and deduplicated
+    ///
+    /// ASCII text renders with nearly any font; these are the characters
+    /// (CJK, Cyrillic, symbols, ...) that may need a specific one.
+    pub codepoints: Vec<char>,
+}
+
+/// Font face selected at a point of an event's text
+#[derive(Debug, Clone)]
+struct FaceState {
+    /// Font family name
+    family: String,
+    /// Whether bold is on
+    bold: bool,
+    /// Whether italic is on
+    italic: bool,
+    /// Whether drawing mode (`\p`) is on, in which text is not glyphs
+    drawing: bool,
+}
+
+impl FaceState {
+    /// Face of `style` at the start of an event
+    fn from_style(style: &Style<'_>) -> Self {
+        Self {
+            family: String::from(style.fontname.trim()),
+            bold: parse_weight(style.bold).unwrap_or(false),
+            italic: parse_flag(style.italic).unwrap_or(false),
+            drawing: false,
+        }
+    }
+
+    /// Apply one parsed override tag
+    ///
+    /// `\t` animations are ignored, as the face is not animatable; `\b` and
+    /// `\i` without a valid argument restore the value of `style`.
+    fn apply(&mut self, tag: &OverrideTag, style: &Style<'_>, styles: &[&Style<'_>]) {
+        match tag {
+            OverrideTag::FontName(family) => {
+                let family = family.trim();
+                self.family = String::from(if family.is_empty() {
+                    style.fontname.trim()
+                } else {
+                    family
+                });
+            }
+            OverrideTag::Bold(on) => self.bold = *on,
+            OverrideTag::FontWeight(weight) => self.bold = *weight >= 700,
+            OverrideTag::Italic(on) => self.italic = *on,
+            OverrideTag::Malformed { name, args } if name == "b" => {
+                self.bold = parse_weight(args).unwrap_or_else(|| Self::from_style(style).bold);
+            }
+            OverrideTag::Malformed { name, args } if name == "i" => {
+                self.italic = parse_flag(args).unwrap_or_else(|| Self::from_style(style).italic);
+            }
+            OverrideTag::Drawing(DrawingMode::Shape(scale), _) => self.drawing = *scale > 0,
+            OverrideTag::Reset(name) => {
+                let reset = find_style(styles, name).unwrap_or(style);
+                *self = Self::from_style(reset);
+            }
+            _ => {}
+        }
+    }
+}
+
+impl Script<'_> {
+    /// List the font faces needed to render the script's dialogue
+    ///
+    /// Every Dialogue event starts in the face of its style (falling back to
+    /// `Default` for unknown styles); `\fn`, `\b`, `\i` and `\r` overrides
+    /// switch faces within the text, while tags inside `\t` animations do
+    /// not. A face is listed once it draws visible text, in order of first
+    /// use. Drawings and Comment events are skipped. Event indices count
+    /// Dialogue and Comment events in document order.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ass_core::parser::Script;
+    /// let script = Script::parse(
+    ///     "[V4+ Styles]\nFormat: Name, Fontname, Fontsize, PrimaryColour, SecondaryColour, OutlineColour, BackColour, Bold, Italic, Underline, StrikeOut, ScaleX, ScaleY, Spacing, Angle, BorderStyle, Outline, Shadow, Alignment, MarginL, MarginR, MarginV, Encoding\nStyle: Default,Arial,20,&H00FFFFFF,&H000000FF,&H00000000,&H00000000,0,0,0,0,100,100,0,0,1,2,0,2,10,10,10,1\n\n[Events]\nFormat: Layer, Start, End, Style, Name, MarginL, MarginR, MarginV, Effect, Text\nDialogue: 0,0:00:00.00,0:00:05.00,Default,,0,0,0,,Hello {\\fnNoto Sans JP\\b1}世界",
+    /// )?;
+    /// let fonts = script.font_list();
+    /// assert_eq!(fonts.len(), 2);
+    /// assert_eq!(fonts[0].font_family, "Arial");
+    /// assert!(fonts[0].codepoints.is_empty());
+    /// assert_eq!(fonts[1].font_family, "Noto Sans JP");
+    /// assert!(fonts[1].bold);
+    /// assert_eq!(fonts[1].codepoints, ['世', '界']);
+    /// # Ok::<(), ass_core::CoreError>(())
+    /// ```
+    #[must_use]
+    pub fn font_list(&self) -> Vec<FontRequirement> {
+        let styles: Vec<&Style> = self
+            .sections
+            .iter()
+            .filter_map(|section| match section {
+                Section::Styles(styles) => Some(styles),
+                _ => None,
+            })
+            .flatten()
+            .collect();
+        let events = self
+            .sections
+            .iter()
+            .filter_map(|section| match section {
+                Section::Events(events) => Some(events),
+                _ => None,
+            })
+            .flatten();
+
+        let mut fonts: Vec<FontRequirement> = Vec::new();
+        for (index, event) in events.enumerate() {
+            if !event.is_dialogue() {
+                continue;
+            }
+            let Some(style) =
+                find_style(&styles, event.style).or_else(|| find_style(&styles, "Default"))
+            else {
+                continue;
+            };
+
+            let mut face = FaceState::from_style(style);
+            let mut rest = event.text;
+            while !rest.is_empty() {
+                let (text, after) = rest.split_once('{').unwrap_or((rest, ""));
+                record_text(&mut fonts, &face, text, index);
+                let Some((block, after)) = after.split_once('}') else {
+                    break;
+                };
+                for tag in &OverrideBlock::parse(block).tags {
+                    face.apply(tag, style, &styles);
+                }
+                rest = after;
+            }
+        }
+        fonts
+    }
+
+    /// Whether the script has a `[Fonts\]` section with at least one font
+    ///
+    /// Cheaper than [`Script::embedded_fonts`]: nothing is decoded.
+    #[must_use]
+    pub fn has_embedded_fonts(&self) -> bool {
+        self.sections
+            .iter()
+            .any(|section| matches!(section, Section::Fonts(fonts) if !fonts.is_empty()))
+    }
+}
+
+/// Record `text` drawn in `face` by event `index`
+fn record_text(fonts: &mut Vec<FontRequirement>, face: &FaceState, text: &str, index: usize) {
+    if face.drawing || face.family.is_empty() {
+        return;
+    }
+    let visible = text
+        .replace("\\N", " ")
+        .replace("\\n", " ")
+        .replace("\\h", " ");
+    if visible.trim().is_empty() {
+        return;
+    }
+
+    let position = fonts.iter().position(|font| {
+        font.font_family.eq_ignore_ascii_case(&face.family)
+            && font.bold == face.bold
+            && font.italic == face.italic
+    });
+    let font = if let Some(position) = position {
+        &mut fonts[position]
+    } else {
+        fonts.push(FontRequirement {
+            font_family: face.family.clone(),
+            bold: face.bold,
+            italic: face.italic,
+            first_event_index: index,
+            codepoints: Vec::new(),
+        });
+        let last = fonts.len() - 1;
+        &mut fonts[last]
+    };
+
+    for ch in visible.chars() {
+        if !ch.is_ascii() && !ch.is_whitespace() && !ch.is_control() {
+            if let Err(slot) = font.codepoints.binary_search(&ch) {
+                font.codepoints.insert(slot, ch);
+            }
+        }
+    }
+}
+
+/// Style called `name`, ignoring a leading `*` as libass does
+fn find_style<'s, 'a>(styles: &[&'s Style<'a>], name: &str) -> Option<&'s Style<'a>> {
+    let name = name.trim().trim_start_matches('*');
+    styles
+        .iter()
+        .rev()
+        .find(|style| style.name.trim().trim_start_matches('*') == name)
+        .copied()
+}
+
+/// Bold flag of a style field or `\b` argument, `None` when absent
+///
+/// `0` is regular, `1`/`-1` bold, and larger values font weights.
+fn parse_weight(value: &str) -> Option<bool> {
+    match value.trim().parse::<i32>().ok()? {
+        0 => Some(false),
+        1 | -1 => Some(true),
+        weight => Some(weight >= 700),
+    }
+}
+
+/// On/off flag of a style field or override argument, `None` when absent
+fn parse_flag(value: &str) -> Option<bool> {
+    value.trim().parse::<i32>().ok().map(|flag| flag != 0)
+}
@@ -0,0 +1,117 @@
+//! Tests for listing the fonts a script needs.
+
+use super::*;
+#[cfg(not(feature = "std"))]
+use alloc::{format, string::String, vec, vec::Vec};
+
+const STYLES: &str = "[V4+ Styles]
+Format: Name, Fontname, Fontsize, PrimaryColour, SecondaryColour, OutlineColour, BackColour, Bold, Italic, Underline, StrikeOut, ScaleX, ScaleY, Spacing, Angle, BorderStyle, Outline, Shadow, Alignment, MarginL, MarginR, MarginV, Encoding
+Style: Default,Arial,20,&H00FFFFFF,&H000000FF,&H00000000,&H00000000,0,0,0,0,100,100,0,0,1,2,0,2,10,10,10,1
+Style: Japanese,Noto Sans CJK JP,20,&H00FFFFFF,&H000000FF,&H00000000,&H00000000,-1,0,0,0,100,100,0,0,1,2,0,2,10,10,10,1
+";
+
+fn script_with_events(events: &[&str]) -> String {
+    let mut source = format!(
+        "[Script Info]\nTitle: Fonts\n\n{STYLES}\n[Events]\nFormat: Layer, Start, End, Style, Name, MarginL, MarginR, MarginV, Effect, Text\n"
+    );
+    for event in events {
+        source.push_str(event);
+        source.push('\n');
+    }
+    source
+}
+
+fn families(fonts: &[FontRequirement]) -> Vec<(&str, bool, bool)> {
+    fonts
+        .iter()
+        .map(|font| (font.font_family.as_str(), font.bold, font.italic))
+        .collect()
+}
+
+#[test]
+fn font_list_mixed_language_script() {
+    let source = script_with_events(&[
+        "Dialogue: 0,0:00:00.00,0:00:02.00,Default,,0,0,0,,Hello there",
+        "Dialogue: 0,0:00:02.00,0:00:04.00,Japanese,,0,0,0,,こんにちは、世界",
+        "Dialogue: 0,0:00:04.00,0:00:06.00,Default,,0,0,0,,Café {\\fnMalgun Gothic}안녕",
+        "Dialogue: 0,0:00:06.00,0:00:08.00,Japanese,,0,0,0,,世界\\Nまた",
+    ]);
+    let script = Script::parse(&source).unwrap();
+    let fonts = script.font_list();
+
+    assert_eq!(
+        families(&fonts),
+        vec![
+            ("Arial", false, false),
+            ("Noto Sans CJK JP", true, false),
+            ("Malgun Gothic", false, false),
+        ]
+    );
+    assert_eq!(fonts[0].first_event_index, 0);
+    assert_eq!(fonts[0].codepoints, vec!['é']);
+    assert_eq!(fonts[1].first_event_index, 1);
+    let mut japanese: Vec<char> = "こんにちは、世界また".chars().collect();
+    japanese.sort_unstable();
+    assert_eq!(fonts[1].codepoints, japanese);
+    assert_eq!(fonts[2].first_event_index, 2);
+    assert_eq!(fonts[2].codepoints, vec!['녕', '안']);
+}
+
+#[test]
+fn font_list_follows_face_overrides() {
+    let source = script_with_events(&[
+        "Comment: 0,0:00:00.00,0:00:01.00,Default,,0,0,0,,{\\fnIgnored}Skipped",
+        "Dialogue: 0,0:00:01.00,0:00:02.00,Default,,0,0,0,,{\\b1}Bold {\\i1\\blur2}both {\\r}plain",
+        "Dialogue: 0,0:00:02.00,0:00:03.00,Missing,,0,0,0,,{\\fnSymbol\\fn}Default font {\\rJapanese}japanese",
+        "Dialogue: 0,0:00:03.00,0:00:04.00,Default,,0,0,0,,{\\p1}m 0 0 l 10 0 10 10{\\p0\\fnDrawn}{\\pos(1,2)}   ",
+        "Dialogue: 0,0:00:04.00,0:00:05.00,Default,,0,0,0,,{\\b700}Heavy{\\b}{\\i}regular",
+    ]);
+    let script = Script::parse(&source).unwrap();
+    let fonts = script.font_list();
+
+    assert_eq!(
+        families(&fonts),
+        vec![
+            ("Arial", true, false),
+            ("Arial", true, true),
+            ("Arial", false, false),
+            ("Noto Sans CJK JP", true, false),
+        ]
+    );
+    assert_eq!(
+        fonts
+            .iter()
+            .map(|font| font.first_event_index)
+            .collect::<Vec<_>>(),
+        vec![1, 1, 1, 2]
+    );
+    assert!(fonts.iter().all(|font| font.codepoints.is_empty()));
+}
+
+#[test]
+fn font_list_ignores_transforms_and_applies_resets() {
+    let source = script_with_events(&[
+        "Dialogue: 0,0:00:00.00,0:00:01.00,Default,,0,0,0,,{\\t(\\fnImpact)\\t(0,500,\\b1)}Still arial",
+        "Dialogue: 0,0:00:01.00,0:00:02.00,Default,,0,0,0,,{\\fnImpact\\i1}Styled{\\r}reset{\\fnImpact\\rJapanese}世界",
+    ]);
+    let script = Script::parse(&source).unwrap();
+    let fonts = script.font_list();
+
+    assert_eq!(
+        families(&fonts),
+        vec![
+            ("Arial", false, false),
+            ("Impact", false, true),
+            ("Noto Sans CJK JP", true, false),
+        ]
+    );
+    assert_eq!(fonts[2].codepoints, vec!['世', '界']);
+}
+
+#[test]
+fn font_list_empty_without_events() {
+    let source = script_with_events(&[]);
+    let script = Script::parse(&source).unwrap();
+    assert!(script.font_list().is_empty());
+    assert!(!script.has_embedded_fonts());
+}
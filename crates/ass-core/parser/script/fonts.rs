@@ -0,0 +1,62 @@
+//! Embedded font extraction for the [`Script`] container.
+//!
+//! Implements [`Script::embedded_fonts`], which walks every `[Fonts]` section
+//! and UU-decodes each `fontname:` entry into an owned [`EmbeddedFont`] that
+//! renderers can register without re-parsing the source.
+
+use alloc::{string::String, vec::Vec};
+
+use crate::parser::ast::Section;
+
+use super::Script;
+
+/// Decoded font file extracted from a `[Fonts\]` section
+///
+/// Owns both the declared font name and the decoded binary data so it can
+/// outlive the [`Script`] it was extracted from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EmbeddedFont {
+    /// Font name as declared by the `fontname:` line
+    pub name: String,
+
+    /// Decoded font file bytes (TTF/OTF/TTC)
+    pub data: Vec<u8>,
+}
+
+impl Script<'_> {
+    /// Extract and decode all fonts embedded in `[Fonts\]` sections
+    ///
+    /// Entries whose UU-encoded payload fails to decode or decodes to no
+    /// bytes are skipped, so the result only contains usable font data.
+    /// Fonts are returned in document order.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ass_core::parser::Script;
+    /// let script = Script::parse("[Script Info]\nTitle: Test\n\n[Fonts]\nfontname: cat.ttf\n#0V%T\n`\n")?;
+    /// let fonts = script.embedded_fonts();
+    /// assert_eq!(fonts.len(), 1);
+    /// assert_eq!(fonts[0].name, "cat.ttf");
+    /// assert_eq!(fonts[0].data, b"Cat");
+    /// # Ok::<(), ass_core::CoreError>(())
+    /// ```
+    #[must_use]
+    pub fn embedded_fonts(&self) -> Vec<EmbeddedFont> {
+        self.sections
+            .iter()
+            .filter_map(|section| match section {
+                Section::Fonts(fonts) => Some(fonts),
+                _ => None,
+            })
+            .flatten()
+            .filter_map(|font| {
+                let data = font.decode_data().ok()?;
+                (!data.is_empty()).then(|| EmbeddedFont {
+                    name: String::from(font.filename),
+                    data,
+                })
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,124 @@
+//! Typed `[Script Info]` access for the [`Script`] container.
+//!
+//! Implements [`Script::info`] and [`Script::info_mut`], which expose the
+//! standard headers as a [`ScriptMetadata`], plus the helpers that keep it in
+//! sync with the parsed section and write edits back on serialization.
+
+use alloc::string::String;
+use core::fmt::Write;
+
+use crate::parser::ast::{ScriptInfo, ScriptMetadata, Section, METADATA_KEYS};
+use crate::ScriptVersion;
+
+use super::source_comments::SectionComments;
+use super::Script;
+
+impl Script<'_> {
+    /// Get the typed values of the standard `[Script Info\]` headers
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ass_core::parser::Script;
+    /// let script = Script::parse("[Script Info]\nTitle: Typed\nPlayResY: 720\n")?;
+    /// assert_eq!(script.info().title.as_deref(), Some("Typed"));
+    /// assert_eq!(script.info().play_res_y, Some(720));
+    /// # Ok::<(), ass_core::CoreError>(())
+    /// ```
+    #[must_use]
+    pub const fn info(&self) -> &ScriptMetadata {
+        &self.info
+    }
+
+    /// Recompute [`Script::info`] from the current `[Script Info\]` section
+    ///
+    /// Discards edits made through [`Script::info_mut`].
+    pub(super) fn refresh_info(&mut self) {
+        self.info = Self::metadata_for(&self.sections, self.version);
+    }
+
+    /// Typed headers of the first `[Script Info\]` section in `sections`
+    pub(super) fn metadata_for(sections: &[Section<'_>], version: ScriptVersion) -> ScriptMetadata {
+        sections
+            .iter()
+            .find_map(|section| match section {
+                Section::ScriptInfo(info) => Some(info),
+                _ => None,
+            })
+            .map_or_else(
+                || ScriptMetadata {
+                    script_type: version,
+                    ..ScriptMetadata::default()
+                },
+                |info| ScriptMetadata::from_script_info(info, version),
+            )
+    }
+
+    /// Serialize `info`, writing back headers changed through
+    /// [`Script::info_mut`]
+    ///
+    /// Headers whose typed value still matches the section are written as
+    /// parsed; changed ones are replaced, removed when unset, or appended.
+    pub(super) fn script_info_to_ass_string(
+        &self,
+        info: &ScriptInfo<'_>,
+        comments: &SectionComments<'_>,
+    ) -> String {
+        let parsed = ScriptMetadata::from_script_info(info, self.version).header_values();
+        let current = self.info.header_values();
+        let changed = |index: usize| parsed[index] != current[index];
+
+        let mut result = String::from("[Script Info]\n");
+        for (field, (key, value)) in info.fields.iter().enumerate() {
+            for comment in comments.before(field) {
+                let _ = writeln!(result, "{comment}");
+            }
+            match METADATA_KEYS.iter().position(|k| k == key) {
+                Some(index) if changed(index) => {
+                    if let Some(value) = &current[index] {
+                        let _ = writeln!(result, "{key}: {value}");
+                    }
+                }
+                _ => {
+                    let _ = writeln!(result, "{key}: {value}");
+                }
+            }
+        }
+        for (index, key) in METADATA_KEYS.iter().enumerate() {
+            let present = info.fields.iter().any(|(k, _)| k == key);
+            if let (false, true, Some(value)) = (present, changed(index), &current[index]) {
+                let _ = writeln!(result, "{key}: {value}");
+            }
+        }
+        for comment in &comments.trailing {
+            let _ = writeln!(result, "{comment}");
+        }
+        result
+    }
+}
+
+impl Script<'static> {
+    /// Get mutable access to the typed `[Script Info\]` headers
+    ///
+    /// Edits are written back by [`Script::to_ass_string`]: changed headers
+    /// replace their line, headers set to `None` are dropped and new ones are
+    /// appended to the section, so a script without one keeps them only in
+    /// memory. They are discarded if the `[Script Info\]` section itself is
+    /// later replaced or edited.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ass_core::parser::Script;
+    /// let mut script = Script::parse("[Script Info]\nTitle: Old\n")?;
+    /// script.info_mut().title = Some("New".to_string());
+    /// script.info_mut().play_res_x = Some(1920);
+    /// let text = script.to_ass_string();
+    /// assert!(text.contains("Title: New\n"));
+    /// assert!(text.contains("PlayResX: 1920\n"));
+    /// # Ok::<(), ass_core::CoreError>(())
+    /// ```
+    pub fn info_mut(&mut self) -> &mut ScriptMetadata {
+        &mut self.info
+    }
+}
@@ -0,0 +1,92 @@
+//! Tests for typed `[Script Info]` access and write-back.
+
+use super::*;
+use crate::parser::ast::{WrapMode, YCbCrMatrix};
+use crate::ScriptVersion;
+#[cfg(not(feature = "std"))]
+use alloc::string::ToString;
+
+const SOURCE: &str = "[Script Info]
+Title: Known Script
+Original Script: Someone
+ScriptType: v4.00+
+PlayResX: 1920
+PlayResY: 1080
+PlayDepth: 32
+Timer: 100.0000
+WrapStyle: 1
+ScaledBorderAndShadow: no
+YCbCr Matrix: TV.709
+Collisions: Normal
+
+[Events]
+Format: Layer, Start, End, Style, Name, MarginL, MarginR, MarginV, Effect, Text
+Dialogue: 0,0:00:01.00,0:00:02.00,Default,,0,0,0,,Text
+";
+
+#[test]
+fn info_populates_all_fields() {
+    let script = Script::parse(SOURCE).unwrap();
+    let info = script.info();
+
+    assert_eq!(info.title.as_deref(), Some("Known Script"));
+    assert_eq!(info.original_script.as_deref(), Some("Someone"));
+    assert_eq!(info.script_type, ScriptVersion::AssV4);
+    assert_eq!(info.play_res_x, Some(1920));
+    assert_eq!(info.play_res_y, Some(1080));
+    assert_eq!(info.play_depth, Some(32));
+    assert_eq!(info.timer, Some(100.0));
+    assert_eq!(info.wrap_style, Some(WrapMode::EndOfLine));
+    assert_eq!(info.scaled_border_and_shadow, Some(false));
+    assert_eq!(info.ycbcr_matrix, Some(YCbCrMatrix::Tv709));
+    assert_eq!(info.play_resolution(), (1920, 1080));
+}
+
+#[test]
+fn info_defaults_without_script_info() {
+    let script = Script::parse("[Events]\n").unwrap();
+
+    assert_eq!(script.info().title, None);
+    assert_eq!(script.info().script_type, script.version());
+    assert_eq!(script.info().play_resolution(), (384, 288));
+}
+
+#[test]
+fn unchanged_info_serializes_as_parsed() {
+    let script = Script::parse(SOURCE).unwrap();
+    let text = script.to_ass_string();
+
+    assert!(text.starts_with(&SOURCE[..=SOURCE.find("\n\n").unwrap()]));
+}
+
+#[test]
+fn info_mut_edits_are_written_back() {
+    let mut script = Script::parse(SOURCE).unwrap();
+    let info = script.info_mut();
+    info.title = Some("Renamed".to_string());
+    info.play_depth = None;
+    info.wrap_style = Some(WrapMode::NoWrap);
+
+    let text = script.to_ass_string();
+    assert!(text.contains("Title: Renamed\n"));
+    assert!(!text.contains("PlayDepth"));
+    assert!(text.contains("WrapStyle: 2\n"));
+    assert!(text.contains("Timer: 100.0000\n"));
+    assert!(text.contains("Collisions: Normal\n"));
+
+    let reparsed = Script::parse(&text).unwrap();
+    assert_eq!(reparsed.info(), script.info());
+}
+
+#[test]
+fn info_mut_appends_missing_headers() {
+    let mut script = Script::parse("[Script Info]\nTitle: Short\n").unwrap();
+    script.info_mut().ycbcr_matrix = Some(YCbCrMatrix::Pc601);
+    script.info_mut().timer = Some(50.0);
+
+    let text = script.to_ass_string();
+    assert_eq!(
+        text,
+        "[Script Info]\nTitle: Short\nTimer: 50.0000\nYCbCr Matrix: PC.601\n"
+    );
+}
@@ -7,15 +7,26 @@ mod auto;
 mod batch;
 mod builder;
 mod container;
+mod font_list;
+mod fonts;
 mod incremental;
+mod info;
 mod lookup;
 mod mutate;
+mod owned;
+mod owned_script;
+mod owned_sections;
 mod parse;
 mod partial;
+mod patch;
+mod patch_parse;
+mod patch_text;
 mod serialize;
+mod source_comments;
 mod tracking;
 mod types;
 mod update;
+mod validate;
 
 #[cfg(feature = "stream")]
 mod delta;
@@ -33,10 +44,20 @@ mod construction_tests;
 #[cfg(test)]
 mod context_tests;
 #[cfg(test)]
+mod embedded_fonts_tests;
+#[cfg(test)]
+mod font_list_tests;
+#[cfg(test)]
+mod info_tests;
+#[cfg(test)]
 mod mutation_tests;
 #[cfg(test)]
+mod owned_tests;
+#[cfg(test)]
 mod parse_basic_tests;
 #[cfg(test)]
+mod patch_tests;
+#[cfg(test)]
 mod script_misc_tests;
 #[cfg(test)]
 mod section_query_tests;
@@ -44,12 +65,18 @@ mod section_query_tests;
 mod serialize_tests;
 #[cfg(test)]
 mod tracking_diff_tests;
+#[cfg(test)]
+mod validate_tests;
 
 #[cfg(all(test, feature = "stream"))]
 mod stream_tests;
 
 pub use builder::ScriptBuilder;
 pub use container::Script;
+pub use font_list::FontRequirement;
+pub use fonts::EmbeddedFont;
+pub use owned_script::OwnedScript;
+pub use serialize::{roundtrip_fidelity_score, SerializeOptions};
 pub use types::{
     BatchUpdateResult, Change, ChangeTracker, EventBatch, LineContent, StyleBatch, UpdateOperation,
 };
@@ -28,7 +28,11 @@ impl<'a> Script<'a> {
             section: section.clone(),
             index,
         });
+        let is_info = matches!(section, Section::ScriptInfo(_));
         self.sections.push(section);
+        if is_info {
+            self.refresh_info();
+        }
         index
     }
 
@@ -55,6 +59,9 @@ impl<'a> Script<'a> {
                 section_type: section.section_type(),
                 index,
             });
+            if matches!(section, Section::ScriptInfo(_)) {
+                self.refresh_info();
+            }
             Ok(section)
         } else {
             Err(ParseError::IndexOutOfBounds)
@@ -0,0 +1,168 @@
+//! Owned conversion and in-place iteration for the [`Script`] container.
+//!
+//! Implements [`Script::into_owned`], which moves a script into an
+//! [`OwnedScript`] that holds its own content, [`Script::parse_bytes_auto`],
+//! which parses raw bytes in any Unicode encoding into such a script,
+//! [`OwnedScript::script`], which borrows it back, and the `events_iter_mut`
+//! and `styles_iter_mut` iterators of both types for editing parsed items in
+//! place.
+
+use alloc::borrow::Cow;
+
+use crate::parser::ast::{Event, OwnedEvent, OwnedStyle, Section, Style};
+#[cfg(feature = "plugins")]
+use crate::plugin::ExtensionRegistry;
+use crate::utils::utf8::decode_to_utf8;
+use crate::Result;
+
+use super::owned_script::ScriptText;
+use super::owned_sections::{borrowed_format, OwnedSection};
+use super::types::ChangeTracker;
+use super::{OwnedScript, Script};
+
+impl<'a> Script<'a> {
+    /// Convert into a script that borrows nothing from the original source
+    ///
+    /// The current contents, including edits whose spans point outside the
+    /// original source, are rendered with [`Script::to_ass_string`] into a
+    /// new buffer that the returned [`OwnedScript`] holds and reparses with
+    /// the resource limits this script was parsed with. Its events and
+    /// styles can then be edited in place with
+    /// [`OwnedScript::events_iter_mut`] and [`OwnedScript::styles_iter_mut`].
+    /// Parse issues are recomputed and change tracking starts fresh. An extension registry
+    /// cannot be carried over; use [`Script::into_owned_with_registry`] to
+    /// reparse with one.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ass_core::parser::Script;
+    /// let owned = {
+    ///     let source = String::from("[Script Info]\nTitle: Owned\n");
+    ///     Script::parse(&source)?.into_owned()
+    /// };
+    /// assert!(owned.source().contains("Title: Owned"));
+    /// # Ok::<(), ass_core::CoreError>(())
+    /// ```
+    #[must_use]
+    pub fn into_owned(self) -> OwnedScript<'static> {
+        OwnedScript::from_text(ScriptText {
+            text: Cow::Owned(self.to_ass_string()),
+            config: self.config,
+            #[cfg(feature = "plugins")]
+            registry: None,
+        })
+    }
+
+    /// Convert into an owned script reparsed with `registry`
+    ///
+    /// Like [`Script::into_owned`], but the registry's section processors
+    /// take part in the reparse and in any later reparse of the result.
+    #[cfg(feature = "plugins")]
+    #[must_use]
+    pub fn into_owned_with_registry(self, registry: &ExtensionRegistry) -> OwnedScript<'_> {
+        OwnedScript::from_text(ScriptText {
+            text: Cow::Owned(self.to_ass_string()),
+            config: self.config,
+            registry: Some(registry),
+        })
+    }
+
+    /// Parse raw script bytes, detecting their encoding
+    ///
+    /// Decodes `bytes` with [`decode_to_utf8`], so UTF-16 and UTF-32 files
+    /// with a byte order mark are transcoded and other input is read as
+    /// UTF-8, then parses the result into an [`OwnedScript`]. Valid UTF-8 is
+    /// borrowed from `bytes`; transcoded or repaired text is held by the
+    /// returned script.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ass_core::parser::Script;
+    /// let utf16le: Vec<u8> = [0xFF, 0xFE]
+    ///     .into_iter()
+    ///     .chain("[Script Info]\nTitle: Wide\n".encode_utf16().flat_map(u16::to_le_bytes))
+    ///     .collect();
+    /// let script = Script::parse_bytes_auto(&utf16le)?;
+    /// assert!(script.source().contains("Title: Wide"));
+    /// # Ok::<(), ass_core::CoreError>(())
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `bytes` announce UTF-16 or UTF-32 but are not
+    /// validly encoded, see [`decode_to_utf8`].
+    pub fn parse_bytes_auto(bytes: &[u8]) -> Result<OwnedScript<'_>> {
+        OwnedScript::parse(decode_to_utf8(bytes)?)
+    }
+
+    /// Iterate mutably over events in every `[Events\]` section
+    ///
+    /// Edits made through the iterator are not recorded by change tracking.
+    pub fn events_iter_mut(&mut self) -> impl Iterator<Item = &mut Event<'a>> {
+        self.sections
+            .iter_mut()
+            .filter_map(|section| match section {
+                Section::Events(events) => Some(events),
+                _ => None,
+            })
+            .flatten()
+    }
+
+    /// Iterate mutably over styles in every `[V4+ Styles\]` section
+    ///
+    /// Edits made through the iterator are not recorded by change tracking.
+    pub fn styles_iter_mut(&mut self) -> impl Iterator<Item = &mut Style<'a>> {
+        self.sections
+            .iter_mut()
+            .filter_map(|section| match section {
+                Section::Styles(styles) => Some(styles),
+                _ => None,
+            })
+            .flatten()
+    }
+}
+
+impl OwnedScript<'_> {
+    /// Borrow the content as a [`Script`]
+    ///
+    /// Builds the borrowed view on each call without reparsing; keep the
+    /// returned script while working with it.
+    #[must_use]
+    pub fn script(&self) -> Script<'_> {
+        Script {
+            source: self.source(),
+            version: self.version,
+            sections: self.sections.iter().map(OwnedSection::as_section).collect(),
+            info: self.info.clone(),
+            issues: self.issues.clone(),
+            styles_format: self.styles_format.as_deref().map(borrowed_format),
+            events_format: self.events_format.as_deref().map(borrowed_format),
+            change_tracker: ChangeTracker::default(),
+            config: self.config,
+        }
+    }
+
+    /// Iterate mutably over events in every `[Events\]` section
+    pub fn events_iter_mut(&mut self) -> impl Iterator<Item = &mut OwnedEvent> {
+        self.sections
+            .iter_mut()
+            .filter_map(|section| match section {
+                OwnedSection::Events(events) => Some(events),
+                _ => None,
+            })
+            .flatten()
+    }
+
+    /// Iterate mutably over styles in every `[V4+ Styles\]` section
+    pub fn styles_iter_mut(&mut self) -> impl Iterator<Item = &mut OwnedStyle> {
+        self.sections
+            .iter_mut()
+            .filter_map(|section| match section {
+                OwnedSection::Styles(styles) => Some(styles),
+                _ => None,
+            })
+            .flatten()
+    }
+}
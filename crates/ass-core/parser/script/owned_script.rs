@@ -0,0 +1,186 @@
+//! Self-owning script container.
+//!
+//! Defines [`OwnedScript`], which keeps owned copies of a parsed script's
+//! sections together with its text, so the script can be stored, moved and
+//! edited freely without leaking or outliving its source buffer.
+
+use alloc::{borrow::Cow, string::String, vec::Vec};
+use core::fmt;
+
+use crate::parser::ast::ScriptMetadata;
+use crate::parser::errors::ParseIssue;
+use crate::parser::main::Parser;
+use crate::parser::ParserConfig;
+use crate::{Result, ScriptVersion};
+
+#[cfg(feature = "plugins")]
+use crate::plugin::ExtensionRegistry;
+
+use super::owned_sections::OwnedSection;
+use super::patch_text::PatchedSource;
+
+/// Text of an [`OwnedScript`] and the settings it is parsed with
+pub(super) struct ScriptText<'r> {
+    /// Text to parse
+    pub(super) text: Cow<'r, str>,
+    /// Resource limits applied when parsing
+    pub(super) config: ParserConfig,
+    /// Extension registry consulted for unknown sections
+    #[cfg(feature = "plugins")]
+    pub(super) registry: Option<&'r ExtensionRegistry>,
+}
+
+/// A parsed script that owns its content
+///
+/// [`Script`] borrows its source, which ties it to the buffer's lifetime.
+/// `OwnedScript` keeps owned copies of the parsed sections and the text
+/// instead, so it can be kept in long-lived structures, moved to another
+/// thread and edited in place with [`OwnedScript::events_iter_mut`] and
+/// [`OwnedScript::styles_iter_mut`]. [`OwnedScript::script`] borrows the
+/// content as a [`Script`] for the rest of the API. The lifetime `'r` is that
+/// of a borrowed source or extension registry, `'static` for owned text.
+///
+/// [`Script`]: super::Script
+///
+/// # Examples
+///
+/// ```rust
+/// # use ass_core::parser::OwnedScript;
+/// let mut owned = OwnedScript::parse(String::from(
+///     "[Events]\nFormat: Layer, Start, End, Style, Name, MarginL, MarginR, MarginV, Effect, Text\nDialogue: 0,0:00:00.00,0:00:05.00,Default,,0,0,0,,Hello\n",
+/// ))?;
+/// for event in owned.events_iter_mut() {
+///     event.text.push_str(", world!");
+/// }
+/// assert!(owned.script().to_ass_string().contains(",,Hello, world!"));
+/// # Ok::<(), ass_core::CoreError>(())
+/// ```
+pub struct OwnedScript<'r> {
+    /// Current document text, see [`OwnedScript::source`]
+    pub(super) text: PatchedSource<'r>,
+    /// Script version detected from headers
+    pub(super) version: ScriptVersion,
+    /// Owned sections in document order
+    pub(super) sections: Vec<OwnedSection>,
+    /// Typed values of the `[Script Info\]` headers
+    pub(super) info: ScriptMetadata,
+    /// Parse warnings and recoverable errors
+    pub(super) issues: Vec<ParseIssue>,
+    /// Format fields for [V4+ Styles] section
+    pub(super) styles_format: Option<Vec<String>>,
+    /// Format fields for `[Events\]` section
+    pub(super) events_format: Option<Vec<String>>,
+    /// Resource limits applied when parsing
+    pub(super) config: ParserConfig,
+    /// Extension registry consulted for unknown sections
+    #[cfg(feature = "plugins")]
+    pub(super) registry: Option<&'r ExtensionRegistry>,
+}
+
+impl fmt::Debug for OwnedScript<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut debug = f.debug_struct("OwnedScript");
+        debug
+            .field("len", &self.text.text.len())
+            .field("version", &self.version)
+            .field("sections", &self.sections)
+            .field("issues", &self.issues)
+            .field("config", &self.config);
+        #[cfg(feature = "plugins")]
+        debug.field("registry", &self.registry.is_some());
+        debug.finish_non_exhaustive()
+    }
+}
+
+impl<'r> OwnedScript<'r> {
+    /// Parse `text` into a script that holds it
+    ///
+    /// Owned text (`String`) makes an `OwnedScript<'static>`; borrowed text
+    /// is kept without copying until it is patched.
+    ///
+    /// # Errors
+    ///
+    /// Same as [`Script::parse`](super::Script::parse).
+    pub fn parse(text: impl Into<Cow<'r, str>>) -> Result<Self> {
+        Self::parse_with_config(text, &ParserConfig::default())
+    }
+
+    /// Parse `text` into a script that holds it, under custom resource limits
+    ///
+    /// # Errors
+    ///
+    /// Same as [`Script::parse_with_config`](super::Script::parse_with_config).
+    pub fn parse_with_config(text: impl Into<Cow<'r, str>>, config: &ParserConfig) -> Result<Self> {
+        Ok(Self::from_text(ScriptText {
+            text: text.into(),
+            config: *config,
+            #[cfg(feature = "plugins")]
+            registry: None,
+        }))
+    }
+
+    /// Parse `text` with the section processors of `registry`
+    ///
+    /// The registry is kept and consulted again whenever the script is
+    /// reparsed.
+    ///
+    /// # Errors
+    ///
+    /// Same as [`Script::parse_with_config`](super::Script::parse_with_config).
+    #[cfg(feature = "plugins")]
+    pub fn parse_with_registry(
+        text: impl Into<Cow<'r, str>>,
+        registry: &'r ExtensionRegistry,
+        config: &ParserConfig,
+    ) -> Result<Self> {
+        Ok(Self::from_text(ScriptText {
+            text: text.into(),
+            config: *config,
+            registry: Some(registry),
+        }))
+    }
+
+    /// Parse `text` with its settings and copy the result
+    pub(super) fn from_text(text: ScriptText<'r>) -> Self {
+        #[cfg(feature = "plugins")]
+        let parser = Parser::new_with_registry(&text.text, text.registry);
+        #[cfg(not(feature = "plugins"))]
+        let parser = Parser::new(&text.text);
+        let script = parser.with_config(text.config).parse();
+
+        let sections = script.sections.iter().map(OwnedSection::from).collect();
+        let owned_format = |format: Option<Vec<&str>>| {
+            format.map(|fields| fields.into_iter().map(String::from).collect())
+        };
+        let styles_format = owned_format(script.styles_format);
+        let events_format = owned_format(script.events_format);
+        Self {
+            version: script.version,
+            sections,
+            info: script.info,
+            issues: script.issues,
+            styles_format,
+            events_format,
+            text: PatchedSource::new(text.text),
+            config: text.config,
+            #[cfg(feature = "plugins")]
+            registry: text.registry,
+        }
+    }
+
+    /// Current document text
+    ///
+    /// This is the text the script was parsed from, with every patch applied
+    /// through [`OwnedScript::apply_patch`] since. Spans refer to it; edits
+    /// made through the mutable iterators do not change it.
+    #[must_use]
+    pub fn source(&self) -> &str {
+        &self.text.text
+    }
+
+    /// Resource limits the script is parsed with
+    #[must_use]
+    pub const fn config(&self) -> &ParserConfig {
+        &self.config
+    }
+}
@@ -0,0 +1,169 @@
+//! Owned section model behind [`OwnedScript`].
+//!
+//! Holds owned copies of parsed sections so an [`OwnedScript`] keeps its
+//! content without borrowing from a source buffer, and borrows them back as
+//! zero-copy [`Section`]s on demand.
+//!
+//! [`OwnedScript`]: super::OwnedScript
+
+use alloc::{string::String, vec::Vec};
+
+use crate::parser::ast::{
+    CustomSection, Font, Graphic, OwnedEvent, OwnedStyle, ScriptInfo, Section, Span,
+};
+
+/// Owned copy of a [`Section`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(super) enum OwnedSection {
+    /// `[Script Info\]` fields
+    ScriptInfo(OwnedFields),
+    /// `[V4+ Styles\]` definitions
+    Styles(Vec<OwnedStyle>),
+    /// `[Events\]` entries
+    Events(Vec<OwnedEvent>),
+    /// `[Fonts\]` attachments
+    Fonts(Vec<OwnedAttachment>),
+    /// `[Graphics\]` attachments
+    Graphics(Vec<OwnedAttachment>),
+    /// Any other section, with its header name
+    Custom(String, OwnedFields),
+}
+
+/// Owned key-value section content
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(super) struct OwnedFields {
+    /// Key-value pairs
+    fields: Vec<(String, String)>,
+    /// Non-empty content lines, kept for custom sections
+    lines: Vec<String>,
+    /// Span in source text
+    pub(super) span: Span,
+}
+
+/// Owned embedded font or graphic
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(super) struct OwnedAttachment {
+    /// File name from the section
+    filename: String,
+    /// UU-encoded data lines
+    data_lines: Vec<String>,
+    /// Span in source text
+    pub(super) span: Span,
+}
+
+impl OwnedFields {
+    /// Copy `fields` and `lines`
+    fn new(fields: &[(&str, &str)], lines: &[&str], span: Span) -> Self {
+        Self {
+            fields: fields
+                .iter()
+                .map(|&(key, value)| (key.into(), value.into()))
+                .collect(),
+            lines: lines.iter().map(|&line| line.into()).collect(),
+            span,
+        }
+    }
+
+    /// Borrowed key-value pairs
+    fn fields(&self) -> Vec<(&str, &str)> {
+        self.fields
+            .iter()
+            .map(|(key, value)| (key.as_str(), value.as_str()))
+            .collect()
+    }
+}
+
+impl OwnedAttachment {
+    /// Copy an attachment's file name and data
+    fn new(filename: &str, data_lines: &[&str], span: Span) -> Self {
+        Self {
+            filename: filename.into(),
+            data_lines: data_lines.iter().map(|&line| line.into()).collect(),
+            span,
+        }
+    }
+
+    /// Borrowed data lines
+    fn data_lines(&self) -> Vec<&str> {
+        self.data_lines.iter().map(String::as_str).collect()
+    }
+}
+
+impl OwnedSection {
+    /// Borrow as a zero-copy [`Section`]
+    pub(super) fn as_section(&self) -> Section<'_> {
+        match self {
+            Self::ScriptInfo(info) => Section::ScriptInfo(ScriptInfo {
+                fields: info.fields(),
+                span: info.span,
+            }),
+            Self::Styles(styles) => {
+                Section::Styles(styles.iter().map(OwnedStyle::as_style).collect())
+            }
+            Self::Events(events) => {
+                Section::Events(events.iter().map(OwnedEvent::as_event).collect())
+            }
+            Self::Fonts(fonts) => Section::Fonts(
+                fonts
+                    .iter()
+                    .map(|font| Font {
+                        filename: &font.filename,
+                        data_lines: font.data_lines(),
+                        span: font.span,
+                    })
+                    .collect(),
+            ),
+            Self::Graphics(graphics) => Section::Graphics(
+                graphics
+                    .iter()
+                    .map(|graphic| Graphic {
+                        filename: &graphic.filename,
+                        data_lines: graphic.data_lines(),
+                        span: graphic.span,
+                    })
+                    .collect(),
+            ),
+            Self::Custom(name, custom) => Section::Custom(CustomSection {
+                name,
+                fields: custom.fields(),
+                lines: custom.lines.iter().map(String::as_str).collect(),
+                span: custom.span,
+            }),
+        }
+    }
+}
+
+impl From<&Section<'_>> for OwnedSection {
+    fn from(section: &Section<'_>) -> Self {
+        match section {
+            Section::ScriptInfo(info) => {
+                Self::ScriptInfo(OwnedFields::new(&info.fields, &[], info.span))
+            }
+            Section::Styles(styles) => Self::Styles(styles.iter().map(OwnedStyle::from).collect()),
+            Section::Events(events) => Self::Events(events.iter().map(OwnedEvent::from).collect()),
+            Section::Fonts(fonts) => Self::Fonts(
+                fonts
+                    .iter()
+                    .map(|font| OwnedAttachment::new(font.filename, &font.data_lines, font.span))
+                    .collect(),
+            ),
+            Section::Graphics(graphics) => Self::Graphics(
+                graphics
+                    .iter()
+                    .map(|graphic| {
+                        OwnedAttachment::new(graphic.filename, &graphic.data_lines, graphic.span)
+                    })
+                    .collect(),
+            ),
+            Section::Custom(custom) => Self::Custom(
+                custom.name.into(),
+                OwnedFields::new(&custom.fields, &custom.lines, custom.span),
+            ),
+        }
+    }
+}
+
+/// Borrow owned format fields
+pub(super) fn borrowed_format(fields: &[String]) -> Vec<&str> {
+    fields.iter().map(String::as_str).collect()
+}
@@ -0,0 +1,162 @@
+//! Tests for owned conversion and mutable event/style iteration.
+
+use super::*;
+use crate::parser::ast::{Event, Section};
+use crate::parser::ParserConfig;
+#[cfg(not(feature = "std"))]
+use alloc::{string::String, vec::Vec};
+
+const SOURCE: &str = "[Script Info]
+Title: Owned
+
+[V4+ Styles]
+Format: Name, Fontname, Fontsize
+Style: Default,Arial,20
+Style: Sign,Verdana,30
+
+[Events]
+Format: Layer, Start, End, Style, Name, MarginL, MarginR, MarginV, Effect, Text
+Dialogue: 0,0:00:01.00,0:00:02.00,Default,,0,0,0,,First
+Dialogue: 0,0:00:03.00,0:00:04.00,Sign,,0,0,0,,Second
+";
+
+#[test]
+fn into_owned_outlives_source_buffer() {
+    let owned: OwnedScript<'static> = {
+        let source = String::from(SOURCE);
+        Script::parse(&source).unwrap().into_owned()
+    };
+
+    assert_eq!(owned.script().sections().len(), 3);
+    let texts: Vec<&str> = owned
+        .script()
+        .sections()
+        .iter()
+        .filter_map(|s| match s {
+            Section::Events(events) => Some(events),
+            _ => None,
+        })
+        .flatten()
+        .map(|e| e.text)
+        .collect();
+    assert_eq!(texts, ["First", "Second"]);
+}
+
+#[test]
+fn into_owned_allows_event_and_style_edits() {
+    let mut owned = {
+        let source = String::from(SOURCE);
+        Script::parse(&source).unwrap().into_owned()
+    };
+
+    for event in owned.events_iter_mut() {
+        event.text = event.text.to_uppercase();
+    }
+    for style in owned.styles_iter_mut().filter(|style| style.name == "Sign") {
+        style.fontname = String::from("Impact");
+    }
+
+    let script = owned.script();
+    let texts: Vec<&str> = script.dialogue_events().map(|e| e.text).collect();
+    assert_eq!(texts, ["FIRST", "SECOND"]);
+    let serialized = script.to_ass_string();
+    assert!(serialized.contains("Style: Sign,Impact,30"));
+    assert!(serialized.contains(",,SECOND"));
+}
+
+#[test]
+fn into_owned_keeps_edits_outside_source() {
+    let mut script = Script::parse(SOURCE).unwrap();
+    let added = String::from("Added later");
+    script.add_event(Event {
+        text: &added,
+        ..Event::default()
+    });
+    let owned = script.into_owned();
+    drop(added);
+
+    assert!(owned.source().contains("Added later"));
+    assert_eq!(owned.script().sections().len(), 3);
+}
+
+#[test]
+fn into_owned_keeps_parse_config() {
+    let config = ParserConfig {
+        max_events: 1,
+        ..ParserConfig::default()
+    };
+    let mut script = Script::parse_with_config(SOURCE, &config).unwrap();
+    let added = String::from("Dropped on reparse");
+    script.add_event(Event {
+        text: &added,
+        ..Event::default()
+    });
+    let owned = script.into_owned();
+
+    assert_eq!(owned.config(), &config);
+    let events: usize = owned
+        .script()
+        .sections()
+        .iter()
+        .map(|s| match s {
+            Section::Events(events) => events.len(),
+            _ => 0,
+        })
+        .sum();
+    assert_eq!(events, 1);
+}
+
+#[test]
+fn events_iter_mut_edits_in_place() {
+    let mut script = Script::parse(SOURCE).unwrap();
+    for event in script.events_iter_mut() {
+        event.text = "Replaced";
+    }
+
+    let serialized = script.to_ass_string();
+    assert_eq!(serialized.matches(",Replaced").count(), 2);
+    assert!(!serialized.contains("First"));
+}
+
+#[test]
+fn styles_iter_mut_edits_in_place() {
+    let mut script = Script::parse(SOURCE).unwrap();
+    for style in script.styles_iter_mut().filter(|s| s.name == "Sign") {
+        style.fontname = "Georgia";
+    }
+
+    let fonts: Vec<&str> = script.styles_iter_mut().map(|s| s.fontname).collect();
+    assert_eq!(fonts, ["Arial", "Georgia"]);
+}
+
+#[test]
+fn mutable_iterators_empty_without_sections() {
+    let mut script = Script::parse("[Script Info]\nTitle: Empty\n").unwrap();
+    assert_eq!(script.events_iter_mut().count(), 0);
+    assert_eq!(script.styles_iter_mut().count(), 0);
+}
+
+#[test]
+fn parse_bytes_auto_reads_utf16_le() {
+    let bytes: Vec<u8> = [0xFF, 0xFE]
+        .into_iter()
+        .chain(SOURCE.encode_utf16().flat_map(u16::to_le_bytes))
+        .collect();
+    let script = Script::parse_bytes_auto(&bytes).unwrap();
+
+    assert_eq!(script.source(), SOURCE);
+    assert_eq!(script.script().sections().len(), 3);
+    assert!(script.script().issues().is_empty());
+}
+
+#[test]
+fn parse_bytes_auto_matches_parse_for_utf8() {
+    let from_bytes = Script::parse_bytes_auto(SOURCE.as_bytes()).unwrap();
+    let from_str = Script::parse(SOURCE).unwrap();
+    assert_eq!(
+        from_bytes.script().to_ass_string(),
+        from_str.to_ass_string()
+    );
+    // Valid UTF-8 is parsed in place
+    assert_eq!(from_bytes.source().as_ptr(), SOURCE.as_ptr());
+}
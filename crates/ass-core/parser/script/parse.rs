@@ -1,12 +1,13 @@
 //! Parsing entry points and context-aware single-line parsing.
 //!
-//! Provides [`Script::parse`], the [`Script::builder`] constructor, and the
+//! Provides [`Script::parse`], [`Script::parse_with_config`], the [`Script::builder`] constructor, and the
 //! format-aware helpers used to parse individual style and event lines against
 //! the script's stored format definitions.
 
 use crate::parser::ast::{Event, Style};
 use crate::parser::errors::ParseError;
 use crate::parser::main::Parser;
+use crate::parser::ParserConfig;
 use crate::Result;
 
 use super::builder::ScriptBuilder;
@@ -37,7 +38,35 @@ impl<'a> Script<'a> {
     /// Returns an error if the source contains malformed section headers or
     /// other unrecoverable syntax errors.
     pub fn parse(source: &'a str) -> Result<Self> {
-        let parser = Parser::new(source);
+        Self::parse_with_config(source, &ParserConfig::default())
+    }
+
+    /// Parse ASS script from source text under custom resource limits
+    ///
+    /// Behaves like [`Script::parse`] with the limits of `config` in place of
+    /// the defaults. Input over the size limit yields an empty script with a
+    /// security issue; styles and events over their limits are dropped.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use ass_core::parser::{ParserConfig, Script};
+    /// let config = ParserConfig {
+    ///     max_input_bytes: 16,
+    ///     ..ParserConfig::default()
+    /// };
+    /// let script = Script::parse_with_config("[Script Info]\nTitle: Too long", &config)?;
+    /// assert!(script.sections().is_empty());
+    /// assert_eq!(script.issues().len(), 1);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the source contains malformed section headers or
+    /// other unrecoverable syntax errors.
+    pub fn parse_with_config(source: &'a str, config: &ParserConfig) -> Result<Self> {
+        let parser = Parser::new(source).with_config(*config);
         Ok(parser.parse())
     }
 
@@ -1,7 +1,8 @@
 //! Tests for basic script parsing, version detection, and core accessors.
 
 use super::*;
-use crate::parser::ast::SectionType;
+use crate::parser::ast::{Section, SectionType};
+use crate::parser::ParserConfig;
 use crate::ScriptVersion;
 #[cfg(not(feature = "std"))]
 use alloc::format;
@@ -180,3 +181,85 @@ fn parse_with_various_line_endings() {
     let script_lf = Script::parse(content_lf).unwrap();
     assert_eq!(script_lf.sections().len(), 1);
 }
+
+/// Number of styles and events in `script`
+fn entry_counts(script: &Script) -> (usize, usize) {
+    let styles = match script.find_section(SectionType::Styles) {
+        Some(Section::Styles(styles)) => styles.len(),
+        _ => 0,
+    };
+    let events = match script.find_section(SectionType::Events) {
+        Some(Section::Events(events)) => events.len(),
+        _ => 0,
+    };
+    (styles, events)
+}
+
+/// Whether `script` reports a security issue mentioning `text`
+fn has_security_issue(script: &Script, text: &str) -> bool {
+    script.issues().iter().any(|issue| {
+        issue.category == crate::parser::IssueCategory::Security && issue.message.contains(text)
+    })
+}
+
+#[test]
+fn parse_with_config_input_size_boundary() {
+    let content = "[Script Info]\nTitle: Limits";
+    let under = ParserConfig {
+        max_input_bytes: content.len() + 1,
+        ..ParserConfig::default()
+    };
+    let script = Script::parse_with_config(content, &under).unwrap();
+    assert_eq!(script.sections().len(), 1);
+    assert!(!has_security_issue(&script, "Input size limit exceeded"));
+
+    let over = ParserConfig {
+        max_input_bytes: content.len() - 1,
+        ..ParserConfig::default()
+    };
+    let script = Script::parse_with_config(content, &over).unwrap();
+    assert!(script.sections().is_empty());
+    assert!(has_security_issue(&script, "Input size limit exceeded"));
+}
+
+#[test]
+fn parse_with_config_limits_styles_and_events() {
+    let content = "[V4+ Styles]\nFormat: Name, Fontname\nStyle: A,Arial\nStyle: B,Arial\n\n[Events]\nFormat: Layer, Start, End, Style, Text\nDialogue: 0,0:00:00.00,0:00:01.00,A,One\nDialogue: 0,0:00:01.00,0:00:02.00,A,Two\nDialogue: 0,0:00:02.00,0:00:03.00,A,Three";
+    let config = ParserConfig {
+        max_styles: 1,
+        max_events: 2,
+        ..ParserConfig::default()
+    };
+    let script = Script::parse_with_config(content, &config).unwrap();
+    assert_eq!(entry_counts(&script), (1, 2));
+    assert!(has_security_issue(&script, "Style limit of 1 exceeded"));
+    assert!(has_security_issue(&script, "Event limit of 2 exceeded"));
+
+    let script = Script::parse(content).unwrap();
+    assert_eq!(entry_counts(&script), (2, 3));
+    assert!(script.issues().is_empty());
+}
+
+#[test]
+fn parse_with_config_skips_disallowed_embedded_data() {
+    let content = "[Script Info]\nTitle: Test\n\n[Fonts]\nfontname: a.ttf\nABCD\n\n[Graphics]\nfilename: b.png\nABCD";
+    let config = ParserConfig {
+        allow_embedded_fonts: false,
+        allow_embedded_graphics: false,
+        ..ParserConfig::default()
+    };
+    let script = Script::parse_with_config(content, &config).unwrap();
+    assert!(script.find_section(SectionType::Fonts).is_none());
+    assert!(script.find_section(SectionType::Graphics).is_none());
+    assert!(has_security_issue(&script, "[Fonts] section skipped"));
+
+    let script = Script::builder()
+        .with_config(ParserConfig {
+            allow_embedded_graphics: false,
+            ..ParserConfig::default()
+        })
+        .parse(content)
+        .unwrap();
+    assert!(script.find_section(SectionType::Fonts).is_some());
+    assert!(script.find_section(SectionType::Graphics).is_none());
+}
@@ -43,13 +43,13 @@ impl<'a> Script<'a> {
     pub fn parse_partial(&self, range: Range<usize>, new_text: &str) -> Result<ScriptDeltaOwned> {
         // Build the modified source
         let modified_source =
-            streaming::build_modified_source(self.source, range.clone(), new_text);
+            streaming::build_modified_source(self.source(), range.clone(), new_text);
 
         // Create a TextChange for incremental parsing
         let change = crate::parser::incremental::TextChange {
             range: range.clone(),
             new_text: new_text.to_string(),
-            line_range: crate::parser::incremental::calculate_line_range(self.source, range),
+            line_range: crate::parser::incremental::calculate_line_range(self.source(), range),
         };
 
         // Parse incrementally
@@ -164,6 +164,11 @@ impl<'a> Script<'a> {
                     .collect();
                 Section::Graphics(new_graphics)
             }
+            Section::Custom(custom) => {
+                let mut new_custom = custom.clone();
+                new_custom.span = adjust_span(&custom.span);
+                Section::Custom(new_custom)
+            }
         }
     }
 }
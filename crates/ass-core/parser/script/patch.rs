@@ -0,0 +1,136 @@
+//! Line-based in-place patching of owned scripts.
+//!
+//! Implements [`OwnedScript::apply_patch`], which replaces a range of lines
+//! and reparses only the style or event lines it covers, shifting the spans
+//! of everything after the edit instead of reparsing the whole document.
+
+use alloc::{borrow::Cow, string::String, vec::Vec};
+
+use crate::parser::errors::{ParseError, ParseIssue};
+use crate::parser::incremental::LineRange;
+use crate::utils::CoreError;
+use crate::Result;
+
+use super::owned_script::ScriptText;
+use super::owned_sections::OwnedSection;
+use super::patch_text::{count_lines, for_each_span_mut, is_structural, item_range, Shift};
+use super::OwnedScript;
+
+impl OwnedScript<'_> {
+    /// Replace the lines in `region` with `new_content` and reparse them
+    ///
+    /// When the patched lines lie in `[V4+ Styles\]` or `[Events\]` and add or
+    /// remove no section header or `Format:` line, only those lines are
+    /// parsed: the styles or events they held are replaced by the ones in
+    /// `new_content`, and spans and issues after the edit are shifted. Any
+    /// other edit reparses the whole document with the parser limits and
+    /// extension registry the script was parsed with.
+    ///
+    /// `new_content` replaces the lines including their line breaks; a final
+    /// newline is added when the replaced lines ended with one or text
+    /// follows them. Patched text is kept by the script, and spans afterwards
+    /// refer to [`OwnedScript::source`]. Once the kept patches outgrow the
+    /// document, it is reparsed to release them.
+    ///
+    /// Returns the issues found in the reparsed text.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ass_core::parser::{LineRange, OwnedScript};
+    /// let mut script = OwnedScript::parse(
+    ///     "[Events]\nFormat: Layer, Start, End, Style, Name, MarginL, MarginR, MarginV, Effect, Text\nDialogue: 0,0:00:00.00,0:00:05.00,Default,,0,0,0,,Old\n",
+    /// )?;
+    /// script.apply_patch(
+    ///     LineRange::line(2),
+    ///     "Dialogue: 0,0:00:00.00,0:00:05.00,Default,,0,0,0,,New",
+    /// )?;
+    /// assert!(script.source().ends_with(",,New\n"));
+    /// # Ok::<(), ass_core::CoreError>(())
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ParseError::IndexOutOfBounds`] if `region` is reversed or
+    /// extends past the end of the document.
+    pub fn apply_patch(&mut self, region: LineRange, new_content: &str) -> Result<Vec<ParseIssue>> {
+        let (start, end) = self
+            .text
+            .line_offset(region.start)
+            .zip(self.text.line_offset(region.end))
+            .filter(|_| region.start <= region.end)
+            .ok_or_else(|| CoreError::from(ParseError::IndexOutOfBounds))?;
+
+        let text = self.source();
+        let mut replacement = String::from(new_content);
+        if !replacement.is_empty()
+            && !replacement.ends_with('\n')
+            && (end < text.len() || text[start..end].ends_with('\n'))
+        {
+            replacement.push('\n');
+        }
+        if !replacement.is_empty()
+            && start == text.len()
+            && !text.is_empty()
+            && !text.ends_with('\n')
+        {
+            replacement.insert(0, '\n');
+        }
+
+        let removed = &text[start..end];
+        let target = self
+            .patch_target(start)
+            .filter(|_| !is_structural(removed) && !is_structural(&replacement));
+        let Some(index) = target else {
+            let mut full = String::with_capacity(text.len() - removed.len() + replacement.len());
+            full.push_str(&text[..start]);
+            full.push_str(&replacement);
+            full.push_str(&text[end..]);
+            self.reparse(full);
+            return Ok(self.issues.clone());
+        };
+        let removed_lines = count_lines(removed);
+
+        let (parsed, issues) = self.parse_patch(index, &replacement, start, region.start + 1)?;
+        let shift = Shift {
+            from: end,
+            removed_bytes: end - start,
+            added_bytes: replacement.len(),
+            removed_lines,
+            added_lines: count_lines(&replacement),
+        };
+        let replaced = item_range(&self.sections[index], start, end);
+        for section in &mut self.sections {
+            for_each_span_mut(section, |span| shift.apply(span));
+        }
+        let last_line = region.end;
+        self.issues
+            .retain(|issue| issue.line <= region.start || issue.line > last_line);
+        for issue in &mut self.issues {
+            shift.apply_to_issue(issue, last_line);
+        }
+        self.issues.extend(issues.iter().cloned());
+
+        match (&mut self.sections[index], parsed) {
+            (OwnedSection::Styles(styles), OwnedSection::Styles(new_styles)) => {
+                styles.splice(replaced, new_styles);
+            }
+            (OwnedSection::Events(events), OwnedSection::Events(new_events)) => {
+                events.splice(replaced, new_events);
+            }
+            _ => {}
+        }
+        self.text.replace(start..end, region.start, &replacement);
+        Ok(issues)
+    }
+
+    /// Replace the script with a full parse of `text` under its settings
+    fn reparse(&mut self, text: String) {
+        *self = Self::from_text(ScriptText {
+            text: Cow::Owned(text),
+            config: self.config,
+            #[cfg(feature = "plugins")]
+            registry: self.registry,
+        });
+    }
+}
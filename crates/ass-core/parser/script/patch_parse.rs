@@ -0,0 +1,119 @@
+//! Locating and parsing the lines replaced by a patch.
+//!
+//! Finds the styles or events section a line patch edits and parses the
+//! patch text in that section's format, for [`OwnedScript::apply_patch`].
+//!
+//! [`OwnedScript::apply_patch`]: super::OwnedScript::apply_patch
+
+use alloc::vec::Vec;
+
+use crate::parser::errors::ParseIssue;
+use crate::parser::sections::{EventsParser, StylesParser};
+use crate::utils::CoreError;
+use crate::Result;
+
+use super::owned_sections::{borrowed_format, OwnedSection};
+use super::patch_text::for_each_span_mut;
+use super::OwnedScript;
+
+impl OwnedScript<'_> {
+    /// Parse the styles or events in `patch`, inserted at byte `offset` on
+    /// line `first_line` (1-based) of the section at `index`
+    pub(super) fn parse_patch(
+        &self,
+        index: usize,
+        patch: &str,
+        offset: usize,
+        first_line: usize,
+    ) -> Result<(OwnedSection, Vec<ParseIssue>)> {
+        let line = u32::try_from(first_line).unwrap_or(u32::MAX);
+        let (parsed, _, mut issues, _, _) = if let OwnedSection::Styles(_) = self.sections[index] {
+            self.styles_format
+                .as_deref()
+                .map(borrowed_format)
+                .map_or_else(
+                    || StylesParser::new(patch, 0, first_line),
+                    |format| StylesParser::with_format(patch, &format, 0, line),
+                )
+                .parse()
+        } else {
+            self.events_format
+                .as_deref()
+                .map(borrowed_format)
+                .map_or_else(
+                    || EventsParser::new(patch, 0, first_line),
+                    |format| EventsParser::with_format(patch, &format, 0, line),
+                )
+                .parse()
+        }
+        .map_err(CoreError::from)?;
+
+        // Parsed spans are relative to the patch text
+        let mut parsed = OwnedSection::from(&parsed);
+        for_each_span_mut(&mut parsed, |span| {
+            span.start += offset;
+            span.end += offset;
+        });
+        for issue in &mut issues {
+            if let Some((start, end)) = issue.span.as_mut() {
+                *start += offset;
+                *end += offset;
+            }
+        }
+        Ok((parsed, issues))
+    }
+
+    /// Index of the styles or events section a patch at `offset` edits
+    ///
+    /// Returns `None` when the lines before `offset` belong to any other
+    /// section, so the caller falls back to a full reparse.
+    pub(super) fn patch_target(&self, offset: usize) -> Option<usize> {
+        let preceding = self
+            .sections
+            .iter()
+            .enumerate()
+            .filter_map(|(index, section)| {
+                let end = match section {
+                    OwnedSection::Styles(styles) => styles
+                        [..styles.partition_point(|s| s.span.start < offset)]
+                        .last()
+                        .map(|s| s.span.end),
+                    OwnedSection::Events(events) => events
+                        [..events.partition_point(|e| e.span.start < offset)]
+                        .last()
+                        .map(|e| e.span.end),
+                    _ => None,
+                }?;
+                Some((index, end))
+            })
+            .max_by_key(|&(_, end)| end);
+
+        let gap_start = preceding.map_or(0, |(_, end)| end.min(offset));
+        let header = self.source()[gap_start..offset]
+            .lines()
+            .rev()
+            .map(str::trim)
+            .find(|line| line.starts_with('['));
+
+        let Some(header) = header else {
+            return preceding.map(|(index, _)| index);
+        };
+        let name = header.trim_start_matches('[').split(']').next()?.trim();
+        let styles = match name {
+            "V4+ Styles" | "V4 Styles" | "V4++ Styles" => true,
+            "Events" => false,
+            _ => return None,
+        };
+        let first = preceding.map_or(0, |(index, _)| index + 1);
+        self.sections
+            .iter()
+            .enumerate()
+            .skip(first)
+            .find(|(_, section)| match section {
+                OwnedSection::Styles(_) => styles,
+                OwnedSection::Events(_) => !styles,
+                _ => false,
+            })
+            .map(|(index, _)| index)
+    }
+}
@@ -0,0 +1,241 @@
+//! Tests for line-based script patching.
+
+use super::*;
+use crate::parser::ast::{Event, Section, Style};
+use crate::parser::{LineRange, ParserConfig};
+#[cfg(not(feature = "std"))]
+use alloc::{boxed::Box, format, string::String, vec::Vec};
+
+const SOURCE: &str = "[Script Info]
+Title: Patch
+
+[V4+ Styles]
+Format: Name, Fontname, Fontsize
+Style: Default,Arial,20
+Style: Sign,Verdana,30
+
+[Events]
+Format: Layer, Start, End, Style, Name, MarginL, MarginR, MarginV, Effect, Text
+Dialogue: 0,0:00:01.00,0:00:02.00,Default,,0,0,0,,First
+; comment
+Dialogue: 0,0:00:03.00,0:00:04.00,Sign,,0,0,0,,Second
+Dialogue: 0,0:00:05.00,0:00:06.00,Default,,0,0,0,,Third
+";
+
+fn events<'s, 'a>(script: &'s Script<'a>) -> Vec<&'s Event<'a>> {
+    script
+        .sections()
+        .iter()
+        .filter_map(|s| match s {
+            Section::Events(events) => Some(events),
+            _ => None,
+        })
+        .flatten()
+        .collect()
+}
+
+fn styles<'s, 'a>(script: &'s Script<'a>) -> Vec<&'s Style<'a>> {
+    script
+        .sections()
+        .iter()
+        .filter_map(|s| match s {
+            Section::Styles(styles) => Some(styles),
+            _ => None,
+        })
+        .flatten()
+        .collect()
+}
+
+/// Patched items must match a full parse of the patched text
+///
+/// Line numbers are left out, as the full parser counts them per section.
+fn assert_matches_full_parse(script: &OwnedScript<'_>) {
+    let full = Script::parse(script.source()).unwrap();
+    let script = script.script();
+    let event_keys = |script: &Script<'_>| -> Vec<(usize, usize, String)> {
+        events(script)
+            .iter()
+            .map(|e| (e.span.start, e.span.end, e.to_ass_string()))
+            .collect()
+    };
+    let style_keys = |script: &Script<'_>| -> Vec<(usize, usize, String)> {
+        styles(script)
+            .iter()
+            .map(|s| (s.span.start, s.span.end, s.to_ass_string()))
+            .collect()
+    };
+    assert_eq!(event_keys(&script), event_keys(&full));
+    assert_eq!(style_keys(&script), style_keys(&full));
+    assert_eq!(script.to_ass_string(), full.to_ass_string());
+}
+
+#[test]
+fn apply_patch_replaces_single_event() {
+    let mut script = OwnedScript::parse(SOURCE).unwrap();
+    let issues = script
+        .apply_patch(
+            LineRange::line(12),
+            "Dialogue: 0,0:00:03.00,0:00:04.00,Sign,,0,0,0,,Second, longer",
+        )
+        .unwrap();
+
+    assert!(issues.is_empty());
+    let texts: Vec<&str> = events(&script.script()).iter().map(|e| e.text).collect();
+    assert_eq!(texts, ["First", "Second, longer", "Third"]);
+    assert!(script.source().contains(",,Second, longer\nDialogue"));
+    assert_eq!(script.script().source(), script.source());
+    assert_matches_full_parse(&script);
+}
+
+#[test]
+fn apply_patch_inserts_and_removes_lines() {
+    let mut script = OwnedScript::parse(SOURCE).unwrap();
+    script
+        .apply_patch(
+            LineRange::new(11, 11),
+            "Dialogue: 0,0:00:02.00,0:00:03.00,Default,,0,0,0,,Inserted\n\n",
+        )
+        .unwrap();
+    assert_eq!(events(&script.script()).len(), 4);
+    assert_matches_full_parse(&script);
+
+    script.apply_patch(LineRange::new(10, 13), "").unwrap();
+    let texts: Vec<&str> = events(&script.script()).iter().map(|e| e.text).collect();
+    assert_eq!(texts, ["Second", "Third"]);
+    assert_matches_full_parse(&script);
+
+    let end = script.source().lines().count();
+    script
+        .apply_patch(
+            LineRange::new(end, end),
+            "Dialogue: 0,0:00:07.00,0:00:08.00,Default,,0,0,0,,Last",
+        )
+        .unwrap();
+    assert_eq!(events(&script.script()).last().unwrap().text, "Last");
+    assert_matches_full_parse(&script);
+}
+
+#[test]
+fn apply_patch_edits_styles() {
+    let mut script = OwnedScript::parse(SOURCE).unwrap();
+    script
+        .apply_patch(LineRange::line(5), "Style: Default,Verdana,24")
+        .unwrap();
+
+    let fonts: Vec<&str> = styles(&script.script())
+        .iter()
+        .map(|s| s.fontname)
+        .collect();
+    assert_eq!(fonts, ["Verdana", "Verdana"]);
+    assert_matches_full_parse(&script);
+}
+
+#[test]
+fn apply_patch_reparses_structural_edits() {
+    let mut script = OwnedScript::parse(SOURCE).unwrap();
+    script
+        .apply_patch(LineRange::line(1), "Title: Renamed\nPlayResX: 1280")
+        .unwrap();
+    script
+        .apply_patch(LineRange::new(8, 8), "[Aegisub Extradata]\nData: 1\n\n")
+        .unwrap();
+
+    assert!(script.source().contains("Title: Renamed\nPlayResX: 1280\n"));
+    assert_eq!(events(&script.script()).len(), 3);
+    assert_matches_full_parse(&script);
+}
+
+#[test]
+fn apply_patch_reports_issues_and_bounds() {
+    let mut script = OwnedScript::parse(SOURCE).unwrap();
+    let issues = script
+        .apply_patch(LineRange::line(13), "Dialogue: broken")
+        .unwrap();
+    assert!(issues.iter().all(|issue| issue.line == 14));
+    assert_eq!(
+        events(&script.script()).len(),
+        3 - 1 + usize::from(issues.is_empty())
+    );
+
+    let lines = SOURCE.lines().count();
+    assert!(script
+        .apply_patch(LineRange::new(lines + 1, lines + 1), "")
+        .is_err());
+    assert!(script.apply_patch(LineRange::new(3, 2), "").is_err());
+}
+
+#[test]
+fn apply_patch_reparses_with_original_config() {
+    let config = ParserConfig {
+        max_events: 2,
+        ..ParserConfig::default()
+    };
+    let mut script = OwnedScript::parse_with_config(SOURCE, &config).unwrap();
+    assert_eq!(events(&script.script()).len(), 2);
+
+    script
+        .apply_patch(LineRange::line(1), "Title: Limited")
+        .unwrap();
+    assert!(script.source().contains("Title: Limited"));
+    assert_eq!(events(&script.script()).len(), 2);
+    assert_eq!(script.config(), &config);
+}
+
+#[cfg(feature = "plugins")]
+#[test]
+fn apply_patch_reparses_with_original_registry() {
+    use crate::plugin::{ExtensionRegistry, SectionProcessor, SectionResult};
+
+    struct WaveProcessor;
+
+    impl SectionProcessor for WaveProcessor {
+        fn name(&self) -> &'static str {
+            "Wave Data"
+        }
+
+        fn process(&self, _header: &str, _lines: &[&str]) -> SectionResult {
+            SectionResult::Processed
+        }
+    }
+
+    let mut registry = ExtensionRegistry::new();
+    registry
+        .register_section_processor(Box::new(WaveProcessor))
+        .unwrap();
+    let source = format!("{SOURCE}\n[Wave Data]\nAmplitude: 3\n");
+    let processed = |script: &OwnedScript<'_>| {
+        script
+            .script()
+            .issues()
+            .iter()
+            .any(|issue| issue.message.contains("processed by plugin"))
+    };
+
+    let mut script =
+        OwnedScript::parse_with_registry(source.as_str(), &registry, &ParserConfig::default())
+            .unwrap();
+    assert!(processed(&script));
+    script
+        .apply_patch(LineRange::line(1), "Title: Registered")
+        .unwrap();
+    assert!(processed(&script));
+}
+
+#[test]
+fn apply_patch_repeated_edits_keep_source_bounded() {
+    let mut script = OwnedScript::parse(SOURCE).unwrap();
+    let long = format!(
+        "Dialogue: 0,0:00:03.00,0:00:04.00,Sign,,0,0,0,,{}",
+        "x".repeat(200)
+    );
+    for round in 0..20 {
+        let text = if round % 2 == 0 {
+            long.as_str()
+        } else {
+            "Dialogue: 0,0:00:03.00,0:00:04.00,Sign,,0,0,0,,Second"
+        };
+        script.apply_patch(LineRange::line(12), text).unwrap();
+        assert!(script.source().len() <= SOURCE.len() + long.len());
+        assert_matches_full_parse(&script);
+    }
+}
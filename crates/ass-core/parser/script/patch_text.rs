@@ -0,0 +1,149 @@
+//! Text bookkeeping for line-based patching.
+//!
+//! Holds the current document text with its line index and the span
+//! shifting and line helpers [`OwnedScript::apply_patch`] relies on.
+//!
+//! [`OwnedScript::apply_patch`]: super::OwnedScript::apply_patch
+
+use alloc::{borrow::Cow, vec::Vec};
+use core::ops::Range;
+
+use crate::parser::ast::Span;
+use crate::parser::errors::ParseIssue;
+
+use super::owned_sections::OwnedSection;
+
+/// Document text kept up to date by [`OwnedScript::apply_patch`]
+///
+/// [`OwnedScript::apply_patch`]: super::OwnedScript::apply_patch
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(super) struct PatchedSource<'r> {
+    /// Current document text, copied on the first patch
+    pub(super) text: Cow<'r, str>,
+    /// Byte offset of the start of every line in `text`
+    line_starts: Vec<usize>,
+}
+
+impl<'r> PatchedSource<'r> {
+    /// Index the lines of `source`
+    pub(super) fn new(source: Cow<'r, str>) -> Self {
+        let line_starts = core::iter::once(0)
+            .chain(source.match_indices('\n').map(|(pos, _)| pos + 1))
+            .collect();
+        Self {
+            text: source,
+            line_starts,
+        }
+    }
+
+    /// Byte offset of the start of `line` (0-based)
+    ///
+    /// The end of a text without final newline counts as the start of the
+    /// line after the last one.
+    pub(super) fn line_offset(&self, line: usize) -> Option<usize> {
+        match self.line_starts.get(line) {
+            Some(&offset) => Some(offset),
+            None if line == self.line_starts.len()
+                && self.line_starts.last() < Some(&self.text.len()) =>
+            {
+                Some(self.text.len())
+            }
+            None => None,
+        }
+    }
+
+    /// Replace `range`, which starts on line `first_line`, with `patch`
+    pub(super) fn replace(&mut self, range: Range<usize>, first_line: usize, patch: &str) {
+        let first = (first_line + 1).min(self.line_starts.len());
+        let after = first + count_lines(&self.text[range.clone()]);
+        for line_start in &mut self.line_starts[after..] {
+            *line_start = *line_start - range.len() + patch.len();
+        }
+        let inserted = patch
+            .match_indices('\n')
+            .map(|(pos, _)| range.start + pos + 1);
+        self.line_starts.splice(first..after, inserted);
+        self.text.to_mut().replace_range(range, patch);
+    }
+}
+
+/// Offset and line changes applied to spans after a patch
+pub(super) struct Shift {
+    /// Old byte offset at which spans start moving
+    pub(super) from: usize,
+    /// Bytes removed by the patch
+    pub(super) removed_bytes: usize,
+    /// Bytes inserted by the patch
+    pub(super) added_bytes: usize,
+    /// Line breaks removed by the patch
+    pub(super) removed_lines: usize,
+    /// Line breaks inserted by the patch
+    pub(super) added_lines: usize,
+}
+
+impl Shift {
+    /// Move `span` if it starts after the patched text
+    pub(super) fn apply(&self, span: &mut Span) {
+        if span.start < self.from {
+            return;
+        }
+        span.start = span.start - self.removed_bytes + self.added_bytes;
+        span.end = span.end - self.removed_bytes + self.added_bytes;
+        span.line = u32::try_from(
+            (span.line as usize).saturating_sub(self.removed_lines) + self.added_lines,
+        )
+        .unwrap_or(u32::MAX);
+    }
+
+    /// Move `issue` if it lies after `last_line`, the last patched line
+    pub(super) fn apply_to_issue(&self, issue: &mut ParseIssue, last_line: usize) {
+        if issue.line > last_line {
+            issue.line = issue.line.saturating_sub(self.removed_lines) + self.added_lines;
+        }
+        if let Some((start, end)) = issue.span.as_mut() {
+            if *start >= self.from {
+                *start = *start - self.removed_bytes + self.added_bytes;
+                *end = *end - self.removed_bytes + self.added_bytes;
+            }
+        }
+    }
+}
+
+/// Call `f` on the span of every item in `section`
+pub(super) fn for_each_span_mut(section: &mut OwnedSection, mut f: impl FnMut(&mut Span)) {
+    match section {
+        OwnedSection::ScriptInfo(fields) | OwnedSection::Custom(_, fields) => f(&mut fields.span),
+        OwnedSection::Styles(styles) => styles.iter_mut().for_each(|style| f(&mut style.span)),
+        OwnedSection::Events(events) => events.iter_mut().for_each(|event| f(&mut event.span)),
+        OwnedSection::Fonts(attachments) | OwnedSection::Graphics(attachments) => attachments
+            .iter_mut()
+            .for_each(|attachment| f(&mut attachment.span)),
+    }
+}
+
+/// Indices of the styles or events of `section` starting in `start..end`
+pub(super) fn item_range(section: &OwnedSection, start: usize, end: usize) -> Range<usize> {
+    match section {
+        OwnedSection::Styles(styles) => {
+            styles.partition_point(|s| s.span.start < start)
+                ..styles.partition_point(|s| s.span.start < end)
+        }
+        OwnedSection::Events(events) => {
+            events.partition_point(|e| e.span.start < start)
+                ..events.partition_point(|e| e.span.start < end)
+        }
+        _ => 0..0,
+    }
+}
+
+/// Number of line breaks in `text`
+pub(super) fn count_lines(text: &str) -> usize {
+    text.bytes().filter(|&byte| byte == b'\n').count()
+}
+
+/// Whether `text` contains a section header or `Format:` line
+pub(super) fn is_structural(text: &str) -> bool {
+    text.lines()
+        .map(str::trim_start)
+        .any(|line| line.starts_with('[') || line.starts_with("Format:"))
+}
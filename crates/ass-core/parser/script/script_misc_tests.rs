@@ -3,7 +3,7 @@
 use super::*;
 use crate::parser::ast::SectionType;
 #[cfg(not(feature = "std"))]
-use alloc::{format, vec};
+use alloc::{format, vec, vec::Vec};
 
 #[test]
 fn script_equality_comprehensive() {
@@ -154,3 +154,27 @@ fn script_delta_debug_comprehensive() {
     let script = Script::parse("[Script Info]\nTitle: Test").unwrap();
     assert!(!script.issues().is_empty() || script.issues().is_empty()); // Just test it compiles
 }
+
+#[test]
+fn dialogue_and_commented_events_filter_by_type() {
+    let content = "[Events]\nFormat: Layer, Start, End, Style, Text\nDialogue: 0,0:00:00.00,0:00:01.00,Default,Shown\nComment: 0,0:00:01.00,0:00:02.00,Default,Hidden\nDialogue: 0,0:00:02.00,0:00:03.00,Default,Also shown";
+    let script = Script::parse(content).unwrap();
+
+    let dialogue: Vec<&str> = script.dialogue_events().map(|e| e.text).collect();
+    assert_eq!(dialogue, ["Shown", "Also shown"]);
+    let comments: Vec<&str> = script.commented_events().map(|e| e.text).collect();
+    assert_eq!(comments, ["Hidden"]);
+}
+
+#[test]
+fn toggled_comment_appears_in_dialogue_events() {
+    let content = "[Events]\nFormat: Layer, Start, End, Style, Text\nDialogue: 0,0:00:00.00,0:00:01.00,Default,Shown\nComment: 0,0:00:01.00,0:00:02.00,Default,Hidden";
+    let mut script = Script::parse(content).unwrap();
+    for event in script.events_iter_mut().filter(|e| e.is_comment()) {
+        event.toggle_comment();
+    }
+
+    let dialogue: Vec<&str> = script.dialogue_events().map(|e| e.text).collect();
+    assert_eq!(dialogue, ["Shown", "Hidden"]);
+    assert_eq!(script.commented_events().count(), 0);
+}
@@ -2,19 +2,63 @@
 //!
 //! Implements [`Script::to_ass_string`], which renders every section back to
 //! canonical ASS text, honoring the stored styles and events format lines when
-//! present.
+//! present, and [`Script::serialize_with_options`], which can additionally
+//! keep `;` comments, reorder sections and events, and trim whitespace.
 
-use alloc::string::String;
+use alloc::{string::String, vec::Vec};
 
-use crate::parser::ast::Section;
+use crate::parser::ast::{Event, Section, SectionType};
 
+use super::source_comments::{SectionComments, SourceComments};
 use super::Script;
 
+/// Options for [`Script::serialize_with_options`]
+///
+/// The default keeps comments and leaves everything in source order, so a
+/// script written in canonical form serializes back byte for byte.
+///
+/// # Examples
+///
+/// ```rust
+/// # use ass_core::parser::script::SerializeOptions;
+/// let options = SerializeOptions {
+///     sort_events_by_time: true,
+///     ..SerializeOptions::default()
+/// };
+/// assert!(options.preserve_comments);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[allow(clippy::struct_excessive_bools)]
+pub struct SerializeOptions {
+    /// Keep `Comment:` events and `;` comment lines
+    pub preserve_comments: bool,
+    /// Write events ordered by start time, keeping equal times in order
+    pub sort_events_by_time: bool,
+    /// Write `[Script Info]`, `[V4+ Styles]` and `[Events]` first, in that
+    /// order, followed by the other sections in source order
+    pub canonical_section_order: bool,
+    /// Trim surrounding whitespace from event text and comment lines
+    pub normalize_whitespace: bool,
+}
+
+impl Default for SerializeOptions {
+    fn default() -> Self {
+        Self {
+            preserve_comments: true,
+            sort_events_by_time: false,
+            canonical_section_order: false,
+            normalize_whitespace: false,
+        }
+    }
+}
+
 impl Script<'_> {
     /// Convert script to ASS string representation
     ///
     /// Generates the complete ASS script with all sections in order.
     /// Respects the stored format lines for styles and events if available.
+    /// `;` comment lines are not written; use
+    /// [`Script::serialize_with_options`] to keep them.
     ///
     /// # Examples
     ///
@@ -27,30 +71,87 @@ impl Script<'_> {
     /// ```
     #[must_use]
     pub fn to_ass_string(&self) -> alloc::string::String {
+        self.write_ass(SerializeOptions::default(), &SourceComments::default())
+    }
+
+    /// Convert script to ASS text with configurable ordering and comments
+    ///
+    /// `;` comment lines in `[Script Info]`, `[V4+ Styles]` and `[Events]`
+    /// are recovered from [`Script::source`] and written before the
+    /// field, style or event that followed them, so they move along when
+    /// events are sorted. Custom sections keep their raw lines.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ass_core::parser::{script::SerializeOptions, Script};
+    /// let source = "[Script Info]\n; Made by hand\nTitle: Test\n";
+    /// let script = Script::parse(source)?;
+    /// assert_eq!(script.serialize_with_options(&SerializeOptions::default()), source);
+    ///
+    /// let options = SerializeOptions {
+    ///     preserve_comments: false,
+    ///     ..SerializeOptions::default()
+    /// };
+    /// assert_eq!(script.serialize_with_options(&options), "[Script Info]\nTitle: Test\n");
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[must_use]
+    pub fn serialize_with_options(&self, options: &SerializeOptions) -> String {
+        let comments = if options.preserve_comments {
+            SourceComments::scan(self.source())
+        } else {
+            SourceComments::default()
+        };
+        self.write_ass(*options, &comments)
+    }
+
+    /// Write all sections, interleaving the recovered `comments`
+    fn write_ass(&self, options: SerializeOptions, comments: &SourceComments<'_>) -> String {
         let mut result = String::new();
+        for comment in comments.leading() {
+            push_line(&mut result, comment, options.normalize_whitespace);
+        }
 
-        for (idx, section) in self.sections.iter().enumerate() {
+        // Comments are matched to sections by position among same-named ones
+        let mut sections: Vec<(usize, &Section)> = self
+            .sections
+            .iter()
+            .enumerate()
+            .map(|(index, section)| {
+                let occurrence = self.sections[..index]
+                    .iter()
+                    .filter(|earlier| earlier.header_name() == section.header_name())
+                    .count();
+                (occurrence, section)
+            })
+            .collect();
+        if options.canonical_section_order {
+            sections.sort_by_key(|(_, section)| canonical_rank(section.section_type()));
+        }
+
+        for (idx, (occurrence, section)) in sections.into_iter().enumerate() {
             // Add newline between sections (but not before first)
             if idx > 0 {
                 result.push('\n');
             }
 
+            let section_comments =
+                comments.for_section(section, occurrence, options.normalize_whitespace);
             match section {
                 Section::ScriptInfo(info) => {
-                    result.push_str(&info.to_ass_string());
+                    result.push_str(&self.script_info_to_ass_string(info, &section_comments));
                 }
                 Section::Styles(styles) => {
                     result.push_str("[V4+ Styles]\n");
+                    push_format(
+                        &mut result,
+                        self.styles_format.as_deref(),
+                        &section_comments,
+                    );
 
-                    // Add format line if available
-                    if let Some(format) = &self.styles_format {
-                        result.push_str("Format: ");
-                        result.push_str(&format.join(", "));
-                        result.push('\n');
-                    }
-
-                    // Add each style
-                    for style in styles {
+                    for (index, style) in styles.iter().enumerate() {
+                        push_comments(&mut result, section_comments.before(index));
                         if let Some(format) = &self.styles_format {
                             result.push_str(&style.to_ass_string_with_format(format));
                         } else {
@@ -58,26 +159,10 @@ impl Script<'_> {
                         }
                         result.push('\n');
                     }
+                    push_comments(&mut result, section_comments.trailing.iter().copied());
                 }
                 Section::Events(events) => {
-                    result.push_str("[Events]\n");
-
-                    // Add format line if available
-                    if let Some(format) = &self.events_format {
-                        result.push_str("Format: ");
-                        result.push_str(&format.join(", "));
-                        result.push('\n');
-                    }
-
-                    // Add each event
-                    for event in events {
-                        if let Some(format) = &self.events_format {
-                            result.push_str(&event.to_ass_string_with_format(format));
-                        } else {
-                            result.push_str(&event.to_ass_string());
-                        }
-                        result.push('\n');
-                    }
+                    self.write_events(&mut result, events, options, &section_comments);
                 }
                 Section::Fonts(fonts) => {
                     result.push_str("[Fonts]\n");
@@ -91,9 +176,140 @@ impl Script<'_> {
                         result.push_str(&graphic.to_ass_string());
                     }
                 }
+                Section::Custom(custom) if !options.preserve_comments => {
+                    let mut custom = custom.clone();
+                    custom
+                        .lines
+                        .retain(|line| !line.trim_start().starts_with(';'));
+                    result.push_str(&custom.to_ass_string());
+                }
+                Section::Custom(custom) => {
+                    result.push_str(&custom.to_ass_string());
+                }
             }
         }
 
         result
     }
+
+    /// Write an `[Events]` section in the order `options` asks for
+    fn write_events(
+        &self,
+        result: &mut String,
+        events: &[Event<'_>],
+        options: SerializeOptions,
+        comments: &SectionComments<'_>,
+    ) {
+        result.push_str("[Events]\n");
+        push_format(result, self.events_format.as_deref(), comments);
+
+        for index in event_order(events, options) {
+            push_comments(result, comments.before(index));
+            let event = &events[index];
+            let trimmed;
+            let event = if options.normalize_whitespace {
+                trimmed = Event {
+                    text: event.text.trim(),
+                    ..event.clone()
+                };
+                &trimmed
+            } else {
+                event
+            };
+            if let Some(format) = &self.events_format {
+                result.push_str(&event.to_ass_string_with_format(format));
+            } else {
+                result.push_str(&event.to_ass_string());
+            }
+            result.push('\n');
+        }
+        push_comments(result, comments.trailing.iter().copied());
+    }
+}
+
+/// Similarity of two ASS texts as a percentage of matching lines
+///
+/// Counts the lines of the longest common subsequence of both texts, so
+/// reordered or edited lines lower the score while identical texts score
+/// `100.0`. Two empty texts are identical. Meant for checking how much of
+/// a script survives a parse and serialize roundtrip.
+///
+/// # Examples
+///
+/// ```rust
+/// # use ass_core::parser::script::roundtrip_fidelity_score;
+/// assert_eq!(roundtrip_fidelity_score("a\nb\n", "a\nb\n"), 100.0);
+/// assert_eq!(roundtrip_fidelity_score("a\nb\n", "a\nc\n"), 50.0);
+/// ```
+#[must_use]
+pub fn roundtrip_fidelity_score(original: &str, roundtripped: &str) -> f64 {
+    let original: Vec<&str> = original.lines().collect();
+    let roundtripped: Vec<&str> = roundtripped.lines().collect();
+    let total = original.len() + roundtripped.len();
+    if total == 0 {
+        return 100.0;
+    }
+
+    // Two-row LCS table over lines
+    let mut previous = alloc::vec![0usize; roundtripped.len() + 1];
+    let mut current = previous.clone();
+    for line in &original {
+        for (j, other) in roundtripped.iter().enumerate() {
+            current[j + 1] = if line == other {
+                previous[j] + 1
+            } else {
+                current[j].max(previous[j + 1])
+            };
+        }
+        core::mem::swap(&mut previous, &mut current);
+    }
+
+    let common = previous[roundtripped.len()];
+    #[allow(clippy::cast_precision_loss)]
+    let score = (2 * common) as f64 / total as f64 * 100.0;
+    score
+}
+
+/// Position of a section type under canonical section order
+const fn canonical_rank(section_type: SectionType) -> u8 {
+    match section_type {
+        SectionType::ScriptInfo => 0,
+        SectionType::Styles => 1,
+        SectionType::Events => 2,
+        _ => 3,
+    }
+}
+
+/// Indices of the events to write, in output order
+fn event_order(events: &[Event<'_>], options: SerializeOptions) -> Vec<usize> {
+    let mut order: Vec<usize> = (0..events.len())
+        .filter(|&index| options.preserve_comments || !events[index].is_comment())
+        .collect();
+    if options.sort_events_by_time {
+        order.sort_by_key(|&index| events[index].start_time_cs().unwrap_or(u32::MAX));
+    }
+    order
+}
+
+/// Write the comments before the `Format:` line, then the line itself
+fn push_format(result: &mut String, format: Option<&[&str]>, comments: &SectionComments<'_>) {
+    push_comments(result, comments.before_format.iter().copied());
+    if let Some(format) = format {
+        result.push_str("Format: ");
+        result.push_str(&format.join(", "));
+        result.push('\n');
+    }
+}
+
+/// Write each comment on its own line
+fn push_comments<'s>(result: &mut String, comments: impl Iterator<Item = &'s str>) {
+    for comment in comments {
+        push_line(result, comment, false);
+    }
+}
+
+/// Write one line, optionally trimmed
+fn push_line(result: &mut String, line: &str, trim: bool) {
+    result.push_str(if trim { line.trim() } else { line });
+    result.push('\n');
 }
@@ -3,7 +3,7 @@
 use super::*;
 use crate::parser::ast::Section;
 #[cfg(not(feature = "std"))]
-use alloc::vec;
+use alloc::{vec, vec::Vec};
 
 // Helper function to create a test script with all sections
 fn create_test_script() -> Script<'static> {
@@ -48,12 +48,12 @@ fn create_test_script() -> Script<'static> {
         }]),
     ];
 
-    Script {
-        source: "",
-        version: ScriptVersion::AssV4Plus,
+    Script::from_parts(
+        "",
+        ScriptVersion::AssV4Plus,
         sections,
-        issues: vec![],
-        styles_format: Some(vec![
+        vec![],
+        Some(vec![
             "Name",
             "Fontname",
             "Fontsize",
@@ -78,12 +78,11 @@ fn create_test_script() -> Script<'static> {
             "MarginV",
             "Encoding",
         ]),
-        events_format: Some(vec![
+        Some(vec![
             "Layer", "Start", "End", "Style", "Name", "MarginL", "MarginR", "MarginV", "Effect",
             "Text",
         ]),
-        change_tracker: ChangeTracker::default(),
-    }
+    )
 }
 
 #[test]
@@ -113,18 +112,17 @@ fn script_to_ass_string_minimal() {
     use crate::parser::ast::{ScriptInfo, Span};
     use crate::ScriptVersion;
 
-    let script = Script {
-        source: "",
-        version: ScriptVersion::AssV4Plus,
-        sections: vec![Section::ScriptInfo(ScriptInfo {
+    let script = Script::from_parts(
+        "",
+        ScriptVersion::AssV4Plus,
+        vec![Section::ScriptInfo(ScriptInfo {
             fields: vec![("Title", "Minimal")],
             span: Span::new(0, 0, 0, 0),
         })],
-        issues: vec![],
-        styles_format: None,
-        events_format: None,
-        change_tracker: ChangeTracker::default(),
-    };
+        vec![],
+        None,
+        None,
+    );
 
     let ass_string = script.to_ass_string();
 
@@ -140,15 +138,7 @@ fn script_to_ass_string_minimal() {
 fn script_to_ass_string_empty() {
     use crate::ScriptVersion;
 
-    let script = Script {
-        source: "",
-        version: ScriptVersion::AssV4Plus,
-        sections: vec![],
-        issues: vec![],
-        styles_format: None,
-        events_format: None,
-        change_tracker: ChangeTracker::default(),
-    };
+    let script = Script::from_parts("", ScriptVersion::AssV4Plus, vec![], vec![], None, None);
 
     let ass_string = script.to_ass_string();
 
@@ -161,10 +151,10 @@ fn script_to_ass_string_with_custom_format_lines() {
     use crate::parser::ast::{Event, EventType, Span};
     use crate::ScriptVersion;
 
-    let script = Script {
-        source: "",
-        version: ScriptVersion::AssV4Plus,
-        sections: vec![Section::Events(vec![Event {
+    let script = Script::from_parts(
+        "",
+        ScriptVersion::AssV4Plus,
+        vec![Section::Events(vec![Event {
             event_type: EventType::Dialogue,
             layer: "0",
             start: "0:00:00.00",
@@ -180,11 +170,10 @@ fn script_to_ass_string_with_custom_format_lines() {
             text: "Test",
             span: Span::new(0, 0, 0, 0),
         }])],
-        issues: vec![],
-        styles_format: None,
-        events_format: Some(vec!["Start", "End", "Text"]),
-        change_tracker: ChangeTracker::default(),
-    };
+        vec![],
+        None,
+        Some(vec!["Start", "End", "Text"]),
+    );
 
     let ass_string = script.to_ass_string();
 
@@ -192,3 +181,122 @@ fn script_to_ass_string_with_custom_format_lines() {
     assert!(ass_string.contains("Format: Start, End, Text\n"));
     assert!(ass_string.contains("Dialogue: 0:00:00.00,0:00:05.00,Test\n"));
 }
+
+const COMMENTED: &str = "[Script Info]
+; Script generated by hand
+Title: Commented
+ScriptType: v4.00+
+
+[V4+ Styles]
+Format: Name, Fontname, Fontsize, PrimaryColour, SecondaryColour, OutlineColour, BackColour, Bold, Italic, Underline, StrikeOut, ScaleX, ScaleY, Spacing, Angle, BorderStyle, Outline, Shadow, Alignment, MarginL, MarginR, MarginV, Encoding
+; Main dialogue style
+Style: Default,Arial,20,&H00FFFFFF,&H000000FF,&H00000000,&H00000000,0,0,0,0,100,100,0,0,1,2,0,2,10,10,10,1
+
+[Events]
+; Timed by ear
+Format: Layer, Start, End, Style, Name, MarginL, MarginR, MarginV, Effect, Text
+Dialogue: 0,0:00:05.00,0:00:07.00,Default,,0,0,0,,Second
+; Opening line
+Dialogue: 0,0:00:01.00,0:00:03.00,Default,,0,0,0,,First
+Comment: 0,0:00:03.00,0:00:05.00,Default,,0,0,0,,Unused line
+; End of file
+";
+
+#[test]
+fn serialize_default_options_preserve_every_byte() {
+    let script = Script::parse(COMMENTED).unwrap();
+    let serialized = script.serialize_with_options(&SerializeOptions::default());
+    assert_eq!(serialized, COMMENTED);
+    assert_score(roundtrip_fidelity_score(COMMENTED, &serialized), 100.0);
+}
+
+#[test]
+fn serialize_without_comments_drops_comment_lines_and_events() {
+    let script = Script::parse(COMMENTED).unwrap();
+    let options = SerializeOptions {
+        preserve_comments: false,
+        ..SerializeOptions::default()
+    };
+    let serialized = script.serialize_with_options(&options);
+    assert!(!serialized.contains(';'));
+    assert!(!serialized.contains("Comment:"));
+    assert!(serialized.contains("Dialogue: 0,0:00:01.00"));
+    assert_eq!(
+        serialized,
+        script.to_ass_string().replace(
+            "Comment: 0,0:00:03.00,0:00:05.00,Default,,0,0,0,,Unused line\n",
+            ""
+        )
+    );
+}
+
+#[test]
+fn serialize_sorted_events_keep_their_comments() {
+    let script = Script::parse(COMMENTED).unwrap();
+    let options = SerializeOptions {
+        sort_events_by_time: true,
+        ..SerializeOptions::default()
+    };
+    let serialized = script.serialize_with_options(&options);
+    let events = &serialized[serialized.find("[Events]").unwrap()..];
+    let lines: Vec<&str> = events.lines().collect();
+    assert_eq!(
+        lines[1..],
+        [
+            "; Timed by ear",
+            "Format: Layer, Start, End, Style, Name, MarginL, MarginR, MarginV, Effect, Text",
+            "; Opening line",
+            "Dialogue: 0,0:00:01.00,0:00:03.00,Default,,0,0,0,,First",
+            "Comment: 0,0:00:03.00,0:00:05.00,Default,,0,0,0,,Unused line",
+            "Dialogue: 0,0:00:05.00,0:00:07.00,Default,,0,0,0,,Second",
+            "; End of file",
+        ]
+    );
+}
+
+#[test]
+fn serialize_canonical_section_order() {
+    let source = "[Events]\nFormat: Start, End, Text\nDialogue: 0:00:00.00,0:00:01.00,Hi\n\n[Script Info]\n; Header comment\nTitle: Reordered\n";
+    let script = Script::parse(source).unwrap();
+    let options = SerializeOptions {
+        canonical_section_order: true,
+        ..SerializeOptions::default()
+    };
+    let serialized = script.serialize_with_options(&options);
+    assert_eq!(
+        serialized,
+        "[Script Info]\n; Header comment\nTitle: Reordered\n\n[Events]\nFormat: Start, End, Text\nDialogue: 0:00:00.00,0:00:01.00,Hi\n"
+    );
+    assert!(roundtrip_fidelity_score(source, &serialized) < 100.0);
+}
+
+#[test]
+fn serialize_normalize_whitespace_trims_text_and_comments() {
+    let source =
+        "[Events]\nFormat: Start, End, Text\n  ;  Indented note  \nDialogue: 0:00:00.00,0:00:01.00,  Spaced  \n";
+    let script = Script::parse(source).unwrap();
+    let options = SerializeOptions {
+        normalize_whitespace: true,
+        ..SerializeOptions::default()
+    };
+    assert_eq!(
+        script.serialize_with_options(&options),
+        "[Events]\nFormat: Start, End, Text\n;  Indented note\nDialogue: 0:00:00.00,0:00:01.00,Spaced\n"
+    );
+}
+
+/// Compare fidelity scores with a tolerance for rounding
+fn assert_score(actual: f64, expected: f64) {
+    assert!((actual - expected).abs() < 1e-9, "{actual} != {expected}");
+}
+
+#[test]
+fn fidelity_score_counts_common_lines() {
+    assert_score(roundtrip_fidelity_score("", ""), 100.0);
+    assert_score(
+        roundtrip_fidelity_score("a\nb\nc\nd\n", "a\nc\nd\n"),
+        600.0 / 7.0,
+    );
+    assert_score(roundtrip_fidelity_score("a\nb\n", "b\na\n"), 50.0);
+    assert_score(roundtrip_fidelity_score("a\n", ""), 0.0);
+}
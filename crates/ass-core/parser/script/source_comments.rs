@@ -0,0 +1,181 @@
+//! Recovery of `;` comment lines for [`Script::serialize_with_options`].
+//!
+//! The parser skips `;` comments, so they are not part of the AST. This
+//! module rescans the document text and anchors every comment to the item
+//! that follows it, letting the serializer write comments back next to the
+//! same style, event or header even when sections or events are reordered.
+//!
+//! [`Script::serialize_with_options`]: super::Script::serialize_with_options
+
+use alloc::vec::Vec;
+
+use crate::parser::ast::Section;
+
+/// A `;` comment line found in the document text
+#[derive(Debug, Clone, Copy)]
+struct SourceComment<'s> {
+    /// Canonical header of the enclosing section, empty before the first one
+    header: &'s str,
+    /// Number of earlier sections with the same header
+    occurrence: usize,
+    /// Byte offset of the line in the document text
+    offset: usize,
+    /// Number of `Key: Value` lines earlier in the section
+    field_index: usize,
+    /// Whether the section's `Format:` line comes later
+    before_format: bool,
+    /// Line without its line ending
+    text: &'s str,
+}
+
+/// All `;` comment lines of a document, in source order
+#[derive(Debug, Default)]
+pub(super) struct SourceComments<'s> {
+    /// Comment lines in source order
+    comments: Vec<SourceComment<'s>>,
+}
+
+impl<'s> SourceComments<'s> {
+    /// Collect the comment lines of `source`
+    ///
+    /// `[Fonts]` and `[Graphics]` are skipped, as uuencoded data lines may
+    /// start with `;`, and so are custom sections, which keep their raw
+    /// lines including comments.
+    pub(super) fn scan(source: &'s str) -> Self {
+        let mut comments = Vec::new();
+        let mut headers: Vec<&'s str> = Vec::new();
+        let mut header = "";
+        let mut field_index = 0;
+        let mut format_seen = false;
+        let mut offset = 0;
+
+        for raw in source.split_inclusive('\n') {
+            let line = raw.trim_end_matches(['\n', '\r']);
+            let trimmed = line.trim();
+            let line_offset = offset;
+            offset += raw.len();
+
+            if trimmed.starts_with('[') && trimmed.ends_with(']') {
+                header = canonical_header(&trimmed[1..trimmed.len() - 1]);
+                headers.push(header);
+                field_index = 0;
+                format_seen = false;
+                continue;
+            }
+            if !keeps_comments(header) || trimmed.is_empty() {
+                continue;
+            }
+            if trimmed.starts_with(';') {
+                comments.push(SourceComment {
+                    header,
+                    occurrence: headers.iter().filter(|&&h| h == header).count().max(1) - 1,
+                    offset: line_offset,
+                    field_index,
+                    before_format: !format_seen,
+                    text: line,
+                });
+            } else if trimmed.starts_with("Format:") {
+                format_seen = true;
+            } else if trimmed.contains(':') {
+                field_index += 1;
+            }
+        }
+
+        Self { comments }
+    }
+
+    /// Comments written before the first section header
+    pub(super) fn leading(&self) -> impl Iterator<Item = &'s str> + '_ {
+        self.comments
+            .iter()
+            .filter(|comment| comment.header.is_empty())
+            .map(|comment| comment.text)
+    }
+
+    /// Anchor the comments of the `occurrence`-th section like `section`
+    ///
+    /// With `trim` the comment lines lose surrounding whitespace.
+    pub(super) fn for_section(
+        &self,
+        section: &Section<'_>,
+        occurrence: usize,
+        trim: bool,
+    ) -> SectionComments<'s> {
+        let header = canonical_header(section.header_name());
+        let mut anchored = SectionComments::default();
+        if !keeps_comments(header) {
+            return anchored;
+        }
+
+        let item_starts: Vec<usize> = match section {
+            Section::Styles(styles) => styles.iter().map(|style| style.span.start).collect(),
+            Section::Events(events) => events.iter().map(|event| event.span.start).collect(),
+            _ => Vec::new(),
+        };
+
+        for comment in self
+            .comments
+            .iter()
+            .filter(|comment| comment.header == header && comment.occurrence == occurrence)
+        {
+            let text = if trim {
+                comment.text.trim()
+            } else {
+                comment.text
+            };
+            let anchor = match section {
+                Section::ScriptInfo(info) => {
+                    (comment.field_index < info.fields.len()).then_some(comment.field_index)
+                }
+                _ if comment.before_format
+                    && item_starts
+                        .first()
+                        .is_some_and(|&start| comment.offset < start) =>
+                {
+                    anchored.before_format.push(text);
+                    continue;
+                }
+                _ => item_starts.iter().position(|&start| start > comment.offset),
+            };
+            match anchor {
+                Some(index) => anchored.before_item.push((index, text)),
+                None => anchored.trailing.push(text),
+            }
+        }
+        anchored
+    }
+}
+
+/// `;` comments of one section, keyed by the item they precede
+#[derive(Debug, Default)]
+pub(super) struct SectionComments<'s> {
+    /// Comments between the header and the `Format:` line
+    pub(super) before_format: Vec<&'s str>,
+    /// Comments preceding the item or `[Script Info]` field at an index
+    before_item: Vec<(usize, &'s str)>,
+    /// Comments after the last item
+    pub(super) trailing: Vec<&'s str>,
+}
+
+impl<'s> SectionComments<'s> {
+    /// Comments to write before the item at `index` in source order
+    pub(super) fn before(&self, index: usize) -> impl Iterator<Item = &'s str> + '_ {
+        self.before_item
+            .iter()
+            .filter(move |(anchor, _)| *anchor == index)
+            .map(|(_, text)| *text)
+    }
+}
+
+/// Header name used to match source headers against parsed sections
+fn canonical_header(header: &str) -> &str {
+    match header.trim() {
+        "V4 Styles" | "V4+ Styles" | "V4++ Styles" => "V4+ Styles",
+        other => other,
+    }
+}
+
+/// Whether comments of sections with this header are recovered
+fn keeps_comments(header: &str) -> bool {
+    matches!(header, "" | "Script Info" | "V4+ Styles" | "Events")
+}
@@ -97,6 +97,10 @@ impl<'a> Script<'a> {
             }),
         };
 
+        if result.is_ok() && matches!(self.sections[section_index], Section::ScriptInfo(_)) {
+            self.refresh_info();
+        }
+
         // Record change if successful
         if let Ok(old_content) = &result {
             if !matches!(old_content, LineContent::Field(_, "")) {
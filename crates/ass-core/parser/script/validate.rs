@@ -0,0 +1,168 @@
+//! Lightweight semantic validation for the [`Script`] container.
+//!
+//! Implements [`Script::validate`] and [`Script::is_valid`], which check the
+//! cross-references and header values the parser accepts as written but
+//! renderers cannot use. Unlike `ScriptAnalysis::analyze` nothing is resolved
+//! or shaped, so these are cheap enough to run after every edit.
+
+use alloc::{format, string::ToString, vec::Vec};
+
+use crate::parser::ast::Section;
+use crate::parser::errors::{IssueCategory, IssueSeverity, ParseIssue};
+use crate::utils::parse_ass_time;
+use crate::ScriptVersion;
+
+use super::Script;
+
+impl Script<'_> {
+    /// Check the script for semantic problems the parser lets through
+    ///
+    /// Reports these problems, header issues first and then event issues in
+    /// document order:
+    ///
+    /// - `PlayResX` or `PlayResY` that is zero or not a number (error)
+    /// - `WrapStyle` outside `0..=3` (warning)
+    /// - `ScriptType` that is not a recognized version (warning)
+    /// - events referencing a style no styles section defines (error)
+    /// - events whose end time precedes their start time (error)
+    ///
+    /// Issues use the same type as parse issues, so both can be shown
+    /// together.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ass_core::parser::{IssueCategory, Script};
+    /// let script = Script::parse(
+    ///     "[Events]\nFormat: Layer, Start, End, Style, Name, MarginL, MarginR, MarginV, Effect, Text\nDialogue: 0,0:00:05.00,0:00:01.00,Default,,0,0,0,,Text",
+    /// )?;
+    /// let issues = script.validate();
+    /// assert_eq!(issues.len(), 2);
+    /// assert_eq!(issues[0].category, IssueCategory::Style);
+    /// assert_eq!(issues[1].category, IssueCategory::Timing);
+    /// assert!(!script.is_valid());
+    /// # Ok::<(), ass_core::CoreError>(())
+    /// ```
+    #[must_use]
+    pub fn validate(&self) -> Vec<ParseIssue> {
+        let mut issues = Vec::new();
+        self.validate_info(&mut issues);
+        self.validate_events(&mut issues);
+        issues
+    }
+
+    /// Whether [`Script::validate`] reports no errors
+    ///
+    /// Warnings do not make a script invalid.
+    #[must_use]
+    pub fn is_valid(&self) -> bool {
+        !self.validate().iter().any(|issue| {
+            matches!(
+                issue.severity,
+                IssueSeverity::Error | IssueSeverity::Critical
+            )
+        })
+    }
+
+    /// Check the `[Script Info\]` header values
+    fn validate_info(&self, issues: &mut Vec<ParseIssue>) {
+        let infos = self.sections.iter().filter_map(|section| match section {
+            Section::ScriptInfo(info) => Some(info),
+            _ => None,
+        });
+        for info in infos {
+            for &(key, value) in &info.fields {
+                let line = self.source_line(value, info.span.line);
+                let issue = match key {
+                    "PlayResX" | "PlayResY"
+                        if value.trim().parse::<u32>().map_or(true, |res| res == 0) =>
+                    {
+                        ParseIssue::error(
+                            IssueCategory::Format,
+                            format!("{key} must be a positive integer, found '{value}'"),
+                            line,
+                        )
+                    }
+                    "WrapStyle" if !matches!(value.trim().parse::<u8>(), Ok(0..=3)) => {
+                        ParseIssue::warning(
+                            IssueCategory::Format,
+                            format!("WrapStyle must be between 0 and 3, found '{value}'"),
+                            line,
+                        )
+                    }
+                    "ScriptType" if ScriptVersion::from_header(value).is_none() => {
+                        ParseIssue::warning(
+                            IssueCategory::Compatibility,
+                            format!("Unrecognized ScriptType '{value}'"),
+                            line,
+                        )
+                        .with_suggestion("Use v4.00+ for ASS or v4.00 for SSA".to_string())
+                    }
+                    _ => continue,
+                };
+                issues.push(issue);
+            }
+        }
+    }
+
+    /// Check event style references and timing
+    fn validate_events(&self, issues: &mut Vec<ParseIssue>) {
+        let style_names: Vec<&str> = self
+            .sections
+            .iter()
+            .filter_map(|section| match section {
+                Section::Styles(styles) => Some(styles.iter().map(|style| style.name)),
+                _ => None,
+            })
+            .flatten()
+            .collect();
+        let events = self
+            .sections
+            .iter()
+            .filter_map(|section| match section {
+                Section::Events(events) => Some(events.iter()),
+                _ => None,
+            })
+            .flatten();
+
+        for event in events {
+            let line = self.source_line(event.start, event.span.line);
+            if !style_names.contains(&event.style) {
+                issues.push(
+                    ParseIssue::error(
+                        IssueCategory::Style,
+                        format!("Event references undefined style: {}", event.style),
+                        line,
+                    )
+                    .with_suggestion(format!(
+                        "Define style '{}' or use an existing style",
+                        event.style
+                    )),
+                );
+            }
+            if let (Ok(start), Ok(end)) = (parse_ass_time(event.start), parse_ass_time(event.end)) {
+                if end < start {
+                    issues.push(ParseIssue::error(
+                        IssueCategory::Timing,
+                        format!(
+                            "Event ends before it starts: start {} > end {}",
+                            event.start, event.end
+                        ),
+                        line,
+                    ));
+                }
+            }
+        }
+    }
+
+    /// 1-based line of `value` in the source, or `fallback` when `value` is
+    /// not borrowed from it
+    fn source_line(&self, value: &str, fallback: u32) -> usize {
+        let base = self.source.as_ptr() as usize;
+        let offset = (value.as_ptr() as usize).wrapping_sub(base);
+        if offset > self.source.len() {
+            return fallback as usize;
+        }
+        self.source[..offset].matches('\n').count() + 1
+    }
+}
@@ -0,0 +1,155 @@
+//! Tests for lightweight semantic validation.
+
+use super::*;
+use crate::parser::{IssueCategory, IssueSeverity};
+#[cfg(not(feature = "std"))]
+use alloc::{format, vec::Vec};
+
+const VALID: &str = "[Script Info]
+Title: Valid
+ScriptType: v4.00+
+PlayResX: 1920
+PlayResY: 1080
+WrapStyle: 2
+
+[V4+ Styles]
+Format: Name, Fontname, Fontsize, PrimaryColour, SecondaryColour, OutlineColour, BackColour, Bold, Italic, Underline, StrikeOut, ScaleX, ScaleY, Spacing, Angle, BorderStyle, Outline, Shadow, Alignment, MarginL, MarginR, MarginV, Encoding
+Style: Default,Arial,20,&H00FFFFFF,&H000000FF,&H00000000,&H00000000,0,0,0,0,100,100,0,0,1,2,0,2,10,10,10,1
+Style: Sign,Arial,30,&H00FFFFFF,&H000000FF,&H00000000,&H00000000,0,0,0,0,100,100,0,0,1,2,0,8,10,10,10,1
+
+[Events]
+Format: Layer, Start, End, Style, Name, MarginL, MarginR, MarginV, Effect, Text
+Dialogue: 0,0:00:01.00,0:00:02.00,Default,,0,0,0,,First
+Dialogue: 0,0:00:02.00,0:00:02.00,Sign,,0,0,0,,Zero length is fine
+";
+
+#[test]
+fn valid_script_has_no_issues() {
+    let script = Script::parse(VALID).unwrap();
+    assert!(script.validate().is_empty());
+    assert!(script.is_valid());
+
+    // Headers are only checked when present
+    let script = Script::parse("[Script Info]\nTitle: Bare\n").unwrap();
+    assert!(script.validate().is_empty());
+}
+
+#[test]
+fn undefined_style_reference() {
+    let source = VALID.replace(",Sign,,", ",Missing,,");
+    let script = Script::parse(&source).unwrap();
+    let issues = script.validate();
+
+    assert_eq!(issues.len(), 1);
+    assert_eq!(issues[0].severity, IssueSeverity::Error);
+    assert_eq!(issues[0].category, IssueCategory::Style);
+    assert_eq!(
+        issues[0].message,
+        "Event references undefined style: Missing"
+    );
+    assert_eq!(issues[0].line, 16);
+    assert!(issues[0].suggestion.is_some());
+    assert!(!script.is_valid());
+}
+
+#[test]
+fn event_ending_before_start() {
+    let source = VALID.replace("0:00:01.00,0:00:02.00", "0:00:03.00,0:00:02.00");
+    let script = Script::parse(&source).unwrap();
+    let issues = script.validate();
+
+    assert_eq!(issues.len(), 1);
+    assert_eq!(issues[0].severity, IssueSeverity::Error);
+    assert_eq!(issues[0].category, IssueCategory::Timing);
+    assert_eq!(
+        issues[0].message,
+        "Event ends before it starts: start 0:00:03.00 > end 0:00:02.00"
+    );
+    assert_eq!(issues[0].line, 15);
+    assert!(!script.is_valid());
+}
+
+#[test]
+fn zero_or_invalid_play_resolution() {
+    for (header, value) in [("PlayResX", "0"), ("PlayResY", "0"), ("PlayResY", "tall")] {
+        let source = VALID.replace(
+            &format!(
+                "{header}: {}",
+                if header == "PlayResX" { 1920 } else { 1080 }
+            ),
+            &format!("{header}: {value}"),
+        );
+        let script = Script::parse(&source).unwrap();
+        let issues = script.validate();
+
+        assert_eq!(issues.len(), 1, "{header}: {value}");
+        assert_eq!(issues[0].severity, IssueSeverity::Error);
+        assert_eq!(issues[0].category, IssueCategory::Format);
+        assert_eq!(
+            issues[0].message,
+            format!("{header} must be a positive integer, found '{value}'")
+        );
+        assert_eq!(issues[0].line, if header == "PlayResX" { 4 } else { 5 });
+        assert!(!script.is_valid());
+    }
+}
+
+#[test]
+fn wrap_style_out_of_range() {
+    for value in ["4", "-1", "smart"] {
+        let source = VALID.replace("WrapStyle: 2", &format!("WrapStyle: {value}"));
+        let script = Script::parse(&source).unwrap();
+        let issues = script.validate();
+
+        assert_eq!(issues.len(), 1, "WrapStyle: {value}");
+        assert_eq!(issues[0].severity, IssueSeverity::Warning);
+        assert_eq!(issues[0].category, IssueCategory::Format);
+        assert_eq!(issues[0].line, 6);
+        // Warnings alone keep the script valid
+        assert!(script.is_valid());
+    }
+}
+
+#[test]
+fn unrecognized_script_type() {
+    for value in ["v4.00", "v4.00++"] {
+        let source = VALID.replace("ScriptType: v4.00+", &format!("ScriptType: {value}"));
+        assert!(Script::parse(&source).unwrap().validate().is_empty());
+    }
+
+    let source = VALID.replace("ScriptType: v4.00+", "ScriptType: v5.00");
+    let script = Script::parse(&source).unwrap();
+    let issues = script.validate();
+
+    assert_eq!(issues.len(), 1);
+    assert_eq!(issues[0].severity, IssueSeverity::Warning);
+    assert_eq!(issues[0].category, IssueCategory::Compatibility);
+    assert_eq!(issues[0].message, "Unrecognized ScriptType 'v5.00'");
+    assert_eq!(issues[0].line, 3);
+    assert!(script.is_valid());
+}
+
+#[test]
+fn issues_are_reported_together() {
+    let source = VALID
+        .replace("PlayResX: 1920", "PlayResX: 0")
+        .replace("WrapStyle: 2", "WrapStyle: 9")
+        .replace(",Default,,", ",Nope,,")
+        .replace("0:00:02.00,0:00:02.00", "0:00:02.00,0:00:01.00");
+    let script = Script::parse(&source).unwrap();
+    let categories: Vec<IssueCategory> = script
+        .validate()
+        .iter()
+        .map(|issue| issue.category)
+        .collect();
+
+    assert_eq!(
+        categories,
+        [
+            IssueCategory::Format,
+            IssueCategory::Format,
+            IssueCategory::Style,
+            IssueCategory::Timing
+        ]
+    );
+}
@@ -69,7 +69,7 @@ pub struct SectionFormats<'a> {
 /// # Errors
 ///
 /// Returns [`ParseError::MissingFormat`] if required format is not provided
-/// Returns [`ParseError::UnsupportedSection`] for Fonts/Graphics sections
+/// Returns [`ParseError::UnsupportedSection`] for Fonts/Graphics/Custom sections
 /// Returns other parse errors from section-specific parsers
 pub fn parse_section_with_context<'a>(
     section_type: SectionType,
@@ -105,6 +105,8 @@ pub fn parse_section_with_context<'a>(
             // These sections are parsed as binary data
             Err(ParseError::UnsupportedSection(section_type))
         }
+        // Custom sections are only parsed by their registered handlers
+        SectionType::Custom => Err(ParseError::UnsupportedSection(section_type)),
     }
 }
 
@@ -0,0 +1,75 @@
+//! Per-event state that built-in tags apply their arguments to.
+//!
+//! Defines [`AnimationState`], the position, alignment and fade of a single
+//! event at one point in time, as updated by [`Tag::apply`](super::Tag::apply).
+
+/// Positioning and fade state of one event at a point in time
+///
+/// Positioning tags follow libass and only take effect the first time they
+/// appear in an event: once a position, alignment or fade is set, later
+/// tags of the same kind leave it unchanged.
+///
+/// # Example
+///
+/// ```rust
+/// use ass_core::plugin::{AnimationState, PositionTagHandler, Tag};
+///
+/// let mut state = AnimationState::new(0, 5000);
+/// let args = PositionTagHandler.parse_args("(320,240)")?;
+/// PositionTagHandler.apply(&args, &mut state);
+/// assert_eq!(state.position, Some((320.0, 240.0)));
+/// # Ok::<(), ass_core::plugin::PluginError>(())
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AnimationState {
+    /// Time since the event start, in milliseconds
+    pub time_ms: u32,
+    /// Event duration in milliseconds
+    pub duration_ms: u32,
+    /// Explicit position set by `\pos` or `\move`
+    pub position: Option<(f32, f32)>,
+    /// Numpad alignment (1-9) set by `\an`
+    pub alignment: Option<u8>,
+    /// Fade alpha set by `\fad` or `\fade` (`0` is opaque, `255` transparent)
+    pub fade_alpha: Option<u8>,
+}
+
+impl AnimationState {
+    /// Create the state of an event `time_ms` into its `duration_ms`, with
+    /// nothing set yet
+    #[must_use]
+    pub const fn new(time_ms: u32, duration_ms: u32) -> Self {
+        Self {
+            time_ms,
+            duration_ms,
+            position: None,
+            alignment: None,
+            fade_alpha: None,
+        }
+    }
+}
+
+/// Alpha of a `\fade` at `time_ms`: `alphas[0]` before `times[0]`, ramping
+/// to `alphas[1]` by `times[1]`, holding until `times[2]` and ramping to
+/// `alphas[2]` by `times[3]`
+pub fn fade_alpha(time_ms: u32, alphas: [u8; 3], times: [u32; 4]) -> u8 {
+    let [a1, a2, a3] = alphas;
+    let [t1, t2, t3, t4] = times;
+    if time_ms < t1 {
+        a1
+    } else if time_ms < t2 {
+        ramp(a1, a2, time_ms - t1, t2 - t1)
+    } else if time_ms < t3 {
+        a2
+    } else if time_ms < t4 {
+        ramp(a2, a3, time_ms - t3, t4 - t3)
+    } else {
+        a3
+    }
+}
+
+/// Linear interpolation from `from` to `to` after `elapsed` of `span`
+fn ramp(from: u8, to: u8, elapsed: u32, span: u32) -> u8 {
+    let delta = (i64::from(to) - i64::from(from)) * i64::from(elapsed) / i64::from(span.max(1));
+    u8::try_from(i64::from(from) + delta).unwrap_or(to)
+}
@@ -0,0 +1,81 @@
+//! Built-in positioning and fade tags.
+//!
+//! Registers the [`Tag`](super::Tag) implementations for `\pos`, `\an`,
+//! `\move`, `\fade` and `\fad` and provides the argument helpers they share.
+
+use super::tags::{
+    FadeTagHandler, MoveTagHandler, NumpadAlignmentTagHandler, PositionTagHandler,
+    SimpleFadeTagHandler,
+};
+use super::{ExtensionRegistry, PluginError, Result, TagHandler};
+use alloc::{boxed::Box, format, vec, vec::Vec};
+use core::str::FromStr;
+
+/// Registration priority of built-in tags, below the default of plugins
+pub const BUILTIN_TAG_PRIORITY: i32 = -100;
+
+/// Create the handlers of the built-in positioning and fade tags
+///
+/// Returns `\pos`, `\an`, `\move`, `\fade` and `\fad`, each of which also
+/// implements [`Tag`](super::Tag).
+#[must_use]
+pub fn create_builtin_tags() -> Vec<Box<dyn TagHandler>> {
+    vec![
+        Box::new(PositionTagHandler),
+        Box::new(NumpadAlignmentTagHandler),
+        Box::new(MoveTagHandler),
+        Box::new(FadeTagHandler),
+        Box::new(SimpleFadeTagHandler),
+    ]
+}
+
+/// Register the built-in positioning and fade tags with `registry`
+///
+/// Tags are registered at [`BUILTIN_TAG_PRIORITY`], so handlers already
+/// registered for the same names are kept.
+///
+/// # Example
+///
+/// ```rust
+/// use ass_core::plugin::{register_builtin_tags, ExtensionRegistry};
+///
+/// let mut registry = ExtensionRegistry::new();
+/// register_builtin_tags(&mut registry)?;
+/// assert!(registry.has_tag_handler("move"));
+/// # Ok::<(), ass_core::plugin::PluginError>(())
+/// ```
+///
+/// # Errors
+/// Returns `PluginError::DuplicateHandler` if one of the tags is already
+/// registered at the built-in priority
+pub fn register_builtin_tags(registry: &mut ExtensionRegistry) -> Result<()> {
+    for handler in create_builtin_tags() {
+        registry.register_tag_handler_with_priority(handler, BUILTIN_TAG_PRIORITY)?;
+    }
+    Ok(())
+}
+
+/// Split tag arguments on commas, dropping optional surrounding parentheses,
+/// and check the count against `expected`
+pub fn split_args<'a>(tag: &str, args: &'a str, expected: &[usize]) -> Result<Vec<&'a str>> {
+    let trimmed = args.trim();
+    let inner = trimmed
+        .strip_prefix('(')
+        .and_then(|inner| inner.strip_suffix(')'))
+        .unwrap_or(trimmed);
+    let parts: Vec<&str> = inner.split(',').map(str::trim).collect();
+    if expected.contains(&parts.len()) {
+        Ok(parts)
+    } else {
+        Err(PluginError::ProcessingFailed(format!(
+            "\\{tag} expects {expected:?} arguments, got {}",
+            parts.len()
+        )))
+    }
+}
+
+/// Parse a single tag argument
+pub fn parse_arg<T: FromStr>(tag: &str, arg: &str) -> Result<T> {
+    arg.parse()
+        .map_err(|_| PluginError::ProcessingFailed(format!("\\{tag} has invalid argument '{arg}'")))
+}
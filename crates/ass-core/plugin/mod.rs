@@ -33,6 +33,8 @@
 pub mod sections;
 pub mod tags;
 
+mod animation_state;
+mod builtins;
 mod error;
 mod registry;
 mod traits;
@@ -40,9 +42,11 @@ mod traits;
 #[cfg(test)]
 mod tests;
 
+pub use animation_state::AnimationState;
+pub use builtins::{create_builtin_tags, register_builtin_tags, BUILTIN_TAG_PRIORITY};
 pub use error::{PluginError, Result};
 pub use registry::ExtensionRegistry;
-pub use traits::{SectionProcessor, SectionResult, TagHandler, TagResult};
+pub use traits::{SectionProcessor, SectionResult, Tag, TagHandler, TagResult};
 
 pub use sections::aegisub::{
     create_aegisub_processors, AegisubExtradataProcessor, AegisubProjectProcessor,
@@ -54,7 +58,8 @@ pub use tags::{
         WrappingStyleTagHandler,
     },
     animation::{
-        create_animation_handlers, FadeTagHandler, SimpleFadeTagHandler, TransformTagHandler,
+        create_animation_handlers, FadeArgs, FadeTagHandler, SimpleFadeTagHandler,
+        TransformTagHandler,
     },
     clipping::{create_clipping_handlers, ClipTagHandler},
     color::{
@@ -72,7 +77,7 @@ pub use tags::{
         KaraokeTimingTagHandler, OutlineKaraokeTagHandler,
     },
     misc::{create_misc_handlers, OriginTagHandler, ResetTagHandler, ShortRotationTagHandler},
-    position::{create_position_handlers, MoveTagHandler, PositionTagHandler},
+    position::{create_position_handlers, MoveArgs, MoveTagHandler, PositionTagHandler},
     special::{
         create_special_handlers, HardLineBreakTagHandler, HardSpaceTagHandler,
         SoftLineBreakTagHandler,
@@ -17,13 +17,25 @@ use std::collections::HashMap;
 #[cfg(not(feature = "std"))]
 use hashbrown::HashMap;
 
+/// Tag handler together with the priority it was registered at
+struct RegisteredTag {
+    /// Priority used to resolve conflicting registrations
+    priority: i32,
+    /// Handler implementation
+    handler: Box<dyn TagHandler>,
+}
+
 /// Central registry for all ASS format extensions
 ///
 /// Manages registration and lookup of tag handlers and section processors.
 /// Optimized for fast lookup during parsing with minimal memory overhead.
+///
+/// When several tag handlers claim the same name, the one registered with
+/// the highest priority handles it; see
+/// [`register_tag_handler_with_priority`](Self::register_tag_handler_with_priority).
 pub struct ExtensionRegistry {
-    /// Registered tag handlers indexed by tag name
-    tag_handlers: HashMap<String, Box<dyn TagHandler>>,
+    /// Registered tag handlers and their priorities indexed by tag name
+    tag_handlers: HashMap<String, RegisteredTag>,
     /// Registered section processors indexed by section name
     section_processors: HashMap<String, Box<dyn SectionProcessor>>,
 }
@@ -38,21 +50,70 @@ impl ExtensionRegistry {
         }
     }
 
-    /// Register a new tag handler
+    /// Create a registry with the built-in positioning and fade tags
+    ///
+    /// Registers `\pos`, `\an`, `\move`, `\fade` and `\fad` through
+    /// [`register_builtin_tags`](super::register_builtin_tags).
+    #[must_use]
+    pub fn with_builtin_tags() -> Self {
+        let mut registry = Self::new();
+        for handler in super::create_builtin_tags() {
+            registry.tag_handlers.insert(
+                handler.name().to_string(),
+                RegisteredTag {
+                    priority: super::BUILTIN_TAG_PRIORITY,
+                    handler,
+                },
+            );
+        }
+        registry
+    }
+
+    /// Register a new tag handler at its own [`TagHandler::priority`]
     ///
     /// # Arguments
     /// * `handler` - Boxed tag handler implementation
     ///
     /// # Errors
-    /// Returns `PluginError::DuplicateHandler` if handler name already exists
+    /// Returns `PluginError::DuplicateHandler` if a handler with the same name
+    /// is already registered at the same priority
     pub fn register_tag_handler(&mut self, handler: Box<dyn TagHandler>) -> Result<()> {
+        let priority = handler.priority();
+        self.register_tag_handler_with_priority(handler, priority)
+    }
+
+    /// Register a tag handler at an explicit priority
+    ///
+    /// If a handler with the same name is already registered, the one with
+    /// the higher priority is kept and the other is dropped, regardless of
+    /// registration order. This lets plugins override built-in tags, which
+    /// are registered at [`BUILTIN_TAG_PRIORITY`](super::BUILTIN_TAG_PRIORITY).
+    ///
+    /// # Arguments
+    /// * `handler` - Boxed tag handler implementation
+    /// * `priority` - Precedence over other handlers of the same tag
+    ///
+    /// # Errors
+    /// Returns `PluginError::DuplicateHandler` if a handler with the same name
+    /// is already registered at the same priority
+    pub fn register_tag_handler_with_priority(
+        &mut self,
+        handler: Box<dyn TagHandler>,
+        priority: i32,
+    ) -> Result<()> {
         let name = handler.name().to_string();
 
-        if self.tag_handlers.contains_key(&name) {
-            return Err(PluginError::DuplicateHandler(name));
+        if let Some(existing) = self.tag_handlers.get(&name) {
+            if existing.priority == priority {
+                return Err(PluginError::DuplicateHandler(name));
+            }
+            if existing.priority > priority {
+                return Ok(());
+            }
         }
 
-        self.tag_handlers.insert(name, handler);
+        self.tag_handlers
+            .insert(name, RegisteredTag { priority, handler });
         Ok(())
     }
 
@@ -90,7 +151,7 @@ impl ExtensionRegistry {
     pub fn process_tag(&self, tag_name: &str, args: &str) -> Option<TagResult> {
         self.tag_handlers
             .get(tag_name)
-            .map(|handler| handler.process(args))
+            .map(|registered| registered.handler.process(args))
     }
 
     /// Process a section using registered processors
@@ -121,6 +182,22 @@ impl ExtensionRegistry {
         self.tag_handlers.keys().map(String::as_str).collect()
     }
 
+    /// Get the handler currently registered for a tag
+    #[must_use]
+    pub fn tag_handler(&self, name: &str) -> Option<&dyn TagHandler> {
+        self.tag_handlers
+            .get(name)
+            .map(|registered| registered.handler.as_ref())
+    }
+
+    /// Get the priority the handler of a tag was registered at
+    #[must_use]
+    pub fn tag_priority(&self, name: &str) -> Option<i32> {
+        self.tag_handlers
+            .get(name)
+            .map(|registered| registered.priority)
+    }
+
     /// Get list of registered section processor names
     #[must_use]
     pub fn section_processor_names(&self) -> Vec<&str> {
@@ -145,7 +222,9 @@ impl ExtensionRegistry {
     /// * `Some(handler)` - If handler was found and removed
     /// * `None` - If no handler with that name was registered
     pub fn remove_tag_handler(&mut self, name: &str) -> Option<Box<dyn TagHandler>> {
-        self.tag_handlers.remove(name)
+        self.tag_handlers
+            .remove(name)
+            .map(|registered| registered.handler)
     }
 
     /// Remove a section processor by name
@@ -2,9 +2,10 @@
 //!
 //! Implements [`TagHandler`] for the `\a`, `\an`, and `\q` commands. Each
 //! handler validates its argument against the ASS specification with zero
-//! allocations and fast integer comparisons.
+//! allocations and fast integer comparisons. `\an` also implements [`Tag`].
 
-use crate::plugin::{TagHandler, TagResult};
+use crate::plugin::builtins::{parse_arg, split_args};
+use crate::plugin::{AnimationState, PluginError, Result, Tag, TagHandler, TagResult};
 use alloc::string::String;
 
 /// Handler for legacy alignment tag (`\a`)
@@ -68,6 +69,35 @@ impl TagHandler for NumpadAlignmentTagHandler {
     }
 }
 
+impl Tag for NumpadAlignmentTagHandler {
+    type Args = u8;
+
+    fn supports_animation(&self) -> bool {
+        false
+    }
+
+    fn expected_args(&self) -> &'static [usize] {
+        &[1]
+    }
+
+    fn parse_args(&self, args: &str) -> Result<Self::Args> {
+        let parts = split_args(self.name(), args, self.expected_args())?;
+        let alignment: u8 = parse_arg(self.name(), parts[0])?;
+        if (1..=9).contains(&alignment) {
+            Ok(alignment)
+        } else {
+            Err(PluginError::ProcessingFailed(String::from(
+                "Numpad alignment tag requires value 1-9",
+            )))
+        }
+    }
+
+    /// Set the alignment unless an earlier `\an` already did
+    fn apply(&self, args: &Self::Args, state: &mut AnimationState) {
+        state.alignment.get_or_insert(*args);
+    }
+}
+
 /// Handler for wrapping style tag (`\q`)
 ///
 /// Controls text wrapping behavior:
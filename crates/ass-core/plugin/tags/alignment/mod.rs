@@ -17,6 +17,8 @@
 
 mod handlers;
 
+#[cfg(test)]
+mod tag_tests;
 #[cfg(test)]
 mod tests;
 
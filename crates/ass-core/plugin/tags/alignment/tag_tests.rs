@@ -0,0 +1,29 @@
+//! Tests for the typed [`Tag`] behavior of `\an`.
+
+use super::*;
+use crate::plugin::{AnimationState, Tag};
+#[cfg(not(feature = "std"))]
+use alloc::string::ToString;
+
+#[test]
+fn numpad_alignment_parse_args() {
+    let tag = NumpadAlignmentTagHandler;
+    for alignment in 1..=9u8 {
+        assert_eq!(tag.parse_args(&alignment.to_string()).unwrap(), alignment);
+    }
+    assert!(tag.parse_args("0").is_err());
+    assert!(tag.parse_args("10").is_err());
+    assert!(tag.parse_args("7,8").is_err());
+    assert!(tag.parse_args("top").is_err());
+    assert!(!tag.supports_animation());
+    assert_eq!(tag.expected_args(), &[1]);
+}
+
+#[test]
+fn numpad_alignment_apply_keeps_first_alignment() {
+    let tag = NumpadAlignmentTagHandler;
+    let mut state = AnimationState::new(0, 1000);
+    tag.apply(&8, &mut state);
+    tag.apply(&2, &mut state);
+    assert_eq!(state.alignment, Some(8));
+}
@@ -3,9 +3,20 @@
 //! Implements [`TagHandler`] for the complex `\fade` and simple `\fad`
 //! alpha-transparency animation commands.
 
-use crate::plugin::{TagHandler, TagResult};
+use crate::plugin::animation_state::fade_alpha;
+use crate::plugin::builtins::{parse_arg, split_args};
+use crate::plugin::{AnimationState, Result, Tag, TagHandler, TagResult};
 use alloc::string::String;
 
+/// Parsed `\fade` arguments
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FadeArgs {
+    /// Alpha before `t1`, between `t2` and `t3`, and after `t4`
+    pub alphas: [u8; 3],
+    /// Transition boundaries in milliseconds from the event start
+    pub times: [u32; 4],
+}
+
 /// Handler for complex fade animation tag (`\fade`)
 ///
 /// Animates alpha transparency with 7 parameters:
@@ -74,6 +85,35 @@ impl TagHandler for FadeTagHandler {
     }
 }
 
+impl Tag for FadeTagHandler {
+    type Args = FadeArgs;
+
+    fn supports_animation(&self) -> bool {
+        true
+    }
+
+    fn expected_args(&self) -> &'static [usize] {
+        &[7]
+    }
+
+    fn parse_args(&self, args: &str) -> Result<Self::Args> {
+        let parts = split_args(self.name(), args, self.expected_args())?;
+        let alpha = |index: usize| parse_arg::<u8>(self.name(), parts[index]);
+        let time = |index: usize| parse_arg::<u32>(self.name(), parts[index]);
+        Ok(FadeArgs {
+            alphas: [alpha(0)?, alpha(1)?, alpha(2)?],
+            times: [time(3)?, time(4)?, time(5)?, time(6)?],
+        })
+    }
+
+    /// Set the fade alpha at the state's time unless an earlier fade did
+    fn apply(&self, args: &Self::Args, state: &mut AnimationState) {
+        state
+            .fade_alpha
+            .get_or_insert_with(|| fade_alpha(state.time_ms, args.alphas, args.times));
+    }
+}
+
 /// Handler for simple fade in/out tag (`\fad`)
 ///
 /// Simple fade effect with 2 parameters:
@@ -125,3 +165,34 @@ impl TagHandler for SimpleFadeTagHandler {
         true
     }
 }
+
+impl Tag for SimpleFadeTagHandler {
+    type Args = (u32, u32);
+
+    fn supports_animation(&self) -> bool {
+        true
+    }
+
+    fn expected_args(&self) -> &'static [usize] {
+        &[2]
+    }
+
+    fn parse_args(&self, args: &str) -> Result<Self::Args> {
+        let parts = split_args(self.name(), args, self.expected_args())?;
+        Ok((
+            parse_arg(self.name(), parts[0])?,
+            parse_arg(self.name(), parts[1])?,
+        ))
+    }
+
+    /// Fade in from transparent over the first duration and out over the
+    /// last, unless an earlier fade already set the alpha
+    fn apply(&self, args: &Self::Args, state: &mut AnimationState) {
+        let (fade_in, fade_out) = *args;
+        let end = state.duration_ms;
+        let times = [0, fade_in, end.saturating_sub(fade_out), end];
+        state
+            .fade_alpha
+            .get_or_insert_with(|| fade_alpha(state.time_ms, [255, 0, 255], times));
+    }
+}
@@ -0,0 +1,70 @@
+//! Tests for the typed [`Tag`] behavior of `\fade` and `\fad`.
+
+use super::*;
+use crate::plugin::{AnimationState, Tag};
+
+/// Fade alpha of `tag` with `args` at `time` ms into a 2 s event
+fn alpha_at<T: Tag>(tag: &T, args: &T::Args, time: u32) -> Option<u8> {
+    let mut state = AnimationState::new(time, 2000);
+    tag.apply(args, &mut state);
+    state.fade_alpha
+}
+
+#[test]
+fn fade_parse_args() {
+    let tag = FadeTagHandler;
+    assert_eq!(
+        tag.parse_args("(255,0,128,0,500,1500,2000)").unwrap(),
+        FadeArgs {
+            alphas: [255, 0, 128],
+            times: [0, 500, 1500, 2000],
+        }
+    );
+    assert!(tag.parse_args("(255,0,128,0,500,1500)").is_err());
+    assert!(tag.parse_args("(256,0,0,0,0,0,0)").is_err());
+    assert!(tag.parse_args("(0,0,0,-1,0,0,0)").is_err());
+    assert!(tag.supports_animation());
+    assert_eq!(tag.expected_args(), &[7]);
+}
+
+#[test]
+fn fade_apply_follows_piecewise_ramp() {
+    let tag = FadeTagHandler;
+    let args = tag.parse_args("(200,0,100,100,300,1500,1700)").unwrap();
+    assert_eq!(alpha_at(&tag, &args, 50), Some(200));
+    assert_eq!(alpha_at(&tag, &args, 200), Some(100));
+    assert_eq!(alpha_at(&tag, &args, 1000), Some(0));
+    assert_eq!(alpha_at(&tag, &args, 1600), Some(50));
+    assert_eq!(alpha_at(&tag, &args, 1900), Some(100));
+}
+
+#[test]
+fn simple_fade_parse_args() {
+    let tag = SimpleFadeTagHandler;
+    assert_eq!(tag.parse_args("(300,500)").unwrap(), (300, 500));
+    assert_eq!(tag.parse_args("0,0").unwrap(), (0, 0));
+    assert!(tag.parse_args("(300)").is_err());
+    assert!(tag.parse_args("(-300,500)").is_err());
+    assert!(tag.supports_animation());
+    assert_eq!(tag.expected_args(), &[2]);
+}
+
+#[test]
+fn simple_fade_apply_fades_in_and_out() {
+    let tag = SimpleFadeTagHandler;
+    let args = (400, 400);
+    assert_eq!(alpha_at(&tag, &args, 0), Some(255));
+    assert_eq!(alpha_at(&tag, &args, 200), Some(128));
+    assert_eq!(alpha_at(&tag, &args, 1000), Some(0));
+    assert_eq!(alpha_at(&tag, &args, 1800), Some(127));
+    assert_eq!(alpha_at(&tag, &args, 2000), Some(255));
+}
+
+#[test]
+fn first_fade_wins() {
+    let mut state = AnimationState::new(1000, 2000);
+    SimpleFadeTagHandler.apply(&(400, 400), &mut state);
+    let complex = FadeTagHandler.parse_args("(255,255,255,0,0,0,0)").unwrap();
+    FadeTagHandler.apply(&complex, &mut state);
+    assert_eq!(state.fade_alpha, Some(0));
+}
@@ -19,12 +19,14 @@
 mod fade_handlers;
 mod transform_handlers;
 
+#[cfg(test)]
+mod fade_tag_tests;
 #[cfg(test)]
 mod fade_tests;
 #[cfg(test)]
 mod transform_tests;
 
-pub use fade_handlers::{FadeTagHandler, SimpleFadeTagHandler};
+pub use fade_handlers::{FadeArgs, FadeTagHandler, SimpleFadeTagHandler};
 pub use transform_handlers::TransformTagHandler;
 
 use crate::plugin::TagHandler;
@@ -51,7 +51,7 @@ pub use alignment::{
     WrappingStyleTagHandler,
 };
 pub use animation::{
-    create_animation_handlers, FadeTagHandler, SimpleFadeTagHandler, TransformTagHandler,
+    create_animation_handlers, FadeArgs, FadeTagHandler, SimpleFadeTagHandler, TransformTagHandler,
 };
 pub use clipping::{create_clipping_handlers, ClipTagHandler};
 pub use color::{
@@ -71,7 +71,7 @@ pub use karaoke::{
     KaraokeTimingTagHandler, OutlineKaraokeTagHandler,
 };
 pub use misc::{create_misc_handlers, OriginTagHandler, ResetTagHandler, ShortRotationTagHandler};
-pub use position::{create_position_handlers, MoveTagHandler, PositionTagHandler};
+pub use position::{create_position_handlers, MoveArgs, MoveTagHandler, PositionTagHandler};
 pub use special::{
     create_special_handlers, HardLineBreakTagHandler, HardSpaceTagHandler, SoftLineBreakTagHandler,
 };
@@ -21,8 +21,10 @@ mod validation;
 
 #[cfg(test)]
 mod handler_tests;
+#[cfg(test)]
+mod tag_tests;
 
-pub use move_handler::MoveTagHandler;
+pub use move_handler::{MoveArgs, MoveTagHandler};
 pub use position_handler::PositionTagHandler;
 
 use crate::plugin::TagHandler;
@@ -4,9 +4,22 @@
 //! `(x1,y1)` to `(x2,y2)` optionally between times `t1` and `t2`.
 
 use super::validation::is_numeric;
-use crate::plugin::{TagHandler, TagResult};
+use crate::plugin::builtins::{parse_arg, split_args};
+use crate::plugin::{AnimationState, Result, Tag, TagHandler, TagResult};
 use alloc::string::String;
 
+/// Parsed `\move` arguments
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MoveArgs {
+    /// Start position
+    pub from: (f32, f32),
+    /// End position
+    pub to: (f32, f32),
+    /// Movement start and end in milliseconds from the event start; the
+    /// whole event when absent
+    pub times: Option<(u32, u32)>,
+}
+
 /// Handler for movement tag (`\move`)
 ///
 /// Moves text from (x1,y1) to (x2,y2) optionally between times t1 and t2.
@@ -45,3 +58,62 @@ impl TagHandler for MoveTagHandler {
         parts.iter().all(|part| is_numeric(part))
     }
 }
+
+impl Tag for MoveTagHandler {
+    type Args = MoveArgs;
+
+    fn supports_animation(&self) -> bool {
+        true
+    }
+
+    fn expected_args(&self) -> &'static [usize] {
+        &[4, 6]
+    }
+
+    fn parse_args(&self, args: &str) -> Result<Self::Args> {
+        let parts = split_args(self.name(), args, self.expected_args())?;
+        let coordinate = |index: usize| parse_arg::<f32>(self.name(), parts[index]);
+        let times = if parts.len() == 6 {
+            Some((
+                parse_arg(self.name(), parts[4])?,
+                parse_arg(self.name(), parts[5])?,
+            ))
+        } else {
+            None
+        };
+        Ok(MoveArgs {
+            from: (coordinate(0)?, coordinate(1)?),
+            to: (coordinate(2)?, coordinate(3)?),
+            times,
+        })
+    }
+
+    /// Interpolate the position at the state's time unless the event was
+    /// already positioned
+    ///
+    /// Reversed times are swapped and equal times move over the whole event,
+    /// as in libass.
+    #[allow(clippy::cast_precision_loss)]
+    fn apply(&self, args: &Self::Args, state: &mut AnimationState) {
+        if state.position.is_some() {
+            return;
+        }
+        let (t1, t2) = match args.times {
+            Some((t1, t2)) if t1 != t2 => (t1.min(t2), t1.max(t2)),
+            _ => (0, state.duration_ms),
+        };
+        let progress = if state.time_ms <= t1 {
+            0.0
+        } else if state.time_ms >= t2 {
+            1.0
+        } else {
+            (state.time_ms - t1) as f32 / (t2 - t1) as f32
+        };
+        let (x1, y1) = args.from;
+        let (x2, y2) = args.to;
+        state.position = Some((
+            (x2 - x1).mul_add(progress, x1),
+            (y2 - y1).mul_add(progress, y1),
+        ));
+    }
+}
@@ -4,7 +4,8 @@
 //! absolute `(x,y)` coordinates. Arguments must be two comma-separated numbers.
 
 use super::validation::is_numeric;
-use crate::plugin::{TagHandler, TagResult};
+use crate::plugin::builtins::{parse_arg, split_args};
+use crate::plugin::{AnimationState, Result, Tag, TagHandler, TagResult};
 use alloc::string::String;
 
 /// Handler for position tag (`\pos`)
@@ -44,3 +45,28 @@ impl TagHandler for PositionTagHandler {
         }
     }
 }
+
+impl Tag for PositionTagHandler {
+    type Args = (f32, f32);
+
+    fn supports_animation(&self) -> bool {
+        false
+    }
+
+    fn expected_args(&self) -> &'static [usize] {
+        &[2]
+    }
+
+    fn parse_args(&self, args: &str) -> Result<Self::Args> {
+        let parts = split_args(self.name(), args, self.expected_args())?;
+        Ok((
+            parse_arg(self.name(), parts[0])?,
+            parse_arg(self.name(), parts[1])?,
+        ))
+    }
+
+    /// Place the event at the position unless it was already positioned
+    fn apply(&self, args: &Self::Args, state: &mut AnimationState) {
+        state.position.get_or_insert(*args);
+    }
+}
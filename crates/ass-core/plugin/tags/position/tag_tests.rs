@@ -0,0 +1,87 @@
+//! Tests for the typed [`Tag`] behavior of `\pos` and `\move`.
+
+use super::*;
+use crate::plugin::{AnimationState, Tag};
+
+#[test]
+fn position_parse_args() {
+    let tag = PositionTagHandler;
+    assert_eq!(tag.parse_args("100,200").unwrap(), (100.0, 200.0));
+    assert_eq!(tag.parse_args("(-5.5, 20)").unwrap(), (-5.5, 20.0));
+    assert!(tag.parse_args("(1)").is_err());
+    assert!(tag.parse_args("(1,2,3)").is_err());
+    assert!(tag.parse_args("(x,2)").is_err());
+    assert!(!tag.supports_animation());
+    assert_eq!(tag.expected_args(), &[2]);
+}
+
+#[test]
+fn position_apply_keeps_first_position() {
+    let tag = PositionTagHandler;
+    let mut state = AnimationState::new(0, 1000);
+    tag.apply(&(10.0, 20.0), &mut state);
+    tag.apply(&(30.0, 40.0), &mut state);
+    assert_eq!(state.position, Some((10.0, 20.0)));
+}
+
+#[test]
+fn move_parse_args() {
+    let tag = MoveTagHandler;
+    assert_eq!(
+        tag.parse_args("(0,0,100,50)").unwrap(),
+        MoveArgs {
+            from: (0.0, 0.0),
+            to: (100.0, 50.0),
+            times: None,
+        }
+    );
+    assert_eq!(
+        tag.parse_args("0,0,100,50,200,800").unwrap().times,
+        Some((200, 800))
+    );
+    assert!(tag.parse_args("(0,0,100)").is_err());
+    assert!(tag.parse_args("(0,0,100,50,200)").is_err());
+    assert!(tag.parse_args("(0,0,100,50,-1,800)").is_err());
+    assert!(tag.supports_animation());
+    assert_eq!(tag.expected_args(), &[4, 6]);
+}
+
+#[test]
+fn move_apply_interpolates_over_event() {
+    let tag = MoveTagHandler;
+    let args = tag.parse_args("(0,0,100,50)").unwrap();
+    for (time, expected) in [(0, (0.0, 0.0)), (500, (50.0, 25.0)), (1000, (100.0, 50.0))] {
+        let mut state = AnimationState::new(time, 1000);
+        tag.apply(&args, &mut state);
+        assert_eq!(state.position, Some(expected), "at {time} ms");
+    }
+}
+
+#[test]
+fn move_apply_respects_times() {
+    let tag = MoveTagHandler;
+    let args = tag.parse_args("(0,0,100,0,200,600)").unwrap();
+    let at = |time| {
+        let mut state = AnimationState::new(time, 1000);
+        tag.apply(&args, &mut state);
+        state.position.unwrap().0
+    };
+    assert!(at(100).abs() < f32::EPSILON);
+    assert!((at(400) - 50.0).abs() < 1e-4);
+    assert!((at(900) - 100.0).abs() < f32::EPSILON);
+
+    // Reversed times are swapped
+    let reversed = tag.parse_args("(0,0,100,0,600,200)").unwrap();
+    let mut state = AnimationState::new(400, 1000);
+    tag.apply(&reversed, &mut state);
+    assert!((state.position.unwrap().0 - 50.0).abs() < 1e-4);
+}
+
+#[test]
+fn move_apply_does_not_override_position() {
+    let mut state = AnimationState::new(500, 1000);
+    PositionTagHandler.apply(&(1.0, 2.0), &mut state);
+    let args = MoveTagHandler.parse_args("(0,0,100,50)").unwrap();
+    MoveTagHandler.apply(&args, &mut state);
+    assert_eq!(state.position, Some((1.0, 2.0)));
+}
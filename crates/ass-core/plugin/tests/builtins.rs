@@ -0,0 +1,42 @@
+//! Tests for built-in tag registration.
+
+use crate::plugin::{
+    create_builtin_tags, register_builtin_tags, ExtensionRegistry, PluginError, TagResult,
+};
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+
+#[test]
+fn builtin_tags_cover_positioning_and_fades() {
+    let mut names: Vec<&str> = create_builtin_tags()
+        .iter()
+        .map(|handler| handler.name())
+        .collect();
+    names.sort_unstable();
+    assert_eq!(names, ["an", "fad", "fade", "move", "pos"]);
+}
+
+#[test]
+fn register_builtin_tags_dispatches_through_registry() {
+    let mut registry = ExtensionRegistry::new();
+    register_builtin_tags(&mut registry).unwrap();
+    assert_eq!(registry.extension_count(), 5);
+    assert_eq!(
+        registry.process_tag("move", "0,0,10,10"),
+        Some(TagResult::Processed)
+    );
+    assert!(matches!(
+        registry.process_tag("an", "12"),
+        Some(TagResult::Failed(_))
+    ));
+}
+
+#[test]
+fn register_builtin_tags_rejects_duplicates() {
+    let mut registry = ExtensionRegistry::with_builtin_tags();
+    assert!(registry.has_tag_handler("pos"));
+    assert!(matches!(
+        register_builtin_tags(&mut registry),
+        Err(PluginError::DuplicateHandler(_))
+    ));
+}
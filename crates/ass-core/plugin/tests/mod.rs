@@ -2,14 +2,18 @@
 //!
 //! Split into focused submodules, each kept under the 200-line limit:
 //!
+//! - `builtins`: built-in positioning and fade tag registration.
 //! - `mocks`: shared mock tag handler and section processor.
 //! - `results`: `TagResult`/`SectionResult` equality and `PluginError` display.
 //! - `registry_basic`: registry construction and handler registration.
 //! - `registry_process`: tag and section processing dispatch.
 //! - `registry_management`: removal, clearing, naming, and debug output.
+//! - `priority`: tag handler priorities and conflict resolution.
 //! - `validation`: handler and processor argument validation.
 
+mod builtins;
 mod mocks;
+mod priority;
 mod registry_basic;
 mod registry_management;
 mod registry_process;
@@ -0,0 +1,103 @@
+//! Tests for tag handler priorities and conflict resolution.
+
+use super::mocks::MockTagHandler;
+use crate::plugin::{
+    register_builtin_tags, ExtensionRegistry, PluginError, TagHandler, TagResult,
+    BUILTIN_TAG_PRIORITY,
+};
+#[cfg(not(feature = "std"))]
+use alloc::{boxed::Box, string::ToString};
+
+/// `\pos` replacement that declares its own priority
+struct PriorityPosHandler;
+
+impl TagHandler for PriorityPosHandler {
+    fn name(&self) -> &'static str {
+        "pos"
+    }
+
+    fn process(&self, _args: &str) -> TagResult {
+        TagResult::Failed("custom pos".to_string())
+    }
+
+    fn priority(&self) -> i32 {
+        10
+    }
+}
+
+fn custom_pos_wins(registry: &ExtensionRegistry) {
+    assert_eq!(
+        registry.process_tag("pos", "(1,2)"),
+        Some(TagResult::Failed("custom pos".to_string()))
+    );
+    assert_eq!(registry.tag_priority("pos"), Some(10));
+}
+
+#[test]
+fn plugin_registered_after_builtins_overrides_them() {
+    let mut registry = ExtensionRegistry::new();
+    register_builtin_tags(&mut registry).unwrap();
+    registry
+        .register_tag_handler(Box::new(PriorityPosHandler))
+        .unwrap();
+    custom_pos_wins(&registry);
+    assert_eq!(registry.extension_count(), 5);
+}
+
+#[test]
+fn plugin_registered_before_builtins_is_kept() {
+    let mut registry = ExtensionRegistry::new();
+    registry
+        .register_tag_handler(Box::new(PriorityPosHandler))
+        .unwrap();
+    register_builtin_tags(&mut registry).unwrap();
+    custom_pos_wins(&registry);
+    assert_eq!(registry.tag_priority("move"), Some(BUILTIN_TAG_PRIORITY));
+}
+
+#[test]
+fn with_builtin_tags_registers_at_builtin_priority() {
+    let mut registry = ExtensionRegistry::with_builtin_tags();
+    assert_eq!(registry.tag_priority("fad"), Some(BUILTIN_TAG_PRIORITY));
+    registry
+        .register_tag_handler(Box::new(MockTagHandler::new("fad").with_failure(true)))
+        .unwrap();
+    assert_eq!(registry.tag_priority("fad"), Some(0));
+    assert_eq!(
+        registry.tag_handler("fad").map(TagHandler::name),
+        Some("fad")
+    );
+}
+
+#[test]
+fn explicit_priority_overrides_handler_default() {
+    let mut registry = ExtensionRegistry::new();
+    registry
+        .register_tag_handler_with_priority(Box::new(MockTagHandler::new("b")), 5)
+        .unwrap();
+    registry
+        .register_tag_handler_with_priority(
+            Box::new(MockTagHandler::new("b").with_failure(true)),
+            1,
+        )
+        .unwrap();
+    assert_eq!(registry.process_tag("b", "1"), Some(TagResult::Processed));
+    assert_eq!(registry.tag_priority("b"), Some(5));
+}
+
+#[test]
+fn equal_priority_is_still_a_duplicate() {
+    let mut registry = ExtensionRegistry::new();
+    registry
+        .register_tag_handler(Box::new(MockTagHandler::new("b")))
+        .unwrap();
+    let result = registry.register_tag_handler(Box::new(MockTagHandler::new("b")));
+    assert!(matches!(result, Err(PluginError::DuplicateHandler(name)) if name == "b"));
+}
+
+#[test]
+fn missing_tag_has_no_handler_or_priority() {
+    let registry = ExtensionRegistry::new();
+    assert!(registry.tag_handler("pos").is_none());
+    assert_eq!(registry.tag_priority("pos"), None);
+}
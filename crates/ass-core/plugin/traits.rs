@@ -2,8 +2,10 @@
 //!
 //! Defines [`TagHandler`] and [`SectionProcessor`] for processing custom ASS
 //! override tags and sections, along with the [`TagResult`] and
-//! [`SectionResult`] values they return.
+//! [`SectionResult`] values they return. [`Tag`] extends a handler with typed
+//! argument parsing and application to an [`AnimationState`].
 
+use super::{AnimationState, Result};
 use alloc::string::String;
 
 /// Result of tag processing operations
@@ -51,6 +53,42 @@ pub trait TagHandler: Send + Sync {
     fn validate(&self, args: &str) -> bool {
         !args.is_empty()
     }
+
+    /// Precedence when several handlers register the same tag name
+    ///
+    /// Higher values win; built-in tags registered through
+    /// [`register_builtin_tags`](super::register_builtin_tags) use
+    /// [`BUILTIN_TAG_PRIORITY`](super::BUILTIN_TAG_PRIORITY) so plugins can
+    /// override them.
+    fn priority(&self) -> i32 {
+        0
+    }
+}
+
+/// Typed behavior of an override tag
+///
+/// Where [`TagHandler`] only checks arguments, `Tag` parses them into a
+/// typed value and applies that value to an [`AnimationState`], so a tag's
+/// effect can be tested without rendering.
+pub trait Tag: TagHandler {
+    /// Parsed tag arguments
+    type Args;
+
+    /// Whether the tag's effect changes over the event's duration
+    fn supports_animation(&self) -> bool;
+
+    /// Accepted numbers of comma-separated arguments
+    fn expected_args(&self) -> &'static [usize];
+
+    /// Parse raw arguments, with or without surrounding parentheses
+    ///
+    /// # Errors
+    /// Returns `PluginError::ProcessingFailed` if the argument count is not
+    /// one of [`Tag::expected_args`] or an argument is out of range
+    fn parse_args(&self, args: &str) -> Result<Self::Args>;
+
+    /// Apply parsed arguments to `state`
+    fn apply(&self, args: &Self::Args, state: &mut AnimationState);
 }
 
 /// Trait for handling custom ASS sections
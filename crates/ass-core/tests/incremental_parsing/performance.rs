@@ -56,6 +56,7 @@ fn test_incremental_parsing_performance() {
             Section::Events(e) => println!("Section {i}: Events ({})", e.len()),
             Section::Fonts(f) => println!("Section {i}: Fonts ({})", f.len()),
             Section::Graphics(g) => println!("Section {i}: Graphics ({})", g.len()),
+            Section::Custom(c) => println!("Section {i}: Custom ({})", c.name),
         }
     }
 
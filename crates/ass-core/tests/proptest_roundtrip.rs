@@ -0,0 +1,170 @@
+//! Property-based roundtrip tests for serialization and value formatting.
+//!
+//! Generates structurally valid scripts with random style names, dialogue
+//! text and timings, serializes them with `Script::to_ass_string`, and checks
+//! that re-parsing preserves every style, event and timestamp. Also covers
+//! the color, time and script-version string conversions, and that
+//! `Script::serialize_with_options` with default options reproduces a
+//! commented script byte for byte.
+//!
+//! Run with `cargo test -p ass-core --features proptest`.
+#![cfg(feature = "proptest")]
+
+use ass_core::parser::script::{roundtrip_fidelity_score, SerializeOptions};
+use ass_core::parser::{Script, Section};
+use ass_core::utils::{format_ass_time, format_bgr_color, parse_ass_time, parse_bgr_color};
+use ass_core::ScriptVersion;
+use proptest::prelude::*;
+use std::fmt::Write;
+
+const STYLE_FORMAT: &str = "Format: Name, Fontname, Fontsize, PrimaryColour, SecondaryColour, OutlineColour, BackColour, Bold, Italic, Underline, StrikeOut, ScaleX, ScaleY, Spacing, Angle, BorderStyle, Outline, Shadow, Alignment, MarginL, MarginR, MarginV, Encoding";
+const EVENT_FORMAT: &str =
+    "Format: Layer, Start, End, Style, Name, MarginL, MarginR, MarginV, Effect, Text";
+
+/// A generated dialogue line: style slot, start and end centiseconds, text
+type DialogueSpec = (usize, u32, u32, String);
+
+fn style_names() -> impl Strategy<Value = Vec<String>> {
+    prop::collection::btree_set("[A-Za-z][A-Za-z0-9_]{0,11}", 1..6)
+        .prop_map(|names| names.into_iter().collect())
+}
+
+fn dialogue() -> impl Strategy<Value = DialogueSpec> {
+    (
+        any::<usize>(),
+        0u32..3_600_000,
+        0u32..60_000,
+        "[A-Za-z0-9][A-Za-z0-9 ,.!?']{0,39}",
+    )
+        .prop_map(|(style, start, length, text)| (style, start, start + length, text))
+}
+
+fn build_script(styles: &[String], events: &[DialogueSpec]) -> String {
+    let mut source = String::from("[Script Info]\nTitle: Roundtrip\nScriptType: v4.00+\n\n");
+    source.push_str("[V4+ Styles]\n");
+    source.push_str(STYLE_FORMAT);
+    source.push('\n');
+    for name in styles {
+        writeln!(
+            source,
+            "Style: {name},Arial,20,&H00FFFFFF,&H000000FF,&H00000000,&H00000000,0,0,0,0,100,100,0,0,1,2,0,2,10,10,10,1"
+        )
+        .unwrap();
+    }
+    source.push_str("\n[Events]\n");
+    source.push_str(EVENT_FORMAT);
+    source.push('\n');
+    for (style, start, end, text) in events {
+        let style = &styles[style % styles.len()];
+        writeln!(
+            source,
+            "Dialogue: 0,{},{},{style},,0,0,0,,{text}",
+            format_ass_time(*start),
+            format_ass_time(*end)
+        )
+        .unwrap();
+    }
+    source
+}
+
+fn style_count(script: &Script) -> usize {
+    script
+        .sections()
+        .iter()
+        .map(|section| match section {
+            Section::Styles(styles) => styles.len(),
+            _ => 0,
+        })
+        .sum()
+}
+
+fn event_times(script: &Script) -> Vec<(u32, u32)> {
+    script
+        .sections()
+        .iter()
+        .filter_map(|section| match section {
+            Section::Events(events) => Some(events),
+            _ => None,
+        })
+        .flatten()
+        .map(|event| {
+            (
+                event.start_time_cs().expect("valid start time"),
+                event.end_time_cs().expect("valid end time"),
+            )
+        })
+        .collect()
+}
+
+proptest! {
+    #[test]
+    fn script_roundtrips_through_serialization(
+        styles in style_names(),
+        events in prop::collection::vec(dialogue(), 0..20),
+    ) {
+        let source = build_script(&styles, &events);
+        let script = Script::parse(&source).expect("generated script parses");
+        let serialized = script.to_ass_string();
+        let reparsed = Script::parse(&serialized).expect("serialized script parses");
+
+        let expected: Vec<(u32, u32)> =
+            events.iter().map(|(_, start, end, _)| (*start, *end)).collect();
+        prop_assert_eq!(style_count(&script), styles.len());
+        prop_assert_eq!(style_count(&reparsed), styles.len());
+        prop_assert_eq!(&event_times(&script), &expected);
+        prop_assert_eq!(&event_times(&reparsed), &expected);
+    }
+
+    #[test]
+    fn default_serialize_options_roundtrip_exactly(
+        styles in style_names(),
+        events in prop::collection::vec(dialogue(), 0..20),
+        comment_slots in prop::collection::vec(any::<bool>(), 0..20),
+    ) {
+        // Well-formed lines have no trailing whitespace, which the parser drops
+        let events: Vec<DialogueSpec> = events
+            .into_iter()
+            .map(|(style, start, end, text)| (style, start, end, text.trim_end().to_string()))
+            .collect();
+
+        // Interleave `;` comments before some of the dialogue lines
+        let mut source = String::new();
+        let mut event_index = 0;
+        for line in build_script(&styles, &events).lines() {
+            if line.starts_with("Dialogue:") {
+                if comment_slots.get(event_index).copied().unwrap_or(false) {
+                    writeln!(source, "; note {event_index}").unwrap();
+                }
+                event_index += 1;
+            }
+            writeln!(source, "{line}").unwrap();
+        }
+
+        let script = Script::parse(&source).expect("generated script parses");
+        let serialized = script.serialize_with_options(&SerializeOptions::default());
+        prop_assert_eq!(&serialized, &source);
+        prop_assert!((roundtrip_fidelity_score(&source, &serialized) - 100.0).abs() < f64::EPSILON);
+        prop_assert_eq!(Script::parse(&serialized).expect("serialized script parses"), script);
+    }
+
+    #[test]
+    fn bgr_color_roundtrips(rgba in any::<[u8; 4]>()) {
+        prop_assert_eq!(parse_bgr_color(&format_bgr_color(rgba)).unwrap(), rgba);
+    }
+
+    #[test]
+    fn ass_time_roundtrips(cs in 0u32..36_000_000) {
+        prop_assert_eq!(parse_ass_time(&format_ass_time(cs)).unwrap(), cs);
+    }
+
+    #[test]
+    fn script_version_roundtrips(
+        version in prop_oneof![
+            Just(ScriptVersion::SsaV4),
+            Just(ScriptVersion::AssV4),
+            Just(ScriptVersion::AssV4Plus),
+        ]
+    ) {
+        prop_assert_eq!(ScriptVersion::from_header(version.as_header_str()), Some(version));
+    }
+}
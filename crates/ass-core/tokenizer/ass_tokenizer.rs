@@ -4,7 +4,9 @@
 //! tokenization, issue access, and position queries. The per-token stepping
 //! logic lives in the sibling `next_token` module.
 
-use super::{IssueCollector, Token, TokenContext, TokenIssue, TokenScanner};
+use super::{
+    override_tokens::OverrideState, IssueCollector, Token, TokenContext, TokenIssue, TokenScanner,
+};
 use crate::Result;
 use alloc::vec::Vec;
 
@@ -23,14 +25,17 @@ pub struct AssTokenizer<'a> {
     pub(super) scanner: TokenScanner<'a>,
     /// Current tokenization context
     pub(super) context: TokenContext,
+    /// Inline override tokenization state
+    pub(super) overrides: OverrideState<'a>,
     /// Issue collector for error reporting
-    issues: IssueCollector<'a>,
+    pub(super) issues: IssueCollector<'a>,
 }
 
 impl<'a> AssTokenizer<'a> {
     /// Create new tokenizer for source text
     ///
-    /// Handles BOM detection and UTF-8 validation upfront.
+    /// Handles BOM detection and UTF-8 validation upfront, recording an
+    /// error issue for a malformed BOM.
     #[must_use]
     pub fn new(source: &'a str) -> Self {
         let initial_position = if source.starts_with('\u{FEFF}') {
@@ -39,10 +44,22 @@ impl<'a> AssTokenizer<'a> {
             0
         };
 
+        let mut tokenizer = Self::for_fragment(source);
+        tokenizer.scanner = TokenScanner::new(source, initial_position, 1, 1);
+        tokenizer.check_bom();
+        tokenizer
+    }
+
+    /// Create tokenizer for a piece of a script, such as an event's text
+    ///
+    /// Unlike [`AssTokenizer::new`], the fragment is tokenized from its
+    /// first byte and not checked for a BOM.
+    pub(crate) fn for_fragment(source: &'a str) -> Self {
         Self {
             source,
-            scanner: TokenScanner::new(source, initial_position, 1, 1),
+            scanner: TokenScanner::new(source, 0, 1, 1),
             context: TokenContext::Document,
+            overrides: OverrideState::default(),
             issues: IssueCollector::new(),
         }
     }
@@ -69,6 +86,13 @@ impl<'a> AssTokenizer<'a> {
     }
 
     /// Get accumulated tokenization issues
+    ///
+    /// Covers a malformed BOM, non-printable characters outside override
+    /// blocks, override blocks left unclosed at the end of a line and
+    /// override blocks longer than [`MAX_OVERRIDE_BLOCK_BYTES`] for the
+    /// tokens scanned so far.
+    ///
+    /// [`MAX_OVERRIDE_BLOCK_BYTES`]: super::MAX_OVERRIDE_BLOCK_BYTES
     #[must_use]
     pub fn issues(&self) -> &[TokenIssue<'a>] {
         self.issues.issues()
@@ -101,6 +125,8 @@ impl<'a> AssTokenizer<'a> {
         };
         self.scanner = TokenScanner::new(self.source, initial_position, 1, 1);
         self.context = TokenContext::Document;
+        self.overrides.reset();
         self.issues.clear();
+        self.check_bom();
     }
 }
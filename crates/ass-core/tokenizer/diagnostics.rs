@@ -0,0 +1,87 @@
+//! Issue detection for [`AssTokenizer`].
+//!
+//! Checks the source for a malformed BOM when tokenization starts, each
+//! `{` for an override block left open or grown unusually large, and text
+//! outside override blocks for non-printable characters. Problems are
+//! recorded as [`TokenIssue`](super::TokenIssue)s; tokenization continues
+//! unchanged.
+
+use super::{AssTokenizer, TokenContext, TokenType};
+use crate::utils::errors::encoding::validate_bom_handling;
+use alloc::{format, string::ToString};
+
+/// Override blocks longer than this many bytes are reported
+pub const MAX_OVERRIDE_BLOCK_BYTES: usize = 4096;
+
+impl<'a> AssTokenizer<'a> {
+    /// Report a malformed byte order mark at the start of the source
+    pub(super) fn check_bom(&mut self) {
+        if let Err(e) = validate_bom_handling(self.source.as_bytes()) {
+            let span = self
+                .source
+                .char_indices()
+                .nth(1)
+                .map_or(self.source, |(end, _)| &self.source[..end]);
+            self.issues
+                .add_error(format!("Invalid UTF-8 BOM sequence: {e}"), span, 1, 1);
+        }
+    }
+
+    /// Report the override block opened by the `{` at `start`
+    ///
+    /// Blocks never continue past the end of their line, so a block without
+    /// a `}` on the same line is unclosed.
+    pub(super) fn check_override_block(&mut self, start: usize, line: usize, column: usize) {
+        let rest = &self.source[start..];
+        let line_end = rest.find(['\n', '\r']).unwrap_or(rest.len());
+        let Some(close) = rest[..line_end].find('}') else {
+            self.issues.add_warning(
+                "Unclosed override block at end of line".to_string(),
+                &rest[..line_end],
+                line,
+                column,
+            );
+            return;
+        };
+
+        let block = &rest[..=close];
+        if block.len() > MAX_OVERRIDE_BLOCK_BYTES {
+            self.issues.add_info(
+                format!(
+                    "Override block of {} bytes exceeds {MAX_OVERRIDE_BLOCK_BYTES} bytes",
+                    block.len()
+                ),
+                block,
+                line,
+                column,
+            );
+        }
+    }
+
+    /// Report control characters in a token scanned outside override blocks
+    ///
+    /// Tabs and line breaks are allowed.
+    pub(super) fn check_non_printable(
+        &mut self,
+        token_type: TokenType,
+        context: TokenContext,
+        span: &'a str,
+        line: usize,
+        column: usize,
+    ) {
+        if context.is_override() || token_type == TokenType::OverrideBlock {
+            return;
+        }
+
+        for (offset, (index, ch)) in span.char_indices().enumerate() {
+            if ch.is_control() && !matches!(ch, '\t' | '\n' | '\r') {
+                self.issues.add_warning(
+                    format!("Non-printable character U+{:04X} in text", u32::from(ch)),
+                    &span[index..index + ch.len_utf8()],
+                    line,
+                    column + offset,
+                );
+            }
+        }
+    }
+}
@@ -24,7 +24,9 @@
 //! ```
 
 mod ass_tokenizer;
+mod diagnostics;
 mod next_token;
+mod override_tokens;
 pub mod scanner;
 #[cfg(feature = "simd")]
 pub mod simd;
@@ -33,6 +35,7 @@ pub mod tokens;
 
 // Re-export public API
 pub use ass_tokenizer::AssTokenizer;
+pub use diagnostics::MAX_OVERRIDE_BLOCK_BYTES;
 pub use scanner::{CharNavigator, TokenScanner};
 pub use state::{IssueCollector, IssueLevel, TokenContext, TokenIssue};
 pub use tokens::{DelimiterType, Token, TokenType};
@@ -29,70 +29,78 @@ impl<'a> AssTokenizer<'a> {
         let start_column = self.scanner.navigator().column();
 
         let current_char = self.scanner.navigator_mut().peek_char()?;
+        let start_context = self.context;
+        if current_char == '{' && !start_context.is_override() {
+            self.check_override_block(start_pos, start_line, start_column);
+        }
 
-        let token_type = match (current_char, self.context) {
-            ('[', _) => {
-                self.context = TokenContext::SectionHeader;
-                self.scanner.scan_section_header()
-            }
-            (']', TokenContext::SectionHeader) => {
-                self.context = TokenContext::Document;
-                self.scanner.navigator_mut().advance_char()?;
-                Ok(TokenType::SectionClose)
-            }
-            (':', TokenContext::Document) => {
-                self.context = self.context.enter_field_value();
-                self.scanner.navigator_mut().advance_char()?;
-                Ok(TokenType::Colon)
-            }
+        let token_type = if let Some(token_type) = self.scan_override_token(current_char) {
+            token_type
+        } else {
+            match (current_char, self.context) {
+                ('[', _) => {
+                    self.context = TokenContext::SectionHeader;
+                    self.scanner.scan_section_header()
+                }
+                (']', TokenContext::SectionHeader) => {
+                    self.context = TokenContext::Document;
+                    self.scanner.navigator_mut().advance_char()?;
+                    Ok(TokenType::SectionClose)
+                }
+                (':', TokenContext::Document) => {
+                    self.context = self.context.enter_field_value();
+                    self.scanner.navigator_mut().advance_char()?;
+                    Ok(TokenType::Colon)
+                }
 
-            ('{', _) => {
-                self.context = TokenContext::StyleOverride;
-                self.scanner.scan_style_override()
-            }
-            ('}', TokenContext::StyleOverride) => {
-                self.context = TokenContext::Document;
-                self.scanner.navigator_mut().advance_char()?;
-                Ok(TokenType::OverrideClose)
-            }
-            (',', _) => {
-                self.scanner.navigator_mut().advance_char()?;
-                Ok(TokenType::Comma)
-            }
-            ('\n' | '\r', _) => {
-                self.context = self.context.reset_to_document();
-                self.scanner.navigator_mut().advance_char()?;
-                if current_char == '\r' && self.scanner.navigator_mut().peek_char()? == '\n' {
+                ('{', _) => {
+                    self.context = TokenContext::StyleOverride;
+                    self.scanner.scan_style_override()
+                }
+                ('}', TokenContext::StyleOverride) => {
+                    self.context = TokenContext::Document;
                     self.scanner.navigator_mut().advance_char()?;
+                    Ok(TokenType::OverrideClose)
                 }
-                Ok(TokenType::Newline)
-            }
-            (';', TokenContext::Document) => self.scanner.scan_comment(),
-            ('!', TokenContext::Document) => {
-                // Check if next character is ':' for comment marker "!:"
-                if self.scanner.navigator().peek_next() == Ok(':') {
-                    self.scanner.scan_comment()
-                } else {
-                    self.scanner.scan_text(self.context)
+                (',', _) => {
+                    self.scanner.navigator_mut().advance_char()?;
+                    Ok(TokenType::Comma)
                 }
-            }
-            // Handle delimiter characters in wrong context as literal text
-            ('}', _) => {
-                // '}' outside StyleOverride context is literal text
-                self.scanner.navigator_mut().advance_char()?;
-                Ok(TokenType::Text)
-            }
-            (']', _) => {
-                // ']' outside SectionHeader context is literal text
-                self.scanner.navigator_mut().advance_char()?;
-                Ok(TokenType::Text)
-            }
-            _ => {
-                // In FieldValue context, consume everything until delimiter
-                if self.context == TokenContext::FieldValue {
-                    self.scanner.scan_field_value()
-                } else {
-                    self.scanner.scan_text(self.context)
+                ('\n' | '\r', _) => {
+                    self.context = self.context.reset_to_document();
+                    self.scanner.navigator_mut().advance_char()?;
+                    if current_char == '\r' && self.scanner.navigator_mut().peek_char()? == '\n' {
+                        self.scanner.navigator_mut().advance_char()?;
+                    }
+                    Ok(TokenType::Newline)
+                }
+                (';', TokenContext::Document) => self.scanner.scan_comment(),
+                ('!', TokenContext::Document) => {
+                    // Check if next character is ':' for comment marker "!:"
+                    if self.scanner.navigator().peek_next() == Ok(':') {
+                        self.scanner.scan_comment()
+                    } else {
+                        self.scanner.scan_text(self.context)
+                    }
+                }
+                // Handle delimiter characters in wrong context as literal text
+                ('}', _) => {
+                    // '}' outside StyleOverride context is literal text
+                    self.scanner.navigator_mut().advance_char()?;
+                    Ok(TokenType::Text)
+                }
+                (']', _) => {
+                    // ']' outside SectionHeader context is literal text
+                    self.scanner.navigator_mut().advance_char()?;
+                    Ok(TokenType::Text)
+                }
+                _ => {
+                    // In FieldValue context, consume everything until delimiter
+                    if self.context == TokenContext::FieldValue {
+                        self.scanner.scan_field_value()
+                    } else {
+                        self.scanner.scan_text(self.context)
+                    }
                 }
             }
         }?;
@@ -107,6 +115,8 @@ impl<'a> AssTokenizer<'a> {
             ));
         }
 
+        self.check_non_printable(token_type, start_context, span, start_line, start_column);
+
         Ok(Some(Token {
             token_type,
             span,
@@ -0,0 +1,184 @@
+//! Inline override tokenization for [`AssTokenizer`].
+//!
+//! When enabled with [`AssTokenizer::with_override_tokens`], `{...}` blocks
+//! are split into tag names, arguments and parentheses, `\t(...)` animation
+//! blocks are tracked by nesting depth, and text after `\p1` is tokenized as
+//! drawing commands until `\p0` or the end of the line.
+
+use super::{AssTokenizer, TokenContext, TokenType};
+use crate::Result;
+
+/// Nesting and mode state carried between inline override tokens
+#[derive(Debug, Clone, Copy, Default)]
+pub(super) struct OverrideState<'a> {
+    /// Whether override blocks are split into inline tokens
+    pub(super) enabled: bool,
+    /// Context to resume after the closing `}`
+    resume: TokenContext,
+    /// Name of the last scanned tag, without its backslash
+    tag: &'a str,
+    /// Depth of open `\t(` animation blocks
+    animation_depth: usize,
+    /// Depth of other open argument lists
+    paren_depth: usize,
+    /// Whether a `\p` tag with a non-zero scale enabled drawing mode
+    drawing: bool,
+}
+
+impl OverrideState<'_> {
+    /// Forget all nesting and drawing state, keeping the enabled flag
+    pub(super) fn reset(&mut self) {
+        *self = Self {
+            enabled: self.enabled,
+            ..Self::default()
+        };
+    }
+
+    /// Forget the tag and argument nesting of a closed block
+    ///
+    /// Drawing mode and the context to resume outlive the block.
+    fn close_block(&mut self) {
+        self.tag = "";
+        self.animation_depth = 0;
+        self.paren_depth = 0;
+    }
+
+    /// Context inside the innermost open block after an argument list closes
+    const fn enclosing(&self) -> TokenContext {
+        if self.paren_depth > 0 {
+            TokenContext::OverrideArgument
+        } else if self.animation_depth > 0 {
+            TokenContext::AnimationBlock
+        } else {
+            TokenContext::StyleOverride
+        }
+    }
+}
+
+impl AssTokenizer<'_> {
+    /// Split override blocks into tag, argument and drawing tokens
+    ///
+    /// By default a whole `{...}` block is one [`TokenType::OverrideBlock`].
+    /// With override tokens enabled the block yields
+    /// [`TokenType::OverrideOpen`], one [`TokenType::OverrideTag`] per tag
+    /// followed by its arguments, and [`TokenType::OverrideClose`], moving
+    /// through the [`TokenContext::OverrideTagName`],
+    /// [`TokenContext::OverrideArgument`] and
+    /// [`TokenContext::AnimationBlock`] contexts. Text after a `\p1` block
+    /// is tokenized in [`TokenContext::DrawingCommands`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ass_core::tokenizer::{AssTokenizer, TokenType};
+    ///
+    /// let mut tokenizer = AssTokenizer::new("{\\fs20}").with_override_tokens();
+    /// let types: Vec<_> = tokenizer
+    ///     .tokenize_all()?
+    ///     .iter()
+    ///     .map(|token| token.token_type)
+    ///     .collect();
+    /// assert_eq!(
+    ///     types,
+    ///     [
+    ///         TokenType::OverrideOpen,
+    ///         TokenType::OverrideTag,
+    ///         TokenType::Number,
+    ///         TokenType::OverrideClose,
+    ///     ]
+    /// );
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[must_use]
+    pub const fn with_override_tokens(mut self) -> Self {
+        self.overrides.enabled = true;
+        self
+    }
+
+    /// Scan the next token of an override block or drawing
+    ///
+    /// Returns `None` when override tokens are disabled or `current_char`
+    /// is left to the general rules, such as commas and line breaks.
+    pub(super) fn scan_override_token(&mut self, current_char: char) -> Option<Result<TokenType>> {
+        if !self.overrides.enabled {
+            return None;
+        }
+
+        match (current_char, self.context) {
+            ('\n' | '\r', _) => {
+                // Override blocks and drawings never span lines
+                self.overrides.reset();
+                None
+            }
+            (',', _) => None,
+            ('{', context) if !context.is_override() => {
+                if context != TokenContext::DrawingCommands {
+                    self.overrides.resume = context;
+                }
+                self.context = TokenContext::StyleOverride;
+                Some(self.advance_as(TokenType::OverrideOpen))
+            }
+            ('}', context) if context.is_override() => {
+                self.context = if self.overrides.drawing {
+                    TokenContext::DrawingCommands
+                } else {
+                    self.overrides.resume
+                };
+                self.overrides.close_block();
+                Some(self.advance_as(TokenType::OverrideClose))
+            }
+            ('\\', context) if context.is_override() => {
+                self.context = TokenContext::OverrideTagName;
+                Some(self.scanner.scan_override_tag().map(|(token_type, name)| {
+                    self.overrides.tag = name;
+                    self.context = TokenContext::OverrideArgument;
+                    token_type
+                }))
+            }
+            ('(', context) if context.is_override() => {
+                if self.overrides.tag == "t" {
+                    self.overrides.animation_depth += 1;
+                    self.context = TokenContext::AnimationBlock;
+                } else {
+                    self.overrides.paren_depth += 1;
+                    self.context = TokenContext::OverrideArgument;
+                }
+                self.overrides.tag = "";
+                Some(self.advance_as(TokenType::ParenOpen))
+            }
+            (')', context)
+                if context.is_override()
+                    && (self.overrides.paren_depth > 0 || self.overrides.animation_depth > 0) =>
+            {
+                if self.overrides.paren_depth > 0 {
+                    self.overrides.paren_depth -= 1;
+                } else {
+                    self.overrides.animation_depth -= 1;
+                }
+                self.overrides.tag = "";
+                self.context = self.overrides.enclosing();
+                Some(self.advance_as(TokenType::ParenClose))
+            }
+            (_, context) if context.is_override() => {
+                let start = self.scanner.navigator().position();
+                let token_type = self.scanner.scan_override_argument();
+                if self.overrides.tag == "p" {
+                    let end = self.scanner.navigator().position();
+                    self.overrides.drawing = self.source[start..end]
+                        .trim()
+                        .parse::<u32>()
+                        .is_ok_and(|scale| scale > 0);
+                }
+                Some(token_type)
+            }
+            (_, TokenContext::DrawingCommands) => Some(self.scanner.scan_drawing_command()),
+            _ => None,
+        }
+    }
+
+    /// Consume the current character as a single-character token
+    fn advance_as(&mut self, token_type: TokenType) -> Result<TokenType> {
+        self.scanner.navigator_mut().advance_char()?;
+        Ok(token_type)
+    }
+}
@@ -4,6 +4,7 @@
 //! including section headers, style overrides, comments, and text content.
 
 mod navigator;
+mod override_scanner;
 mod text_scanner;
 mod token_scanner;
 
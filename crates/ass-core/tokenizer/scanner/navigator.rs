@@ -145,6 +145,37 @@ impl<'a> CharNavigator<'a> {
         }
     }
 
+    /// Advance to the next byte contained in `delimiters`, or to end of input
+    ///
+    /// Skips the whole run of non-delimiter text in one step, using SIMD
+    /// scanning when the `simd` feature is enabled. `delimiters` must be
+    /// ASCII and include `\n` and `\r`, so that the skipped run never spans
+    /// a line break and only the column needs updating.
+    pub fn advance_to_delimiter(&mut self, delimiters: &[u8]) {
+        debug_assert!(delimiters.is_ascii() && delimiters.contains(&b'\n'));
+        debug_assert!(delimiters.contains(&b'\r'));
+
+        let rest = &self.source.as_bytes()[self.position..];
+        #[cfg(feature = "simd")]
+        let offset = crate::tokenizer::simd::scan_to_any(rest, delimiters);
+        #[cfg(not(feature = "simd"))]
+        let offset = rest
+            .iter()
+            .position(|byte| delimiters.contains(byte))
+            .unwrap_or(rest.len());
+
+        if offset == 0 {
+            return;
+        }
+
+        let skipped = &self.source[self.position..self.position + offset];
+        self.column += skipped.chars().count();
+        self.last_char = skipped.chars().next_back();
+        self.position += offset;
+        self.chars = self.source[self.position..].chars();
+        self.peek_char = None;
+    }
+
     /// Check if at end of source
     #[must_use]
     pub const fn is_at_end(&self) -> bool {
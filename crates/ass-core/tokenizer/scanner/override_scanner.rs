@@ -0,0 +1,97 @@
+//! Inline override scanning routines for the token scanner.
+//!
+//! Implements scanning of override tag names, tag arguments, and drawing
+//! commands, used when the tokenizer splits `{...}` blocks into their parts
+//! instead of returning a single override block token.
+
+use super::token_scanner::TokenScanner;
+use crate::tokenizer::tokens::TokenType;
+use crate::Result;
+
+impl<'a> TokenScanner<'a> {
+    /// Scan override tag like `\fs` or `\1c`, returning its name
+    ///
+    /// The name is an optional leading digit followed by ASCII letters, so
+    /// `\fs20` yields `fs` and leaves `20` for the argument. Names that run
+    /// straight into a letter argument (`\fnArial`) are not split, as that
+    /// needs the tag table. A backslash without a name is invalid.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if character navigation fails.
+    pub fn scan_override_tag(&mut self) -> Result<(TokenType, &'a str)> {
+        self.navigator.advance_char()?; // Skip '\'
+        let start = self.navigator.position();
+
+        if !self.navigator.is_at_end() && self.navigator.peek_char()?.is_ascii_digit() {
+            self.navigator.advance_char()?;
+        }
+        while !self.navigator.is_at_end() && self.navigator.peek_char()?.is_ascii_alphabetic() {
+            self.navigator.advance_char()?;
+        }
+
+        let name = &self.source[start..self.navigator.position()];
+        if name.is_empty() {
+            Ok((TokenType::Invalid, name))
+        } else {
+            Ok((TokenType::OverrideTag, name))
+        }
+    }
+
+    /// Scan override tag argument up to the next tag, parenthesis, comma or
+    /// block end
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if character navigation fails.
+    pub fn scan_override_argument(&mut self) -> Result<TokenType> {
+        let start = self.navigator.position();
+        self.navigator.advance_to_delimiter(b"\\(),}\n\r");
+        if self.navigator.position() == start {
+            // Stray delimiter with no tag to belong to
+            self.navigator.advance_char()?;
+            return Ok(TokenType::Text);
+        }
+
+        let span = &self.source[start..self.navigator.position()];
+        let trimmed = span.trim_end();
+        if Self::is_hex_value(trimmed) {
+            Ok(TokenType::HexValue)
+        } else if !trimmed.is_empty()
+            && trimmed
+                .chars()
+                .all(|c| c.is_ascii_digit() || c == '.' || c == '-')
+        {
+            Ok(TokenType::Number)
+        } else {
+            Ok(TokenType::Text)
+        }
+    }
+
+    /// Scan one drawing command letter or coordinate
+    ///
+    /// Command letters (`m`, `n`, `l`, `b`, `s`, `p`, `c`) are returned one
+    /// at a time; coordinates are numbers. Anything else is consumed as a
+    /// single invalid character.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if character navigation fails.
+    pub fn scan_drawing_command(&mut self) -> Result<TokenType> {
+        let ch = self.navigator.advance_char()?;
+        match ch {
+            'm' | 'n' | 'l' | 'b' | 's' | 'p' | 'c' => Ok(TokenType::DrawingCommand),
+            '0'..='9' | '.' | '-' => {
+                while !self.navigator.is_at_end() {
+                    let next = self.navigator.peek_char()?;
+                    if !(next.is_ascii_digit() || next == '.') {
+                        break;
+                    }
+                    self.navigator.advance_char()?;
+                }
+                Ok(TokenType::Number)
+            }
+            _ => Ok(TokenType::Invalid),
+        }
+    }
+}
@@ -63,3 +63,29 @@ fn char_navigator_peek_next_error_path() {
     let result = nav.peek_next();
     assert!(result.is_err());
 }
+
+#[test]
+fn char_navigator_advance_to_delimiter_tracks_column() {
+    let source = "Dialogue café text that is longer than one chunk,next";
+    let mut nav = CharNavigator::new(source, 0, 1, 1);
+
+    nav.advance_to_delimiter(b",\n\r");
+    assert_eq!(nav.position(), source.find(',').unwrap());
+    assert_eq!(nav.column(), 49);
+    assert_eq!(nav.peek_char().unwrap(), ',');
+
+    // Already at a delimiter: nothing to skip
+    nav.advance_to_delimiter(b",\n\r");
+    assert_eq!(nav.column(), 49);
+}
+
+#[test]
+fn token_scanner_scan_field_value_keeps_colons() {
+    let source = "0:00:05.00,Default";
+    let mut scanner = TokenScanner::new(source, 0, 1, 1);
+
+    let result = scanner.scan_field_value().unwrap();
+    assert_eq!(result, crate::tokenizer::tokens::TokenType::Number);
+    assert_eq!(scanner.navigator().position(), 10);
+    assert_eq!(scanner.navigator().column(), 11);
+}
@@ -19,53 +19,13 @@ impl TokenScanner<'_> {
     pub fn scan_text(&mut self, context: TokenContext) -> Result<TokenType> {
         let start = self.navigator.position();
 
-        // Use SIMD delimiter scanning when available and context doesn't affect delimiters
-        #[cfg(feature = "simd")]
-        {
-            // Only use SIMD when context doesn't change delimiter behavior
-            let use_simd = !matches!(context, TokenContext::FieldValue);
-
-            if use_simd {
-                if let Some(delimiter_pos) = self.scan_delimiters_simd(start) {
-                    self.navigator.position = delimiter_pos;
-                } else {
-                    self.navigator.position = self.source.len();
-                }
-                self.navigator.chars = self.source[self.navigator.position..].chars();
-                self.navigator.peek_char = None;
-            }
-        }
-
-        // Fallback to scalar scanning (or when SIMD can't be used due to context)
-        #[cfg(not(feature = "simd"))]
-        let use_scalar = true;
-        #[cfg(feature = "simd")]
-        let use_scalar = matches!(context, TokenContext::FieldValue);
-
-        if use_scalar {
-            while !self.navigator.is_at_end() {
-                let ch = self.navigator.peek_char()?;
-
-                // Check for delimiters based on context
-                let is_delimiter = match context {
-                    TokenContext::FieldValue => {
-                        // In field values, don't treat colon as delimiter (for time formats)
-                        matches!(ch, ',' | '{' | '}' | '[' | ']' | '\n' | '\r')
-                    }
-                    _ => {
-                        // In other contexts, treat colon as delimiter
-                        matches!(ch, ',' | ':' | '{' | '}' | '[' | ']' | '\n' | '\r')
-                            || (ch == ';' && context == TokenContext::Document)
-                    }
-                };
-
-                if is_delimiter {
-                    break;
-                }
-
-                self.navigator.advance_char()?;
-            }
-        }
+        let delimiters: &[u8] = match context {
+            // In field values, don't treat colon as delimiter (for time formats)
+            TokenContext::FieldValue => b",{}[]\n\r",
+            TokenContext::Document => b",:{}[]\n\r;",
+            _ => b",:{}[]\n\r",
+        };
+        self.navigator.advance_to_delimiter(delimiters);
 
         let span = &self.source[start..self.navigator.position()];
 
@@ -116,12 +76,6 @@ impl TokenScanner<'_> {
         false
     }
 
-    /// Fast delimiter scanning using SIMD when available
-    #[cfg(feature = "simd")]
-    fn scan_delimiters_simd(&self, start: usize) -> Option<usize> {
-        simd::scan_delimiters(&self.source[start..]).map(|offset| start + offset)
-    }
-
     /// Fast hex parsing using SIMD when available
     #[cfg(feature = "simd")]
     fn parse_hex_simd(hex_str: &str) -> Option<u32> {
@@ -139,16 +93,8 @@ impl TokenScanner<'_> {
     pub fn scan_field_value(&mut self) -> Result<TokenType> {
         let start = self.navigator.position();
 
-        while !self.navigator.is_at_end() {
-            let ch = self.navigator.peek_char()?;
-
-            // Stop at delimiters that end field values
-            if ch == ',' || ch == '\n' || ch == '\r' || ch == '{' || ch == '[' {
-                break;
-            }
-
-            self.navigator.advance_char()?;
-        }
+        // Stop at delimiters that end field values
+        self.navigator.advance_to_delimiter(b",\n\r{[");
 
         let span = &self.source[start..self.navigator.position()];
 
@@ -160,3 +160,29 @@ fn scan_delimiters_all_positions() {
         assert_eq!(scan_delimiters(&text), Some(i));
     }
 }
+
+#[test]
+fn scan_to_delimiter_returns_length_without_match() {
+    assert_eq!(scan_to_delimiter(b""), 0);
+    assert_eq!(scan_to_delimiter(b"plain text"), 10);
+    assert_eq!(scan_to_delimiter("a".repeat(40).as_bytes()), 40);
+}
+
+#[test]
+fn scan_to_delimiter_matches_scalar_at_every_offset() {
+    for len in 0..48 {
+        for (pos, &delimiter) in (0..len).zip(DELIMITERS.iter().cycle()) {
+            let mut bytes = vec![b'x'; len];
+            bytes[pos] = delimiter;
+            assert_eq!(scan_to_delimiter(&bytes), pos, "len {len}, pos {pos}");
+        }
+    }
+}
+
+#[test]
+fn scan_to_any_uses_custom_delimiters() {
+    let line = b"0:00:01.00,0:00:05.00,Default";
+    assert_eq!(scan_to_any(line, b",\n\r"), 10);
+    assert_eq!(scan_to_delimiter(line), 1);
+    assert_eq!(scan_to_any(b"{\\pos(1,2)}text;comment", b";"), 15);
+}
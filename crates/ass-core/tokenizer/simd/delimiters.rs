@@ -3,9 +3,19 @@
 //! Provides vectorized and scalar implementations for locating common ASS
 //! delimiters within input text, with automatic fallback to the scalar path
 //! when SIMD is unavailable.
+//!
+//! The vector width is chosen at compile time by the `wide` crate, which
+//! uses SSE2/AVX2 on x86, NEON on aarch64 and `simd128` on wasm, and plain
+//! scalar lanes on every other target.
 
 use wide::u8x16;
 
+/// Delimiters recognized by [`scan_delimiters`] and [`scan_to_delimiter`]
+pub const DELIMITERS: &[u8] = b":,{}[]\n\r";
+
+/// Number of bytes compared per SIMD step
+const LANES: usize = 16;
+
 /// Scan for delimiter characters using SIMD acceleration
 ///
 /// Searches for common ASS delimiters (comma, colon, braces, brackets)
@@ -30,70 +40,72 @@ use wide::u8x16;
 /// ```
 #[must_use]
 pub fn scan_delimiters(text: &str) -> Option<usize> {
-    let bytes = text.as_bytes();
-
-    #[cfg(feature = "simd")]
-    {
-        if bytes.len() >= 16 {
-            return scan_delimiters_simd_impl(bytes);
-        }
-    }
-
-    scan_delimiters_scalar(bytes)
+    let offset = scan_to_delimiter(text.as_bytes());
+    (offset < text.len()).then_some(offset)
 }
 
-/// SIMD implementation for delimiter scanning
-#[cfg(feature = "simd")]
-fn scan_delimiters_simd_impl(bytes: &[u8]) -> Option<usize> {
-    let delim_colon = u8x16::splat(b':');
-    let delim_comma = u8x16::splat(b',');
-    let delim_open_brace = u8x16::splat(b'{');
-    let delim_close_brace = u8x16::splat(b'}');
-    let delim_open_bracket = u8x16::splat(b'[');
-    let delim_close_bracket = u8x16::splat(b']');
-    let delim_newline = u8x16::splat(b'\n');
-    let delim_carriage = u8x16::splat(b'\r');
+/// Find the offset of the next delimiter in `src`
+///
+/// Looks for any of [`DELIMITERS`] and returns `src.len()` if none is
+/// present, so the result can be used directly to split off the run of
+/// non-delimiter bytes.
+///
+/// # Example
+///
+/// ```rust
+/// use ass_core::tokenizer::simd::scan_to_delimiter;
+///
+/// assert_eq!(scan_to_delimiter(b"Default,Arial"), 7);
+/// assert_eq!(scan_to_delimiter(b"no delimiters"), 13);
+/// ```
+#[must_use]
+pub fn scan_to_delimiter(src: &[u8]) -> usize {
+    scan_to_any(src, DELIMITERS)
+}
 
-    let chunks = bytes.chunks_exact(16);
-    let remainder = chunks.remainder();
+/// Find the offset of the first byte of `src` contained in `delimiters`
+///
+/// Returns `src.len()` if no delimiter is present. Inputs shorter than one
+/// SIMD chunk, and the tail of longer ones, are scanned byte by byte.
+///
+/// Delimiters should be ASCII so that the returned offset is always a
+/// character boundary when `src` is UTF-8: multi-byte sequences never
+/// contain ASCII bytes.
+///
+/// # Example
+///
+/// ```rust
+/// use ass_core::tokenizer::simd::scan_to_any;
+///
+/// // Field values keep colons, e.g. in timestamps
+/// assert_eq!(scan_to_any(b"0:00:05.00,Default", b",\n\r"), 10);
+/// ```
+#[must_use]
+pub fn scan_to_any(src: &[u8], delimiters: &[u8]) -> usize {
+    let chunks = src.chunks_exact(LANES);
+    let remainder_offset = src.len() - chunks.remainder().len();
 
     for (chunk_idx, chunk) in chunks.enumerate() {
-        let chunk_array: [u8; 16] = chunk.try_into().unwrap();
+        let mut chunk_array = [0u8; LANES];
+        chunk_array.copy_from_slice(chunk);
         let simd_chunk = u8x16::from(chunk_array);
 
-        let mask = simd_chunk.cmp_eq(delim_colon)
-            | simd_chunk.cmp_eq(delim_comma)
-            | simd_chunk.cmp_eq(delim_open_brace)
-            | simd_chunk.cmp_eq(delim_close_brace)
-            | simd_chunk.cmp_eq(delim_open_bracket)
-            | simd_chunk.cmp_eq(delim_close_bracket)
-            | simd_chunk.cmp_eq(delim_newline)
-            | simd_chunk.cmp_eq(delim_carriage);
+        let mask = delimiters.iter().fold(u8x16::splat(0), |mask, &delimiter| {
+            mask | simd_chunk.cmp_eq(u8x16::splat(delimiter))
+        });
 
         let mask_bits = mask.move_mask();
         if mask_bits != 0 {
-            let first_match = mask_bits.trailing_zeros() as usize;
-            return Some(chunk_idx * 16 + first_match);
+            return chunk_idx * LANES + mask_bits.trailing_zeros() as usize;
         }
     }
 
-    if !remainder.is_empty() {
-        let remainder_offset = bytes.len() - remainder.len();
-        if let Some(pos) = scan_delimiters_scalar(remainder) {
-            return Some(remainder_offset + pos);
-        }
-    }
-
-    None
+    remainder_offset + scan_to_any_scalar(&src[remainder_offset..], delimiters)
 }
 
 /// Scalar implementation for delimiter scanning
-fn scan_delimiters_scalar(bytes: &[u8]) -> Option<usize> {
-    for (i, &byte) in bytes.iter().enumerate() {
-        match byte {
-            b':' | b',' | b'{' | b'}' | b'[' | b']' | b'\n' | b'\r' => return Some(i),
-            _ => {}
-        }
-    }
-    None
+fn scan_to_any_scalar(src: &[u8], delimiters: &[u8]) -> usize {
+    src.iter()
+        .position(|byte| delimiters.contains(byte))
+        .unwrap_or(src.len())
 }
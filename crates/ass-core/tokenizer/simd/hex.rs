@@ -123,6 +123,59 @@ fn parse_hex_scalar_direct(bytes: &[u8]) -> Option<u32> {
     Some(result)
 }
 
+/// Decode up to 8 ASCII hex digits into a `u32` with SIMD
+///
+/// All digits are classified and converted to nibble values in parallel
+/// across the lanes of one vector: `'0'..='9'` map to `byte - '0'`, and
+/// letters are case-folded with `| 0x20` before mapping `'a'..='f'` to
+/// `byte - 'a' + 10`. Lanes matching neither range reject the input. The
+/// nibbles are then packed pairwise within a single `u64`.
+///
+/// Unlike `u32::from_str_radix`, a leading `+` is rejected. This is not
+/// faster than the scalar loop on current targets, so the parsers here do
+/// not use it; it is kept for benchmarking the vector approach.
+///
+/// # Example
+///
+/// ```rust
+/// use ass_core::tokenizer::simd::decode_hex_digits;
+///
+/// assert_eq!(decode_hex_digits("00FFa0c8"), Some(0x00FF_A0C8));
+/// assert_eq!(decode_hex_digits("12G4"), None);
+/// ```
+#[must_use]
+pub fn decode_hex_digits(hex_str: &str) -> Option<u32> {
+    let bytes = hex_str.as_bytes();
+    if bytes.is_empty() || bytes.len() > 8 {
+        return None;
+    }
+
+    // Right-align the digits in the first eight lanes; the leading and
+    // unused lanes hold '0', which decodes to a zero nibble
+    let mut lanes = [b'0'; 16];
+    lanes[8 - bytes.len()..8].copy_from_slice(bytes);
+    let chars = u8x16::from(lanes);
+
+    let digits = chars - u8x16::splat(b'0');
+    let is_digit = digits.min(u8x16::splat(9)).cmp_eq(digits);
+    let letters = (chars | u8x16::splat(0x20)) - u8x16::splat(b'a');
+    let is_letter = letters.min(u8x16::splat(5)).cmp_eq(letters);
+
+    if (is_digit | is_letter).move_mask() != 0xFFFF {
+        return None;
+    }
+
+    // Pack the eight nibble bytes pairwise: 8 x 4 bits -> 4 x 8 -> 2 x 16 -> 32
+    let nibbles = is_digit
+        .blend(digits, letters + u8x16::splat(10))
+        .to_array();
+    let mut packed = u64::from_be_bytes(nibbles[..8].try_into().ok()?);
+    packed = ((packed & 0x0F00_0F00_0F00_0F00) >> 4) | (packed & 0x000F_000F_000F_000F);
+    packed = ((packed & 0x00FF_0000_00FF_0000) >> 8) | (packed & 0x0000_00FF_0000_00FF);
+    packed = ((packed & 0x0000_FFFF_0000_0000) >> 16) | (packed & 0x0000_0000_0000_FFFF);
+    u32::try_from(packed).ok()
+}
+
 /// Scalar hex parsing implementation
 fn parse_hex_scalar(hex_str: &str) -> Option<u32> {
     u32::from_str_radix(hex_str, 16).ok()
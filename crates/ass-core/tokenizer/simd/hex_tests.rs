@@ -78,3 +78,43 @@ fn parse_hex_maximum_value() {
     assert_eq!(parse_hex_u32("FFFFFFFF"), Some(u32::MAX));
     assert_eq!(parse_hex_u32("ffffffff"), Some(u32::MAX));
 }
+
+#[test]
+fn decode_hex_digits_matches_scalar_for_every_byte() {
+    for byte in 0..=u8::MAX {
+        for position in 0..8 {
+            let mut digits = *b"0a1B2c3D";
+            digits[position] = byte;
+            let Ok(text) = core::str::from_utf8(&digits) else {
+                continue;
+            };
+            let scalar = if byte == b'+' {
+                None
+            } else {
+                u32::from_str_radix(text, 16).ok()
+            };
+            assert_eq!(decode_hex_digits(text), scalar, "{text:?}");
+        }
+    }
+}
+
+#[test]
+fn decode_hex_digits_rejects_bad_lengths() {
+    assert_eq!(decode_hex_digits(""), None);
+    assert_eq!(decode_hex_digits("123456789"), None);
+    assert_eq!(decode_hex_digits("fFfFfF"), Some(0x00FF_FFFF));
+}
+
+#[test]
+fn color_parsing_keeps_scalar_behavior() {
+    use crate::utils::parse_bgr_color;
+
+    assert_eq!(
+        parse_bgr_color("&H00FF8040&").unwrap(),
+        [0x40, 0x80, 0xFF, 0]
+    );
+    assert_eq!(parse_bgr_color("&hff8040").unwrap(), [0x40, 0x80, 0xFF, 0]);
+    // `from_str_radix` accepts a sign, which the vector path rejects
+    assert_eq!(parse_bgr_color("&H+FF804&").unwrap(), [0x04, 0xF8, 0x0F, 0]);
+    assert!(parse_bgr_color("&H00FF80ZZ&").is_err());
+}
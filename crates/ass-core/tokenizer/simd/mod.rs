@@ -26,6 +26,6 @@ mod hex_tests;
 #[cfg(test)]
 mod utf8_tests;
 
-pub use delimiters::scan_delimiters;
-pub use hex::parse_hex_u32;
+pub use delimiters::{scan_delimiters, scan_to_any, scan_to_delimiter, DELIMITERS};
+pub use hex::{decode_hex_digits, parse_hex_u32};
 pub use utf8::validate_utf8_batch;
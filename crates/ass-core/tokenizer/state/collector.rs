@@ -28,6 +28,11 @@ impl<'a> IssueCollector<'a> {
         self.issues.push(issue);
     }
 
+    /// Add info issue
+    pub fn add_info(&mut self, message: String, span: &'a str, line: usize, column: usize) {
+        self.add_issue(TokenIssue::info(message, span, line, column));
+    }
+
     /// Add warning issue
     pub fn add_warning(&mut self, message: String, span: &'a str, line: usize, column: usize) {
         self.add_issue(TokenIssue::warning(message, span, line, column));
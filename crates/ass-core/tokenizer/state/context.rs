@@ -34,6 +34,21 @@ pub enum TokenContext {
     /// Override tags use backslash prefixes and have special syntax rules.
     StyleOverride,
 
+    /// Inside an override tag name right after `\`
+    ///
+    /// Collects the tag name up to its argument or the next delimiter.
+    OverrideTagName,
+
+    /// Inside an override tag argument like `20` in `\fs20`
+    ///
+    /// Also covers parenthesized argument lists such as `\pos(10,20)`.
+    OverrideArgument,
+
+    /// Inside an animation block like `\t(0,500,\fs40)`
+    ///
+    /// Holds nested override tags until the matching `)`.
+    AnimationBlock,
+
     /// Inside drawing commands (\p1)
     ///
     /// Drawing commands use vector graphics syntax with different
@@ -57,7 +72,20 @@ impl TokenContext {
     /// Check if context is inside a delimited block
     #[must_use]
     pub const fn is_delimited_block(self) -> bool {
-        matches!(self, Self::SectionHeader | Self::StyleOverride)
+        matches!(self, Self::SectionHeader) || self.is_override()
+    }
+
+    /// Check if context is inside an override block, including its tags,
+    /// arguments and animation blocks
+    #[must_use]
+    pub const fn is_override(self) -> bool {
+        matches!(
+            self,
+            Self::StyleOverride
+                | Self::OverrideTagName
+                | Self::OverrideArgument
+                | Self::AnimationBlock
+        )
     }
 
     /// Get expected closing delimiter for context
@@ -65,7 +93,8 @@ impl TokenContext {
     pub const fn closing_delimiter(self) -> Option<char> {
         match self {
             Self::SectionHeader => Some(']'),
-            Self::StyleOverride => Some('}'),
+            Self::StyleOverride | Self::OverrideTagName | Self::OverrideArgument => Some('}'),
+            Self::AnimationBlock => Some(')'),
             _ => None,
         }
     }
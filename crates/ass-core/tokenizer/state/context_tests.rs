@@ -156,3 +156,25 @@ fn token_context_clone_and_copy() {
     assert_eq!(context1, context2);
     assert_eq!(context1, context3);
 }
+
+#[test]
+fn token_context_override_variants() {
+    for context in [
+        TokenContext::StyleOverride,
+        TokenContext::OverrideTagName,
+        TokenContext::OverrideArgument,
+        TokenContext::AnimationBlock,
+    ] {
+        assert!(context.is_override());
+        assert!(context.is_delimited_block());
+        assert!(context.allows_whitespace_skipping());
+    }
+    assert!(!TokenContext::DrawingCommands.is_override());
+    assert!(!TokenContext::SectionHeader.is_override());
+
+    assert_eq!(
+        TokenContext::OverrideArgument.closing_delimiter(),
+        Some('}')
+    );
+    assert_eq!(TokenContext::AnimationBlock.closing_delimiter(), Some(')'));
+}
@@ -56,6 +56,12 @@ impl<'a> TokenIssue<'a> {
         }
     }
 
+    /// Create info issue
+    #[must_use]
+    pub const fn info(message: String, span: &'a str, line: usize, column: usize) -> Self {
+        Self::new(IssueLevel::Info, message, span, line, column)
+    }
+
     /// Create warning issue
     #[must_use]
     pub const fn warning(message: String, span: &'a str, line: usize, column: usize) -> Self {
@@ -9,6 +9,12 @@
 /// error handling and recovery strategies.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum IssueLevel {
+    /// Informational note about unusual but valid input
+    ///
+    /// Indicates input that tokenizes correctly but may be slow to
+    /// process or worth reviewing.
+    Info,
+
     /// Warning that doesn't prevent tokenization
     ///
     /// Indicates potential problems that don't break parsing but may
@@ -45,6 +51,7 @@ impl IssueLevel {
     #[must_use]
     pub const fn as_str(self) -> &'static str {
         match self {
+            Self::Info => "info",
             Self::Warning => "warning",
             Self::Error => "error",
             Self::Critical => "critical",
@@ -109,3 +109,76 @@ fn tokenizer_all_token_types_properties() {
         }
     }
 }
+
+/// Tokenize `source` and return its issues as `(level, message)` pairs
+fn issues_of(
+    source: &str,
+) -> alloc::vec::Vec<(crate::tokenizer::IssueLevel, alloc::string::String)> {
+    let mut tokenizer = crate::tokenizer::AssTokenizer::new(source);
+    tokenizer.tokenize_all().unwrap();
+    tokenizer
+        .issues()
+        .iter()
+        .map(|issue| (issue.level, issue.message.clone()))
+        .collect()
+}
+
+#[test]
+fn tokenizer_reports_non_printable_text() {
+    use crate::tokenizer::IssueLevel;
+
+    let mut tokenizer = crate::tokenizer::AssTokenizer::new("Hi\u{7}there");
+    tokenizer.tokenize_all().unwrap();
+    let issues = tokenizer.issues();
+    assert_eq!(issues.len(), 1);
+    assert_eq!(issues[0].level, IssueLevel::Warning);
+    assert!(issues[0].message.contains("Non-printable character U+0007"));
+    assert_eq!((issues[0].span, issues[0].column), ("\u{7}", 3));
+
+    // Tabs are fine, and so is anything inside an override block
+    assert!(issues_of("a\tb {\\fn\u{1}x}").is_empty());
+}
+
+#[test]
+fn tokenizer_reports_unclosed_override_block() {
+    use crate::tokenizer::IssueLevel;
+
+    let issues = issues_of("{\\b1 text\nnext");
+    assert_eq!(issues.len(), 1);
+    assert_eq!(issues[0].0, IssueLevel::Warning);
+    assert!(issues[0].1.contains("Unclosed override block"));
+
+    assert!(issues_of("{\\b1}text{\\b0}").is_empty());
+}
+
+#[test]
+fn tokenizer_reports_malformed_bom() {
+    use crate::tokenizer::IssueLevel;
+
+    // U+FEC0 starts with the first two bytes of a UTF-8 BOM
+    let mut tokenizer = crate::tokenizer::AssTokenizer::new("\u{FEC0}x");
+    let issues = tokenizer.issues();
+    assert_eq!(issues.len(), 1);
+    assert_eq!(issues[0].level, IssueLevel::Error);
+    assert!(issues[0].message.contains("BOM"));
+
+    // Resetting keeps the BOM issue, as it describes the whole source
+    tokenizer.reset();
+    assert_eq!(tokenizer.issues().len(), 1);
+
+    assert!(issues_of("\u{FEFF}Title").is_empty());
+}
+
+#[test]
+fn tokenizer_reports_oversized_override_block() {
+    use crate::tokenizer::{IssueLevel, MAX_OVERRIDE_BLOCK_BYTES};
+
+    let block = format!("{{\\fn{}}}", "a".repeat(MAX_OVERRIDE_BLOCK_BYTES));
+    let issues = issues_of(&block);
+    assert_eq!(issues.len(), 1);
+    assert_eq!(issues[0].0, IssueLevel::Info);
+    assert!(issues[0].1.contains("exceeds 4096 bytes"));
+
+    let block = format!("{{\\fn{}}}", "a".repeat(MAX_OVERRIDE_BLOCK_BYTES - 5));
+    assert!(issues_of(&block).is_empty());
+}
@@ -15,5 +15,6 @@ mod context_scanning;
 mod delimiter_context;
 mod edge_cases;
 mod fields_delimiters;
+mod override_contexts;
 mod state_position;
 mod unicode_long;
@@ -0,0 +1,148 @@
+//! Inline override context transition tests for [`AssTokenizer`].
+
+use crate::tokenizer::{AssTokenizer, TokenContext, TokenType};
+
+#[cfg(not(feature = "std"))]
+extern crate alloc;
+
+use alloc::vec::Vec;
+
+/// Tokenize with override tokens, pairing each token with the context after it
+fn tokens_with_context(source: &str) -> Vec<(TokenType, &str, TokenContext)> {
+    let mut tokenizer = AssTokenizer::new(source).with_override_tokens();
+    let mut tokens = Vec::new();
+    while let Some(token) = tokenizer.next_token().unwrap() {
+        tokens.push((token.token_type, token.span, tokenizer.context));
+    }
+    tokens
+}
+
+#[test]
+fn override_blocks_stay_whole_by_default() {
+    let tokens = AssTokenizer::new("{\\b1}").tokenize_all().unwrap();
+    assert_eq!(tokens[0].token_type, TokenType::OverrideBlock);
+}
+
+#[test]
+fn tag_name_then_argument() {
+    let tokens = tokens_with_context("{\\fs20\\1c&H00FF00&}");
+    assert_eq!(
+        tokens,
+        [
+            (TokenType::OverrideOpen, "{", TokenContext::StyleOverride),
+            (
+                TokenType::OverrideTag,
+                "\\fs",
+                TokenContext::OverrideArgument
+            ),
+            (TokenType::Number, "20", TokenContext::OverrideArgument),
+            (
+                TokenType::OverrideTag,
+                "\\1c",
+                TokenContext::OverrideArgument
+            ),
+            (
+                TokenType::HexValue,
+                "&H00FF00&",
+                TokenContext::OverrideArgument
+            ),
+            (TokenType::OverrideClose, "}", TokenContext::Document),
+        ]
+    );
+}
+
+#[test]
+fn parenthesized_arguments() {
+    let tokens = tokens_with_context("{\\pos(10,20)\\b1}");
+    let types: Vec<_> = tokens.iter().map(|token| token.0).collect();
+    assert_eq!(
+        types,
+        [
+            TokenType::OverrideOpen,
+            TokenType::OverrideTag,
+            TokenType::ParenOpen,
+            TokenType::Number,
+            TokenType::Comma,
+            TokenType::Number,
+            TokenType::ParenClose,
+            TokenType::OverrideTag,
+            TokenType::Number,
+            TokenType::OverrideClose,
+        ]
+    );
+    assert_eq!(tokens[2].2, TokenContext::OverrideArgument);
+    assert_eq!(tokens[6].2, TokenContext::StyleOverride);
+}
+
+#[test]
+fn animation_block_nesting() {
+    let tokens = tokens_with_context("{\\t(0,500,\\t(\\clip(1,2)))\\b1}");
+    let contexts: Vec<_> = tokens
+        .iter()
+        .filter(|token| matches!(token.0, TokenType::ParenOpen | TokenType::ParenClose))
+        .map(|token| token.2)
+        .collect();
+    assert_eq!(
+        contexts,
+        [
+            TokenContext::AnimationBlock,
+            TokenContext::AnimationBlock,
+            TokenContext::OverrideArgument,
+            TokenContext::AnimationBlock,
+            TokenContext::AnimationBlock,
+            TokenContext::StyleOverride,
+        ]
+    );
+    assert_eq!(tokens[tokens.len() - 3].1, "\\b");
+}
+
+#[test]
+fn drawing_commands_after_p1() {
+    let tokens = tokens_with_context("{\\p1}m 0 0 l 10 -5{\\p0}x");
+    let drawing: Vec<_> = tokens[4..tokens.len() - 5]
+        .iter()
+        .map(|token| (token.0, token.1))
+        .collect();
+    assert_eq!(tokens[3].2, TokenContext::DrawingCommands);
+    assert_eq!(
+        drawing,
+        [
+            (TokenType::DrawingCommand, "m"),
+            (TokenType::Number, "0"),
+            (TokenType::Number, "0"),
+            (TokenType::DrawingCommand, "l"),
+            (TokenType::Number, "10"),
+            (TokenType::Number, "-5"),
+        ]
+    );
+    // `\p0` ends drawing mode
+    let last = tokens.last().unwrap();
+    assert_eq!((last.0, last.1), (TokenType::Text, "x"));
+    assert_eq!(last.2, TokenContext::Document);
+}
+
+#[test]
+fn newline_leaves_drawing_and_overrides() {
+    let tokens = tokens_with_context("{\\p1}m 0 0\nm{\\t(");
+    let newline = tokens
+        .iter()
+        .position(|token| token.0 == TokenType::Newline)
+        .unwrap();
+    assert_eq!(tokens[newline].2, TokenContext::Document);
+    assert_eq!(tokens[newline + 1].0, TokenType::Text);
+}
+
+#[test]
+fn dialogue_text_resumes_field_value() {
+    let tokens = tokens_with_context("Dialogue: {\\i1}Hi");
+    let last = tokens.last().unwrap();
+    assert_eq!((last.0, last.1), (TokenType::Text, "Hi"));
+    assert_eq!(last.2, TokenContext::FieldValue);
+}
+
+#[test]
+fn lone_backslash_is_invalid() {
+    let tokens = tokens_with_context("{\\}");
+    assert_eq!(tokens[1].0, TokenType::Invalid);
+    assert_eq!(tokens[2].0, TokenType::OverrideClose);
+}
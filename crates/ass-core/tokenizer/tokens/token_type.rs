@@ -50,6 +50,15 @@ pub enum TokenType {
     /// Style override block content
     OverrideBlock,
 
+    /// Override tag name with its backslash (\fs, \1c)
+    OverrideTag,
+
+    /// Override argument list opening (
+    ParenOpen,
+
+    /// Override argument list closing )
+    ParenClose,
+
     /// Comment line (; or !:)
     Comment,
 
@@ -59,6 +68,9 @@ pub enum TokenType {
     /// Drawing mode scale indicator (\p)
     DrawingScale,
 
+    /// Drawing command letter (m, l, b, ...)
+    DrawingCommand,
+
     /// UU-encoded data line
     UuEncodedLine,
 
@@ -105,6 +117,8 @@ impl TokenType {
                 | Self::SectionClose
                 | Self::OverrideOpen
                 | Self::OverrideClose
+                | Self::ParenOpen
+                | Self::ParenClose
         )
     }
 
@@ -159,9 +173,13 @@ impl TokenType {
             Self::OverrideOpen => "override open",
             Self::OverrideClose => "override close",
             Self::OverrideBlock => "override block",
+            Self::OverrideTag => "override tag",
+            Self::ParenOpen => "paren open",
+            Self::ParenClose => "paren close",
             Self::Comment => "comment",
             Self::Whitespace => "whitespace",
             Self::DrawingScale => "drawing scale",
+            Self::DrawingCommand => "drawing command",
             Self::UuEncodedLine => "UU-encoded line",
             Self::FontFilename => "font filename",
             Self::GraphicFilename => "graphic filename",
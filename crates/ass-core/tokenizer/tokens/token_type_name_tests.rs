@@ -40,6 +40,10 @@ fn override_token_type_names() {
     assert_eq!(TokenType::OverrideOpen.name(), "override open");
     assert_eq!(TokenType::OverrideClose.name(), "override close");
     assert_eq!(TokenType::OverrideBlock.name(), "override block");
+    assert_eq!(TokenType::OverrideTag.name(), "override tag");
+    assert_eq!(TokenType::ParenOpen.name(), "paren open");
+    assert_eq!(TokenType::ParenClose.name(), "paren close");
+    assert_eq!(TokenType::DrawingCommand.name(), "drawing command");
 }
 
 #[test]
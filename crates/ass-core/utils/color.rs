@@ -4,9 +4,9 @@
 //! suitable for rendering.
 
 #[cfg(not(feature = "std"))]
-use alloc::format;
+use alloc::{format, string::String};
 #[cfg(feature = "std")]
-use std::format;
+use std::{format, string::String};
 
 use super::CoreError;
 
@@ -85,3 +85,21 @@ pub fn parse_bgr_color(color_str: &str) -> Result<[u8; 4], CoreError> {
 
     Ok(color_array)
 }
+
+/// Format RGBA bytes as an ASS `&HAABBGGRR&` color string
+///
+/// Inverse of [`parse_bgr_color`] for the 8-digit ABGR form.
+///
+/// # Example
+///
+/// ```rust
+/// # use ass_core::utils::{format_bgr_color, parse_bgr_color};
+/// assert_eq!(format_bgr_color([255, 0, 0, 0]), "&H000000FF&");
+/// assert_eq!(parse_bgr_color(&format_bgr_color([1, 2, 3, 4]))?, [1, 2, 3, 4]);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[must_use]
+pub fn format_bgr_color(rgba: [u8; 4]) -> String {
+    let [red, green, blue, alpha] = rgba;
+    format!("&H{alpha:02X}{blue:02X}{green:02X}{red:02X}&")
+}
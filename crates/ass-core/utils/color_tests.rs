@@ -72,3 +72,10 @@ fn parse_bgr_color_edge_cases() {
     assert!(parse_bgr_color("&H000000X").is_err());
     assert!(parse_bgr_color("X&H000000").is_err());
 }
+
+#[test]
+fn format_bgr_colors() {
+    assert_eq!(format_bgr_color([255, 0, 0, 0]), "&H000000FF&");
+    assert_eq!(format_bgr_color([0, 0, 255, 255]), "&HFFFF0000&");
+    assert_eq!(format_bgr_color([0x12, 0x34, 0x56, 0x78]), "&H78563412&");
+}
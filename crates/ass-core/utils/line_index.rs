@@ -0,0 +1,74 @@
+//! Precomputed byte offset to line/column mapping.
+//!
+//! Provides [`LineIndex`], which records every newline of a source text once
+//! so that line and column lookups are binary searches instead of rescans of
+//! the text before the offset.
+
+use alloc::vec::Vec;
+
+/// Newline positions of a source text for fast offset lookups
+///
+/// Building the index is O(n) in the source length; each lookup is
+/// O(log n) in the number of lines. Offsets past the end of the source
+/// resolve to the last line.
+///
+/// # Example
+///
+/// ```rust
+/// use ass_core::utils::LineIndex;
+///
+/// let index = LineIndex::new("[Script Info]\nTitle: Demo\n");
+/// assert_eq!(index.line_of_offset(14), 2);
+/// assert_eq!(index.column_of_offset(21), 8);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LineIndex {
+    /// Byte offsets of every `\n`, in ascending order
+    newlines: Vec<usize>,
+}
+
+impl LineIndex {
+    /// Index the newlines of `source`
+    #[must_use]
+    pub fn new(source: &str) -> Self {
+        let newlines = source
+            .bytes()
+            .enumerate()
+            .filter_map(|(offset, byte)| (byte == b'\n').then_some(offset))
+            .collect();
+        Self { newlines }
+    }
+
+    /// Line number (1-based) containing the byte at `offset`
+    ///
+    /// A newline belongs to the line it terminates.
+    #[must_use]
+    pub fn line_of_offset(&self, offset: usize) -> usize {
+        self.newlines.partition_point(|&newline| newline < offset) + 1
+    }
+
+    /// Column number (1-based, in bytes) of `offset` within its line
+    #[must_use]
+    pub fn column_of_offset(&self, offset: usize) -> usize {
+        offset - self.line_start(self.line_of_offset(offset)) + 1
+    }
+
+    /// Byte offset where line `line` (1-based) starts
+    ///
+    /// Lines past the last one start after the final newline.
+    #[must_use]
+    pub fn line_start(&self, line: usize) -> usize {
+        line.checked_sub(2).map_or(0, |previous| {
+            self.newlines
+                .get(previous)
+                .or_else(|| self.newlines.last())
+                .map_or(0, |&newline| newline + 1)
+        })
+    }
+
+    /// Number of lines in the source, counting a trailing partial line
+    #[must_use]
+    pub fn line_count(&self) -> usize {
+        self.newlines.len() + 1
+    }
+}
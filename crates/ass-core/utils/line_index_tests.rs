@@ -0,0 +1,52 @@
+//! Tests for the precomputed [`LineIndex`].
+
+use super::*;
+
+#[test]
+fn line_index_lines_and_columns() {
+    let source = "Line 1\nLine 2\n\nLine 4";
+    let index = LineIndex::new(source);
+
+    assert_eq!(index.line_count(), 4);
+    assert_eq!(index.line_of_offset(0), 1);
+    assert_eq!(index.column_of_offset(0), 1);
+    // The newline ending a line still belongs to it
+    assert_eq!(index.line_of_offset(6), 1);
+    assert_eq!(index.column_of_offset(6), 7);
+    assert_eq!(index.line_of_offset(7), 2);
+    assert_eq!(index.column_of_offset(9), 3);
+    assert_eq!(index.line_of_offset(14), 3);
+    assert_eq!(index.line_of_offset(15), 4);
+    assert_eq!(index.column_of_offset(20), 6);
+}
+
+#[test]
+fn line_index_out_of_range() {
+    let index = LineIndex::new("a\nb\n");
+    assert_eq!(index.line_count(), 3);
+    assert_eq!(index.line_of_offset(100), 3);
+    assert_eq!(index.column_of_offset(100), 97);
+    assert_eq!(index.line_start(0), 0);
+    assert_eq!(index.line_start(3), 4);
+    assert_eq!(index.line_start(10), 4);
+
+    let empty = LineIndex::new("");
+    assert_eq!(empty.line_count(), 1);
+    assert_eq!(empty.line_of_offset(0), 1);
+    assert_eq!(empty.column_of_offset(5), 6);
+}
+
+#[test]
+fn line_index_matches_spans() {
+    let source = "[Events]\r\nDialogue: 0\r\n\r\nComment: 1\nend";
+    let (spans, index) = Spans::with_index(source);
+
+    for (offset, _) in source.char_indices() {
+        let span = &source[offset..];
+        assert_eq!(Some(index.line_of_offset(offset)), spans.span_line(span));
+        assert_eq!(
+            Some(index.column_of_offset(offset)),
+            spans.span_column(span)
+        );
+    }
+}
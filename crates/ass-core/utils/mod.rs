@@ -6,6 +6,7 @@
 //! # Performance
 //!
 //! - Zero-copy span utilities for AST references
+//! - Precomputed line index for O(log n) offset to line/column lookups
 //! - SIMD-optimized color conversions when available
 //! - Minimal allocation math helpers (bezier evaluation)
 //!
@@ -27,7 +28,9 @@ pub mod utf8;
 
 mod color;
 mod fields;
+mod line_index;
 mod math;
+mod smpte;
 mod spans;
 mod time;
 mod uu;
@@ -37,8 +40,12 @@ mod color_tests;
 #[cfg(test)]
 mod fields_tests;
 #[cfg(test)]
+mod line_index_tests;
+#[cfg(test)]
 mod math_tests;
 #[cfg(test)]
+mod smpte_tests;
+#[cfg(test)]
 mod spans_tests;
 #[cfg(test)]
 mod time_tests;
@@ -51,11 +58,15 @@ pub use benchmark_generators::{
 };
 pub use errors::CoreError;
 pub use hashers::{create_hash_map, create_hash_map_with_capacity, create_hasher, hash_value};
-pub use utf8::{detect_encoding, normalize_line_endings, recover_utf8, strip_bom, validate_utf8};
+pub use utf8::{
+    decode_to_utf8, detect_encoding, normalize_line_endings, recover_utf8, strip_bom, validate_utf8,
+};
 
-pub use color::parse_bgr_color;
+pub use color::{format_bgr_color, parse_bgr_color};
 pub use fields::{normalize_field_value, parse_numeric, validate_ass_name};
+pub use line_index::LineIndex;
 pub use math::eval_cubic_bezier;
+pub use smpte::{format_smpte_timecode, parse_smpte_timecode, SmpteTimecode};
 pub use spans::Spans;
 pub use time::{format_ass_time, parse_ass_time};
 pub use uu::decode_uu_data;
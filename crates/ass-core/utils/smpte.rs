@@ -0,0 +1,248 @@
+//! SMPTE timecode conversion helpers.
+//!
+//! Converts broadcast `HH:MM:SS:FF` (non-drop-frame) and `HH:MM:SS;FF`
+//! (drop-frame) timecodes to and from ASS centiseconds. NTSC rates such as
+//! 23.976, 29.97 and 59.94 are treated as their exact `N * 1000 / 1001`
+//! ratios so long timecodes do not drift.
+
+use core::fmt;
+
+#[cfg(not(feature = "std"))]
+use alloc::string::{String, ToString};
+#[cfg(feature = "std")]
+use std::string::{String, ToString};
+
+use super::{errors::invalid_time, CoreError};
+
+/// Tolerance used to recognise integer and NTSC frame rates
+const RATE_EPSILON: f64 = 0.005;
+
+/// A single SMPTE timecode at a given frame rate
+///
+/// Drop-frame timecodes skip frame numbers 0 and 1 (0-3 at 59.94) on the
+/// first second of every minute except each tenth minute, keeping the
+/// labels aligned with wall-clock time at NTSC rates.
+///
+/// # Example
+///
+/// ```rust
+/// # use ass_core::utils::SmpteTimecode;
+/// let tc = SmpteTimecode::parse("00:01:00;02", 29.97)?;
+/// assert_eq!(tc.frames, 2);
+/// assert_eq!(SmpteTimecode::from_centiseconds(tc.to_centiseconds(), 29.97, true), tc);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SmpteTimecode {
+    /// Hours component
+    pub hours: u32,
+    /// Minutes component (0-59)
+    pub minutes: u32,
+    /// Seconds component (0-59)
+    pub seconds: u32,
+    /// Frame number within the second
+    pub frames: u32,
+    /// Frame rate the timecode is expressed in
+    pub fps: f64,
+    /// Whether frame numbers are dropped to track NTSC wall-clock time
+    pub drop_frame: bool,
+}
+
+impl SmpteTimecode {
+    /// Parse `HH:MM:SS:FF` or drop-frame `HH:MM:SS;FF` at the given rate
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CoreError::InvalidTime`] if the frame rate is not positive,
+    /// the string is malformed, a component is out of range, drop-frame is
+    /// requested at a rate without dropped frames, or the timecode names a
+    /// frame number that drop-frame counting skips.
+    pub fn parse(s: &str, fps: f64) -> Result<Self, CoreError> {
+        if !fps.is_finite() || fps <= 0.0 {
+            return Err(invalid_time(s, "frame rate must be positive"));
+        }
+        let rate = FrameRate::new(fps);
+        let drop_frame = s.contains(';');
+
+        let mut parts = s.trim().split([':', ';']);
+        let mut next = |name: &str| -> Result<u32, CoreError> {
+            parts
+                .next()
+                .and_then(|part| part.parse().ok())
+                .ok_or_else(|| invalid_time(s, name))
+        };
+        let hours = next("invalid hours")?;
+        let minutes = next("invalid minutes")?;
+        let seconds = next("invalid seconds")?;
+        let frames = next("invalid frames")?;
+        if parts.next().is_some() {
+            return Err(invalid_time(s, "expected HH:MM:SS:FF"));
+        }
+
+        if minutes >= 60 || seconds >= 60 {
+            return Err(invalid_time(s, "minutes and seconds must be < 60"));
+        }
+        if frames >= rate.nominal {
+            return Err(invalid_time(s, "frame number exceeds frame rate"));
+        }
+        let dropped = rate.dropped_per_minute(drop_frame);
+        if drop_frame && dropped == 0 {
+            return Err(invalid_time(s, "drop-frame requires a 29.97 or 59.94 rate"));
+        }
+        if seconds == 0 && minutes % 10 != 0 && frames < dropped {
+            return Err(invalid_time(s, "frame number is skipped by drop-frame"));
+        }
+
+        Ok(Self {
+            hours,
+            minutes,
+            seconds,
+            frames,
+            fps,
+            drop_frame,
+        })
+    }
+
+    /// Convert to ASS centiseconds, rounding to the nearest centisecond
+    #[must_use]
+    pub fn to_centiseconds(&self) -> u32 {
+        let rate = FrameRate::new(self.fps);
+        let nominal = u64::from(rate.nominal);
+        let dropped = u64::from(rate.dropped_per_minute(self.drop_frame));
+
+        let total_minutes = u64::from(self.hours) * 60 + u64::from(self.minutes);
+        let labelled =
+            (total_minutes * 60 + u64::from(self.seconds)) * nominal + u64::from(self.frames);
+        let index = labelled.saturating_sub(dropped * (total_minutes - total_minutes / 10));
+
+        let cs = (index * 200 * rate.den + rate.num) / (2 * rate.num);
+        u32::try_from(cs).unwrap_or(u32::MAX)
+    }
+
+    /// Build the timecode of the frame nearest to `cs` centiseconds
+    ///
+    /// `drop_frame` is ignored at rates without dropped frames, so the result
+    /// always describes a timecode that [`SmpteTimecode::parse`] accepts.
+    #[must_use]
+    pub fn from_centiseconds(cs: u32, fps: f64, drop_frame: bool) -> Self {
+        let rate = FrameRate::new(fps);
+        let nominal = u64::from(rate.nominal);
+        let dropped = u64::from(rate.dropped_per_minute(drop_frame));
+
+        let index = (u64::from(cs) * rate.num * 2 + 100 * rate.den) / (200 * rate.den);
+        let mut labelled = index;
+        if dropped > 0 {
+            let per_ten_minutes = nominal * 600 - dropped * 9;
+            let per_minute = nominal * 60 - dropped;
+            let remainder = index % per_ten_minutes;
+            labelled += dropped * 9 * (index / per_ten_minutes);
+            if remainder > dropped {
+                labelled += dropped * ((remainder - dropped) / per_minute);
+            }
+        }
+
+        let component = |value: u64| u32::try_from(value).unwrap_or(u32::MAX);
+        Self {
+            hours: component(labelled / (nominal * 3600)),
+            minutes: component(labelled / (nominal * 60) % 60),
+            seconds: component(labelled / nominal % 60),
+            frames: component(labelled % nominal),
+            fps,
+            drop_frame: dropped > 0,
+        }
+    }
+}
+
+impl fmt::Display for SmpteTimecode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let separator = if self.drop_frame { ';' } else { ':' };
+        write!(
+            f,
+            "{:02}:{:02}:{:02}{separator}{:02}",
+            self.hours, self.minutes, self.seconds, self.frames
+        )
+    }
+}
+
+/// Parse an SMPTE timecode to centiseconds
+///
+/// A `;` anywhere in the string selects drop-frame counting.
+///
+/// # Example
+///
+/// ```rust
+/// # use ass_core::utils::parse_smpte_timecode;
+/// assert_eq!(parse_smpte_timecode("00:00:01:12", 24.0)?, 150);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+///
+/// # Errors
+///
+/// Returns an error under the same conditions as [`SmpteTimecode::parse`].
+pub fn parse_smpte_timecode(s: &str, fps: f64) -> Result<u32, CoreError> {
+    SmpteTimecode::parse(s, fps).map(|tc| tc.to_centiseconds())
+}
+
+/// Format centiseconds as an SMPTE timecode at the given rate
+#[must_use]
+pub fn format_smpte_timecode(cs: u32, fps: f64, drop_frame: bool) -> String {
+    SmpteTimecode::from_centiseconds(cs, fps, drop_frame).to_string()
+}
+
+/// Exact frame rate as a ratio plus the nominal frames-per-second label count
+#[derive(Debug, Clone, Copy)]
+struct FrameRate {
+    /// Frame labels per second (24 for 23.976, 30 for 29.97)
+    nominal: u32,
+    /// Rate numerator in frames
+    num: u64,
+    /// Rate denominator in seconds
+    den: u64,
+}
+
+impl FrameRate {
+    /// Snap `fps` to an integer or NTSC ratio when it is close to one
+    fn new(fps: f64) -> Self {
+        let nominal = u32::try_from(round_to_u64(fps).max(1)).unwrap_or(u32::MAX);
+        let integer = f64::from(nominal);
+        let ntsc = integer * 1000.0 / 1001.0;
+        let near = |target: f64| fps - target < RATE_EPSILON && target - fps < RATE_EPSILON;
+
+        let (num, den) = if near(integer) {
+            (u64::from(nominal), 1)
+        } else if near(ntsc) {
+            (u64::from(nominal) * 1000, 1001)
+        } else {
+            (round_to_u64(fps * 1000.0).max(1), 1000)
+        };
+        Self { nominal, num, den }
+    }
+
+    /// Frame numbers skipped per minute (two per 30 nominal frames)
+    const fn dropped_per_minute(self, drop_frame: bool) -> u32 {
+        if drop_frame && self.nominal % 30 == 0 && self.den == 1001 {
+            self.nominal / 15
+        } else {
+            0
+        }
+    }
+}
+
+/// Round a non-negative value to the nearest integer; negative and NaN give 0
+///
+/// Takes the integer part from the IEEE 754 bits, as `core` has no float
+/// rounding; values past `u64::MAX` saturate.
+fn round_to_u64(value: f64) -> u64 {
+    let value = value + 0.5;
+    if value.is_nan() || value < 1.0 {
+        return 0;
+    }
+    let bits = value.to_bits();
+    let mantissa = (bits & ((1 << 52) - 1)) | (1 << 52);
+    // `value` is at least 1.0, so the biased exponent is at least 1023
+    match ((bits >> 52) & 0x7ff) - 1023 {
+        64.. => u64::MAX,
+        exponent @ 52.. => mantissa << (exponent - 52),
+        exponent => mantissa >> (52 - exponent),
+    }
+}
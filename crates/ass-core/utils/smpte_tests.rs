@@ -0,0 +1,125 @@
+//! Tests for SMPTE timecode parsing and formatting.
+
+use super::*;
+use proptest::prelude::*;
+
+#[cfg(not(feature = "std"))]
+use alloc::{format, string::ToString};
+
+#[test]
+fn parse_non_drop_frame() {
+    assert_eq!(parse_smpte_timecode("00:00:00:00", 25.0).unwrap(), 0);
+    assert_eq!(parse_smpte_timecode("00:00:01:00", 25.0).unwrap(), 100);
+    assert_eq!(parse_smpte_timecode("00:00:00:12", 24.0).unwrap(), 50);
+    assert_eq!(parse_smpte_timecode("01:00:00:00", 30.0).unwrap(), 360_000);
+}
+
+#[test]
+fn parse_non_standard_rate_rounds_nominal_rate() {
+    // 12.5 fps labels 13 frames per second, so two labelled seconds are
+    // 26 frames lasting 2.08 seconds.
+    assert_eq!(parse_smpte_timecode("00:00:00:05", 12.5).unwrap(), 40);
+    assert_eq!(parse_smpte_timecode("00:00:02:00", 12.5).unwrap(), 208);
+}
+
+#[test]
+fn parse_ntsc_uses_exact_ratio() {
+    // 86_400 frames at 24000/1001 fps last exactly 3603.6 seconds.
+    assert_eq!(
+        parse_smpte_timecode("01:00:00:00", 23.976).unwrap(),
+        360_360
+    );
+}
+
+#[test]
+fn drop_frame_hour_matches_wall_clock() {
+    // One drop-frame hour at 29.97 is 107_892 frames, i.e. 3599.9964 seconds.
+    assert_eq!(parse_smpte_timecode("01:00:00;00", 29.97).unwrap(), 360_000);
+    assert_eq!(parse_smpte_timecode("01:00:00;00", 59.94).unwrap(), 360_000);
+}
+
+#[test]
+fn drop_frame_skips_first_frames_of_minute() {
+    let before = parse_smpte_timecode("00:00:59;29", 29.97).unwrap();
+    let after = parse_smpte_timecode("00:01:00;02", 29.97).unwrap();
+    assert_eq!(after - before, 3);
+    assert_eq!(format_smpte_timecode(after, 29.97, true), "00:01:00;02");
+
+    assert!(parse_smpte_timecode("00:01:00;00", 29.97).is_err());
+    assert!(parse_smpte_timecode("00:01:00;01", 29.97).is_err());
+    assert!(parse_smpte_timecode("00:01:00;03", 59.94).is_err());
+    assert!(parse_smpte_timecode("00:10:00;00", 29.97).is_ok());
+}
+
+#[test]
+fn from_centiseconds_builds_components() {
+    let tc = SmpteTimecode::from_centiseconds(9040, 25.0, false);
+    assert_eq!(
+        (tc.hours, tc.minutes, tc.seconds, tc.frames),
+        (0, 1, 30, 10)
+    );
+    assert!(!tc.drop_frame);
+    assert_eq!(tc.to_string(), "00:01:30:10");
+}
+
+#[test]
+fn drop_frame_ignored_without_ntsc_rate() {
+    let tc = SmpteTimecode::from_centiseconds(100, 25.0, true);
+    assert!(!tc.drop_frame);
+    assert_eq!(format_smpte_timecode(100, 25.0, true), "00:00:01:00");
+}
+
+#[test]
+fn parse_invalid_timecodes() {
+    assert!(parse_smpte_timecode("00:00:00", 25.0).is_err());
+    assert!(parse_smpte_timecode("00:00:00:00:00", 25.0).is_err());
+    assert!(parse_smpte_timecode("00:60:00:00", 25.0).is_err());
+    assert!(parse_smpte_timecode("00:00:60:00", 25.0).is_err());
+    assert!(parse_smpte_timecode("00:00:00:25", 25.0).is_err());
+    assert!(parse_smpte_timecode("aa:00:00:00", 25.0).is_err());
+    assert!(parse_smpte_timecode("00:00:00;00", 25.0).is_err());
+    assert!(parse_smpte_timecode("00:00:00:00", 0.0).is_err());
+    assert!(parse_smpte_timecode("00:00:00:00", f64::NAN).is_err());
+}
+
+fn frame_rates() -> impl Strategy<Value = (f64, u32, bool)> {
+    prop_oneof![
+        Just((23.976, 24, false)),
+        Just((29.97, 30, false)),
+        Just((29.97, 30, true)),
+        Just((59.94, 60, false)),
+        Just((59.94, 60, true)),
+    ]
+}
+
+proptest! {
+    #[test]
+    fn timecode_roundtrips_through_centiseconds(
+        (fps, nominal, drop_frame) in frame_rates(),
+        hours in 0u32..10,
+        minutes in 0u32..60,
+        seconds in 0u32..60,
+        frame in 0u32..60,
+    ) {
+        let mut frames = frame % nominal;
+        if drop_frame && seconds == 0 && minutes % 10 != 0 {
+            frames = frames.max(nominal / 15);
+        }
+        let tc = SmpteTimecode { hours, minutes, seconds, frames, fps, drop_frame };
+
+        let cs = tc.to_centiseconds();
+        prop_assert_eq!(SmpteTimecode::from_centiseconds(cs, fps, drop_frame), tc);
+        prop_assert_eq!(parse_smpte_timecode(&tc.to_string(), fps).unwrap(), cs);
+    }
+
+    #[test]
+    fn centiseconds_land_on_nearest_frame(
+        (fps, _, drop_frame) in frame_rates(),
+        cs in 0u32..3_600_000,
+    ) {
+        let formatted = format_smpte_timecode(cs, fps, drop_frame);
+        let back = parse_smpte_timecode(&formatted, fps).unwrap();
+        let half_frame = 50.0 / fps;
+        prop_assert!((f64::from(back) - f64::from(cs)).abs() <= half_frame + 0.5);
+    }
+}
@@ -3,6 +3,7 @@
 //! Provides [`Spans`], a helper for validating and locating string slices that
 //! reference the original source text while maintaining zero-copy semantics.
 
+use super::LineIndex;
 use core::ops::Range;
 
 /// Zero-copy span utilities for AST node validation and manipulation
@@ -22,6 +23,16 @@ impl<'a> Spans<'a> {
         Self { source }
     }
 
+    /// Create span utilities together with a [`LineIndex`] of the source
+    ///
+    /// Prefer the index's lookups over [`Spans::span_line`] and
+    /// [`Spans::span_column`] when locating many offsets, as those rescan
+    /// the source on every call.
+    #[must_use]
+    pub fn with_index(source: &'a str) -> (Self, LineIndex) {
+        (Self::new(source), LineIndex::new(source))
+    }
+
     /// Validate that a span references this source text
     ///
     /// Returns `true` if the span is a valid substring of the source.
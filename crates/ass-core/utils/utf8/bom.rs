@@ -171,5 +171,73 @@ pub fn detect_bom(bytes: &[u8]) -> Option<(BomType, usize)> {
     }
 }
 
+/// Detect and strip a BOM from raw bytes
+///
+/// Byte-level counterpart to [`strip_bom`] for input that has not been
+/// decoded yet. Returns the payload after the BOM together with the encoding
+/// it announced, so callers can transcode UTF-16/UTF-32 input to UTF-8
+/// before parsing. Input without a BOM is returned unchanged with `None`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use ass_core::utils::utf8::{detect_and_strip_bom, BomType};
+/// let (payload, bom) = detect_and_strip_bom(&[0xFF, 0xFE, b'[', 0x00]);
+/// assert_eq!(payload, &[b'[', 0x00]);
+/// assert_eq!(bom, Some(BomType::Utf16Le));
+///
+/// let (payload, bom) = detect_and_strip_bom(b"[Script Info]");
+/// assert_eq!(payload, b"[Script Info]");
+/// assert_eq!(bom, None);
+/// ```
+#[must_use]
+pub fn detect_and_strip_bom(bytes: &[u8]) -> (&[u8], Option<BomType>) {
+    detect_bom(bytes).map_or((bytes, None), |(bom_type, skip)| {
+        (&bytes[skip..], Some(bom_type))
+    })
+}
+
 #[cfg(test)]
-mod tests {}
+mod tests {
+    use super::*;
+
+    #[test]
+    fn detect_and_strip_utf8_bom() {
+        let input = [0xEF, 0xBB, 0xBF, b'H', b'i'];
+        let (payload, bom) = detect_and_strip_bom(&input);
+        assert_eq!(payload, b"Hi");
+        assert_eq!(bom, Some(BomType::Utf8));
+    }
+
+    #[test]
+    fn detect_and_strip_utf16_boms() {
+        let le = [0xFF, 0xFE, b'H', 0x00];
+        assert_eq!(
+            detect_and_strip_bom(&le),
+            (&[b'H', 0x00][..], Some(BomType::Utf16Le))
+        );
+
+        let be = [0xFE, 0xFF, 0x00, b'H'];
+        assert_eq!(
+            detect_and_strip_bom(&be),
+            (&[0x00, b'H'][..], Some(BomType::Utf16Be))
+        );
+    }
+
+    #[test]
+    fn detect_and_strip_prefers_utf32_le_over_utf16_le() {
+        let input = [0xFF, 0xFE, 0x00, 0x00, b'H', 0x00, 0x00, 0x00];
+        let (payload, bom) = detect_and_strip_bom(&input);
+        assert_eq!(payload, &[b'H', 0x00, 0x00, 0x00]);
+        assert_eq!(bom, Some(BomType::Utf32Le));
+    }
+
+    #[test]
+    fn detect_and_strip_without_bom_is_identity() {
+        let input = b"[Script Info]";
+        let (payload, bom) = detect_and_strip_bom(input);
+        assert_eq!(payload, input);
+        assert!(bom.is_none());
+        assert_eq!(detect_and_strip_bom(&[]), (&[][..], None));
+    }
+}
@@ -0,0 +1,124 @@
+//! Decoding of raw script bytes to UTF-8
+//!
+//! Provides [`decode_to_utf8`], which picks the encoding from the byte order
+//! mark and transcodes UTF-16 and UTF-32 input, as saved by many Windows
+//! subtitle editors, so the result can be handed to the parser.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use ass_core::utils::utf8::decode_to_utf8;
+//!
+//! let utf16le: Vec<u8> = [0xFF, 0xFE]
+//!     .into_iter()
+//!     .chain("[Script Info]".encode_utf16().flat_map(u16::to_le_bytes))
+//!     .collect();
+//! assert_eq!(decode_to_utf8(&utf16le)?, "[Script Info]");
+//! # Ok::<(), ass_core::CoreError>(())
+//! ```
+
+use super::bom::{detect_and_strip_bom, BomType};
+use crate::utils::CoreError;
+use alloc::{borrow::Cow, format, string::String};
+
+/// Decode script bytes to UTF-8 text
+///
+/// The encoding is taken from the byte order mark, which is stripped:
+///
+/// - UTF-8 and unmarked input is borrowed when valid, with invalid sequences
+///   replaced by U+FFFD otherwise
+/// - UTF-16 LE/BE and UTF-32 LE/BE input is transcoded into a new string
+///
+/// # Errors
+///
+/// Returns `CoreError::Utf8Error` if UTF-16 or UTF-32 input ends in a
+/// partial code unit or contains an invalid code point (such as an unpaired
+/// surrogate). The position is the byte offset of the offending unit in
+/// `bytes`.
+pub fn decode_to_utf8(bytes: &[u8]) -> Result<Cow<'_, str>, CoreError> {
+    let (payload, bom) = detect_and_strip_bom(bytes);
+    let bom_len = bom.map_or(0, BomType::len);
+
+    match bom {
+        None | Some(BomType::Utf8) => Ok(String::from_utf8_lossy(payload)),
+        Some(BomType::Utf16Le) => decode_utf16(payload, bom_len, u16::from_le_bytes),
+        Some(BomType::Utf16Be) => decode_utf16(payload, bom_len, u16::from_be_bytes),
+        Some(BomType::Utf32Le) => decode_utf32(payload, bom_len, u32::from_le_bytes),
+        Some(BomType::Utf32Be) => decode_utf32(payload, bom_len, u32::from_be_bytes),
+    }
+}
+
+/// Transcode UTF-16 code units to UTF-8
+fn decode_utf16(
+    payload: &[u8],
+    offset: usize,
+    unit: fn([u8; 2]) -> u16,
+) -> Result<Cow<'static, str>, CoreError> {
+    let name = if unit([0, 1]) == 1 {
+        BomType::Utf16Be
+    } else {
+        BomType::Utf16Le
+    }
+    .encoding_name();
+
+    let chunks = payload.chunks_exact(2);
+    if !chunks.remainder().is_empty() {
+        return Err(CoreError::utf8_error(
+            offset + payload.len() - 1,
+            format!("{name} input ends in a partial code unit"),
+        ));
+    }
+
+    let units = chunks.map(|pair| unit([pair[0], pair[1]]));
+    let mut text = String::with_capacity(payload.len() / 2);
+    let mut position = offset;
+    for decoded in char::decode_utf16(units) {
+        let ch = decoded.map_err(|err| {
+            CoreError::utf8_error(
+                position,
+                format!(
+                    "{name} input has unpaired surrogate {:#06X}",
+                    err.unpaired_surrogate()
+                ),
+            )
+        })?;
+        text.push(ch);
+        position += ch.len_utf16() * 2;
+    }
+    Ok(Cow::Owned(text))
+}
+
+/// Transcode UTF-32 code units to UTF-8
+fn decode_utf32(
+    payload: &[u8],
+    offset: usize,
+    unit: fn([u8; 4]) -> u32,
+) -> Result<Cow<'static, str>, CoreError> {
+    let name = if unit([0, 0, 0, 1]) == 1 {
+        BomType::Utf32Be
+    } else {
+        BomType::Utf32Le
+    }
+    .encoding_name();
+
+    let chunks = payload.chunks_exact(4);
+    if !chunks.remainder().is_empty() {
+        return Err(CoreError::utf8_error(
+            offset + payload.len() - chunks.remainder().len(),
+            format!("{name} input ends in a partial code unit"),
+        ));
+    }
+
+    let mut text = String::with_capacity(payload.len() / 4);
+    for (index, quad) in chunks.enumerate() {
+        let code = unit([quad[0], quad[1], quad[2], quad[3]]);
+        let ch = char::from_u32(code).ok_or_else(|| {
+            CoreError::utf8_error(
+                offset + index * 4,
+                format!("{name} input has invalid code point {code:#X}"),
+            )
+        })?;
+        text.push(ch);
+    }
+    Ok(Cow::Owned(text))
+}
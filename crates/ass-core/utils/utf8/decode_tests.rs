@@ -0,0 +1,77 @@
+//! Tests for decoding raw script bytes to UTF-8.
+
+use super::*;
+use crate::utils::CoreError;
+use alloc::{borrow::Cow, vec::Vec};
+
+const SCRIPT: &str = "[Script Info]\r\nTitle: Téléchargé 字幕 🎵\r\nScriptType: v4.00+\r\n";
+
+/// Encode `text` as UTF-16 with the given byte order and a BOM
+fn utf16(text: &str, to_bytes: fn(u16) -> [u8; 2]) -> Vec<u8> {
+    to_bytes(0xFEFF)
+        .into_iter()
+        .chain(text.encode_utf16().flat_map(to_bytes))
+        .collect()
+}
+
+#[test]
+fn decode_utf8_borrows_input() {
+    let decoded = decode_to_utf8(SCRIPT.as_bytes()).unwrap();
+    assert!(matches!(decoded, Cow::Borrowed(SCRIPT)));
+
+    let with_bom: Vec<u8> = [0xEF, 0xBB, 0xBF]
+        .into_iter()
+        .chain(SCRIPT.bytes())
+        .collect();
+    let decoded = decode_to_utf8(&with_bom).unwrap();
+    assert!(matches!(decoded, Cow::Borrowed(SCRIPT)));
+}
+
+#[test]
+fn decode_invalid_utf8_is_lossy() {
+    let decoded = decode_to_utf8(&[b'T', 0xFF, b'!']).unwrap();
+    assert_eq!(decoded, "T\u{FFFD}!");
+}
+
+#[test]
+fn decode_utf16_le_and_be() {
+    let le = utf16(SCRIPT, u16::to_le_bytes);
+    assert_eq!(le[..4], [0xFF, 0xFE, b'[', 0x00]);
+    assert_eq!(decode_to_utf8(&le).unwrap(), SCRIPT);
+
+    let be = utf16(SCRIPT, u16::to_be_bytes);
+    assert_eq!(be[..4], [0xFE, 0xFF, 0x00, b'[']);
+    assert_eq!(decode_to_utf8(&be).unwrap(), SCRIPT);
+}
+
+#[test]
+fn decode_utf32_le_and_be() {
+    let le: Vec<u8> = "\u{FEFF}[Events] 🎵"
+        .chars()
+        .flat_map(|ch| u32::from(ch).to_le_bytes())
+        .collect();
+    assert_eq!(decode_to_utf8(&le).unwrap(), "[Events] 🎵");
+
+    let be: Vec<u8> = "\u{FEFF}[Events] 🎵"
+        .chars()
+        .flat_map(|ch| u32::from(ch).to_be_bytes())
+        .collect();
+    assert_eq!(decode_to_utf8(&be).unwrap(), "[Events] 🎵");
+}
+
+#[test]
+fn decode_utf16_rejects_truncated_input() {
+    let mut le = utf16("[Script Info]", u16::to_le_bytes);
+    le.push(b'\n');
+    let err = decode_to_utf8(&le).unwrap_err();
+    assert!(matches!(err, CoreError::Utf8Error { position, .. } if position == le.len() - 1));
+}
+
+#[test]
+fn decode_utf16_rejects_unpaired_surrogate() {
+    let mut le = utf16("ab", u16::to_le_bytes);
+    le.extend_from_slice(&0xD800u16.to_le_bytes());
+    le.extend_from_slice(&u16::from(b'c').to_le_bytes());
+    let err = decode_to_utf8(&le).unwrap_err();
+    assert!(matches!(err, CoreError::Utf8Error { position: 6, .. }));
+}
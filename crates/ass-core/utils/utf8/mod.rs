@@ -6,6 +6,7 @@
 //! # Features
 //!
 //! - BOM detection and stripping for common encodings
+//! - Decoding of UTF-16 and UTF-32 input to UTF-8
 //! - UTF-8 validation with detailed error reporting
 //! - Encoding detection for legacy ASS files
 //! - `nostd` compatible implementation
@@ -34,12 +35,14 @@
 //! ```
 
 mod bom;
+mod decode;
 mod encoding;
 mod normalization;
 mod validation;
 
 // Re-export all public types and functions for API compatibility
-pub use bom::{detect_bom, strip_bom, BomType};
+pub use bom::{detect_and_strip_bom, detect_bom, strip_bom, BomType};
+pub use decode::decode_to_utf8;
 pub use encoding::{detect_encoding, is_likely_ass_content, EncodingInfo};
 pub use normalization::{
     normalize_line_endings, normalize_whitespace, remove_control_chars, trim_lines,
@@ -49,6 +52,9 @@ pub use validation::{
     validate_utf8,
 };
 
+#[cfg(test)]
+mod decode_tests;
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -78,6 +78,30 @@ impl ScriptVersion {
         }
     }
 
+    /// Canonical `ScriptType` header value for this version.
+    ///
+    /// Inverse of [`ScriptVersion::from_header`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ass_core::ScriptVersion;
+    ///
+    /// assert_eq!(ScriptVersion::AssV4.as_header_str(), "v4.00+");
+    /// assert_eq!(
+    ///     ScriptVersion::from_header(ScriptVersion::SsaV4.as_header_str()),
+    ///     Some(ScriptVersion::SsaV4)
+    /// );
+    /// ```
+    #[must_use]
+    pub const fn as_header_str(self) -> &'static str {
+        match self {
+            Self::SsaV4 => "v4.00",
+            Self::AssV4 => "v4.00+",
+            Self::AssV4Plus => "v4.00++",
+        }
+    }
+
     /// Check if the script version supports modern ASS extensions.
     ///
     /// Modern ASS extensions include features like:
@@ -25,6 +25,7 @@ pub fn bench_index_operations(c: &mut Criterion) {
                         case_sensitive: false,
                         whole_words: false,
                         use_regex: false,
+                        multiline: false,
                         scope: SearchScope::All,
                         max_results: 100,
                     };
@@ -51,6 +52,7 @@ pub fn bench_index_operations(c: &mut Criterion) {
                     case_sensitive: false,
                     whole_words: false,
                     use_regex: false,
+                    multiline: false,
                     scope: SearchScope::All,
                     max_results: 100,
                 };
@@ -24,6 +24,7 @@ pub fn bench_regex_search(c: &mut Criterion) {
                     case_sensitive: true,
                     whole_words: false,
                     use_regex: true,
+                    multiline: false,
                     scope: SearchScope::All,
                     max_results: 100,
                 };
@@ -46,6 +47,7 @@ pub fn bench_regex_search(c: &mut Criterion) {
                     case_sensitive: true,
                     whole_words: false,
                     use_regex: true,
+                    multiline: false,
                     scope: SearchScope::All,
                     max_results: 100,
                 };
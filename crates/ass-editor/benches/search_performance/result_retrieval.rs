@@ -24,6 +24,7 @@ pub fn bench_result_retrieval(c: &mut Criterion) {
                     case_sensitive: false,
                     whole_words: false,
                     use_regex: false,
+                    multiline: false,
                     scope: SearchScope::All,
                     max_results: 10,
                 };
@@ -46,6 +47,7 @@ pub fn bench_result_retrieval(c: &mut Criterion) {
                     case_sensitive: false,
                     whole_words: false,
                     use_regex: false,
+                    multiline: false,
                     scope: SearchScope::All,
                     max_results: 1000,
                 };
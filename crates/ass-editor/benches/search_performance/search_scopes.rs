@@ -24,6 +24,7 @@ pub fn bench_search_scopes(c: &mut Criterion) {
                     case_sensitive: false,
                     whole_words: false,
                     use_regex: false,
+                    multiline: false,
                     scope: SearchScope::All,
                     max_results: 100,
                 };
@@ -46,6 +47,7 @@ pub fn bench_search_scopes(c: &mut Criterion) {
                     case_sensitive: false,
                     whole_words: false,
                     use_regex: false,
+                    multiline: false,
                     scope: SearchScope::Lines {
                         start: 50,
                         end: 150,
@@ -71,6 +73,7 @@ pub fn bench_search_scopes(c: &mut Criterion) {
                     case_sensitive: false,
                     whole_words: false,
                     use_regex: false,
+                    multiline: false,
                     scope: SearchScope::Sections(vec!["Events".to_string()]),
                     max_results: 100,
                 };
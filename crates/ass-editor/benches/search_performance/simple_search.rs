@@ -27,6 +27,7 @@ pub fn bench_simple_search(c: &mut Criterion) {
                             case_sensitive: true,
                             whole_words: false,
                             use_regex: false,
+                            multiline: false,
                             scope: SearchScope::All,
                             max_results: 100,
                         };
@@ -52,6 +53,7 @@ pub fn bench_simple_search(c: &mut Criterion) {
                             case_sensitive: false,
                             whole_words: false,
                             use_regex: false,
+                            multiline: false,
                             scope: SearchScope::All,
                             max_results: 100,
                         };
@@ -77,6 +79,7 @@ pub fn bench_simple_search(c: &mut Criterion) {
                             case_sensitive: false,
                             whole_words: true,
                             use_regex: false,
+                            multiline: false,
                             scope: SearchScope::All,
                             max_results: 100,
                         };
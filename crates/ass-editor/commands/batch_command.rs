@@ -2,7 +2,7 @@
 
 use crate::core::{EditorDocument, Position, Range, Result};
 
-use super::{CommandResult, EditorCommand};
+use super::{CommandResult, EditorCommand, RecordedCommand};
 
 #[cfg(not(feature = "std"))]
 use alloc::{boxed::Box, format, string::String, vec::Vec};
@@ -97,6 +97,18 @@ impl EditorCommand for BatchCommand {
         &self.description
     }
 
+    /// Recordable only if every command of the batch is
+    fn to_recorded(&self) -> Option<RecordedCommand> {
+        Some(RecordedCommand::Batch {
+            description: self.description.clone(),
+            commands: self
+                .commands
+                .iter()
+                .map(|command| command.to_recorded())
+                .collect::<Option<_>>()?,
+        })
+    }
+
     fn memory_usage(&self) -> usize {
         core::mem::size_of::<Self>()
             + self.description.len()
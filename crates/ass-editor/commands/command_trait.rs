@@ -2,7 +2,7 @@
 
 use crate::core::{EditorDocument, Result};
 
-use super::CommandResult;
+use super::{CommandResult, RecordedCommand};
 
 /// Trait for editor commands that can be executed and undone
 ///
@@ -67,4 +67,13 @@ pub trait EditorCommand: core::fmt::Debug + Send + Sync {
     fn memory_usage(&self) -> usize {
         64 // Conservative default estimate for command overhead
     }
+
+    /// Describe this command for macro recording
+    ///
+    /// Returns the command type and arguments without any document state,
+    /// or `None` (the default) if the command cannot be recorded by a
+    /// [`MacroRecorder`](super::MacroRecorder).
+    fn to_recorded(&self) -> Option<RecordedCommand> {
+        None
+    }
 }
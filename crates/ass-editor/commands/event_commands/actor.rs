@@ -0,0 +1,64 @@
+//! Command to set the actor (`Name` field) of a single event.
+
+use super::helpers::event_fields;
+use crate::commands::{CommandResult, EditorCommand};
+use crate::core::{EditorDocument, EditorError, Position, Range, Result};
+
+#[cfg(not(feature = "std"))]
+use alloc::{format, string::String};
+
+/// Command to set the actor of an event
+///
+/// Rewrites only the `Name` field of the event at `event_index`, counting
+/// both Dialogue and Comment events. An empty actor clears the field. Run it
+/// through [`EditorDocument::execute_command`] to make it undoable.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AssignActorCommand {
+    /// Index of the event to change
+    pub event_index: usize,
+    /// New actor name
+    pub actor: String,
+}
+
+impl AssignActorCommand {
+    /// Create a new assign actor command
+    pub fn new(event_index: usize, actor: String) -> Self {
+        Self { event_index, actor }
+    }
+}
+
+impl EditorCommand for AssignActorCommand {
+    fn execute(&self, document: &mut EditorDocument) -> Result<CommandResult> {
+        // A comma or line break would shift the remaining fields
+        if self.actor.contains([',', '\n', '\r']) {
+            return Err(EditorError::command_failed(format!(
+                "Actor name cannot contain commas or line breaks: {:?}",
+                self.actor
+            )));
+        }
+
+        let fields = event_fields(document)?;
+        let event = fields.get(self.event_index).ok_or_else(|| {
+            EditorError::command_failed(format!("Event index {} not found", self.event_index))
+        })?;
+        let range = event
+            .name
+            .ok_or_else(|| EditorError::command_failed("Events format has no Name field"))?;
+
+        document.replace_raw(range, &self.actor)?;
+
+        let end = Position::new(range.start.offset + self.actor.len());
+        Ok(CommandResult::success_with_change(
+            Range::new(range.start, end),
+            end,
+        ))
+    }
+
+    fn description(&self) -> &str {
+        "Assign actor"
+    }
+
+    fn memory_usage(&self) -> usize {
+        core::mem::size_of::<Self>() + self.actor.len()
+    }
+}
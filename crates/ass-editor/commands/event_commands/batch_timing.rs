@@ -0,0 +1,248 @@
+//! Commands shifting or scaling the timing of many events at once.
+//!
+//! Provides [`BatchTimingShiftCommand`], which moves events by a fixed
+//! offset given in centiseconds or video frames, and
+//! [`BatchTimingScaleCommand`], which stretches all times around a pivot to
+//! follow a change in frame rate. Both rewrite only the time fields through
+//! a [`BatchCommand`], so each is a single undo step.
+
+use crate::commands::{BatchCommand, CommandResult, EditorCommand};
+use crate::core::{EditorDocument, EditorError, Result};
+use crate::utils::timeline::{TimelineEvent, TimelineModel};
+
+#[cfg(not(feature = "std"))]
+use alloc::{format, string::String, string::ToString, vec::Vec};
+
+/// Command adding a fixed offset to event start and end times
+///
+/// Times that would become negative are clamped to zero, and an end never
+/// precedes its start, so events keep their order. Indices count every
+/// event of the script in document order; `None` shifts all of them.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct BatchTimingShiftCommand {
+    /// Offset in centiseconds, negative to move events earlier
+    pub offset_cs: i64,
+    /// Events to shift, `None` for all events
+    pub event_indices: Option<Vec<usize>>,
+    /// Custom undo history description
+    pub description: Option<String>,
+}
+
+impl BatchTimingShiftCommand {
+    /// Create a command shifting every event by `offset_cs`
+    pub fn new(offset_cs: i64) -> Self {
+        Self {
+            offset_cs,
+            event_indices: None,
+            description: None,
+        }
+    }
+
+    /// Create a command shifting every event by `frames` frames at `fps`
+    ///
+    /// The offset is rounded to the nearest centisecond. Returns an error
+    /// for a frame rate that is not positive and finite.
+    pub fn from_frame_offset(frames: i64, fps: f64) -> Result<Self> {
+        if !fps.is_finite() || fps <= 0.0 {
+            return Err(EditorError::command_failed(format!(
+                "Invalid frame rate {fps}"
+            )));
+        }
+        Ok(Self::new((frames as f64 * 100.0 / fps).round() as i64))
+    }
+
+    /// Restrict the shift to the events at `event_indices`
+    #[must_use]
+    pub fn with_events(mut self, event_indices: Vec<usize>) -> Self {
+        self.event_indices = Some(event_indices);
+        self
+    }
+
+    /// Set a custom description for this command
+    #[must_use]
+    pub fn with_description(mut self, description: String) -> Self {
+        self.description = Some(description);
+        self
+    }
+
+    /// Build the batch rewriting the times of every selected event
+    ///
+    /// Returns an error when an index is past the last event.
+    pub fn to_batch(&self, document: &EditorDocument) -> Result<BatchCommand> {
+        self.plan(document).map(|(batch, _)| batch)
+    }
+
+    /// The batch along with the indices of events clamped to start at zero
+    fn plan(&self, document: &EditorDocument) -> Result<(BatchCommand, Vec<usize>)> {
+        let timeline = TimelineModel::new(document)?;
+        let selected = selected_slots(&timeline, self.event_indices.as_deref())?;
+
+        let shift = |cs: u32| (i64::from(cs) + self.offset_cs).clamp(0, i64::from(u32::MAX)) as u32;
+        let mut clamped = Vec::new();
+        let times = selected
+            .into_iter()
+            .map(|(slot, event)| {
+                if i64::from(event.start_cs) + self.offset_cs < 0 {
+                    clamped.push(event.index);
+                }
+                let start = shift(event.start_cs);
+                (slot, start, shift(event.end_cs).max(start))
+            })
+            .collect();
+
+        Ok((
+            timeline.retime_many(times, self.description().to_string()),
+            clamped,
+        ))
+    }
+}
+
+impl EditorCommand for BatchTimingShiftCommand {
+    fn execute(&self, document: &mut EditorDocument) -> Result<CommandResult> {
+        let (batch, clamped) = self.plan(document)?;
+        if batch.commands.is_empty() {
+            return Ok(CommandResult::success().with_message("No events were shifted".to_string()));
+        }
+
+        let result = batch.execute(document)?;
+        if !result.success {
+            return Ok(result);
+        }
+        let message = if clamped.is_empty() {
+            format!("Shifted events by {} cs", self.offset_cs)
+        } else {
+            format!(
+                "Shifted events by {} cs; {} event(s) clamped to start at zero",
+                self.offset_cs,
+                clamped.len()
+            )
+        };
+        Ok(result.with_message(message))
+    }
+
+    fn description(&self) -> &str {
+        self.description.as_deref().unwrap_or("Shift event timing")
+    }
+
+    fn memory_usage(&self) -> usize {
+        core::mem::size_of::<Self>()
+            + self
+                .event_indices
+                .as_ref()
+                .map_or(0, |indices| indices.len() * core::mem::size_of::<usize>())
+            + self.description.as_ref().map_or(0, |d| d.len())
+    }
+}
+
+/// Command scaling every event time around a pivot
+///
+/// Each time becomes `pivot_cs + (time - pivot_cs) * factor`, rounded to the
+/// nearest centisecond and clamped to zero. To follow a video re-encoded
+/// from `old_fps` to `new_fps` frames per second, use a factor of
+/// `old_fps / new_fps`.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct BatchTimingScaleCommand {
+    /// Scale factor, must be positive
+    pub factor: f64,
+    /// Time in centiseconds that stays in place
+    pub pivot_cs: u32,
+    /// Custom undo history description
+    pub description: Option<String>,
+}
+
+impl BatchTimingScaleCommand {
+    /// Create a command scaling all times by `factor` around `pivot_cs`
+    pub fn new(factor: f64, pivot_cs: u32) -> Self {
+        Self {
+            factor,
+            pivot_cs,
+            description: None,
+        }
+    }
+
+    /// Set a custom description for this command
+    #[must_use]
+    pub fn with_description(mut self, description: String) -> Self {
+        self.description = Some(description);
+        self
+    }
+
+    /// Build the batch rewriting the times of every event
+    ///
+    /// Returns an error when the factor is not positive and finite, as it
+    /// would reverse or collapse the order of events.
+    pub fn to_batch(&self, document: &EditorDocument) -> Result<BatchCommand> {
+        if !self.factor.is_finite() || self.factor <= 0.0 {
+            return Err(EditorError::command_failed(format!(
+                "Invalid scale factor {}",
+                self.factor
+            )));
+        }
+        let timeline = TimelineModel::new(document)?;
+        let pivot = f64::from(self.pivot_cs);
+        let scale = |cs: u32| {
+            (pivot + (f64::from(cs) - pivot) * self.factor)
+                .round()
+                .clamp(0.0, f64::from(u32::MAX)) as u32
+        };
+        let times = timeline
+            .events()
+            .iter()
+            .enumerate()
+            .map(|(slot, event)| {
+                let start = scale(event.start_cs);
+                (slot, start, scale(event.end_cs).max(start))
+            })
+            .collect();
+
+        Ok(timeline.retime_many(times, self.description().to_string()))
+    }
+}
+
+impl EditorCommand for BatchTimingScaleCommand {
+    fn execute(&self, document: &mut EditorDocument) -> Result<CommandResult> {
+        let batch = self.to_batch(document)?;
+        if batch.commands.is_empty() {
+            return Ok(CommandResult::success().with_message("No events were scaled".to_string()));
+        }
+
+        let result = batch.execute(document)?;
+        if !result.success {
+            return Ok(result);
+        }
+        Ok(result.with_message(format!(
+            "Scaled event timing by {} around {} cs",
+            self.factor, self.pivot_cs
+        )))
+    }
+
+    fn description(&self) -> &str {
+        self.description.as_deref().unwrap_or("Scale event timing")
+    }
+
+    fn memory_usage(&self) -> usize {
+        core::mem::size_of::<Self>() + self.description.as_ref().map_or(0, |d| d.len())
+    }
+}
+
+/// Timeline slots and events for `indices`, or every event for `None`
+fn selected_slots<'a>(
+    timeline: &'a TimelineModel,
+    indices: Option<&[usize]>,
+) -> Result<Vec<(usize, &'a TimelineEvent)>> {
+    let events = timeline.events().iter().enumerate();
+    let Some(indices) = indices else {
+        return Ok(events.collect());
+    };
+    if let Some(&index) = indices
+        .iter()
+        .find(|&&index| timeline.event(index).is_none())
+    {
+        return Err(EditorError::command_failed(format!(
+            "Event index {index} out of range"
+        )));
+    }
+    Ok(events
+        .filter(|(_, event)| indices.contains(&event.index))
+        .collect())
+}
@@ -0,0 +1,173 @@
+//! Command to remove repeated dialogue lines from an ASS document.
+//!
+//! Provides [`DeduplicateCommand`], which groups dialogue events with the
+//! same text using [`DuplicateDetector`] and deletes all but one event of
+//! each group as a single undoable batch.
+
+use crate::commands::{BatchCommand, CommandResult, DeleteTextCommand, EditorCommand};
+use crate::core::{EditorDocument, Position, Range, Result};
+use crate::utils::{DuplicateDetector, EventIndex};
+use ass_core::parser::{Event, Section};
+
+#[cfg(not(feature = "std"))]
+use alloc::{boxed::Box, format, string::ToString, vec::Vec};
+
+/// Which event of a duplicate group survives deduplication
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum KeepPolicy {
+    /// Keep the event that comes first in the script
+    #[default]
+    First,
+    /// Keep the event shown the longest, the earliest one on ties
+    LongestDuration,
+}
+
+/// Options for [`DeduplicateCommand`]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct DeduplicateOptions {
+    /// Which event of each group to keep
+    pub keep_policy: KeepPolicy,
+}
+
+/// Command to delete dialogue events repeating another event's text
+///
+/// Texts are compared case-insensitively with override blocks stripped, so
+/// `{\i1}Hello` and `hello` are duplicates. Comment events are never
+/// touched.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DeduplicateCommand {
+    /// Deduplication options
+    pub options: DeduplicateOptions,
+}
+
+impl DeduplicateCommand {
+    /// Create a command keeping the first event of each group
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a command with the given options
+    pub fn with_options(options: DeduplicateOptions) -> Self {
+        Self { options }
+    }
+
+    /// The detector used to group events
+    fn detector() -> DuplicateDetector {
+        DuplicateDetector::new().with_strip_overrides(true)
+    }
+
+    /// Indices of the events that would be removed, ascending
+    ///
+    /// Indices count every event of the script, Dialogue and Comment alike.
+    pub fn duplicates_to_remove(&self, document: &EditorDocument) -> Result<Vec<EventIndex>> {
+        document.parse_script_with(|script| {
+            let events = script_events(script);
+            self.removals(&events)
+                .into_iter()
+                .map(|(index, _)| index)
+                .collect()
+        })
+    }
+
+    /// Build the batch deleting every duplicate event line from `document`
+    ///
+    /// The batch holds one delete per removed event, so its length is the
+    /// number of events removed.
+    pub fn to_batch(&self, document: &EditorDocument) -> Result<BatchCommand> {
+        let content = document.text();
+        let starts = document.parse_script_with(|script| {
+            let events = script_events(script);
+            self.removals(&events)
+                .into_iter()
+                .map(|(_, start)| start)
+                .collect::<Vec<_>>()
+        })?;
+
+        // Delete from the end so earlier line ranges stay valid
+        let commands = starts
+            .into_iter()
+            .rev()
+            .map(|start| {
+                let line_start = content[..start].rfind('\n').map_or(0, |pos| pos + 1);
+                let line_end = content[start..]
+                    .find('\n')
+                    .map_or(content.len(), |pos| start + pos + 1);
+                let range = Range::new(Position::new(line_start), Position::new(line_end));
+                Box::new(DeleteTextCommand::new(range)) as Box<dyn EditorCommand>
+            })
+            .collect();
+
+        Ok(BatchCommand::new(self.description().to_string()).add_commands(commands))
+    }
+
+    /// Event indices to remove with the byte offset of each event, ascending
+    fn removals(&self, events: &[Event]) -> Vec<(EventIndex, usize)> {
+        let mut removed = Vec::new();
+        for group in Self::detector().find_duplicates(events) {
+            let keep = match self.options.keep_policy {
+                KeepPolicy::First => group.indices[0],
+                KeepPolicy::LongestDuration => group
+                    .indices
+                    .iter()
+                    .copied()
+                    .max_by_key(|&index| {
+                        let event = &events[index];
+                        let duration = event
+                            .end_time_cs()
+                            .unwrap_or(0)
+                            .saturating_sub(event.start_time_cs().unwrap_or(0));
+                        (duration, core::cmp::Reverse(index))
+                    })
+                    .unwrap_or(group.indices[0]),
+            };
+            removed.extend(
+                group
+                    .indices
+                    .into_iter()
+                    .filter(|&index| index != keep)
+                    .map(|index| (index, events[index].span.start)),
+            );
+        }
+        removed.sort_unstable();
+        removed
+    }
+}
+
+/// All events of `script`, in document order
+fn script_events<'a>(script: &ass_core::parser::Script<'a>) -> Vec<Event<'a>> {
+    script
+        .sections()
+        .iter()
+        .filter_map(|section| match section {
+            Section::Events(events) => Some(events.iter().cloned()),
+            _ => None,
+        })
+        .flatten()
+        .collect()
+}
+
+impl EditorCommand for DeduplicateCommand {
+    fn execute(&self, document: &mut EditorDocument) -> Result<CommandResult> {
+        let batch = self.to_batch(document)?;
+        let removed = batch.commands.len();
+        if removed == 0 {
+            return Ok(
+                CommandResult::success().with_message("No duplicate events found".to_string())
+            );
+        }
+
+        let result = batch.execute(document)?;
+        if !result.success {
+            return Ok(result);
+        }
+        Ok(result.with_message(format!("Removed {removed} duplicate events")))
+    }
+
+    fn description(&self) -> &str {
+        "Remove duplicate events"
+    }
+
+    fn memory_usage(&self) -> usize {
+        core::mem::size_of::<Self>()
+    }
+}
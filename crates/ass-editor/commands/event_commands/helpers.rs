@@ -1,13 +1,20 @@
 //! Shared parsing helpers for event management commands.
 //!
 //! Provides robust ASS event line parsing with proper comma handling for the
-//! `Effect` and `Text` fields, used by the sibling command submodules.
+//! `Effect` and `Text` fields, and locates the fields of event lines in a
+//! document so they can be rewritten in place, used by the sibling command
+//! submodules.
 
-use crate::core::EditorError;
+use crate::core::{EditorDocument, EditorError, Position, Range, Result};
 use ass_core::parser::ast::{Event, EventType, Span};
+use ass_core::parser::Section;
+use ass_core::utils::format_ass_time;
 
 #[cfg(not(feature = "std"))]
-use alloc::vec::Vec;
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
 
 /// Helper function to parse an ASS event line with proper comma handling
 /// Returns parsed Event struct or error if parsing fails
@@ -97,3 +104,93 @@ pub(super) fn parse_event_line(line: &str) -> core::result::Result<Event<'_>, Ed
         span: Span::new(0, line.len(), 1, 1), // Dummy span
     })
 }
+
+/// Document location of an event line and its time and text fields
+#[derive(Debug, Clone)]
+pub(super) struct EventFields {
+    /// The whole line, without its line ending
+    pub line: Range,
+    /// The `Start` field
+    pub start: Range,
+    /// The `End` field
+    pub end: Range,
+    /// The `Text` field
+    pub text: Range,
+    /// The `Name` field, `None` when the `Format` line has no `Name`
+    pub name: Option<Range>,
+}
+
+impl EventFields {
+    /// The line of this event with new times and text
+    ///
+    /// Every other field is copied from `content` as written, whatever the
+    /// section's `Format` order.
+    pub fn rewrite(&self, content: &str, start_cs: u32, end_cs: u32, text: &str) -> String {
+        let mut fields = [
+            (self.start, format_ass_time(start_cs)),
+            (self.end, format_ass_time(end_cs)),
+            (self.text, text.to_string()),
+        ];
+        fields.sort_by_key(|(range, _)| range.start.offset);
+
+        let mut line = String::new();
+        let mut copied = self.line.start.offset;
+        for (range, value) in fields {
+            line.push_str(&content[copied..range.start.offset]);
+            line.push_str(&value);
+            copied = range.end.offset;
+        }
+        line.push_str(&content[copied..self.line.end.offset]);
+        line
+    }
+}
+
+/// Field locations of every event in `document`, in document order
+///
+/// Positions in the result match event indices, counting every event type.
+pub(super) fn event_fields(document: &EditorDocument) -> Result<Vec<EventFields>> {
+    let content = document.text();
+    document.parse_script_with(|script| {
+        let base = script.source().as_ptr() as usize;
+        let field = |value: &str| {
+            let start = value.as_ptr() as usize - base;
+            Range::new(Position::new(start), Position::new(start + value.len()))
+        };
+        // Fields missing from the `Format` line are empty strings that do
+        // not point into the source
+        let source_field = |value: &str| {
+            (base..=base + script.source().len())
+                .contains(&(value.as_ptr() as usize))
+                .then(|| field(value))
+        };
+
+        script
+            .sections()
+            .iter()
+            .filter_map(|section| match section {
+                Section::Events(events) => Some(events.iter()),
+                _ => None,
+            })
+            .flatten()
+            .map(|event| {
+                let text = field(event.text);
+                let line_start = content[..event.span.start]
+                    .rfind('\n')
+                    .map_or(0, |pos| pos + 1);
+                let mut line_end = content[text.end.offset..]
+                    .find('\n')
+                    .map_or(content.len(), |pos| text.end.offset + pos);
+                if content[..line_end].ends_with('\r') {
+                    line_end -= 1;
+                }
+                EventFields {
+                    line: Range::new(Position::new(line_start), Position::new(line_end)),
+                    start: field(event.start),
+                    end: field(event.end),
+                    text,
+                    name: source_field(event.name),
+                }
+            })
+            .collect()
+    })
+}
@@ -1,12 +1,15 @@
-//! Command to merge two consecutive events into one.
+//! Commands to merge consecutive events into one.
 
-use super::helpers::parse_event_line;
-use crate::commands::{CommandResult, EditorCommand};
+use super::helpers::{event_fields, parse_event_line};
+use crate::commands::{
+    BatchCommand, CommandResult, DeleteTextCommand, EditorCommand, ReplaceTextCommand,
+};
 use crate::core::{EditorDocument, EditorError, Position, Range, Result};
 use ass_core::parser::ast::EventType;
 
 #[cfg(not(feature = "std"))]
 use alloc::{
+    boxed::Box,
     format,
     string::{String, ToString},
     vec::Vec,
@@ -44,6 +47,12 @@ impl MergeEventsCommand {
         self.description = Some(description);
         self
     }
+
+    /// Create a command merging a run of consecutive events, joining their
+    /// texts with `separator`
+    pub fn merge_consecutive(indices: &[usize], separator: &str) -> MergeConsecutiveCommand {
+        MergeConsecutiveCommand::new(indices.to_vec(), separator.to_string())
+    }
 }
 
 impl EditorCommand for MergeEventsCommand {
@@ -170,3 +179,125 @@ impl EditorCommand for MergeEventsCommand {
             + self.description.as_ref().map_or(0, |d| d.len())
     }
 }
+
+/// Command to merge a run of consecutive events into the first of them
+///
+/// The merged event keeps every field of the first event except its end
+/// time, which becomes the latest end of the run, and its text, which joins
+/// the texts of the run with `separator`. Merging with `\N` undoes a
+/// [`SegmentationCommand`](super::SegmentationCommand) split. Only the
+/// event lines change, so lines between them are kept.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MergeConsecutiveCommand {
+    /// Ascending, consecutive indices of the events to merge
+    pub event_indices: Vec<usize>,
+    /// Text put between merged texts
+    pub separator: String,
+    /// Custom undo history description
+    pub description: Option<String>,
+}
+
+impl MergeConsecutiveCommand {
+    /// Create a command merging the events at `event_indices`
+    pub fn new(event_indices: Vec<usize>, separator: String) -> Self {
+        Self {
+            event_indices,
+            separator,
+            description: None,
+        }
+    }
+
+    /// Set a custom description for this command
+    #[must_use]
+    pub fn with_description(mut self, description: String) -> Self {
+        self.description = Some(description);
+        self
+    }
+
+    /// Build the batch rewriting the first event and deleting the others
+    ///
+    /// Returns an error unless there are at least two indices, each one
+    /// more than the last, and all name events of the document.
+    pub fn to_batch(&self, document: &EditorDocument) -> Result<BatchCommand> {
+        let indices = &self.event_indices;
+        if indices.len() < 2 || indices.windows(2).any(|pair| pair[1] != pair[0] + 1) {
+            return Err(EditorError::command_failed(
+                "Merging requires at least two consecutive event indices",
+            ));
+        }
+
+        let content = document.text();
+        let fields = event_fields(document)?;
+        let run = fields
+            .get(indices[0]..=indices[indices.len() - 1])
+            .ok_or_else(|| {
+                EditorError::command_failed(format!(
+                    "Event index {} out of range",
+                    indices[indices.len() - 1]
+                ))
+            })?;
+
+        let time = |range: Range| {
+            let field = &content[range.start.offset..range.end.offset];
+            ass_core::utils::parse_ass_time(field)
+                .map_err(|_| EditorError::command_failed(format!("Invalid event time '{field}'")))
+        };
+        let start = time(run[0].start)?;
+        let mut end = 0;
+        for event in run {
+            end = end.max(time(event.end)?);
+        }
+        let text = run
+            .iter()
+            .map(|event| &content[event.text.start.offset..event.text.end.offset])
+            .collect::<Vec<_>>()
+            .join(&self.separator);
+
+        let mut commands: Vec<Box<dyn EditorCommand>> = run[1..]
+            .iter()
+            .rev()
+            .map(|event| {
+                let line_end = content[event.line.end.offset..]
+                    .find('\n')
+                    .map_or(event.line.end.offset, |pos| event.line.end.offset + pos + 1);
+                Box::new(DeleteTextCommand::new(Range::new(
+                    event.line.start,
+                    Position::new(line_end),
+                ))) as Box<dyn EditorCommand>
+            })
+            .collect();
+        commands.push(Box::new(ReplaceTextCommand::new(
+            run[0].line,
+            run[0].rewrite(&content, start, end.max(start), &text),
+        )));
+
+        Ok(BatchCommand::new(self.description().to_string()).add_commands(commands))
+    }
+}
+
+impl EditorCommand for MergeConsecutiveCommand {
+    fn execute(&self, document: &mut EditorDocument) -> Result<CommandResult> {
+        let result = self.to_batch(document)?.execute(document)?;
+        if !result.success {
+            return Ok(result);
+        }
+        Ok(result.with_message(format!(
+            "Merged {} events into event {}",
+            self.event_indices.len(),
+            self.event_indices[0]
+        )))
+    }
+
+    fn description(&self) -> &str {
+        self.description
+            .as_deref()
+            .unwrap_or("Merge consecutive events")
+    }
+
+    fn memory_usage(&self) -> usize {
+        core::mem::size_of::<Self>()
+            + self.event_indices.len() * core::mem::size_of::<usize>()
+            + self.separator.len()
+            + self.description.as_ref().map_or(0, |d| d.len())
+    }
+}
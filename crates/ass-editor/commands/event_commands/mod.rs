@@ -1,27 +1,39 @@
 //! Event management commands for ASS documents
 //!
-//! Provides commands for splitting, merging, timing adjustments, toggling event types,
-//! and effect modifications with proper validation and delta tracking.
+//! Provides commands for assigning actors, splitting (including at line
+//! breaks), merging, deduplicating, timing adjustments, batch timing shifts
+//! and scales, toggling event types and comments, and effect modifications
+//! with proper validation and delta tracking.
 
+mod actor;
 mod batch_delete;
+mod batch_timing;
+mod deduplicate;
 mod delete;
 mod effect;
 mod effect_exec;
 mod helpers;
 mod merge;
+mod segment;
 mod split;
 mod timing;
 mod toggle;
+mod toggle_comment;
 
 #[cfg(test)]
 mod effect_tests;
 #[cfg(test)]
 mod tests;
 
+pub use actor::AssignActorCommand;
 pub use batch_delete::BatchDeleteEventsCommand;
+pub use batch_timing::{BatchTimingScaleCommand, BatchTimingShiftCommand};
+pub use deduplicate::{DeduplicateCommand, DeduplicateOptions, KeepPolicy};
 pub use delete::DeleteEventCommand;
 pub use effect::{EffectOperation, EventEffectCommand};
-pub use merge::MergeEventsCommand;
+pub use merge::{MergeConsecutiveCommand, MergeEventsCommand};
+pub use segment::{SegmentationCommand, SegmentationOptions};
 pub use split::SplitEventCommand;
 pub use timing::TimingAdjustCommand;
 pub use toggle::ToggleEventTypeCommand;
+pub use toggle_comment::ToggleCommentCommand;
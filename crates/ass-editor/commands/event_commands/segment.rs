@@ -0,0 +1,210 @@
+//! Command to split events at their embedded line breaks.
+//!
+//! Provides [`SegmentationCommand`], which turns every event whose text
+//! contains `\N` or `\n` into one event per line, dividing the original
+//! time span between them, and [`SegmentationOptions`] to tune the split.
+//! All events are rewritten through one [`BatchCommand`], so the split is a
+//! single undo step.
+
+use super::helpers::{event_fields, EventFields};
+use crate::commands::{BatchCommand, CommandResult, EditorCommand, ReplaceTextCommand};
+use crate::core::{EditorDocument, Result};
+use crate::utils::width::strip_overrides;
+use ass_core::utils::parse_ass_time;
+
+#[cfg(not(feature = "std"))]
+use alloc::{
+    boxed::Box,
+    format,
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+
+/// Options for [`SegmentationCommand`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SegmentationOptions {
+    /// Give every segment the same duration, instead of a share
+    /// proportional to its visible character count
+    pub even_split: bool,
+    /// Leave an event unsplit when a segment would be shorter than this
+    pub min_duration_cs: u32,
+    /// Repeat the override blocks leading the first segment at the start of
+    /// every other segment
+    pub preserve_overrides: bool,
+}
+
+impl Default for SegmentationOptions {
+    fn default() -> Self {
+        Self {
+            even_split: true,
+            min_duration_cs: 0,
+            preserve_overrides: false,
+        }
+    }
+}
+
+/// Command to split every multi-line event into one event per line
+///
+/// Empty lines are dropped, and each new event keeps every field of the
+/// original except its times and text. Events whose times cannot be parsed
+/// are left alone.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SegmentationCommand {
+    /// How segments are timed and formatted
+    pub options: SegmentationOptions,
+    /// Custom undo history description
+    pub description: Option<String>,
+}
+
+impl SegmentationCommand {
+    /// Create a segmentation command with `options`
+    pub fn new(options: SegmentationOptions) -> Self {
+        Self {
+            options,
+            description: None,
+        }
+    }
+
+    /// Build the batch splitting every multi-line event of `document`
+    pub fn split_at_line_breaks(
+        document: &EditorDocument,
+        options: SegmentationOptions,
+    ) -> Result<BatchCommand> {
+        Self::new(options).to_batch(document)
+    }
+
+    /// Set a custom description for this command
+    #[must_use]
+    pub fn with_description(mut self, description: String) -> Self {
+        self.description = Some(description);
+        self
+    }
+
+    /// Build the batch replacing each multi-line event with its segments
+    pub fn to_batch(&self, document: &EditorDocument) -> Result<BatchCommand> {
+        let content = document.text();
+        let commands = event_fields(document)?
+            .iter()
+            .rev()
+            .filter_map(|fields| {
+                let lines = self.segment(&content, fields)?;
+                Some(
+                    Box::new(ReplaceTextCommand::new(fields.line, lines.join("\n")))
+                        as Box<dyn EditorCommand>,
+                )
+            })
+            .collect();
+
+        Ok(BatchCommand::new(self.description().to_string()).add_commands(commands))
+    }
+
+    /// Replacement lines for the event at `fields`, `None` to keep it
+    fn segment(&self, content: &str, fields: &EventFields) -> Option<Vec<String>> {
+        let text = &content[fields.text.start.offset..fields.text.end.offset];
+        let segments: Vec<&str> = text
+            .split("\\N")
+            .flat_map(|part| part.split("\\n"))
+            .filter(|segment| !segment.is_empty())
+            .collect();
+        if segments.len() < 2 {
+            return None;
+        }
+
+        let start =
+            parse_ass_time(&content[fields.start.start.offset..fields.start.end.offset]).ok()?;
+        let end = parse_ass_time(&content[fields.end.start.offset..fields.end.end.offset]).ok()?;
+        let weights: Vec<u64> = if self.options.even_split {
+            vec![1; segments.len()]
+        } else {
+            segments
+                .iter()
+                .map(|segment| strip_overrides(segment).chars().count().max(1) as u64)
+                .collect()
+        };
+        let bounds = split_span(start, end.max(start), &weights);
+        if bounds
+            .windows(2)
+            .any(|pair| pair[1] - pair[0] < self.options.min_duration_cs)
+        {
+            return None;
+        }
+
+        let prefix = if self.options.preserve_overrides {
+            leading_overrides(segments[0])
+        } else {
+            ""
+        };
+        Some(
+            segments
+                .iter()
+                .zip(bounds.windows(2))
+                .enumerate()
+                .map(|(i, (segment, span))| {
+                    let text = if i == 0 || segment.starts_with(prefix) {
+                        (*segment).to_string()
+                    } else {
+                        format!("{prefix}{segment}")
+                    };
+                    fields.rewrite(content, span[0], span[1], &text)
+                })
+                .collect(),
+        )
+    }
+}
+
+impl EditorCommand for SegmentationCommand {
+    fn execute(&self, document: &mut EditorDocument) -> Result<CommandResult> {
+        let batch = self.to_batch(document)?;
+        let split = batch.commands.len();
+        if split == 0 {
+            return Ok(CommandResult::success().with_message("No events were split".to_string()));
+        }
+
+        let result = batch.execute(document)?;
+        if !result.success {
+            return Ok(result);
+        }
+        Ok(result.with_message(format!("Split {split} events at line breaks")))
+    }
+
+    fn description(&self) -> &str {
+        self.description
+            .as_deref()
+            .unwrap_or("Split events at line breaks")
+    }
+
+    fn memory_usage(&self) -> usize {
+        core::mem::size_of::<Self>() + self.description.as_ref().map_or(0, |d| d.len())
+    }
+}
+
+/// Boundaries dividing `start..end` into parts proportional to `weights`
+///
+/// Returns one more boundary than there are weights, the first being
+/// `start` and the last `end`.
+fn split_span(start: u32, end: u32, weights: &[u64]) -> Vec<u32> {
+    let total: u64 = weights.iter().sum();
+    let duration = u64::from(end - start);
+    let mut bounds = Vec::with_capacity(weights.len() + 1);
+    let mut cumulative = 0;
+    bounds.push(start);
+    for weight in weights {
+        cumulative += weight;
+        let offset = (duration * cumulative + total / 2) / total;
+        bounds.push(start + u32::try_from(offset).unwrap_or(u32::MAX - start));
+    }
+    bounds
+}
+
+/// The override blocks at the very start of `segment`
+fn leading_overrides(segment: &str) -> &str {
+    let mut end = 0;
+    while segment[end..].starts_with('{') {
+        match segment[end..].find('}') {
+            Some(close) => end += close + 1,
+            None => break,
+        }
+    }
+    &segment[..end]
+}
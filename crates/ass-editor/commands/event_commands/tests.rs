@@ -1,10 +1,10 @@
-//! Tests for split, merge, timing, and toggle event commands.
+//! Tests for split, merge, deduplicate, timing, toggle, and comment event commands.
 
 use super::*;
 use crate::commands::EditorCommand;
 use crate::core::EditorDocument;
 #[cfg(not(feature = "std"))]
-use alloc::string::ToString;
+use alloc::string::{String, ToString};
 #[cfg(not(feature = "std"))]
 use alloc::{vec, vec::Vec};
 const TEST_CONTENT: &str = r#"[Script Info]
@@ -109,6 +109,43 @@ fn test_toggle_event_type_command() {
     assert!(event_lines[2].starts_with("Comment:")); // Unchanged
 }
 
+#[test]
+fn test_toggle_comment_command_is_one_undo_step() {
+    let mut doc = EditorDocument::from_content(TEST_CONTENT).unwrap();
+
+    let command = ToggleCommentCommand::new(vec![0, 2]);
+    let result = doc.execute_command(&command).unwrap();
+    assert!(result.success);
+    assert_eq!(
+        result.message.as_deref(),
+        Some("Toggled comment on 2 events")
+    );
+
+    let dialogue: Vec<String> = doc
+        .parse_script_with(|script| {
+            script
+                .dialogue_events()
+                .map(|event| event.text.to_string())
+                .collect()
+        })
+        .unwrap();
+    assert_eq!(dialogue, ["Second event", "Third event"]);
+    assert!(doc.text().contains("Comment: 0,0:00:01.00"));
+
+    doc.undo().unwrap();
+    assert_eq!(doc.text(), TEST_CONTENT);
+}
+
+#[test]
+fn test_toggle_comment_rejects_out_of_range_index() {
+    let mut doc = EditorDocument::from_content(TEST_CONTENT).unwrap();
+
+    assert!(ToggleCommentCommand::new(vec![3])
+        .execute(&mut doc)
+        .is_err());
+    assert_eq!(doc.text(), TEST_CONTENT);
+}
+
 #[test]
 fn test_split_event_invalid_time() {
     let mut doc = EditorDocument::from_content(TEST_CONTENT).unwrap();
@@ -145,3 +182,298 @@ fn test_timing_adjust_with_specific_events() {
     assert!(doc.text().contains("0:00:02.00,0:00:06.00")); // First event adjusted
     assert!(doc.text().contains("0:00:05.00,0:00:10.00")); // Second event unchanged
 }
+
+const DUPLICATES_CONTENT: &str = r#"[Script Info]
+Title: Deduplicate Test
+
+[V4+ Styles]
+Format: Name, Fontname, Fontsize, PrimaryColour, SecondaryColour, OutlineColour, BackColour, Bold, Italic, Underline, StrikeOut, ScaleX, ScaleY, Spacing, Angle, BorderStyle, Outline, Shadow, Alignment, MarginL, MarginR, MarginV, Encoding
+Style: Default,Arial,20,&H00FFFFFF,&H000000FF,&H00000000,&H00000000,0,0,0,0,100,100,0,0,1,2,0,2,10,10,10,1
+
+[Events]
+Format: Layer, Start, End, Style, Name, MarginL, MarginR, MarginV, Effect, Text
+Dialogue: 0,0:00:01.00,0:00:02.00,Default,,0,0,0,,Where are you going?
+Dialogue: 0,0:00:03.00,0:00:04.00,Default,,0,0,0,,Nowhere.
+Dialogue: 0,0:00:05.00,0:00:09.00,Default,,0,0,0,,{\i1}where are YOU going?{\i0}
+Comment: 0,0:00:10.00,0:00:11.00,Default,,0,0,0,,Nowhere.
+Dialogue: 0,0:00:12.00,0:00:15.00,Default,,0,0,0,,nowhere.
+Dialogue: 0,0:00:16.00,0:00:17.00,Default,,0,0,0,,Where are you going?
+Dialogue: 0,0:00:18.00,0:00:19.00,Default,,0,0,0,,Nowhere...
+"#;
+
+#[test]
+fn test_deduplicate_keeps_first_occurrence() {
+    let mut doc = EditorDocument::from_content(DUPLICATES_CONTENT).unwrap();
+
+    let command = DeduplicateCommand::new();
+    assert_eq!(command.duplicates_to_remove(&doc).unwrap(), vec![2, 4, 5]);
+
+    let result = doc.execute_command(&command).unwrap();
+    assert!(result.success);
+    assert_eq!(
+        result.message.as_deref(),
+        Some("Removed 3 duplicate events")
+    );
+
+    let text = doc.text();
+    assert_eq!(text.matches("Dialogue:").count(), 3);
+    assert!(text.contains("0:00:01.00,0:00:02.00,Default,,0,0,0,,Where are you going?"));
+    assert!(text.contains("Comment: 0,0:00:10.00"));
+    assert!(text.contains("Nowhere..."));
+    doc.validate().unwrap();
+
+    // The whole deduplication is one undo step
+    doc.undo().unwrap();
+    assert_eq!(doc.text(), DUPLICATES_CONTENT);
+}
+
+#[test]
+fn test_deduplicate_keeps_longest_duration() {
+    let mut doc = EditorDocument::from_content(DUPLICATES_CONTENT).unwrap();
+
+    let command = DeduplicateCommand::with_options(DeduplicateOptions {
+        keep_policy: KeepPolicy::LongestDuration,
+    });
+    assert_eq!(command.duplicates_to_remove(&doc).unwrap(), vec![0, 1, 5]);
+
+    command.execute(&mut doc).unwrap();
+    let text = doc.text();
+    assert!(text.contains("0:00:05.00,0:00:09.00,Default,,0,0,0,,{\\i1}where are YOU going?"));
+    assert!(text.contains("0:00:12.00,0:00:15.00,Default,,0,0,0,,nowhere."));
+    assert!(!text.contains("Dialogue: 0,0:00:03.00"));
+
+    // Nothing left to remove
+    let again = DeduplicateCommand::new().execute(&mut doc).unwrap();
+    assert!(!again.content_changed);
+}
+
+/// Start and end times of every event line in `doc`, in centiseconds
+fn event_times(doc: &EditorDocument) -> Vec<(u32, u32)> {
+    doc.parse_script_with(|script| {
+        script
+            .sections()
+            .iter()
+            .filter_map(|section| match section {
+                ass_core::parser::Section::Events(events) => Some(events.iter()),
+                _ => None,
+            })
+            .flatten()
+            .map(|event| (event.start_time_cs().unwrap(), event.end_time_cs().unwrap()))
+            .collect()
+    })
+    .unwrap()
+}
+
+#[test]
+fn test_batch_timing_shift_by_frames() {
+    for (fps, frames, expected_cs) in [(23.976, 24, 100), (25.0, 12, 48), (29.97, -15, -50)] {
+        let command = BatchTimingShiftCommand::from_frame_offset(frames, fps).unwrap();
+        assert_eq!(command.offset_cs, expected_cs);
+
+        let mut doc = EditorDocument::from_content(TEST_CONTENT).unwrap();
+        doc.execute_command(&command).unwrap();
+        let shift = |cs: u32| (i64::from(cs) + expected_cs) as u32;
+        assert_eq!(
+            event_times(&doc),
+            [
+                (shift(100), shift(500)),
+                (shift(500), shift(1000)),
+                (shift(1000), shift(1500))
+            ]
+        );
+
+        doc.undo().unwrap();
+        assert_eq!(doc.text(), TEST_CONTENT);
+    }
+    assert!(BatchTimingShiftCommand::from_frame_offset(1, 0.0).is_err());
+}
+
+#[test]
+fn test_batch_timing_shift_clamps_at_zero() {
+    let mut doc = EditorDocument::from_content(TEST_CONTENT).unwrap();
+
+    let command = BatchTimingShiftCommand::new(-600).with_events(vec![0, 1]);
+    let result = doc.execute_command(&command).unwrap();
+    assert_eq!(
+        result.message.as_deref(),
+        Some("Shifted events by -600 cs; 2 event(s) clamped to start at zero")
+    );
+    assert_eq!(event_times(&doc), [(0, 0), (0, 400), (1000, 1500)]);
+
+    assert!(BatchTimingShiftCommand::new(10)
+        .with_events(vec![3])
+        .execute(&mut doc)
+        .is_err());
+}
+
+#[test]
+fn test_batch_timing_scale_for_frame_rate_change() {
+    // Timed against 25 fps video, now playing at 23.976 fps
+    for (old_fps, new_fps) in [(25.0, 23.976), (23.976, 25.0), (30.0, 29.97)] {
+        let mut doc = EditorDocument::from_content(TEST_CONTENT).unwrap();
+        let factor: f64 = old_fps / new_fps;
+        doc.execute_command(&BatchTimingScaleCommand::new(factor, 100))
+            .unwrap();
+
+        let times = event_times(&doc);
+        let scale = |cs: u32| (100.0 + (f64::from(cs) - 100.0) * factor).round() as u32;
+        assert_eq!(times[0].0, 100);
+        assert_eq!(times[1], (scale(500), scale(1000)));
+        assert_eq!(times[2].1, scale(1500));
+        assert!(times.windows(2).all(|pair| pair[0].0 <= pair[1].0));
+        assert!(times.iter().all(|(start, end)| start <= end));
+
+        doc.undo().unwrap();
+        assert_eq!(doc.text(), TEST_CONTENT);
+    }
+
+    let mut doc = EditorDocument::from_content(TEST_CONTENT).unwrap();
+    doc.execute_command(&BatchTimingScaleCommand::new(0.5, 1500))
+        .unwrap();
+    assert_eq!(event_times(&doc), [(800, 1000), (1000, 1250), (1250, 1500)]);
+    assert!(BatchTimingScaleCommand::new(-1.0, 0)
+        .execute(&mut doc)
+        .is_err());
+}
+
+/// Texts of every event in `doc`, in document order
+fn event_texts(doc: &EditorDocument) -> Vec<String> {
+    doc.parse_script_with(|script| {
+        script
+            .sections()
+            .iter()
+            .filter_map(|section| match section {
+                ass_core::parser::Section::Events(events) => Some(events.iter()),
+                _ => None,
+            })
+            .flatten()
+            .map(|event| event.text.to_string())
+            .collect()
+    })
+    .unwrap()
+}
+
+#[test]
+fn test_split_at_line_breaks_and_merge_back() {
+    let content = TEST_CONTENT.replace("First event", r"{\an8}One\NTwo\NThree");
+    let mut doc = EditorDocument::from_content(&content).unwrap();
+
+    let batch =
+        SegmentationCommand::split_at_line_breaks(&doc, SegmentationOptions::default()).unwrap();
+    doc.execute_command(&batch).unwrap();
+    assert_eq!(
+        event_texts(&doc)[..3],
+        [
+            r"{\an8}One".to_string(),
+            "Two".to_string(),
+            "Three".to_string()
+        ]
+    );
+    assert_eq!(
+        event_times(&doc),
+        [
+            (100, 233),
+            (233, 367),
+            (367, 500),
+            (500, 1000),
+            (1000, 1500)
+        ]
+    );
+    assert!(doc
+        .text()
+        .contains("Dialogue: 0,0:00:02.33,0:00:03.67,Default,Speaker,0,0,0,,Two\n"));
+
+    doc.execute_command(&MergeEventsCommand::merge_consecutive(&[0, 1, 2], r"\N"))
+        .unwrap();
+    assert_eq!(doc.text(), content);
+
+    // Split and merge are each a single undo step
+    doc.undo().unwrap();
+    assert_eq!(event_times(&doc).len(), 5);
+    doc.undo().unwrap();
+    assert_eq!(doc.text(), content);
+}
+
+#[test]
+fn test_split_at_line_breaks_options() {
+    let content = TEST_CONTENT
+        .replace("First event", r"{\i1}Hi\NHello there")
+        .replace("Second event", r"A\nB\N\NC");
+    let mut doc = EditorDocument::from_content(&content).unwrap();
+
+    // Durations follow visible character counts, empty lines are dropped
+    let options = SegmentationOptions {
+        even_split: false,
+        preserve_overrides: true,
+        ..SegmentationOptions::default()
+    };
+    let result = SegmentationCommand::new(options).execute(&mut doc).unwrap();
+    assert_eq!(
+        result.message.as_deref(),
+        Some("Split 2 events at line breaks")
+    );
+    assert_eq!(
+        event_texts(&doc),
+        [
+            r"{\i1}Hi",
+            r"{\i1}Hello there",
+            "A",
+            "B",
+            "C",
+            "Third event"
+        ]
+    );
+    assert_eq!(
+        event_times(&doc),
+        [
+            (100, 162),
+            (162, 500),
+            (500, 667),
+            (667, 833),
+            (833, 1000),
+            (1000, 1500)
+        ]
+    );
+
+    // Segments shorter than the minimum leave the event whole
+    let mut doc = EditorDocument::from_content(&content).unwrap();
+    let options = SegmentationOptions {
+        min_duration_cs: 200,
+        ..SegmentationOptions::default()
+    };
+    SegmentationCommand::new(options).execute(&mut doc).unwrap();
+    assert_eq!(
+        event_texts(&doc)[..3],
+        [r"{\i1}Hi", "Hello there", r"A\nB\N\NC"]
+    );
+
+    let mut doc = EditorDocument::from_content(TEST_CONTENT).unwrap();
+    let result = SegmentationCommand::default().execute(&mut doc).unwrap();
+    assert_eq!(result.message.as_deref(), Some("No events were split"));
+    assert_eq!(doc.text(), TEST_CONTENT);
+}
+
+#[test]
+fn test_merge_consecutive_events() {
+    let mut doc = EditorDocument::from_content(TEST_CONTENT).unwrap();
+
+    for indices in [&[0, 2][..], &[1], &[], &[1, 0], &[2, 3]] {
+        assert!(MergeEventsCommand::merge_consecutive(indices, " ")
+            .execute(&mut doc)
+            .is_err());
+    }
+    assert_eq!(doc.text(), TEST_CONTENT);
+
+    let result = MergeEventsCommand::merge_consecutive(&[1, 2], " / ")
+        .execute(&mut doc)
+        .unwrap();
+    assert_eq!(
+        result.message.as_deref(),
+        Some("Merged 2 events into event 1")
+    );
+    assert_eq!(
+        event_texts(&doc),
+        ["First event", "Second event / Third event"]
+    );
+    assert_eq!(event_times(&doc), [(100, 500), (500, 1500)]);
+}
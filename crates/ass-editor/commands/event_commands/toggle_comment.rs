@@ -0,0 +1,119 @@
+//! Command to comment out or restore events as one undoable step.
+//!
+//! Provides [`ToggleCommentCommand`], which rewrites the `Dialogue:` or
+//! `Comment:` keyword of each selected event through a [`BatchCommand`], so
+//! the whole toggle is a single undo entry.
+
+use crate::commands::{BatchCommand, CommandResult, EditorCommand, ReplaceTextCommand};
+use crate::core::{EditorDocument, EditorError, Position, Range, Result};
+use ass_core::parser::{ast::EventType, Section};
+
+#[cfg(not(feature = "std"))]
+use alloc::{boxed::Box, format, string::String, string::ToString, vec::Vec};
+
+/// Command to switch events between `Dialogue` and `Comment`
+///
+/// Indices count every event of the script in document order. Events of
+/// other types (`Picture`, `Sound`, ...) are left unchanged.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ToggleCommentCommand {
+    /// Indices of the events to toggle
+    pub event_indices: Vec<usize>,
+    /// Custom undo history description
+    pub description: Option<String>,
+}
+
+impl ToggleCommentCommand {
+    /// Create a command toggling the events at `event_indices`
+    pub fn new(event_indices: Vec<usize>) -> Self {
+        Self {
+            event_indices,
+            description: None,
+        }
+    }
+
+    /// Set a custom description for this command
+    #[must_use]
+    pub fn with_description(mut self, description: String) -> Self {
+        self.description = Some(description);
+        self
+    }
+
+    /// Build the batch rewriting the type keyword of every selected event
+    ///
+    /// Returns an error when an index is past the last event.
+    pub fn to_batch(&self, document: &EditorDocument) -> Result<BatchCommand> {
+        let content = document.text();
+        let targets = document.parse_script_with(|script| {
+            let events: Vec<_> = script
+                .sections()
+                .iter()
+                .filter_map(|section| match section {
+                    Section::Events(events) => Some(events.iter()),
+                    _ => None,
+                })
+                .flatten()
+                .map(|event| (event.event_type, event.span.start))
+                .collect();
+            self.event_indices
+                .iter()
+                .map(|&index| events.get(index).copied().ok_or(index))
+                .collect::<core::result::Result<Vec<_>, usize>>()
+        })?;
+        let mut targets = targets.map_err(|index| {
+            EditorError::command_failed(format!("Event index {index} out of range"))
+        })?;
+        targets.sort_unstable_by_key(|&(_, start)| start);
+        targets.dedup_by_key(|&mut (_, start)| start);
+
+        // Replace from the end so earlier keyword ranges stay valid
+        let commands = targets
+            .into_iter()
+            .rev()
+            .filter_map(|(event_type, start)| {
+                let (from, to) = match event_type {
+                    EventType::Dialogue => ("Dialogue", "Comment"),
+                    EventType::Comment => ("Comment", "Dialogue"),
+                    _ => return None,
+                };
+                let line_start = content[..start].rfind('\n').map_or(0, |pos| pos + 1);
+                let line = &content[line_start..];
+                let keyword_start = line_start + line.len() - line.trim_start().len();
+                let range = Range::new(
+                    Position::new(keyword_start),
+                    Position::new(keyword_start + from.len()),
+                );
+                Some(Box::new(ReplaceTextCommand::new(range, to.to_string()))
+                    as Box<dyn EditorCommand>)
+            })
+            .collect();
+
+        Ok(BatchCommand::new(self.description().to_string()).add_commands(commands))
+    }
+}
+
+impl EditorCommand for ToggleCommentCommand {
+    fn execute(&self, document: &mut EditorDocument) -> Result<CommandResult> {
+        let batch = self.to_batch(document)?;
+        let toggled = batch.commands.len();
+        if toggled == 0 {
+            return Ok(CommandResult::success().with_message("No events were toggled".to_string()));
+        }
+
+        let result = batch.execute(document)?;
+        if !result.success {
+            return Ok(result);
+        }
+        Ok(result.with_message(format!("Toggled comment on {toggled} events")))
+    }
+
+    fn description(&self) -> &str {
+        self.description.as_deref().unwrap_or("Toggle comment")
+    }
+
+    fn memory_usage(&self) -> usize {
+        core::mem::size_of::<Self>()
+            + self.event_indices.len() * core::mem::size_of::<usize>()
+            + self.description.as_ref().map_or(0, |d| d.len())
+    }
+}
@@ -52,80 +52,88 @@ impl GenerateKaraokeCommand {
 
     /// Split text into syllables automatically
     fn split_into_syllables(&self, text: &str) -> Vec<String> {
-        if !self.auto_detect_syllables {
-            return vec![text.to_string()];
+        if self.auto_detect_syllables {
+            detect_syllables(text)
+        } else {
+            vec![text.to_string()]
         }
+    }
+}
 
-        // Simple syllable detection based on vowels and common patterns
-        let mut syllables = Vec::new();
-        let mut current_start = 0;
-        let chars: Vec<char> = text.chars().collect();
-
-        if chars.is_empty() {
-            return vec![text.to_string()];
-        }
+/// Split text into syllables at whitespace and vowel-consonant boundaries
+///
+/// Runs of two or more whitespace characters become their own syllables;
+/// a single space stays attached to the following syllable.
+pub(super) fn detect_syllables(text: &str) -> Vec<String> {
+    // Simple syllable detection based on vowels and common patterns
+    let mut syllables = Vec::new();
+    let mut current_start = 0;
+    let chars: Vec<char> = text.chars().collect();
+
+    if chars.is_empty() {
+        return vec![text.to_string()];
+    }
 
-        for (i, &ch) in chars.iter().enumerate() {
-            // Split on spaces and common syllable boundaries
-            if ch.is_whitespace() || (i > 0 && self.is_syllable_boundary(&chars, i)) {
-                if current_start < i {
-                    let syllable: String = chars[current_start..i].iter().collect();
-                    if !syllable.trim().is_empty() {
-                        syllables.push(syllable);
-                    }
+    for (i, &ch) in chars.iter().enumerate() {
+        // Split on spaces and common syllable boundaries
+        if ch.is_whitespace() || (i > 0 && is_syllable_boundary(&chars, i)) {
+            if current_start < i {
+                let syllable: String = chars[current_start..i].iter().collect();
+                if !syllable.trim().is_empty() {
+                    syllables.push(syllable);
                 }
+            }
 
-                // Handle whitespace
-                if ch.is_whitespace() {
-                    let mut end = i + 1;
-                    while end < chars.len() && chars[end].is_whitespace() {
-                        end += 1;
-                    }
-                    if end > i + 1 {
-                        let whitespace: String = chars[i..end].iter().collect();
-                        syllables.push(whitespace);
-                        current_start = end;
-                        continue;
-                    }
+            // Handle whitespace
+            if ch.is_whitespace() {
+                let mut end = i + 1;
+                while end < chars.len() && chars[end].is_whitespace() {
+                    end += 1;
+                }
+                if end > i + 1 {
+                    let whitespace: String = chars[i..end].iter().collect();
+                    syllables.push(whitespace);
+                    current_start = end;
+                    continue;
                 }
-
-                current_start = i;
             }
-        }
 
-        // Add remaining text
-        if current_start < chars.len() {
-            let remaining: String = chars[current_start..].iter().collect();
-            if !remaining.trim().is_empty() {
-                syllables.push(remaining);
-            }
+            current_start = i;
         }
+    }
 
-        // Return syllables or whole text if none found
-        if syllables.is_empty() {
-            vec![text.to_string()]
-        } else {
-            syllables
+    // Add remaining text
+    if current_start < chars.len() {
+        let remaining: String = chars[current_start..].iter().collect();
+        if !remaining.trim().is_empty() {
+            syllables.push(remaining);
         }
     }
 
-    /// Check if position is a syllable boundary
-    fn is_syllable_boundary(&self, chars: &[char], pos: usize) -> bool {
-        if pos == 0 || pos >= chars.len() {
-            return false;
-        }
+    // Return syllables or whole text if none found
+    if syllables.is_empty() {
+        vec![text.to_string()]
+    } else {
+        syllables
+    }
+}
 
-        let prev = chars[pos - 1];
-        let curr = chars[pos];
+/// Check if position is a syllable boundary
+fn is_syllable_boundary(chars: &[char], pos: usize) -> bool {
+    if pos == 0 || pos >= chars.len() {
+        return false;
+    }
 
-        // Split on vowel-consonant or consonant-vowel boundaries
-        let prev_vowel = "aeiouAEIOU".contains(prev);
-        let curr_vowel = "aeiouAEIOU".contains(curr);
+    let prev = chars[pos - 1];
+    let curr = chars[pos];
 
-        // Simple heuristic: split when transitioning from vowel to consonant
-        // or when encountering certain consonant clusters
-        prev_vowel && !curr_vowel && !curr.is_whitespace()
-    }
+    // Split on vowel-consonant or consonant-vowel boundaries
+    let prev_vowel = "aeiouAEIOU".contains(prev);
+    let curr_vowel = "aeiouAEIOU".contains(curr);
+
+    // Simple heuristic: split when transitioning from vowel to consonant
+    // or when encountering certain consonant clusters
+    prev_vowel && !curr_vowel && !curr.is_whitespace()
 }
 
 impl EditorCommand for GenerateKaraokeCommand {
@@ -0,0 +1,175 @@
+//! Karaoke tag generation from per-syllable durations.
+//!
+//! `KaraokeGenerator` turns plain text into karaoke-tagged text either from
+//! externally supplied syllable durations (e.g. from an audio aligner) or by
+//! splitting a total duration across syllables with an `AutoSplitMethod`.
+
+use super::generate::detect_syllables;
+use super::KaraokeType;
+use crate::core::{EditorError, Result};
+
+#[cfg(not(feature = "std"))]
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
+
+/// Explicit syllable separator accepted by [`KaraokeGenerator`]
+pub const SYLLABLE_SEPARATOR: char = '|';
+
+/// How [`KaraokeGenerator::auto_split`] divides a duration across syllables
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum AutoSplitMethod {
+    /// Every syllable gets the same share
+    #[default]
+    Uniform,
+    /// Shares proportional to each syllable's non-whitespace length
+    ProportionalToLength,
+    /// Like `ProportionalToLength`, but vowels count three times, since
+    /// sung vowels are held longer than consonants
+    VowelWeighted,
+}
+
+impl AutoSplitMethod {
+    /// Relative weight of `syllable` under this method
+    fn weight(self, syllable: &str) -> u64 {
+        let letters = syllable.chars().filter(|c| !c.is_whitespace());
+        match self {
+            Self::Uniform => 1,
+            Self::ProportionalToLength => letters.count() as u64,
+            Self::VowelWeighted => letters
+                .map(|c| if "aeiouAEIOU".contains(c) { 3 } else { 1 })
+                .sum(),
+        }
+    }
+}
+
+/// Builds karaoke-tagged text from syllable timing
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct KaraokeGenerator;
+
+impl KaraokeGenerator {
+    /// Split `text` into syllables
+    ///
+    /// Syllables are delimited by [`SYLLABLE_SEPARATOR`] when present and
+    /// detected automatically otherwise. Whitespace-only entries are kept so
+    /// the text round-trips, but carry no timing.
+    pub fn syllables(text: &str) -> Vec<String> {
+        if text.contains(SYLLABLE_SEPARATOR) {
+            text.split(SYLLABLE_SEPARATOR).map(str::to_string).collect()
+        } else {
+            detect_syllables(text)
+        }
+    }
+
+    /// Tag each syllable of `text` with its duration from `syllable_durations`
+    ///
+    /// Durations are in centiseconds, one per non-blank syllable as returned
+    /// by [`Self::syllables`].
+    ///
+    /// # Errors
+    ///
+    /// Fails if `text` contains override blocks, the number of durations
+    /// does not match the number of syllables, or `karaoke_type` is
+    /// [`KaraokeType::Transition`].
+    pub fn from_syllables(
+        text: &str,
+        syllable_durations: &[u32],
+        karaoke_type: KaraokeType,
+    ) -> Result<String> {
+        let syllables = Self::timed_syllables(text)?;
+        Self::tag_syllables(&syllables, syllable_durations, karaoke_type)
+    }
+
+    /// Tag the syllables of `text` so their durations sum to
+    /// `total_duration_cs`, divided according to `method`
+    ///
+    /// Rounding remainders go to the syllables with the largest fractional
+    /// shares, so the total is always exact.
+    ///
+    /// # Errors
+    ///
+    /// Fails if `text` contains override blocks or has no syllables, or
+    /// `karaoke_type` is [`KaraokeType::Transition`].
+    pub fn auto_split(
+        text: &str,
+        total_duration_cs: u32,
+        method: AutoSplitMethod,
+        karaoke_type: KaraokeType,
+    ) -> Result<String> {
+        let syllables = Self::timed_syllables(text)?;
+        let weights: Vec<u64> = syllables
+            .iter()
+            .filter(|s| !s.trim().is_empty())
+            .map(|s| method.weight(s).max(1))
+            .collect();
+        let weight_sum: u64 = weights.iter().sum();
+        let total = u64::from(total_duration_cs);
+
+        let mut durations: Vec<u32> = Vec::with_capacity(weights.len());
+        let mut remainders: Vec<(u64, usize)> = Vec::with_capacity(weights.len());
+        for (i, weight) in weights.iter().enumerate() {
+            let share = total * weight;
+            durations.push((share / weight_sum) as u32);
+            remainders.push((share % weight_sum, i));
+        }
+        let assigned: u32 = durations.iter().sum();
+        remainders.sort_by(|a, b| b.0.cmp(&a.0).then(a.1.cmp(&b.1)));
+        for &(_, i) in remainders
+            .iter()
+            .take((total_duration_cs - assigned) as usize)
+        {
+            durations[i] += 1;
+        }
+
+        Self::tag_syllables(&syllables, &durations, karaoke_type)
+    }
+
+    /// Prefix each non-blank syllable with its karaoke tag
+    fn tag_syllables(
+        syllables: &[String],
+        durations: &[u32],
+        karaoke_type: KaraokeType,
+    ) -> Result<String> {
+        if karaoke_type == KaraokeType::Transition {
+            return Err(EditorError::command_failed(
+                "\\kt sets absolute times and cannot carry syllable durations",
+            ));
+        }
+        let timed = syllables.iter().filter(|s| !s.trim().is_empty()).count();
+        if timed != durations.len() {
+            return Err(EditorError::command_failed(format!(
+                "Expected {timed} syllable durations, got {}",
+                durations.len()
+            )));
+        }
+
+        let tag = karaoke_type.tag_string();
+        let mut durations = durations.iter();
+        let mut result = String::new();
+        for syllable in syllables {
+            if !syllable.trim().is_empty() {
+                if let Some(duration) = durations.next() {
+                    result.push_str(&format!("{{\\{tag}{duration}}}"));
+                }
+            }
+            result.push_str(syllable);
+        }
+        Ok(result)
+    }
+
+    /// Syllables of plain `text`, rejecting tagged or empty input
+    fn timed_syllables(text: &str) -> Result<Vec<String>> {
+        if text.contains('{') || text.contains('}') {
+            return Err(EditorError::command_failed(
+                "Cannot generate karaoke for text containing override blocks",
+            ));
+        }
+        let syllables = Self::syllables(text);
+        if syllables.iter().all(|s| s.trim().is_empty()) {
+            return Err(EditorError::command_failed("Text has no syllables"));
+        }
+        Ok(syllables)
+    }
+}
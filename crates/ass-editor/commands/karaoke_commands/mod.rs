@@ -2,15 +2,18 @@
 //!
 //! Provides commands for generating, splitting, adjusting, and applying
 //! ASS karaoke timing tags like \k, \kf, \ko, \kt with proper syllable
-//! detection and timing validation.
+//! detection and timing validation. `KaraokeGenerator` builds tagged text
+//! from per-syllable durations and `KaraokeValidator` checks the totals.
 
 mod adjust;
 mod adjust_impl;
 mod apply;
 mod apply_impl;
 mod generate;
+mod generator;
 mod split;
 mod types;
+mod validate;
 
 #[cfg(test)]
 mod tests;
@@ -18,5 +21,7 @@ mod tests;
 pub use adjust::{AdjustKaraokeCommand, TimingAdjustment};
 pub use apply::{ApplyKaraokeCommand, KaraokeTemplate};
 pub use generate::GenerateKaraokeCommand;
+pub use generator::{AutoSplitMethod, KaraokeGenerator, SYLLABLE_SEPARATOR};
 pub use split::SplitKaraokeCommand;
 pub use types::KaraokeType;
+pub use validate::KaraokeValidator;
@@ -4,7 +4,7 @@ use super::*;
 use crate::commands::EditorCommand;
 use crate::core::{EditorDocument, Position, Range};
 #[cfg(not(feature = "std"))]
-use alloc::vec;
+use alloc::{string::String, vec};
 #[cfg(not(feature = "std"))]
 #[test]
 fn generate_karaoke_basic() {
@@ -69,3 +69,89 @@ fn karaoke_types() {
     assert_eq!(KaraokeType::Outline.tag_string(), "ko");
     assert_eq!(KaraokeType::Transition.tag_string(), "kt");
 }
+
+#[test]
+fn generator_from_syllables_with_separator() {
+    let tagged =
+        KaraokeGenerator::from_syllables("Ka|ra|o|ke", &[10, 20, 30, 40], KaraokeType::Fill)
+            .unwrap();
+    assert_eq!(tagged, "{\\kf10}Ka{\\kf20}ra{\\kf30}o{\\kf40}ke");
+
+    // Whitespace syllables carry no timing
+    let tagged =
+        KaraokeGenerator::from_syllables("Hel|lo|  |you", &[5, 5, 5], KaraokeType::Outline)
+            .unwrap();
+    assert_eq!(tagged, "{\\ko5}Hel{\\ko5}lo  {\\ko5}you");
+}
+
+#[test]
+fn generator_from_syllables_errors() {
+    assert!(KaraokeGenerator::from_syllables("a|b", &[10], KaraokeType::Standard).is_err());
+    assert!(KaraokeGenerator::from_syllables("{\\b1}a", &[10], KaraokeType::Standard).is_err());
+    assert!(KaraokeGenerator::from_syllables("", &[], KaraokeType::Standard).is_err());
+    assert!(KaraokeGenerator::from_syllables("a", &[10], KaraokeType::Transition).is_err());
+}
+
+#[test]
+fn generator_auto_split_totals_are_exact() {
+    for method in [
+        AutoSplitMethod::Uniform,
+        AutoSplitMethod::ProportionalToLength,
+        AutoSplitMethod::VowelWeighted,
+    ] {
+        for total in [0, 1, 7, 100, 333] {
+            let tagged = KaraokeGenerator::auto_split(
+                "Twin|kle twin|kle",
+                total,
+                method,
+                KaraokeType::Standard,
+            )
+            .unwrap();
+            KaraokeValidator::check(&tagged, total)
+                .unwrap_or_else(|e| panic!("{method:?} with {total}cs produced {tagged}: {e}"));
+        }
+    }
+}
+
+#[test]
+fn generator_auto_split_methods() {
+    let durations = |method| {
+        let tagged =
+            KaraokeGenerator::auto_split("aaa|b|st", 100, method, KaraokeType::Standard).unwrap();
+        KaraokeValidator::durations(&tagged)
+    };
+
+    assert_eq!(durations(AutoSplitMethod::Uniform), [34, 33, 33]);
+    assert_eq!(
+        durations(AutoSplitMethod::ProportionalToLength),
+        [50, 17, 33]
+    );
+    // Vowels weigh 3: "aaa" = 9, "b" = 1, "st" = 2
+    assert_eq!(durations(AutoSplitMethod::VowelWeighted), [75, 8, 17]);
+}
+
+#[test]
+fn validator_parses_all_duration_tags() {
+    let text = "{\\k10}a{\\K20\\b1}b{\\kf 30}c{\\ko40}d{\\kt500}e";
+    assert_eq!(KaraokeValidator::durations(text), [10, 20, 30, 40]);
+    assert!(KaraokeValidator::check(text, 100).is_ok());
+    assert!(KaraokeValidator::check(text, 99).is_err());
+    assert!(KaraokeValidator::check("plain", 0).is_err());
+}
+
+#[test]
+fn karaoke_roundtrip_through_validator() {
+    let durations = [12, 0, 45, 3];
+    let tagged =
+        KaraokeGenerator::from_syllables("one|two|three|four", &durations, KaraokeType::Standard)
+            .unwrap();
+    assert_eq!(KaraokeValidator::durations(&tagged), durations);
+    KaraokeValidator::check(&tagged, durations.iter().sum()).unwrap();
+
+    let syllables = KaraokeGenerator::syllables("one|two|three|four");
+    let plain: String = tagged
+        .split('{')
+        .map(|part| part.split_once('}').map_or(part, |(_, text)| text))
+        .collect();
+    assert_eq!(plain, syllables.concat());
+}
@@ -0,0 +1,67 @@
+//! Karaoke timing validation against event duration.
+
+use crate::core::{EditorError, Result};
+
+#[cfg(not(feature = "std"))]
+use alloc::{format, vec::Vec};
+
+/// Checks that karaoke syllable timing fits its event
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct KaraokeValidator;
+
+impl KaraokeValidator {
+    /// Durations of the `\k`, `\K`, `\kf` and `\ko` tags in `text`, in order
+    ///
+    /// `\kt` is skipped, as it sets an absolute time rather than a duration.
+    pub fn durations(text: &str) -> Vec<u32> {
+        let mut durations = Vec::new();
+        for block in text.split('{').skip(1) {
+            let block = block.split('}').next().unwrap_or_default();
+            for tag in block.split('\\').skip(1) {
+                let args = if let Some(rest) = tag.strip_prefix('K') {
+                    rest
+                } else if let Some(rest) = tag.strip_prefix('k') {
+                    if rest.starts_with('t') {
+                        continue;
+                    }
+                    rest.strip_prefix(['f', 'o']).unwrap_or(rest)
+                } else {
+                    continue;
+                };
+                let digits = args.trim_start();
+                let end = digits
+                    .find(|c: char| !c.is_ascii_digit())
+                    .unwrap_or(digits.len());
+                if let Ok(duration) = digits[..end].parse() {
+                    durations.push(duration);
+                }
+            }
+        }
+        durations
+    }
+
+    /// Verify the karaoke durations in `text_with_k_tags` add up exactly to
+    /// `event_duration_cs`
+    ///
+    /// # Errors
+    ///
+    /// Returns [`EditorError::ValidationError`] when the text has no karaoke
+    /// tags or their total differs from the event duration.
+    pub fn check(text_with_k_tags: &str, event_duration_cs: u32) -> Result<()> {
+        let durations = Self::durations(text_with_k_tags);
+        if durations.is_empty() {
+            return Err(EditorError::ValidationError {
+                message: "Text has no karaoke tags".into(),
+            });
+        }
+        let total: u64 = durations.iter().map(|&d| u64::from(d)).sum();
+        if total != u64::from(event_duration_cs) {
+            return Err(EditorError::ValidationError {
+                message: format!(
+                    "Karaoke durations sum to {total}cs but the event lasts {event_duration_cs}cs"
+                ),
+            });
+        }
+        Ok(())
+    }
+}
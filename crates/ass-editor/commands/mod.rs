@@ -9,6 +9,7 @@ pub mod event_commands;
 pub mod fonts_graphics_commands;
 pub mod karaoke_commands;
 pub mod macros;
+pub mod recording;
 pub mod script_info_commands;
 pub mod style_commands;
 pub mod tag_commands;
@@ -31,6 +32,8 @@ pub use script_info_commands::*;
 pub use style_commands::*;
 pub use tag_commands::*;
 
+pub use recording::{Macro, MacroRecorder, RecordedCommand, SELECTION_PLACEHOLDER};
+
 pub use batch_command::BatchCommand;
 pub use command_result::CommandResult;
 pub use command_trait::EditorCommand;
@@ -0,0 +1,104 @@
+//! Macro recording and playback of editor commands
+//!
+//! [`MacroRecorder`] wraps a document and records every command executed
+//! through it as a [`RecordedCommand`]: the command type and its arguments,
+//! without any document state. The resulting [`Macro`] can be played back on
+//! any other document as a single undoable step.
+//!
+//! The text argument of a recorded replacement may contain the
+//! [`SELECTION_PLACEHOLDER`], which expands to the text selected at playback
+//! time.
+//!
+//! # Examples
+//!
+//! ```
+//! use ass_editor::commands::{Macro, MacroRecorder, ReplaceTextCommand};
+//! use ass_editor::{EditorDocument, Position, Range, Selection};
+//!
+//! let mut doc = EditorDocument::from_content("Hello World").unwrap();
+//! let mut recorder = MacroRecorder::new(&mut doc);
+//! recorder.start("Shout");
+//! let range = Range::new(Position::new(0), Position::new(5));
+//! recorder
+//!     .execute(&ReplaceTextCommand::new(range, "%SELECTION%!".to_string()))
+//!     .unwrap();
+//! let shout: Macro = recorder.stop();
+//!
+//! let mut other = EditorDocument::from_content("Hi there").unwrap();
+//! let selection = Selection::new(Position::new(0), Position::new(2));
+//! shout.play_with_selection(&mut other, selection).unwrap();
+//! assert_eq!(other.text(), "Hi!ere");
+//! ```
+
+mod playback;
+mod recorder;
+
+#[cfg(feature = "serde")]
+mod persist;
+
+#[cfg(test)]
+mod tests;
+
+pub use recorder::MacroRecorder;
+
+use crate::core::{Position, Range};
+
+#[cfg(not(feature = "std"))]
+use alloc::{string::String, vec::Vec};
+
+/// Placeholder in replacement text that expands to the selected text at
+/// playback time
+pub const SELECTION_PLACEHOLDER: &str = "%SELECTION%";
+
+/// A command captured by [`MacroRecorder`], independent of any document
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RecordedCommand {
+    /// See [`InsertTextCommand`](super::InsertTextCommand)
+    InsertText {
+        /// Insertion point
+        position: Position,
+        /// Inserted text
+        text: String,
+    },
+    /// See [`DeleteTextCommand`](super::DeleteTextCommand)
+    DeleteText {
+        /// Deleted range
+        range: Range,
+    },
+    /// See [`ReplaceTextCommand`](super::ReplaceTextCommand)
+    ReplaceText {
+        /// Replaced range
+        range: Range,
+        /// Replacement, which may contain [`SELECTION_PLACEHOLDER`]
+        new_text: String,
+    },
+    /// See [`BatchCommand`](super::BatchCommand)
+    Batch {
+        /// Batch description
+        description: String,
+        /// Commands of the batch in order
+        commands: Vec<RecordedCommand>,
+    },
+}
+
+/// A named sequence of recorded commands
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Macro {
+    /// Name given when recording started
+    pub name: String,
+    /// Commands in execution order
+    pub commands: Vec<RecordedCommand>,
+}
+
+impl Macro {
+    /// Create a macro from already recorded commands
+    pub fn new(name: String, commands: Vec<RecordedCommand>) -> Self {
+        Self { name, commands }
+    }
+
+    /// Whether the macro records no commands
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.commands.is_empty()
+    }
+}
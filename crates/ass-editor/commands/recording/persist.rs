@@ -0,0 +1,130 @@
+//! Binary persistence of recorded macros.
+//!
+//! [`Macro::serialize`] encodes the macro with `bincode` through private
+//! mirror types, like the undo history, so the stored layout does not depend
+//! on the in-memory command representation.
+
+use super::{Macro, RecordedCommand};
+use crate::core::errors::{EditorError, Result};
+use crate::core::position::{Position, Range};
+use serde::{Deserialize, Serialize};
+
+/// Version tag written ahead of the macro payload
+const FORMAT_VERSION: u32 = 1;
+
+/// Serialized form of a [`Macro`]
+#[derive(Serialize, Deserialize)]
+struct SavedMacro {
+    /// Layout version, checked on restore
+    version: u32,
+    /// Macro name
+    name: String,
+    /// Recorded commands in order
+    commands: Vec<SavedCommand>,
+}
+
+/// Serialized form of a [`RecordedCommand`], with ranges as `(start, end)`
+#[derive(Serialize, Deserialize)]
+enum SavedCommand {
+    /// See [`RecordedCommand::InsertText`]
+    InsertText { position: usize, text: String },
+    /// See [`RecordedCommand::DeleteText`]
+    DeleteText { range: (usize, usize) },
+    /// See [`RecordedCommand::ReplaceText`]
+    ReplaceText {
+        range: (usize, usize),
+        new_text: String,
+    },
+    /// See [`RecordedCommand::Batch`]
+    Batch {
+        description: String,
+        commands: Vec<SavedCommand>,
+    },
+}
+
+impl Macro {
+    /// Encode the macro as bytes
+    pub fn serialize(&self) -> Result<Vec<u8>> {
+        let saved = SavedMacro {
+            version: FORMAT_VERSION,
+            name: self.name.clone(),
+            commands: self.commands.iter().map(SavedCommand::from).collect(),
+        };
+        bincode::serialize(&saved).map_err(EditorError::serialization)
+    }
+
+    /// Restore a macro written by [`Self::serialize`]
+    pub fn deserialize(bytes: &[u8]) -> Result<Self> {
+        let saved: SavedMacro = bincode::deserialize(bytes).map_err(EditorError::serialization)?;
+        if saved.version != FORMAT_VERSION {
+            return Err(EditorError::serialization(format!(
+                "Unsupported macro version {}",
+                saved.version
+            )));
+        }
+        Ok(Self::new(
+            saved.name,
+            saved
+                .commands
+                .into_iter()
+                .map(RecordedCommand::from)
+                .collect(),
+        ))
+    }
+}
+
+impl From<&RecordedCommand> for SavedCommand {
+    fn from(command: &RecordedCommand) -> Self {
+        let bounds = |range: &Range| (range.start.offset, range.end.offset);
+        match command {
+            RecordedCommand::InsertText { position, text } => Self::InsertText {
+                position: position.offset,
+                text: text.clone(),
+            },
+            RecordedCommand::DeleteText { range } => Self::DeleteText {
+                range: bounds(range),
+            },
+            RecordedCommand::ReplaceText { range, new_text } => Self::ReplaceText {
+                range: bounds(range),
+                new_text: new_text.clone(),
+            },
+            RecordedCommand::Batch {
+                description,
+                commands,
+            } => Self::Batch {
+                description: description.clone(),
+                commands: commands.iter().map(Self::from).collect(),
+            },
+        }
+    }
+}
+
+impl From<SavedCommand> for RecordedCommand {
+    fn from(saved: SavedCommand) -> Self {
+        let range =
+            |(start, end): (usize, usize)| Range::new(Position::new(start), Position::new(end));
+        match saved {
+            SavedCommand::InsertText { position, text } => Self::InsertText {
+                position: Position::new(position),
+                text,
+            },
+            SavedCommand::DeleteText { range: bounds } => Self::DeleteText {
+                range: range(bounds),
+            },
+            SavedCommand::ReplaceText {
+                range: bounds,
+                new_text,
+            } => Self::ReplaceText {
+                range: range(bounds),
+                new_text,
+            },
+            SavedCommand::Batch {
+                description,
+                commands,
+            } => Self::Batch {
+                description,
+                commands: commands.into_iter().map(Self::from).collect(),
+            },
+        }
+    }
+}
@@ -0,0 +1,132 @@
+//! Playback of a [`Macro`] as a single undoable command.
+
+use super::{Macro, RecordedCommand, SELECTION_PLACEHOLDER};
+use crate::commands::{
+    CommandResult, DeleteTextCommand, EditorCommand, InsertTextCommand, ReplaceTextCommand,
+};
+use crate::core::{EditorDocument, Position, Range, Result, Selection};
+
+#[cfg(not(feature = "std"))]
+use alloc::{format, string::String};
+
+impl Macro {
+    /// Play the macro on `document` with an empty selection at the cursor
+    /// (or the document start), so [`SELECTION_PLACEHOLDER`] expands to
+    /// nothing
+    pub fn play(&self, document: &mut EditorDocument) -> Result<CommandResult> {
+        let cursor = document.cursor_position().unwrap_or_default();
+        self.play_with_selection(document, Selection::empty(cursor))
+    }
+
+    /// Play the macro on `document`, expanding [`SELECTION_PLACEHOLDER`] to
+    /// the text `selection` covers when each replacement runs
+    ///
+    /// All commands are applied as one undo step. Playback stops at the
+    /// first failing command.
+    pub fn play_with_selection(
+        &self,
+        document: &mut EditorDocument,
+        selection: Selection,
+    ) -> Result<CommandResult> {
+        document.execute_command(&Playback {
+            recorded: self,
+            selection: selection.range(),
+            description: format!("Play macro: {}", self.name),
+        })
+    }
+}
+
+/// Command executing every recorded command of a macro in order
+#[derive(Debug)]
+struct Playback<'m> {
+    /// Macro being played
+    recorded: &'m Macro,
+    /// Range `%SELECTION%` refers to
+    selection: Range,
+    /// Undo history description
+    description: String,
+}
+
+impl Playback<'_> {
+    /// Execute `command` and fold its outcome into `overall`
+    fn run(
+        &self,
+        command: &RecordedCommand,
+        document: &mut EditorDocument,
+        overall: &mut CommandResult,
+    ) -> Result<bool> {
+        let result = match command {
+            RecordedCommand::InsertText { position, text } => {
+                InsertTextCommand::new(*position, text.clone()).execute(document)?
+            }
+            RecordedCommand::DeleteText { range } => {
+                DeleteTextCommand::new(*range).execute(document)?
+            }
+            RecordedCommand::ReplaceText { range, new_text } => {
+                let new_text = if new_text.contains(SELECTION_PLACEHOLDER) {
+                    let selected = document.text_range(self.selection)?;
+                    new_text.replace(SELECTION_PLACEHOLDER, &selected)
+                } else {
+                    new_text.clone()
+                };
+                ReplaceTextCommand::new(*range, new_text).execute(document)?
+            }
+            RecordedCommand::Batch { commands, .. } => {
+                for command in commands {
+                    if !self.run(command, document, overall)? {
+                        return Ok(false);
+                    }
+                }
+                return Ok(true);
+            }
+        };
+
+        if !result.success {
+            return Ok(false);
+        }
+        if let Some(range) = result.modified_range {
+            overall.modified_range = Some(match overall.modified_range {
+                Some(existing) => existing.union(&range),
+                None => range,
+            });
+        }
+        if result.new_cursor.is_some() {
+            overall.new_cursor = result.new_cursor;
+        }
+        overall.content_changed |= result.content_changed;
+        Ok(true)
+    }
+}
+
+impl EditorCommand for Playback<'_> {
+    /// Runs every command, restoring the original text if one fails so a
+    /// partial playback never reaches the document
+    fn execute(&self, document: &mut EditorDocument) -> Result<CommandResult> {
+        let before = document.text();
+        let mut overall = CommandResult::success();
+        let mut completed = Ok(true);
+        for command in &self.recorded.commands {
+            completed = self.run(command, document, &mut overall);
+            if !matches!(completed, Ok(true)) {
+                break;
+            }
+        }
+
+        match completed {
+            Ok(true) => Ok(overall),
+            failed => {
+                let all = Range::new(Position::new(0), Position::new(document.len()));
+                document.replace_raw(all, &before)?;
+                failed?;
+                Ok(CommandResult::failure(format!(
+                    "Macro playback failed: {}",
+                    self.recorded.name
+                )))
+            }
+        }
+    }
+
+    fn description(&self) -> &str {
+        &self.description
+    }
+}
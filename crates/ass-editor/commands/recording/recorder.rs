@@ -0,0 +1,87 @@
+//! `MacroRecorder`, which records commands while executing them.
+
+use super::{Macro, RecordedCommand};
+use crate::commands::{CommandResult, EditorCommand};
+use crate::core::{EditorDocument, EditorError, Result};
+
+#[cfg(not(feature = "std"))]
+use alloc::{format, string::String, vec::Vec};
+
+/// Executes commands on a document, recording them while a recording is
+/// active
+///
+/// Only commands that describe themselves through
+/// [`EditorCommand::to_recorded`] can be recorded; executing any other
+/// command while recording fails without touching the document.
+#[derive(Debug)]
+pub struct MacroRecorder<'a> {
+    /// Document the commands run on
+    document: &'a mut EditorDocument,
+    /// Name and commands of the active recording
+    recording: Option<(String, Vec<RecordedCommand>)>,
+}
+
+impl<'a> MacroRecorder<'a> {
+    /// Wrap `document`; nothing is recorded until [`Self::start`]
+    pub fn new(document: &'a mut EditorDocument) -> Self {
+        Self {
+            document,
+            recording: None,
+        }
+    }
+
+    /// Start recording a macro called `name`, discarding any recording in
+    /// progress
+    pub fn start(&mut self, name: &str) {
+        self.recording = Some((name.into(), Vec::new()));
+    }
+
+    /// Stop recording and return the recorded macro
+    ///
+    /// Returns an empty, unnamed macro when no recording was active.
+    pub fn stop(&mut self) -> Macro {
+        self.recording
+            .take()
+            .map(|(name, commands)| Macro::new(name, commands))
+            .unwrap_or_default()
+    }
+
+    /// Whether a recording is active
+    #[must_use]
+    pub fn is_recording(&self) -> bool {
+        self.recording.is_some()
+    }
+
+    /// Execute `command` on the document with undo support, recording it
+    /// when a recording is active and the command succeeds
+    pub fn execute(&mut self, command: &dyn EditorCommand) -> Result<CommandResult> {
+        let recorded = match &self.recording {
+            Some(_) => Some(command.to_recorded().ok_or_else(|| {
+                EditorError::command_failed(format!(
+                    "Command cannot be recorded: {}",
+                    command.description()
+                ))
+            })?),
+            None => None,
+        };
+
+        let result = self.document.execute_command(command)?;
+        if let (Some((_, commands)), Some(recorded)) = (&mut self.recording, recorded) {
+            if result.success {
+                commands.push(recorded);
+            }
+        }
+        Ok(result)
+    }
+
+    /// The wrapped document
+    #[must_use]
+    pub fn document(&self) -> &EditorDocument {
+        self.document
+    }
+
+    /// Play `recorded` on `document`, see [`Macro::play`]
+    pub fn play(recorded: &Macro, document: &mut EditorDocument) -> Result<CommandResult> {
+        recorded.play(document)
+    }
+}
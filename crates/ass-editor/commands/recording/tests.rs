@@ -0,0 +1,122 @@
+//! Unit tests for macro recording and playback.
+
+use super::*;
+use crate::commands::{BatchCommand, EditorCommand, InsertTextCommand, ReplaceTextCommand};
+use crate::core::{EditorDocument, Position, Range, Selection};
+
+#[cfg(not(feature = "std"))]
+use alloc::{boxed::Box, string::ToString, vec};
+
+fn range(start: usize, end: usize) -> Range {
+    Range::new(Position::new(start), Position::new(end))
+}
+
+fn replace(start: usize, end: usize, text: &str) -> ReplaceTextCommand {
+    ReplaceTextCommand::new(range(start, end), text.to_string())
+}
+
+#[test]
+fn three_replacements_replay_on_fresh_document() {
+    let mut doc = EditorDocument::from_content("Hello World").unwrap();
+    let mut recorder = MacroRecorder::new(&mut doc);
+    recorder.start("Greeting");
+    recorder.execute(&replace(0, 5, "Howdy")).unwrap();
+    recorder.execute(&replace(6, 11, "Folks")).unwrap();
+    recorder.execute(&replace(5, 6, ", ")).unwrap();
+    assert!(recorder.is_recording());
+    let recorded = recorder.stop();
+    assert!(!recorder.is_recording());
+    assert_eq!(recorder.document().text(), "Howdy, Folks");
+    assert_eq!(recorded.name, "Greeting");
+    assert_eq!(recorded.commands.len(), 3);
+
+    let mut fresh = EditorDocument::from_content("Hello World").unwrap();
+    let result = MacroRecorder::play(&recorded, &mut fresh).unwrap();
+    assert!(result.success);
+    assert_eq!(fresh.text(), "Howdy, Folks");
+
+    // The whole playback is a single undo step
+    fresh.undo().unwrap();
+    assert_eq!(fresh.text(), "Hello World");
+}
+
+#[test]
+fn selection_placeholder_expands_at_playback() {
+    let recorded = Macro::new(
+        "Italicize".to_string(),
+        vec![RecordedCommand::ReplaceText {
+            range: range(0, 4),
+            new_text: "{\\i1}%SELECTION%{\\i0}".to_string(),
+        }],
+    );
+
+    let mut doc = EditorDocument::from_content("Word here").unwrap();
+    recorded
+        .play_with_selection(&mut doc, Selection::new(Position::new(4), Position::new(0)))
+        .unwrap();
+    assert_eq!(doc.text(), "{\\i1}Word{\\i0} here");
+
+    let mut empty = EditorDocument::from_content("Word here").unwrap();
+    recorded.play(&mut empty).unwrap();
+    assert_eq!(empty.text(), "{\\i1}{\\i0} here");
+}
+
+#[test]
+fn commands_outside_recording_are_not_recorded() {
+    let mut doc = EditorDocument::from_content("abc").unwrap();
+    let mut recorder = MacroRecorder::new(&mut doc);
+    recorder.execute(&replace(0, 1, "A")).unwrap();
+    recorder.start("Batch");
+    let batch = BatchCommand::new("Two inserts".to_string())
+        .add_command(Box::new(InsertTextCommand::new(
+            Position::new(0),
+            "<".to_string(),
+        )))
+        .add_command(Box::new(InsertTextCommand::new(
+            Position::new(4),
+            ">".to_string(),
+        )));
+    recorder.execute(&batch).unwrap();
+    let recorded = recorder.stop();
+
+    assert_eq!(recorded.commands, [batch.to_recorded().unwrap()]);
+    assert!(recorder.stop().is_empty());
+}
+
+#[test]
+fn failed_playback_leaves_document_untouched() {
+    let recorded = Macro::new(
+        "Out of range".to_string(),
+        vec![
+            RecordedCommand::InsertText {
+                position: Position::new(0),
+                text: "x".to_string(),
+            },
+            RecordedCommand::DeleteText {
+                range: range(50, 60),
+            },
+        ],
+    );
+    let mut doc = EditorDocument::from_content("short").unwrap();
+    assert!(recorded.play(&mut doc).is_err());
+    assert_eq!(doc.text(), "short");
+    assert!(!doc.can_undo());
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn macro_round_trips_through_bytes() {
+    let batch =
+        BatchCommand::new("Wrap".to_string()).add_command(Box::new(replace(0, 1, "%SELECTION%")));
+    let recorded = Macro::new(
+        "Saved".to_string(),
+        vec![
+            batch.to_recorded().unwrap(),
+            RecordedCommand::DeleteText { range: range(1, 2) },
+        ],
+    );
+
+    let bytes = recorded.serialize().unwrap();
+    assert_eq!(Macro::deserialize(&bytes).unwrap(), recorded);
+    assert!(Macro::deserialize(&bytes[..3]).is_err());
+}
@@ -0,0 +1,98 @@
+//! Style merge command for ASS documents.
+//!
+//! Provides [`StyleMergeCommand`] to remap every reference from one style to
+//! another and then delete the now unused style, as a single undoable batch.
+
+use super::references::{batch_edits, find_style_line, style_references};
+use crate::commands::{BatchCommand, CommandResult, EditorCommand};
+use crate::core::{EditorDocument, EditorError, Result};
+
+#[cfg(not(feature = "std"))]
+use alloc::{format, string::String, vec::Vec};
+
+/// Command to merge one style into another
+///
+/// Events and `\r` tags referring to `source` are pointed at `target`, and
+/// the `source` definition is removed.
+#[derive(Debug, Clone)]
+pub struct StyleMergeCommand {
+    pub source: String,
+    pub target: String,
+    pub description: Option<String>,
+}
+
+impl StyleMergeCommand {
+    /// Create a new style merge command
+    pub fn new(source: String, target: String) -> Self {
+        Self {
+            source,
+            target,
+            description: None,
+        }
+    }
+
+    /// Set custom description
+    #[must_use]
+    pub fn with_description(mut self, description: String) -> Self {
+        self.description = Some(description);
+        self
+    }
+
+    /// Build the batch of edits performing the merge on `document`
+    ///
+    /// Fails if either style does not exist or both names are the same.
+    pub fn to_batch(&self, document: &EditorDocument) -> Result<BatchCommand> {
+        let content = document.text();
+        if self.source == self.target {
+            return Err(EditorError::command_failed(format!(
+                "Cannot merge style '{}' into itself",
+                self.source
+            )));
+        }
+
+        let source = find_style_line(&content, &self.source).ok_or_else(|| {
+            EditorError::command_failed(format!("Style '{}' not found", self.source))
+        })?;
+        if find_style_line(&content, &self.target).is_none() {
+            return Err(EditorError::command_failed(format!(
+                "Style '{}' not found",
+                self.target
+            )));
+        }
+
+        let mut edits: Vec<_> = style_references(&content, &self.source)
+            .into_iter()
+            .map(|range| (range, self.target.clone()))
+            .collect();
+        edits.push((source.line, String::new()));
+
+        Ok(batch_edits(self.description(), edits))
+    }
+}
+
+impl EditorCommand for StyleMergeCommand {
+    fn execute(&self, document: &mut EditorDocument) -> Result<CommandResult> {
+        let batch = self.to_batch(document)?;
+        let references = batch.commands.len() - 1;
+
+        let result = batch.execute(document)?;
+        if !result.success {
+            return Ok(result);
+        }
+        Ok(result.with_message(format!(
+            "Merged style '{}' into '{}' ({references} references updated)",
+            self.source, self.target
+        )))
+    }
+
+    fn description(&self) -> &str {
+        self.description.as_deref().unwrap_or("Merge styles")
+    }
+
+    fn memory_usage(&self) -> usize {
+        core::mem::size_of::<Self>()
+            + self.source.len()
+            + self.target.len()
+            + self.description.as_ref().map_or(0, |d| d.len())
+    }
+}
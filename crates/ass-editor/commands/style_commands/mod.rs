@@ -1,13 +1,16 @@
 //! Style management commands for ASS documents
 //!
-//! Provides commands for creating, editing, deleting, cloning, and applying styles
-//! with proper validation and delta tracking.
+//! Provides commands for creating, editing, deleting, cloning, applying, renaming
+//! and merging styles with proper validation and delta tracking.
 
 mod apply;
 mod clone;
 mod create;
 mod delete;
 mod edit;
+mod merge;
+mod references;
+mod rename;
 
 #[cfg(test)]
 mod tests;
@@ -17,3 +20,5 @@ pub use clone::CloneStyleCommand;
 pub use create::CreateStyleCommand;
 pub use delete::DeleteStyleCommand;
 pub use edit::EditStyleCommand;
+pub use merge::StyleMergeCommand;
+pub use rename::StyleRenameCommand;
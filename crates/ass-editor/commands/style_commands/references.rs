@@ -0,0 +1,193 @@
+//! Style reference scanning shared by the rename and merge commands.
+//!
+//! Locates style definitions in the styles sections and every reference to a
+//! style from event `Style` fields and inline `\r` reset tags, returning byte
+//! ranges into the document text.
+
+use crate::commands::{BatchCommand, EditorCommand, ReplaceTextCommand};
+use crate::core::{Position, Range};
+
+#[cfg(not(feature = "std"))]
+use alloc::{
+    boxed::Box,
+    string::{String, ToString},
+    vec::Vec,
+};
+
+/// Byte ranges of a `Style:` definition line
+pub(super) struct StyleLine {
+    /// The whole line including its trailing newline, if any
+    pub(super) line: Range,
+    /// The style name within the line
+    pub(super) name: Range,
+}
+
+/// Iterate over `(offset, line)` pairs with the line terminator stripped
+fn lines_with_offsets(content: &str) -> impl Iterator<Item = (usize, &str)> {
+    content.split_inclusive('\n').scan(0, |offset, line| {
+        let start = *offset;
+        *offset += line.len();
+        Some((start, line.trim_end_matches(['\n', '\r'])))
+    })
+}
+
+/// Build a range from byte offsets
+fn range(start: usize, end: usize) -> Range {
+    Range::new(Position::new(start), Position::new(end))
+}
+
+/// Range of the trimmed `field` within `line`, shifted by `base`
+fn trimmed_range(base: usize, field: &str) -> Range {
+    let leading = field.len() - field.trim_start().len();
+    let start = base + leading;
+    range(start, start + field.trim().len())
+}
+
+/// Find the definition of `name` in any styles section
+pub(super) fn find_style_line(content: &str, name: &str) -> Option<StyleLine> {
+    let mut in_styles = false;
+    for (offset, line) in lines_with_offsets(content) {
+        let trimmed = line.trim_start();
+        if trimmed.starts_with('[') {
+            in_styles = trimmed.to_ascii_lowercase().contains("styles]");
+            continue;
+        }
+        if !in_styles {
+            continue;
+        }
+
+        let Some(fields) = trimmed.strip_prefix("Style:") else {
+            continue;
+        };
+        let field = fields.split(',').next().unwrap_or_default();
+        if field.trim() == name {
+            let fields_offset = offset + (line.len() - fields.len());
+            let line_end = content[offset..]
+                .find('\n')
+                .map_or(content.len(), |pos| offset + pos + 1);
+            return Some(StyleLine {
+                line: range(offset, line_end),
+                name: trimmed_range(fields_offset, field),
+            });
+        }
+    }
+    None
+}
+
+/// Positions of the `Style` and `Text` fields in an event line
+struct EventFields {
+    /// Number of fields; the last one (`Text`) may contain commas
+    count: usize,
+    /// Index of the `Style` field, if the format has one
+    style: Option<usize>,
+    /// Index of the `Text` field, if the format has one
+    text: Option<usize>,
+}
+
+impl EventFields {
+    /// Read the field positions from the fields of an `[Events]` `Format:` line
+    fn from_format(format: &str) -> Self {
+        let names: Vec<&str> = format.split(',').map(str::trim).collect();
+        let position = |field: &str| {
+            names
+                .iter()
+                .position(|name| name.eq_ignore_ascii_case(field))
+        };
+        Self {
+            count: names.len(),
+            style: position("Style"),
+            text: position("Text"),
+        }
+    }
+}
+
+impl Default for EventFields {
+    /// The standard v4+ layout, used until a `Format:` line is seen
+    fn default() -> Self {
+        Self::from_format("Layer, Start, End, Style, Name, MarginL, MarginR, MarginV, Effect, Text")
+    }
+}
+
+/// Ranges of every event reference to `name`: the `Style` field of
+/// `Dialogue`/`Comment` lines and the argument of `\r` tags in their text
+///
+/// Field positions follow the section's `Format:` line.
+pub(super) fn style_references(content: &str, name: &str) -> Vec<Range> {
+    let mut references = Vec::new();
+    let mut in_events = false;
+    let mut layout = EventFields::default();
+
+    for (offset, line) in lines_with_offsets(content) {
+        let trimmed = line.trim_start();
+        if trimmed.starts_with('[') {
+            in_events = trimmed.eq_ignore_ascii_case("[Events]");
+            layout = EventFields::default();
+            continue;
+        }
+        if !in_events {
+            continue;
+        }
+
+        if let Some(format) = trimmed.strip_prefix("Format:") {
+            layout = EventFields::from_format(format);
+            continue;
+        }
+        let Some(fields) = trimmed
+            .strip_prefix("Dialogue:")
+            .or_else(|| trimmed.strip_prefix("Comment:"))
+        else {
+            continue;
+        };
+        let fields_offset = offset + (line.len() - fields.len());
+
+        let mut field_start = 0;
+        for (index, field) in fields.splitn(layout.count, ',').enumerate() {
+            let base = fields_offset + field_start;
+            if Some(index) == layout.style && field.trim() == name {
+                references.push(trimmed_range(base, field));
+            } else if Some(index) == layout.text {
+                reset_tag_references(field, name, base, &mut references);
+            }
+            field_start += field.len() + 1;
+        }
+    }
+
+    references
+}
+
+/// Collect `\r<name>` arguments inside the override blocks of `text`
+fn reset_tag_references(text: &str, name: &str, base: usize, references: &mut Vec<Range>) {
+    let mut search = 0;
+    while let Some(open) = text[search..].find('{').map(|pos| search + pos) {
+        let close = text[open..].find('}').map_or(text.len(), |pos| open + pos);
+        let block = &text[open + 1..close];
+
+        let mut tag_start = 0;
+        for tag in block.split('\\') {
+            if let Some(argument) = tag.strip_prefix('r') {
+                if !argument.is_empty() && argument.trim() == name {
+                    references.push(trimmed_range(base + open + 1 + tag_start + 1, argument));
+                }
+            }
+            tag_start += tag.len() + 1;
+        }
+
+        search = close;
+    }
+}
+
+/// Bundle edits given in original-text offsets into one batch
+///
+/// Edits are applied from the end of the document backwards so earlier
+/// ranges stay valid while later ones change length.
+pub(super) fn batch_edits(description: &str, mut edits: Vec<(Range, String)>) -> BatchCommand {
+    edits.sort_by_key(|(range, _)| core::cmp::Reverse(range.start.offset));
+    BatchCommand::new(description.to_string()).add_commands(
+        edits
+            .into_iter()
+            .map(|(range, text)| {
+                Box::new(ReplaceTextCommand::new(range, text)) as Box<dyn EditorCommand>
+            })
+            .collect(),
+    )
+}
@@ -0,0 +1,104 @@
+//! Style rename command for ASS documents.
+//!
+//! Provides [`StyleRenameCommand`] to rename a style together with every
+//! event and `\r` tag that refers to it, as a single undoable batch.
+
+use super::references::{batch_edits, find_style_line, style_references};
+use crate::commands::{BatchCommand, CommandResult, EditorCommand};
+use crate::core::{EditorDocument, EditorError, Result};
+
+#[cfg(not(feature = "std"))]
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
+
+/// Command to rename a style and update all references to it
+///
+/// The style definition, the `Style` field of every event using it and
+/// every inline `\r<name>` reset tag are rewritten together.
+#[derive(Debug, Clone)]
+pub struct StyleRenameCommand {
+    pub old_name: String,
+    pub new_name: String,
+    pub description: Option<String>,
+}
+
+impl StyleRenameCommand {
+    /// Create a new style rename command
+    pub fn new(old_name: String, new_name: String) -> Self {
+        Self {
+            old_name,
+            new_name,
+            description: None,
+        }
+    }
+
+    /// Set custom description
+    #[must_use]
+    pub fn with_description(mut self, description: String) -> Self {
+        self.description = Some(description);
+        self
+    }
+
+    /// Build the batch of replacements performing the rename on `document`
+    ///
+    /// Fails if the old style does not exist, the new name is already taken
+    /// or the new name cannot be used as a style name.
+    pub fn to_batch(&self, document: &EditorDocument) -> Result<BatchCommand> {
+        let content = document.text();
+        let new_name = self.new_name.trim();
+        if new_name.is_empty() || new_name.contains([',', '\n', '\\', '{', '}']) {
+            return Err(EditorError::command_failed(format!(
+                "Invalid style name '{}'",
+                self.new_name
+            )));
+        }
+
+        let style = find_style_line(&content, &self.old_name).ok_or_else(|| {
+            EditorError::command_failed(format!("Style '{}' not found", self.old_name))
+        })?;
+        if new_name != self.old_name && find_style_line(&content, new_name).is_some() {
+            return Err(EditorError::command_failed(format!(
+                "Style '{new_name}' already exists"
+            )));
+        }
+
+        let mut edits: Vec<_> = style_references(&content, &self.old_name)
+            .into_iter()
+            .map(|range| (range, new_name.to_string()))
+            .collect();
+        edits.push((style.name, new_name.to_string()));
+
+        Ok(batch_edits(self.description(), edits))
+    }
+}
+
+impl EditorCommand for StyleRenameCommand {
+    fn execute(&self, document: &mut EditorDocument) -> Result<CommandResult> {
+        let batch = self.to_batch(document)?;
+        let references = batch.commands.len() - 1;
+
+        let result = batch.execute(document)?;
+        if !result.success {
+            return Ok(result);
+        }
+        Ok(result.with_message(format!(
+            "Renamed style '{}' to '{}' ({references} references updated)",
+            self.old_name,
+            self.new_name.trim()
+        )))
+    }
+
+    fn description(&self) -> &str {
+        self.description.as_deref().unwrap_or("Rename style")
+    }
+
+    fn memory_usage(&self) -> usize {
+        core::mem::size_of::<Self>()
+            + self.old_name.len()
+            + self.new_name.len()
+            + self.description.as_ref().map_or(0, |d| d.len())
+    }
+}
@@ -4,7 +4,7 @@ use super::*;
 use crate::commands::EditorCommand;
 use crate::core::{EditorDocument, StyleBuilder};
 #[cfg(not(feature = "std"))]
-use alloc::string::ToString;
+use alloc::{format, string::String, string::ToString};
 const TEST_CONTENT: &str = r#"[Script Info]
 Title: Test Script
 
@@ -139,3 +139,112 @@ fn test_clone_to_existing_style() {
 
     assert!(result.is_err());
 }
+
+/// Script with `Default` and `Sign` styles and 50 events, every fifth one
+/// switching to `Default` through an inline reset tag
+fn fifty_event_script() -> String {
+    let mut content = String::from(
+        "[Script Info]\nTitle: Rename Test\n\n[V4+ Styles]\n\
+         Format: Name, Fontname, Fontsize, PrimaryColour, SecondaryColour, OutlineColour, BackColour, Bold, Italic, Underline, StrikeOut, ScaleX, ScaleY, Spacing, Angle, BorderStyle, Outline, Shadow, Alignment, MarginL, MarginR, MarginV, Encoding\n\
+         Style: Default,Arial,20,&H00FFFFFF,&H000000FF,&H00000000,&H00000000,0,0,0,0,100,100,0,0,1,2,0,2,10,10,10,1\n\
+         Style: Sign,Arial,30,&H00FFFFFF,&H000000FF,&H00000000,&H00000000,0,0,0,0,100,100,0,0,1,2,0,8,10,10,10,1\n\n\
+         [Events]\n\
+         Format: Layer, Start, End, Style, Name, MarginL, MarginR, MarginV, Effect, Text\n",
+    );
+    for i in 0..50 {
+        let style = if i % 2 == 0 { "Default" } else { "Sign" };
+        let text = if i % 5 == 0 {
+            format!("{{\\rSign}}Line {i} {{\\i1\\rDefault}}back")
+        } else {
+            format!("Line {i}, with Default in the text")
+        };
+        content.push_str(&format!(
+            "Dialogue: 0,0:00:{:02}.00,0:00:{:02}.50,{style},Default,0,0,0,,{text}\n",
+            i, i
+        ));
+    }
+    content
+}
+
+#[test]
+fn test_rename_style_updates_all_references() {
+    let original = fifty_event_script();
+    let mut doc = EditorDocument::from_content(&original).unwrap();
+
+    let command = StyleRenameCommand::new("Default".to_string(), "Dialogue".to_string());
+    let result = doc.execute_command(&command).unwrap();
+    assert!(result.success);
+    assert!(result.content_changed);
+
+    let text = doc.text();
+    assert!(text.contains("Style: Dialogue,Arial,20"));
+    assert!(!text.contains("Style: Default"));
+    assert!(!text.contains(",Default,Default,"));
+    assert!(!text.contains("\\rDefault"));
+    assert_eq!(text.matches(",Dialogue,Default,").count(), 25);
+    assert_eq!(text.matches("\\rDialogue}").count(), 10);
+    // Actor names and dialogue text are not style references
+    assert_eq!(text.matches("with Default in the text").count(), 40);
+    doc.validate().unwrap();
+
+    // The whole rename is one undo step
+    doc.undo().unwrap();
+    assert_eq!(doc.text(), original);
+}
+
+#[test]
+fn test_rename_style_rejects_conflicts() {
+    let mut doc = EditorDocument::from_content(&fifty_event_script()).unwrap();
+
+    let taken = StyleRenameCommand::new("Default".to_string(), "Sign".to_string());
+    assert!(taken.execute(&mut doc).is_err());
+
+    let missing = StyleRenameCommand::new("Missing".to_string(), "Other".to_string());
+    assert!(missing.execute(&mut doc).is_err());
+
+    let invalid = StyleRenameCommand::new("Default".to_string(), "A,B".to_string());
+    assert!(invalid.execute(&mut doc).is_err());
+}
+
+#[test]
+fn test_merge_style_remaps_and_deletes_source() {
+    let original = fifty_event_script();
+    let mut doc = EditorDocument::from_content(&original).unwrap();
+
+    let command = StyleMergeCommand::new("Sign".to_string(), "Default".to_string());
+    let result = doc.execute_command(&command).unwrap();
+    assert!(result.success);
+
+    let text = doc.text();
+    assert!(!text.contains("Style: Sign"));
+    assert!(!text.contains(",Sign,"));
+    assert!(!text.contains("\\rSign"));
+    assert_eq!(text.matches(",Default,Default,").count(), 50);
+    doc.validate().unwrap();
+
+    doc.undo().unwrap();
+    assert_eq!(doc.text(), original);
+
+    let itself = StyleMergeCommand::new("Sign".to_string(), "Sign".to_string());
+    assert!(itself.execute(&mut doc).is_err());
+}
+
+#[test]
+fn test_rename_style_follows_events_format() {
+    let original = r#"[V4+ Styles]
+Format: Name, Fontname, Fontsize, PrimaryColour, SecondaryColour, OutlineColour, BackColour, Bold, Italic, Underline, StrikeOut, ScaleX, ScaleY, Spacing, Angle, BorderStyle, Outline, Shadow, Alignment, MarginL, MarginR, MarginV, Encoding
+Style: Default,Arial,20,&H00FFFFFF,&H000000FF,&H00000000,&H00000000,0,0,0,0,100,100,0,0,1,2,0,2,10,10,10,1
+
+[Events]
+Format: Layer, Start, End, Name, Style, Text
+Dialogue: 0,0:00:01.00,0:00:05.00,Default,Default,Hi, {\rDefault}there
+"#;
+    let mut doc = EditorDocument::from_content(original).unwrap();
+
+    let command = StyleRenameCommand::new("Default".to_string(), "Main".to_string());
+    doc.execute_command(&command).unwrap();
+
+    assert!(doc
+        .text()
+        .contains("Dialogue: 0,0:00:01.00,0:00:05.00,Default,Main,Hi, {\\rMain}there"));
+}
@@ -2,7 +2,7 @@
 
 use crate::core::{EditorDocument, Position, Range, Result};
 
-use super::{CommandResult, EditorCommand};
+use super::{CommandResult, EditorCommand, RecordedCommand};
 
 #[cfg(not(feature = "std"))]
 use alloc::string::String;
@@ -63,6 +63,13 @@ impl EditorCommand for InsertTextCommand {
         self.description.as_deref().unwrap_or("Insert text")
     }
 
+    fn to_recorded(&self) -> Option<RecordedCommand> {
+        Some(RecordedCommand::InsertText {
+            position: self.position,
+            text: self.text.clone(),
+        })
+    }
+
     fn memory_usage(&self) -> usize {
         core::mem::size_of::<Self>()
             + self.text.len()
@@ -109,6 +116,10 @@ impl EditorCommand for DeleteTextCommand {
     fn description(&self) -> &str {
         self.description.as_deref().unwrap_or("Delete text")
     }
+
+    fn to_recorded(&self) -> Option<RecordedCommand> {
+        Some(RecordedCommand::DeleteText { range: self.range })
+    }
 }
 
 /// Text replacement command
@@ -154,6 +165,13 @@ impl EditorCommand for ReplaceTextCommand {
         self.description.as_deref().unwrap_or("Replace text")
     }
 
+    fn to_recorded(&self) -> Option<RecordedCommand> {
+        Some(RecordedCommand::ReplaceText {
+            range: self.range,
+            new_text: self.new_text.clone(),
+        })
+    }
+
     fn memory_usage(&self) -> usize {
         core::mem::size_of::<Self>()
             + self.new_text.len()
@@ -4,6 +4,7 @@
 //! string, from disk), the persistence helpers, and the private `emit`
 //! helper shared with the editing submodules.
 
+use super::line_index::LineIndex;
 use super::EditorDocument;
 #[cfg(feature = "std")]
 use super::EventSender;
@@ -25,6 +26,7 @@ impl EditorDocument {
             text_rope: ropey::Rope::new(),
             #[cfg(not(feature = "rope"))]
             text_content: String::new(),
+            line_index: LineIndex::default(),
             id: Self::generate_id(),
             modified: false,
             file_path: None,
@@ -107,6 +109,17 @@ impl EditorDocument {
         self.event_tx.is_some()
     }
 
+    /// Send `event` on the document's event channel
+    ///
+    /// Returns `false` if the document has no channel or its receiver has
+    /// been dropped.
+    #[cfg(feature = "std")]
+    pub fn send_event(&self, event: DocumentEvent) -> bool {
+        self.event_tx
+            .as_ref()
+            .is_some_and(|tx| tx.send(event).is_ok())
+    }
+
     /// Load document from string content
     ///
     /// Creates a new `EditorDocument` from ASS subtitle content. The content
@@ -151,6 +164,7 @@ impl EditorDocument {
             text_rope: ropey::Rope::from_str(content),
             #[cfg(not(feature = "rope"))]
             text_content: content.to_string(),
+            line_index: LineIndex::new(content),
             id: Self::generate_id(),
             modified: false,
             file_path: None,
@@ -13,7 +13,7 @@ use ass_core::parser::script::ScriptDeltaOwned;
 use ass_core::parser::Script;
 
 #[cfg(not(feature = "std"))]
-use alloc::string::ToString;
+use alloc::{format, string::ToString};
 
 impl EditorDocument {
     /// Apply a script delta and record it with undo data
@@ -106,16 +106,10 @@ impl EditorDocument {
     /// Find the start offset of a section in the document
     fn find_section_start(&self, section: &Section) -> Result<usize> {
         // Get the section header text
-        let header = match section {
-            Section::ScriptInfo(_) => "[Script Info]",
-            Section::Styles(_) => "[V4+ Styles]",
-            Section::Events(_) => "[Events]",
-            Section::Fonts(_) => "[Fonts]",
-            Section::Graphics(_) => "[Graphics]",
-        };
+        let header = format!("[{}]", section.header_name());
 
         // Find the header in the document
-        if let Some(pos) = self.text().find(header) {
+        if let Some(pos) = self.text().find(&header) {
             Ok(pos)
         } else {
             Err(EditorError::SectionNotFound {
@@ -7,10 +7,12 @@
 use super::EditorDocument;
 use crate::core::errors::{EditorError, Result};
 use crate::core::position::{Position, Range};
-use ass_core::parser::ast::Section;
 
 #[cfg(not(feature = "std"))]
-use alloc::string::ToString;
+use alloc::{
+    format,
+    string::{String, ToString},
+};
 
 impl EditorDocument {
     // === Delta undo/redo helper methods ===
@@ -44,15 +46,9 @@ impl EditorDocument {
         let insert_pos = self.parse_script_with(|script| -> Result<usize> {
             if let Some(section) = script.sections().get(index) {
                 // Find the start of this section to insert before it
-                let header = match section {
-                    Section::ScriptInfo(_) => "[Script Info]",
-                    Section::Styles(_) => "[V4+ Styles]",
-                    Section::Events(_) => "[Events]",
-                    Section::Fonts(_) => "[Fonts]",
-                    Section::Graphics(_) => "[Graphics]",
-                };
-
-                if let Some(pos) = content.find(header) {
+                let header = format!("[{}]", section.header_name());
+
+                if let Some(pos) = content.find(&header) {
                     Ok(pos)
                 } else {
                     Err(EditorError::SectionNotFound {
@@ -88,15 +84,9 @@ impl EditorDocument {
         // Parse to find the section and get its boundaries
         // Get the section to replace
         let content = self.text();
-        let section_info: Result<Option<&str>> = self.parse_script_with(|script| {
+        let section_info: Result<Option<String>> = self.parse_script_with(|script| {
             if let Some(section) = script.sections().get(index) {
-                let header = match section {
-                    Section::ScriptInfo(_) => "[Script Info]",
-                    Section::Styles(_) => "[V4+ Styles]",
-                    Section::Events(_) => "[Events]",
-                    Section::Fonts(_) => "[Fonts]",
-                    Section::Graphics(_) => "[Graphics]",
-                };
+                let header = format!("[{}]", section.header_name());
                 Ok(Some(header))
             } else {
                 Ok(None)
@@ -104,7 +94,7 @@ impl EditorDocument {
         })?;
 
         if let Some(header) = section_info? {
-            let start = self.find_section_start_by_header(&content, header)?;
+            let start = self.find_section_start_by_header(&content, &header)?;
             let end = self.find_section_end_from_start(&content, start)?;
 
             self.replace_raw(
@@ -121,15 +111,9 @@ impl EditorDocument {
         // Parse to find the last section and get its boundaries
         // Get the last section
         let content = self.text();
-        let section_info: Result<Option<&str>> = self.parse_script_with(|script| {
+        let section_info: Result<Option<String>> = self.parse_script_with(|script| {
             if let Some(section) = script.sections().last() {
-                let header = match section {
-                    Section::ScriptInfo(_) => "[Script Info]",
-                    Section::Styles(_) => "[V4+ Styles]",
-                    Section::Events(_) => "[Events]",
-                    Section::Fonts(_) => "[Fonts]",
-                    Section::Graphics(_) => "[Graphics]",
-                };
+                let header = format!("[{}]", section.header_name());
                 Ok(Some(header))
             } else {
                 Ok(None)
@@ -137,7 +121,7 @@ impl EditorDocument {
         })?;
 
         if let Some(header) = section_info? {
-            let start = self.find_section_start_by_header(&content, header)?;
+            let start = self.find_section_start_by_header(&content, &header)?;
             let end = self.find_section_end_from_start(&content, start)?;
 
             self.delete_raw(Range::new(Position::new(start), Position::new(end)))?;
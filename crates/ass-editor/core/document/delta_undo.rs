@@ -11,6 +11,7 @@ use ass_core::parser::script::ScriptDeltaOwned;
 
 #[cfg(not(feature = "std"))]
 use alloc::{
+    format,
     string::{String, ToString},
     vec::Vec,
 };
@@ -78,17 +79,11 @@ impl EditorDocument {
 
     /// Extract section text from content
     fn extract_section_text(&self, content: &str, section: &Section) -> Result<String> {
-        let header = match section {
-            Section::ScriptInfo(_) => "[Script Info]",
-            Section::Styles(_) => "[V4+ Styles]",
-            Section::Events(_) => "[Events]",
-            Section::Fonts(_) => "[Fonts]",
-            Section::Graphics(_) => "[Graphics]",
-        };
+        let header = format!("[{}]", section.header_name());
 
         // Find the header in the content
         let start = content
-            .find(header)
+            .find(&header)
             .ok_or_else(|| EditorError::SectionNotFound {
                 section: header.to_string(),
             })?;
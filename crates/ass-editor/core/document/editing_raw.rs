@@ -1,7 +1,8 @@
 //! Low-level text mutation primitives without undo recording
 //!
-//! These `pub(crate)` helpers mutate the underlying rope/string directly and
-//! are the building blocks used by the higher-level editing and history APIs.
+//! These `pub(crate)` helpers mutate the underlying rope/string directly,
+//! keeping the line index in step, and are the building blocks used by the
+//! higher-level editing and history APIs.
 
 use super::EditorDocument;
 use crate::core::errors::{EditorError, Result};
@@ -27,6 +28,7 @@ impl EditorDocument {
         {
             self.text_content.insert_str(pos.offset, text);
         }
+        self.line_index.insert(pos.offset, text);
 
         self.modified = true;
         Ok(())
@@ -54,6 +56,7 @@ impl EditorDocument {
             self.text_content
                 .drain(range.start.offset..range.end.offset);
         }
+        self.line_index.delete(range.start.offset, range.end.offset);
 
         self.modified = true;
         Ok(())
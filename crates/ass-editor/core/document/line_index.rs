@@ -0,0 +1,180 @@
+//! Cached line index and line/column coordinates
+//!
+//! `LineIndex` keeps the byte offset of every newline in the document so
+//! line lookups are a binary search instead of a scan. The raw editing
+//! primitives update it incrementally, touching only the newlines at or
+//! after the edit. Lines and columns are 1-indexed like [`LineColumn`],
+//! with columns counted in characters.
+//!
+//! [`LineColumn`]: crate::core::position::LineColumn
+
+use super::EditorDocument;
+use crate::core::position::{Position, Range};
+
+#[cfg(not(feature = "std"))]
+use alloc::{string::String, vec::Vec};
+
+/// Byte offsets of the newlines in a document, ascending
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub(super) struct LineIndex {
+    /// Offset of each `\n`
+    newlines: Vec<usize>,
+}
+
+impl LineIndex {
+    /// Index the newlines of `text`
+    pub(super) fn new(text: &str) -> Self {
+        Self {
+            newlines: newline_offsets(text, 0).collect(),
+        }
+    }
+
+    /// Record `text` inserted at byte `offset`
+    pub(super) fn insert(&mut self, offset: usize, text: &str) {
+        let at = self.newlines.partition_point(|&newline| newline < offset);
+        for newline in &mut self.newlines[at..] {
+            *newline += text.len();
+        }
+        self.newlines.splice(at..at, newline_offsets(text, offset));
+    }
+
+    /// Record the bytes in `start..end` deleted
+    pub(super) fn delete(&mut self, start: usize, end: usize) {
+        let from = self.newlines.partition_point(|&newline| newline < start);
+        let to = self.newlines.partition_point(|&newline| newline < end);
+        self.newlines.drain(from..to);
+        for newline in &mut self.newlines[from..] {
+            *newline -= end - start;
+        }
+    }
+
+    /// Number of lines, one more than the number of newlines
+    pub(super) fn line_count(&self) -> usize {
+        self.newlines.len() + 1
+    }
+
+    /// 1-indexed line containing byte `offset`
+    fn line_of(&self, offset: usize) -> usize {
+        self.newlines.partition_point(|&newline| newline < offset) + 1
+    }
+
+    /// Byte offset where 1-indexed `line` starts
+    fn line_start(&self, line: usize) -> usize {
+        match line {
+            0 | 1 => 0,
+            _ => self.newlines[line - 2] + 1,
+        }
+    }
+}
+
+/// Offsets of the newlines in `text`, shifted by `base`
+fn newline_offsets(text: &str, base: usize) -> impl Iterator<Item = usize> + '_ {
+    text.bytes()
+        .enumerate()
+        .filter(|&(_, byte)| byte == b'\n')
+        .map(move |(index, _)| base + index)
+}
+
+/// Whether `byte` starts a UTF-8 character
+const fn is_char_start(byte: u8) -> bool {
+    byte & 0xC0 != 0x80
+}
+
+impl EditorDocument {
+    /// Number of lines in the document
+    ///
+    /// A trailing newline starts a final empty line, so `"a\n"` has two.
+    #[must_use]
+    pub fn line_count(&self) -> usize {
+        self.line_index.line_count()
+    }
+
+    /// Byte range of 1-indexed `line`, without its line ending
+    ///
+    /// Both `\n` and `\r\n` endings are excluded.
+    #[must_use]
+    pub fn line_range(&self, line: usize) -> Option<Range> {
+        if line == 0 || line > self.line_count() {
+            return None;
+        }
+        let start = self.line_index.line_start(line);
+        let mut end = self
+            .line_index
+            .newlines
+            .get(line - 1)
+            .copied()
+            .unwrap_or_else(|| self.len_bytes());
+        if end > start && self.bytes_from(end - 1).next() == Some(b'\r') {
+            end -= 1;
+        }
+        Some(Range::new(Position::new(start), Position::new(end)))
+    }
+
+    /// Text of 1-indexed `line`, without its line ending
+    #[must_use]
+    pub fn line_text(&self, line: usize) -> Option<String> {
+        self.text_range(self.line_range(line)?).ok()
+    }
+
+    /// Document bytes from `offset` onwards
+    fn bytes_from(&self, offset: usize) -> impl Iterator<Item = u8> + '_ {
+        #[cfg(feature = "rope")]
+        {
+            self.text_rope.bytes_at(offset)
+        }
+        #[cfg(not(feature = "rope"))]
+        {
+            self.text_content.as_bytes()[offset..].iter().copied()
+        }
+    }
+}
+
+impl Position {
+    /// Position of 1-indexed `line` and character column `col` in `doc`
+    ///
+    /// Column `n + 1` on a line of `n` characters is its end. Returns `None`
+    /// for lines or columns past the document.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ass_editor::{EditorDocument, Position};
+    ///
+    /// let doc = EditorDocument::from_content("[Script Info]\nTitle: Café").unwrap();
+    /// let pos = Position::from_line_col(&doc, 2, 12).unwrap();
+    /// assert_eq!(pos.offset, doc.len_bytes());
+    /// assert_eq!(pos.to_line_col(&doc), (2, 12));
+    /// ```
+    #[must_use]
+    pub fn from_line_col(doc: &EditorDocument, line: usize, col: usize) -> Option<Self> {
+        let range = doc.line_range(line)?;
+        let (start, end) = (range.start.offset, range.end.offset);
+        let mut chars = 0;
+        for (index, byte) in doc.bytes_from(start).take(end - start).enumerate() {
+            if is_char_start(byte) {
+                chars += 1;
+                if chars == col {
+                    return Some(Self::new(start + index));
+                }
+            }
+        }
+        (col == chars + 1).then(|| Self::new(end))
+    }
+
+    /// 1-indexed line and character column of this position in `doc`
+    ///
+    /// Offsets past the end of the document are clamped to it, and an offset
+    /// inside a multi-byte character counts that character as passed.
+    #[must_use]
+    pub fn to_line_col(self, doc: &EditorDocument) -> (usize, usize) {
+        let offset = self.offset.min(doc.len_bytes());
+        let line = doc.line_index.line_of(offset);
+        let start = doc.line_index.line_start(line);
+        let col = doc
+            .bytes_from(start)
+            .take(offset - start)
+            .filter(|&byte| is_char_start(byte))
+            .count();
+        (line, col + 1)
+    }
+}
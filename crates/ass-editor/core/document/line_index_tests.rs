@@ -0,0 +1,135 @@
+//! Tests for the cached line index and line/column coordinates
+
+use super::*;
+use crate::core::position::{Position, Range};
+
+#[cfg(not(feature = "std"))]
+use alloc::{string::String, vec::Vec};
+
+const SCRIPT: &str = "[Script Info]
+Title: 日本語のテスト
+
+[Events]
+Format: Layer, Start, End, Style, Name, MarginL, MarginR, MarginV, Effect, Text
+Dialogue: 0,0:00:01.00,0:00:02.00,Default,,0,0,0,,Ünïcödé 🎬 text
+";
+
+/// Every line of `doc` read through the line API
+fn lines(doc: &EditorDocument) -> Vec<String> {
+    (1..=doc.line_count())
+        .map(|line| doc.line_text(line).unwrap())
+        .collect()
+}
+
+/// Assert the line API agrees with splitting the document text
+fn assert_lines_match(doc: &EditorDocument) {
+    let expected: Vec<String> = doc.text().split('\n').map(String::from).collect();
+    assert_eq!(lines(doc), expected);
+}
+
+#[test]
+fn line_queries_on_unicode_script() {
+    let doc = EditorDocument::from_content(SCRIPT).unwrap();
+
+    assert_eq!(doc.line_count(), 7);
+    assert_eq!(doc.line_text(2).as_deref(), Some("Title: 日本語のテスト"));
+    assert_eq!(doc.line_text(3).as_deref(), Some(""));
+    assert_eq!(doc.line_text(7).as_deref(), Some(""));
+    assert_eq!(doc.line_text(0), None);
+    assert_eq!(doc.line_text(8), None);
+
+    let range = doc.line_range(2).unwrap();
+    assert_eq!(range.start.offset, "[Script Info]\n".len());
+    assert_eq!(range.len(), "Title: 日本語のテスト".len());
+    assert_lines_match(&doc);
+}
+
+#[test]
+fn line_col_round_trip_with_multibyte_characters() {
+    let doc = EditorDocument::from_content(SCRIPT).unwrap();
+
+    // Column 8 of line 2 is the first CJK character
+    let pos = Position::from_line_col(&doc, 2, 8).unwrap();
+    assert_eq!(doc.text()[pos.offset..].chars().next(), Some('日'));
+    assert_eq!(pos.to_line_col(&doc), (2, 8));
+
+    let dialogue = doc.line_text(6).unwrap();
+    let emoji_col = dialogue.chars().position(|c| c == '🎬').unwrap() + 1;
+    let pos = Position::from_line_col(&doc, 6, emoji_col).unwrap();
+    assert_eq!(doc.text()[pos.offset..].chars().next(), Some('🎬'));
+    assert_eq!(pos.to_line_col(&doc), (6, emoji_col));
+
+    // One past the last character is the end of the line, further is not
+    let end_col = dialogue.chars().count() + 1;
+    assert_eq!(
+        Position::from_line_col(&doc, 6, end_col),
+        Some(doc.line_range(6).unwrap().end)
+    );
+    assert_eq!(Position::from_line_col(&doc, 6, end_col + 1), None);
+    assert_eq!(Position::from_line_col(&doc, 6, 0), None);
+
+    // Every character start converts back to the same position
+    let text = doc.text();
+    for (offset, _) in text.char_indices() {
+        let (line, col) = Position::new(offset).to_line_col(&doc);
+        assert_eq!(
+            Position::from_line_col(&doc, line, col),
+            Some(Position::new(offset))
+        );
+        assert_eq!(
+            doc.position_to_line_column(Position::new(offset)).unwrap(),
+            crate::core::position::LineColumn::new(line, col).unwrap()
+        );
+    }
+    assert_eq!(Position::new(usize::MAX).to_line_col(&doc), (7, 1));
+}
+
+#[test]
+fn line_index_follows_edits_and_undo() {
+    let mut doc = EditorDocument::from_content(SCRIPT).unwrap();
+
+    let title_end = doc.line_range(2).unwrap().end;
+    doc.insert(title_end, "\nScriptType: v4.00+\n; コメント")
+        .unwrap();
+    assert_eq!(doc.line_count(), 9);
+    assert_eq!(doc.line_text(4).as_deref(), Some("; コメント"));
+    assert_lines_match(&doc);
+
+    // Join the two header lines by deleting across a newline
+    let first = doc.line_range(1).unwrap();
+    let second = doc.line_range(2).unwrap();
+    doc.delete(Range::new(first.end, second.start.advance(7)))
+        .unwrap();
+    assert_eq!(
+        doc.line_text(1).as_deref(),
+        Some("[Script Info]日本語のテスト")
+    );
+    assert_lines_match(&doc);
+
+    let last = doc.line_range(doc.line_count() - 1).unwrap();
+    doc.replace(
+        last,
+        "Dialogue: 0,0:00:03.00,0:00:04.00,Default,,0,0,0,,新しい\n行",
+    )
+    .unwrap();
+    assert_lines_match(&doc);
+
+    while doc.can_undo() {
+        doc.undo().unwrap();
+        assert_lines_match(&doc);
+    }
+    assert_eq!(doc.text(), SCRIPT);
+    assert_eq!(doc.line_count(), 7);
+}
+
+#[test]
+fn line_range_excludes_crlf_endings() {
+    let doc = EditorDocument::from_content("[Script Info]\r\nTitle: Ça\r\n").unwrap();
+
+    assert_eq!(doc.line_count(), 3);
+    assert_eq!(doc.line_text(1).as_deref(), Some("[Script Info]"));
+    assert_eq!(doc.line_text(2).as_deref(), Some("Title: Ça"));
+    let end = Position::from_line_col(&doc, 2, 10).unwrap();
+    assert_eq!(end, doc.line_range(2).unwrap().end);
+    assert_eq!(Position::new(end.offset + 1).to_line_col(&doc), (2, 11));
+}
@@ -11,8 +11,10 @@ mod editing_raw;
 mod event_edit;
 mod event_line;
 mod incremental_parse;
+mod line_index;
 mod metadata;
 mod position_api;
+mod script_info_editor;
 mod section_lines;
 mod text_access;
 mod types;
@@ -40,6 +42,10 @@ mod event_builder_tests;
 #[cfg(test)]
 mod event_index_tests;
 #[cfg(test)]
+mod line_index_tests;
+#[cfg(test)]
+mod script_info_editor_tests;
+#[cfg(test)]
 mod undo_tests;
 #[cfg(test)]
 mod validator_tests;
@@ -48,6 +54,7 @@ mod validator_tests;
 mod plugin_tests;
 
 pub use position_api::DocumentPosition;
+pub use script_info_editor::ScriptInfoEditor;
 pub use types::EditorDocument;
 
 #[cfg(feature = "std")]
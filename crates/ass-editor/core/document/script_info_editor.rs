@@ -0,0 +1,246 @@
+//! Typed, batched editing of `[Script Info]` fields
+//!
+//! [`ScriptInfoEditor`] collects field changes and applies them as a single
+//! `BatchCommand` when it is committed or dropped, so a group of edits undoes
+//! and redoes as one step and emits one `TextReplaced` event.
+
+use super::EditorDocument;
+use crate::commands::{BatchCommand, InsertTextCommand, ReplaceTextCommand};
+use crate::core::errors::Result;
+use crate::core::position::{Position, Range};
+use ass_core::parser::ast::WrapMode;
+
+#[cfg(feature = "std")]
+use super::validation::text_change;
+#[cfg(feature = "std")]
+use crate::core::history::Operation;
+#[cfg(feature = "std")]
+use crate::events::DocumentEvent;
+
+#[cfg(not(feature = "std"))]
+use alloc::{
+    boxed::Box,
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
+
+/// Guard for editing `[Script Info]` fields of an [`EditorDocument`]
+///
+/// Setters only record the new values; they are written to the document by
+/// [`commit`](Self::commit) or, if it is never called, when the guard is
+/// dropped. Existing field lines are rewritten in place, new fields are
+/// appended to the section, and the section is created when missing.
+///
+/// # Examples
+///
+/// ```
+/// use ass_editor::EditorDocument;
+///
+/// let mut doc = EditorDocument::from_content("[Script Info]\nPlayResX: 384\nPlayResY: 288\n")?;
+/// doc.script_info_mut()
+///     .set_title("Demo")
+///     .set_play_res(1920, 1080);
+///
+/// assert_eq!(doc.play_res(), (1920, 1080));
+/// doc.undo()?;
+/// assert_eq!(doc.play_res(), (384, 288));
+/// # Ok::<(), ass_editor::EditorError>(())
+/// ```
+pub struct ScriptInfoEditor<'a> {
+    document: &'a mut EditorDocument,
+    pending: Vec<(String, String)>,
+}
+
+impl<'a> ScriptInfoEditor<'a> {
+    /// Create an editor with no pending changes
+    fn new(document: &'a mut EditorDocument) -> Self {
+        Self {
+            document,
+            pending: Vec::new(),
+        }
+    }
+
+    /// Set the `Title` field
+    pub fn set_title(&mut self, title: &str) -> &mut Self {
+        self.custom_field("Title", title)
+    }
+
+    /// Set the `PlayResX` and `PlayResY` fields
+    pub fn set_play_res(&mut self, width: u32, height: u32) -> &mut Self {
+        self.custom_field("PlayResX", &width.to_string())
+            .custom_field("PlayResY", &height.to_string())
+    }
+
+    /// Set the `WrapStyle` field
+    pub fn set_wrap_style(&mut self, wrap_style: WrapMode) -> &mut Self {
+        self.custom_field("WrapStyle", &wrap_style.value().to_string())
+    }
+
+    /// Set the `ScaledBorderAndShadow` field to `yes` or `no`
+    pub fn set_scaled_border_and_shadow(&mut self, scaled: bool) -> &mut Self {
+        self.custom_field("ScaledBorderAndShadow", if scaled { "yes" } else { "no" })
+    }
+
+    /// Set any field by key, replacing an earlier pending value for it
+    pub fn custom_field(&mut self, key: &str, value: &str) -> &mut Self {
+        match self.pending.iter_mut().find(|(pending, _)| pending == key) {
+            Some((_, pending_value)) => *pending_value = value.to_string(),
+            None => self.pending.push((key.to_string(), value.to_string())),
+        }
+        self
+    }
+
+    /// Write the pending changes to the document as one undoable batch
+    ///
+    /// Dropping the guard commits as well, but discards any error.
+    pub fn commit(&mut self) -> Result<()> {
+        if self.pending.is_empty() {
+            return Ok(());
+        }
+
+        let before = self.document.text();
+        let batch = script_info_batch(&before, core::mem::take(&mut self.pending));
+        self.document.execute_command(&batch)?;
+
+        #[cfg(feature = "std")]
+        if let Some(operation) = text_change(&before, &self.document.text()) {
+            let (range, old_text, new_text) = match operation {
+                Operation::Insert { position, text } => {
+                    (Range::new(position, position), String::new(), text)
+                }
+                Operation::Delete {
+                    range,
+                    deleted_text,
+                } => (range, deleted_text, String::new()),
+                Operation::Replace {
+                    range,
+                    old_text,
+                    new_text,
+                } => (range, old_text, new_text),
+                #[cfg(feature = "stream")]
+                Operation::Delta { .. } => return Ok(()),
+                Operation::Group { .. } => return Ok(()),
+            };
+            self.document.emit(DocumentEvent::TextReplaced {
+                range,
+                old_text,
+                new_text,
+            });
+        }
+        Ok(())
+    }
+}
+
+impl Drop for ScriptInfoEditor<'_> {
+    fn drop(&mut self) {
+        let _ = self.commit();
+    }
+}
+
+/// Build the commands that write `fields` into the `[Script Info]` of `text`
+///
+/// Each command's position accounts for the edits before it in the batch.
+fn script_info_batch(text: &str, fields: Vec<(String, String)>) -> BatchCommand {
+    let mut text = text.to_string();
+    let mut batch = BatchCommand::new("Edit script info".to_string());
+
+    for (key, value) in fields {
+        let line = format!("{key}: {value}");
+        let (section_start, section_end) = match script_info_body(&text) {
+            Some(body) => body,
+            None => {
+                let section = if text.is_empty() {
+                    "[Script Info]\n".to_string()
+                } else {
+                    "[Script Info]\n\n".to_string()
+                };
+                batch = batch.add_command(Box::new(InsertTextCommand::new(
+                    Position::new(0),
+                    section.clone(),
+                )));
+                text.insert_str(0, &section);
+                let header_end = "[Script Info]".len();
+                (header_end, header_end)
+            }
+        };
+
+        match find_field_line(&text, section_start, section_end, &key) {
+            Some((start, end)) => {
+                let range = Range::new(Position::new(start), Position::new(end));
+                batch = batch.add_command(Box::new(ReplaceTextCommand::new(range, line.clone())));
+                text.replace_range(start..end, &line);
+            }
+            None => {
+                // Append after the last non-blank line of the section
+                let position = text[..section_end].trim_end().len();
+                let position = position.max(section_start);
+                let inserted = format!("\n{line}");
+                batch = batch.add_command(Box::new(InsertTextCommand::new(
+                    Position::new(position),
+                    inserted.clone(),
+                )));
+                text.insert_str(position, &inserted);
+            }
+        }
+    }
+    batch
+}
+
+/// Byte range of the `[Script Info]` fields, from the end of the header
+/// line up to the next section header or the end of the text
+fn script_info_body(text: &str) -> Option<(usize, usize)> {
+    let mut offset = 0;
+    let mut start = None;
+    for raw in text.split_inclusive('\n') {
+        let trimmed = raw.trim();
+        if trimmed.starts_with('[') && trimmed.ends_with(']') {
+            if start.is_some() {
+                return start.map(|start| (start, offset));
+            }
+            if trimmed.eq_ignore_ascii_case("[Script Info]") {
+                start = Some(offset + raw.trim_end_matches(['\n', '\r']).len());
+            }
+        }
+        offset += raw.len();
+    }
+    start.map(|start| (start, text.len()))
+}
+
+/// Byte range of the `key: value` line in `text[start..end]`, without its
+/// line ending
+fn find_field_line(text: &str, start: usize, end: usize, key: &str) -> Option<(usize, usize)> {
+    let mut offset = start;
+    for raw in text[start..end].split_inclusive('\n') {
+        let line = raw.trim_end_matches(['\n', '\r']);
+        let is_field = !line.trim_start().starts_with(';')
+            && line
+                .split_once(':')
+                .is_some_and(|(name, _)| name.trim() == key);
+        if is_field {
+            let line_start = offset + (line.len() - line.trim_start().len());
+            return Some((line_start, offset + line.len()));
+        }
+        offset += raw.len();
+    }
+    None
+}
+
+impl EditorDocument {
+    /// Edit `[Script Info]` fields through a batching guard
+    ///
+    /// All changes made through the returned [`ScriptInfoEditor`] form one
+    /// undo step and are applied when it is committed or dropped.
+    pub fn script_info_mut(&mut self) -> ScriptInfoEditor<'_> {
+        ScriptInfoEditor::new(self)
+    }
+
+    /// Script resolution from `PlayResX`/`PlayResY`
+    ///
+    /// Missing values are derived the way libass does, defaulting to
+    /// 384x288 when neither is set or the document does not parse.
+    pub fn play_res(&self) -> (u32, u32) {
+        self.parse_script_with(|script| script.info().play_resolution())
+            .unwrap_or((384, 288))
+    }
+}
@@ -0,0 +1,91 @@
+//! Tests for batched `[Script Info]` editing through `ScriptInfoEditor`
+
+use super::*;
+use ass_core::parser::ast::WrapMode;
+
+const SCRIPT: &str = "[Script Info]\nTitle: Old\nPlayResX: 640\nPlayResY: 480\n\n[Events]\nFormat: Layer, Start, End, Style, Name, MarginL, MarginR, MarginV, Effect, Text\n";
+
+#[test]
+fn play_res_edit_is_one_redoable_step() {
+    let mut doc = EditorDocument::from_content(SCRIPT).unwrap();
+    assert_eq!(doc.play_res(), (640, 480));
+
+    doc.script_info_mut().set_play_res(1920, 1080);
+    assert_eq!(doc.play_res(), (1920, 1080));
+    assert!(doc.text().contains("PlayResX: 1920\nPlayResY: 1080\n"));
+
+    doc.undo().unwrap();
+    assert_eq!(doc.text(), SCRIPT);
+    assert!(doc.can_redo());
+
+    doc.redo().unwrap();
+    assert_eq!(doc.play_res(), (1920, 1080));
+    assert!(doc.can_undo() && !doc.can_redo());
+}
+
+#[test]
+fn serialization_reflects_new_and_existing_fields() {
+    let mut doc = EditorDocument::from_content(SCRIPT).unwrap();
+    doc.script_info_mut()
+        .set_title("New")
+        .set_wrap_style(WrapMode::NoWrap)
+        .set_scaled_border_and_shadow(true)
+        .custom_field("YCbCr Matrix", "TV.709")
+        .commit()
+        .unwrap();
+
+    let serialized = doc
+        .parse_script_with(|script| script.to_ass_string())
+        .unwrap();
+    assert!(serialized.contains("Title: New\n"));
+    assert!(serialized.contains("WrapStyle: 2\n"));
+    assert!(serialized.contains("ScaledBorderAndShadow: yes\n"));
+    assert!(serialized.contains("YCbCr Matrix: TV.709\n"));
+    assert!(!serialized.contains("Title: Old"));
+    // New fields stay inside the section, before the blank separator line
+    assert!(doc.text().contains("YCbCr Matrix: TV.709\n\n[Events]"));
+}
+
+#[test]
+fn missing_section_is_created() {
+    let mut doc = EditorDocument::from_content("[Events]\n").unwrap();
+    doc.script_info_mut().set_play_res(1280, 720);
+    assert_eq!(
+        doc.text(),
+        "[Script Info]\nPlayResX: 1280\nPlayResY: 720\n\n[Events]\n"
+    );
+    assert_eq!(doc.play_res(), (1280, 720));
+}
+
+#[test]
+fn empty_guard_leaves_history_untouched() {
+    let mut doc = EditorDocument::from_content(SCRIPT).unwrap();
+    doc.script_info_mut();
+    assert!(!doc.can_undo());
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn commit_emits_text_replaced() {
+    use crate::events::DocumentEvent;
+
+    let (tx, rx) = std::sync::mpsc::channel();
+    let mut doc = EditorDocument::from_content(SCRIPT).unwrap();
+    doc.set_event_channel(tx);
+
+    doc.script_info_mut()
+        .set_title("New")
+        .set_play_res(800, 600);
+
+    let events: Vec<_> = rx.try_iter().collect();
+    assert_eq!(events.len(), 1);
+    match &events[0] {
+        DocumentEvent::TextReplaced {
+            old_text, new_text, ..
+        } => {
+            assert!(old_text.starts_with("Old"));
+            assert!(new_text.starts_with("New"));
+        }
+        other => panic!("unexpected event {other:?}"),
+    }
+}
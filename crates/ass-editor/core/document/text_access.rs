@@ -102,39 +102,7 @@ impl EditorDocument {
         }
     }
 
-    /// Convert byte position to line/column
-    #[cfg(feature = "rope")]
-    pub fn position_to_line_column(&self, pos: Position) -> Result<LineColumn> {
-        if pos.offset > self.len_bytes() {
-            return Err(EditorError::PositionOutOfBounds {
-                position: pos.offset,
-                length: self.len_bytes(),
-            });
-        }
-
-        let line_idx = self.text_rope.byte_to_line(pos.offset);
-        let line_start = self.text_rope.line_to_byte(line_idx);
-        let col_offset = pos.offset - line_start;
-
-        // Convert byte offset to character offset within line
-        let line = self.text_rope.line(line_idx);
-        let mut char_col = 0;
-        let mut byte_count = 0;
-
-        for ch in line.chars() {
-            if byte_count >= col_offset {
-                break;
-            }
-            byte_count += ch.len_utf8();
-            char_col += 1;
-        }
-
-        // Convert to 1-indexed
-        LineColumn::new(line_idx + 1, char_col + 1)
-    }
-
-    /// Convert byte position to line/column (without rope)
-    #[cfg(not(feature = "rope"))]
+    /// Convert byte position to 1-indexed line and character column
     pub fn position_to_line_column(&self, pos: Position) -> Result<LineColumn> {
         if pos.offset > self.len_bytes() {
             return Err(EditorError::PositionOutOfBounds {
@@ -143,25 +111,7 @@ impl EditorDocument {
             });
         }
 
-        let mut line = 1;
-        let mut col = 1;
-        let mut byte_pos = 0;
-
-        for ch in self.text_content.chars() {
-            if byte_pos >= pos.offset {
-                break;
-            }
-
-            if ch == '\n' {
-                line += 1;
-                col = 1;
-            } else {
-                col += 1;
-            }
-
-            byte_pos += ch.len_utf8();
-        }
-
-        LineColumn::new(line, col)
+        let (line, column) = pos.to_line_col(self);
+        LineColumn::new(line, column)
     }
 }
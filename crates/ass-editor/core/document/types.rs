@@ -36,6 +36,9 @@ pub struct EditorDocument {
     #[cfg(not(feature = "rope"))]
     pub(super) text_content: String,
 
+    /// Newline offsets for line lookups, kept in step with the text
+    pub(super) line_index: super::line_index::LineIndex,
+
     /// Document identifier for session management
     pub(super) id: String,
 
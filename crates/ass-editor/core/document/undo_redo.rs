@@ -1,13 +1,14 @@
 //! Undo/redo execution and history manager access
 //!
 //! Implements reversing and replaying recorded operations (including
-//! stream-feature delta operations) and exposes the underlying
-//! `UndoManager` for configuration and inspection.
+//! stream-feature delta operations and squashed groups), switching between
+//! history branches, and exposes the underlying `UndoManager` for
+//! configuration and inspection.
 
 use super::EditorDocument;
 use crate::commands::CommandResult;
 use crate::core::errors::{EditorError, Result};
-use crate::core::history::UndoManager;
+use crate::core::history::{ForkPath, Operation, UndoManager};
 use crate::core::position::{Position, Range};
 
 #[cfg(not(feature = "std"))]
@@ -19,62 +20,14 @@ impl EditorDocument {
     /// Retrieves the most recent operation from the undo stack and reverses it.
     /// If the operation includes a script delta, it will be applied for efficient updates.
     pub fn undo(&mut self) -> Result<CommandResult> {
-        use crate::core::history::Operation;
-
         // Pop from undo stack
         if let Some(entry) = self.history.pop_undo_entry() {
             let mut result = CommandResult::success();
             result.content_changed = true;
 
             // Execute the inverse of the operation
-            match &entry.operation {
-                Operation::Insert { position, text } => {
-                    // Undo insert by deleting the inserted text
-                    let end_pos = Position::new(position.offset + text.len());
-                    let range = Range::new(*position, end_pos);
-                    self.delete_raw(range)?;
-                    result.modified_range = Some(Range::new(*position, *position));
-                    result.new_cursor = entry.cursor_before;
-                }
-                Operation::Delete {
-                    range,
-                    deleted_text,
-                } => {
-                    // Undo delete by inserting the deleted text
-                    self.insert_raw(range.start, deleted_text)?;
-                    let end_pos = Position::new(range.start.offset + deleted_text.len());
-                    result.modified_range = Some(Range::new(range.start, end_pos));
-                    result.new_cursor = entry.cursor_before;
-                }
-                Operation::Replace {
-                    range, old_text, ..
-                } => {
-                    // Undo replace by restoring old text
-                    self.replace_raw(*range, old_text)?;
-                    let end_pos = Position::new(range.start.offset + old_text.len());
-                    result.modified_range = Some(Range::new(range.start, end_pos));
-                    result.new_cursor = entry.cursor_before;
-                }
-                #[cfg(feature = "stream")]
-                Operation::Delta { forward, undo_data } => {
-                    // Restore removed sections
-                    for (index, section_text) in undo_data.removed_sections.iter() {
-                        self.insert_section_at(*index, section_text)?;
-                    }
-
-                    // Restore modified sections
-                    for (index, original_text) in undo_data.modified_sections.iter() {
-                        self.replace_section(*index, original_text)?;
-                    }
-
-                    // Remove added sections
-                    for _ in 0..forward.added.len() {
-                        self.remove_last_section()?;
-                    }
-
-                    result.message = Some("Delta operation undone".to_string());
-                }
-            }
+            result.modified_range = self.revert_operation(&entry.operation)?;
+            result.new_cursor = entry.cursor_before;
 
             // Push to redo stack for future redo
             self.history.push_redo_entry(entry);
@@ -97,47 +50,14 @@ impl EditorDocument {
     /// Retrieves the most recent operation from the redo stack and re-executes it.
     /// If the operation includes a script delta, it will be applied for efficient updates.
     pub fn redo(&mut self) -> Result<CommandResult> {
-        use crate::core::history::Operation;
-
         // Pop from redo stack
         if let Some(entry) = self.history.pop_redo_entry() {
             let mut result = CommandResult::success();
             result.content_changed = true;
 
             // Re-execute the original operation
-            match &entry.operation {
-                Operation::Insert { position, text } => {
-                    // Redo insert
-                    self.insert_raw(*position, text)?;
-                    let end_pos = Position::new(position.offset + text.len());
-                    result.modified_range = Some(Range::new(*position, end_pos));
-                    result.new_cursor = entry.cursor_after;
-                }
-                Operation::Delete { range, .. } => {
-                    // Redo delete
-                    self.delete_raw(*range)?;
-                    result.modified_range = Some(Range::new(range.start, range.start));
-                    result.new_cursor = entry.cursor_after;
-                }
-                Operation::Replace {
-                    range, new_text, ..
-                } => {
-                    // Redo replace
-                    self.replace_raw(*range, new_text)?;
-                    let end_pos = Position::new(range.start.offset + new_text.len());
-                    result.modified_range = Some(Range::new(range.start, end_pos));
-                    result.new_cursor = entry.cursor_after;
-                }
-                #[cfg(feature = "stream")]
-                Operation::Delta {
-                    forward,
-                    undo_data: _,
-                } => {
-                    // Re-apply the delta
-                    self.apply_script_delta(forward.clone())?;
-                    result.message = Some("Delta re-applied".to_string());
-                }
-            }
+            result.modified_range = self.replay_operation(&entry.operation)?;
+            result.new_cursor = entry.cursor_after;
 
             // Record in history without using the public methods (to avoid recursion)
             // We need to manually update the history manager's cursor
@@ -145,16 +65,18 @@ impl EditorDocument {
                 self.history.set_cursor(Some(cursor));
             }
 
-            // Create a new history entry for the redo operation
-            let new_entry = crate::core::history::HistoryEntry::new(
+            // Create a new history entry for the redo operation, keeping its
+            // action id so branches forking from it still find it
+            let mut new_entry = crate::core::history::HistoryEntry::new(
                 entry.operation,
                 entry.description,
                 &result,
                 entry.cursor_before,
             );
+            new_entry.action_id = entry.action_id;
 
-            // Push back to undo stack
-            self.history.stack_mut().push(new_entry);
+            // Push back to undo stack, leaving the remaining redo entries
+            self.history.push_undo_entry(new_entry);
 
             result.message = Some("Redo successful".to_string());
             Ok(result)
@@ -163,6 +85,150 @@ impl EditorDocument {
         }
     }
 
+    /// Switch to history branch `branch_id` and replay it
+    ///
+    /// Undoes or redoes back to the point where the branch was cut off, makes
+    /// the branch the redo history (preserving the current one as a branch),
+    /// and redoes the whole branch, leaving the document as it was at the
+    /// branch's last edit. See [`UndoManager::branches`] for the available
+    /// branches.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ass_editor::{EditorDocument, Position};
+    ///
+    /// let mut doc = EditorDocument::new();
+    /// doc.insert(Position::new(0), "Hello").unwrap();
+    /// doc.undo().unwrap();
+    /// doc.insert(Position::new(0), "Goodbye").unwrap();
+    ///
+    /// let branch = doc.undo_manager().branches()[0].id;
+    /// doc.undo_branch_switch(branch).unwrap();
+    /// assert_eq!(doc.text(), "Hello");
+    /// ```
+    pub fn undo_branch_switch(&mut self, branch_id: usize) -> Result<()> {
+        match self.history.branch_fork_path(branch_id)? {
+            ForkPath::Undo(steps) => {
+                for _ in 0..steps {
+                    self.undo()?;
+                }
+            }
+            ForkPath::Redo(steps) => {
+                for _ in 0..steps {
+                    self.redo()?;
+                }
+            }
+        }
+        self.history.undo_branch_switch(branch_id)?;
+        while self.history.can_redo() {
+            self.redo()?;
+        }
+        Ok(())
+    }
+
+    /// Reverse `operation`, returning the range it now covers
+    fn revert_operation(&mut self, operation: &Operation) -> Result<Option<Range>> {
+        let range = match operation {
+            Operation::Insert { position, text } => {
+                // Undo insert by deleting the inserted text
+                let end_pos = Position::new(position.offset + text.len());
+                self.delete_raw(Range::new(*position, end_pos))?;
+                Some(Range::new(*position, *position))
+            }
+            Operation::Delete {
+                range,
+                deleted_text,
+            } => {
+                // Undo delete by inserting the deleted text
+                self.insert_raw(range.start, deleted_text)?;
+                let end_pos = Position::new(range.start.offset + deleted_text.len());
+                Some(Range::new(range.start, end_pos))
+            }
+            Operation::Replace {
+                range,
+                old_text,
+                new_text,
+            } => {
+                // Undo replace by restoring old text over the span the
+                // new text now occupies
+                let new_end = Position::new(range.start.offset + new_text.len());
+                self.replace_raw(Range::new(range.start, new_end), old_text)?;
+                let end_pos = Position::new(range.start.offset + old_text.len());
+                Some(Range::new(range.start, end_pos))
+            }
+            #[cfg(feature = "stream")]
+            Operation::Delta { forward, undo_data } => {
+                // Restore removed sections
+                for (index, section_text) in undo_data.removed_sections.iter() {
+                    self.insert_section_at(*index, section_text)?;
+                }
+
+                // Restore modified sections
+                for (index, original_text) in undo_data.modified_sections.iter() {
+                    self.replace_section(*index, original_text)?;
+                }
+
+                // Remove added sections
+                for _ in 0..forward.added.len() {
+                    self.remove_last_section()?;
+                }
+                None
+            }
+            Operation::Group { operations } => {
+                // Undo squashed operations newest first
+                let mut range = None;
+                for operation in operations.iter().rev() {
+                    range = self.revert_operation(operation)?;
+                }
+                range
+            }
+        };
+        Ok(range)
+    }
+
+    /// Re-apply `operation`, returning the range it covers
+    fn replay_operation(&mut self, operation: &Operation) -> Result<Option<Range>> {
+        let range = match operation {
+            Operation::Insert { position, text } => {
+                // Redo insert
+                self.insert_raw(*position, text)?;
+                let end_pos = Position::new(position.offset + text.len());
+                Some(Range::new(*position, end_pos))
+            }
+            Operation::Delete { range, .. } => {
+                // Redo delete
+                self.delete_raw(*range)?;
+                Some(Range::new(range.start, range.start))
+            }
+            Operation::Replace {
+                range, new_text, ..
+            } => {
+                // Redo replace
+                self.replace_raw(*range, new_text)?;
+                let end_pos = Position::new(range.start.offset + new_text.len());
+                Some(Range::new(range.start, end_pos))
+            }
+            #[cfg(feature = "stream")]
+            Operation::Delta {
+                forward,
+                undo_data: _,
+            } => {
+                // Re-apply the delta
+                self.apply_script_delta(forward.clone())?;
+                None
+            }
+            Operation::Group { operations } => {
+                let mut range = None;
+                for operation in operations {
+                    range = self.replay_operation(operation)?;
+                }
+                range
+            }
+        };
+        Ok(range)
+    }
+
     /// Check if undo is available
     pub fn can_undo(&self) -> bool {
         self.history.can_undo()
@@ -125,3 +125,14 @@ fn test_undo_redo_delete() {
     doc.redo().unwrap();
     assert!(!doc.text().contains("Author: John"));
 }
+
+#[test]
+fn test_undo_replace_with_longer_text() {
+    let mut doc = EditorDocument::from_content("[Script Info]\nTitle: Test").unwrap();
+    let start = doc.text().find("Test").unwrap();
+    let range = Range::new(Position::new(start), Position::new(start + 4));
+
+    doc.replace(range, "A Much Longer Title").unwrap();
+    doc.undo().unwrap();
+    assert_eq!(doc.text(), "[Script Info]\nTitle: Test");
+}
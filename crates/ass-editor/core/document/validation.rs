@@ -9,7 +9,7 @@ use crate::core::position::{Position, Range};
 use ass_core::parser::Script;
 
 #[cfg(not(feature = "std"))]
-use alloc::string::{String, ToString};
+use alloc::string::ToString;
 
 impl EditorDocument {
     /// Parse the current document content into a Script
@@ -40,76 +40,32 @@ impl EditorDocument {
     /// This method ensures that commands are properly recorded in the undo history.
     /// Use this instead of calling command.execute() directly if you want undo support.
     ///
-    /// For BatchCommand, this creates a synthetic undo operation that captures
-    /// the aggregate effect of all sub-commands.
+    /// The recorded operation is the span that differs between the text
+    /// before and after the command, so a `BatchCommand` undoes as one step.
     pub fn execute_command(
         &mut self,
         command: &dyn crate::commands::EditorCommand,
     ) -> Result<crate::commands::CommandResult> {
-        use crate::core::history::Operation;
-
-        // Get the cursor position before
-        let _cursor_before = self.cursor_position();
+        let before = self.text();
 
         // Execute the command
         let result = command.execute(self)?;
 
         // Only record if the command changed content
         if result.content_changed {
-            // Determine the operation based on the result
-            let operation = if let Some(range) = result.modified_range {
-                if range.is_empty() {
-                    // This was an insertion
-                    let inserted_text = self.text_range(Range::new(
-                        range.start,
-                        Position::new(
-                            range.start.offset
-                                + result
-                                    .new_cursor
-                                    .map_or(0, |c| c.offset - range.start.offset),
-                        ),
-                    ))?;
-                    Operation::Insert {
-                        position: range.start,
-                        text: inserted_text,
-                    }
-                } else {
-                    // For batch commands and complex operations, we need to be more careful
-                    // Store enough information to properly undo
-                    // This is a simplified approach - ideally each command would handle its own undo
-                    let cmd_desc = command.description();
-                    if cmd_desc.contains("batch") || cmd_desc.contains("Multiple") {
-                        // For batch operations, we don't have enough info
-                        // Just use a simple insert operation
-                        Operation::Insert {
-                            position: range.start,
-                            text: String::new(),
-                        }
-                    } else {
-                        // For simple operations, use the range info
-                        Operation::Replace {
-                            range,
-                            old_text: String::new(), // We don't have the old text
-                            new_text: self.text_range(range).unwrap_or_default(),
-                        }
-                    }
-                }
-            } else {
-                // No range info - create a generic operation
-                Operation::Insert {
-                    position: Position::new(0),
-                    text: String::new(),
-                }
-            };
-
             // Update cursor if needed
             if let Some(new_cursor) = result.new_cursor {
                 self.set_cursor_position(Some(new_cursor));
             }
 
             // Record in history
-            self.history
-                .record_operation(operation, command.description().to_string(), &result);
+            if let Some(operation) = text_change(&before, &self.text()) {
+                self.history.record_operation(
+                    operation,
+                    command.description().to_string(),
+                    &result,
+                );
+            }
 
             // Clear validation cache since content changed
             self.validator.clear_cache();
@@ -160,3 +116,52 @@ impl EditorDocument {
         &mut self.validator
     }
 }
+
+/// The operation turning `before` into `after`, spanning everything between
+/// their common prefix and suffix, or `None` if they are equal
+pub(super) fn text_change(before: &str, after: &str) -> Option<crate::core::history::Operation> {
+    use crate::core::history::Operation;
+
+    let mut prefix = before
+        .bytes()
+        .zip(after.bytes())
+        .take_while(|(a, b)| a == b)
+        .count();
+    while !before.is_char_boundary(prefix) {
+        prefix -= 1;
+    }
+    let max_suffix = before.len().min(after.len()) - prefix;
+    let mut suffix = before
+        .bytes()
+        .rev()
+        .zip(after.bytes().rev())
+        .take(max_suffix)
+        .take_while(|(a, b)| a == b)
+        .count();
+    while !before.is_char_boundary(before.len() - suffix) {
+        suffix -= 1;
+    }
+
+    let old_text = &before[prefix..before.len() - suffix];
+    let new_text = &after[prefix..after.len() - suffix];
+    let range = Range::new(
+        Position::new(prefix),
+        Position::new(prefix + old_text.len()),
+    );
+    match (old_text.is_empty(), new_text.is_empty()) {
+        (true, true) => None,
+        (true, false) => Some(Operation::Insert {
+            position: range.start,
+            text: new_text.to_string(),
+        }),
+        (false, true) => Some(Operation::Delete {
+            range,
+            deleted_text: old_text.to_string(),
+        }),
+        (false, false) => Some(Operation::Replace {
+            range,
+            old_text: old_text.to_string(),
+            new_text: new_text.to_string(),
+        }),
+    }
+}
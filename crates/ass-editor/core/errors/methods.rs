@@ -45,6 +45,13 @@ impl EditorError {
         }
     }
 
+    /// Create a new history error
+    pub fn history<T: fmt::Display>(message: T) -> Self {
+        Self::HistoryError {
+            message: message.to_string(),
+        }
+    }
+
     /// Create a new format line error
     pub fn format_line<T: fmt::Display>(message: T) -> Self {
         Self::FormatLineError {
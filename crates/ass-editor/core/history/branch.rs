@@ -0,0 +1,214 @@
+//! Branching undo history and squashing of undo entries.
+//!
+//! When an edit follows an undo, [`UndoStack::push`] keeps the undone entries
+//! as a branch instead of dropping them. [`UndoStack::switch_branch`] swaps a
+//! branch in as the redo stack once the history stands at the branch's fork
+//! point; `EditorDocument::undo_branch_switch` walks the document there and
+//! replays the branch.
+
+use super::{HistoryEntry, Operation, UndoStack};
+use crate::core::errors::{EditorError, Result};
+
+#[cfg(not(feature = "std"))]
+use alloc::{collections::VecDeque, format, string::String, vec::Vec};
+#[cfg(feature = "std")]
+use std::collections::VecDeque;
+
+/// Summary of a preserved history branch
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BranchInfo {
+    /// Branch identifier for [`UndoStack::switch_branch`]
+    pub id: usize,
+    /// Description of the first operation on the branch
+    pub label: String,
+    /// Number of operations on the branch
+    pub depth: usize,
+    /// Action id of the entry the branch forks from (0 = empty history)
+    pub created_at_action_id: usize,
+}
+
+/// Undone entries preserved when a new edit was made
+#[derive(Debug)]
+pub(super) struct UndoBranch {
+    /// Branch identifier
+    pub(super) id: usize,
+    /// Action id of the undo entry on top when the branch was cut
+    pub(super) fork_action_id: usize,
+    /// Entries in redo order (next to redo first)
+    pub(super) entries: VecDeque<HistoryEntry>,
+}
+
+impl UndoBranch {
+    /// Memory used by the branch's entries
+    pub(super) fn memory_usage(&self) -> usize {
+        self.entries.iter().map(|entry| entry.memory_usage).sum()
+    }
+}
+
+/// Way from the current history position to a branch's fork point
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum ForkPath {
+    /// Undo this many entries
+    Undo(usize),
+    /// Redo this many entries
+    Redo(usize),
+}
+
+impl UndoStack {
+    /// Action id of the most recent undo entry, 0 when there is none
+    #[must_use]
+    pub fn current_action_id(&self) -> usize {
+        self.undo_stack.front().map_or(0, |entry| entry.action_id)
+    }
+
+    /// Preserved branches, oldest first
+    #[must_use]
+    pub fn branches(&self) -> Vec<BranchInfo> {
+        self.branches
+            .iter()
+            .map(|branch| BranchInfo {
+                id: branch.id,
+                label: branch
+                    .entries
+                    .front()
+                    .map(|entry| entry.description.clone())
+                    .unwrap_or_default(),
+                depth: branch.entries.len(),
+                created_at_action_id: branch.fork_action_id,
+            })
+            .collect()
+    }
+
+    /// Make branch `branch_id` the redo stack
+    ///
+    /// The history must stand at the branch's fork point, i.e.
+    /// [`Self::current_action_id`] must equal its `created_at_action_id`.
+    /// The current redo stack, if any, is preserved as a new branch in its
+    /// place.
+    pub fn switch_branch(&mut self, branch_id: usize) -> Result<()> {
+        let index = self.branch_index(branch_id)?;
+        if self.branches[index].fork_action_id != self.current_action_id() {
+            return Err(EditorError::history(format!(
+                "History is not at the fork point of branch {branch_id}"
+            )));
+        }
+
+        let Some(mut branch) = self.branches.remove(index) else {
+            return Ok(());
+        };
+        core::mem::swap(&mut branch.entries, &mut self.redo_stack);
+        if !branch.entries.is_empty() {
+            branch.id = self.next_branch_id;
+            self.next_branch_id += 1;
+            self.branches.push_back(branch);
+        }
+        Ok(())
+    }
+
+    /// Undo or redo steps that lead to the fork point of branch `branch_id`
+    pub(crate) fn branch_fork_path(&self, branch_id: usize) -> Result<ForkPath> {
+        let fork = self.branches[self.branch_index(branch_id)?].fork_action_id;
+        if fork == 0 {
+            return Ok(ForkPath::Undo(self.undo_stack.len()));
+        }
+        if let Some(steps) = self.undo_stack.iter().position(|e| e.action_id == fork) {
+            return Ok(ForkPath::Undo(steps));
+        }
+        if let Some(index) = self.redo_stack.iter().position(|e| e.action_id == fork) {
+            return Ok(ForkPath::Redo(index + 1));
+        }
+        Err(EditorError::history(format!(
+            "Fork point of branch {branch_id} is not in the current history"
+        )))
+    }
+
+    /// Combine the `n` most recent undo entries into one named entry
+    ///
+    /// Undoing the combined entry reverts all of them at once. Branches
+    /// forking between the combined entries can no longer be reached and are
+    /// dropped.
+    pub fn squash_last(&mut self, n: usize, description: String) -> Result<()> {
+        if n == 0 || n > self.undo_stack.len() {
+            return Err(EditorError::history(format!(
+                "Cannot squash {n} entries, {} can be undone",
+                self.undo_stack.len()
+            )));
+        }
+
+        // Oldest first
+        let mut entries: Vec<HistoryEntry> = self.undo_stack.drain(..n).rev().collect();
+        self.current_memory -= entries.iter().map(|e| e.memory_usage).sum::<usize>();
+        let Some(newest) = entries.pop() else {
+            return Ok(());
+        };
+        let cursor_before = entries
+            .first()
+            .map_or(newest.cursor_before, |e| e.cursor_before);
+
+        let inner: Vec<usize> = entries.iter().map(|e| e.action_id).collect();
+        let mut freed = 0;
+        self.branches.retain(|branch| {
+            let reachable = !inner.contains(&branch.fork_action_id);
+            if !reachable {
+                freed += branch.memory_usage();
+            }
+            reachable
+        });
+        self.current_memory -= freed;
+
+        let operation = if entries.is_empty() {
+            newest.operation
+        } else {
+            let mut operations: Vec<Operation> = entries.into_iter().map(|e| e.operation).collect();
+            operations.push(newest.operation);
+            Operation::Group { operations }
+        };
+        let memory_usage = operation.memory_usage() + description.len();
+        self.current_memory += memory_usage;
+        self.undo_stack.push_front(HistoryEntry {
+            operation,
+            description,
+            modified_range: newest.modified_range,
+            cursor_before,
+            cursor_after: newest.cursor_after,
+            #[cfg(feature = "stream")]
+            script_delta: None,
+            #[cfg(feature = "std")]
+            timestamp: newest.timestamp,
+            memory_usage,
+            action_id: newest.action_id,
+        });
+        Ok(())
+    }
+
+    /// Keep the redo stack as a branch before a new edit replaces it
+    pub(super) fn preserve_redo_as_branch(&mut self) {
+        if self.redo_stack.is_empty() {
+            return;
+        }
+        if self.config.max_branches == 0 {
+            self.clear_redo_stack();
+            return;
+        }
+
+        self.branches.push_back(UndoBranch {
+            id: self.next_branch_id,
+            fork_action_id: self.current_action_id(),
+            entries: core::mem::take(&mut self.redo_stack),
+        });
+        self.next_branch_id += 1;
+        while self.branches.len() > self.config.max_branches {
+            if let Some(branch) = self.branches.pop_front() {
+                self.current_memory -= branch.memory_usage();
+            }
+        }
+    }
+
+    /// Position of branch `branch_id` in the branch list
+    fn branch_index(&self, branch_id: usize) -> Result<usize> {
+        self.branches
+            .iter()
+            .position(|branch| branch.id == branch_id)
+            .ok_or_else(|| EditorError::history(format!("Unknown history branch {branch_id}")))
+    }
+}
@@ -0,0 +1,156 @@
+//! Tests for branching history and squashing through [`UndoManager`].
+
+use super::*;
+use crate::core::{EditorDocument, Position};
+#[cfg(not(feature = "std"))]
+use alloc::string::ToString;
+
+fn append(doc: &mut EditorDocument, text: &str) {
+    let end = Position::new(doc.len_bytes());
+    doc.insert(end, text).unwrap();
+}
+
+#[test]
+fn switching_branches_restores_document_state() {
+    let mut doc = EditorDocument::from_content("Hello").unwrap();
+    append(&mut doc, " world");
+    append(&mut doc, "!");
+    doc.undo().unwrap();
+    doc.undo().unwrap();
+    append(&mut doc, " there");
+    assert_eq!(doc.text(), "Hello there");
+    assert!(!doc.can_redo());
+
+    let branches = doc.undo_manager().branches();
+    assert_eq!(branches.len(), 1);
+    assert_eq!(branches[0].depth, 2);
+    assert_eq!(branches[0].label, "Insert text");
+    assert_eq!(branches[0].created_at_action_id, 0);
+
+    doc.undo_branch_switch(branches[0].id).unwrap();
+    assert_eq!(doc.text(), "Hello world!");
+
+    // The abandoned edit is now a branch of its own
+    let branches = doc.undo_manager().branches();
+    assert_eq!(branches.len(), 1);
+    assert_eq!(branches[0].depth, 1);
+    doc.undo_branch_switch(branches[0].id).unwrap();
+    assert_eq!(doc.text(), "Hello there");
+
+    doc.undo().unwrap();
+    assert_eq!(doc.text(), "Hello");
+}
+
+#[test]
+fn switching_reaches_fork_points_in_redo_history() {
+    let mut doc = EditorDocument::from_content("").unwrap();
+    append(&mut doc, "a");
+    append(&mut doc, "b");
+    append(&mut doc, "c");
+    doc.undo().unwrap();
+    append(&mut doc, "d");
+    let fork = doc.undo_manager().branches()[0].clone();
+    // Forked from the second edit
+    assert_eq!(fork.created_at_action_id, 2);
+
+    // Walk back past the fork point
+    doc.undo().unwrap();
+    doc.undo().unwrap();
+    assert_eq!(doc.text(), "a");
+
+    doc.undo_branch_switch(fork.id).unwrap();
+    assert_eq!(doc.text(), "abc");
+    assert!(doc.undo_branch_switch(fork.id).is_err());
+}
+
+#[test]
+fn redo_keeps_remaining_entries() {
+    let mut doc = EditorDocument::from_content("").unwrap();
+    for text in ["one ", "two ", "three"] {
+        append(&mut doc, text);
+    }
+    for _ in 0..3 {
+        doc.undo().unwrap();
+    }
+    for _ in 0..3 {
+        doc.redo().unwrap();
+    }
+    assert_eq!(doc.text(), "one two three");
+    assert!(doc.undo_manager().branches().is_empty());
+}
+
+#[test]
+fn branch_count_is_limited() {
+    let mut doc = EditorDocument::from_content("").unwrap();
+    doc.undo_manager_mut().set_config(UndoStackConfig {
+        max_branches: 2,
+        ..UndoStackConfig::default()
+    });
+    for text in ["a", "b", "c"] {
+        append(&mut doc, text);
+        doc.undo().unwrap();
+    }
+    append(&mut doc, "d");
+
+    let branches = doc.undo_manager().branches();
+    assert_eq!(branches.len(), 2);
+    doc.undo_branch_switch(branches[0].id).unwrap();
+    assert_eq!(doc.text(), "b");
+    doc.undo_branch_switch(branches[1].id).unwrap();
+    assert_eq!(doc.text(), "c");
+}
+
+#[test]
+fn zero_branches_discards_undone_operations() {
+    let mut doc = EditorDocument::from_content("").unwrap();
+    doc.undo_manager_mut().set_config(UndoStackConfig {
+        max_branches: 0,
+        ..UndoStackConfig::default()
+    });
+    append(&mut doc, "a");
+    doc.undo().unwrap();
+    append(&mut doc, "b");
+    assert!(doc.undo_manager().branches().is_empty());
+    assert!(doc.undo_branch_switch(1).is_err());
+    doc.undo().unwrap();
+    assert_eq!(doc.text(), "");
+}
+
+#[test]
+fn squash_last_undoes_as_one_step() {
+    let mut doc = EditorDocument::from_content("Title").unwrap();
+    append(&mut doc, ": ");
+    append(&mut doc, "draft");
+    doc.replace(
+        crate::core::Range::new(Position::new(7), Position::new(12)),
+        "final",
+    )
+    .unwrap();
+    assert_eq!(doc.text(), "Title: final");
+
+    doc.undo_manager_mut()
+        .squash_last(3, "Write subtitle".to_string())
+        .unwrap();
+    assert_eq!(doc.undo_manager().stats().undo_count, 1);
+    assert_eq!(doc.next_undo_description(), Some("Write subtitle"));
+
+    doc.undo().unwrap();
+    assert_eq!(doc.text(), "Title");
+    assert!(!doc.can_undo());
+    doc.redo().unwrap();
+    assert_eq!(doc.text(), "Title: final");
+}
+
+#[test]
+fn squash_last_rejects_bad_counts() {
+    let mut manager = UndoManager::new();
+    assert!(manager.squash_last(1, "Nothing".to_string()).is_err());
+
+    let mut doc = EditorDocument::from_content("").unwrap();
+    append(&mut doc, "a");
+    let manager = doc.undo_manager_mut();
+    assert!(manager.squash_last(0, "None".to_string()).is_err());
+    assert!(manager.squash_last(2, "Too many".to_string()).is_err());
+    manager.squash_last(1, "Renamed".to_string()).unwrap();
+    assert_eq!(manager.next_undo_description(), Some("Renamed"));
+}
@@ -1,7 +1,8 @@
 //! Configuration for undo-stack capacity and memory behavior.
 //!
 //! [`UndoStackConfig`] controls how many entries the undo stack retains, its
-//! memory budget, compression, and arena-reset cadence.
+//! memory budget, compression, arena-reset cadence, and how many alternate
+//! history branches are preserved.
 
 /// Configuration for undo stack behavior
 #[derive(Debug, Clone)]
@@ -17,6 +18,10 @@ pub struct UndoStackConfig {
 
     /// Interval for arena resets (0 = never reset)
     pub arena_reset_interval: usize,
+
+    /// Maximum number of alternate branches to preserve (0 = discard undone
+    /// operations on a new edit)
+    pub max_branches: usize,
 }
 
 impl Default for UndoStackConfig {
@@ -26,6 +31,7 @@ impl Default for UndoStackConfig {
             max_memory: 10 * 1024 * 1024, // 10MB default
             enable_compression: true,
             arena_reset_interval: 100, // Reset arena every 100 operations
+            max_branches: 5,
         }
     }
 }
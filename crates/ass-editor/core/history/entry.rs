@@ -44,6 +44,11 @@ pub struct HistoryEntry {
 
     /// Memory usage of this entry (for capacity management)
     pub memory_usage: usize,
+
+    /// Identifier assigned when the entry is first pushed (0 until then)
+    ///
+    /// Kept across undo and redo; branches record the entry they fork from.
+    pub action_id: usize,
 }
 
 impl HistoryEntry {
@@ -67,6 +72,7 @@ impl HistoryEntry {
             #[cfg(feature = "std")]
             timestamp: std::time::Instant::now(),
             memory_usage,
+            action_id: 0,
         }
     }
 
@@ -97,6 +103,7 @@ impl HistoryEntry {
             #[cfg(feature = "std")]
             timestamp: std::time::Instant::now(),
             memory_usage,
+            action_id: 0,
         }
     }
 }
@@ -1,14 +1,16 @@
 //! High-level undo/redo coordination.
 //!
 //! [`UndoManager`] drives the [`UndoStack`], translating command results into
-//! recorded operations and exposing aggregate [`HistoryStats`].
+//! recorded operations, exposing aggregate [`HistoryStats`], and managing
+//! history branches.
 
-use super::{HistoryEntry, Operation, UndoStack, UndoStackConfig};
+use super::{BranchInfo, ForkPath, HistoryEntry, Operation, UndoStack, UndoStackConfig};
 use crate::commands::CommandResult;
+use crate::core::errors::Result;
 use crate::core::position::Position;
 
 #[cfg(not(feature = "std"))]
-use alloc::string::String;
+use alloc::{string::String, vec::Vec};
 
 /// Undo manager that coordinates between commands and history stack
 ///
@@ -155,6 +157,38 @@ impl UndoManager {
     pub fn push_redo_entry(&mut self, entry: HistoryEntry) {
         self.stack.push_redo(entry);
     }
+
+    /// Push a redone entry back to the undo stack, keeping the redo stack
+    pub fn push_undo_entry(&mut self, entry: HistoryEntry) {
+        self.stack.push_undo(entry);
+    }
+
+    /// Alternate histories preserved by edits made after an undo
+    ///
+    /// At most `UndoStackConfig::max_branches` are kept, oldest first.
+    #[must_use]
+    pub fn branches(&self) -> Vec<BranchInfo> {
+        self.stack.branches()
+    }
+
+    /// Make branch `branch_id` the redo history
+    ///
+    /// Only swaps the history: it must already stand at the branch's fork
+    /// point. Use `EditorDocument::undo_branch_switch` to also bring the
+    /// document to the end of the branch.
+    pub fn undo_branch_switch(&mut self, branch_id: usize) -> Result<()> {
+        self.stack.switch_branch(branch_id)
+    }
+
+    /// Steps that lead to the fork point of branch `branch_id`
+    pub(crate) fn branch_fork_path(&self, branch_id: usize) -> Result<ForkPath> {
+        self.stack.branch_fork_path(branch_id)
+    }
+
+    /// Combine the last `n` undo entries into one entry named `description`
+    pub fn squash_last(&mut self, n: usize, description: String) -> Result<()> {
+        self.stack.squash_last(n, description)
+    }
 }
 
 impl Default for UndoManager {
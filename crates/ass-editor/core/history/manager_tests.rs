@@ -45,6 +45,7 @@ fn programmatic_undo_limit_configuration() {
         max_memory: 1000,
         enable_compression: false,
         arena_reset_interval: 0,
+        max_branches: 5,
     };
 
     let mut manager = UndoManager::with_config(custom_config);
@@ -100,6 +101,7 @@ fn undo_manager_config_update() {
         max_memory: 0,
         enable_compression: false,
         arena_reset_interval: 0,
+        max_branches: 5,
     };
 
     manager.set_config(restrictive_config);
@@ -124,6 +126,7 @@ fn memory_limit_enforcement() {
         max_memory: 50,   // Very low memory limit (50 bytes)
         enable_compression: false,
         arena_reset_interval: 0,
+        max_branches: 5,
     };
 
     let mut manager = UndoManager::with_config(memory_limited_config);
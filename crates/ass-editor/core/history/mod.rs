@@ -1,20 +1,30 @@
 //! History management for undo/redo operations
 //!
 //! Provides efficient undo/redo functionality with configurable depth limits,
-//! arena-based memory pooling, and delta compression to minimize memory usage.
+//! branching history, arena-based memory pooling, and delta compression to
+//! minimize memory usage.
 
+mod branch;
 mod config;
 mod entry;
 mod manager;
 mod operation;
+#[cfg(feature = "serde")]
+mod persist;
 mod stack;
 mod stack_impl;
 
+#[cfg(test)]
+mod branch_tests;
 #[cfg(test)]
 mod manager_tests;
+#[cfg(all(test, feature = "serde"))]
+mod persist_tests;
 #[cfg(test)]
 mod stack_tests;
 
+pub use branch::BranchInfo;
+pub(crate) use branch::ForkPath;
 pub use config::UndoStackConfig;
 pub use entry::HistoryEntry;
 pub use manager::{HistoryStats, UndoManager};
@@ -42,6 +42,8 @@ pub enum Operation {
         /// Data needed to undo this delta
         undo_data: DeltaUndoData,
     },
+    /// Several operations squashed into one undo step, oldest first
+    Group { operations: Vec<Operation> },
 }
 
 impl Operation {
@@ -69,6 +71,10 @@ impl Operation {
                         .map(|(_, s)| s.len())
                         .sum::<usize>()
             }
+            Self::Group { operations } => {
+                core::mem::size_of::<Self>()
+                    + operations.iter().map(Self::memory_usage).sum::<usize>()
+            }
         }
     }
 }
@@ -0,0 +1,260 @@
+//! Binary persistence of undo/redo history.
+//!
+//! [`UndoStack::serialize`] encodes both queues with `bincode` through private
+//! mirror types, so the on-disk layout is independent of the in-memory entry
+//! representation. Timestamps, script deltas attached for incremental
+//! parsing, and the parse issues of delta operations are not persisted;
+//! undo and redo never read them. Preserved history branches are not
+//! persisted either; restored entries get fresh action ids.
+
+use super::{HistoryEntry, Operation, UndoStack, UndoStackConfig};
+use crate::core::errors::{EditorError, Result};
+use crate::core::position::{Position, Range};
+use serde::{Deserialize, Serialize};
+
+/// Version tag written ahead of the history payload
+const FORMAT_VERSION: u32 = 1;
+
+/// Serialized form of a whole stack, most recent entries first
+#[derive(Serialize, Deserialize)]
+struct SavedHistory {
+    /// Layout version, checked on restore
+    version: u32,
+    /// Undo queue
+    undo: Vec<SavedEntry>,
+    /// Redo queue
+    redo: Vec<SavedEntry>,
+}
+
+/// Serialized form of a [`HistoryEntry`]
+#[derive(Serialize, Deserialize)]
+struct SavedEntry {
+    /// Recorded operation
+    operation: SavedOperation,
+    /// Operation description
+    description: String,
+    /// Modified byte range as `(start, end)`
+    modified_range: Option<(usize, usize)>,
+    /// Cursor offset before the operation
+    cursor_before: Option<usize>,
+    /// Cursor offset after the operation
+    cursor_after: Option<usize>,
+}
+
+/// Serialized form of an [`Operation`], with ranges as `(start, end)`
+#[derive(Serialize, Deserialize)]
+enum SavedOperation {
+    /// See [`Operation::Insert`]
+    Insert { position: usize, text: String },
+    /// See [`Operation::Delete`]
+    Delete {
+        range: (usize, usize),
+        deleted_text: String,
+    },
+    /// See [`Operation::Replace`]
+    Replace {
+        range: (usize, usize),
+        old_text: String,
+        new_text: String,
+    },
+    /// See [`Operation::Delta`]
+    Delta {
+        added: Vec<String>,
+        modified: Vec<(usize, String)>,
+        removed: Vec<usize>,
+        removed_sections: Vec<(usize, String)>,
+        modified_sections: Vec<(usize, String)>,
+    },
+    /// See [`Operation::Group`]
+    Group { operations: Vec<SavedOperation> },
+}
+
+impl UndoStack {
+    /// Encode the undo and redo history as bytes
+    ///
+    /// The configuration is not included; it is supplied again on restore.
+    pub fn serialize(&self) -> Result<Vec<u8>> {
+        let history = SavedHistory {
+            version: FORMAT_VERSION,
+            undo: self.undo_stack.iter().map(SavedEntry::from).collect(),
+            redo: self.redo_stack.iter().map(SavedEntry::from).collect(),
+        };
+        bincode::serialize(&history).map_err(EditorError::serialization)
+    }
+
+    /// Restore a stack written by [`Self::serialize`] with the default
+    /// configuration
+    pub fn deserialize(bytes: &[u8]) -> Result<Self> {
+        Self::deserialize_with_config(bytes, UndoStackConfig::default())
+    }
+
+    /// Restore a stack written by [`Self::serialize`] under `config`
+    ///
+    /// The oldest entries beyond `config.max_entries` or the memory budget
+    /// are dropped, exactly as if the history had been recorded under this
+    /// configuration.
+    pub fn deserialize_with_config(bytes: &[u8], config: UndoStackConfig) -> Result<Self> {
+        let history: SavedHistory =
+            bincode::deserialize(bytes).map_err(EditorError::serialization)?;
+        if history.version != FORMAT_VERSION {
+            return Err(EditorError::serialization(format!(
+                "Unsupported undo history version {}",
+                history.version
+            )));
+        }
+
+        let mut stack = Self::with_config(config);
+        for saved in history.undo {
+            let mut entry = saved.into_entry()?;
+            entry.action_id = stack.next_action_id;
+            stack.next_action_id += 1;
+            stack.current_memory += entry.memory_usage;
+            stack.undo_stack.push_back(entry);
+        }
+        for saved in history.redo.into_iter().take(stack.config.max_entries) {
+            let mut entry = saved.into_entry()?;
+            entry.action_id = stack.next_action_id;
+            stack.next_action_id += 1;
+            stack.current_memory += entry.memory_usage;
+            stack.redo_stack.push_back(entry);
+        }
+        stack.enforce_limits();
+        Ok(stack)
+    }
+}
+
+impl From<&Operation> for SavedOperation {
+    fn from(operation: &Operation) -> Self {
+        match operation {
+            Operation::Insert { position, text } => Self::Insert {
+                position: position.offset,
+                text: text.clone(),
+            },
+            Operation::Delete {
+                range,
+                deleted_text,
+            } => Self::Delete {
+                range: (range.start.offset, range.end.offset),
+                deleted_text: deleted_text.clone(),
+            },
+            Operation::Replace {
+                range,
+                old_text,
+                new_text,
+            } => Self::Replace {
+                range: (range.start.offset, range.end.offset),
+                old_text: old_text.clone(),
+                new_text: new_text.clone(),
+            },
+            #[cfg(feature = "stream")]
+            Operation::Delta { forward, undo_data } => Self::Delta {
+                added: forward.added.clone(),
+                modified: forward.modified.clone(),
+                removed: forward.removed.clone(),
+                removed_sections: undo_data.removed_sections.clone(),
+                modified_sections: undo_data.modified_sections.clone(),
+            },
+            Operation::Group { operations } => Self::Group {
+                operations: operations.iter().map(Self::from).collect(),
+            },
+        }
+    }
+}
+
+impl From<&HistoryEntry> for SavedEntry {
+    fn from(entry: &HistoryEntry) -> Self {
+        Self {
+            operation: SavedOperation::from(&entry.operation),
+            description: entry.description.clone(),
+            modified_range: entry
+                .modified_range
+                .map(|range| (range.start.offset, range.end.offset)),
+            cursor_before: entry.cursor_before.map(|pos| pos.offset),
+            cursor_after: entry.cursor_after.map(|pos| pos.offset),
+        }
+    }
+}
+
+impl SavedEntry {
+    /// Rebuild the in-memory entry, timestamped now
+    fn into_entry(self) -> Result<HistoryEntry> {
+        let operation = self.operation.into_operation()?;
+        let memory_usage = operation.memory_usage() + self.description.len();
+        Ok(HistoryEntry {
+            operation,
+            description: self.description,
+            modified_range: self
+                .modified_range
+                .map(|(start, end)| Range::new(Position::new(start), Position::new(end))),
+            cursor_before: self.cursor_before.map(Position::new),
+            cursor_after: self.cursor_after.map(Position::new),
+            #[cfg(feature = "stream")]
+            script_delta: None,
+            timestamp: std::time::Instant::now(),
+            memory_usage,
+            action_id: 0,
+        })
+    }
+}
+
+impl SavedOperation {
+    /// Rebuild the in-memory operation
+    fn into_operation(self) -> Result<Operation> {
+        let range =
+            |(start, end): (usize, usize)| Range::new(Position::new(start), Position::new(end));
+        let operation = match self {
+            Self::Insert { position, text } => Operation::Insert {
+                position: Position::new(position),
+                text,
+            },
+            Self::Delete {
+                range: bounds,
+                deleted_text,
+            } => Operation::Delete {
+                range: range(bounds),
+                deleted_text,
+            },
+            Self::Replace {
+                range: bounds,
+                old_text,
+                new_text,
+            } => Operation::Replace {
+                range: range(bounds),
+                old_text,
+                new_text,
+            },
+            #[cfg(feature = "stream")]
+            Self::Delta {
+                added,
+                modified,
+                removed,
+                removed_sections,
+                modified_sections,
+            } => Operation::Delta {
+                forward: ass_core::parser::ScriptDeltaOwned {
+                    added,
+                    modified,
+                    removed,
+                    new_issues: Vec::new(),
+                },
+                undo_data: super::DeltaUndoData {
+                    removed_sections,
+                    modified_sections,
+                },
+            },
+            #[cfg(not(feature = "stream"))]
+            Self::Delta { .. } => {
+                return Err(EditorError::serialization(
+                    "Delta operations require the `stream` feature",
+                ))
+            }
+            Self::Group { operations } => Operation::Group {
+                operations: operations
+                    .into_iter()
+                    .map(Self::into_operation)
+                    .collect::<Result<_>>()?,
+            },
+        };
+        Ok(operation)
+    }
+}
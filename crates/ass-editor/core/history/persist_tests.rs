@@ -0,0 +1,130 @@
+//! Tests for [`UndoStack`] serialization and restore.
+
+use super::*;
+use crate::commands::CommandResult;
+use crate::core::position::{Position, Range};
+use crate::core::{EditorDocument, EditorError};
+
+/// Deterministic xorshift generator for reproducible "random" edits
+struct Rng(u64);
+
+impl Rng {
+    fn below(&mut self, bound: usize) -> usize {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        (self.0 % bound as u64) as usize
+    }
+}
+
+fn entry(text: &str) -> HistoryEntry {
+    let operation = Operation::Insert {
+        position: Position::new(0),
+        text: text.to_string(),
+    };
+    HistoryEntry::new(operation, text.to_string(), &CommandResult::success(), None)
+}
+
+#[test]
+fn roundtrip_fifty_random_edits_then_undo_all() {
+    let original = "[Script Info]\nTitle: Persist\n\n[Events]\nFormat: Layer, Start, End, Style, Name, MarginL, MarginR, MarginV, Effect, Text\nDialogue: 0,0:00:00.00,0:00:05.00,Default,,0,0,0,,Hello world";
+    let mut doc = EditorDocument::from_content(original).unwrap();
+    let mut rng = Rng(0x9E37_79B9_7F4A_7C15);
+
+    for i in 0..50 {
+        let len = doc.len_bytes();
+        let start = rng.below(len + 1);
+        let end = (start + rng.below(8)).min(len);
+        let range = Range::new(Position::new(start), Position::new(end));
+        match rng.below(3) {
+            0 => doc
+                .insert(Position::new(start), &format!("ins{i}"))
+                .unwrap(),
+            1 if start < end => doc.delete(range).unwrap(),
+            _ => doc.replace(range, &format!("rep{i}")).unwrap(),
+        }
+    }
+    assert_eq!(doc.undo_manager().stack().undo_count(), 50);
+
+    let bytes = doc.undo_manager().stack().serialize().unwrap();
+    let edited = doc.text();
+
+    let mut restored = EditorDocument::from_content(&edited).unwrap();
+    *restored.undo_manager_mut().stack_mut() = UndoStack::deserialize(&bytes).unwrap();
+    assert!(restored.can_undo());
+    assert!(!restored.can_redo());
+    assert_eq!(
+        restored.next_undo_description(),
+        doc.next_undo_description()
+    );
+
+    for _ in 0..50 {
+        restored.undo().unwrap();
+    }
+    assert!(!restored.can_undo());
+    assert!(restored.can_redo());
+    assert_eq!(restored.text(), original);
+}
+
+#[test]
+fn restore_preserves_redo_queue() {
+    let mut stack = UndoStack::new();
+    stack.push(entry("first"));
+    stack.push(entry("second"));
+    let undone = stack.pop_undo().unwrap();
+    stack.push_redo(undone);
+
+    let restored = UndoStack::deserialize(&stack.serialize().unwrap()).unwrap();
+    assert!(restored.can_undo());
+    assert!(restored.can_redo());
+    assert_eq!(restored.next_undo_description(), Some("first"));
+    assert_eq!(restored.next_redo_description(), Some("second"));
+    assert_eq!(restored.memory_usage(), stack.memory_usage());
+}
+
+#[test]
+fn restore_respects_max_entries() {
+    let mut stack = UndoStack::new();
+    for i in 0..10 {
+        stack.push(entry(&format!("edit {i}")));
+    }
+    let config = UndoStackConfig {
+        max_entries: 4,
+        ..UndoStackConfig::default()
+    };
+
+    let restored = UndoStack::deserialize_with_config(&stack.serialize().unwrap(), config).unwrap();
+    assert_eq!(restored.undo_count(), 4);
+    assert_eq!(restored.next_undo_description(), Some("edit 9"));
+}
+
+#[test]
+fn restore_rejects_invalid_bytes() {
+    assert!(matches!(
+        UndoStack::deserialize(&[0xFF; 3]),
+        Err(EditorError::SerializationError { .. })
+    ));
+
+    let mut stack = UndoStack::new();
+    stack.push(entry("edit"));
+    let mut bytes = stack.serialize().unwrap();
+    bytes[0] = 99;
+    assert!(UndoStack::deserialize(&bytes).is_err());
+}
+
+#[test]
+fn squashed_entries_roundtrip() {
+    let mut doc = EditorDocument::from_content("Line").unwrap();
+    doc.insert(Position::new(4), " one").unwrap();
+    doc.insert(Position::new(8), " two").unwrap();
+    doc.undo_manager_mut()
+        .squash_last(2, "Add words".to_string())
+        .unwrap();
+
+    let bytes = doc.undo_manager().stack().serialize().unwrap();
+    let mut restored = EditorDocument::from_content(&doc.text()).unwrap();
+    *restored.undo_manager_mut().stack_mut() = UndoStack::deserialize(&bytes).unwrap();
+    assert_eq!(restored.next_undo_description(), Some("Add words"));
+    restored.undo().unwrap();
+    assert_eq!(restored.text(), "Line");
+}
@@ -1,9 +1,11 @@
 //! Undo/redo stack storage.
 //!
-//! [`UndoStack`] owns the undo and redo queues plus the optional arena used for
-//! temporary allocations; its inherent methods live in the sibling `stack_impl`
-//! module, so the fields are `pub(super)` for use across the `history` subtree.
+//! [`UndoStack`] owns the undo and redo queues, the preserved history
+//! branches, plus the optional arena used for temporary allocations; its
+//! inherent methods live in the sibling `stack_impl` and `branch` modules, so
+//! the fields are `pub(super)` for use across the `history` subtree.
 
+use super::branch::UndoBranch;
 use super::{HistoryEntry, UndoStackConfig};
 
 #[cfg(feature = "arena")]
@@ -30,9 +32,19 @@ pub struct UndoStack {
     /// Redo history (operations that can be redone)
     pub(super) redo_stack: VecDeque<HistoryEntry>,
 
-    /// Current memory usage in bytes
+    /// Redo histories preserved when a new edit followed an undo, oldest
+    /// first
+    pub(super) branches: VecDeque<UndoBranch>,
+
+    /// Current memory usage in bytes, branches included
     pub(super) current_memory: usize,
 
+    /// Action id handed to the next pushed entry
+    pub(super) next_action_id: usize,
+
+    /// Branch id handed to the next preserved branch
+    pub(super) next_branch_id: usize,
+
     /// Arena for temporary allocations
     #[cfg(feature = "arena")]
     pub(super) arena: Bump,
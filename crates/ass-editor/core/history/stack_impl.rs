@@ -26,7 +26,10 @@ impl UndoStack {
             config,
             undo_stack: VecDeque::new(),
             redo_stack: VecDeque::new(),
+            branches: VecDeque::new(),
             current_memory: 0,
+            next_action_id: 1,
+            next_branch_id: 1,
             #[cfg(feature = "arena")]
             arena: Bump::new(),
             #[cfg(feature = "arena")]
@@ -36,20 +39,12 @@ impl UndoStack {
 
     /// Push a new entry onto the undo stack
     ///
-    /// This clears the redo stack as new operations invalidate
-    /// previously undone operations.
+    /// New operations invalidate previously undone operations: the redo
+    /// stack is preserved as a branch (see [`Self::branches`]) while fewer
+    /// than `max_branches` are kept, evicting the oldest branch otherwise.
     pub fn push(&mut self, entry: HistoryEntry) {
-        // Clear redo stack - new operations invalidate undone changes
-        self.clear_redo_stack();
-
-        // Add memory usage
-        self.current_memory += entry.memory_usage;
-
-        // Add to undo stack
-        self.undo_stack.push_front(entry);
-
-        // Enforce limits
-        self.enforce_limits();
+        self.preserve_redo_as_branch();
+        self.push_undo(entry);
 
         // Periodic arena reset
         #[cfg(feature = "arena")]
@@ -63,6 +58,26 @@ impl UndoStack {
         }
     }
 
+    /// Push an entry onto the undo stack, keeping the redo stack
+    ///
+    /// Used to put back an entry that was redone. Entries without an action
+    /// id get the next one.
+    pub fn push_undo(&mut self, mut entry: HistoryEntry) {
+        if entry.action_id == 0 {
+            entry.action_id = self.next_action_id;
+            self.next_action_id += 1;
+        }
+
+        // Add memory usage
+        self.current_memory += entry.memory_usage;
+
+        // Add to undo stack
+        self.undo_stack.push_front(entry);
+
+        // Enforce limits
+        self.enforce_limits();
+    }
+
     /// Pop the most recent entry from the undo stack
     pub fn pop_undo(&mut self) -> Option<HistoryEntry> {
         if let Some(entry) = self.undo_stack.pop_front() {
@@ -135,10 +150,11 @@ impl UndoStack {
             .map(|entry| entry.description.as_str())
     }
 
-    /// Clear all history
+    /// Clear all history, branches included
     pub fn clear(&mut self) {
         self.undo_stack.clear();
         self.redo_stack.clear();
+        self.branches.clear();
         self.current_memory = 0;
 
         #[cfg(feature = "arena")]
@@ -148,32 +164,51 @@ impl UndoStack {
     }
 
     /// Clear only the redo stack (called when new operations are performed)
-    fn clear_redo_stack(&mut self) {
+    pub(super) fn clear_redo_stack(&mut self) {
         for entry in self.redo_stack.drain(..) {
             self.current_memory -= entry.memory_usage;
         }
     }
 
     /// Enforce memory and count limits
-    fn enforce_limits(&mut self) {
+    pub(super) fn enforce_limits(&mut self) {
         // Enforce entry count limit
         while self.undo_stack.len() > self.config.max_entries {
-            if let Some(entry) = self.undo_stack.pop_back() {
-                self.current_memory -= entry.memory_usage;
-            }
+            self.trim_oldest_entry();
         }
 
-        // Enforce memory limit
-        while self.config.max_memory > 0
-            && self.current_memory > self.config.max_memory
-            && !self.undo_stack.is_empty()
-        {
-            if let Some(entry) = self.undo_stack.pop_back() {
-                self.current_memory -= entry.memory_usage;
+        // Enforce memory limit, giving up alternate branches first
+        while self.config.max_memory > 0 && self.current_memory > self.config.max_memory {
+            if let Some(branch) = self.branches.pop_front() {
+                self.current_memory -= branch.memory_usage();
+            } else if !self.undo_stack.is_empty() {
+                self.trim_oldest_entry();
+            } else {
+                break;
             }
         }
     }
 
+    /// Drop the oldest undo entry along with the branches forking before it
+    fn trim_oldest_entry(&mut self) {
+        let Some(entry) = self.undo_stack.pop_back() else {
+            return;
+        };
+        self.current_memory -= entry.memory_usage;
+
+        // Their fork point can no longer be reached by undoing; an empty
+        // history (fork id 0) now starts after the dropped entry
+        let mut freed = 0;
+        self.branches.retain(|branch| {
+            let reachable = branch.fork_action_id != 0 && branch.fork_action_id != entry.action_id;
+            if !reachable {
+                freed += branch.memory_usage();
+            }
+            reachable
+        });
+        self.current_memory -= freed;
+    }
+
     /// Reset the arena allocator to reclaim memory
     #[cfg(feature = "arena")]
     fn reset_arena(&mut self) {
@@ -38,6 +38,7 @@ fn undo_stack_memory_limits() {
         max_memory: 1000,
         enable_compression: false,
         arena_reset_interval: 0,
+        max_branches: 5,
     };
 
     let mut stack = UndoStack::with_config(config);
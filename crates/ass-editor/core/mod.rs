@@ -20,13 +20,15 @@ pub mod thread_safety;
 
 // Re-export commonly used types
 pub use builders::{EventBuilder, StyleBuilder};
-pub use document::{DocumentPosition, EditorDocument};
+pub use document::{DocumentPosition, EditorDocument, ScriptInfoEditor};
 pub use errors::{EditorError, Result};
 pub use fluent::{
     AtPosition, EventAccessor, EventFilter, EventInfo, EventQuery, EventSortCriteria,
     EventSortOptions, OwnedEvent, SelectRange,
 };
-pub use history::{HistoryEntry, HistoryStats, UndoManager, UndoStack, UndoStackConfig};
+pub use history::{
+    BranchInfo, HistoryEntry, HistoryStats, UndoManager, UndoStack, UndoStackConfig,
+};
 #[cfg(feature = "stream")]
 pub use incremental::{DocumentChange, IncrementalParser};
 pub use position::{LineColumn, Position, PositionBuilder, Range, Selection};
@@ -1,12 +1,15 @@
 //! Document range type built from start/end [`Position`] values.
 //!
 //! Defines [`Range`], a half-open interval `[start, end)` with
-//! containment, overlap, union, and intersection operations.
+//! containment, overlap, union, intersection, and subtraction operations.
 
 use super::Position;
 use core::cmp::{max, min};
 use core::fmt;
 
+#[cfg(not(feature = "std"))]
+use alloc::{vec, vec::Vec};
+
 /// A range in a document represented by start and end positions
 ///
 /// Ranges are half-open intervals [start, end) where start is inclusive
@@ -85,6 +88,15 @@ impl Range {
         pos.offset >= self.start.offset && pos.offset < self.end.offset
     }
 
+    /// Check if a cursor at `pos` touches this range
+    ///
+    /// Unlike [`Range::contains`], the end position counts, so a cursor
+    /// right after the last character, or at an empty range, is inside.
+    #[must_use]
+    pub const fn contains_position(&self, pos: Position) -> bool {
+        pos.offset >= self.start.offset && pos.offset <= self.end.offset
+    }
+
     /// Check if this range overlaps with another
     #[must_use]
     pub const fn overlaps(&self, other: &Self) -> bool {
@@ -121,6 +133,37 @@ impl Range {
             None
         }
     }
+
+    /// Get the parts of this range not covered by another
+    ///
+    /// Yields no range when `other` covers this one, two when it lies
+    /// strictly inside, and this range itself when they do not overlap.
+    /// Empty parts are never returned.
+    #[must_use]
+    pub fn subtract(&self, other: &Self) -> Vec<Self> {
+        if !self.overlaps(other) {
+            return if self.is_empty() {
+                Vec::new()
+            } else {
+                vec![*self]
+            };
+        }
+
+        let mut parts = Vec::new();
+        if self.start.offset < other.start.offset {
+            parts.push(Self {
+                start: self.start,
+                end: other.start,
+            });
+        }
+        if other.end.offset < self.end.offset {
+            parts.push(Self {
+                start: other.end,
+                end: self.end,
+            });
+        }
+        parts
+    }
 }
 
 impl fmt::Display for Range {
@@ -1,9 +1,12 @@
 //! Directional text selection type.
 //!
 //! Defines [`Selection`], which pairs an anchor and cursor [`Position`]
-//! to track selection direction and expose the covered [`Range`].
+//! to track selection direction and expose the covered [`Range`], and
+//! selects whole words using Unicode word boundaries.
 
 use super::{Position, Range};
+use crate::core::EditorDocument;
+use unicode_segmentation::UnicodeSegmentation;
 
 /// Selection represents a range with a direction
 ///
@@ -59,4 +62,53 @@ impl Selection {
             cursor: pos,
         }
     }
+
+    /// Select the word at `pos` in `doc`
+    ///
+    /// Word boundaries follow the Unicode word-break algorithm (UAX #29),
+    /// so CJK text, contractions and numbers split the way users expect. A
+    /// position right after a word selects that word. Returns an empty
+    /// selection at `pos` when no word touches it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ass_editor::{EditorDocument, Position, Selection};
+    ///
+    /// let doc = EditorDocument::from_content("Title: Don't panic").unwrap();
+    /// let word = Selection::select_word_at(Position::new(9), &doc);
+    /// assert_eq!(doc.text_range(word.range()).unwrap(), "Don't");
+    /// ```
+    #[must_use]
+    pub fn select_word_at(pos: Position, doc: &EditorDocument) -> Self {
+        let (line, _) = pos.to_line_col(doc);
+        let Some((range, text)) = doc
+            .line_range(line)
+            .and_then(|range| Some((range, doc.text_range(range).ok()?)))
+        else {
+            return Self::empty(pos);
+        };
+
+        let offset = pos.offset.saturating_sub(range.start.offset);
+        let is_word = |segment: &str| segment.chars().any(char::is_alphanumeric);
+        let mut word = None;
+        for (start, segment) in text.split_word_bound_indices() {
+            let end = start + segment.len();
+            if start > offset {
+                break;
+            }
+            if offset < end && is_word(segment) {
+                word = Some((start, end));
+                break;
+            }
+            if end == offset && is_word(segment) {
+                word = Some((start, end));
+            }
+        }
+
+        word.map_or_else(
+            || Self::empty(pos),
+            |(start, end)| Self::new(range.start.advance(start), range.start.advance(end)),
+        )
+    }
 }
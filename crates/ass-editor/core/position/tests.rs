@@ -64,3 +64,142 @@ fn position_builder_offset() {
     let pos = PositionBuilder::new().offset(42).build().unwrap();
     assert_eq!(pos.offset, 42);
 }
+
+/// Range from byte offsets
+fn span(start: usize, end: usize) -> Range {
+    Range::new(Position::new(start), Position::new(end))
+}
+
+#[test]
+fn range_subtract_cases() {
+    let range = span(10, 20);
+    assert_eq!(range.subtract(&span(0, 5)), [range]);
+    assert_eq!(range.subtract(&span(20, 30)), [range]);
+    assert_eq!(range.subtract(&span(5, 25)), []);
+    assert_eq!(range.subtract(&span(10, 20)), []);
+    assert_eq!(range.subtract(&span(5, 15)), [span(15, 20)]);
+    assert_eq!(range.subtract(&span(15, 25)), [span(10, 15)]);
+    assert_eq!(range.subtract(&span(12, 17)), [span(10, 12), span(17, 20)]);
+    assert_eq!(span(3, 3).subtract(&span(0, 1)), []);
+}
+
+#[test]
+fn range_contains_position_includes_end() {
+    let range = span(2, 5);
+    assert!(range.contains_position(Position::new(2)));
+    assert!(range.contains_position(Position::new(5)));
+    assert!(!range.contains(Position::new(5)));
+    assert!(!range.contains_position(Position::new(6)));
+    assert!(span(4, 4).contains_position(Position::new(4)));
+}
+
+#[test]
+fn selection_select_word_at() {
+    let doc = crate::core::EditorDocument::from_content(
+        "[Events]\nDialogue: 0,0:00:00.00,0:00:01.00,Default,,0,0,0,,Ça va? 東京タワー don't",
+    )
+    .unwrap();
+    let text = doc.text();
+    let word_at = |needle: &str, skip: usize| {
+        let offset = text.find(needle).unwrap() + skip;
+        let selection = Selection::select_word_at(Position::new(offset), &doc);
+        doc.text_range(selection.range()).unwrap()
+    };
+
+    assert_eq!(word_at("Ça", 0), "Ça");
+    assert_eq!(word_at("va?", 2), "va");
+    assert_eq!(word_at("don't", 3), "don't");
+    assert_eq!(word_at("don't", 5), "don't");
+    assert!(!word_at("東京", 0).is_empty());
+    assert_eq!(word_at("Events", 0), "Events");
+
+    // Between punctuation and a space there is no word
+    let offset = text.find("? ").unwrap() + 1;
+    let selection = Selection::select_word_at(Position::new(offset), &doc);
+    assert!(selection.is_empty());
+    assert_eq!(selection.cursor.offset, offset);
+}
+
+#[cfg(feature = "std")]
+mod range_properties {
+    use super::span;
+    use crate::core::position::{Position, Range};
+    use proptest::prelude::*;
+
+    /// Largest offset the generated ranges use
+    const LIMIT: usize = 64;
+
+    /// Ranges within `0..=LIMIT`
+    fn ranges() -> impl Strategy<Value = Range> {
+        (0..=LIMIT, 0..=LIMIT).prop_map(|(a, b)| span(a, b))
+    }
+
+    /// Whether byte `offset` is covered by any of `parts`
+    fn covered(parts: &[Range], offset: usize) -> bool {
+        parts
+            .iter()
+            .any(|part| part.contains(Position::new(offset)))
+    }
+
+    proptest! {
+        #[test]
+        fn subtract_matches_set_difference(a in ranges(), b in ranges()) {
+            let parts = a.subtract(&b);
+            prop_assert!(parts.len() <= 2);
+            for offset in 0..LIMIT {
+                let position = Position::new(offset);
+                prop_assert_eq!(
+                    covered(&parts, offset),
+                    a.contains(position) && !b.contains(position)
+                );
+            }
+        }
+
+        #[test]
+        fn de_morgan_laws_hold_within_universe(
+            universe in ranges(),
+            a in ranges(),
+            b in ranges(),
+        ) {
+            let minus_a = universe.subtract(&a);
+            let minus_b = universe.subtract(&b);
+
+            // U - (A ∩ B) = (U - A) ∪ (U - B)
+            let minus_both = a
+                .intersection(&b)
+                .map_or_else(|| vec![universe], |overlap| universe.subtract(&overlap));
+            for offset in 0..LIMIT {
+                prop_assert_eq!(
+                    covered(&minus_both, offset),
+                    covered(&minus_a, offset) || covered(&minus_b, offset)
+                );
+            }
+
+            // U - (A ∪ B) = (U - A) ∩ (U - B), where the union is a set union
+            if a.overlaps(&b) || a.end == b.start || b.end == a.start {
+                let minus_either = universe.subtract(&a.union(&b));
+                for offset in 0..LIMIT {
+                    prop_assert_eq!(
+                        covered(&minus_either, offset),
+                        covered(&minus_a, offset) && covered(&minus_b, offset)
+                    );
+                }
+            }
+        }
+
+        #[test]
+        fn set_operations_are_idempotent(a in ranges(), b in ranges()) {
+            prop_assert_eq!(a.union(&a), a);
+            prop_assert_eq!(a.union(&b).union(&b), a.union(&b));
+            if !a.is_empty() {
+                prop_assert_eq!(a.intersection(&a), Some(a));
+            }
+            if let Some(overlap) = a.intersection(&b) {
+                prop_assert_eq!(overlap.intersection(&b), Some(overlap));
+            }
+            for part in a.subtract(&b) {
+                prop_assert_eq!(part.subtract(&b), [part]);
+            }
+        }
+    }
+}
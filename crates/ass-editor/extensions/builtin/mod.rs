@@ -3,12 +3,18 @@
 //! This module provides commonly-used extensions that ship with the editor:
 //! - Syntax highlighting for ASS/SSA files
 //! - Auto-completion for ASS format elements
+//! - Detection and repair of broken override tags
+//! - Reading-speed (CPS) analysis of dialogue events
 
 pub mod auto_complete;
+pub mod reading_speed;
 pub mod syntax_highlight;
+pub mod tag_repair;
 
 pub use auto_complete::AutoCompleteExtension;
+pub use reading_speed::{ReadingSpeedExtension, ReadingSpeedInfo, ReadingSpeedSummary};
 pub use syntax_highlight::SyntaxHighlightExtension;
+pub use tag_repair::{BrokenTagRepair, TagRepairExtension};
 
 /// Load all built-in extensions into an extension manager
 #[cfg(feature = "std")]
@@ -23,6 +29,14 @@ pub fn load_builtin_extensions(
     let autocomplete_ext = Box::new(AutoCompleteExtension::new());
     manager.load_extension(autocomplete_ext)?;
 
+    // Load broken-tag repair
+    let tag_repair_ext = Box::new(TagRepairExtension::new()?);
+    manager.load_extension(tag_repair_ext)?;
+
+    // Load reading-speed analysis
+    let reading_speed_ext = Box::new(ReadingSpeedExtension::new());
+    manager.load_extension(reading_speed_ext)?;
+
     Ok(())
 }
 
@@ -0,0 +1,109 @@
+//! Per-event reading speed measurements and their document summary.
+
+use crate::core::{EditorDocument, Result};
+use crate::utils::{CpsValidator, EventIndex, ValidatorConfig};
+use ass_core::parser::{Event, Section};
+
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+
+/// Reading speed of one dialogue event
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ReadingSpeedInfo {
+    /// Characters per second
+    pub cps: f64,
+    /// Visible length under the configured `CpsCountMode`
+    pub char_count: usize,
+    /// Display duration in centiseconds
+    pub duration_cs: u32,
+    /// Whether `cps` exceeds `ValidatorConfig::max_cps`
+    pub over_limit: bool,
+}
+
+/// Reading speed statistics over every measured event
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct ReadingSpeedSummary {
+    /// Mean characters per second
+    pub average_cps: f64,
+    /// Fastest event
+    pub max_cps: f64,
+    /// Slowest event
+    pub min_cps: f64,
+    /// Number of events over the limit
+    pub violation_count: usize,
+}
+
+impl ReadingSpeedInfo {
+    /// Measure `event`, or `None` for comments, events without visible
+    /// text and events with unparsable times
+    pub(super) fn measure(
+        event: &Event,
+        validator: &CpsValidator,
+        config: &ValidatorConfig,
+    ) -> Option<Self> {
+        if !event.is_dialogue() {
+            return None;
+        }
+        let char_count = validator.text_length(event.text);
+        if char_count == 0 {
+            return None;
+        }
+        let duration_cs = event.duration_cs().ok()?;
+        // Zero-length events read at the speed of a single centisecond
+        let cps = char_count as f64 * 100.0 / f64::from(duration_cs.max(1));
+        Some(Self {
+            cps,
+            char_count,
+            duration_cs,
+            over_limit: cps > config.max_cps,
+        })
+    }
+
+    /// Measure every dialogue event of `document`, keyed by event index
+    pub(super) fn measure_document(
+        document: &EditorDocument,
+        config: &ValidatorConfig,
+    ) -> Result<Vec<(EventIndex, Self)>> {
+        let validator = CpsValidator::new(config);
+        document.parse_script_with(|script| {
+            script
+                .sections()
+                .iter()
+                .filter_map(|section| match section {
+                    Section::Events(events) => Some(events),
+                    _ => None,
+                })
+                .flatten()
+                .enumerate()
+                .filter_map(|(index, event)| {
+                    Self::measure(event, &validator, config).map(|info| (index, info))
+                })
+                .collect()
+        })
+    }
+}
+
+impl ReadingSpeedSummary {
+    /// Summarize measured events, all zero when there are none
+    pub(super) fn from_infos<'a>(infos: impl IntoIterator<Item = &'a ReadingSpeedInfo>) -> Self {
+        let mut summary = Self::default();
+        let mut total = 0.0;
+        let mut count = 0usize;
+        for info in infos {
+            if count == 0 {
+                summary.max_cps = info.cps;
+                summary.min_cps = info.cps;
+            } else {
+                summary.max_cps = summary.max_cps.max(info.cps);
+                summary.min_cps = summary.min_cps.min(info.cps);
+            }
+            total += info.cps;
+            count += 1;
+            summary.violation_count += usize::from(info.over_limit);
+        }
+        if count > 0 {
+            summary.average_cps = total / count as f64;
+        }
+        summary
+    }
+}
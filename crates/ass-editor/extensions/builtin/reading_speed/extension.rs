@@ -0,0 +1,214 @@
+//! `EditorExtension` keeping a reading-speed cache of the current document.
+
+use super::{ReadingSpeedInfo, ReadingSpeedSummary};
+use crate::core::{EditorDocument, Result};
+use crate::events::DocumentEvent;
+use crate::extensions::{
+    EditorExtension, ExtensionCapability, ExtensionCommand, ExtensionContext, ExtensionInfo,
+    ExtensionResult, ExtensionState, MessageLevel,
+};
+use crate::utils::{EventIndex, ValidatorConfig};
+
+#[cfg(not(feature = "std"))]
+use alloc::{
+    collections::BTreeMap as HashMap,
+    format,
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+#[cfg(feature = "std")]
+use std::collections::HashMap;
+
+/// Extension measuring dialogue reading speed against
+/// `ValidatorConfig::max_cps`
+///
+/// Measurements are cached per event and dropped on any text insertion,
+/// deletion or replacement, so the next query re-measures the document.
+pub struct ReadingSpeedExtension {
+    info: ExtensionInfo,
+    state: ExtensionState,
+    /// Source of the CPS limit and counting mode
+    config: ValidatorConfig,
+    /// Measured dialogue events in document order, `None` once invalidated
+    cache: Option<Vec<(EventIndex, ReadingSpeedInfo)>>,
+    /// Events over the limit found by the last highlight command
+    violations: Vec<EventIndex>,
+}
+
+impl ReadingSpeedExtension {
+    /// Create a reading speed extension with the default validator config
+    pub fn new() -> Self {
+        Self::with_config(ValidatorConfig::default())
+    }
+
+    /// Create a reading speed extension using the limits of `config`
+    pub fn with_config(config: ValidatorConfig) -> Self {
+        let info = ExtensionInfo::new(
+            "reading-speed".to_string(),
+            "1.0.0".to_string(),
+            "ASS-RS Team".to_string(),
+            "Measures dialogue reading speed in characters per second".to_string(),
+        )
+        .with_capability(ExtensionCapability::Linting)
+        .with_license("MIT".to_string());
+
+        Self {
+            info,
+            state: ExtensionState::Uninitialized,
+            config,
+            cache: None,
+            violations: Vec::new(),
+        }
+    }
+
+    /// Validator config supplying the limit
+    pub fn config(&self) -> &ValidatorConfig {
+        &self.config
+    }
+
+    /// Replace the validator config, dropping cached measurements
+    pub fn set_config(&mut self, config: ValidatorConfig) {
+        self.config = config;
+        self.invalidate();
+    }
+
+    /// Measure `document` unless a cached measurement is still valid
+    pub fn analyze(
+        &mut self,
+        document: &EditorDocument,
+    ) -> Result<&[(EventIndex, ReadingSpeedInfo)]> {
+        if self.cache.is_none() {
+            self.cache = Some(ReadingSpeedInfo::measure_document(document, &self.config)?);
+        }
+        Ok(self.cache.as_deref().unwrap_or_default())
+    }
+
+    /// Cached measurement of event `index`, if measured and a dialogue
+    pub fn event_info(&self, index: EventIndex) -> Option<&ReadingSpeedInfo> {
+        let cache = self.cache.as_ref()?;
+        cache
+            .binary_search_by_key(&index, |(i, _)| *i)
+            .ok()
+            .map(|pos| &cache[pos].1)
+    }
+
+    /// Whether measurements are cached for the current text
+    pub fn is_cached(&self) -> bool {
+        self.cache.is_some()
+    }
+
+    /// Drop cached measurements and highlighted violations
+    pub fn invalidate(&mut self) {
+        self.cache = None;
+        self.violations.clear();
+    }
+
+    /// Events over the limit found by `reading-speed.highlight-violations`
+    pub fn violations(&self) -> &[EventIndex] {
+        &self.violations
+    }
+
+    /// Statistics over the cached measurements, all zero when none are
+    /// cached
+    pub fn summary(&self) -> ReadingSpeedSummary {
+        ReadingSpeedSummary::from_infos(self.cache.iter().flatten().map(|(_, info)| info))
+    }
+
+    /// Measure `document` and record the events over the limit
+    pub fn highlight_violations(&mut self, document: &EditorDocument) -> Result<&[EventIndex]> {
+        let violations = self
+            .analyze(document)?
+            .iter()
+            .filter(|(_, info)| info.over_limit)
+            .map(|(index, _)| *index)
+            .collect();
+        self.violations = violations;
+        Ok(&self.violations)
+    }
+}
+
+impl Default for ReadingSpeedExtension {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl EditorExtension for ReadingSpeedExtension {
+    fn info(&self) -> &ExtensionInfo {
+        &self.info
+    }
+
+    fn initialize(&mut self, context: &mut dyn ExtensionContext) -> Result<()> {
+        self.state = ExtensionState::Active;
+        context.show_message("Reading speed analysis initialized", MessageLevel::Info)?;
+        Ok(())
+    }
+
+    fn shutdown(&mut self, _context: &mut dyn ExtensionContext) -> Result<()> {
+        self.invalidate();
+        self.state = ExtensionState::Shutdown;
+        Ok(())
+    }
+
+    fn state(&self) -> ExtensionState {
+        self.state
+    }
+
+    fn execute_command(
+        &mut self,
+        command_id: &str,
+        _args: &HashMap<String, String>,
+        context: &mut dyn ExtensionContext,
+    ) -> Result<ExtensionResult> {
+        match command_id {
+            "reading-speed.highlight-violations" => {
+                let Some(doc) = context.current_document() else {
+                    return Ok(ExtensionResult::failure("No active document".to_string()));
+                };
+                let max_cps = self.config.max_cps;
+                let violations = self.highlight_violations(doc)?;
+                let list = violations
+                    .iter()
+                    .map(ToString::to_string)
+                    .collect::<Vec<_>>()
+                    .join(",");
+                Ok(ExtensionResult::success_with_message(format!(
+                    "Found {} event(s) above {:.1} CPS",
+                    violations.len(),
+                    max_cps
+                ))
+                .with_data("violation_count".to_string(), violations.len().to_string())
+                .with_data("violations".to_string(), list))
+            }
+            _ => Ok(ExtensionResult::failure(format!(
+                "Unknown command: {command_id}"
+            ))),
+        }
+    }
+
+    fn commands(&self) -> Vec<ExtensionCommand> {
+        vec![ExtensionCommand::new(
+            "reading-speed.highlight-violations".to_string(),
+            "Highlight Reading Speed Violations".to_string(),
+            "List dialogue events read faster than the configured CPS limit".to_string(),
+        )
+        .with_category("Analysis".to_string())]
+    }
+
+    fn handle_event(
+        &mut self,
+        event: &DocumentEvent,
+        _context: &mut dyn ExtensionContext,
+    ) -> Result<()> {
+        if matches!(
+            event,
+            DocumentEvent::TextInserted { .. }
+                | DocumentEvent::TextDeleted { .. }
+                | DocumentEvent::TextReplaced { .. }
+        ) {
+            self.invalidate();
+        }
+        Ok(())
+    }
+}
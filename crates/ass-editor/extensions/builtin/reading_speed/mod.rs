@@ -0,0 +1,17 @@
+//! Built-in reading-speed analysis
+//!
+//! Measures the characters per second of every dialogue event with the
+//! `CpsValidator` counting rules (CJK characters count double by default)
+//! and keeps the results cached until the document text changes:
+//! - Per-event `ReadingSpeedInfo` for inline display
+//! - A list of events above `ValidatorConfig::max_cps`
+//! - A `ReadingSpeedSummary` over the whole document
+
+mod analysis;
+mod extension;
+
+#[cfg(test)]
+mod tests;
+
+pub use analysis::{ReadingSpeedInfo, ReadingSpeedSummary};
+pub use extension::ReadingSpeedExtension;
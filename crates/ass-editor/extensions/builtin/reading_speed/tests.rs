@@ -0,0 +1,131 @@
+//! Unit tests for reading-speed measurement and cache invalidation.
+
+use super::*;
+use crate::core::{EditorDocument, Position, Range};
+use crate::events::DocumentEvent;
+use crate::extensions::{EditorExtension, ExtensionManager};
+use crate::utils::{CpsCountMode, ValidatorConfig};
+
+#[cfg(not(feature = "std"))]
+use alloc::{
+    collections::BTreeMap as HashMap,
+    string::{String, ToString},
+};
+#[cfg(feature = "std")]
+use std::collections::HashMap;
+
+const DOCUMENT: &str = "[Events]\nFormat: Layer, Start, End, Style, Name, MarginL, MarginR, MarginV, Effect, Text\nDialogue: 0,0:00:00.00,0:00:01.00,Default,,0,0,0,,Hello\nDialogue: 0,0:00:01.00,0:00:01.20,Default,,0,0,0,,日本語字\nComment: 0,0:00:01.00,0:00:01.10,Default,,0,0,0,,Way too fast to read\nDialogue: 0,0:00:02.00,0:00:06.00,Default,,0,0,0,,{\\b1}Slow text";
+
+#[test]
+fn cjk_characters_count_double() {
+    let doc = EditorDocument::from_content(DOCUMENT).unwrap();
+    let mut ext = ReadingSpeedExtension::new();
+    ext.analyze(&doc).unwrap();
+
+    let cjk = ext.event_info(1).unwrap();
+    assert_eq!(cjk.char_count, 8);
+    assert_eq!(cjk.duration_cs, 20);
+    assert_eq!(cjk.cps, 40.0);
+    assert!(cjk.over_limit);
+
+    let latin = ext.event_info(0).unwrap();
+    assert_eq!((latin.char_count, latin.cps), (5, 5.0));
+    assert!(!latin.over_limit);
+
+    // Override blocks and whitespace are not read
+    assert_eq!(ext.event_info(3).unwrap().char_count, 8);
+    // Comments are never measured
+    assert!(ext.event_info(2).is_none());
+}
+
+#[test]
+fn summary_covers_measured_dialogue() {
+    let doc = EditorDocument::from_content(DOCUMENT).unwrap();
+    let mut ext = ReadingSpeedExtension::new();
+    assert_eq!(ext.summary(), ReadingSpeedSummary::default());
+
+    ext.analyze(&doc).unwrap();
+    let summary = ext.summary();
+    assert_eq!(summary.max_cps, 40.0);
+    assert_eq!(summary.min_cps, 2.0);
+    assert_eq!(summary.average_cps, 47.0 / 3.0);
+    assert_eq!(summary.violation_count, 1);
+}
+
+#[test]
+fn edits_invalidate_the_cache() {
+    let mut doc = EditorDocument::from_content(DOCUMENT).unwrap();
+    let mut manager = ExtensionManager::new();
+    let mut ext = ReadingSpeedExtension::new();
+    ext.analyze(&doc).unwrap();
+    assert!(ext.is_cached());
+
+    let start = DOCUMENT.find("Hello").unwrap();
+    let range = Range::new(Position::new(start), Position::new(start + 5));
+    doc.replace(range, "Hello there, everyone").unwrap();
+    let event = DocumentEvent::text_replaced(
+        range,
+        "Hello".to_string(),
+        "Hello there, everyone".to_string(),
+    );
+    let mut context = manager
+        .create_context("reading-speed".to_string(), None)
+        .unwrap();
+    ext.handle_event(&event, &mut *context).unwrap();
+    drop(context);
+    assert!(!ext.is_cached());
+    assert!(ext.event_info(0).is_none());
+
+    ext.analyze(&doc).unwrap();
+    let info = ext.event_info(0).unwrap();
+    assert_eq!(info.char_count, 19);
+    assert!(!info.over_limit);
+}
+
+#[test]
+fn config_supplies_the_limit() {
+    let doc = EditorDocument::from_content(DOCUMENT).unwrap();
+    let mut ext = ReadingSpeedExtension::new();
+    assert_eq!(ext.config().max_cps, 20.0);
+    ext.analyze(&doc).unwrap();
+
+    ext.set_config(ValidatorConfig {
+        max_cps: 4.0,
+        count_mode: CpsCountMode::Bytes,
+        ..ValidatorConfig::default()
+    });
+    assert!(!ext.is_cached());
+    ext.analyze(&doc).unwrap();
+    // "日本語字" is twelve UTF-8 bytes
+    assert_eq!(ext.event_info(1).unwrap().char_count, 12);
+    assert_eq!(ext.summary().violation_count, 2);
+}
+
+#[test]
+fn command_highlights_violations() {
+    let mut ext = ReadingSpeedExtension::new();
+    assert_eq!(ext.info().name, "reading-speed");
+    assert_eq!(ext.commands().len(), 1);
+
+    let mut manager = ExtensionManager::new();
+    let mut doc = EditorDocument::from_content(DOCUMENT).unwrap();
+    let mut context = manager
+        .create_context("reading-speed".to_string(), Some(&mut doc))
+        .unwrap();
+    ext.initialize(&mut *context).unwrap();
+
+    let result = ext
+        .execute_command(
+            "reading-speed.highlight-violations",
+            &HashMap::new(),
+            &mut *context,
+        )
+        .unwrap();
+    assert!(result.success);
+    assert_eq!(
+        result.data.get("violation_count").map(String::as_str),
+        Some("1")
+    );
+    assert_eq!(result.data.get("violations").map(String::as_str), Some("1"));
+    assert_eq!(ext.violations(), [1]);
+}
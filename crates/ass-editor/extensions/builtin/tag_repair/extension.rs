@@ -0,0 +1,131 @@
+//! `EditorExtension` wrapper exposing tag repair as editor commands.
+
+use super::BrokenTagRepair;
+use crate::core::Result;
+use crate::extensions::{
+    EditorExtension, ExtensionCapability, ExtensionCommand, ExtensionContext, ExtensionInfo,
+    ExtensionResult, ExtensionState, MessageLevel,
+};
+
+#[cfg(not(feature = "std"))]
+use alloc::{
+    collections::BTreeMap as HashMap,
+    format,
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+#[cfg(feature = "std")]
+use std::collections::HashMap;
+
+/// Extension offering broken-tag scanning and repair
+pub struct TagRepairExtension {
+    info: ExtensionInfo,
+    state: ExtensionState,
+    /// Repairer shared by all commands
+    repair: BrokenTagRepair,
+}
+
+impl TagRepairExtension {
+    /// Create a new tag repair extension
+    pub fn new() -> Result<Self> {
+        let info = ExtensionInfo::new(
+            "tag-repair".to_string(),
+            "1.0.0".to_string(),
+            "ASS-RS Team".to_string(),
+            "Detects and repairs broken override tags".to_string(),
+        )
+        .with_capability(ExtensionCapability::Linting)
+        .with_capability(ExtensionCapability::TextProcessing)
+        .with_license("MIT".to_string());
+
+        Ok(Self {
+            info,
+            state: ExtensionState::Uninitialized,
+            repair: BrokenTagRepair::new()?,
+        })
+    }
+
+    /// The underlying repairer
+    pub fn repair(&self) -> &BrokenTagRepair {
+        &self.repair
+    }
+}
+
+impl EditorExtension for TagRepairExtension {
+    fn info(&self) -> &ExtensionInfo {
+        &self.info
+    }
+
+    fn initialize(&mut self, context: &mut dyn ExtensionContext) -> Result<()> {
+        self.state = ExtensionState::Active;
+        context.show_message("Tag repair initialized", MessageLevel::Info)?;
+        Ok(())
+    }
+
+    fn shutdown(&mut self, _context: &mut dyn ExtensionContext) -> Result<()> {
+        self.state = ExtensionState::Shutdown;
+        Ok(())
+    }
+
+    fn state(&self) -> ExtensionState {
+        self.state
+    }
+
+    fn execute_command(
+        &mut self,
+        command_id: &str,
+        _args: &HashMap<String, String>,
+        context: &mut dyn ExtensionContext,
+    ) -> Result<ExtensionResult> {
+        match command_id {
+            "tag_repair.scan" => {
+                let Some(doc) = context.current_document() else {
+                    return Ok(ExtensionResult::failure("No active document".to_string()));
+                };
+                let issues = self.repair.scan_document(doc)?;
+                Ok(ExtensionResult::success_with_message(format!(
+                    "Found {} broken tag(s)",
+                    issues.len()
+                ))
+                .with_data("issue_count".to_string(), issues.len().to_string()))
+            }
+            "tag_repair.repair" => {
+                let Some(doc) = context.current_document_mut() else {
+                    return Ok(ExtensionResult::failure("No active document".to_string()));
+                };
+                let command = self.repair.repair_command(doc)?;
+                let count = command.commands.len();
+                if count > 0 {
+                    doc.execute_command(&command)?;
+                }
+                Ok(
+                    ExtensionResult::success_with_message(format!(
+                        "Repaired {count} broken tag(s)"
+                    ))
+                    .with_data("repair_count".to_string(), count.to_string()),
+                )
+            }
+            _ => Ok(ExtensionResult::failure(format!(
+                "Unknown command: {command_id}"
+            ))),
+        }
+    }
+
+    fn commands(&self) -> Vec<ExtensionCommand> {
+        vec![
+            ExtensionCommand::new(
+                "tag_repair.scan".to_string(),
+                "Find Broken Tags".to_string(),
+                "Report broken override tags in the current document".to_string(),
+            )
+            .with_category("Repair".to_string()),
+            ExtensionCommand::new(
+                "tag_repair.repair".to_string(),
+                "Repair Broken Tags".to_string(),
+                "Fix broken override tags in the current document as one undoable step".to_string(),
+            )
+            .with_category("Repair".to_string()),
+        ]
+    }
+}
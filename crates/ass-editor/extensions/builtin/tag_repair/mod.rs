@@ -0,0 +1,21 @@
+//! Built-in detection and repair of broken override tags
+//!
+//! Detects the override-block damage common in real-world scripts:
+//! - Unclosed `{` and stray `}`
+//! - Tag names with no registered handler (e.g. `\foo`)
+//! - Numeric arguments outside a tag's range (e.g. `\frz400`, `\b11`)
+//! - `\t(` animations missing their closing `)`
+//!
+//! Repairs are conservative: values with a clear range are clamped, and
+//! anything ambiguous is removed rather than guessed at.
+
+mod extension;
+mod repair;
+mod scan;
+
+#[cfg(test)]
+mod tests;
+
+pub use extension::TagRepairExtension;
+pub use repair::BrokenTagRepair;
+pub use scan::{TagFix, TagIssue, TagIssueKind};
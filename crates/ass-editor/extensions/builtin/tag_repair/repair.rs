@@ -0,0 +1,122 @@
+//! `BrokenTagRepair` entry points for plain text and whole documents.
+
+use super::scan::{Scanner, TagFix, TagIssue};
+use crate::commands::{BatchCommand, EditorCommand, ReplaceTextCommand};
+use crate::core::{EditorDocument, Position, Range, Result};
+use crate::extensions::registry_integration::RegistryIntegration;
+use ass_core::parser::Section;
+
+#[cfg(not(feature = "std"))]
+use alloc::{
+    boxed::Box,
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
+
+/// Detects and repairs broken override tags
+///
+/// Tag names are recognized when the wrapped registry has a handler for
+/// them, so custom tags registered by extensions are never reported.
+///
+/// # Examples
+///
+/// ```
+/// use ass_editor::extensions::builtin::tag_repair::BrokenTagRepair;
+///
+/// let repair = BrokenTagRepair::new().unwrap();
+/// assert_eq!(repair.repair("{\\frz400\\foo1}Hi}"), "{\\frz360}Hi");
+/// ```
+#[derive(Debug)]
+pub struct BrokenTagRepair {
+    /// Registry holding the recognized tag handlers
+    registry: RegistryIntegration,
+}
+
+impl BrokenTagRepair {
+    /// Create a repairer recognizing ass-core's built-in tags
+    pub fn new() -> Result<Self> {
+        let mut registry = RegistryIntegration::new();
+        registry.register_builtin_handlers()?;
+        Ok(Self { registry })
+    }
+
+    /// Create a repairer recognizing the tags handled by `registry`
+    pub fn with_registry(registry: RegistryIntegration) -> Self {
+        Self { registry }
+    }
+
+    /// Find broken override tags in event `text`
+    pub fn scan(&self, text: &str) -> Vec<TagIssue> {
+        Scanner::scan(self.registry.registry(), text)
+    }
+
+    /// Return `text` with every issue found by [`Self::scan`] fixed
+    pub fn repair(&self, text: &str) -> String {
+        let mut fixes: Vec<TagFix> = self.scan(text).into_iter().map(|issue| issue.fix).collect();
+        sort_back_to_front(&mut fixes);
+        let mut repaired = text.to_string();
+        for fix in fixes {
+            repaired.replace_range(fix.range, &fix.replacement);
+        }
+        repaired
+    }
+
+    /// Find broken override tags in the text of every event in `document`
+    ///
+    /// Issue spans and fixes are byte offsets into the document.
+    pub fn scan_document(&self, document: &EditorDocument) -> Result<Vec<TagIssue>> {
+        document.parse_script_with(|script| {
+            let base = script.source().as_ptr() as usize;
+            let mut issues = Vec::new();
+            let events = script
+                .sections()
+                .iter()
+                .filter_map(|section| match section {
+                    Section::Events(events) => Some(events),
+                    _ => None,
+                })
+                .flatten();
+            for event in events {
+                let offset = event.text.as_ptr() as usize - base;
+                issues.extend(self.scan(event.text).into_iter().map(|mut issue| {
+                    issue.span = issue.span.start + offset..issue.span.end + offset;
+                    issue.fix.range = issue.fix.range.start + offset..issue.fix.range.end + offset;
+                    issue
+                }));
+            }
+            issues
+        })
+    }
+
+    /// Command fixing every issue found by [`Self::scan_document`]
+    ///
+    /// The edits run back to front as one batch, so the whole repair is
+    /// undone in a single step.
+    pub fn repair_command(&self, document: &EditorDocument) -> Result<BatchCommand> {
+        let mut fixes: Vec<TagFix> = self
+            .scan_document(document)?
+            .into_iter()
+            .map(|issue| issue.fix)
+            .collect();
+        sort_back_to_front(&mut fixes);
+        let count = fixes.len();
+        let commands = fixes
+            .into_iter()
+            .map(|fix| {
+                let range =
+                    Range::new(Position::new(fix.range.start), Position::new(fix.range.end));
+                Box::new(ReplaceTextCommand::new(range, fix.replacement)) as Box<dyn EditorCommand>
+            })
+            .collect();
+        Ok(BatchCommand::new(format!("Repair {count} broken tag(s)")).add_commands(commands))
+    }
+}
+
+/// Order fixes so applying them in sequence never shifts a later one
+///
+/// Removals sort ahead of insertions at the same offset, and insertions at
+/// the same offset keep scan order, so `\t(` closes before its block.
+fn sort_back_to_front(fixes: &mut [TagFix]) {
+    fixes.sort_by_key(|fix| core::cmp::Reverse((fix.range.start, fix.range.end)));
+}
@@ -0,0 +1,321 @@
+//! Override-block scanner producing [`TagIssue`]s with their fixes.
+
+use ass_core::plugin::{ExtensionRegistry, TagResult};
+use core::ops::Range;
+
+#[cfg(not(feature = "std"))]
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
+
+/// Standard tags ass-core has no handler for, accepted as recognized
+const UNHANDLED_STANDARD_TAGS: [&str; 9] = [
+    "blur", "xbord", "ybord", "xshad", "yshad", "iclip", "K", "p", "pbo",
+];
+
+/// Inclusive bounds that out-of-range numeric arguments are clamped to
+const NUMERIC_BOUNDS: [(&str, f64, f64); 14] = [
+    ("frx", -360.0, 360.0),
+    ("fry", -360.0, 360.0),
+    ("frz", -360.0, 360.0),
+    ("fr", -360.0, 360.0),
+    ("bord", 0.0, f64::MAX),
+    ("xbord", 0.0, f64::MAX),
+    ("ybord", 0.0, f64::MAX),
+    ("shad", 0.0, f64::MAX),
+    ("be", 0.0, 127.0),
+    ("blur", 0.0, f64::MAX),
+    ("k", 0.0, f64::MAX),
+    ("K", 0.0, f64::MAX),
+    ("kf", 0.0, f64::MAX),
+    ("ko", 0.0, f64::MAX),
+];
+
+/// Category of a broken override tag
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TagIssueKind {
+    /// `{` with no matching `}` before the next `{` or the end of the text
+    UnclosedBrace,
+    /// `}` outside any override block
+    ExtraClosingBrace,
+    /// Tag name with no registered handler
+    UnknownTag,
+    /// Numeric argument outside the values the tag accepts
+    OutOfRange,
+    /// `\t(` without a closing `)`
+    UnmatchedTransform,
+}
+
+/// Edit that repairs a [`TagIssue`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TagFix {
+    /// Byte range to replace; empty for insertions
+    pub range: Range<usize>,
+    /// Text written over `range`; empty for removals
+    pub replacement: String,
+}
+
+/// A broken override tag found by [`BrokenTagRepair`](super::BrokenTagRepair)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TagIssue {
+    /// What is wrong
+    pub kind: TagIssueKind,
+    /// Byte range of the offending text
+    pub span: Range<usize>,
+    /// Human-readable description
+    pub message: String,
+    /// Conservative repair for this issue
+    pub fix: TagFix,
+}
+
+/// Single pass over one piece of event text
+pub(super) struct Scanner<'a> {
+    /// Registry deciding which tag names are recognized
+    registry: &'a ExtensionRegistry,
+    /// Text being scanned
+    text: &'a str,
+    /// Issues found so far, in text order
+    issues: Vec<TagIssue>,
+}
+
+impl<'a> Scanner<'a> {
+    /// Scan `text`, returning its issues in text order
+    pub fn scan(registry: &'a ExtensionRegistry, text: &'a str) -> Vec<TagIssue> {
+        let mut scanner = Self {
+            registry,
+            text,
+            issues: Vec::new(),
+        };
+        scanner.scan_text();
+        scanner.issues
+    }
+
+    /// Find override blocks and stray braces at the top level
+    fn scan_text(&mut self) {
+        let bytes = self.text.as_bytes();
+        let mut pos = 0;
+        while pos < bytes.len() {
+            match bytes[pos] {
+                b'{' => {
+                    let rest = &self.text[pos + 1..];
+                    let next_open = rest.find('{');
+                    pos = match rest.find('}') {
+                        Some(close) if next_open.is_none_or(|open| close < open) => {
+                            self.scan_tags(pos + 1, pos + 1 + close);
+                            pos + close + 2
+                        }
+                        _ => self.unclosed_block(pos, pos + 1 + next_open.unwrap_or(rest.len())),
+                    };
+                }
+                b'}' => {
+                    self.push(
+                        TagIssueKind::ExtraClosingBrace,
+                        pos..pos + 1,
+                        "Closing brace outside an override block".to_string(),
+                        pos..pos + 1,
+                        "",
+                    );
+                    pos += 1;
+                }
+                _ => pos += 1,
+            }
+        }
+    }
+
+    /// Report the `{` at `open`, whose block can extend no further than
+    /// `limit`, and return where top-level scanning resumes
+    ///
+    /// Blocks starting with a tag are closed where the run of tags ends;
+    /// anything else cannot be told apart from text, so the `{` is removed.
+    fn unclosed_block(&mut self, open: usize, limit: usize) -> usize {
+        let start = open + 1;
+        let content = &self.text[start..limit];
+        if !content.starts_with('\\') {
+            self.push(
+                TagIssueKind::UnclosedBrace,
+                open..start,
+                "Unclosed override block".to_string(),
+                open..start,
+                "",
+            );
+            return start;
+        }
+        let end = start + tag_run_len(content);
+        self.push(
+            TagIssueKind::UnclosedBrace,
+            open..start,
+            "Unclosed override block".to_string(),
+            end..end,
+            "}",
+        );
+        self.scan_tags(start, end);
+        end
+    }
+
+    /// Check every tag in `start..end`, descending into `\t(...)`
+    fn scan_tags(&mut self, start: usize, end: usize) {
+        let mut pos = start;
+        while let Some(found) = self.text[pos..end].find('\\') {
+            let tag_start = pos + found;
+            let name_start = tag_start + 1;
+            let name_end = name_start + tag_name_len(&self.text[name_start..end]);
+            let name = &self.text[name_start..name_end];
+            if name.is_empty() {
+                pos = name_start;
+                continue;
+            }
+
+            if name == "t" && self.text[name_end..end].starts_with('(') {
+                let inner_start = name_end + 1;
+                let inner_end = match closing_paren(&self.text[name_end..end]) {
+                    Some(close) => name_end + close,
+                    None => {
+                        self.push(
+                            TagIssueKind::UnmatchedTransform,
+                            tag_start..end,
+                            "\\t( is missing its closing parenthesis".to_string(),
+                            end..end,
+                            ")",
+                        );
+                        end
+                    }
+                };
+                self.scan_tags(inner_start, inner_end);
+                pos = (inner_end + 1).min(end);
+            } else {
+                let args_end = self.text[name_end..end]
+                    .find('\\')
+                    .map_or(end, |next| name_end + next);
+                self.check_tag(tag_start, name_start..name_end, name_end..args_end);
+                pos = args_end;
+            }
+        }
+    }
+
+    /// Check one tag's name against the registry and its numeric argument
+    /// against the tag's range
+    fn check_tag(&mut self, tag_start: usize, name: Range<usize>, args: Range<usize>) {
+        let tag = tag_start..args.end;
+        let name = &self.text[name];
+        if !self.registry.has_tag_handler(name) && !UNHANDLED_STANDARD_TAGS.contains(&name) {
+            self.push(
+                TagIssueKind::UnknownTag,
+                tag.clone(),
+                format!("Unrecognized tag \\{name}"),
+                tag,
+                "",
+            );
+            return;
+        }
+
+        let arg_text = &self.text[args.clone()];
+        let Some(value) = arg_text
+            .trim()
+            .parse::<f64>()
+            .ok()
+            .filter(|v| v.is_finite())
+        else {
+            return;
+        };
+        if let Some(&(_, min, max)) = NUMERIC_BOUNDS.iter().find(|(tag, ..)| *tag == name) {
+            if value < min || value > max {
+                let clamped = format_number(value.clamp(min, max));
+                self.push(
+                    TagIssueKind::OutOfRange,
+                    tag,
+                    format!(
+                        "\\{name}{} is out of range; clamping to {clamped}",
+                        arg_text.trim()
+                    ),
+                    args,
+                    &clamped,
+                );
+            }
+        } else if let Some(TagResult::Failed(reason)) = self.registry.process_tag(name, arg_text) {
+            self.push(TagIssueKind::OutOfRange, tag.clone(), reason, tag, "");
+        }
+    }
+
+    /// Record an issue and its fix
+    fn push(
+        &mut self,
+        kind: TagIssueKind,
+        span: Range<usize>,
+        message: String,
+        fix_range: Range<usize>,
+        replacement: &str,
+    ) {
+        self.issues.push(TagIssue {
+            kind,
+            span,
+            message,
+            fix: TagFix {
+                range: fix_range,
+                replacement: replacement.to_string(),
+            },
+        });
+    }
+}
+
+/// Byte length of the tag name at the start of `s`
+///
+/// Mirrors ass-core's parser: an optional leading digit then ASCII letters,
+/// stopping early after `r` and `fn`, whose arguments may start with letters.
+fn tag_name_len(s: &str) -> usize {
+    let mut len = 0;
+    for (i, c) in s.char_indices() {
+        let accepted = c.is_ascii_alphabetic() || (i == 0 && c.is_ascii_digit());
+        if !accepted {
+            break;
+        }
+        len = i + 1;
+        if matches!(&s[..len], "r" | "fn") {
+            break;
+        }
+    }
+    len
+}
+
+/// Offset of the `)` closing the `(` that starts `s`
+fn closing_paren(s: &str) -> Option<usize> {
+    let mut depth = 0usize;
+    for (i, c) in s.char_indices() {
+        match c {
+            '(' => depth += 1,
+            ')' => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some(i);
+                }
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+/// Length of the run of tags at the start of unclosed block content: up to
+/// the first whitespace outside parentheses
+fn tag_run_len(s: &str) -> usize {
+    let mut depth = 0usize;
+    for (i, c) in s.char_indices() {
+        match c {
+            '(' => depth += 1,
+            ')' => depth = depth.saturating_sub(1),
+            c if c.is_whitespace() && depth == 0 => return i,
+            _ => {}
+        }
+    }
+    s.len()
+}
+
+/// Format a clamped value without a trailing `.0`
+fn format_number(value: f64) -> String {
+    if value.fract() == 0.0 {
+        format!("{}", value as i64)
+    } else {
+        value.to_string()
+    }
+}
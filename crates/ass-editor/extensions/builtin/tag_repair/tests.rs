@@ -0,0 +1,144 @@
+//! Unit tests for broken-tag detection and repair.
+
+use super::*;
+use crate::core::EditorDocument;
+use crate::extensions::{EditorExtension, ExtensionManager};
+
+#[cfg(not(feature = "std"))]
+use alloc::{
+    collections::BTreeMap as HashMap,
+    string::{String, ToString},
+    vec::Vec,
+};
+#[cfg(feature = "std")]
+use std::collections::HashMap;
+
+fn kinds(text: &str) -> Vec<TagIssueKind> {
+    BrokenTagRepair::new()
+        .unwrap()
+        .scan(text)
+        .into_iter()
+        .map(|issue| issue.kind)
+        .collect()
+}
+
+fn repaired(text: &str) -> String {
+    BrokenTagRepair::new().unwrap().repair(text)
+}
+
+#[test]
+fn well_formed_text_has_no_issues() {
+    let text = "{\\b1\\fnArial Black\\1c&H0000FF&\\t(0,500,\\frz90)\\blur2}Hello{\\r}";
+    assert!(kinds(text).is_empty());
+    assert_eq!(repaired(text), text);
+}
+
+#[test]
+fn unclosed_brace_closed_after_tag_run() {
+    assert_eq!(kinds("{\\b1 Hello"), [TagIssueKind::UnclosedBrace]);
+    assert_eq!(repaired("{\\b1 Hello"), "{\\b1} Hello");
+    assert_eq!(repaired("{\\i1Hi{\\i0}"), "{\\i1Hi}{\\i0}");
+}
+
+#[test]
+fn unclosed_brace_without_tags_removed() {
+    assert_eq!(kinds("Say {hello"), [TagIssueKind::UnclosedBrace]);
+    assert_eq!(repaired("Say {hello"), "Say hello");
+}
+
+#[test]
+fn extra_closing_brace_removed() {
+    let issues = BrokenTagRepair::new().unwrap().scan("{\\b1}Bold} text");
+    assert_eq!(issues.len(), 1);
+    assert_eq!(issues[0].kind, TagIssueKind::ExtraClosingBrace);
+    assert_eq!(issues[0].span, 9..10);
+    assert_eq!(repaired("{\\b1}Bold} text"), "{\\b1}Bold text");
+}
+
+#[test]
+fn unknown_tag_removed() {
+    assert_eq!(kinds("{\\foo12\\b1}x"), [TagIssueKind::UnknownTag]);
+    assert_eq!(repaired("{\\foo12\\b1}x"), "{\\b1}x");
+}
+
+#[test]
+fn out_of_range_value_clamped() {
+    assert_eq!(kinds("{\\frz400}x"), [TagIssueKind::OutOfRange]);
+    assert_eq!(repaired("{\\frz400}x"), "{\\frz360}x");
+    assert_eq!(repaired("{\\bord-2\\be200}x"), "{\\bord0\\be127}x");
+}
+
+#[test]
+fn ambiguous_out_of_range_value_removed() {
+    // `\b11` could mean `\b1` or `\b100`, so it is dropped
+    assert_eq!(kinds("{\\b11\\i1}x"), [TagIssueKind::OutOfRange]);
+    assert_eq!(repaired("{\\b11\\i1}x"), "{\\i1}x");
+}
+
+#[test]
+fn unmatched_transform_closed() {
+    assert_eq!(
+        kinds("{\\t(0,500,\\c&HFF0000&}x"),
+        [TagIssueKind::UnmatchedTransform]
+    );
+    assert_eq!(
+        repaired("{\\t(0,500,\\c&HFF0000&}x"),
+        "{\\t(0,500,\\c&HFF0000&)}x"
+    );
+    assert_eq!(repaired("{\\t(0,500,\\frz400"), "{\\t(0,500,\\frz360)}");
+}
+
+#[test]
+fn issues_inside_transform_detected() {
+    assert_eq!(
+        kinds("{\\t(\\foo1\\frz-500)}x"),
+        [TagIssueKind::UnknownTag, TagIssueKind::OutOfRange]
+    );
+    assert_eq!(repaired("{\\t(\\foo1\\frz-500)}x"), "{\\t(\\frz-360)}x");
+}
+
+const DOCUMENT: &str = "[Events]\nFormat: Layer, Start, End, Style, Name, MarginL, MarginR, MarginV, Effect, Text\nDialogue: 0,0:00:00.00,0:00:05.00,Default,,0,0,0,,{\\frz400\\foo}Hi}\nDialogue: 0,0:00:05.00,0:00:09.00,Default,,0,0,0,,{\\b1 there";
+
+#[test]
+fn repair_command_is_one_undoable_step() {
+    let mut doc = EditorDocument::from_content(DOCUMENT).unwrap();
+    let repair = BrokenTagRepair::new().unwrap();
+    assert_eq!(repair.scan_document(&doc).unwrap().len(), 4);
+
+    let command = repair.repair_command(&doc).unwrap();
+    doc.execute_command(&command).unwrap();
+    assert!(doc.text().ends_with(
+        ",,{\\frz360}Hi\nDialogue: 0,0:00:05.00,0:00:09.00,Default,,0,0,0,,{\\b1} there"
+    ));
+    assert!(repair.scan_document(&doc).unwrap().is_empty());
+
+    doc.undo().unwrap();
+    assert_eq!(doc.text(), DOCUMENT);
+}
+
+#[test]
+fn extension_repairs_current_document() {
+    let mut ext = TagRepairExtension::new().unwrap();
+    assert_eq!(ext.info().name, "tag-repair");
+    assert_eq!(ext.commands().len(), 2);
+
+    let mut manager = ExtensionManager::new();
+    let mut doc = EditorDocument::from_content(DOCUMENT).unwrap();
+    let mut context = manager
+        .create_context("tag-repair".to_string(), Some(&mut doc))
+        .unwrap();
+    ext.initialize(&mut *context).unwrap();
+
+    let args = HashMap::new();
+    let scan = ext
+        .execute_command("tag_repair.scan", &args, &mut *context)
+        .unwrap();
+    assert_eq!(scan.data.get("issue_count").map(String::as_str), Some("4"));
+
+    let repair = ext
+        .execute_command("tag_repair.repair", &args, &mut *context)
+        .unwrap();
+    assert!(repair.success);
+    drop(context);
+    assert!(doc.text().contains("{\\frz360}Hi\n"));
+}
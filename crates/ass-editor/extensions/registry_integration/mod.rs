@@ -2,14 +2,17 @@
 //!
 //! This module provides the glue between ass-editor's extension system and
 //! ass-core's plugin system, allowing editor extensions to register tag handlers
-//! and section processors that will be used during ASS parsing.
+//! and section processors that will be used during ASS parsing. It also
+//! provides custom section handlers for sections written by other editors.
 
 mod adapters;
 mod builtins;
 mod integration;
+mod sections;
 
 #[cfg(test)]
 mod tests;
 
 pub use adapters::{EditorSectionProcessorAdapter, EditorTagHandlerAdapter};
 pub use integration::RegistryIntegration;
+pub use sections::AegisubProjectGarbageHandler;
@@ -0,0 +1,49 @@
+//! Custom section handlers for sections written by other editors.
+//!
+//! Provides [`AegisubProjectGarbageHandler`], a reference
+//! [`CustomSectionHandler`] that keeps Aegisub's project state when parsing
+//! with [`Script::builder`](ass_core::parser::Script::builder).
+
+use ass_core::parser::{ast::CustomSection, CustomSectionHandler, Section};
+
+/// Keeps `[Aegisub Project Garbage]` sections as key-value pairs
+///
+/// Aegisub stores editor state such as the loaded video, audio and the
+/// active line in this section. Without a handler the parser drops it with
+/// an "Unknown section" warning; with this handler it becomes a
+/// [`Section::Custom`] whose fields can be read and which is written back on
+/// save.
+///
+/// # Example
+///
+/// ```rust
+/// use ass_core::parser::{Script, Section};
+/// use ass_editor::extensions::registry_integration::AegisubProjectGarbageHandler;
+///
+/// let source = "[Script Info]\nTitle: Demo\n\n[Aegisub Project Garbage]\nVideo File: demo.mkv\n";
+/// let script = Script::builder()
+///     .with_section_handler(Box::new(AegisubProjectGarbageHandler))
+///     .parse(source)?;
+/// let Some(Section::Custom(garbage)) = script.sections().last() else {
+///     panic!("project garbage not kept");
+/// };
+/// assert_eq!(garbage.get_field("Video File"), Some("demo.mkv"));
+/// # Ok::<(), ass_core::CoreError>(())
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct AegisubProjectGarbageHandler;
+
+impl AegisubProjectGarbageHandler {
+    /// Header name of the section handled
+    pub const SECTION_NAME: &'static str = "Aegisub Project Garbage";
+}
+
+impl CustomSectionHandler for AegisubProjectGarbageHandler {
+    fn handles(&self, name: &str) -> bool {
+        name == Self::SECTION_NAME
+    }
+
+    fn parse<'a>(&self, name: &'a str, content: &'a str) -> Section<'a> {
+        Section::Custom(CustomSection::from_key_values(name, content))
+    }
+}
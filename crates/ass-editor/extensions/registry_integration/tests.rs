@@ -116,3 +116,32 @@ fn test_full_integration() {
 
     assert!(result.is_ok());
 }
+
+#[test]
+fn test_aegisub_project_garbage_handler_keeps_fields() {
+    use ass_core::parser::{Script, Section};
+
+    let content = "[Script Info]\nTitle: Test\n\n[Aegisub Project Garbage]\nAudio File: clip.wav\nVideo File: clip.mkv\nActive Line: 7\n\n[Events]\nFormat: Layer, Start, End, Style, Name, MarginL, MarginR, MarginV, Effect, Text\nDialogue: 0,0:00:00.00,0:00:05.00,Default,,0,0,0,,Text\n";
+
+    let script = Script::builder()
+        .with_section_handler(Box::new(AegisubProjectGarbageHandler))
+        .parse(content)
+        .unwrap();
+    assert!(script.issues().is_empty());
+
+    let garbage = script
+        .sections()
+        .iter()
+        .find_map(|section| match section {
+            Section::Custom(custom) => Some(custom),
+            _ => None,
+        })
+        .expect("project garbage section");
+    assert_eq!(garbage.name, AegisubProjectGarbageHandler::SECTION_NAME);
+    assert_eq!(garbage.get_field("Audio File"), Some("clip.wav"));
+    assert_eq!(garbage.get_field("Video File"), Some("clip.mkv"));
+    assert_eq!(garbage.get_field("Active Line"), Some("7"));
+    assert!(script
+        .to_ass_string()
+        .contains("[Aegisub Project Garbage]\nAudio File: clip.wav\n"));
+}
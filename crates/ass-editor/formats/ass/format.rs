@@ -5,7 +5,7 @@
 
 use crate::core::{EditorDocument, EditorError};
 use crate::formats::{
-    Format, FormatExporter, FormatImporter, FormatInfo, FormatOptions, FormatResult,
+    Format, FormatExporter, FormatImporter, FormatInfo, FormatKind, FormatOptions, FormatResult,
 };
 use ass_core::parser::Script;
 use std::io::{Read, Write};
@@ -20,14 +20,7 @@ impl AssFormat {
     /// Create a new ASS format handler
     pub fn new() -> Self {
         Self {
-            info: FormatInfo {
-                name: "ASS".to_string(),
-                extensions: vec!["ass".to_string()],
-                mime_type: "text/x-ass".to_string(),
-                description: "Advanced SubStation Alpha subtitle format".to_string(),
-                supports_styling: true,
-                supports_positioning: true,
-            },
+            info: FormatInfo::for_format(FormatKind::Ass),
         }
     }
 }
@@ -26,7 +26,7 @@ fn test_ass_format_creation() {
     let format = AssFormat::new();
     let info = FormatImporter::format_info(&format);
     assert_eq!(info.name, "ASS");
-    assert!(info.supports_styling);
+    assert!(info.supports_styles);
     assert!(info.supports_positioning);
     assert!(format.can_import("ass"));
     assert!(format.can_export("ass"));
@@ -0,0 +1,145 @@
+//! Content-based subtitle format detection.
+//!
+//! Provides [`DetectionResult`] and the heuristics behind
+//! [`FormatRegistry::detect_format`]: input is decoded according to its
+//! byte order mark, so UTF-16 and UTF-32 files are recognized too, then
+//! checked for each format's signature.
+
+use super::{FormatImporter, FormatInfo, FormatOptions, FormatRegistry, FormatResult};
+use crate::core::{EditorDocument, EditorError};
+use ass_core::utils::utf8::{decode_to_utf8, detect_encoding, EncodingInfo};
+
+/// Subtitle format recognized from file content
+#[derive(Debug, Clone, PartialEq)]
+pub struct DetectionResult {
+    /// Information about the detected format, as registered
+    pub format: FormatInfo,
+    /// How certain the detection is, from 0.0 to 1.0
+    pub confidence: f32,
+    /// Encoding the content was decoded from
+    pub encoding: EncodingInfo,
+}
+
+impl FormatRegistry {
+    /// Detect the format of `bytes` from their content
+    ///
+    /// Returns `None` when no heuristic matches or the matching format has
+    /// no registered importer.
+    pub fn detect_format(&self, bytes: &[u8]) -> Option<DetectionResult> {
+        let text = decode_to_utf8(bytes).ok()?;
+        let (name, confidence) = sniff(&text)?;
+        let importer = self.find_importer_by_name(name)?;
+        Some(DetectionResult {
+            format: importer.format_info().clone(),
+            confidence,
+            encoding: detect_encoding(bytes),
+        })
+    }
+
+    /// Detect the format of `bytes` and import them with its importer
+    ///
+    /// The detected format name and encoding are recorded in the result
+    /// metadata under `detected_format` and `detected_encoding`.
+    pub fn detect_and_import(
+        &self,
+        bytes: &[u8],
+        options: &FormatOptions,
+    ) -> Result<(EditorDocument, FormatResult), EditorError> {
+        let detection = self.detect_format(bytes).ok_or_else(|| {
+            EditorError::UnsupportedFormat("Could not detect subtitle format".to_string())
+        })?;
+        let text = decode_to_utf8(bytes)?;
+        let importer = self
+            .find_importer_by_name(&detection.format.name)
+            .ok_or_else(|| EditorError::UnsupportedFormat(detection.format.name.clone()))?;
+
+        let (document, result) = importer.import_from_string(&text, options)?;
+        Ok((
+            document,
+            result
+                .with_metadata("detected_format".to_string(), detection.format.name)
+                .with_metadata("detected_encoding".to_string(), detection.encoding.encoding),
+        ))
+    }
+
+    /// Find the importer whose format is called `name`, ignoring case
+    fn find_importer_by_name(&self, name: &str) -> Option<&dyn FormatImporter> {
+        let matches = |info: &FormatInfo| info.name.eq_ignore_ascii_case(name);
+        self.formats
+            .values()
+            .map(|format| format.as_importer())
+            .find(|importer| matches(importer.format_info()))
+            .or_else(|| {
+                self.importers
+                    .values()
+                    .map(AsRef::as_ref)
+                    .find(|importer| matches(importer.format_info()))
+            })
+    }
+}
+
+/// Format name and confidence the content heuristics give `text`
+pub(super) fn sniff(text: &str) -> Option<(&'static str, f32)> {
+    let trimmed = text.trim_start();
+    if trimmed.starts_with("WEBVTT") {
+        return Some(("WebVTT", 1.0));
+    }
+    if starts_with_ignore_case(trimmed, "[Script Info]") {
+        return Some(("ASS", 1.0));
+    }
+    if trimmed.starts_with("<?xml") && trimmed.contains("<tt") {
+        return Some(("TTML", 0.9));
+    }
+
+    let lines: Vec<&str> = trimmed.lines().map(str::trim).collect();
+    if lines.windows(2).any(|pair| {
+        !pair[0].is_empty() && pair[0].bytes().all(|b| b.is_ascii_digit()) && is_srt_time(pair[1])
+    }) {
+        return Some(("SRT", 0.9));
+    }
+    if lines.iter().any(|line| is_lrc_time(line)) {
+        return Some(("LRC", 0.8));
+    }
+    if lines.iter().any(|line| {
+        line.eq_ignore_ascii_case("[Events]") || line.eq_ignore_ascii_case("[V4+ Styles]")
+    }) {
+        return Some(("ASS", 0.6));
+    }
+    None
+}
+
+/// Whether `text` starts with `prefix`, ignoring ASCII case
+fn starts_with_ignore_case(text: &str, prefix: &str) -> bool {
+    text.get(..prefix.len())
+        .is_some_and(|head| head.eq_ignore_ascii_case(prefix))
+}
+
+/// Whether `line` starts with an SRT `HH:MM:SS,mmm` timestamp
+fn is_srt_time(line: &str) -> bool {
+    let bytes = line.as_bytes();
+    bytes.len() >= 12
+        && bytes[..12]
+            .iter()
+            .enumerate()
+            .all(|(index, &b)| match index {
+                2 | 5 => b == b':',
+                8 => b == b',',
+                _ => b.is_ascii_digit(),
+            })
+}
+
+/// Whether `line` starts with an LRC `[mm:ss.xx]` time tag
+fn is_lrc_time(line: &str) -> bool {
+    let Some(rest) = line.strip_prefix('[') else {
+        return false;
+    };
+    let Some((minutes, rest)) = rest.split_once(':') else {
+        return false;
+    };
+    let Some((seconds, _)) = rest.split_once(']') else {
+        return false;
+    };
+    let (whole, fraction) = seconds.split_once('.').unwrap_or((seconds, "0"));
+    let digits = |part: &str| !part.is_empty() && part.bytes().all(|b| b.is_ascii_digit());
+    digits(minutes) && whole.len() == 2 && digits(whole) && digits(fraction)
+}
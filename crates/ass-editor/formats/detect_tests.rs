@@ -0,0 +1,131 @@
+//! Tests for content-based format detection and auto-import.
+
+use super::ass::AssFormat;
+use super::detect::sniff;
+use super::lrc::LrcImporter;
+use super::srt::SrtFormat;
+use super::webvtt::WebVttFormat;
+use super::*;
+use crate::core::EditorError;
+
+const ASS: &str = "[Script Info]
+Title: Detect
+
+[Events]
+Format: Layer, Start, End, Style, Name, MarginL, MarginR, MarginV, Effect, Text
+Dialogue: 0,0:00:01.00,0:00:02.00,Default,,0,0,0,,Hello
+";
+
+const SRT: &str = "1
+00:00:01,000 --> 00:00:02,000
+Hello
+
+2
+00:00:03,000 --> 00:00:04,500
+World
+";
+
+const WEBVTT: &str = "WEBVTT
+
+00:00:01.000 --> 00:00:02.000
+Hello
+";
+
+const LRC: &str = "[ti:Detect]
+[00:01.00]Hello
+[00:03.50]World
+";
+
+const TTML: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<tt xmlns="http://www.w3.org/ns/ttml"><body><div><p begin="00:00:01.000" end="00:00:02.000">Hello</p></div></body></tt>
+"#;
+
+/// Registry with every built-in format
+fn registry() -> FormatRegistry {
+    let mut registry = FormatRegistry::new();
+    registry.register_format(Box::new(AssFormat::new()));
+    registry.register_format(Box::new(SrtFormat::new()));
+    registry.register_format(Box::new(WebVttFormat::new()));
+    registry.register_importer(Box::new(LrcImporter::new()));
+    registry
+}
+
+/// Name and confidence of the format detected for `content`
+fn detect(content: &str) -> (String, f32) {
+    let detection = registry().detect_format(content.as_bytes()).unwrap();
+    (detection.format.name, detection.confidence)
+}
+
+#[test]
+fn test_detect_each_builtin_format() {
+    assert_eq!(detect(ASS), ("ASS".to_string(), 1.0));
+    assert_eq!(detect(SRT), ("SRT".to_string(), 0.9));
+    assert_eq!(detect(WEBVTT), ("WebVTT".to_string(), 1.0));
+    assert_eq!(detect(LRC), ("LRC".to_string(), 0.8));
+}
+
+#[test]
+fn test_detect_ass_without_script_info_header() {
+    let events_only = &ASS["[Script Info]\nTitle: Detect\n\n".len()..];
+    assert_eq!(detect(events_only), ("ASS".to_string(), 0.6));
+}
+
+#[test]
+fn test_detect_utf16_ass_by_bom() {
+    let bytes: Vec<u8> = [0xFF, 0xFE]
+        .into_iter()
+        .chain(ASS.encode_utf16().flat_map(u16::to_le_bytes))
+        .collect();
+    let registry = registry();
+
+    let detection = registry.detect_format(&bytes).unwrap();
+    assert_eq!(detection.format.name, "ASS");
+    assert!(detection.encoding.has_bom);
+    assert_eq!(detection.encoding.encoding, "UTF-16LE");
+
+    let (document, result) = registry
+        .detect_and_import(&bytes, &FormatOptions::default())
+        .unwrap();
+    assert!(document.text().contains("Dialogue: 0,0:00:01.00"));
+    assert_eq!(
+        result.metadata.get("detected_encoding").map(String::as_str),
+        Some("UTF-16LE")
+    );
+}
+
+#[test]
+fn test_detect_and_import_srt() {
+    let (document, result) = registry()
+        .detect_and_import(SRT.as_bytes(), &FormatOptions::default())
+        .unwrap();
+    assert!(result.success);
+    assert_eq!(
+        result.metadata.get("detected_format").map(String::as_str),
+        Some("SRT")
+    );
+    assert_eq!(document.text().matches("Dialogue:").count(), 2);
+}
+
+#[test]
+fn test_detect_and_import_lrc() {
+    let (document, _) = registry()
+        .detect_and_import(LRC.as_bytes(), &FormatOptions::default())
+        .unwrap();
+    assert!(document.text().contains("World"));
+}
+
+#[test]
+fn test_detect_ttml_needs_registered_importer() {
+    assert!(sniff(TTML).is_some_and(|(name, _)| name == "TTML"));
+    assert!(registry().detect_format(TTML.as_bytes()).is_none());
+}
+
+#[test]
+fn test_detect_unknown_content() {
+    let registry = registry();
+    assert!(registry.detect_format(b"just some text\n").is_none());
+    assert!(matches!(
+        registry.detect_and_import(b"just some text\n", &FormatOptions::default()),
+        Err(EditorError::UnsupportedFormat(_))
+    ));
+}
@@ -11,18 +11,76 @@ fn test_format_info_creation() {
     let info = FormatInfo {
         name: "Test Format".to_string(),
         extensions: vec!["test".to_string(), "tst".to_string()],
-        mime_type: "text/test".to_string(),
+        mime_types: vec!["text/test".to_string()],
         description: "A test format".to_string(),
-        supports_styling: true,
+        supports_styles: true,
         supports_positioning: false,
+        supports_formatting: true,
+        supports_embedded_fonts: false,
+        is_lossless: false,
+        spec_version: None,
     };
 
     assert_eq!(info.name, "Test Format");
     assert_eq!(info.extensions.len(), 2);
-    assert!(info.supports_styling);
+    assert_eq!(info.mime_type(), "text/test");
+    assert!(info.supports_styles);
     assert!(!info.supports_positioning);
 }
 
+#[test]
+fn test_format_info_for_format() {
+    let ass = FormatInfo::for_format(FormatKind::Ass);
+    assert!(ass.is_lossless);
+    assert!(ass.supports_embedded_fonts);
+    assert_eq!(ass.spec_version.as_deref(), Some("v4.00+"));
+
+    let srt = FormatInfo::for_format(FormatKind::Srt);
+    assert!(!srt.is_lossless);
+    assert!(!srt.supports_positioning);
+    assert!(!srt.supports_styles);
+    assert_eq!(srt.mime_type(), "text/srt");
+
+    for kind in [FormatKind::WebVtt, FormatKind::Lrc] {
+        assert!(!FormatInfo::for_format(kind).is_lossless);
+    }
+}
+
+#[test]
+fn test_lossless_export_check() {
+    let content = r#"[Script Info]
+Title: Loss
+
+[Events]
+Format: Layer, Start, End, Style, Name, MarginL, MarginR, MarginV, Effect, Text
+Dialogue: 0,0:00:00.00,0:00:01.00,Default,,0,0,0,,Plain
+Dialogue: 0,0:00:01.00,0:00:02.00,Default,,0,0,0,,{\i1}Italic{\i0}
+Dialogue: 0,0:00:02.00,0:00:03.00,Sign,,0,0,0,,{\an8\pos(10,20)}Sign
+"#;
+    let script = ass_core::parser::Script::parse(content).unwrap();
+
+    let losses = ass::AssFormat::new().lossless_export_check(&script);
+    assert!(losses.is_empty());
+
+    let losses = srt::SrtFormat::new().lossless_export_check(&script);
+    assert_eq!(
+        losses,
+        vec![
+            FeatureLoss {
+                feature: "named styles",
+                example_event_index: 2,
+            },
+            FeatureLoss {
+                feature: "positioning",
+                example_event_index: 2,
+            },
+        ]
+    );
+
+    let losses = webvtt::WebVttFormat::new().lossless_export_check(&script);
+    assert!(losses.is_empty());
+}
+
 #[test]
 fn test_format_options_default() {
     let options = FormatOptions::default();
@@ -0,0 +1,92 @@
+//! Detection of script features an export format cannot represent.
+//!
+//! Scans the events of a [`Script`] for named styles, positioning and inline
+//! formatting, and reports each one the target [`FormatInfo`] lacks.
+
+use super::FormatInfo;
+use ass_core::parser::{Script, Section};
+
+/// Override tags that place text on screen
+const POSITIONING_TAGS: [&str; 5] = ["pos", "move", "an", "a", "org"];
+
+/// A script feature that is dropped when exporting to a format
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FeatureLoss {
+    /// Name of the lost feature (e.g., "positioning")
+    pub feature: &'static str,
+    /// Index of the first event using the feature
+    pub example_event_index: usize,
+}
+
+/// Features of `script` that `info` cannot represent, one entry per feature
+pub(super) fn feature_losses(info: &FormatInfo, script: &Script) -> Vec<FeatureLoss> {
+    if info.is_lossless {
+        return Vec::new();
+    }
+
+    let mut styles = None;
+    let mut positioning = None;
+    let mut formatting = None;
+    let events = script
+        .sections()
+        .iter()
+        .filter_map(|section| match section {
+            Section::Events(events) => Some(events),
+            _ => None,
+        })
+        .flatten();
+
+    for (index, event) in events.enumerate() {
+        let style = event.style.trim().trim_start_matches('*');
+        if !style.is_empty() && !style.eq_ignore_ascii_case("Default") {
+            styles.get_or_insert(index);
+        }
+
+        let margins = [event.margin_l, event.margin_r, event.margin_v];
+        if margins.iter().any(|margin| is_set(margin)) {
+            positioning.get_or_insert(index);
+        }
+
+        for tag in override_tags(event.text) {
+            let name = tag
+                .split(|ch: char| !ch.is_ascii_alphabetic())
+                .next()
+                .unwrap_or_default();
+            if POSITIONING_TAGS.contains(&name) {
+                positioning.get_or_insert(index);
+            } else {
+                formatting.get_or_insert(index);
+            }
+        }
+    }
+
+    [
+        ("named styles", info.supports_styles, styles),
+        ("positioning", info.supports_positioning, positioning),
+        ("formatting", info.supports_formatting, formatting),
+    ]
+    .into_iter()
+    .filter(|(_, supported, _)| !supported)
+    .filter_map(|(feature, _, index)| {
+        index.map(|example_event_index| FeatureLoss {
+            feature,
+            example_event_index,
+        })
+    })
+    .collect()
+}
+
+/// Whether an event margin overrides the style's margin
+fn is_set(margin: &str) -> bool {
+    margin.trim().parse::<i32>().is_ok_and(|value| value != 0)
+}
+
+/// Override tags in `text`, without their leading backslash
+fn override_tags(text: &str) -> impl Iterator<Item = &str> {
+    text.split('{')
+        .skip(1)
+        .filter_map(|rest| rest.split_once('}').map(|(block, _)| block))
+        .flat_map(|block| block.split('\\').skip(1))
+        .map(str::trim)
+        .filter(|tag| !tag.is_empty())
+}
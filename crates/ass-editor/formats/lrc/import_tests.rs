@@ -0,0 +1,101 @@
+//! Tests for LRC timestamp parsing and lyrics import.
+
+use super::parse::{parse_line, parse_timestamp, LrcLine};
+use super::*;
+use crate::formats::{FormatImporter, FormatOptions};
+
+const SAMPLE_LRC: &str = "[ti:Daybreak]
+[ar:The Example Band]
+[al:First Light]
+[by:lrc-maker]
+[length:00:45]
+
+[00:12.00]<00:12.00>Wake <00:12.40>up <00:12.90>to <00:13.20>the <00:13.60>light<00:14.50>
+[00:15.50]Every morning brings a song
+[00:20.00][00:30.00]Sing along, sing along
+[00:24.00]
+not a lyric line
+[00:32.10]<00:32.10>Fade <00:33.00>away
+";
+
+#[test]
+fn test_parse_timestamp_variants() {
+    assert_eq!(parse_timestamp("00:12.34"), Some(1234));
+    assert_eq!(parse_timestamp("01:02.5"), Some(6250));
+    assert_eq!(parse_timestamp("01:02.345"), Some(6234));
+    assert_eq!(parse_timestamp("01:02:03"), Some(6203));
+    assert_eq!(parse_timestamp("10:00"), Some(60000));
+
+    assert_eq!(parse_timestamp("ar:Artist"), None);
+    assert_eq!(parse_timestamp("00:60.00"), None);
+    assert_eq!(parse_timestamp("00:12.3456"), None);
+}
+
+#[test]
+fn test_parse_line_kinds() {
+    assert_eq!(
+        parse_line("[ti: Song ]"),
+        LrcLine::Metadata("ti".to_string(), "Song")
+    );
+    assert_eq!(
+        parse_line("[00:01.00][00:05.00] Chorus"),
+        LrcLine::Timed(vec![100, 500], "Chorus")
+    );
+    assert_eq!(parse_line("   "), LrcLine::Blank);
+    assert_eq!(parse_line("plain text"), LrcLine::Invalid);
+}
+
+#[test]
+fn test_lrc_import_sample() {
+    let importer = LrcImporter::new();
+    assert!(importer.can_import("LRC"));
+
+    let (document, result) = importer
+        .import_from_string(SAMPLE_LRC, &FormatOptions::default())
+        .unwrap();
+    let text = document.text();
+
+    assert!(text.contains("Title: Daybreak\n"));
+    assert!(text.contains("Original Translation: The Example Band\n"));
+    assert!(text.contains("Comment: First Light\n"));
+
+    assert!(text.contains(
+        "Dialogue: 0,0:00:12.00,0:00:15.00,Default,,0,0,0,,{\\k40}Wake {\\k50}up {\\k30}to {\\k40}the {\\k90}light\n"
+    ));
+    // Cut short by the blank end marker
+    assert!(
+        text.contains("Dialogue: 0,0:00:20.00,0:00:23.00,Default,,0,0,0,,Sing along, sing along\n")
+    );
+    // Repeated chorus is placed at its second timestamp as well
+    assert!(
+        text.contains("Dialogue: 0,0:00:30.00,0:00:32.10,Default,,0,0,0,,Sing along, sing along\n")
+    );
+    // Last word runs until the line ends
+    assert!(text
+        .contains("Dialogue: 0,0:00:32.10,0:00:35.10,Default,,0,0,0,,{\\k90}Fade {\\k210}away\n"));
+
+    assert_eq!(result.lines_processed, 5);
+    assert_eq!(result.metadata.get("extended"), Some(&"true".to_string()));
+    assert_eq!(result.warnings.len(), 1);
+    assert!(result.warnings[0].contains("not a lyric line"));
+}
+
+#[test]
+fn test_lrc_import_custom_options() {
+    let importer = LrcImporter::with_options(LrcFormatOptions {
+        default_duration_cs: 150,
+        target_style: "Lyrics".to_string(),
+        parse_extended: false,
+    });
+
+    let (document, _) = importer
+        .import_from_string(SAMPLE_LRC, &FormatOptions::default())
+        .unwrap();
+    let text = document.text();
+
+    assert!(text.contains("Style: Lyrics,"));
+    assert!(
+        text.contains("Dialogue: 0,0:00:12.00,0:00:13.50,Lyrics,,0,0,0,,Wake up to the light\n")
+    );
+    assert!(!text.contains("\\k"));
+}
@@ -0,0 +1,222 @@
+//! LRC import: convert timed lyric lines into an ASS [`EditorDocument`].
+//!
+//! Defines [`LrcImporter`] and its [`LrcFormatOptions`], and implements
+//! [`FormatImporter`] by building an ASS script from the parsed lines.
+
+use super::parse::{karaoke_text, parse_line, split_word_timing, LrcLine};
+use crate::core::{EditorDocument, EditorError};
+use crate::formats::{FormatImporter, FormatInfo, FormatKind, FormatOptions, FormatResult};
+use ass_core::parser::Script;
+use ass_core::utils::format_ass_time;
+use std::io::Read;
+
+/// Options controlling how LRC lyrics become dialogue events
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LrcFormatOptions {
+    /// Duration of each lyric line in centiseconds, cut short when the
+    /// next line starts earlier
+    pub default_duration_cs: u32,
+    /// Style name assigned to every imported event
+    pub target_style: String,
+    /// Whether `<mm:ss.xx>` word stamps become `\k` karaoke tags; when
+    /// disabled they are stripped and the plain text is kept
+    pub parse_extended: bool,
+}
+
+impl Default for LrcFormatOptions {
+    fn default() -> Self {
+        Self {
+            default_duration_cs: 300,
+            target_style: "Default".to_string(),
+            parse_extended: true,
+        }
+    }
+}
+
+/// Importer for LRC and Extended LRC lyrics files
+#[derive(Debug)]
+pub struct LrcImporter {
+    info: FormatInfo,
+    options: LrcFormatOptions,
+}
+
+/// A lyric line placed on the timeline
+struct TimedLyric<'a> {
+    /// Start time in centiseconds
+    start: u32,
+    /// Raw lyric text, possibly containing word stamps
+    text: &'a str,
+}
+
+impl LrcImporter {
+    /// Create a new LRC importer with default options
+    pub fn new() -> Self {
+        Self::with_options(LrcFormatOptions::default())
+    }
+
+    /// Create a new LRC importer with the given options
+    pub fn with_options(options: LrcFormatOptions) -> Self {
+        Self {
+            info: FormatInfo::for_format(FormatKind::Lrc),
+            options,
+        }
+    }
+
+    /// Get the conversion options
+    pub fn options(&self) -> &LrcFormatOptions {
+        &self.options
+    }
+
+    /// Convert one lyric into dialogue text, returning whether word timing
+    /// was found
+    fn lyric_text(&self, lyric: &TimedLyric, end: u32) -> (String, bool) {
+        let segments = split_word_timing(lyric.text, lyric.start);
+        let timed = segments.len() > 1;
+
+        if timed && self.options.parse_extended {
+            (karaoke_text(&segments, end), true)
+        } else {
+            let plain: String = segments.into_iter().map(|(_, text)| text).collect();
+            (plain.trim().to_string(), timed)
+        }
+    }
+}
+
+impl Default for LrcImporter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl FormatImporter for LrcImporter {
+    fn format_info(&self) -> &FormatInfo {
+        &self.info
+    }
+
+    fn import_from_reader(
+        &self,
+        reader: &mut dyn Read,
+        options: &FormatOptions,
+    ) -> Result<(EditorDocument, FormatResult), EditorError> {
+        // Read the entire content
+        let mut content = String::new();
+        reader
+            .read_to_string(&mut content)
+            .map_err(|e| EditorError::IoError(format!("Failed to read LRC content: {e}")))?;
+
+        let mut warnings = Vec::new();
+        let mut title = None;
+        let mut artist = None;
+        let mut album = None;
+        let mut lyrics = Vec::new();
+
+        for (idx, line) in content.lines().enumerate() {
+            match parse_line(line) {
+                LrcLine::Metadata(key, value) => match key.as_str() {
+                    "ti" => title = Some(value),
+                    "ar" => artist = Some(value),
+                    "al" => album = Some(value),
+                    _ => {}
+                },
+                LrcLine::Timed(stamps, text) => {
+                    lyrics.extend(stamps.into_iter().map(|start| TimedLyric { start, text }));
+                }
+                LrcLine::Blank => {}
+                LrcLine::Invalid => {
+                    warnings.push(format!("Skipping invalid LRC line {}: {line}", idx + 1));
+                }
+            }
+        }
+
+        // Lines repeated under several stamps arrive out of order
+        lyrics.sort_by_key(|lyric| lyric.start);
+
+        let mut dialogues = Vec::new();
+        let mut extended = false;
+        for (idx, lyric) in lyrics.iter().enumerate() {
+            // An empty lyric only marks where the previous line ends
+            if lyric.text.is_empty() {
+                continue;
+            }
+
+            let mut end = lyric.start.saturating_add(self.options.default_duration_cs);
+            if let Some(next) = lyrics[idx + 1..]
+                .iter()
+                .find(|next| next.start > lyric.start)
+            {
+                end = end.min(next.start);
+            }
+
+            let (text, timed) = self.lyric_text(lyric, end);
+            extended |= timed;
+            dialogues.push(format!(
+                "Dialogue: 0,{},{},{},,0,0,0,,{text}",
+                format_ass_time(lyric.start),
+                format_ass_time(end),
+                self.options.target_style
+            ));
+        }
+
+        // Build ASS script content
+        let mut ass_content = String::new();
+
+        // Add script info section, mapping the LRC metadata tags
+        ass_content.push_str("[Script Info]\n");
+        ass_content.push_str(&format!(
+            "Title: {}\n",
+            title.unwrap_or("Converted from LRC")
+        ));
+        if let Some(artist) = artist {
+            ass_content.push_str(&format!("Original Translation: {artist}\n"));
+        }
+        if let Some(album) = album {
+            ass_content.push_str(&format!("Comment: {album}\n"));
+        }
+        ass_content.push_str("ScriptType: v4.00+\n");
+        ass_content.push_str("Collisions: Normal\n");
+        ass_content.push_str("PlayDepth: 0\n");
+        ass_content.push_str("Timer: 100.0000\n");
+        ass_content.push_str("Video Aspect Ratio: 0\n");
+        ass_content.push_str("Video Zoom: 6\n");
+        ass_content.push_str("Video Position: 0\n\n");
+
+        // Add styles section with the target style
+        ass_content.push_str("[V4+ Styles]\n");
+        ass_content.push_str("Format: Name, Fontname, Fontsize, PrimaryColour, SecondaryColour, OutlineColour, BackColour, Bold, Italic, Underline, StrikeOut, ScaleX, ScaleY, Spacing, Angle, BorderStyle, Outline, Shadow, Alignment, MarginL, MarginR, MarginV, Encoding\n");
+        ass_content.push_str(&format!(
+            "Style: {},Arial,20,&H00FFFFFF,&H000000FF,&H00000000,&H80000000,0,0,0,0,100,100,0,0,1,2,0,2,10,10,10,1\n\n",
+            self.options.target_style
+        ));
+
+        // Add events section
+        ass_content.push_str("[Events]\n");
+        ass_content.push_str(
+            "Format: Layer, Start, End, Style, Name, MarginL, MarginR, MarginV, Effect, Text\n",
+        );
+
+        for dialogue in &dialogues {
+            ass_content.push_str(dialogue);
+            ass_content.push('\n');
+        }
+
+        // Validate the generated ASS content
+        let _script = Script::parse(&ass_content)?;
+
+        // Create EditorDocument
+        let document = EditorDocument::from_content(&ass_content)?;
+
+        // Create result with metadata
+        let lyric_count = dialogues.len();
+        let mut result = FormatResult::success(lyric_count)
+            .with_metadata("original_format".to_string(), "LRC".to_string())
+            .with_metadata("lyrics_count".to_string(), lyric_count.to_string())
+            .with_metadata("extended".to_string(), extended.to_string())
+            .with_metadata("encoding".to_string(), options.encoding.clone());
+
+        if !warnings.is_empty() {
+            result = result.with_warnings(warnings);
+        }
+
+        Ok((document, result))
+    }
+}
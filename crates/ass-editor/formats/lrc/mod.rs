@@ -0,0 +1,12 @@
+//! LRC (lyrics) format import with extended word timing.
+//!
+//! This module converts `[mm:ss.xx]` timed lyric lines into dialogue events
+//! and turns Extended LRC `<mm:ss.xx>` word stamps into `\k` karaoke tags.
+
+mod importer;
+mod parse;
+
+#[cfg(test)]
+mod import_tests;
+
+pub use importer::{LrcFormatOptions, LrcImporter};
@@ -0,0 +1,142 @@
+//! Line, timestamp and word-timing parsing for LRC content.
+
+/// A classified line of LRC content
+#[derive(Debug, PartialEq, Eq)]
+pub(super) enum LrcLine<'a> {
+    /// `[key:value]` metadata tag, with the key lowercased
+    Metadata(String, &'a str),
+    /// One or more leading timestamps followed by lyric text
+    Timed(Vec<u32>, &'a str),
+    /// Empty or whitespace-only line
+    Blank,
+    /// Line that is neither metadata nor timed lyrics
+    Invalid,
+}
+
+/// Parse an LRC timestamp (`mm:ss.xx`, `mm:ss.xxx`, `mm:ss:xx` or `mm:ss`)
+/// into centiseconds
+pub(super) fn parse_timestamp(stamp: &str) -> Option<u32> {
+    let (minutes, rest) = stamp.trim().split_once(':')?;
+    let (seconds, fraction) = match rest.split_once(['.', ':']) {
+        Some((seconds, fraction)) => (seconds, fraction),
+        None => (rest, ""),
+    };
+
+    let all_digits = |part: &str| !part.is_empty() && part.bytes().all(|b| b.is_ascii_digit());
+    if !all_digits(minutes) || !all_digits(seconds) {
+        return None;
+    }
+    let minutes: u32 = minutes.parse().ok()?;
+    let seconds: u32 = seconds.parse().ok()?;
+    if seconds >= 60 {
+        return None;
+    }
+
+    let centis = match fraction.len() {
+        0 => 0,
+        1..=3 if all_digits(fraction) => {
+            let value: u32 = fraction.parse().ok()?;
+            match fraction.len() {
+                1 => value * 10,
+                2 => value,
+                _ => value / 10,
+            }
+        }
+        _ => return None,
+    };
+
+    minutes
+        .checked_mul(6000)?
+        .checked_add(seconds * 100 + centis)
+}
+
+/// Classify a single line of LRC content
+pub(super) fn parse_line(line: &str) -> LrcLine<'_> {
+    let mut rest = line.trim();
+    if rest.is_empty() {
+        return LrcLine::Blank;
+    }
+
+    let mut stamps = Vec::new();
+    while let Some(tag) = rest.strip_prefix('[') {
+        let Some(close) = tag.find(']') else {
+            break;
+        };
+        let content = &tag[..close];
+
+        if let Some(time) = parse_timestamp(content) {
+            stamps.push(time);
+            rest = &tag[close + 1..];
+        } else if stamps.is_empty() {
+            return match content.split_once(':') {
+                Some((key, value)) if !key.trim().is_empty() => {
+                    LrcLine::Metadata(key.trim().to_ascii_lowercase(), value.trim())
+                }
+                _ => LrcLine::Invalid,
+            };
+        } else {
+            break;
+        }
+    }
+
+    if stamps.is_empty() {
+        LrcLine::Invalid
+    } else {
+        LrcLine::Timed(stamps, rest.trim())
+    }
+}
+
+/// Split lyric text on `<mm:ss.xx>` word stamps
+///
+/// Returns `(start_cs, text)` segments. The first segment starts at
+/// `line_start` and holds any text before the first stamp. Angle brackets
+/// that do not enclose a valid timestamp are kept as text.
+pub(super) fn split_word_timing(text: &str, line_start: u32) -> Vec<(u32, String)> {
+    let mut segments = Vec::new();
+    let mut current = (line_start, String::new());
+    let mut rest = text;
+
+    while let Some(open) = rest.find('<') {
+        let after = &rest[open + 1..];
+        let stamp = after
+            .find('>')
+            .and_then(|close| parse_timestamp(&after[..close]).map(|time| (close, time)));
+
+        match stamp {
+            Some((close, time)) => {
+                current.1.push_str(&rest[..open]);
+                segments.push(core::mem::replace(&mut current, (time, String::new())));
+                rest = &after[close + 1..];
+            }
+            None => {
+                current.1.push_str(&rest[..=open]);
+                rest = after;
+            }
+        }
+    }
+    current.1.push_str(rest);
+    segments.push(current);
+
+    segments
+}
+
+/// Build `\k`-tagged karaoke text from word-timed segments
+///
+/// Each segment lasts until the next stamp, and the last one until
+/// `line_end`. A trailing stamp with no text only closes the previous word,
+/// while silent gaps elsewhere become empty `\k` syllables so later words
+/// stay aligned.
+pub(super) fn karaoke_text(segments: &[(u32, String)], line_end: u32) -> String {
+    let mut output = String::new();
+
+    for (index, (start, text)) in segments.iter().enumerate() {
+        let next = segments.get(index + 1).map(|(time, _)| *time);
+        let duration = next.unwrap_or(line_end).saturating_sub(*start);
+        if text.is_empty() && (next.is_none() || duration == 0) {
+            continue;
+        }
+        output.push_str(&format!("{{\\k{duration}}}{text}"));
+    }
+
+    output
+}
@@ -3,18 +3,25 @@
 //! This module provides traits and implementations for importing and exporting
 //! various subtitle formats, reusing ass-core's parsing capabilities where possible.
 
+mod detect;
+mod loss;
 mod registry;
 mod traits;
 mod types;
 
+pub use detect::DetectionResult;
+pub use loss::FeatureLoss;
 pub use registry::FormatRegistry;
 pub use traits::{Format, FormatExporter, FormatImporter};
-pub use types::{FormatInfo, FormatOptions, FormatResult};
+pub use types::{FormatInfo, FormatKind, FormatOptions, FormatResult};
 
 // Individual format modules
 pub mod ass;
+pub mod lrc;
 pub mod srt;
 pub mod webvtt;
 
+#[cfg(test)]
+mod detect_tests;
 #[cfg(test)]
 mod formats_tests;
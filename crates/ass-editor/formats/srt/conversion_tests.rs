@@ -12,7 +12,8 @@ fn test_srt_format_creation() {
     let format = SrtFormat::new();
     let info = FormatImporter::format_info(&format);
     assert_eq!(info.name, "SRT");
-    assert!(info.supports_styling);
+    assert!(!info.supports_styles);
+    assert!(info.supports_formatting);
     assert!(!info.supports_positioning);
     assert!(format.can_import("srt"));
     assert!(format.can_export("srt"));
@@ -4,7 +4,7 @@
 //! dispatch implementation. Import and export behaviour live in sibling
 //! modules.
 
-use crate::formats::{Format, FormatExporter, FormatImporter, FormatInfo};
+use crate::formats::{Format, FormatExporter, FormatImporter, FormatInfo, FormatKind};
 
 /// SRT format handler with style preservation
 #[derive(Debug)]
@@ -16,14 +16,7 @@ impl SrtFormat {
     /// Create a new SRT format handler
     pub fn new() -> Self {
         Self {
-            info: FormatInfo {
-                name: "SRT".to_string(),
-                extensions: vec!["srt".to_string()],
-                mime_type: "text/srt".to_string(),
-                description: "SubRip subtitle format with style preservation".to_string(),
-                supports_styling: true,
-                supports_positioning: false,
-            },
+            info: FormatInfo::for_format(FormatKind::Srt),
         }
     }
 }
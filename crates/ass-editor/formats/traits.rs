@@ -3,8 +3,10 @@
 //! Defines [`FormatImporter`], [`FormatExporter`], and the combined [`Format`]
 //! trait used to read and write subtitle documents.
 
+use super::loss::{feature_losses, FeatureLoss};
 use super::{FormatInfo, FormatOptions, FormatResult};
 use crate::core::{EditorDocument, EditorError};
+use ass_core::parser::Script;
 use std::fmt;
 use std::io::{Read, Write};
 use std::path::Path;
@@ -96,6 +98,17 @@ pub trait FormatExporter: fmt::Debug + Send + Sync {
             .map_err(|e| EditorError::InvalidFormat(format!("Invalid UTF-8 output: {e}")))?;
         Ok((content, result))
     }
+
+    /// Check which features of `script` this format cannot represent
+    ///
+    /// Returns one [`FeatureLoss`] per lost feature, naming the first event
+    /// that uses it, so callers can warn before exporting. Formats marked
+    /// [lossless](FormatInfo::is_lossless) never report losses. Embedded
+    /// fonts are not tied to an event; check
+    /// [`FormatInfo::supports_embedded_fonts`] for them.
+    fn lossless_export_check(&self, script: &Script) -> Vec<FeatureLoss> {
+        feature_losses(self.format_info(), script)
+    }
 }
 
 /// Combined trait for formats that support both import and export
@@ -1,25 +1,123 @@
 //! Core data types describing subtitle formats and operation results.
 //!
-//! Defines [`FormatInfo`] (format metadata), [`FormatOptions`] (import/export
-//! configuration), and [`FormatResult`] (the outcome of an operation).
+//! Defines [`FormatInfo`] (format metadata), [`FormatKind`] (the built-in
+//! formats), [`FormatOptions`] (import/export configuration), and
+//! [`FormatResult`] (the outcome of an operation).
 
 use std::collections::HashMap;
 
 /// Metadata about a subtitle format
+///
+/// The capability flags describe what the format can represent, so UIs can
+/// warn before exporting a script to a format that drops some of it.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct FormatInfo {
     /// Format name (e.g., "ASS", "SRT", "WebVTT")
     pub name: String,
     /// File extensions supported by this format
     pub extensions: Vec<String>,
-    /// MIME type for this format
-    pub mime_type: String,
+    /// MIME types for this format, preferred type first
+    pub mime_types: Vec<String>,
     /// Brief description of the format
     pub description: String,
-    /// Whether this format supports styling
-    pub supports_styling: bool,
-    /// Whether this format supports positioning
+    /// Whether this format supports named style definitions
+    pub supports_styles: bool,
+    /// Whether this format supports positioned text
     pub supports_positioning: bool,
+    /// Whether this format supports inline text formatting
+    pub supports_formatting: bool,
+    /// Whether this format can embed font files
+    pub supports_embedded_fonts: bool,
+    /// Whether an ASS script survives a round trip through this format
+    pub is_lossless: bool,
+    /// Version of the format specification, if it has one
+    pub spec_version: Option<String>,
+}
+
+/// Subtitle formats implemented by this crate
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FormatKind {
+    /// Advanced SubStation Alpha
+    Ass,
+    /// SubRip
+    Srt,
+    /// Web Video Text Tracks
+    WebVtt,
+    /// LRC lyrics (import only)
+    Lrc,
+}
+
+impl FormatInfo {
+    /// Metadata for one of the built-in formats
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ass_editor::formats::{FormatInfo, FormatKind};
+    ///
+    /// let srt = FormatInfo::for_format(FormatKind::Srt);
+    /// assert!(!srt.is_lossless);
+    /// assert!(!srt.supports_positioning);
+    /// ```
+    pub fn for_format(format: FormatKind) -> Self {
+        let strings = |values: &[&str]| values.iter().map(|value| value.to_string()).collect();
+        match format {
+            FormatKind::Ass => Self {
+                name: "ASS".to_string(),
+                extensions: strings(&["ass"]),
+                mime_types: strings(&["text/x-ass", "text/x-ssa"]),
+                description: "Advanced SubStation Alpha subtitle format".to_string(),
+                supports_styles: true,
+                supports_positioning: true,
+                supports_formatting: true,
+                supports_embedded_fonts: true,
+                is_lossless: true,
+                spec_version: Some("v4.00+".to_string()),
+            },
+            FormatKind::Srt => Self {
+                name: "SRT".to_string(),
+                extensions: strings(&["srt"]),
+                mime_types: strings(&["text/srt", "application/x-subrip"]),
+                description: "SubRip subtitle format with style preservation".to_string(),
+                supports_styles: false,
+                supports_positioning: false,
+                supports_formatting: true,
+                supports_embedded_fonts: false,
+                is_lossless: false,
+                spec_version: None,
+            },
+            FormatKind::WebVtt => Self {
+                name: "WebVTT".to_string(),
+                extensions: strings(&["vtt", "webvtt"]),
+                mime_types: strings(&["text/vtt"]),
+                description: "WebVTT subtitle format with full style and positioning preservation"
+                    .to_string(),
+                supports_styles: true,
+                supports_positioning: true,
+                supports_formatting: true,
+                supports_embedded_fonts: false,
+                is_lossless: false,
+                spec_version: Some("W3C WebVTT".to_string()),
+            },
+            FormatKind::Lrc => Self {
+                name: "LRC".to_string(),
+                extensions: strings(&["lrc"]),
+                mime_types: strings(&["application/x-lrc"]),
+                description: "LRC lyrics with Extended LRC word timing".to_string(),
+                supports_styles: false,
+                supports_positioning: false,
+                supports_formatting: false,
+                supports_embedded_fonts: false,
+                is_lossless: false,
+                spec_version: None,
+            },
+        }
+    }
+
+    /// Preferred MIME type for this format
+    pub fn mime_type(&self) -> &str {
+        self.mime_types.first().map_or("", String::as_str)
+    }
 }
 
 /// Configuration options for format import/export operations
@@ -13,7 +13,7 @@ fn test_webvtt_format_creation() {
     let format = WebVttFormat::new();
     let info = FormatImporter::format_info(&format);
     assert_eq!(info.name, "WebVTT");
-    assert!(info.supports_styling);
+    assert!(info.supports_styles);
     assert!(info.supports_positioning);
     assert!(format.can_import("vtt"));
     assert!(format.can_import("webvtt"));
@@ -4,7 +4,7 @@
 //! dispatch implementation. Import and export behaviour live in sibling
 //! modules.
 
-use crate::formats::{Format, FormatExporter, FormatImporter, FormatInfo};
+use crate::formats::{Format, FormatExporter, FormatImporter, FormatInfo, FormatKind};
 
 /// WebVTT format handler with style and positioning preservation
 #[derive(Debug)]
@@ -16,15 +16,7 @@ impl WebVttFormat {
     /// Create a new WebVTT format handler
     pub fn new() -> Self {
         Self {
-            info: FormatInfo {
-                name: "WebVTT".to_string(),
-                extensions: vec!["vtt".to_string(), "webvtt".to_string()],
-                mime_type: "text/vtt".to_string(),
-                description: "WebVTT subtitle format with full style and positioning preservation"
-                    .to_string(),
-                supports_styling: true,
-                supports_positioning: true,
-            },
+            info: FormatInfo::for_format(FormatKind::WebVtt),
         }
     }
 }
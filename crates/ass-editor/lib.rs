@@ -21,7 +21,7 @@
 //! ## 🔍 Advanced Features
 //! - **Search indexing**: FST-based trie indexing for fast regex queries across large scripts
 //! - **Plugin system**: Extensible architecture with syntax highlighting and auto-completion
-//! - **Format support**: Import/export SRT, WebVTT with configurable conversion options
+//! - **Format support**: Import/export SRT, WebVTT and import LRC lyrics with configurable conversion options
 //! - **Karaoke support**: Generate, split, adjust, and apply karaoke timing with syllable detection
 //!
 //! # Quick Start
@@ -157,19 +157,21 @@ pub mod sessions;
 pub mod formats;
 
 // Re-export ass-core types as first-class citizens
-pub use ass_core::parser::ast::{Event, EventType, ScriptInfo, Section, SectionType, Span, Style};
+pub use ass_core::parser::ast::{
+    Event, EventType, ScriptInfo, Section, SectionType, Span, Style, WrapMode,
+};
 pub use ass_core::parser::Script;
 
 // Public API exports
 pub use commands::{
     BatchCommand, CommandResult, DeleteTextCommand, DocumentCommandExt, EditorCommand,
-    InsertTextCommand, ReplaceTextCommand, TextCommand,
+    InsertTextCommand, Macro, MacroRecorder, RecordedCommand, ReplaceTextCommand, TextCommand,
 };
 pub use core::{
-    DocumentPosition, EditorDocument, EditorError, EventAccessor, EventBuilder, EventInfo,
-    EventQuery, EventSortCriteria, EventSortOptions, HistoryEntry, HistoryStats, OwnedEvent,
-    Position, PositionBuilder, Range, Result, Selection, StyleBuilder, UndoManager, UndoStack,
-    UndoStackConfig,
+    BranchInfo, DocumentPosition, EditorDocument, EditorError, EventAccessor, EventBuilder,
+    EventInfo, EventQuery, EventSortCriteria, EventSortOptions, HistoryEntry, HistoryStats,
+    OwnedEvent, Position, PositionBuilder, Range, Result, Selection, StyleBuilder, UndoManager,
+    UndoStack, UndoStackConfig,
 };
 // Re-export the fluent EventFilter directly
 pub use core::fluent::EventFilter;
@@ -178,19 +180,25 @@ pub use events::{
     DocumentEvent, EventChannel, EventChannelConfig, EventFilter as DocumentEventFilter,
     EventHandler, EventStats,
 };
+pub use extensions::builtin::{ReadingSpeedExtension, ReadingSpeedInfo, ReadingSpeedSummary};
 pub use extensions::{
     EditorContext, EditorExtension, ExtensionCapability, ExtensionCommand, ExtensionContext,
     ExtensionInfo, ExtensionManager, ExtensionResult, ExtensionState, MessageLevel,
 };
 
 pub use utils::{
-    LazyValidator, ValidationIssue, ValidationResult, ValidationSeverity, ValidatorConfig,
+    display_width, ActorRegistry, CpsCountMode, CpsValidator, DuplicateDetector, DuplicateGroup,
+    EventDisplayExt, LazyValidator, ValidationIssue, ValidationResult, ValidationSeverity,
+    ValidatorConfig,
 };
 
 #[cfg(feature = "std")]
-pub use sessions::{EditorSession, EditorSessionManager, SessionConfig, SessionStats};
+pub use sessions::{
+    EditorSession, EditorSessionManager, FontRegistry, SessionConfig, SessionStats,
+};
 
 #[cfg(feature = "std")]
 pub use formats::{
-    Format, FormatExporter, FormatImporter, FormatInfo, FormatOptions, FormatRegistry, FormatResult,
+    DetectionResult, FeatureLoss, Format, FormatExporter, FormatImporter, FormatInfo, FormatKind,
+    FormatOptions, FormatRegistry, FormatResult,
 };
@@ -1,12 +1,14 @@
 //! Document-access, statistics, and maintenance operations.
 //!
 //! Implements read/write access to a session's [`EditorDocument`], session
-//! statistics reporting, stale-session cleanup, shared-arena resets, and
-//! shared extension-registry management for [`EditorSessionManager`].
+//! and dialogue timing statistics, stale-session cleanup, shared-arena
+//! resets, and shared extension-registry management for
+//! [`EditorSessionManager`].
 
 use super::config::SessionStats;
 use super::manager::EditorSessionManager;
 use crate::core::{EditorDocument, EditorError, Result};
+use ass_core::analysis::TimingStats;
 
 #[cfg(feature = "plugins")]
 use std::sync::Arc;
@@ -55,6 +57,13 @@ impl EditorSessionManager {
         self.with_inner(|inner| inner.stats.clone())
     }
 
+    /// Dialogue timing statistics of a session's document
+    pub fn timing_stats(&self, session_id: &str) -> Result<TimingStats> {
+        self.with_document(session_id, |document| {
+            document.parse_script_with(TimingStats::compute)
+        })
+    }
+
     /// Perform cleanup of stale sessions
     #[cfg(feature = "std")]
     pub fn cleanup_stale_sessions(&mut self, max_age: std::time::Duration) -> Result<usize> {
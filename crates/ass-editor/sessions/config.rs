@@ -4,6 +4,11 @@
 //! [`SessionStats`] for reporting active sessions, memory usage, and
 //! arena-reset activity.
 
+use super::fonts::FontRegistry;
+use crate::core::Result;
+use std::path::Path;
+use std::sync::Arc;
+
 /// Configuration for session management
 #[derive(Debug, Clone)]
 pub struct SessionConfig {
@@ -24,6 +29,9 @@ pub struct SessionConfig {
 
     /// Whether to share extension registry across sessions
     pub share_extensions: bool,
+
+    /// Fonts shared by every session of the manager
+    pub fonts: Option<Arc<FontRegistry>>,
 }
 
 impl Default for SessionConfig {
@@ -35,10 +43,19 @@ impl Default for SessionConfig {
             auto_cleanup: true,
             arena_reset_interval: 1000, // Reset every 1000 operations
             share_extensions: true,
+            fonts: None,
         }
     }
 }
 
+impl SessionConfig {
+    /// Pre-load every font in `path` into a registry shared by all sessions
+    pub fn with_font_dir(mut self, path: &Path) -> Result<Self> {
+        self.fonts = Some(Arc::new(FontRegistry::load_dir(path)?));
+        Ok(self)
+    }
+}
+
 /// Statistics about session manager
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct SessionStats {
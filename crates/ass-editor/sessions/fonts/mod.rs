@@ -0,0 +1,172 @@
+//! Font registry shared by all sessions of a manager.
+//!
+//! Defines [`FontRegistry`], which loads every TrueType/OpenType file in a
+//! directory once and hands out reference-counted font data, so sessions
+//! rendering the same family never load it twice.
+
+mod sfnt;
+
+use self::sfnt::{face_style, family_name, REGULAR_WEIGHT};
+use crate::core::{EditorError, Result};
+use std::collections::HashMap;
+use std::path::Path;
+use std::sync::Arc;
+
+/// File extensions loaded by [`FontRegistry::load_dir`]
+const FONT_EXTENSIONS: [&str; 4] = ["ttf", "otf", "ttc", "otc"];
+
+/// One face of a registered family
+struct FontFace {
+    /// Weight class, 400 for regular and 700 for bold
+    weight: u16,
+    /// Whether the face is italic or oblique
+    italic: bool,
+    /// Font file contents
+    data: Arc<[u8]>,
+}
+
+/// Font files indexed by family name
+///
+/// Family names come from the font's `name` table, falling back to the file
+/// stem, and are matched ASCII case-insensitively. A family keeps every face
+/// registered for it; [`FontRegistry::get_face`] picks the closest one.
+#[derive(Default)]
+pub struct FontRegistry {
+    /// Family name and faces keyed by lowercased family name
+    fonts: HashMap<String, (String, Vec<FontFace>)>,
+}
+
+impl FontRegistry {
+    /// Create an empty registry
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Load every font file in `dir` (non-recursive)
+    pub fn load_dir(dir: &Path) -> Result<Self> {
+        let entries = std::fs::read_dir(dir)
+            .map_err(|e| EditorError::IoError(format!("{}: {e}", dir.display())))?;
+        let mut registry = Self::new();
+        for entry in entries {
+            let path = entry
+                .map_err(|e| EditorError::IoError(e.to_string()))?
+                .path();
+            let is_font = path
+                .extension()
+                .and_then(|ext| ext.to_str())
+                .is_some_and(|ext| FONT_EXTENSIONS.iter().any(|f| ext.eq_ignore_ascii_case(f)));
+            if !is_font {
+                continue;
+            }
+            let data = std::fs::read(&path)
+                .map_err(|e| EditorError::IoError(format!("{}: {e}", path.display())))?;
+            let family = family_name(&data).or_else(|| {
+                path.file_stem()
+                    .and_then(|stem| stem.to_str())
+                    .map(str::to_string)
+            });
+            if let Some(family) = family {
+                registry.insert(family, data.into());
+            }
+        }
+        Ok(registry)
+    }
+
+    /// Register font `data` under `family`
+    ///
+    /// The weight and slant are read from the font's `OS/2` or `head` table.
+    /// A face with the same weight and slant as `data` is replaced.
+    pub fn insert(&mut self, family: String, data: Arc<[u8]>) {
+        let (weight, italic) = face_style(&data);
+        self.insert_face(family, weight, italic, data);
+    }
+
+    /// Register font `data` as the `weight`/`italic` face of `family`
+    ///
+    /// Replaces a face with the same weight and slant.
+    pub fn insert_face(&mut self, family: String, weight: u16, italic: bool, data: Arc<[u8]>) {
+        let (_, faces) = self
+            .fonts
+            .entry(family.to_ascii_lowercase())
+            .or_insert_with(|| (family, Vec::new()));
+        let face = FontFace {
+            weight,
+            italic,
+            data,
+        };
+        match faces
+            .iter_mut()
+            .find(|f| f.weight == weight && f.italic == italic)
+        {
+            Some(existing) => *existing = face,
+            None => faces.push(face),
+        }
+    }
+
+    /// Font data for the regular face of `family`, or its closest face
+    #[must_use]
+    pub fn get(&self, family: &str) -> Option<Arc<[u8]>> {
+        self.get_face(family, REGULAR_WEIGHT, false)
+    }
+
+    /// Font data for the face of `family` closest to `weight` and `italic`
+    ///
+    /// A face with the requested slant is preferred over any weight match;
+    /// among those, the face nearest in weight wins, the heavier one on ties.
+    #[must_use]
+    pub fn get_face(&self, family: &str, weight: u16, italic: bool) -> Option<Arc<[u8]>> {
+        let (_, faces) = self.fonts.get(&family.to_ascii_lowercase())?;
+        faces
+            .iter()
+            .min_by_key(|face| {
+                (
+                    face.italic != italic,
+                    face.weight.abs_diff(weight),
+                    core::cmp::Reverse(face.weight),
+                )
+            })
+            .map(|face| Arc::clone(&face.data))
+    }
+
+    /// Number of faces registered for `family`
+    #[must_use]
+    pub fn face_count(&self, family: &str) -> usize {
+        self.fonts
+            .get(&family.to_ascii_lowercase())
+            .map_or(0, |(_, faces)| faces.len())
+    }
+
+    /// Whether `family` is registered
+    #[must_use]
+    pub fn contains(&self, family: &str) -> bool {
+        self.fonts.contains_key(&family.to_ascii_lowercase())
+    }
+
+    /// Registered family names, sorted
+    #[must_use]
+    pub fn families(&self) -> Vec<&str> {
+        let mut families: Vec<&str> = self.fonts.values().map(|(name, _)| name.as_str()).collect();
+        families.sort_unstable();
+        families
+    }
+
+    /// Number of registered families
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.fonts.len()
+    }
+
+    /// Whether the registry holds no fonts
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.fonts.is_empty()
+    }
+}
+
+impl core::fmt::Debug for FontRegistry {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("FontRegistry")
+            .field("families", &self.families())
+            .finish()
+    }
+}
@@ -0,0 +1,96 @@
+//! Minimal sfnt table reading for [`super::FontRegistry`].
+//!
+//! Reads just enough of a TrueType/OpenType file to index it: the family
+//! name from the `name` table and the weight and slant from the `OS/2` or
+//! `head` table.
+
+/// Weight of a regular face
+pub(super) const REGULAR_WEIGHT: u16 = 400;
+
+/// Weight assumed for faces flagged bold without an `OS/2` table
+const BOLD_WEIGHT: u16 = 700;
+
+/// Read a big-endian `u16` at `offset`
+fn read_u16(data: &[u8], offset: usize) -> Option<u16> {
+    data.get(offset..offset + 2)
+        .map(|bytes| u16::from_be_bytes([bytes[0], bytes[1]]))
+}
+
+/// Read a big-endian `u32` at `offset`
+fn read_u32(data: &[u8], offset: usize) -> Option<u32> {
+    data.get(offset..offset + 4)
+        .map(|bytes| u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+}
+
+/// Offset of table `tag` of the first face in `data`
+fn table(data: &[u8], tag: &[u8; 4]) -> Option<usize> {
+    // Collections point at their first face's table directory
+    let face = if data.starts_with(b"ttcf") {
+        read_u32(data, 12)? as usize
+    } else {
+        0
+    };
+    let num_tables = read_u16(data, face + 4)? as usize;
+    (0..num_tables)
+        .map(|i| face + 12 + i * 16)
+        .find(|&record| data.get(record..record + 4) == Some(tag.as_slice()))
+        .and_then(|record| read_u32(data, record + 8))
+        .map(|offset| offset as usize)
+}
+
+/// Family name (name ID 1) from the `name` table of the first face in `data`
+pub(super) fn family_name(data: &[u8]) -> Option<String> {
+    let name_table = table(data, b"name")?;
+    let count = read_u16(data, name_table + 2)? as usize;
+    let strings = name_table + read_u16(data, name_table + 4)? as usize;
+    let mut fallback = None;
+    for i in 0..count {
+        let record = name_table + 6 + i * 12;
+        let platform = read_u16(data, record)?;
+        let name_id = read_u16(data, record + 6)?;
+        if name_id != 1 {
+            continue;
+        }
+        let length = read_u16(data, record + 8)? as usize;
+        let start = strings + read_u16(data, record + 10)? as usize;
+        let bytes = data.get(start..start + length)?;
+        match platform {
+            // Unicode and Windows names are UTF-16BE
+            0 | 3 => {
+                let units: Vec<u16> = bytes
+                    .chunks_exact(2)
+                    .map(|pair| u16::from_be_bytes([pair[0], pair[1]]))
+                    .collect();
+                return String::from_utf16(&units).ok();
+            }
+            // Macintosh Roman, close enough to ASCII for family names
+            1 if fallback.is_none() => {
+                fallback = Some(bytes.iter().map(|&b| char::from(b)).collect());
+            }
+            _ => {}
+        }
+    }
+    fallback
+}
+
+/// Weight and italic flag of the first face in `data`
+///
+/// Prefers `usWeightClass` and `fsSelection` from `OS/2`, falls back to the
+/// `macStyle` bits of `head`, and assumes a regular upright face otherwise.
+pub(super) fn face_style(data: &[u8]) -> (u16, bool) {
+    if let Some(os2) = table(data, b"OS/2") {
+        if let (Some(weight), Some(selection)) = (read_u16(data, os2 + 4), read_u16(data, os2 + 62))
+        {
+            return (weight, selection & 1 != 0);
+        }
+    }
+    if let Some(mac_style) = table(data, b"head").and_then(|head| read_u16(data, head + 44)) {
+        let weight = if mac_style & 1 != 0 {
+            BOLD_WEIGHT
+        } else {
+            REGULAR_WEIGHT
+        };
+        return (weight, mac_style & 2 != 0);
+    }
+    (REGULAR_WEIGHT, false)
+}
@@ -41,7 +41,8 @@ impl EditorSessionManager {
             }
 
             // Create new session
-            let session = EditorSession::new(session_id.clone(), document);
+            let mut session = EditorSession::new(session_id.clone(), document);
+            session.fonts = inner.config.fonts.clone();
 
             // Add to sessions map
             inner.sessions.insert(session_id.clone(), session);
@@ -75,6 +75,33 @@ fn session_document_access() {
         .unwrap();
 }
 
+#[test]
+fn session_timing_stats() {
+    let mut manager = EditorSessionManager::new();
+    let doc = EditorDocument::from_content(
+        "[Events]
+Format: Layer, Start, End, Style, Name, MarginL, MarginR, MarginV, Effect, Text
+Dialogue: 0,0:00:00.00,0:00:02.00,Default,,0,0,0,,One
+Dialogue: 0,0:00:05.00,0:00:06.00,Default,,0,0,0,,Two
+",
+    )
+    .unwrap();
+    manager
+        .create_session_with_document("timed".to_string(), doc)
+        .unwrap();
+
+    let stats = manager.timing_stats("timed").unwrap();
+    assert_eq!(stats.event_count, 2);
+    assert_eq!(stats.total_dialogue_centiseconds, 300);
+    assert_eq!(stats.max_gap_cs, 300);
+    assert_eq!(stats.script_duration_cs, 600);
+
+    assert!(matches!(
+        manager.timing_stats("missing"),
+        Err(EditorError::DocumentNotFound { .. })
+    ));
+}
+
 #[test]
 fn session_removal() {
     let mut manager = EditorSessionManager::new();
@@ -1,7 +1,7 @@
 //! Session management for multi-document editing
 //!
 //! Provides the `EditorSessionManager` for managing multiple documents
-//! with shared resources, arenas, and extension registries. Supports
+//! with shared resources, arenas, extension registries, and fonts. Supports
 //! efficient session switching (<100µs target) and resource pooling.
 
 #[cfg(not(feature = "std"))]
@@ -9,13 +9,18 @@ extern crate alloc;
 
 mod access;
 mod config;
+mod fonts;
 mod lifecycle;
 mod manager;
 mod session;
+mod shared;
 
 #[cfg(test)]
 mod manager_tests;
+#[cfg(test)]
+mod shared_tests;
 
 pub use config::{SessionConfig, SessionStats};
+pub use fonts::FontRegistry;
 pub use manager::EditorSessionManager;
 pub use session::EditorSession;
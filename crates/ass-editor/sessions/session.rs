@@ -4,7 +4,9 @@
 //! identifier, access bookkeeping, memory accounting, and per-session
 //! metadata.
 
+use super::fonts::FontRegistry;
 use crate::core::EditorDocument;
+use std::sync::Arc;
 
 #[cfg(feature = "std")]
 use std::collections::HashMap;
@@ -36,6 +38,9 @@ pub struct EditorSession {
 
     /// Session-specific metadata
     pub metadata: HashMap<String, String>,
+
+    /// Fonts shared with the other sessions of the manager
+    pub fonts: Option<Arc<FontRegistry>>,
 }
 
 impl EditorSession {
@@ -49,6 +54,7 @@ impl EditorSession {
             memory_usage: 0,
             operation_count: 0,
             metadata: HashMap::new(),
+            fonts: None,
         }
     }
 
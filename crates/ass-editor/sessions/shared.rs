@@ -0,0 +1,68 @@
+//! Cross-session operations for [`EditorSessionManager`].
+//!
+//! Implements access to the shared font registry, event broadcasting to
+//! every session's document channel, and search across all sessions.
+
+use super::fonts::FontRegistry;
+use super::manager::EditorSessionManager;
+use crate::core::{EditorError, Result};
+use crate::events::DocumentEvent;
+use std::sync::Arc;
+
+#[cfg(feature = "formats")]
+use crate::utils::search::{DocumentSearcher, SearchMatch};
+#[cfg(feature = "formats")]
+use std::collections::HashMap;
+
+impl EditorSessionManager {
+    /// Get the font registry shared by all sessions
+    #[must_use]
+    pub fn font_registry(&self) -> Option<Arc<FontRegistry>> {
+        self.with_inner(|inner| inner.config.fonts.clone())
+    }
+
+    /// Send `event` to the event channel of every session's document
+    ///
+    /// Sessions whose documents have no event channel are skipped. Every
+    /// session is attempted; those whose receiver has been dropped are
+    /// reported in the returned error.
+    pub fn broadcast_event(&self, event: DocumentEvent) -> Result<()> {
+        let failed = self.with_inner(|inner| {
+            let mut failed: Vec<&str> = inner
+                .sessions
+                .iter()
+                .filter(|(_, session)| session.document.has_event_channel())
+                .filter(|(_, session)| !session.document.send_event(event.clone()))
+                .map(|(id, _)| id.as_str())
+                .collect();
+            failed.sort_unstable();
+            failed.join(", ")
+        });
+
+        if failed.is_empty() {
+            Ok(())
+        } else {
+            Err(EditorError::EventChannelError {
+                message: format!("Event receivers dropped for sessions: {failed}"),
+            })
+        }
+    }
+
+    /// Search event fields of every session for regex `pattern`
+    ///
+    /// Sessions without matches are omitted from the result, and each
+    /// session reports at most the default `SearchOptions::max_results`.
+    #[cfg(feature = "formats")]
+    pub fn find_events(&self, pattern: &str) -> Result<HashMap<String, Vec<SearchMatch>>> {
+        self.with_inner(|inner| {
+            let mut found = HashMap::new();
+            for (id, session) in &inner.sessions {
+                let matches = DocumentSearcher::new(&session.document).find_all(pattern)?;
+                if !matches.is_empty() {
+                    found.insert(id.clone(), matches);
+                }
+            }
+            Ok(found)
+        })
+    }
+}
@@ -0,0 +1,191 @@
+//! Tests for the shared font registry and cross-session operations.
+
+use super::*;
+use crate::core::{EditorDocument, EditorError};
+use crate::events::DocumentEvent;
+use std::sync::{mpsc, Arc};
+
+/// Minimal sfnt file holding `tables`
+fn sfnt(tables: &[(&[u8; 4], Vec<u8>)]) -> Vec<u8> {
+    let mut data = Vec::new();
+    // Offset table: version, table count, zeroed search hints
+    data.extend_from_slice(&[0, 1, 0, 0]);
+    data.extend_from_slice(&(tables.len() as u16).to_be_bytes());
+    data.extend_from_slice(&[0; 6]);
+    // Table records: tag, checksum, offset, length
+    let mut offset = 12 + 16 * tables.len();
+    for (tag, table) in tables {
+        data.extend_from_slice(*tag);
+        data.extend_from_slice(&0u32.to_be_bytes());
+        data.extend_from_slice(&(offset as u32).to_be_bytes());
+        data.extend_from_slice(&(table.len() as u32).to_be_bytes());
+        offset += table.len();
+    }
+    for (_, table) in tables {
+        data.extend_from_slice(table);
+    }
+    data
+}
+
+/// `name` table with one Windows family-name record
+fn name_table(family: &str) -> Vec<u8> {
+    let name: Vec<u8> = family.encode_utf16().flat_map(u16::to_be_bytes).collect();
+    let mut table = Vec::new();
+    for value in [0u16, 1, 18, 3, 1, 0x409, 1, name.len() as u16, 0] {
+        table.extend_from_slice(&value.to_be_bytes());
+    }
+    table.extend_from_slice(&name);
+    table
+}
+
+/// Minimal sfnt file whose only table is a `name` table naming `family`
+fn font_bytes(family: &str) -> Vec<u8> {
+    sfnt(&[(b"name", name_table(family))])
+}
+
+/// Minimal sfnt file for the `weight`/`italic` face of `family`
+fn face_bytes(family: &str, weight: u16, italic: bool) -> Vec<u8> {
+    let mut os2 = vec![0; 64];
+    os2[4..6].copy_from_slice(&weight.to_be_bytes());
+    os2[62..64].copy_from_slice(&u16::from(italic).to_be_bytes());
+    sfnt(&[(b"OS/2", os2), (b"name", name_table(family))])
+}
+
+#[test]
+fn font_registry_loads_family_names() {
+    let dir = tempfile::tempdir().unwrap();
+    std::fs::write(dir.path().join("a.ttf"), font_bytes("Test Sans")).unwrap();
+    std::fs::write(dir.path().join("Fallback.otf"), b"not a font").unwrap();
+    std::fs::write(dir.path().join("notes.txt"), b"ignored").unwrap();
+
+    let registry = FontRegistry::load_dir(dir.path()).unwrap();
+    assert_eq!(registry.families(), ["Fallback", "Test Sans"]);
+    assert!(registry.contains("test sans"));
+    assert_eq!(registry.get("TEST SANS").unwrap().len(), 64);
+    assert!(FontRegistry::load_dir(&dir.path().join("missing")).is_err());
+}
+
+#[test]
+fn font_registry_keeps_every_face_of_a_family() {
+    let dir = tempfile::tempdir().unwrap();
+    for (file, weight, italic) in [
+        ("Family-Regular.ttf", 400, false),
+        ("Family-Bold.ttf", 700, false),
+        ("Family-Italic.ttf", 400, true),
+        ("Family-BoldItalic.ttf", 700, true),
+    ] {
+        std::fs::write(dir.path().join(file), face_bytes("Family", weight, italic)).unwrap();
+    }
+
+    let registry = FontRegistry::load_dir(dir.path()).unwrap();
+    assert_eq!(registry.families(), ["Family"]);
+    assert_eq!(registry.len(), 1);
+    assert_eq!(registry.face_count("family"), 4);
+
+    let face = |weight, italic| registry.get_face("Family", weight, italic).unwrap();
+    let expected = |weight, italic| Arc::<[u8]>::from(face_bytes("Family", weight, italic));
+    assert_eq!(registry.get("Family").unwrap(), expected(400, false));
+    assert_eq!(face(700, false), expected(700, false));
+    assert_eq!(face(400, true), expected(400, true));
+    assert_eq!(face(700, true), expected(700, true));
+    // Closest weight with the requested slant
+    assert_eq!(face(900, false), expected(700, false));
+    assert_eq!(face(300, true), expected(400, true));
+}
+
+#[test]
+fn font_registry_falls_back_to_closest_face() {
+    let mut registry = FontRegistry::new();
+    let bold: Arc<[u8]> = face_bytes("Solo", 700, false).into();
+    registry.insert("Solo".to_string(), Arc::clone(&bold));
+    assert!(Arc::ptr_eq(&registry.get("solo").unwrap(), &bold));
+    assert!(Arc::ptr_eq(
+        &registry.get_face("Solo", 400, true).unwrap(),
+        &bold
+    ));
+
+    // Same weight and slant replaces the face
+    let replacement: Arc<[u8]> = Arc::from(&b"bold v2"[..]);
+    registry.insert_face("SOLO".to_string(), 700, false, Arc::clone(&replacement));
+    assert_eq!(registry.face_count("Solo"), 1);
+    assert!(Arc::ptr_eq(&registry.get("Solo").unwrap(), &replacement));
+    assert!(registry.get_face("Other", 400, false).is_none());
+}
+
+#[test]
+fn sessions_share_a_single_font() {
+    let dir = tempfile::tempdir().unwrap();
+    std::fs::write(dir.path().join("shared.ttf"), font_bytes("Shared")).unwrap();
+
+    let config = SessionConfig::default().with_font_dir(dir.path()).unwrap();
+    let mut manager = EditorSessionManager::with_config(config);
+    manager.create_session("one".to_string()).unwrap();
+    manager.create_session("two".to_string()).unwrap();
+
+    let registry = manager.font_registry().unwrap();
+    let one = manager.remove_session("one").unwrap().fonts.unwrap();
+    let two = manager.remove_session("two").unwrap().fonts.unwrap();
+    assert!(Arc::ptr_eq(&one, &registry));
+    assert!(Arc::ptr_eq(&one, &two));
+    assert!(Arc::ptr_eq(
+        &one.get("Shared").unwrap(),
+        &two.get("shared").unwrap()
+    ));
+}
+
+#[test]
+fn broadcast_event_reaches_every_channel() {
+    let mut manager = EditorSessionManager::new();
+    let (tx_a, rx_a) = mpsc::channel();
+    let (tx_b, rx_b) = mpsc::channel();
+    manager
+        .create_session_with_document("a".to_string(), EditorDocument::with_event_channel(tx_a))
+        .unwrap();
+    manager
+        .create_session_with_document("b".to_string(), EditorDocument::with_event_channel(tx_b))
+        .unwrap();
+    manager.create_session("no-channel".to_string()).unwrap();
+
+    let event = DocumentEvent::ConfigChanged {
+        key: "theme".to_string(),
+        old_value: None,
+        new_value: "dark".to_string(),
+    };
+    manager.broadcast_event(event.clone()).unwrap();
+    assert_eq!(rx_a.try_recv().unwrap(), event);
+    assert_eq!(rx_b.try_recv().unwrap(), event);
+
+    drop(rx_b);
+    let result = manager.broadcast_event(event);
+    assert!(matches!(
+        result,
+        Err(EditorError::EventChannelError { message }) if message.ends_with(": b")
+    ));
+}
+
+#[cfg(feature = "formats")]
+#[test]
+fn find_events_searches_all_sessions() {
+    const EVENTS: &str =
+        "[Events]\nFormat: Layer, Start, End, Style, Name, MarginL, MarginR, MarginV, Effect, Text\n";
+    let mut manager = EditorSessionManager::new();
+    for (id, text) in [
+        ("a", "Hello there"),
+        ("b", "Nothing"),
+        ("c", "hello, hello"),
+    ] {
+        let content = format!("{EVENTS}Dialogue: 0,0:00:00.00,0:00:01.00,Default,,0,0,0,,{text}");
+        manager
+            .create_session_with_document(
+                id.to_string(),
+                EditorDocument::from_content(&content).unwrap(),
+            )
+            .unwrap();
+    }
+
+    let found = manager.find_events("hello").unwrap();
+    assert_eq!(found.len(), 2);
+    assert_eq!(found["a"].len(), 1);
+    assert_eq!(found["c"].len(), 2);
+    assert!(manager.find_events("(").is_err());
+}
@@ -0,0 +1,134 @@
+//! Cast list of a document's events
+//!
+//! `ActorRegistry` collects the actor (`Name` field) of every event, answers
+//! which events an actor speaks in, and turns renames into `BatchCommand`s of
+//! [`AssignActorCommand`]s. It listens on the document event channel to learn
+//! when its snapshot is out of date.
+
+use crate::commands::{AssignActorCommand, BatchCommand, EditorCommand};
+use crate::core::{EditorDocument, Result};
+use crate::events::{DocumentEvent, EventHandler};
+use ass_core::parser::Section;
+
+#[cfg(not(feature = "std"))]
+use alloc::{
+    boxed::Box,
+    collections::BTreeMap,
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
+#[cfg(feature = "std")]
+use std::collections::BTreeMap;
+
+#[cfg(test)]
+mod tests;
+
+/// Actors of a document's events and the events they appear in
+///
+/// Events without an actor are not listed. Event indices count Dialogue and
+/// Comment events in document order. Feed the registry the document's
+/// events through [`EventHandler::handle_event`]; once an edit is seen it
+/// reports itself [stale](Self::is_stale) until [`refresh`](Self::refresh)
+/// rebuilds it.
+///
+/// # Examples
+///
+/// ```
+/// use ass_editor::{utils::ActorRegistry, EditorDocument};
+///
+/// let mut doc = EditorDocument::from_content(
+///     "[Events]\nFormat: Layer, Start, End, Style, Name, MarginL, MarginR, MarginV, Effect, Text\nDialogue: 0,0:00:01.00,0:00:03.00,Default,Alice,0,0,0,,Hello\nDialogue: 0,0:00:03.00,0:00:05.00,Default,Bob,0,0,0,,Hi",
+/// ).unwrap();
+///
+/// let registry = ActorRegistry::from_document(&doc).unwrap();
+/// assert_eq!(registry.actors(), vec!["Alice", "Bob"]);
+/// assert_eq!(registry.events_for("Bob"), vec![1]);
+///
+/// doc.execute_command(&registry.rename_actor("Bob", "Carol")).unwrap();
+/// assert!(doc.text().contains("Default,Carol,"));
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct ActorRegistry {
+    /// Event indices per actor, sorted by index
+    actors: BTreeMap<String, Vec<usize>>,
+    /// Whether the document changed since the registry was built
+    stale: bool,
+}
+
+impl ActorRegistry {
+    /// Build the registry for `document`
+    pub fn from_document(document: &EditorDocument) -> Result<Self> {
+        let mut registry = Self::default();
+        registry.refresh(document)?;
+        Ok(registry)
+    }
+
+    /// Rebuild the registry from `document` and clear the stale flag
+    pub fn refresh(&mut self, document: &EditorDocument) -> Result<()> {
+        self.actors = document.parse_script_with(|script| {
+            let mut actors: BTreeMap<String, Vec<usize>> = BTreeMap::new();
+            let all_events = script
+                .sections()
+                .iter()
+                .filter_map(|section| match section {
+                    Section::Events(events) => Some(events),
+                    _ => None,
+                })
+                .flatten();
+            for (index, event) in all_events.enumerate() {
+                let actor = event.name.trim();
+                if !actor.is_empty() {
+                    actors.entry(actor.to_string()).or_default().push(index);
+                }
+            }
+            actors
+        })?;
+        self.stale = false;
+        Ok(())
+    }
+
+    /// Whether a document edit was seen since the last refresh
+    pub fn is_stale(&self) -> bool {
+        self.stale
+    }
+
+    /// All actor names, sorted
+    pub fn actors(&self) -> Vec<&str> {
+        self.actors.keys().map(String::as_str).collect()
+    }
+
+    /// Whether `actor` appears in any event
+    pub fn contains(&self, actor: &str) -> bool {
+        self.actors.contains_key(actor)
+    }
+
+    /// Indices of the events spoken by `actor`, in document order
+    pub fn events_for(&self, actor: &str) -> Vec<usize> {
+        self.actors.get(actor).cloned().unwrap_or_default()
+    }
+
+    /// Command renaming `old` to `new` in every event it appears in
+    ///
+    /// The batch is empty when `old` is unknown; executing it through
+    /// [`EditorDocument::execute_command`] makes the rename one undo step.
+    pub fn rename_actor(&self, old: &str, new: &str) -> BatchCommand {
+        let commands = self
+            .events_for(old)
+            .into_iter()
+            .map(|index| {
+                Box::new(AssignActorCommand::new(index, new.to_string())) as Box<dyn EditorCommand>
+            })
+            .collect();
+        BatchCommand::new(format!("Rename actor {old} to {new}")).add_commands(commands)
+    }
+}
+
+impl EventHandler for ActorRegistry {
+    fn handle_event(&mut self, event: &DocumentEvent) -> Result<()> {
+        if event.is_modification() || matches!(event, DocumentEvent::DocumentLoaded { .. }) {
+            self.stale = true;
+        }
+        Ok(())
+    }
+}
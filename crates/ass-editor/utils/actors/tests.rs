@@ -0,0 +1,149 @@
+//! Tests for the actor registry and actor commands
+
+use super::*;
+use crate::core::Position;
+
+#[cfg(not(feature = "std"))]
+use alloc::{format, string::String, vec, vec::Vec};
+
+const HEADER: &str = "[Script Info]
+Title: Cast
+
+[Events]
+Format: Layer, Start, End, Style, Name, MarginL, MarginR, MarginV, Effect, Text
+";
+
+const CAST: [&str; 3] = ["Alice", "Bob", "Narrator"];
+
+/// A 20-event script rotating through the cast, with every fifth event
+/// left without an actor
+fn document() -> EditorDocument {
+    let mut content = String::from(HEADER);
+    for index in 0..20 {
+        let actor = if index % 5 == 4 { "" } else { CAST[index % 3] };
+        let kind = if index == 7 { "Comment" } else { "Dialogue" };
+        content.push_str(&format!(
+            "{kind}: 0,0:00:{index:02}.00,0:00:{index:02}.50,Default,{actor},0,0,0,,Line {index}\n"
+        ));
+    }
+    EditorDocument::from_content(&content).unwrap()
+}
+
+fn expected_events(actor: &str) -> Vec<usize> {
+    let slot = CAST.iter().position(|&name| name == actor).unwrap();
+    (0..20)
+        .filter(|index| index % 5 != 4 && index % 3 == slot)
+        .collect()
+}
+
+#[test]
+fn collects_cast_and_appearances() {
+    let registry = ActorRegistry::from_document(&document()).unwrap();
+    assert_eq!(registry.actors(), CAST.to_vec());
+    for actor in CAST {
+        assert_eq!(registry.events_for(actor), expected_events(actor));
+    }
+    assert!(registry.events_for("Nobody").is_empty());
+    assert!(!registry.contains(""));
+}
+
+#[test]
+fn rename_updates_every_event_in_one_step() {
+    let mut doc = document();
+    let original = doc.text();
+    let registry = ActorRegistry::from_document(&doc).unwrap();
+    let bob = registry.events_for("Bob");
+
+    doc.execute_command(&registry.rename_actor("Bob", "Robert"))
+        .unwrap();
+
+    let renamed = ActorRegistry::from_document(&doc).unwrap();
+    assert!(!renamed.contains("Bob"));
+    assert_eq!(renamed.events_for("Robert"), bob);
+    assert_eq!(renamed.events_for("Alice"), expected_events("Alice"));
+    assert_eq!(doc.text().matches("Default,Robert,").count(), bob.len());
+
+    doc.undo().unwrap();
+    assert_eq!(doc.text(), original);
+}
+
+#[test]
+fn assign_actor_is_undoable() {
+    let mut doc = document();
+    doc.execute_command(&AssignActorCommand::new(4, "Bob".to_string()))
+        .unwrap();
+    assert!(ActorRegistry::from_document(&doc)
+        .unwrap()
+        .events_for("Bob")
+        .contains(&4));
+    assert!(doc.text().contains("Default,Bob,0,0,0,,Line 4\n"));
+
+    doc.undo().unwrap();
+    assert!(doc.text().contains("Default,,0,0,0,,Line 4\n"));
+}
+
+#[test]
+fn assign_actor_rejects_bad_input() {
+    let mut doc = document();
+    let original = doc.text();
+    assert!(doc
+        .execute_command(&AssignActorCommand::new(20, "Bob".to_string()))
+        .is_err());
+    assert!(doc
+        .execute_command(&AssignActorCommand::new(0, "Bob, Alice".to_string()))
+        .is_err());
+    assert_eq!(doc.text(), original);
+}
+
+#[test]
+fn edits_mark_registry_stale_until_refresh() {
+    let mut doc = document();
+    let mut registry = ActorRegistry::from_document(&doc).unwrap();
+    assert!(!registry.is_stale());
+
+    registry
+        .handle_event(&DocumentEvent::CursorMoved {
+            old_position: Position::new(0),
+            new_position: Position::new(0),
+        })
+        .unwrap();
+    assert!(!registry.is_stale());
+
+    doc.execute_command(&registry.rename_actor("Narrator", "Voice"))
+        .unwrap();
+    registry
+        .handle_event(&DocumentEvent::TextReplaced {
+            range: crate::core::Range::new(Position::new(0), Position::new(0)),
+            old_text: String::new(),
+            new_text: String::new(),
+        })
+        .unwrap();
+    assert!(registry.is_stale());
+
+    registry.refresh(&doc).unwrap();
+    assert!(!registry.is_stale());
+    assert_eq!(registry.actors(), vec!["Alice", "Bob", "Voice"]);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn follows_document_event_channel() {
+    let (tx, rx) = std::sync::mpsc::channel();
+    let mut doc = document();
+    doc.set_event_channel(tx);
+    let mut registry = ActorRegistry::from_document(&doc).unwrap();
+
+    let end = Position::new(doc.len_bytes());
+    doc.insert(
+        end,
+        "Dialogue: 0,0:00:20.00,0:00:21.00,Default,Dave,0,0,0,,Line 20\n",
+    )
+    .unwrap();
+    for event in rx.try_iter() {
+        registry.handle_event(&event).unwrap();
+    }
+    assert!(registry.is_stale());
+
+    registry.refresh(&doc).unwrap();
+    assert_eq!(registry.events_for("Dave"), vec![20]);
+}
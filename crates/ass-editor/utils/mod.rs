@@ -3,20 +3,31 @@
 //! Contains helper functionality like validation, search, and other
 //! utilities that support the main editor operations.
 
+pub mod actors;
 pub mod indexing;
 pub mod search;
+pub mod timeline;
 pub mod validator;
+pub mod width;
 
 #[cfg(feature = "formats")]
 pub mod formats;
 
 // Re-export commonly used types
+pub use actors::ActorRegistry;
 pub use validator::{
-    LazyValidator, ValidationIssue, ValidationResult, ValidationSeverity, ValidatorConfig,
+    CpsCountMode, CpsValidator, DuplicateDetector, DuplicateGroup, LazyValidator, ValidationIssue,
+    ValidationResult, ValidationSeverity, ValidatorConfig,
 };
 
 pub use indexing::{create_search_index, IndexEntry};
 pub use search::{DocumentSearch, SearchOptions, SearchResult, SearchScope, SearchStats};
+#[cfg(all(feature = "formats", feature = "std"))]
+pub use search::{DocumentSearcher, EventField, SearchMatch};
+pub use timeline::{
+    EventIndex, GapAdjustment, SnapPolicy, TimelineEvent, TimelineModel, TimingNormalizer,
+};
+pub use width::{display_width, strip_overrides, EventDisplayExt};
 
 #[cfg(feature = "search-index")]
 pub use indexing::FstSearchIndex;
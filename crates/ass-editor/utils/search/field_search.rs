@@ -0,0 +1,206 @@
+//! Regex search and replace over event fields
+//!
+//! [`DocumentSearcher`] matches a regular expression against individual
+//! event fields rather than the raw document text, so a pattern never spans
+//! field separators and `replace_all` only touches the fields it searched.
+//! Requires the `formats` and `std` features for the `regex` dependency.
+
+use super::options::SearchOptions;
+use crate::commands::{BatchCommand, EditorCommand, ReplaceTextCommand};
+use crate::core::{EditorDocument, EditorError, Position, Range, Result};
+use ass_core::parser::{Event, Section};
+use core::ops::Range as ByteRange;
+use regex::{Regex, RegexBuilder};
+
+/// Event field searched by [`DocumentSearcher`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum EventField {
+    /// Dialogue text, including override blocks
+    Text,
+    /// Style referenced by the event
+    StyleName,
+    /// Actor or speaker name
+    Name,
+    /// Effect field
+    Effect,
+}
+
+impl EventField {
+    /// All fields, in the order they are searched
+    pub const ALL: [Self; 4] = [Self::Text, Self::StyleName, Self::Name, Self::Effect];
+
+    /// Read this field from `event`
+    fn get<'a>(self, event: &Event<'a>) -> &'a str {
+        match self {
+            Self::Text => event.text,
+            Self::StyleName => event.style,
+            Self::Name => event.name,
+            Self::Effect => event.effect,
+        }
+    }
+}
+
+/// A regex match inside an event field
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SearchMatch {
+    /// Index of the event in document order
+    pub event_index: usize,
+    /// Field containing the match
+    pub field: EventField,
+    /// Byte range of the match in the document text
+    pub byte_range: ByteRange<usize>,
+    /// The matched text
+    pub text: String,
+}
+
+/// Regex search and replace over the event fields of a document
+///
+/// # Examples
+///
+/// ```
+/// use ass_editor::{EditorDocument, utils::search::{DocumentSearcher, EventField}};
+///
+/// let mut doc = EditorDocument::from_content(
+///     "[Events]\nFormat: Layer, Start, End, Style, Name, MarginL, MarginR, MarginV, Effect, Text\nDialogue: 0,0:00:00.00,0:00:05.00,Default,Ann,0,0,0,,{\\b1}Hello{\\b0} world",
+/// ).unwrap();
+///
+/// let matches = DocumentSearcher::new(&doc).find_all("Hello").unwrap();
+/// assert_eq!(matches[0].field, EventField::Text);
+///
+/// let batch = DocumentSearcher::new(&doc).replace_all("Hello", "Goodbye").unwrap();
+/// doc.execute_command(&batch).unwrap();
+/// assert!(doc.text().ends_with("{\\b1}Goodbye{\\b0} world"));
+/// ```
+#[derive(Debug, Clone)]
+pub struct DocumentSearcher<'a> {
+    /// Document to search
+    document: &'a EditorDocument,
+    /// Regex flags and result limit
+    options: SearchOptions,
+    /// Fields to search
+    fields: Vec<EventField>,
+}
+
+impl<'a> DocumentSearcher<'a> {
+    /// Create a searcher over all event fields with default options
+    pub fn new(document: &'a EditorDocument) -> Self {
+        Self {
+            document,
+            options: SearchOptions::default(),
+            fields: EventField::ALL.to_vec(),
+        }
+    }
+
+    /// Use `options` for case sensitivity, multiline mode, whole words and
+    /// the `find_all` result limit
+    #[must_use]
+    pub fn with_options(mut self, options: SearchOptions) -> Self {
+        self.options = options;
+        self
+    }
+
+    /// Restrict the search to `fields`
+    #[must_use]
+    pub fn with_fields(mut self, fields: &[EventField]) -> Self {
+        self.fields = fields.to_vec();
+        self
+    }
+
+    /// Find all matches of `pattern`, up to `SearchOptions::max_results`
+    pub fn find_all(&self, pattern: &str) -> Result<Vec<SearchMatch>> {
+        let regex = self.compile(pattern)?;
+        let limit = self.options.max_results;
+        let mut matches = Vec::new();
+        self.for_each_field(|event_index, field, offset, value| {
+            for found in regex.find_iter(value) {
+                if limit > 0 && matches.len() >= limit {
+                    return;
+                }
+                matches.push(SearchMatch {
+                    event_index,
+                    field,
+                    byte_range: offset + found.start()..offset + found.end(),
+                    text: found.as_str().to_string(),
+                });
+            }
+        })?;
+        Ok(matches)
+    }
+
+    /// Build a command replacing every match of `pattern` with `replacement`
+    ///
+    /// `replacement` may reference capture groups as `$1` or `${name}`. The
+    /// returned batch applies its edits back to front, so it can be run with
+    /// [`EditorDocument::execute_command`] as one undoable step.
+    pub fn replace_all(&self, pattern: &str, replacement: &str) -> Result<BatchCommand> {
+        let regex = self.compile(pattern)?;
+        let mut edits = Vec::new();
+        self.for_each_field(|_, _, offset, value| {
+            for captures in regex.captures_iter(value) {
+                let Some(found) = captures.get(0) else {
+                    continue;
+                };
+                let mut new_text = String::new();
+                captures.expand(replacement, &mut new_text);
+                let range = Range::new(
+                    Position::new(offset + found.start()),
+                    Position::new(offset + found.end()),
+                );
+                edits.push(ReplaceTextCommand::new(range, new_text));
+            }
+        })?;
+
+        edits.sort_by_key(|edit| core::cmp::Reverse(edit.range.start.offset));
+        let count = edits.len();
+        let commands = edits
+            .into_iter()
+            .map(|edit| Box::new(edit) as Box<dyn EditorCommand>)
+            .collect();
+        Ok(
+            BatchCommand::new(format!("Replace {count} match(es) of '{pattern}'"))
+                .add_commands(commands),
+        )
+    }
+
+    /// Compile `pattern` with the configured flags
+    fn compile(&self, pattern: &str) -> Result<Regex> {
+        let pattern = if self.options.whole_words {
+            format!(r"\b(?:{pattern})\b")
+        } else {
+            pattern.to_string()
+        };
+        RegexBuilder::new(&pattern)
+            .case_insensitive(!self.options.case_sensitive)
+            .multi_line(self.options.multiline)
+            .build()
+            .map_err(|e| EditorError::CommandFailed {
+                message: format!("Invalid regex pattern: {e}"),
+            })
+    }
+
+    /// Call `visit` with each searched field of every event and the field's
+    /// byte offset in the document
+    fn for_each_field(&self, mut visit: impl FnMut(usize, EventField, usize, &str)) -> Result<()> {
+        self.document.parse_script_with(|script| {
+            let source = script.source().as_bytes().as_ptr_range();
+            let events = script
+                .sections()
+                .iter()
+                .filter_map(|section| match section {
+                    Section::Events(events) => Some(events),
+                    _ => None,
+                })
+                .flatten();
+            for (index, event) in events.enumerate() {
+                for &field in &self.fields {
+                    let value = field.get(event);
+                    let start = value.as_ptr();
+                    // Fields not borrowed from the source cannot be mapped back
+                    if source.contains(&start) || start == source.end {
+                        visit(index, field, start as usize - source.start as usize, value);
+                    }
+                }
+            }
+        })
+    }
+}
@@ -0,0 +1,129 @@
+//! Tests for regex search and replace over event fields
+
+use super::*;
+use crate::core::EditorDocument;
+
+const CONTENT: &str = r"[Script Info]
+Title: Search
+
+[V4+ Styles]
+Format: Name, Fontname, Fontsize
+Style: Default,Arial,20
+
+[Events]
+Format: Layer, Start, End, Style, Name, MarginL, MarginR, MarginV, Effect, Text
+Dialogue: 0,0:00:00.00,0:00:05.00,Default,Alice,0,0,0,,{\b1}Hello{\b0} world
+Dialogue: 0,0:00:05.00,0:00:10.00,Default,Bob,0,0,0,,hello {\i1}again{\i0}
+Comment: 0,0:00:10.00,0:00:15.00,Default,Alice,0,0,0,Scroll up,Note";
+
+#[test]
+fn test_find_all_reports_fields_and_ranges() {
+    let doc = EditorDocument::from_content(CONTENT).unwrap();
+    let matches = DocumentSearcher::new(&doc).find_all("hello").unwrap();
+
+    assert_eq!(matches.len(), 2);
+    assert_eq!(matches[0].event_index, 0);
+    assert_eq!(matches[0].field, EventField::Text);
+    assert_eq!(matches[0].text, "Hello");
+    assert_eq!(&CONTENT[matches[0].byte_range.clone()], "Hello");
+    assert_eq!(matches[1].event_index, 1);
+
+    let names = DocumentSearcher::new(&doc).find_all("^Alice$").unwrap();
+    assert_eq!(names.len(), 2);
+    assert!(names.iter().all(|m| m.field == EventField::Name));
+
+    let effects = DocumentSearcher::new(&doc).find_all("scroll").unwrap();
+    assert_eq!(effects[0].field, EventField::Effect);
+    assert_eq!(effects[0].event_index, 2);
+}
+
+#[test]
+fn test_find_all_respects_options_and_fields() {
+    let doc = EditorDocument::from_content(CONTENT).unwrap();
+    let case_sensitive = SearchOptions {
+        case_sensitive: true,
+        ..Default::default()
+    };
+    let matches = DocumentSearcher::new(&doc)
+        .with_options(case_sensitive)
+        .find_all("hello")
+        .unwrap();
+    assert_eq!(matches.len(), 1);
+    assert_eq!(matches[0].event_index, 1);
+
+    let styles = DocumentSearcher::new(&doc)
+        .with_fields(&[EventField::StyleName])
+        .find_all("default")
+        .unwrap();
+    assert_eq!(styles.len(), 3);
+
+    let limited = SearchOptions {
+        max_results: 1,
+        ..Default::default()
+    };
+    let matches = DocumentSearcher::new(&doc)
+        .with_options(limited)
+        .find_all("l")
+        .unwrap();
+    assert_eq!(matches.len(), 1);
+}
+
+#[test]
+fn test_find_all_multiline_anchor() {
+    let doc = EditorDocument::from_content(CONTENT).unwrap();
+    let multiline = SearchOptions {
+        multiline: true,
+        ..Default::default()
+    };
+    // Fields are single lines, so `^` still anchors at the field start
+    let matches = DocumentSearcher::new(&doc)
+        .with_options(multiline)
+        .with_fields(&[EventField::Text])
+        .find_all("^hello")
+        .unwrap();
+    assert_eq!(matches.len(), 1);
+    assert_eq!(matches[0].event_index, 1);
+}
+
+#[test]
+fn test_invalid_pattern_is_error() {
+    let doc = EditorDocument::from_content(CONTENT).unwrap();
+    assert!(DocumentSearcher::new(&doc).find_all("(unclosed").is_err());
+    assert!(DocumentSearcher::new(&doc).replace_all("[", "x").is_err());
+}
+
+#[test]
+fn test_replace_all_preserves_override_tags() {
+    let mut doc = EditorDocument::from_content(CONTENT).unwrap();
+    let batch = DocumentSearcher::new(&doc)
+        .with_fields(&[EventField::Text])
+        .replace_all(r"h(ello)", "J${1}y")
+        .unwrap();
+    assert_eq!(batch.commands.len(), 2);
+
+    doc.execute_command(&batch).unwrap();
+    let text = doc.text();
+    assert!(text.contains(r",,{\b1}Jelloy{\b0} world"));
+    assert!(text.contains(r",,Jelloy {\i1}again{\i0}"));
+    assert!(text.contains("Default,Alice,"));
+}
+
+#[test]
+fn test_replace_all_whole_words_leaves_other_fields() {
+    let mut doc = EditorDocument::from_content(CONTENT).unwrap();
+    let whole_words = SearchOptions {
+        whole_words: true,
+        case_sensitive: true,
+        ..Default::default()
+    };
+    let batch = DocumentSearcher::new(&doc)
+        .with_options(whole_words)
+        .replace_all("Alice", "Carol")
+        .unwrap();
+    doc.execute_command(&batch).unwrap();
+
+    let text = doc.text();
+    assert_eq!(text.matches("Carol").count(), 2);
+    assert!(!text.contains("Alice"));
+    assert!(text.contains(r"{\b1}Hello{\b0} world"));
+}
@@ -2,10 +2,13 @@
 //!
 //! Provides the `DocumentSearch` trait for efficient text search with
 //! FST-based indexing, regex support, and incremental updates. Targets
-//! <10ms initial indexing and <1ms query response times.
+//! <10ms initial indexing and <1ms query response times. `DocumentSearcher`
+//! adds regex search and replace scoped to event fields.
 
 mod basic;
 mod engine;
+#[cfg(all(feature = "formats", feature = "std"))]
+mod field_search;
 mod incremental;
 mod index;
 mod options;
@@ -14,11 +17,15 @@ mod regex_search;
 mod trait_impl;
 mod traits;
 
+#[cfg(all(test, feature = "formats", feature = "std"))]
+mod field_search_tests;
 #[cfg(test)]
 mod regex_tests;
 #[cfg(test)]
 mod tests;
 
 pub use engine::DocumentSearchImpl;
+#[cfg(all(feature = "formats", feature = "std"))]
+pub use field_search::{DocumentSearcher, EventField, SearchMatch};
 pub use options::{SearchError, SearchOptions, SearchResult, SearchScope, SearchStats};
 pub use traits::{create_search, DocumentSearch};
@@ -29,6 +29,9 @@ pub struct SearchOptions {
     /// Whether to use regular expressions
     pub use_regex: bool,
 
+    /// Whether `^` and `$` match at line boundaries in regex searches
+    pub multiline: bool,
+
     /// Search scope (e.g., specific sections or lines)
     pub scope: SearchScope,
 }
@@ -40,6 +43,7 @@ impl Default for SearchOptions {
             whole_words: false,
             max_results: 100,
             use_regex: false,
+            multiline: false,
             scope: SearchScope::All,
         }
     }
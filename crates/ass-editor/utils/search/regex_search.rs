@@ -17,7 +17,7 @@ use crate::core::{Position, Result};
 use std::borrow::Cow;
 
 #[cfg(all(feature = "formats", feature = "std"))]
-use regex::Regex;
+use regex::RegexBuilder;
 
 #[cfg(all(feature = "formats", feature = "std"))]
 impl DocumentSearchImpl {
@@ -30,15 +30,13 @@ impl DocumentSearchImpl {
         use crate::core::EditorError;
 
         // Compile regex
-        let regex_pattern = if options.case_sensitive {
-            Regex::new(pattern)
-        } else {
-            Regex::new(&format!("(?i){pattern}"))
-        };
-
-        let regex = regex_pattern.map_err(|e| EditorError::CommandFailed {
-            message: format!("Invalid regex pattern: {e}"),
-        })?;
+        let regex = RegexBuilder::new(pattern)
+            .case_insensitive(!options.case_sensitive)
+            .multi_line(options.multiline)
+            .build()
+            .map_err(|e| EditorError::CommandFailed {
+                message: format!("Invalid regex pattern: {e}"),
+            })?;
 
         let mut results = Vec::new();
         let text = &self.cached_text;
@@ -0,0 +1,90 @@
+//! Static interval tree over event time extents
+//!
+//! Intervals are sorted by start time and treated as an implicit balanced
+//! binary tree: the middle element of each slice is the node, and each node
+//! records the latest end time in its subtree so whole subtrees that finish
+//! before a query window can be skipped.
+
+use core::ops::Range;
+
+#[cfg(not(feature = "std"))]
+use alloc::{vec, vec::Vec};
+
+/// Half-open time interval `[start, end)` tagged with an event index
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) struct Interval {
+    /// Start time in centiseconds
+    pub start: u32,
+    /// End time in centiseconds
+    pub end: u32,
+    /// Index of the event in document order
+    pub index: usize,
+}
+
+/// Interval tree supporting overlap queries in `O(log n + k)`
+#[derive(Debug, Clone, Default)]
+pub(super) struct IntervalTree {
+    /// Intervals sorted by start time
+    intervals: Vec<Interval>,
+    /// Latest end time in the subtree rooted at each position
+    max_end: Vec<u32>,
+}
+
+impl IntervalTree {
+    /// Build the tree from unsorted intervals
+    pub fn new(mut intervals: Vec<Interval>) -> Self {
+        intervals.sort_by_key(|interval| (interval.start, interval.index));
+        let mut max_end = vec![0; intervals.len()];
+        build(&intervals, &mut max_end, 0..intervals.len());
+        Self { intervals, max_end }
+    }
+
+    /// Visit every interval overlapping `[start, end)`
+    ///
+    /// A zero-width query window matches intervals containing `start`.
+    pub fn query(&self, start: u32, end: u32, visit: &mut impl FnMut(&Interval)) {
+        self.query_slice(
+            0..self.intervals.len(),
+            start,
+            end.max(start.saturating_add(1)),
+            visit,
+        );
+    }
+
+    /// Recursive overlap query over the subtree spanning `range`
+    fn query_slice(
+        &self,
+        range: Range<usize>,
+        start: u32,
+        end: u32,
+        visit: &mut impl FnMut(&Interval),
+    ) {
+        if range.is_empty() {
+            return;
+        }
+        let mid = range.start + range.len() / 2;
+        if self.max_end[mid] <= start {
+            return;
+        }
+        self.query_slice(range.start..mid, start, end, visit);
+        let node = &self.intervals[mid];
+        if node.start < end {
+            if node.end > start {
+                visit(node);
+            }
+            self.query_slice(mid + 1..range.end, start, end, visit);
+        }
+    }
+}
+
+/// Fill `max_end` for the subtree spanning `range`, returning its maximum
+fn build(intervals: &[Interval], max_end: &mut [u32], range: Range<usize>) -> u32 {
+    if range.is_empty() {
+        return 0;
+    }
+    let mid = range.start + range.len() / 2;
+    let left = build(intervals, max_end, range.start..mid);
+    let right = build(intervals, max_end, mid + 1..range.end);
+    max_end[mid] = intervals[mid].end.max(left).max(right);
+    max_end[mid]
+}
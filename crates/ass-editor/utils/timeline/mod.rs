@@ -0,0 +1,15 @@
+//! Timeline data model for visual subtitle editors
+//!
+//! Represents events as bars with time extents, answers viewport and
+//! click queries through an interval tree, and expresses drag operations
+//! and document-wide timing normalization as undoable commands.
+
+mod interval_tree;
+mod model;
+mod normalize;
+
+#[cfg(test)]
+mod tests;
+
+pub use model::{EventIndex, TimelineEvent, TimelineModel, PREVIEW_CHARS};
+pub use normalize::{GapAdjustment, SnapPolicy, TimingNormalizer};
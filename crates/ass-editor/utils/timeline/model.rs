@@ -0,0 +1,272 @@
+//! Timeline snapshot of a document's events
+//!
+//! `TimelineModel` captures each event's time extent, layer, style and text
+//! preview when built, indexes the extents in an interval tree, and turns
+//! drag operations into `BatchCommand`s that rewrite only the time fields.
+
+use super::interval_tree::{Interval, IntervalTree};
+use crate::commands::{BatchCommand, EditorCommand, ReplaceTextCommand};
+use crate::core::{EditorDocument, EditorError, Position, Range, Result};
+use crate::utils::width::strip_overrides;
+use ass_core::parser::{ast::EventType, Section};
+use ass_core::utils::format_ass_time;
+
+#[cfg(not(feature = "std"))]
+use alloc::{
+    boxed::Box,
+    format,
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+
+/// Index of an event in document order, counting dialogues and comments
+pub type EventIndex = usize;
+
+/// Maximum number of characters in [`TimelineEvent::preview`]
+pub const PREVIEW_CHARS: usize = 30;
+
+/// An event as drawn on the timeline
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TimelineEvent {
+    /// Index of the event in document order
+    pub index: EventIndex,
+    /// Start time in centiseconds
+    pub start_cs: u32,
+    /// End time in centiseconds
+    pub end_cs: u32,
+    /// Drawing layer
+    pub layer: i32,
+    /// Style name
+    pub style: String,
+    /// First characters of the text with override blocks removed
+    pub preview: String,
+    /// Whether the event is a `Comment` line
+    pub comment: bool,
+}
+
+/// Document byte ranges of an event's time fields
+#[derive(Debug, Clone, Copy)]
+struct TimeFields {
+    /// Range of the `Start` field
+    start: Range,
+    /// Range of the `End` field
+    end: Range,
+}
+
+/// Queryable timeline of a document's events
+///
+/// The model is a snapshot: rebuild it after the document changes, as the
+/// commands it produces address the byte ranges seen at construction.
+/// Events whose times cannot be parsed are left off the timeline.
+///
+/// # Examples
+///
+/// ```
+/// use ass_editor::{EditorDocument, utils::timeline::TimelineModel};
+///
+/// let mut doc = EditorDocument::from_content(
+///     "[Events]\nFormat: Layer, Start, End, Style, Name, MarginL, MarginR, MarginV, Effect, Text\nDialogue: 0,0:00:01.00,0:00:03.00,Default,,0,0,0,,Hello",
+/// ).unwrap();
+///
+/// let timeline = TimelineModel::new(&doc).unwrap();
+/// assert_eq!(timeline.event_at_time(150), Some(0));
+///
+/// let command = timeline.move_event(0, 500).unwrap();
+/// doc.execute_command(&command).unwrap();
+/// assert!(doc.text().contains("0:00:05.00,0:00:07.00"));
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct TimelineModel {
+    /// Timeline events in document order
+    events: Vec<TimelineEvent>,
+    /// Time field ranges, parallel to `events`
+    fields: Vec<TimeFields>,
+    /// Interval index over `events`, keyed by position in `events`
+    tree: IntervalTree,
+}
+
+impl TimelineModel {
+    /// Build the timeline for `document`
+    pub fn new(document: &EditorDocument) -> Result<Self> {
+        document.parse_script_with(|script| {
+            let base = script.source().as_ptr() as usize;
+            let field_range = |value: &str| {
+                let start = value.as_ptr() as usize - base;
+                Range::new(Position::new(start), Position::new(start + value.len()))
+            };
+
+            let mut events = Vec::new();
+            let mut fields = Vec::new();
+            let all_events = script
+                .sections()
+                .iter()
+                .filter_map(|section| match section {
+                    Section::Events(events) => Some(events),
+                    _ => None,
+                })
+                .flatten();
+            for (index, event) in all_events.enumerate() {
+                let (Ok(start_cs), Ok(end_cs)) = (event.start_time_cs(), event.end_time_cs())
+                else {
+                    continue;
+                };
+                events.push(TimelineEvent {
+                    index,
+                    start_cs,
+                    end_cs,
+                    layer: event.layer.trim().parse().unwrap_or(0),
+                    style: event.style.to_string(),
+                    preview: strip_overrides(event.text)
+                        .chars()
+                        .take(PREVIEW_CHARS)
+                        .collect(),
+                    comment: event.event_type == EventType::Comment,
+                });
+                fields.push(TimeFields {
+                    start: field_range(event.start),
+                    end: field_range(event.end),
+                });
+            }
+
+            let intervals = events
+                .iter()
+                .enumerate()
+                .map(|(slot, event)| Interval {
+                    start: event.start_cs,
+                    end: event.end_cs,
+                    index: slot,
+                })
+                .collect();
+            Self {
+                events,
+                fields,
+                tree: IntervalTree::new(intervals),
+            }
+        })
+    }
+
+    /// All timeline events in document order
+    pub fn events(&self) -> &[TimelineEvent] {
+        &self.events
+    }
+
+    /// Look up an event by its document index
+    pub fn event(&self, idx: EventIndex) -> Option<&TimelineEvent> {
+        self.slot(idx).map(|slot| &self.events[slot])
+    }
+
+    /// Events visible in the window `[start_cs, end_cs)`, in document order
+    pub fn events_in_range(&self, start_cs: u32, end_cs: u32) -> Vec<TimelineEvent> {
+        let mut slots = Vec::new();
+        self.tree
+            .query(start_cs, end_cs, &mut |interval| slots.push(interval.index));
+        slots.sort_unstable();
+        slots
+            .into_iter()
+            .map(|slot| self.events[slot].clone())
+            .collect()
+    }
+
+    /// The event to select when clicking at `cs`
+    ///
+    /// Among events shown at `cs`, prefers the highest layer and then the
+    /// event latest in the document, matching drawing order.
+    pub fn event_at_time(&self, cs: u32) -> Option<EventIndex> {
+        let mut best: Option<&TimelineEvent> = None;
+        self.tree.query(cs, cs, &mut |interval| {
+            let candidate = &self.events[interval.index];
+            if best.is_none_or(|b| (candidate.layer, candidate.index) > (b.layer, b.index)) {
+                best = Some(candidate);
+            }
+        });
+        best.map(|event| event.index)
+    }
+
+    /// Command moving event `idx` to start at `new_start_cs`, keeping its
+    /// duration
+    pub fn move_event(&self, idx: EventIndex, new_start_cs: u32) -> Result<BatchCommand> {
+        let slot = self.require_slot(idx)?;
+        let event = &self.events[slot];
+        let duration = event.end_cs.saturating_sub(event.start_cs);
+        Ok(self.retime(
+            slot,
+            new_start_cs,
+            new_start_cs.saturating_add(duration),
+            format!("Move event {idx}"),
+        ))
+    }
+
+    /// Command changing the end of event `idx` to `new_end_cs`
+    ///
+    /// The end is clamped so it never precedes the start.
+    pub fn resize_event(&self, idx: EventIndex, new_end_cs: u32) -> Result<BatchCommand> {
+        let slot = self.require_slot(idx)?;
+        let start_cs = self.events[slot].start_cs;
+        Ok(self.retime(
+            slot,
+            start_cs,
+            new_end_cs.max(start_cs),
+            format!("Resize event {idx}"),
+        ))
+    }
+
+    /// Batch rewriting both time fields of the event at `slot`
+    fn retime(&self, slot: usize, start_cs: u32, end_cs: u32, description: String) -> BatchCommand {
+        let fields = self.fields[slot];
+        // End follows start in the line, so edit it first to keep the start
+        // range valid
+        let commands: Vec<Box<dyn EditorCommand>> = vec![
+            Box::new(ReplaceTextCommand::new(fields.end, format_ass_time(end_cs))),
+            Box::new(ReplaceTextCommand::new(
+                fields.start,
+                format_ass_time(start_cs),
+            )),
+        ];
+        BatchCommand::new(description).add_commands(commands)
+    }
+
+    /// Batch rewriting the time fields of several events at once
+    ///
+    /// `times` holds `(slot, start_cs, end_cs)` triples; fields already at
+    /// their new value are left alone.
+    pub(crate) fn retime_many(
+        &self,
+        mut times: Vec<(usize, u32, u32)>,
+        description: String,
+    ) -> BatchCommand {
+        // Edit from the end of the document so earlier ranges stay valid
+        times.sort_unstable_by_key(|&(slot, _, _)| core::cmp::Reverse(slot));
+        let mut commands: Vec<Box<dyn EditorCommand>> = Vec::new();
+        for (slot, start_cs, end_cs) in times {
+            let (event, fields) = (&self.events[slot], self.fields[slot]);
+            if end_cs != event.end_cs {
+                commands.push(Box::new(ReplaceTextCommand::new(
+                    fields.end,
+                    format_ass_time(end_cs),
+                )));
+            }
+            if start_cs != event.start_cs {
+                commands.push(Box::new(ReplaceTextCommand::new(
+                    fields.start,
+                    format_ass_time(start_cs),
+                )));
+            }
+        }
+        BatchCommand::new(description).add_commands(commands)
+    }
+
+    /// Position in `events` of document event `idx`
+    fn slot(&self, idx: EventIndex) -> Option<usize> {
+        self.events
+            .binary_search_by_key(&idx, |event| event.index)
+            .ok()
+    }
+
+    /// Like [`Self::slot`], but failing for events not on the timeline
+    fn require_slot(&self, idx: EventIndex) -> Result<usize> {
+        self.slot(idx).ok_or_else(|| {
+            EditorError::command_failed(format!("Event {idx} is not on the timeline"))
+        })
+    }
+}
@@ -0,0 +1,200 @@
+//! Timing normalization across a whole document
+//!
+//! `TimingNormalizer` snaps event times to video frame boundaries and
+//! enforces a minimum gap between consecutive dialogue lines. Both produce
+//! a single `BatchCommand`, so the normalization is one undo step.
+
+use super::model::TimelineModel;
+use crate::commands::BatchCommand;
+use crate::core::{EditorDocument, EditorError, Result};
+
+#[cfg(not(feature = "std"))]
+use alloc::{format, vec::Vec};
+
+/// Which event times [`TimingNormalizer::snap_to_frames`] moves, and how
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SnapPolicy {
+    /// Move start times to the nearest frame boundary
+    SnapStart,
+    /// Move end times to the nearest frame boundary
+    SnapEnd,
+    /// Move start times back and end times forward to a frame boundary, so
+    /// events cover every frame they overlapped
+    SnapBoth,
+    /// Move both times to the nearest frame boundary
+    #[default]
+    SnapNearest,
+}
+
+/// Which event [`TimingNormalizer::ensure_minimum_gap`] shortens
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum GapAdjustment {
+    /// End the earlier event sooner
+    #[default]
+    TrimEarlier,
+    /// Start the later event later
+    DelayLater,
+}
+
+/// Document-wide timing fixes expressed as undoable batches
+///
+/// The returned batches address the document as it was when they were
+/// built; execute them through [`EditorDocument::execute_command`] before
+/// making other changes. Events whose times cannot be parsed are skipped.
+///
+/// # Examples
+///
+/// ```
+/// use ass_editor::{utils::timeline::{SnapPolicy, TimingNormalizer}, EditorDocument};
+///
+/// let mut doc = EditorDocument::from_content(
+///     "[Events]\nFormat: Layer, Start, End, Style, Name, MarginL, MarginR, MarginV, Effect, Text\nDialogue: 0,0:00:01.03,0:00:02.98,Default,,0,0,0,,Hello",
+/// ).unwrap();
+///
+/// let batch = TimingNormalizer::snap_to_frames(&doc, 25.0, SnapPolicy::SnapNearest).unwrap();
+/// doc.execute_command(&batch).unwrap();
+/// assert!(doc.text().contains("0:00:01.04,0:00:03.00"));
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct TimingNormalizer;
+
+impl TimingNormalizer {
+    /// Batch moving event times of `document` onto frame boundaries at `fps`
+    ///
+    /// Frame `n` starts at `n * 100 / fps` centiseconds, rounded to the
+    /// nearest centisecond. An event with a non-zero duration keeps at least
+    /// one frame.
+    pub fn snap_to_frames(
+        document: &EditorDocument,
+        fps: f64,
+        policy: SnapPolicy,
+    ) -> Result<BatchCommand> {
+        if !fps.is_finite() || fps <= 0.0 {
+            return Err(EditorError::command_failed(format!(
+                "Invalid frame rate {fps}"
+            )));
+        }
+        let frames = FrameGrid { fps };
+        let timeline = TimelineModel::new(document)?;
+        let times = timeline
+            .events()
+            .iter()
+            .enumerate()
+            .map(|(slot, event)| {
+                let (start, end) = (event.start_cs, event.end_cs);
+                let (mut new_start, mut new_end) = match policy {
+                    SnapPolicy::SnapStart => (frames.nearest(start), end),
+                    SnapPolicy::SnapEnd => (start, frames.nearest(end)),
+                    SnapPolicy::SnapBoth => (frames.at_or_before(start), frames.at_or_after(end)),
+                    SnapPolicy::SnapNearest => (frames.nearest(start), frames.nearest(end)),
+                };
+                if end > start && new_end <= new_start {
+                    if policy == SnapPolicy::SnapStart {
+                        new_start = frames.at_or_before(start);
+                    } else {
+                        new_end = frames.after(new_start);
+                    }
+                }
+                (slot, new_start, new_end.max(new_start))
+            })
+            .collect();
+
+        Ok(timeline.retime_many(times, format!("Snap timing to {fps} fps")))
+    }
+
+    /// Batch opening gaps of at least `min_gap_cs` between consecutive
+    /// dialogue events of `document`
+    ///
+    /// Events are taken in start time order. Only pairs closer than
+    /// `min_gap_cs` without overlapping are changed; overlaps are treated
+    /// as intentional, and pairs where the gap would leave an event with no
+    /// duration are left alone.
+    pub fn ensure_minimum_gap(
+        document: &EditorDocument,
+        min_gap_cs: u32,
+        adjustment: GapAdjustment,
+    ) -> Result<BatchCommand> {
+        let timeline = TimelineModel::new(document)?;
+        let mut times: Vec<(usize, u32, u32)> = timeline
+            .events()
+            .iter()
+            .enumerate()
+            .filter(|(_, event)| !event.comment)
+            .map(|(slot, event)| (slot, event.start_cs, event.end_cs))
+            .collect();
+        times.sort_by_key(|&(slot, start, _)| (start, slot));
+
+        for next in 1..times.len() {
+            let (_, prev_start, prev_end) = times[next - 1];
+            let (_, next_start, next_end) = times[next];
+            if next_start < prev_end || next_start - prev_end >= min_gap_cs {
+                continue;
+            }
+            match adjustment {
+                GapAdjustment::TrimEarlier => {
+                    let end = next_start.saturating_sub(min_gap_cs);
+                    if end > prev_start {
+                        times[next - 1].2 = end;
+                    }
+                }
+                GapAdjustment::DelayLater => {
+                    let start = prev_end.saturating_add(min_gap_cs);
+                    if start < next_end {
+                        times[next].1 = start;
+                    }
+                }
+            }
+        }
+
+        Ok(timeline.retime_many(times, format!("Ensure {min_gap_cs} cs gaps")))
+    }
+}
+
+/// Frame boundaries at a frame rate, in whole centiseconds
+#[derive(Debug, Clone, Copy)]
+struct FrameGrid {
+    /// Frames per second
+    fps: f64,
+}
+
+impl FrameGrid {
+    /// Start of frame `frame` in centiseconds
+    fn boundary(self, frame: u64) -> u32 {
+        (frame as f64 * 100.0 / self.fps)
+            .round()
+            .min(f64::from(u32::MAX)) as u32
+    }
+
+    /// Frame whose start is nearest `cs`
+    fn frame_near(self, cs: u32) -> u64 {
+        (f64::from(cs) * self.fps / 100.0).round() as u64
+    }
+
+    /// Boundary nearest `cs`
+    fn nearest(self, cs: u32) -> u32 {
+        self.boundary(self.frame_near(cs))
+    }
+
+    /// Latest boundary not after `cs`
+    fn at_or_before(self, cs: u32) -> u32 {
+        let mut frame = self.frame_near(cs);
+        while frame > 0 && self.boundary(frame) > cs {
+            frame -= 1;
+        }
+        self.boundary(frame)
+    }
+
+    /// Earliest boundary not before `cs`
+    fn at_or_after(self, cs: u32) -> u32 {
+        let mut frame = self.frame_near(cs);
+        while self.boundary(frame) < cs {
+            frame += 1;
+        }
+        self.boundary(frame)
+    }
+
+    /// Boundary of the frame after the one starting at `cs`
+    fn after(self, cs: u32) -> u32 {
+        self.boundary(self.frame_near(cs) + 1)
+    }
+}
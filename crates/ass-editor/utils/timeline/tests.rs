@@ -0,0 +1,228 @@
+//! Tests for the timeline model and its interval index
+
+use super::*;
+use crate::core::EditorDocument;
+
+#[cfg(not(feature = "std"))]
+use alloc::{format, string::String, vec::Vec};
+
+const HEADER: &str = "[Script Info]
+Title: Timeline
+
+[Events]
+Format: Layer, Start, End, Style, Name, MarginL, MarginR, MarginV, Effect, Text
+";
+
+fn document(events: &str) -> EditorDocument {
+    EditorDocument::from_content(&format!("{HEADER}{events}")).unwrap()
+}
+
+fn indices(events: &[TimelineEvent]) -> Vec<EventIndex> {
+    events.iter().map(|event| event.index).collect()
+}
+
+const EVENTS: &str = r"Dialogue: 0,0:00:01.00,0:00:04.00,Default,,0,0,0,,{\b1}First{\b0} line\Nwith a break that is rather long
+Dialogue: 1,0:00:02.00,0:00:03.00,Sign,,0,0,0,,Sign
+Comment: 0,0:00:05.00,0:00:06.00,Default,,0,0,0,,Note
+Dialogue: 0,bad,0:00:07.00,Default,,0,0,0,,Broken
+Dialogue: 0,0:00:06.00,0:00:08.00,Default,,0,0,0,,Last
+";
+
+#[test]
+fn test_timeline_event_fields() {
+    let timeline = TimelineModel::new(&document(EVENTS)).unwrap();
+
+    assert_eq!(indices(timeline.events()), [0, 1, 2, 4]);
+    let first = timeline.event(0).unwrap();
+    assert_eq!((first.start_cs, first.end_cs), (100, 400));
+    assert_eq!(first.style, "Default");
+    assert_eq!(first.preview, "First line with a break that i");
+    assert_eq!(first.preview.chars().count(), PREVIEW_CHARS);
+    assert_eq!(timeline.event(1).unwrap().layer, 1);
+    assert!(timeline.event(3).is_none());
+}
+
+#[test]
+fn test_events_in_range() {
+    let timeline = TimelineModel::new(&document(EVENTS)).unwrap();
+
+    assert_eq!(
+        indices(&timeline.events_in_range(0, 100)),
+        Vec::<usize>::new()
+    );
+    assert_eq!(indices(&timeline.events_in_range(0, 101)), [0]);
+    assert_eq!(indices(&timeline.events_in_range(250, 550)), [0, 1, 2]);
+    assert_eq!(
+        indices(&timeline.events_in_range(400, 500)),
+        Vec::<usize>::new()
+    );
+    assert_eq!(
+        indices(&timeline.events_in_range(0, u32::MAX)),
+        [0, 1, 2, 4]
+    );
+}
+
+#[test]
+fn test_event_at_time_prefers_top_layer() {
+    let timeline = TimelineModel::new(&document(EVENTS)).unwrap();
+
+    assert_eq!(timeline.event_at_time(150), Some(0));
+    assert_eq!(timeline.event_at_time(250), Some(1));
+    assert_eq!(timeline.event_at_time(300), Some(0));
+    assert_eq!(timeline.event_at_time(450), None);
+    assert_eq!(timeline.event_at_time(600), Some(4));
+}
+
+#[test]
+fn test_move_and_resize_commands() {
+    let mut doc = document(EVENTS);
+    let timeline = TimelineModel::new(&doc).unwrap();
+
+    let command = timeline.move_event(1, 1000).unwrap();
+    doc.execute_command(&command).unwrap();
+    assert!(doc
+        .text()
+        .contains("Dialogue: 1,0:00:10.00,0:00:11.00,Sign,,0,0,0,,Sign"));
+
+    let timeline = TimelineModel::new(&doc).unwrap();
+    let command = timeline.resize_event(4, 50).unwrap();
+    doc.execute_command(&command).unwrap();
+    assert!(doc
+        .text()
+        .contains("0:00:06.00,0:00:06.00,Default,,0,0,0,,Last"));
+
+    assert!(timeline.move_event(3, 0).is_err());
+    assert!(timeline.resize_event(99, 0).is_err());
+}
+
+#[test]
+fn test_large_timeline_queries() {
+    let mut events = String::new();
+    for i in 0..10_000u32 {
+        let start = i * 50;
+        events.push_str(&format!(
+            "Dialogue: {},{},{},Default,,0,0,0,,Line {i}\n",
+            i % 3,
+            ass_core::utils::format_ass_time(start),
+            ass_core::utils::format_ass_time(start + 200),
+        ));
+    }
+    let doc = document(&events);
+
+    #[cfg(feature = "std")]
+    let started = std::time::Instant::now();
+    let timeline = TimelineModel::new(&doc).unwrap();
+    assert_eq!(timeline.events().len(), 10_000);
+
+    for window in 0..1_000u32 {
+        let start = window * 500;
+        let visible = timeline.events_in_range(start, start + 500);
+        // Windows overlap the 10 events starting inside plus up to 4 before
+        assert!((10..=14).contains(&visible.len()));
+        assert!(timeline.event_at_time(start + 25).is_some());
+    }
+    #[cfg(feature = "std")]
+    assert!(started.elapsed() < std::time::Duration::from_secs(5));
+}
+
+const UNSNAPPED: &str = r"Dialogue: 0,0:00:01.03,0:00:02.98,Default,,0,0,0,,One
+Dialogue: 0,0:00:03.11,0:00:03.12,Default,,0,0,0,,Blink
+Comment: 0,0:00:10.07,0:00:12.51,Default,,0,0,0,,Note
+Dialogue: 0,0:01:23.45,0:01:25.67,Default,,0,0,0,,Later
+";
+
+/// Assert `cs` is the whole centisecond nearest a frame boundary at `fps`
+fn assert_on_frame(cs: u32, fps: f64) {
+    let frame = (f64::from(cs) * fps / 100.0).round();
+    let remainder = (f64::from(cs) - frame * 100.0 / fps).abs();
+    assert!(
+        remainder <= 0.5,
+        "{cs} cs is {remainder} cs off a frame at {fps} fps"
+    );
+}
+
+#[test]
+fn test_snap_to_frames_at_common_rates() {
+    for fps in [23.976, 25.0, 29.97] {
+        for policy in [SnapPolicy::SnapNearest, SnapPolicy::SnapBoth] {
+            let mut doc = document(UNSNAPPED);
+            let original = TimelineModel::new(&doc).unwrap();
+            let batch = TimingNormalizer::snap_to_frames(&doc, fps, policy).unwrap();
+            doc.execute_command(&batch).unwrap();
+
+            let snapped = TimelineModel::new(&doc).unwrap();
+            for (before, after) in original.events().iter().zip(snapped.events()) {
+                assert_on_frame(after.start_cs, fps);
+                assert_on_frame(after.end_cs, fps);
+                assert!(after.end_cs > after.start_cs);
+                if policy == SnapPolicy::SnapBoth {
+                    assert!(after.start_cs <= before.start_cs);
+                    assert!(after.end_cs >= before.end_cs);
+                }
+            }
+
+            // Snapped times are fixed points
+            let again = TimingNormalizer::snap_to_frames(&doc, fps, policy).unwrap();
+            assert!(again.commands.is_empty());
+
+            doc.undo().unwrap();
+            assert_eq!(doc.text(), format!("{HEADER}{UNSNAPPED}"));
+        }
+    }
+}
+
+#[test]
+fn test_snap_to_frames_single_field() {
+    let mut doc = document(UNSNAPPED);
+    let batch = TimingNormalizer::snap_to_frames(&doc, 25.0, SnapPolicy::SnapStart).unwrap();
+    doc.execute_command(&batch).unwrap();
+    let events = TimelineModel::new(&doc).unwrap();
+    let first = &events.events()[0];
+    assert_eq!((first.start_cs, first.end_cs), (104, 298));
+
+    let mut doc = document(UNSNAPPED);
+    let batch = TimingNormalizer::snap_to_frames(&doc, 25.0, SnapPolicy::SnapEnd).unwrap();
+    doc.execute_command(&batch).unwrap();
+    let events = TimelineModel::new(&doc).unwrap();
+    let first = &events.events()[0];
+    assert_eq!((first.start_cs, first.end_cs), (103, 300));
+
+    assert!(TimingNormalizer::snap_to_frames(&doc, 0.0, SnapPolicy::SnapEnd).is_err());
+}
+
+const CRAMPED: &str = r"Dialogue: 0,0:00:01.00,0:00:02.00,Default,,0,0,0,,One
+Dialogue: 0,0:00:02.05,0:00:03.00,Default,,0,0,0,,Close
+Comment: 0,0:00:03.00,0:00:03.50,Default,,0,0,0,,Ignored
+Dialogue: 0,0:00:03.02,0:00:04.00,Default,,0,0,0,,Closer
+Dialogue: 0,0:00:03.50,0:00:05.00,Default,,0,0,0,,Overlap
+";
+
+/// Start and end times of every event in `doc`
+fn spans(doc: &EditorDocument) -> Vec<(u32, u32)> {
+    let timeline = TimelineModel::new(doc).unwrap();
+    timeline
+        .events()
+        .iter()
+        .map(|event| (event.start_cs, event.end_cs))
+        .collect()
+}
+
+#[test]
+fn test_ensure_minimum_gap() {
+    let mut doc = document(CRAMPED);
+    let batch = TimingNormalizer::ensure_minimum_gap(&doc, 10, GapAdjustment::TrimEarlier).unwrap();
+    doc.execute_command(&batch).unwrap();
+    assert_eq!(
+        spans(&doc),
+        [(100, 195), (205, 292), (300, 350), (302, 400), (350, 500)]
+    );
+    doc.undo().unwrap();
+    assert_eq!(doc.text(), format!("{HEADER}{CRAMPED}"));
+
+    let batch = TimingNormalizer::ensure_minimum_gap(&doc, 10, GapAdjustment::DelayLater).unwrap();
+    doc.execute_command(&batch).unwrap();
+    assert_eq!(
+        spans(&doc),
+        [(100, 200), (210, 300), (300, 350), (310, 400), (350, 500)]
+    );
+}
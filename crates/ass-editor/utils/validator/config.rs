@@ -3,7 +3,10 @@
 //! Defines `ValidatorConfig`, controlling validation behavior such as
 //! enabled rule sets, severity thresholds, and caching intervals.
 
-use super::ValidationSeverity;
+use super::{CpsCountMode, ValidationSeverity};
+
+#[cfg(not(feature = "std"))]
+use alloc::{string::String, vec::Vec};
 
 /// Configuration for the lazy validator
 #[derive(Debug, Clone)]
@@ -26,6 +29,29 @@ pub struct ValidatorConfig {
     pub enable_accessibility_checks: bool,
     pub enable_spec_compliance: bool,
     pub enable_unicode_checks: bool,
+
+    /// Installed font families; when set, fonts referenced by styles or
+    /// `\fn` overrides but absent from this list are reported
+    pub available_fonts: Option<Vec<String>>,
+
+    /// Check dialogue reading speed with `CpsValidator`
+    pub enable_cps_checks: bool,
+
+    /// Maximum reading speed in characters per second
+    pub max_cps: f64,
+
+    /// Minimum display duration of a dialogue event in centiseconds
+    pub min_duration_cs: u32,
+
+    /// How event text is counted for reading speed
+    pub count_mode: CpsCountMode,
+
+    /// Report dialogue events repeating an earlier event's text
+    pub enable_duplicate_checks: bool,
+
+    /// When set, only report duplicates shown within this many centiseconds
+    /// of each other
+    pub duplicate_timing_tolerance_cs: Option<u32>,
 }
 
 impl Default for ValidatorConfig {
@@ -40,6 +66,13 @@ impl Default for ValidatorConfig {
             enable_accessibility_checks: true,
             enable_spec_compliance: true,
             enable_unicode_checks: true,
+            available_fonts: None,
+            enable_cps_checks: false,
+            max_cps: 20.0,
+            min_duration_cs: 50,
+            count_mode: CpsCountMode::Characters,
+            enable_duplicate_checks: false,
+            duplicate_timing_tolerance_cs: None,
         }
     }
 }
@@ -3,14 +3,16 @@
 //! Implements the analysis-backed and fallback validation paths for
 //! `LazyValidator`, plus structural sanity checks applied to all documents.
 
-use super::{LazyValidator, ValidationIssue, ValidationSeverity};
+use super::{CpsValidator, DuplicateDetector, LazyValidator, ValidationIssue, ValidationSeverity};
 use crate::core::{EditorDocument, Result};
 
 #[cfg(feature = "analysis")]
 use ass_core::analysis::linting::IssueSeverity;
 #[cfg(feature = "analysis")]
-use ass_core::analysis::ScriptAnalysis;
+use ass_core::analysis::{FontUsageReport, ScriptAnalysis};
 
+#[cfg(all(not(feature = "std"), feature = "analysis"))]
+use alloc::{format, string::String};
 #[cfg(not(feature = "std"))]
 use alloc::{string::ToString, vec::Vec};
 
@@ -66,10 +68,26 @@ impl LazyValidator {
                     ));
                 }
             }
+
+            if let Some(available) = &self.config.available_fonts {
+                let available: Vec<&str> = available.iter().map(String::as_str).collect();
+                for font in FontUsageReport::compute(script).missing_fonts(&available) {
+                    issues.push(
+                        ValidationIssue::new(
+                            ValidationSeverity::Warning,
+                            format!("Font '{font}' is not available"),
+                            "missing-font".to_string(),
+                        )
+                        .with_suggestion(format!("Install or embed '{font}'")),
+                    );
+                }
+            }
         })?;
 
         // Add basic structural checks even with analysis feature
         self.add_basic_checks(content, &mut issues);
+        self.add_cps_checks(document, &mut issues)?;
+        self.add_duplicate_checks(document, &mut issues)?;
 
         // Apply severity threshold filter
         issues.retain(|issue| issue.severity >= self.config.severity_threshold);
@@ -87,7 +105,7 @@ impl LazyValidator {
     pub(super) fn validate_with_core(
         &self,
         content: &str,
-        _document: &EditorDocument,
+        document: &EditorDocument,
     ) -> Result<Vec<ValidationIssue>> {
         let mut issues = Vec::new();
 
@@ -95,6 +113,8 @@ impl LazyValidator {
         // Note: We can't do full parsing validation without the analysis feature,
         // so we do basic structural checks only
         self.add_basic_checks(content, &mut issues);
+        self.add_cps_checks(document, &mut issues)?;
+        self.add_duplicate_checks(document, &mut issues)?;
 
         // Apply severity threshold filter
         issues.retain(|issue| issue.severity >= self.config.severity_threshold);
@@ -107,6 +127,32 @@ impl LazyValidator {
         Ok(issues)
     }
 
+    /// Add reading-speed checks when enabled in the config
+    fn add_cps_checks(
+        &self,
+        document: &EditorDocument,
+        issues: &mut Vec<ValidationIssue>,
+    ) -> Result<()> {
+        if self.config.enable_cps_checks {
+            issues.extend(CpsValidator::new(&self.config).validate(document)?);
+        }
+        Ok(())
+    }
+
+    /// Add duplicate-text checks when enabled in the config
+    fn add_duplicate_checks(
+        &self,
+        document: &EditorDocument,
+        issues: &mut Vec<ValidationIssue>,
+    ) -> Result<()> {
+        if self.config.enable_duplicate_checks {
+            let detector = DuplicateDetector::new()
+                .with_timing_tolerance(self.config.duplicate_timing_tolerance_cs);
+            issues.extend(detector.validate(document)?);
+        }
+        Ok(())
+    }
+
     /// Add basic structural checks that work regardless of analysis feature
     fn add_basic_checks(&self, content: &str, issues: &mut Vec<ValidationIssue>) {
         // Basic checks
@@ -0,0 +1,126 @@
+//! Reading-speed (characters per second) checks for dialogue events.
+//!
+//! Implements `CpsValidator`, which flags events whose text is displayed
+//! too briefly to be read comfortably. Text is measured after stripping
+//! override blocks and line breaks, using the configured `CpsCountMode`.
+
+use super::{ValidationIssue, ValidationSeverity, ValidatorConfig};
+use crate::core::{EditorDocument, Result};
+use crate::utils::width::{counted_width, strip_overrides};
+use ass_core::parser::{Event, Section};
+
+#[cfg(not(feature = "std"))]
+use alloc::{format, string::ToString, vec::Vec};
+
+/// How event text length is measured for reading speed
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CpsCountMode {
+    /// UTF-8 byte length of the visible text
+    Bytes,
+    /// Display width of visible non-whitespace characters, so fullwidth CJK
+    /// characters count double
+    #[default]
+    Characters,
+    /// Whitespace-separated words
+    Words,
+}
+
+/// Validator enforcing a maximum reading speed on dialogue events
+///
+/// Events shorter than the minimum duration are reported as too fast to
+/// read regardless of their length; all other events are reported when
+/// their reading speed exceeds the configured maximum.
+#[derive(Debug, Clone, Copy)]
+pub struct CpsValidator {
+    /// Maximum allowed reading speed
+    max_cps: f64,
+    /// Minimum display duration in centiseconds
+    min_duration_cs: u32,
+    /// How text length is measured
+    count_mode: CpsCountMode,
+}
+
+impl CpsValidator {
+    /// Create a validator from the reading-speed settings of `config`
+    pub fn new(config: &ValidatorConfig) -> Self {
+        Self {
+            max_cps: config.max_cps,
+            min_duration_cs: config.min_duration_cs,
+            count_mode: config.count_mode,
+        }
+    }
+
+    /// Measure the visible length of event `text` under the configured mode
+    pub fn text_length(&self, text: &str) -> usize {
+        let visible = strip_overrides(text);
+        match self.count_mode {
+            CpsCountMode::Bytes => visible.len(),
+            CpsCountMode::Characters => counted_width(&visible),
+            CpsCountMode::Words => visible.split_whitespace().count(),
+        }
+    }
+
+    /// Check every dialogue event in `document`
+    pub fn validate(&self, document: &EditorDocument) -> Result<Vec<ValidationIssue>> {
+        document.parse_script_with(|script| {
+            let events = script
+                .sections()
+                .iter()
+                .filter_map(|section| match section {
+                    Section::Events(events) => Some(events),
+                    _ => None,
+                })
+                .flatten();
+            events
+                .enumerate()
+                .filter_map(|(index, event)| self.check_event(index, event))
+                .collect()
+        })
+    }
+
+    /// Check a single event, returning at most one issue
+    fn check_event(&self, index: usize, event: &Event) -> Option<ValidationIssue> {
+        if !event.is_dialogue() {
+            return None;
+        }
+        let length = self.text_length(event.text);
+        if length == 0 {
+            return None;
+        }
+        let duration_cs = event.duration_cs().ok()?;
+
+        let issue = if duration_cs < self.min_duration_cs {
+            ValidationIssue::new(
+                ValidationSeverity::Warning,
+                format!(
+                    "Event {index} is shown for {duration_cs}cs, shorter than the {}cs minimum",
+                    self.min_duration_cs
+                ),
+                "min-duration".to_string(),
+            )
+            .with_suggestion(format!(
+                "Extend the event to at least {}cs",
+                self.min_duration_cs
+            ))
+        } else {
+            let cps = length as f64 * 100.0 / f64::from(duration_cs);
+            if cps <= self.max_cps {
+                return None;
+            }
+            let needed_cs = (length as f64 * 100.0 / self.max_cps).ceil();
+            ValidationIssue::new(
+                ValidationSeverity::Warning,
+                format!(
+                    "Event {index} reads at {cps:.1} CPS, above the {:.1} CPS limit",
+                    self.max_cps
+                ),
+                "reading-speed".to_string(),
+            )
+            .with_suggestion(format!(
+                "Extend the event to at least {needed_cs}cs or shorten the text"
+            ))
+        };
+
+        Some(issue.at_location(event.span.line as usize, event.span.column as usize))
+    }
+}
@@ -0,0 +1,382 @@
+//! Duplicate subtitle event detection.
+//!
+//! Implements `DuplicateDetector`, which groups dialogue events sharing the
+//! same text, optionally restricted to events shown at the same time, and
+//! groups near-duplicates whose texts differ by a few Levenshtein edits or
+//! scores pairs by Jaro-Winkler similarity.
+
+use super::segment_index::candidate_pairs;
+use super::{ValidationIssue, ValidationSeverity};
+use crate::core::{EditorDocument, Result};
+use crate::utils::EventIndex;
+use ass_core::parser::{Event, Section};
+
+#[cfg(feature = "std")]
+use std::collections::HashMap;
+
+#[cfg(not(feature = "std"))]
+use alloc::collections::BTreeMap as HashMap;
+
+#[cfg(not(feature = "std"))]
+use alloc::{
+    borrow::Cow,
+    format,
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+#[cfg(feature = "std")]
+use std::borrow::Cow;
+
+/// Events whose texts were found to be duplicates of each other
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DuplicateGroup {
+    /// Indices of the grouped events within the input slice, ascending
+    pub indices: Vec<usize>,
+}
+
+/// Detector for repeated dialogue lines
+///
+/// Only dialogue events with non-blank text are considered. Texts are
+/// compared after trimming surrounding whitespace and, unless the detector
+/// is case-sensitive, after lowercasing. Override blocks can optionally be
+/// stripped first so differently styled copies of a line match.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct DuplicateDetector {
+    /// Compare texts case-sensitively
+    case_sensitive: bool,
+    /// Remove `{...}` override blocks before comparing
+    strip_overrides: bool,
+    /// Also require start and end times within this many centiseconds
+    timing_tolerance_cs: Option<u32>,
+}
+
+impl DuplicateDetector {
+    /// Create a case-insensitive detector that ignores timing
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set whether texts are compared case-sensitively
+    #[must_use]
+    pub const fn with_case_sensitive(mut self, case_sensitive: bool) -> Self {
+        self.case_sensitive = case_sensitive;
+        self
+    }
+
+    /// Set whether `{...}` override blocks are removed before comparing
+    #[must_use]
+    pub const fn with_strip_overrides(mut self, strip_overrides: bool) -> Self {
+        self.strip_overrides = strip_overrides;
+        self
+    }
+
+    /// Only group exact duplicates whose start and end times both lie
+    /// within `tolerance_cs` centiseconds of the group's earliest event
+    #[must_use]
+    pub const fn with_timing_tolerance(mut self, tolerance_cs: Option<u32>) -> Self {
+        self.timing_tolerance_cs = tolerance_cs;
+        self
+    }
+
+    /// Group events with identical normalized text
+    ///
+    /// Texts are bucketed by hash, then each bucket is sorted by start time
+    /// and split into timing windows when a tolerance is set.
+    pub fn find_duplicates(&self, events: &[Event]) -> Vec<DuplicateGroup> {
+        let mut buckets: HashMap<Cow<'_, str>, Vec<usize>> = HashMap::new();
+        for (index, text) in self.candidates(events) {
+            buckets.entry(text).or_default().push(index);
+        }
+
+        let mut groups = Vec::new();
+        for indices in buckets.into_values().filter(|indices| indices.len() > 1) {
+            match self.timing_tolerance_cs {
+                Some(tolerance) => groups.extend(split_by_timing(events, indices, tolerance)),
+                None => groups.push(DuplicateGroup { indices }),
+            }
+        }
+        groups.sort_by_key(|group| group.indices[0]);
+        groups
+    }
+
+    /// Group events whose texts differ by at most `threshold` edits
+    ///
+    /// Texts are bucketed by segments that any text within `threshold` edits
+    /// must share, and only texts meeting in a bucket are compared, so
+    /// unrelated lines are rarely compared at all. Groups are transitive: two events may share a group
+    /// through a third event close to both.
+    pub fn find_near_duplicates(&self, events: &[Event], threshold: usize) -> Vec<DuplicateGroup> {
+        let mut texts: Vec<(usize, Vec<char>)> = self
+            .candidates(events)
+            .map(|(index, text)| (index, text.chars().collect()))
+            .collect();
+        texts.sort_by_key(|(_, chars)| chars.len());
+
+        let (indices, texts): (Vec<usize>, Vec<Vec<char>>) = texts.into_iter().unzip();
+        let mut parent: Vec<usize> = (0..texts.len()).collect();
+        for (i, j) in candidate_pairs(&texts, threshold) {
+            if within_edits(&texts[i], &texts[j], threshold) {
+                let (a, b) = (find_root(&mut parent, i), find_root(&mut parent, j));
+                parent[a.max(b)] = a.min(b);
+            }
+        }
+
+        let mut grouped: HashMap<usize, Vec<usize>> = HashMap::new();
+        for (slot, index) in indices.into_iter().enumerate() {
+            let root = find_root(&mut parent, slot);
+            grouped.entry(root).or_default().push(index);
+        }
+        let mut groups: Vec<DuplicateGroup> = grouped
+            .into_values()
+            .filter(|indices| indices.len() > 1)
+            .map(|mut indices| {
+                indices.sort_unstable();
+                DuplicateGroup { indices }
+            })
+            .collect();
+        groups.sort_by_key(|group| group.indices[0]);
+        groups
+    }
+
+    /// Score every pair of events whose texts have a Jaro-Winkler
+    /// similarity of at least `threshold`
+    ///
+    /// Returns `(first, second, score)` with `first < second`, ordered by
+    /// index. Every pair of candidates is compared, so this is quadratic in
+    /// the number of dialogue events.
+    pub fn near_duplicate_scan(
+        &self,
+        events: &[Event],
+        threshold: f64,
+    ) -> Vec<(EventIndex, EventIndex, f64)> {
+        let texts: Vec<(usize, Vec<char>)> = self
+            .candidates(events)
+            .map(|(index, text)| (index, text.chars().collect()))
+            .collect();
+
+        let mut pairs = Vec::new();
+        for (i, (first, a)) in texts.iter().enumerate() {
+            for (second, b) in &texts[i + 1..] {
+                let score = jaro_winkler(a, b);
+                if score >= threshold {
+                    pairs.push((*first, *second, score));
+                }
+            }
+        }
+        pairs
+    }
+
+    /// Report every event that repeats an earlier event's text
+    pub fn validate(&self, document: &EditorDocument) -> Result<Vec<ValidationIssue>> {
+        document.parse_script_with(|script| {
+            let events: Vec<Event> = script
+                .sections()
+                .iter()
+                .filter_map(|section| match section {
+                    Section::Events(events) => Some(events.iter().cloned()),
+                    _ => None,
+                })
+                .flatten()
+                .collect();
+
+            let mut issues = Vec::new();
+            for group in self.find_duplicates(&events) {
+                let first = group.indices[0];
+                for &index in &group.indices[1..] {
+                    let span = &events[index].span;
+                    issues.push(
+                        ValidationIssue::new(
+                            ValidationSeverity::Warning,
+                            format!("Event {index} duplicates the text of event {first}"),
+                            "duplicate-text".to_string(),
+                        )
+                        .at_location(span.line as usize, span.column as usize)
+                        .with_suggestion(
+                            "Remove the repeated line or merge the events".to_string(),
+                        ),
+                    );
+                }
+            }
+            issues
+        })
+    }
+
+    /// Dialogue events with their normalized, non-blank text
+    fn candidates<'e>(
+        &self,
+        events: &'e [Event],
+    ) -> impl Iterator<Item = (usize, Cow<'e, str>)> + 'e {
+        let detector = *self;
+        events
+            .iter()
+            .enumerate()
+            .filter(|(_, event)| event.is_dialogue())
+            .filter_map(move |(index, event)| Some((index, detector.normalize(event.text)?)))
+    }
+
+    /// Normalize `text` for comparison, or `None` when nothing is left
+    fn normalize<'e>(&self, text: &'e str) -> Option<Cow<'e, str>> {
+        let text = if self.strip_overrides {
+            strip_overrides(text)
+        } else {
+            Cow::Borrowed(text)
+        };
+        let text = match text {
+            Cow::Borrowed(text) => Cow::Borrowed(text.trim()),
+            Cow::Owned(text) => Cow::Owned(text.trim().to_string()),
+        };
+
+        if text.is_empty() {
+            None
+        } else if self.case_sensitive {
+            Some(text)
+        } else {
+            Some(Cow::Owned(text.to_lowercase()))
+        }
+    }
+}
+
+/// Remove `{...}` override blocks, keeping an unclosed `{` as text
+fn strip_overrides(text: &str) -> Cow<'_, str> {
+    if !text.contains('{') {
+        return Cow::Borrowed(text);
+    }
+
+    let mut plain = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(open) = rest.find('{') {
+        let Some(close) = rest[open..].find('}') else {
+            break;
+        };
+        plain.push_str(&rest[..open]);
+        rest = &rest[open + close + 1..];
+    }
+    plain.push_str(rest);
+    Cow::Owned(plain)
+}
+
+/// Split same-text events into groups shown within `tolerance` of each other
+fn split_by_timing(events: &[Event], indices: Vec<usize>, tolerance: u32) -> Vec<DuplicateGroup> {
+    let mut timed: Vec<(u32, u32, usize)> = indices
+        .into_iter()
+        .filter_map(|index| {
+            let event = &events[index];
+            Some((
+                event.start_time_cs().ok()?,
+                event.end_time_cs().ok()?,
+                index,
+            ))
+        })
+        .collect();
+    timed.sort_unstable();
+
+    let mut groups = Vec::new();
+    let mut current: Vec<(u32, u32, usize)> = Vec::new();
+    for entry in timed {
+        let fits = current.first().is_some_and(|&(start, end, _)| {
+            entry.0.abs_diff(start) <= tolerance && entry.1.abs_diff(end) <= tolerance
+        });
+        if !fits {
+            groups.push(core::mem::take(&mut current));
+        }
+        current.push(entry);
+    }
+    groups.push(current);
+
+    groups
+        .into_iter()
+        .filter(|group| group.len() > 1)
+        .map(|group| {
+            let mut indices: Vec<usize> = group.into_iter().map(|(_, _, index)| index).collect();
+            indices.sort_unstable();
+            DuplicateGroup { indices }
+        })
+        .collect()
+}
+
+/// Whether `a` and `b` are at most `threshold` Levenshtein edits apart
+///
+/// Stops as soon as every cell of a row exceeds the threshold.
+fn within_edits(a: &[char], b: &[char], threshold: usize) -> bool {
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    let mut current = vec![0; b.len() + 1];
+    for (i, &ca) in a.iter().enumerate() {
+        current[0] = i + 1;
+        for (j, &cb) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(ca != cb);
+            current[j + 1] = substitution.min(previous[j + 1] + 1).min(current[j] + 1);
+        }
+        if current.iter().all(|&distance| distance > threshold) {
+            return false;
+        }
+        core::mem::swap(&mut previous, &mut current);
+    }
+    previous[b.len()] <= threshold
+}
+
+/// Jaro-Winkler similarity of `a` and `b`, from 0.0 to 1.0
+///
+/// Uses the standard 0.1 prefix scale over at most four leading characters.
+fn jaro_winkler(a: &[char], b: &[char]) -> f64 {
+    if a.is_empty() || b.is_empty() {
+        return if a.len() == b.len() { 1.0 } else { 0.0 };
+    }
+
+    let window = (a.len().max(b.len()) / 2).saturating_sub(1);
+    let mut b_matched = vec![false; b.len()];
+    let mut a_matches = Vec::new();
+    for (i, &ca) in a.iter().enumerate() {
+        let end = (i + window + 1).min(b.len());
+        for j in i.saturating_sub(window)..end {
+            if !b_matched[j] && b[j] == ca {
+                b_matched[j] = true;
+                a_matches.push(ca);
+                break;
+            }
+        }
+    }
+    if a_matches.is_empty() {
+        return 0.0;
+    }
+
+    let b_matches = b
+        .iter()
+        .zip(&b_matched)
+        .filter(|(_, &matched)| matched)
+        .map(|(&cb, _)| cb);
+    let transpositions = a_matches
+        .iter()
+        .zip(b_matches)
+        .filter(|(ca, cb)| **ca != *cb)
+        .count()
+        / 2;
+
+    let matches = a_matches.len() as f64;
+    let jaro = (matches / a.len() as f64
+        + matches / b.len() as f64
+        + (matches - transpositions as f64) / matches)
+        / 3.0;
+    let prefix = a
+        .iter()
+        .zip(b)
+        .take(4)
+        .take_while(|(ca, cb)| ca == cb)
+        .count();
+    jaro + prefix as f64 * 0.1 * (1.0 - jaro)
+}
+
+/// Find the representative slot of `slot`, compressing the path
+fn find_root(parent: &mut [usize], slot: usize) -> usize {
+    let mut root = slot;
+    while parent[root] != root {
+        root = parent[root];
+    }
+    let mut node = slot;
+    while parent[node] != root {
+        let next = parent[node];
+        parent[node] = root;
+        node = next;
+    }
+    root
+}
@@ -7,14 +7,19 @@
 mod api;
 mod config;
 mod core_validation;
+mod cps;
+mod duplicates;
 mod issue;
 mod lazy_validator;
 mod result;
+mod segment_index;
 
 #[cfg(test)]
 mod tests;
 
 pub use config::ValidatorConfig;
+pub use cps::{CpsCountMode, CpsValidator};
+pub use duplicates::{DuplicateDetector, DuplicateGroup};
 pub use issue::{ValidationIssue, ValidationSeverity};
 pub use lazy_validator::LazyValidator;
 pub use result::ValidationResult;
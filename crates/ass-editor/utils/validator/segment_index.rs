@@ -0,0 +1,82 @@
+//! Candidate pairs for near-duplicate detection.
+//!
+//! Implements the bucketing pass behind
+//! `DuplicateDetector::find_near_duplicates`: instead of comparing every
+//! pair of texts, texts are indexed by segments that any text within the
+//! edit threshold must share, and only texts meeting in a bucket are
+//! compared by edit distance.
+
+#[cfg(feature = "std")]
+use std::collections::HashMap;
+
+#[cfg(not(feature = "std"))]
+use alloc::collections::BTreeMap as HashMap;
+
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+
+/// Slot pairs `(earlier, later)` of `texts` that may lie within `threshold`
+/// edits of each other
+///
+/// `texts` must be sorted by length. Each text of length `L` is split into
+/// `threshold + 1` segments; by the pigeonhole principle a text within
+/// `threshold` edits keeps one of them unchanged, shifted by at most
+/// `threshold` characters. So a text only looks up its substrings near the
+/// segment positions of shorter or equally long texts, and meets the texts
+/// holding that segment in the same bucket. Texts too short to split are
+/// paired with every text at most `threshold` characters longer.
+pub(super) fn candidate_pairs(texts: &[Vec<char>], threshold: usize) -> Vec<(usize, usize)> {
+    let parts = threshold + 1;
+    let mut buckets: HashMap<(usize, usize, &[char]), Vec<usize>> = HashMap::new();
+    let mut short: Vec<usize> = Vec::new();
+    let mut pairs = Vec::new();
+
+    for (slot, text) in texts.iter().enumerate() {
+        let len = text.len();
+        let mut found: Vec<usize> = short
+            .iter()
+            .copied()
+            .filter(|&other| len - texts[other].len() <= threshold)
+            .collect();
+
+        for other_len in len.saturating_sub(threshold).max(parts)..=len {
+            for (part, (start, part_len)) in segments(other_len, parts).enumerate() {
+                let first = start.saturating_sub(threshold);
+                let last = (start + threshold).min(len - part_len);
+                for pos in first..=last {
+                    let key = (other_len, part, &text[pos..pos + part_len]);
+                    if let Some(slots) = buckets.get(&key) {
+                        found.extend_from_slice(slots);
+                    }
+                }
+            }
+        }
+        found.sort_unstable();
+        found.dedup();
+        pairs.extend(found.into_iter().map(|other| (other, slot)));
+
+        if len < parts {
+            short.push(slot);
+        } else {
+            for (part, (start, part_len)) in segments(len, parts).enumerate() {
+                buckets
+                    .entry((len, part, &text[start..start + part_len]))
+                    .or_default()
+                    .push(slot);
+            }
+        }
+    }
+    pairs
+}
+
+/// Start and length of each of `parts` segments of a text of length `len`
+///
+/// Segments are as even as possible, with the longer ones last.
+fn segments(len: usize, parts: usize) -> impl Iterator<Item = (usize, usize)> {
+    let base = len / parts;
+    let shorter = parts - len % parts;
+    (0..parts).map(move |part| {
+        let start = part * base + part.saturating_sub(shorter);
+        (start, base + usize::from(part >= shorter))
+    })
+}
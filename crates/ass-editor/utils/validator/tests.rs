@@ -3,7 +3,12 @@
 use super::*;
 use crate::EditorDocument;
 #[cfg(not(feature = "std"))]
-use alloc::{string::ToString, vec};
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
 
 #[test]
 fn test_validation_issue_creation() {
@@ -105,3 +110,371 @@ fn test_validation_with_missing_sections() {
     let warnings = result.issues_with_severity(ValidationSeverity::Warning);
     assert!(!warnings.is_empty());
 }
+
+#[cfg(feature = "analysis")]
+#[test]
+fn test_validation_reports_missing_fonts() {
+    let content = r"[Script Info]
+Title: Fonts
+
+[V4+ Styles]
+Format: Name, Fontname, Fontsize
+Style: Default,Arial,20
+
+[Events]
+Format: Layer, Start, End, Style, Name, MarginL, MarginR, MarginV, Effect, Text
+Dialogue: 0,0:00:00.00,0:00:05.00,Default,,0,0,0,,{\fnGeorgia}Hello";
+    let document = EditorDocument::from_content(content).unwrap();
+    let mut validator = LazyValidator::with_config(ValidatorConfig {
+        available_fonts: Some(vec!["Arial".to_string()]),
+        ..Default::default()
+    });
+
+    let result = validator.validate(&document).unwrap();
+    let mut missing = result
+        .issues
+        .iter()
+        .filter(|issue| issue.rule == "missing-font");
+    assert!(missing.next().unwrap().message.contains("Georgia"));
+    assert!(missing.next().is_none());
+}
+
+#[cfg(feature = "analysis")]
+#[test]
+fn test_validation_reports_unused_styles() {
+    let content = r"[Script Info]
+Title: Styles
+
+[V4+ Styles]
+Format: Name, Fontname, Fontsize
+Style: Default,Arial,20
+Style: Orphan,Arial,20
+
+[Events]
+Format: Layer, Start, End, Style, Name, MarginL, MarginR, MarginV, Effect, Text
+Dialogue: 0,0:00:00.00,0:00:05.00,Default,,0,0,0,,Hello";
+    let document = EditorDocument::from_content(content).unwrap();
+    let mut validator = LazyValidator::new();
+
+    let result = validator.validate(&document).unwrap();
+    assert!(result
+        .issues
+        .iter()
+        .any(|issue| issue.rule == "unused-styles" && issue.message.contains("Orphan")));
+}
+
+const CPS_HEADER: &str = "[Script Info]
+Title: Reading speed
+
+[Events]
+Format: Layer, Start, End, Style, Name, MarginL, MarginR, MarginV, Effect, Text
+";
+
+fn cps_issues(events: &str, config: &ValidatorConfig) -> Vec<ValidationIssue> {
+    let content = format!("{CPS_HEADER}{events}");
+    let document = EditorDocument::from_content(&content).unwrap();
+    CpsValidator::new(config).validate(&document).unwrap()
+}
+
+#[test]
+fn test_cps_text_length_modes() {
+    let mut config = ValidatorConfig::default();
+    let text = r"{\b1}Hello\Nbig\hworld";
+    assert_eq!(CpsValidator::new(&config).text_length(text), 13);
+    assert_eq!(CpsValidator::new(&config).text_length("日本語 ok"), 8);
+
+    config.count_mode = CpsCountMode::Words;
+    assert_eq!(CpsValidator::new(&config).text_length(text), 3);
+
+    config.count_mode = CpsCountMode::Bytes;
+    assert_eq!(CpsValidator::new(&config).text_length("日本"), 6);
+}
+
+#[test]
+fn test_cps_single_character_events() {
+    let config = ValidatorConfig::default();
+
+    // One character over 0.5s is 2 CPS: fine
+    let ok = cps_issues(
+        "Dialogue: 0,0:00:00.00,0:00:00.50,Default,,0,0,0,,A\n",
+        &config,
+    );
+    assert!(ok.is_empty());
+
+    // One character over 0.49s is readable but below the minimum duration
+    let short = cps_issues(
+        "Dialogue: 0,0:00:00.00,0:00:00.49,Default,,0,0,0,,A\n",
+        &config,
+    );
+    assert_eq!(short.len(), 1);
+    assert_eq!(short[0].rule, "min-duration");
+    assert_eq!(short[0].severity, ValidationSeverity::Warning);
+
+    // Zero-length events with text are flagged without dividing by zero
+    let zero = cps_issues(
+        "Dialogue: 0,0:00:01.00,0:00:01.00,Default,,0,0,0,,A\n",
+        &config,
+    );
+    assert_eq!(zero[0].rule, "min-duration");
+
+    // Override-only and comment events are ignored
+    let ignored = cps_issues(
+        "Dialogue: 0,0:00:00.00,0:00:00.10,Default,,0,0,0,,{\\pos(1,1)}\n\
+         Comment: 0,0:00:00.00,0:00:00.10,Default,,0,0,0,,A\n",
+        &config,
+    );
+    assert!(ignored.is_empty());
+}
+
+#[test]
+fn test_cps_reading_speed_limit_and_cjk() {
+    let config = ValidatorConfig::default();
+
+    // 20 characters in 1s is exactly at the limit
+    let at_limit = cps_issues(
+        "Dialogue: 0,0:00:00.00,0:00:01.00,Default,,0,0,0,,abcdefghijklmnopqrst\n",
+        &config,
+    );
+    assert!(at_limit.is_empty());
+
+    // 12 CJK characters weigh 24, over the limit in 1s
+    let cjk = cps_issues(
+        "Dialogue: 0,0:00:00.00,0:00:01.00,Default,,0,0,0,,日本語の字幕はとても速い\n",
+        &config,
+    );
+    assert_eq!(cjk.len(), 1);
+    assert_eq!(cjk[0].rule, "reading-speed");
+    assert!(cjk[0].message.contains("24.0 CPS"));
+    assert!(cjk[0].suggestion.as_deref().unwrap().contains("120cs"));
+}
+
+#[test]
+fn test_lazy_validator_runs_cps_checks_when_enabled() {
+    let content =
+        format!("{CPS_HEADER}Dialogue: 0,0:00:00.00,0:00:00.20,Default,,0,0,0,,Too quick\n");
+    let document = EditorDocument::from_content(&content).unwrap();
+
+    let mut validator = LazyValidator::new();
+    let result = validator.validate(&document).unwrap();
+    assert!(!result.issues.iter().any(|i| i.rule == "min-duration"));
+
+    let mut validator = LazyValidator::with_config(ValidatorConfig {
+        enable_cps_checks: true,
+        ..Default::default()
+    });
+    let result = validator.validate(&document).unwrap();
+    assert!(result.issues.iter().any(|i| i.rule == "min-duration"));
+}
+
+fn parse_events(events: &str) -> Vec<ass_core::parser::Event<'_>> {
+    let script = ass_core::Script::parse(events).unwrap();
+    script
+        .sections()
+        .iter()
+        .filter_map(|section| match section {
+            ass_core::parser::Section::Events(events) => Some(events.clone()),
+            _ => None,
+        })
+        .flatten()
+        .collect()
+}
+
+#[test]
+fn test_duplicate_detector_exact_matches() {
+    let content = format!(
+        "{CPS_HEADER}Dialogue: 0,0:00:00.00,0:00:01.00,Default,,0,0,0,,Hello there\n\
+         Dialogue: 0,0:00:02.00,0:00:03.00,Default,,0,0,0,,Something else\n\
+         Dialogue: 0,0:00:04.00,0:00:05.00,Default,,0,0,0,,  hello THERE \n\
+         Comment: 0,0:00:06.00,0:00:07.00,Default,,0,0,0,,Hello there\n\
+         Dialogue: 0,0:00:08.00,0:00:09.00,Default,,0,0,0,,Something else\n"
+    );
+    let events = parse_events(&content);
+
+    let groups = DuplicateDetector::new().find_duplicates(&events);
+    assert_eq!(groups.len(), 2);
+    assert_eq!(groups[0].indices, vec![0, 2]);
+    assert_eq!(groups[1].indices, vec![1, 4]);
+
+    let sensitive = DuplicateDetector::new()
+        .with_case_sensitive(true)
+        .find_duplicates(&events);
+    assert_eq!(sensitive.len(), 1);
+    assert_eq!(sensitive[0].indices, vec![1, 4]);
+}
+
+#[test]
+fn test_duplicate_detector_timing_tolerance() {
+    let content = format!(
+        "{CPS_HEADER}Dialogue: 0,0:00:00.00,0:00:01.00,Default,,0,0,0,,Same\n\
+         Dialogue: 0,0:00:00.05,0:00:01.02,Default,,0,0,0,,Same\n\
+         Dialogue: 0,0:00:30.00,0:00:31.00,Default,,0,0,0,,Same\n"
+    );
+    let events = parse_events(&content);
+
+    let groups = DuplicateDetector::new()
+        .with_timing_tolerance(Some(10))
+        .find_duplicates(&events);
+    assert_eq!(groups.len(), 1);
+    assert_eq!(groups[0].indices, vec![0, 1]);
+
+    let loose = DuplicateDetector::new().find_duplicates(&events);
+    assert_eq!(loose[0].indices, vec![0, 1, 2]);
+}
+
+#[test]
+fn test_duplicate_detector_near_duplicates() {
+    let content = format!(
+        "{CPS_HEADER}Dialogue: 0,0:00:00.00,0:00:01.00,Default,,0,0,0,,I will be there\n\
+         Dialogue: 0,0:00:02.00,0:00:03.00,Default,,0,0,0,,Completely different\n\
+         Dialogue: 0,0:00:04.00,0:00:05.00,Default,,0,0,0,,I wil be ther\n\
+         Dialogue: 0,0:00:06.00,0:00:07.00,Default,,0,0,0,,I will be there.\n"
+    );
+    let events = parse_events(&content);
+    let detector = DuplicateDetector::new();
+
+    let near = detector.find_near_duplicates(&events, 2);
+    assert_eq!(near.len(), 1);
+    assert_eq!(near[0].indices, vec![0, 2, 3]);
+
+    let strict = detector.find_near_duplicates(&events, 1);
+    assert_eq!(strict.len(), 1);
+    assert_eq!(strict[0].indices, vec![0, 3]);
+
+    assert!(detector.find_near_duplicates(&events, 0).is_empty());
+}
+
+#[test]
+fn test_near_duplicate_candidates_bounded_on_large_input() {
+    // Pseudo-random words, so lines share no long runs by construction
+    let mut seed = 0x2545_f491_u32;
+    let mut word = || {
+        (0..5)
+            .map(|_| {
+                seed = seed.wrapping_mul(1_103_515_245).wrapping_add(12_345);
+                char::from(b'a' + u8::try_from((seed >> 16) % 26).unwrap())
+            })
+            .collect::<String>()
+    };
+    let mut texts: Vec<Vec<char>> = (0..5_000)
+        .map(|_| {
+            let line = format!("{} {} {} {} {}", word(), word(), word(), word(), word());
+            line.chars().collect()
+        })
+        .collect();
+    let original = texts[42].clone();
+    let mut typo = original.clone();
+    typo[3] = '#';
+    texts.push(typo.clone());
+    texts.sort_by_key(Vec::len);
+
+    let pairs = segment_index::candidate_pairs(&texts, 2);
+    assert!(pairs.len() < texts.len(), "{} candidate pairs", pairs.len());
+    let slot = |text: &Vec<char>| texts.iter().position(|t| t == text).unwrap();
+    let (a, b) = (slot(&original), slot(&typo));
+    assert!(pairs.contains(&(a.min(b), a.max(b))));
+}
+
+#[test]
+fn test_near_duplicate_candidates_cover_every_close_pair() {
+    let words = [
+        "a", "ab", "abc", "abd", "bcd", "abcd", "abdc", "xbcd", "abcde", "aXcde", "bcdef",
+    ];
+    let mut texts: Vec<Vec<char>> = words.iter().map(|word| word.chars().collect()).collect();
+    texts.sort_by_key(Vec::len);
+
+    for threshold in 0..=3 {
+        let pairs = segment_index::candidate_pairs(&texts, threshold);
+        for j in 0..texts.len() {
+            for i in 0..j {
+                let close = levenshtein(&texts[i], &texts[j]) <= threshold;
+                assert!(!close || pairs.contains(&(i, j)), "{i} {j} at {threshold}");
+            }
+        }
+    }
+}
+
+/// Plain dynamic-programming edit distance
+fn levenshtein(a: &[char], b: &[char]) -> usize {
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.iter().enumerate() {
+        let mut current = vec![i + 1];
+        for (j, cb) in b.iter().enumerate() {
+            let cost = previous[j] + usize::from(ca != cb);
+            current.push(cost.min(previous[j + 1] + 1).min(current[j] + 1));
+        }
+        previous = current;
+    }
+    previous[b.len()]
+}
+
+#[test]
+fn test_duplicate_detector_strips_overrides() {
+    let content = format!(
+        "{CPS_HEADER}Dialogue: 0,0:00:00.00,0:00:01.00,Default,,0,0,0,,{{\\b1}}Run!\n\
+         Dialogue: 0,0:00:02.00,0:00:03.00,Default,,0,0,0,,run!{{\\i1}}\n\
+         Dialogue: 0,0:00:04.00,0:00:05.00,Default,,0,0,0,,{{\\pos(1,2)}}\n"
+    );
+    let events = parse_events(&content);
+
+    assert!(DuplicateDetector::new().find_duplicates(&events).is_empty());
+    let groups = DuplicateDetector::new()
+        .with_strip_overrides(true)
+        .find_duplicates(&events);
+    assert_eq!(groups.len(), 1);
+    assert_eq!(groups[0].indices, vec![0, 1]);
+}
+
+#[test]
+fn test_duplicate_detector_jaro_winkler_scan() {
+    let content = format!(
+        "{CPS_HEADER}Dialogue: 0,0:00:00.00,0:00:01.00,Default,,0,0,0,,MARTHA\n\
+         Dialogue: 0,0:00:02.00,0:00:03.00,Default,,0,0,0,,MARHTA\n\
+         Dialogue: 0,0:00:04.00,0:00:05.00,Default,,0,0,0,,Something else\n\
+         Dialogue: 0,0:00:06.00,0:00:07.00,Default,,0,0,0,,martha\n"
+    );
+    let events = parse_events(&content);
+    let detector = DuplicateDetector::new();
+
+    let pairs = detector.near_duplicate_scan(&events, 0.9);
+    assert_eq!(pairs.len(), 3);
+    assert_eq!((pairs[0].0, pairs[0].1), (0, 1));
+    // Textbook MARTHA/MARHTA score
+    assert!((pairs[0].2 - 0.9611).abs() < 1e-4);
+    assert_eq!((pairs[1].0, pairs[1].1), (0, 3));
+    assert_eq!(pairs[1].2, 1.0);
+    assert_eq!((pairs[2].0, pairs[2].1), (1, 3));
+
+    assert!(detector.near_duplicate_scan(&events, 0.99).len() == 1);
+    assert!(detector
+        .near_duplicate_scan(&events, 0.0)
+        .iter()
+        .any(|&(a, b, _)| (a, b) == (0, 2)));
+}
+
+#[test]
+fn test_lazy_validator_reports_duplicate_text() {
+    let content = format!(
+        "{CPS_HEADER}Dialogue: 0,0:00:00.00,0:00:02.00,Default,,0,0,0,,Repeated line\n\
+         Dialogue: 0,0:00:03.00,0:00:05.00,Default,,0,0,0,,Repeated line\n"
+    );
+    let document = EditorDocument::from_content(&content).unwrap();
+
+    let mut validator = LazyValidator::new();
+    let result = validator.validate(&document).unwrap();
+    assert!(!result.issues.iter().any(|i| i.rule == "duplicate-text"));
+
+    let mut validator = LazyValidator::with_config(ValidatorConfig {
+        enable_duplicate_checks: true,
+        ..Default::default()
+    });
+    let result = validator.validate(&document).unwrap();
+    let duplicates: Vec<_> = result
+        .issues
+        .iter()
+        .filter(|i| i.rule == "duplicate-text")
+        .collect();
+    assert_eq!(duplicates.len(), 1);
+    assert!(duplicates[0]
+        .message
+        .contains("Event 1 duplicates the text of event 0"));
+}
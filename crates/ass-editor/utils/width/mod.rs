@@ -0,0 +1,84 @@
+//! Display-width measurement of subtitle text
+//!
+//! Counts characters the way CPS and line-length limits expect: override
+//! blocks are removed first, then each character weighs its Unicode East
+//! Asian Width, so fullwidth CJK characters count as 2 and ASCII as 1.
+//! [`EventDisplayExt`] applies the same measure to parsed events.
+
+use ass_core::parser::Event;
+use unicode_width::UnicodeWidthChar;
+
+#[cfg(not(feature = "std"))]
+use alloc::string::String;
+
+#[cfg(test)]
+mod tests;
+
+/// Remove override blocks and turn `\N`, `\n` and `\h` into spaces
+pub fn strip_overrides(text: &str) -> String {
+    let mut visible = String::with_capacity(text.len());
+    let mut in_block = false;
+    let mut chars = text.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '{' => in_block = true,
+            '}' if in_block => in_block = false,
+            _ if in_block => {}
+            '\\' => match chars.peek() {
+                Some('N' | 'n' | 'h') => {
+                    chars.next();
+                    visible.push(' ');
+                }
+                _ => visible.push(c),
+            },
+            _ => visible.push(c),
+        }
+    }
+    visible
+}
+
+/// Display width of `text` once override blocks are stripped
+///
+/// Wide and fullwidth characters count 2, combining marks and control
+/// characters count 0 and everything else counts 1.
+pub fn display_width(text: &str) -> usize {
+    strip_overrides(text).chars().map(char_width).sum()
+}
+
+/// Display width of the non-whitespace characters of already visible text
+pub(crate) fn counted_width(visible: &str) -> usize {
+    visible
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .map(char_width)
+        .sum()
+}
+
+/// East Asian Width of a single character
+fn char_width(c: char) -> usize {
+    c.width().unwrap_or(0)
+}
+
+/// Display-width measurements on parsed events
+pub trait EventDisplayExt {
+    /// Display width of the visible text, ignoring whitespace
+    fn display_char_count(&self) -> usize;
+
+    /// Reading speed in display-width units per second
+    ///
+    /// Returns `0.0` when the start or end time cannot be parsed. Zero-length
+    /// events read at the speed of a single centisecond.
+    fn cps(&self) -> f64;
+}
+
+impl EventDisplayExt for Event<'_> {
+    fn display_char_count(&self) -> usize {
+        counted_width(&strip_overrides(self.text))
+    }
+
+    fn cps(&self) -> f64 {
+        self.duration_cs().map_or(0.0, |duration_cs| {
+            self.display_char_count() as f64 * 100.0 / f64::from(duration_cs.max(1))
+        })
+    }
+}
@@ -0,0 +1,63 @@
+//! Tests for display-width counting and override stripping.
+
+use super::*;
+
+/// Dialogue event with the given times and text
+fn event<'a>(start: &'a str, end: &'a str, text: &'a str) -> Event<'a> {
+    Event {
+        start,
+        end,
+        text,
+        ..Event::default()
+    }
+}
+
+#[test]
+fn strip_overrides_removes_blocks_and_line_breaks() {
+    assert_eq!(
+        strip_overrides(r"{\an8}Hello{\i1}\Nworld\hagain"),
+        "Hello world again"
+    );
+    assert_eq!(strip_overrides(r"C:\path"), r"C:\path");
+}
+
+#[test]
+fn display_width_counts_fullwidth_characters_twice() {
+    assert_eq!(display_width("Hello"), 5);
+    assert_eq!(display_width("日本語"), 6);
+    assert_eq!(display_width("ＡＢＣ"), 6);
+    // Halfwidth katakana stays narrow
+    assert_eq!(display_width("ｶﾀｶﾅ"), 4);
+    assert_eq!(display_width(r"{\fs40}日本語 and English"), 18);
+}
+
+#[test]
+fn display_width_ignores_combining_marks() {
+    assert_eq!(display_width("e\u{301}"), 1);
+}
+
+#[test]
+fn display_char_count_skips_whitespace_in_mixed_lines() {
+    let line = event(
+        "0:00:00.00",
+        "0:00:02.00",
+        r"{\i1}今日は{\i0} good day\Nですね",
+    );
+    // 今日は (6) + good (4) + day (3) + ですね (6)
+    assert_eq!(line.display_char_count(), 19);
+}
+
+#[test]
+fn cps_uses_display_width_per_second() {
+    let line = event("0:00:00.00", "0:00:02.00", "こんにちは world");
+    assert!((line.cps() - 7.5).abs() < f64::EPSILON);
+
+    let ascii = event("0:00:00.00", "0:00:02.00", "Hello world");
+    assert!((ascii.cps() - 5.0).abs() < f64::EPSILON);
+}
+
+#[test]
+fn cps_handles_zero_length_and_unparsable_times() {
+    assert!((event("0:00:01.00", "0:00:01.00", "ab").cps() - 200.0).abs() < f64::EPSILON);
+    assert!(event("bad", "0:00:01.00", "ab").cps().abs() < f64::EPSILON);
+}
@@ -278,6 +278,36 @@ fn benchmark_comparison(c: &mut Criterion) {
     group.finish();
 }
 
+fn benchmark_static_shaping_cache(c: &mut Criterion) {
+    use ass_renderer::pipeline::shaping::TextShaper;
+
+    // A 5-minute script: 100 lines of 3 s each, shaped on every frame at 30 fps
+    let lines: Vec<String> = (0..100)
+        .map(|i| format!("Dialogue line {i} of a five minute episode"))
+        .collect();
+    let mut db = fontdb::Database::new();
+    db.load_system_fonts();
+
+    let mut group = c.benchmark_group("static_shaping_cache");
+    group.sample_size(10);
+    for (name, cached) in [("cached", true), ("uncached", false)] {
+        group.bench_function(name, |b| {
+            b.iter(|| {
+                let shaper = TextShaper::new().with_shaping_cache(cached);
+                for frame in 0..(5 * 60 * 30) {
+                    let line = &lines[frame / 90];
+                    black_box(
+                        shaper
+                            .shape_text(line, "Arial", 40.0, false, false, &db)
+                            .unwrap(),
+                    );
+                }
+            })
+        });
+    }
+    group.finish();
+}
+
 criterion_group!(
     benches,
     benchmark_simple_render,
@@ -289,7 +319,8 @@ criterion_group!(
     benchmark_parsing,
     benchmark_collision_detection,
     benchmark_animation_evaluation,
-    benchmark_comparison
+    benchmark_comparison,
+    benchmark_static_shaping_cache
 );
 
 criterion_main!(benches);
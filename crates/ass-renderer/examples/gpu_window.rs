@@ -531,7 +531,7 @@ impl Demo {
         };
         surface.configure(&device, &config);
 
-        let compositor = Compositor::new(&device);
+        let compositor = Compositor::new(&device).map_err(|e| format!("gpu compositor: {e}"))?;
         let renderer = Renderer::new(BackendType::Software, RenderContext::new(width, height))
             .map_err(|e| format!("software renderer: {e}"))?;
         let (clip_start, clip_end) = clip_span(&script);
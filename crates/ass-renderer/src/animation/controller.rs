@@ -1,9 +1,13 @@
 //! Animation controller that drives multiple tracks and `\t` tag parsing
 
 #[cfg(feature = "nostd")]
-use alloc::{string::ToString, vec::Vec};
+use alloc::{
+    collections::BTreeMap as HashMap,
+    string::{String, ToString},
+    vec::Vec,
+};
 #[cfg(not(feature = "nostd"))]
-use std::vec::Vec;
+use std::{collections::HashMap, string::String, vec::Vec};
 
 use crate::utils::RenderError;
 use smallvec::SmallVec;
@@ -11,7 +15,7 @@ use smallvec::SmallVec;
 use super::state::AnimationState;
 use super::timing::{AnimationInterpolation, AnimationTag, AnimationTiming};
 use super::track::AnimationTrack;
-use super::value::AnimatedValue;
+use super::value::{AnimatedResult, AnimatedValue};
 
 /// Animation controller for managing multiple tracks
 pub struct AnimationController {
@@ -166,6 +170,26 @@ impl AnimationController {
         state
     }
 
+    /// Evaluate every track at a fractional time in centiseconds
+    ///
+    /// Returns a snapshot of all animated properties keyed by property name.
+    /// Tracks outside their window hold their start or end value, and when
+    /// several tracks animate the same property the last one added wins, as
+    /// with [`AnimationController::evaluate`].
+    pub fn evaluate_at(&self, time_cs: f64) -> HashMap<String, AnimatedResult> {
+        self.tracks
+            .iter()
+            .map(|track| (track.property.clone(), track.evaluate_at(time_cs)))
+            .collect()
+    }
+
+    /// Tracks whose timing window contains a fractional time in centiseconds
+    pub fn active_at(&self, time_cs: f64) -> impl Iterator<Item = &AnimationTrack> {
+        self.tracks
+            .iter()
+            .filter(move |track| track.timing.contains(time_cs))
+    }
+
     /// Check if any animations are active at given time
     pub fn is_active(&self, time_cs: u32) -> bool {
         self.tracks
@@ -71,4 +71,107 @@ mod tests {
             panic!("Property not found or wrong type");
         }
     }
+    fn float_track(property: &str, from: f32, to: f32, start: u32, end: u32) -> AnimationTrack {
+        AnimationTrack::new(
+            property.to_string(),
+            AnimationTiming::new(start, end, 1.0),
+            AnimatedValue::Float { from, to },
+            AnimationInterpolation::Linear,
+        )
+    }
+
+    fn float_of(result: Option<&AnimatedResult>) -> f32 {
+        match result {
+            Some(AnimatedResult::Float(value)) => *value,
+            other => panic!("expected float, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn evaluate_at_snapshots_every_property() {
+        let mut controller = AnimationController::new();
+        controller.add_track(float_track("fs", 20.0, 40.0, 0, 100));
+        controller.add_track(float_track("frz", 0.0, 90.0, 100, 200));
+
+        let snapshot = controller.evaluate_at(50.5);
+        assert_eq!(snapshot.len(), 2);
+        assert!((float_of(snapshot.get("fs")) - 30.1).abs() < 1e-4);
+        assert_eq!(float_of(snapshot.get("frz")), 0.0);
+
+        let active: Vec<&str> = controller
+            .active_at(150.0)
+            .map(|track| track.property.as_str())
+            .collect();
+        assert_eq!(active, ["frz"]);
+        assert_eq!(controller.active_at(100.0).count(), 2);
+        assert_eq!(controller.active_at(250.0).count(), 0);
+    }
+
+    #[test]
+    fn bezier_interpolation_eases() {
+        let track = AnimationTrack::new(
+            "fs".to_string(),
+            AnimationTiming::new(0, 100, 1.0),
+            AnimatedValue::Float {
+                from: 0.0,
+                to: 100.0,
+            },
+            AnimationInterpolation::Bezier(0.42, 0.0, 0.58, 1.0),
+        );
+        let at = |time| match track.evaluate_at(time) {
+            AnimatedResult::Float(value) => value,
+            other => panic!("expected float, got {other:?}"),
+        };
+        assert_eq!(at(0.0), 0.0);
+        assert_eq!(at(100.0), 100.0);
+        assert!((at(50.0) - 50.0).abs() < 1e-3);
+        // Ease-in-out starts slower than linear
+        assert!(at(20.0) < 20.0);
+        assert!(at(80.0) > 80.0);
+
+        let linear = AnimationInterpolation::Bezier(0.0, 0.0, 1.0, 1.0);
+        let track = AnimationTrack::new(
+            "fs".to_string(),
+            AnimationTiming::new(0, 100, 1.0),
+            AnimatedValue::Float {
+                from: 0.0,
+                to: 100.0,
+            },
+            linear,
+        );
+        if let AnimatedResult::Float(value) = track.evaluate_at(30.0) {
+            assert!((value - 30.0).abs() < 0.5);
+        }
+    }
+
+    proptest::proptest! {
+        #[test]
+        fn linear_reaches_exact_endpoints(
+            from in -1000.0f32..1000.0,
+            to in -1000.0f32..1000.0,
+            start in 0u32..10_000,
+            length in 1u32..10_000,
+        ) {
+            let end = start + length;
+            let mut controller = AnimationController::new();
+            controller.add_track(float_track("fs", from, to, start, end));
+
+            proptest::prop_assert_eq!(float_of(controller.evaluate_at(f64::from(start)).get("fs")), from);
+            proptest::prop_assert_eq!(float_of(controller.evaluate_at(f64::from(end)).get("fs")), to);
+            proptest::prop_assert_eq!(float_of(controller.evaluate_at(f64::from(start) - 0.5).get("fs")), from);
+            proptest::prop_assert_eq!(float_of(controller.evaluate_at(f64::from(end) + 0.5).get("fs")), to);
+        }
+
+        #[test]
+        fn linear_stays_between_endpoints(
+            from in -1000.0f32..1000.0,
+            to in -1000.0f32..1000.0,
+            fraction in 0.0f64..=1.0,
+        ) {
+            let mut controller = AnimationController::new();
+            controller.add_track(float_track("fs", from, to, 100, 300));
+            let value = float_of(controller.evaluate_at(100.0 + 200.0 * fraction).get("fs"));
+            proptest::prop_assert!(value >= from.min(to) - 1e-3 && value <= from.max(to) + 1e-3);
+        }
+    }
 }
@@ -6,7 +6,7 @@ use alloc::{string::String, vec::Vec};
 use std::{string::String, vec::Vec};
 
 /// Animation interpolation types
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub enum AnimationInterpolation {
     /// Linear interpolation
     Linear,
@@ -14,6 +14,9 @@ pub enum AnimationInterpolation {
     Smooth,
     /// Smoother step (smoother ease-in-out)
     Smoother,
+    /// Cubic bezier easing through control points `(c1x, c1y)` and
+    /// `(c2x, c2y)`, like CSS `cubic-bezier()`
+    Bezier(f32, f32, f32, f32),
 }
 
 /// Animation tag (\t) representation
@@ -55,16 +58,21 @@ impl AnimationTiming {
 
     /// Calculate progress at given time (0.0 to 1.0)
     pub fn progress(&self, time_cs: u32) -> f32 {
-        if time_cs <= self.start_cs {
+        self.progress_at(f64::from(time_cs))
+    }
+
+    /// Calculate progress at a fractional time in centiseconds
+    pub fn progress_at(&self, time_cs: f64) -> f32 {
+        let start = f64::from(self.start_cs);
+        let end = f64::from(self.end_cs);
+        if time_cs <= start {
             return 0.0;
         }
-        if time_cs >= self.end_cs {
+        if time_cs >= end {
             return 1.0;
         }
 
-        let duration = (self.end_cs - self.start_cs) as f32;
-        let elapsed = (time_cs - self.start_cs) as f32;
-        let linear_progress = elapsed / duration;
+        let linear_progress = ((time_cs - start) / (end - start)) as f32;
 
         // Apply acceleration
         if (self.accel - 1.0).abs() < 0.001 {
@@ -73,4 +81,9 @@ impl AnimationTiming {
             linear_progress.powf(self.accel)
         }
     }
+
+    /// Whether `time_cs` lies within `[start_cs, end_cs]`
+    pub fn contains(&self, time_cs: f64) -> bool {
+        f64::from(self.start_cs) <= time_cs && time_cs <= f64::from(self.end_cs)
+    }
 }
@@ -5,6 +5,8 @@ use alloc::string::String;
 #[cfg(not(feature = "nostd"))]
 use std::string::String;
 
+use ass_core::utils::eval_cubic_bezier;
+
 use super::timing::{AnimationInterpolation, AnimationTiming};
 use super::value::{AnimatedResult, AnimatedValue};
 
@@ -39,7 +41,12 @@ impl AnimationTrack {
 
     /// Evaluate animation at given time
     pub fn evaluate(&self, time_cs: u32) -> AnimatedResult {
-        let progress = self.timing.progress(time_cs);
+        self.evaluate_at(f64::from(time_cs))
+    }
+
+    /// Evaluate animation at a fractional time in centiseconds
+    pub fn evaluate_at(&self, time_cs: f64) -> AnimatedResult {
+        let progress = self.timing.progress_at(time_cs);
         let interpolated_progress = self.apply_interpolation(progress);
         self.value.interpolate(interpolated_progress)
     }
@@ -50,6 +57,9 @@ impl AnimationTrack {
             AnimationInterpolation::Linear => progress,
             AnimationInterpolation::Smooth => smooth_step(progress),
             AnimationInterpolation::Smoother => smoother_step(progress),
+            AnimationInterpolation::Bezier(c1x, c1y, c2x, c2y) => {
+                bezier_ease(progress, (c1x, c1y), (c2x, c2y))
+            }
         }
     }
 }
@@ -63,3 +73,31 @@ fn smooth_step(t: f32) -> f32 {
 fn smoother_step(t: f32) -> f32 {
     t * t * t * (t * (t * 6.0 - 15.0) + 10.0)
 }
+
+/// Cubic bezier easing from `(0, 0)` to `(1, 1)`
+///
+/// Finds the curve parameter whose x equals `t` by bisection, then returns
+/// the y at that parameter. Control x coordinates are clamped to `[0, 1]` so
+/// that x is monotonic.
+fn bezier_ease(t: f32, c1: (f32, f32), c2: (f32, f32)) -> f32 {
+    if t <= 0.0 {
+        return 0.0;
+    }
+    if t >= 1.0 {
+        return 1.0;
+    }
+    let c1 = (c1.0.clamp(0.0, 1.0), c1.1);
+    let c2 = (c2.0.clamp(0.0, 1.0), c2.1);
+    let point = |s: f32| eval_cubic_bezier((0.0, 0.0), c1, c2, (1.0, 1.0), s);
+
+    let (mut low, mut high) = (0.0_f32, 1.0_f32);
+    for _ in 0..32 {
+        let mid = (low + high) * 0.5;
+        if point(mid).0 < t {
+            low = mid;
+        } else {
+            high = mid;
+        }
+    }
+    point((low + high) * 0.5).1
+}
@@ -50,10 +50,7 @@ impl AnimatedValue {
                 let value = *from + ((to - from) as f32 * t) as i32;
                 AnimatedResult::Integer(value)
             }
-            Self::Float { from, to } => {
-                let value = from + (to - from) * t;
-                AnimatedResult::Float(value)
-            }
+            Self::Float { from, to } => AnimatedResult::Float(lerp(*from, *to, t)),
             Self::Color { from, to } => {
                 let r = from[0] as f32 + (to[0] as f32 - from[0] as f32) * t;
                 let g = from[1] as f32 + (to[1] as f32 - from[1] as f32) * t;
@@ -62,19 +59,20 @@ impl AnimatedValue {
                 AnimatedResult::Color([r as u8, g as u8, b as u8, a as u8])
             }
             Self::Position { from, to } => {
-                let x = from.0 + (to.0 - from.0) * t;
-                let y = from.1 + (to.1 - from.1) * t;
-                AnimatedResult::Position((x, y))
+                AnimatedResult::Position((lerp(from.0, to.0, t), lerp(from.1, to.1, t)))
             }
             Self::Scale { from, to } => {
-                let x = from.0 + (to.0 - from.0) * t;
-                let y = from.1 + (to.1 - from.1) * t;
-                AnimatedResult::Scale((x, y))
+                AnimatedResult::Scale((lerp(from.0, to.0, t), lerp(from.1, to.1, t)))
             }
         }
     }
 }
 
+/// Linear interpolation that returns `from` and `to` exactly at 0 and 1
+fn lerp(from: f32, to: f32, t: f32) -> f32 {
+    from * (1.0 - t) + to * t
+}
+
 /// Result of animation interpolation
 #[derive(Debug, Clone)]
 pub enum AnimatedResult {
@@ -1,5 +1,7 @@
 //! Backend-agnostic geometry helpers shared across rendering backends.
 
+#[cfg(not(feature = "nostd"))]
+use crate::layout::Rotation3D;
 use tiny_skia::Transform;
 
 /// Merge positioned glyph outlines into a single path under one transform.
@@ -48,35 +50,23 @@ pub(crate) fn stroke_outline(path: &tiny_skia::Path, wx: f32, wy: f32) -> Option
     stroked.transform(Transform::from_scale(1.0, 1.0 / sy))
 }
 
-/// Project a screen-space path through a 3D rotation (`\frx`/`\fry`) and a pinhole
-/// perspective division about `(cx, cy)`, mirroring libass's transform matrix
-/// (RX then RY about a camera at distance `dist`). `\frz` is applied beforehand as
-/// a 2D rotation, matching libass's RZ->RX->RY order. Returns `None` if the
-/// projected outline is empty.
+/// Project a screen-space path through `rotation` and a pinhole perspective
+/// division about `(cx, cy)`, with the camera `focal_length` pixels away (see
+/// [`Rotation3D::project`]). Callers apply `\frz` beforehand as a 2D rotation
+/// and pass only the `\frx`/`\fry` part. Returns `None` if the projected
+/// outline is empty.
 #[cfg(not(feature = "nostd"))]
 pub(crate) fn project_path_3d(
     path: &tiny_skia::Path,
-    frx_rad: f32,
-    fry_rad: f32,
+    rotation: Rotation3D,
     cx: f32,
     cy: f32,
-    dist: f32,
+    focal_length: f32,
 ) -> Option<tiny_skia::Path> {
     use tiny_skia::{PathSegment, Point};
-    // libass: sx = -sin(frx), cx = cos(frx); sy = sin(fry), cy = cos(fry).
-    let (sfx, cfx) = (-frx_rad.sin(), frx_rad.cos());
-    let (sfy, cfy) = (fry_rad.sin(), fry_rad.cos());
     let project = |p: Point| -> Point {
-        let dx = p.x - cx;
-        let dy = p.y - cy;
-        // The glyph starts in the z=0 plane; rotate about X then Y.
-        let z3 = dy * sfx; // depth after \frx
-        let y3 = dy * cfx; // y after \frx
-        let x4 = dx * cfy - z3 * sfy;
-        let z4 = dx * sfy + z3 * cfy;
-        // Perspective divide by the camera distance (libass adds dist to z).
-        let zf = (z4 + dist).max(0.1);
-        Point::from_xy(cx + x4 * dist / zf, cy + y3 * dist / zf)
+        let (x, y) = rotation.project(p.x - cx, p.y - cy, focal_length);
+        Point::from_xy(cx + x, cy + y)
     };
     let mut pb = tiny_skia::PathBuilder::new();
     for seg in path.segments() {
@@ -28,6 +28,7 @@ use std::num::NonZeroU64;
 use crate::backends::coverage::RenderBitmap;
 use crate::utils::RenderError;
 
+use super::error::check_surface_format;
 use super::layer::{Layer, Screen};
 use super::pipeline::{build_pipeline, Programs};
 use super::pool::{TilePool, UNIFORM_SIZE};
@@ -67,7 +68,10 @@ pub struct Compositor {
 
 impl Compositor {
     /// Build the compositor pipeline, layouts and sampler on `device`.
-    pub fn new(device: &wgpu::Device) -> Self {
+    ///
+    /// Fails with [`super::GpuBackendError::ShaderCompilationFailed`] if the
+    /// WGSL module or pipeline does not validate on this device.
+    pub fn new(device: &wgpu::Device) -> Result<Self, RenderError> {
         let Programs {
             pipeline,
             frame_layout,
@@ -75,12 +79,12 @@ impl Compositor {
             sampler,
             shader,
             pipeline_layout,
-        } = Programs::new(device);
+        } = Programs::new(device)?;
 
         let align = device.limits().min_uniform_buffer_offset_alignment;
         let uniform_stride = UNIFORM_SIZE.div_ceil(align) * align;
 
-        Self {
+        Ok(Self {
             pipeline,
             frame_layout,
             tile_layout,
@@ -94,7 +98,7 @@ impl Compositor {
             screen: None,
             uniforms: None,
             pool: TilePool::new(),
-        }
+        })
     }
 
     /// Ensure a cached offscreen target and readback buffer exist for `width *
@@ -280,12 +284,20 @@ impl Compositor {
 
     /// Build (once per format) and cache a present pipeline whose colour target is
     /// `format`, so an external surface of an arbitrary format can be presented to.
-    fn ensure_present_pipeline(&mut self, device: &wgpu::Device, format: wgpu::TextureFormat) {
+    /// Formats that cannot be blended into are rejected with
+    /// [`super::GpuBackendError::SurfaceIncompatible`].
+    fn ensure_present_pipeline(
+        &mut self,
+        device: &wgpu::Device,
+        format: wgpu::TextureFormat,
+    ) -> Result<(), RenderError> {
         if self.present_pipelines.contains_key(&format) {
-            return;
+            return Ok(());
         }
-        let pipeline = build_pipeline(device, &self.shader, &self.pipeline_layout, format);
+        check_surface_format(device, format)?;
+        let pipeline = build_pipeline(device, &self.shader, &self.pipeline_layout, format)?;
         self.present_pipelines.insert(format, pipeline);
+        Ok(())
     }
 
     /// Blend the resident subtitle layer over `background` into the externally owned
@@ -321,7 +333,7 @@ impl Compositor {
             1
         };
         self.ensure_uniforms(device, quad_count);
-        self.ensure_present_pipeline(device, target.format);
+        self.ensure_present_pipeline(device, target.format)?;
         present::run_to_view(
             self,
             device,
@@ -0,0 +1,264 @@
+//! Structured GPU backend failures.
+//!
+//! wgpu reports most failures asynchronously through error scopes, the
+//! uncaptured-error handler and the device-lost callback. [`DeviceWatch`]
+//! routes all three into [`GpuBackendError`] so they surface as `Err` from
+//! the backend instead of wgpu's default panic.
+
+use std::future::Future;
+use std::sync::{Arc, Mutex};
+
+use thiserror::Error;
+
+use crate::utils::RenderError;
+
+/// Shader stage whose compilation failed
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ShaderStage {
+    /// The WGSL module itself failed to parse or validate
+    Module,
+    /// The vertex entry point
+    Vertex,
+    /// The fragment entry point
+    Fragment,
+}
+
+impl ShaderStage {
+    /// Stage named by a wgpu pipeline validation message
+    fn from_message(message: &str) -> Self {
+        if message.contains("FRAGMENT") || message.contains("fs_main") {
+            Self::Fragment
+        } else if message.contains("VERTEX") || message.contains("vs_main") {
+            Self::Vertex
+        } else {
+            Self::Module
+        }
+    }
+}
+
+/// GPU backend failure with the context needed to diagnose it
+#[derive(Debug, Clone, Error)]
+pub enum GpuBackendError {
+    /// No adapter matched the requested power preference
+    #[error("no compatible GPU adapter for power preference {requested_power:?}")]
+    NoCompatibleAdapter {
+        /// Power preference passed to the adapter request
+        requested_power: wgpu::PowerPreference,
+    },
+
+    /// The device was lost; the backend must be recreated
+    #[error("GPU device lost ({reason:?}): {message}")]
+    DeviceLost {
+        /// Why the device was lost
+        reason: wgpu::DeviceLostReason,
+        /// Driver message, if any
+        message: String,
+    },
+
+    /// The device ran out of memory
+    #[error("GPU out of memory (operation needed about {requested_bytes} bytes)")]
+    OutOfMemory {
+        /// Estimated bytes of GPU memory the failing operation needed
+        requested_bytes: usize,
+    },
+
+    /// A shader failed to compile or link into a pipeline
+    #[error("{stage:?} shader compilation failed: {message}")]
+    ShaderCompilationFailed {
+        /// Stage the failure was reported for
+        stage: ShaderStage,
+        /// Validation message from wgpu
+        message: String,
+    },
+
+    /// The surface format cannot be rendered to with blending
+    #[error("surface format {format:?} is not a blendable render target")]
+    SurfaceIncompatible {
+        /// Format of the rejected surface
+        format: wgpu::TextureFormat,
+    },
+}
+
+impl GpuBackendError {
+    /// Whether rendering can continue without recreating the backend
+    ///
+    /// A lost device or exhausted memory requires a new backend. The other
+    /// failures leave the device usable, or allow falling back to the
+    /// software backend.
+    pub fn is_recoverable(&self) -> bool {
+        !matches!(self, Self::DeviceLost { .. } | Self::OutOfMemory { .. })
+    }
+}
+
+impl From<GpuBackendError> for RenderError {
+    fn from(error: GpuBackendError) -> Self {
+        Self::Gpu(error)
+    }
+}
+
+/// Reject surface formats the compositor cannot blend into
+pub(super) fn check_surface_format(
+    device: &wgpu::Device,
+    format: wgpu::TextureFormat,
+) -> Result<(), GpuBackendError> {
+    let features = format.guaranteed_format_features(device.features());
+    if features
+        .allowed_usages
+        .contains(wgpu::TextureUsages::RENDER_ATTACHMENT)
+        && features
+            .flags
+            .contains(wgpu::TextureFormatFeatureFlags::BLENDABLE)
+    {
+        Ok(())
+    } else {
+        Err(GpuBackendError::SurfaceIncompatible { format })
+    }
+}
+
+/// Run `create` inside a validation error scope, reporting failures as a
+/// shader compilation error for `stage` (or the stage named by wgpu)
+pub(super) fn compile<T>(
+    device: &wgpu::Device,
+    stage: Option<ShaderStage>,
+    create: impl FnOnce() -> T,
+) -> Result<T, GpuBackendError> {
+    device.push_error_scope(wgpu::ErrorFilter::Validation);
+    let value = create();
+    match wait(device.pop_error_scope()) {
+        Some(error) => {
+            let message = error.to_string();
+            Err(GpuBackendError::ShaderCompilationFailed {
+                stage: stage.unwrap_or_else(|| ShaderStage::from_message(&message)),
+                message,
+            })
+        }
+        None => Ok(value),
+    }
+}
+
+fn wait<F: Future>(future: F) -> F::Output {
+    pollster::block_on(future)
+}
+
+/// Device-level failure recorded by a wgpu callback
+#[derive(Debug, Default)]
+struct Recorded {
+    lost: Option<(wgpu::DeviceLostReason, String)>,
+    uncaptured: Option<String>,
+}
+
+/// Tracks device loss and uncaptured errors of a device
+#[derive(Debug, Clone, Default)]
+pub(super) struct DeviceWatch {
+    recorded: Arc<Mutex<Recorded>>,
+}
+
+impl DeviceWatch {
+    /// Install loss and uncaptured-error callbacks on `device`
+    pub(super) fn install(device: &wgpu::Device) -> Self {
+        let watch = Self::default();
+        let recorded = Arc::clone(&watch.recorded);
+        device.set_device_lost_callback(move |reason, message| {
+            // Dropping the device also reports loss; only the first reason counts
+            let mut recorded = recorded.lock().unwrap_or_else(|e| e.into_inner());
+            recorded.lost.get_or_insert((reason, message));
+        });
+        let recorded = Arc::clone(&watch.recorded);
+        device.on_uncaptured_error(Box::new(move |error| {
+            let mut recorded = recorded.lock().unwrap_or_else(|e| e.into_inner());
+            recorded.uncaptured.get_or_insert_with(|| error.to_string());
+        }));
+        watch
+    }
+
+    /// Run a GPU operation needing about `requested_bytes`, converting
+    /// out-of-memory and validation errors, device loss and earlier
+    /// uncaptured errors into `Err`
+    pub(super) fn guard<T>(
+        &self,
+        device: &wgpu::Device,
+        requested_bytes: usize,
+        operation: impl FnOnce() -> Result<T, RenderError>,
+    ) -> Result<T, RenderError> {
+        self.check()?;
+        device.push_error_scope(wgpu::ErrorFilter::OutOfMemory);
+        device.push_error_scope(wgpu::ErrorFilter::Validation);
+        let result = operation();
+        let validation = wait(device.pop_error_scope());
+        let out_of_memory = wait(device.pop_error_scope());
+
+        self.check()?;
+        if out_of_memory.is_some() {
+            return Err(GpuBackendError::OutOfMemory { requested_bytes }.into());
+        }
+        if let Some(error) = validation {
+            return Err(RenderError::GpuError(error.to_string()));
+        }
+        result
+    }
+
+    /// Report device loss or an uncaptured error recorded so far
+    fn check(&self) -> Result<(), RenderError> {
+        let mut recorded = self.recorded.lock().unwrap_or_else(|e| e.into_inner());
+        if let Some((reason, message)) = &recorded.lost {
+            return Err(GpuBackendError::DeviceLost {
+                reason: *reason,
+                message: message.clone(),
+            }
+            .into());
+        }
+        match recorded.uncaptured.take() {
+            Some(message) => Err(RenderError::GpuError(message)),
+            None => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn missing_adapter_is_recoverable() {
+        let error = GpuBackendError::NoCompatibleAdapter {
+            requested_power: wgpu::PowerPreference::HighPerformance,
+        };
+        assert!(error.is_recoverable());
+        assert!(RenderError::from(error).is_recoverable());
+    }
+
+    #[test]
+    fn lost_device_and_oom_need_recreation() {
+        let lost = GpuBackendError::DeviceLost {
+            reason: wgpu::DeviceLostReason::Unknown,
+            message: "driver reset".into(),
+        };
+        assert!(!lost.is_recoverable());
+        assert!(lost.to_string().contains("driver reset"));
+        assert!(!GpuBackendError::OutOfMemory {
+            requested_bytes: 1 << 20
+        }
+        .is_recoverable());
+        assert!(GpuBackendError::ShaderCompilationFailed {
+            stage: ShaderStage::Fragment,
+            message: String::new(),
+        }
+        .is_recoverable());
+    }
+
+    #[test]
+    fn stage_is_read_from_pipeline_messages() {
+        assert_eq!(
+            ShaderStage::from_message("Error matching ShaderStages(FRAGMENT) shader requirements"),
+            ShaderStage::Fragment
+        );
+        assert_eq!(
+            ShaderStage::from_message("entry point vs_main not found"),
+            ShaderStage::Vertex
+        );
+        assert_eq!(
+            ShaderStage::from_message("parse error"),
+            ShaderStage::Module
+        );
+    }
+}
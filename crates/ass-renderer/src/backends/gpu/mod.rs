@@ -12,6 +12,7 @@
 //! `One`/`OneMinusSrcAlpha` blend. The `compositor` submodule holds the pipeline.
 
 mod compositor;
+mod error;
 mod layer;
 mod pipeline;
 mod pool;
@@ -23,13 +24,20 @@ mod target;
 mod tests;
 
 pub use compositor::{Background, Compositor, PresentTarget};
+pub use error::{GpuBackendError, ShaderStage};
 
 use crate::backends::coverage::RenderBitmap;
 use crate::backends::software::SoftwareBackend;
-use crate::backends::{BackendFeature, BackendType, RenderBackend};
+use crate::backends::{
+    BackendCapabilities, BackendFeature, BackendType, RenderBackend, ThreadingModel,
+};
 use crate::pipeline::{IntermediateLayer, Pipeline, SoftwarePipeline};
 use crate::renderer::RenderContext;
 use crate::utils::RenderError;
+use error::DeviceWatch;
+
+/// Adapter power preference requested by [`GpuBackend::new`]
+const POWER_PREFERENCE: wgpu::PowerPreference = wgpu::PowerPreference::HighPerformance;
 
 /// GPU backend that composites software-produced tiles via wgpu.
 pub struct GpuBackend {
@@ -37,22 +45,28 @@ pub struct GpuBackend {
     queue: wgpu::Queue,
     software: SoftwareBackend,
     compositor: Compositor,
+    watch: DeviceWatch,
 }
 
 impl GpuBackend {
     /// Initialise wgpu (high-performance adapter, default features/limits) and a
     /// software backend sized to `width * height` for tile production.
+    ///
+    /// Device loss, out-of-memory and validation errors raised later are
+    /// returned from the compositing calls instead of panicking.
     pub fn new(width: u32, height: u32) -> Result<Self, RenderError> {
         let instance = wgpu::Instance::new(wgpu::InstanceDescriptor {
             backends: wgpu::Backends::all(),
             ..Default::default()
         });
         let adapter = pollster::block_on(instance.request_adapter(&wgpu::RequestAdapterOptions {
-            power_preference: wgpu::PowerPreference::HighPerformance,
+            power_preference: POWER_PREFERENCE,
             compatible_surface: None,
             force_fallback_adapter: false,
         }))
-        .ok_or_else(|| RenderError::BackendError("no wgpu adapter available".into()))?;
+        .ok_or(GpuBackendError::NoCompatibleAdapter {
+            requested_power: POWER_PREFERENCE,
+        })?;
 
         let (device, queue) = pollster::block_on(adapter.request_device(
             &wgpu::DeviceDescriptor {
@@ -64,15 +78,17 @@ impl GpuBackend {
         ))
         .map_err(|e| RenderError::BackendError(format!("wgpu request_device failed: {e}")))?;
 
+        let watch = DeviceWatch::install(&device);
         let context = RenderContext::new(width, height);
         let software = SoftwareBackend::new(&context)?;
-        let compositor = Compositor::new(&device);
+        let compositor = Compositor::new(&device)?;
 
         Ok(Self {
             device,
             queue,
             software,
             compositor,
+            watch,
         })
     }
 
@@ -88,8 +104,12 @@ impl GpuBackend {
         width: u32,
         height: u32,
     ) -> Result<Vec<u8>, RenderError> {
-        self.compositor
-            .composite(&self.device, &self.queue, bitmaps, width, height)
+        // Offscreen target plus readback buffer, and the uploaded tiles
+        let requested = frame_bytes(width, height) * 2 + tile_bytes(bitmaps);
+        self.watch.guard(&self.device, requested, || {
+            self.compositor
+                .composite(&self.device, &self.queue, bitmaps, width, height)
+        })
     }
 
     /// Composite `bitmaps` into the resident GPU subtitle-layer texture, leaving
@@ -101,8 +121,11 @@ impl GpuBackend {
         width: u32,
         height: u32,
     ) -> Result<(), RenderError> {
-        self.compositor
-            .render_layer(&self.device, &self.queue, bitmaps, width, height)
+        let requested = frame_bytes(width, height) + tile_bytes(bitmaps);
+        self.watch.guard(&self.device, requested, || {
+            self.compositor
+                .render_layer(&self.device, &self.queue, bitmaps, width, height)
+        })
     }
 
     /// Present the cached subtitle layer over an opaque black background into the
@@ -110,13 +133,16 @@ impl GpuBackend {
     /// no readback. Requires a prior [`GpuBackend::render_subtitle_layer`] at the
     /// same size.
     pub fn present_frame(&mut self, width: u32, height: u32) -> Result<(), RenderError> {
-        self.compositor.present_over(
-            &self.device,
-            &self.queue,
-            Background::Clear(wgpu::Color::BLACK),
-            width,
-            height,
-        )
+        self.watch
+            .guard(&self.device, frame_bytes(width, height), || {
+                self.compositor.present_over(
+                    &self.device,
+                    &self.queue,
+                    Background::Clear(wgpu::Color::BLACK),
+                    width,
+                    height,
+                )
+            })
     }
 
     /// Read the resident subtitle layer back to straight premultiplied-RGBA bytes.
@@ -142,13 +168,7 @@ impl RenderBackend for GpuBackend {
         context: &RenderContext,
     ) -> Result<Vec<u8>, RenderError> {
         let bitmaps = self.software.render_layers_to_bitmaps(layers, context)?;
-        self.compositor.composite(
-            &self.device,
-            &self.queue,
-            &bitmaps,
-            context.width(),
-            context.height(),
-        )
+        self.composite_bitmaps(&bitmaps, context.width(), context.height())
     }
 
     fn render_layers_to_bitmaps(
@@ -162,4 +182,33 @@ impl RenderBackend for GpuBackend {
     fn supports_feature(&self, feature: BackendFeature) -> bool {
         matches!(feature, BackendFeature::HardwareAcceleration)
     }
+
+    fn capabilities(&self) -> BackendCapabilities {
+        let max = self.device.limits().max_texture_dimension_2d;
+        BackendCapabilities {
+            max_resolution: (max, max),
+            threading: ThreadingModel::GpuQueue,
+            ..BackendCapabilities::default()
+        }
+    }
+
+    fn load_font_data(&mut self, data: Vec<u8>) {
+        self.software.load_font_data(data);
+    }
+}
+
+/// Bytes of a `width * height` RGBA8 texture
+fn frame_bytes(width: u32, height: u32) -> usize {
+    width as usize * height as usize * 4
+}
+
+/// Bytes uploaded for `bitmaps`
+fn tile_bytes(bitmaps: &[RenderBitmap]) -> usize {
+    bitmaps
+        .iter()
+        .map(|bitmap| match bitmap {
+            RenderBitmap::Coverage { coverage, .. } => coverage.len(),
+            RenderBitmap::Rgba { pixels, .. } => pixels.len(),
+        })
+        .sum()
 }
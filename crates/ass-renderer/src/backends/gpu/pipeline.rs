@@ -7,7 +7,9 @@
 
 use std::num::NonZeroU64;
 
+use super::error::{compile, ShaderStage};
 use super::pool::UNIFORM_SIZE;
+use crate::utils::RenderError;
 
 /// Offscreen target format. `Unorm` (not `Srgb`) so blending happens directly on
 /// the stored bytes, reproducing the software backend's gamma-free premultiplied
@@ -34,11 +36,15 @@ pub(super) struct Programs {
 
 impl Programs {
     /// Build every reusable GPU object the compositor needs on `device`.
-    pub(super) fn new(device: &wgpu::Device) -> Self {
-        let shader = device.create_shader_module(wgpu::ShaderModuleDescriptor {
-            label: Some("ass-gpu-shader"),
-            source: wgpu::ShaderSource::Wgsl(super::shader::SHADER.into()),
-        });
+    ///
+    /// Shader compilation failures are returned rather than panicking.
+    pub(super) fn new(device: &wgpu::Device) -> Result<Self, RenderError> {
+        let shader = compile(device, Some(ShaderStage::Module), || {
+            device.create_shader_module(wgpu::ShaderModuleDescriptor {
+                label: Some("ass-gpu-shader"),
+                source: wgpu::ShaderSource::Wgsl(super::shader::SHADER.into()),
+            })
+        })?;
 
         let frame_layout = device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
             label: Some("ass-gpu-frame-bgl"),
@@ -82,7 +88,7 @@ impl Programs {
             push_constant_ranges: &[],
         });
 
-        let pipeline = build_pipeline(device, &shader, &pipeline_layout, TARGET_FORMAT);
+        let pipeline = build_pipeline(device, &shader, &pipeline_layout, TARGET_FORMAT)?;
 
         let sampler = device.create_sampler(&wgpu::SamplerDescriptor {
             label: Some("ass-gpu-sampler"),
@@ -92,14 +98,14 @@ impl Programs {
             ..Default::default()
         });
 
-        Self {
+        Ok(Self {
             pipeline,
             frame_layout,
             tile_layout,
             sampler,
             shader,
             pipeline_layout,
-        }
+        })
     }
 }
 
@@ -109,44 +115,48 @@ impl Programs {
 /// Every tile draw and every present pass uses the same WGSL and bindings; only
 /// the colour-attachment format differs (the offscreen target/layer use
 /// [`TARGET_FORMAT`], a window surface uses its own format). The blend stays the
-/// gamma-free premultiplied source-over (`One` / `OneMinusSrcAlpha`).
+/// gamma-free premultiplied source-over (`One` / `OneMinusSrcAlpha`). Entry-point
+/// validation failures are reported for the stage wgpu names.
 pub(super) fn build_pipeline(
     device: &wgpu::Device,
     shader: &wgpu::ShaderModule,
     layout: &wgpu::PipelineLayout,
     format: wgpu::TextureFormat,
-) -> wgpu::RenderPipeline {
+) -> Result<wgpu::RenderPipeline, RenderError> {
     let blend = wgpu::BlendComponent {
         src_factor: wgpu::BlendFactor::One,
         dst_factor: wgpu::BlendFactor::OneMinusSrcAlpha,
         operation: wgpu::BlendOperation::Add,
     };
-    device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
-        label: Some("ass-gpu-pipeline"),
-        layout: Some(layout),
-        vertex: wgpu::VertexState {
-            module: shader,
-            entry_point: "vs_main",
-            buffers: &[],
-        },
-        fragment: Some(wgpu::FragmentState {
-            module: shader,
-            entry_point: "fs_main",
-            targets: &[Some(wgpu::ColorTargetState {
-                format,
-                blend: Some(wgpu::BlendState {
-                    color: blend,
-                    alpha: blend,
-                }),
-                write_mask: wgpu::ColorWrites::ALL,
-            })],
-        }),
-        primitive: wgpu::PrimitiveState {
-            topology: wgpu::PrimitiveTopology::TriangleList,
-            ..Default::default()
-        },
-        depth_stencil: None,
-        multisample: wgpu::MultisampleState::default(),
-        multiview: None,
-    })
+    let pipeline = compile(device, None, || {
+        device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+            label: Some("ass-gpu-pipeline"),
+            layout: Some(layout),
+            vertex: wgpu::VertexState {
+                module: shader,
+                entry_point: "vs_main",
+                buffers: &[],
+            },
+            fragment: Some(wgpu::FragmentState {
+                module: shader,
+                entry_point: "fs_main",
+                targets: &[Some(wgpu::ColorTargetState {
+                    format,
+                    blend: Some(wgpu::BlendState {
+                        color: blend,
+                        alpha: blend,
+                    }),
+                    write_mask: wgpu::ColorWrites::ALL,
+                })],
+            }),
+            primitive: wgpu::PrimitiveState {
+                topology: wgpu::PrimitiveTopology::TriangleList,
+                ..Default::default()
+            },
+            depth_stencil: None,
+            multisample: wgpu::MultisampleState::default(),
+            multiview: None,
+        })
+    })?;
+    Ok(pipeline)
 }
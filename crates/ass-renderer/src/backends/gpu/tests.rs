@@ -232,7 +232,7 @@ fn present_to_view_targets_external_bgra_surface() {
     };
 
     let (width, height) = (64u32, 64u32);
-    let mut compositor = Compositor::new(&device);
+    let mut compositor = Compositor::new(&device).expect("compositor");
 
     let tile = RenderBitmap::Coverage {
         width: 10,
@@ -5,10 +5,17 @@ use crate::renderer::RenderContext;
 use crate::utils::{DirtyRegion, RenderError};
 
 #[cfg(feature = "nostd")]
-use alloc::{boxed::Box, format, vec::Vec};
+use alloc::{boxed::Box, format, vec, vec::Vec};
 #[cfg(not(feature = "nostd"))]
 use std::{boxed::Box, vec::Vec};
 
+#[cfg(all(feature = "backend-metrics", feature = "nostd"))]
+use alloc::collections::BTreeMap as HashMap;
+#[cfg(feature = "backend-metrics")]
+use core::time::Duration;
+#[cfg(all(feature = "backend-metrics", not(feature = "nostd")))]
+use std::collections::HashMap;
+
 #[cfg(feature = "software-backend")]
 pub mod blur;
 
@@ -27,15 +34,29 @@ pub mod software;
 #[cfg(feature = "gpu")]
 pub mod gpu;
 
+pub mod null;
+pub use null::NullBackend;
+
 /// Backend type enumeration
+///
+/// With the `serde` feature, variants (de)serialize as lowercase names, and
+/// `"hardware"` is accepted for [`BackendType::Gpu`].
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "lowercase")
+)]
 pub enum BackendType {
     /// Auto-detect best available backend
     Auto,
     /// CPU-based software renderer
     Software,
     /// Native hybrid GPU compositor (wgpu) over software-produced tiles
+    #[cfg_attr(feature = "serde", serde(alias = "hardware"))]
     Gpu,
+    /// Backend that lays out events but outputs transparent frames (testing)
+    Null,
 }
 
 impl BackendType {
@@ -45,6 +66,7 @@ impl BackendType {
             Self::Auto => "Auto",
             Self::Software => "Software",
             Self::Gpu => "Gpu",
+            Self::Null => "Null",
         }
     }
 }
@@ -102,6 +124,18 @@ pub trait RenderBackend: Send + Sync {
         }
     }
 
+    /// Describe the output formats, size limit and threading of this backend
+    fn capabilities(&self) -> BackendCapabilities {
+        BackendCapabilities::default()
+    }
+
+    /// Register font data (TTF/OTF bytes) used when rasterizing text
+    ///
+    /// Backends that do not rasterize text ignore it.
+    fn load_font_data(&mut self, data: Vec<u8>) {
+        let _ = data;
+    }
+
     /// Get backend metrics if available
     #[cfg(feature = "backend-metrics")]
     fn metrics(&self) -> Option<BackendMetrics> {
@@ -109,6 +143,38 @@ pub trait RenderBackend: Send + Sync {
     }
 }
 
+/// How a backend uses threads while rendering a frame
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ThreadingModel {
+    /// Everything runs on the calling thread
+    SingleThreaded,
+    /// Work is split across a CPU thread pool
+    Parallel,
+    /// Compositing is submitted to a GPU queue
+    GpuQueue,
+}
+
+/// Static description of what a backend can produce
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BackendCapabilities {
+    /// Pixel formats of the frames the backend returns
+    pub pixel_formats: Vec<crate::renderer::PixelFormat>,
+    /// Largest supported frame width and height in pixels
+    pub max_resolution: (u32, u32),
+    /// Threading model used for rendering
+    pub threading: ThreadingModel,
+}
+
+impl Default for BackendCapabilities {
+    fn default() -> Self {
+        Self {
+            pixel_formats: vec![crate::renderer::PixelFormat::Rgba8],
+            max_resolution: (16384, 16384),
+            threading: ThreadingModel::SingleThreaded,
+        }
+    }
+}
+
 /// Backend feature capabilities
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum BackendFeature {
@@ -136,6 +202,12 @@ pub struct BackendMetrics {
     pub avg_frame_time_ms: f32,
     /// Peak frame time in milliseconds
     pub peak_frame_time_ms: f32,
+    /// Number of frames recorded
+    pub frames_rendered: u64,
+    /// Total render time of recorded frames
+    pub total_render_time: Duration,
+    /// Total time spent in each pipeline stage across recorded frames
+    pub stage_times: HashMap<crate::pipeline::StageName, Duration>,
 }
 
 #[cfg(feature = "backend-metrics")]
@@ -148,8 +220,26 @@ impl BackendMetrics {
             batch_threshold: 100,
             avg_frame_time_ms: 0.0,
             peak_frame_time_ms: 0.0,
+            frames_rendered: 0,
+            total_render_time: Duration::ZERO,
+            stage_times: HashMap::new(),
         }
     }
+
+    /// Record one frame's stage timings, updating totals and frame times
+    pub fn record_frame(&mut self, stages: &[(crate::pipeline::StageName, Duration)]) {
+        let frame_time: Duration = stages.iter().map(|(_, time)| *time).sum();
+        for (stage, time) in stages {
+            *self.stage_times.entry(*stage).or_default() += *time;
+        }
+        self.frames_rendered += 1;
+        self.total_render_time += frame_time;
+
+        let frame_ms = frame_time.as_secs_f32() * 1000.0;
+        self.peak_frame_time_ms = self.peak_frame_time_ms.max(frame_ms);
+        self.avg_frame_time_ms =
+            self.total_render_time.as_secs_f32() * 1000.0 / self.frames_rendered as f32;
+    }
 }
 
 #[cfg(feature = "backend-metrics")]
@@ -189,6 +279,8 @@ pub fn create_backend(
             Ok(Box::new(backend))
         }
 
+        BackendType::Null => Ok(Box::new(NullBackend::new())),
+
         #[allow(unreachable_patterns)]
         _ => {
             let backend_name = backend_type.as_str();
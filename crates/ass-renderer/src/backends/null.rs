@@ -0,0 +1,55 @@
+//! Null backend producing transparent frames
+
+use crate::backends::{BackendCapabilities, BackendType, RenderBackend};
+use crate::pipeline::{IntermediateLayer, Pipeline, SoftwarePipeline};
+use crate::renderer::RenderContext;
+use crate::utils::RenderError;
+
+#[cfg(feature = "nostd")]
+use alloc::{boxed::Box, vec, vec::Vec};
+#[cfg(not(feature = "nostd"))]
+use std::{boxed::Box, vec::Vec};
+
+/// Backend that runs the software pipeline but draws nothing
+///
+/// Events are still styled, shaped and laid out, so pipeline errors surface,
+/// while every composited frame is fully transparent. Useful for testing
+/// renderer plumbing without depending on rasterization output.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct NullBackend;
+
+impl NullBackend {
+    /// Create a null backend
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl RenderBackend for NullBackend {
+    fn backend_type(&self) -> BackendType {
+        BackendType::Null
+    }
+
+    fn create_pipeline(&self) -> Result<Box<dyn Pipeline>, RenderError> {
+        Ok(Box::new(SoftwarePipeline::new()))
+    }
+
+    fn composite_layers(
+        &mut self,
+        layers: &[IntermediateLayer],
+        context: &RenderContext,
+    ) -> Result<Vec<u8>, RenderError> {
+        let _ = layers;
+        Ok(vec![
+            0;
+            context.width() as usize * context.height() as usize * 4
+        ])
+    }
+
+    fn capabilities(&self) -> BackendCapabilities {
+        BackendCapabilities {
+            max_resolution: (u32::MAX, u32::MAX),
+            ..BackendCapabilities::default()
+        }
+    }
+}
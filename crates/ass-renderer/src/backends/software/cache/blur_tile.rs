@@ -191,6 +191,7 @@ impl super::super::SoftwareBackend {
             font: data.font_family.clone(),
             size: data.font_size.to_bits(),
             spacing: data.spacing.to_bits(),
+            baseline_offset: data.baseline_offset.to_bits(),
             bold,
             italic,
             blur: radius.to_bits(),
@@ -31,6 +31,7 @@ struct RunCoverageKey {
     font: String,
     size: u32,
     spacing: u32,
+    baseline_offset: u32,
     bold: bool,
     italic: bool,
     outline: Option<(u32, u32)>,
@@ -51,6 +52,7 @@ pub(super) struct BlurTileKey {
     pub(super) font: String,
     pub(super) size: u32,
     pub(super) spacing: u32,
+    pub(super) baseline_offset: u32,
     pub(super) bold: bool,
     pub(super) italic: bool,
     pub(super) blur: u32,
@@ -177,6 +179,7 @@ fn coverage_key(
         font: data.font_family.clone(),
         size: data.font_size.to_bits(),
         spacing: data.spacing.to_bits(),
+        baseline_offset: data.baseline_offset.to_bits(),
         bold,
         italic,
         outline: outline.map(|(_, wx, wy)| (wx.to_bits(), wy.to_bits())),
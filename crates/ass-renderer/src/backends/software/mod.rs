@@ -20,6 +20,20 @@ use cache::{DIRTY_BBOX, EMIT_SINK};
 #[cfg(not(feature = "nostd"))]
 use dirty::{clear_region, crop_pixmap};
 
+/// Largest frame width and height the software backend renders
+pub const MAX_FRAME_SIZE: (u32, u32) = (16_384, 16_384);
+
+/// Allocate a `width`x`height` pixmap, rejecting sizes over [`MAX_FRAME_SIZE`]
+fn new_pixmap(width: u32, height: u32) -> Result<Pixmap, RenderError> {
+    if width > MAX_FRAME_SIZE.0 || height > MAX_FRAME_SIZE.1 {
+        return Err(RenderError::FrameSizeTooLarge {
+            requested: (width, height),
+            maximum: MAX_FRAME_SIZE,
+        });
+    }
+    Pixmap::new(width, height).ok_or(RenderError::InvalidDimensions)
+}
+
 /// Software rendering backend using tiny-skia
 pub struct SoftwareBackend {
     pixmap: Pixmap,
@@ -36,8 +50,7 @@ pub struct SoftwareBackend {
 impl SoftwareBackend {
     /// Create a new software backend
     pub fn new(context: &RenderContext) -> Result<Self, RenderError> {
-        let pixmap =
-            Pixmap::new(context.width(), context.height()).ok_or(RenderError::InvalidDimensions)?;
+        let pixmap = new_pixmap(context.width(), context.height())?;
 
         // Share the process-wide, lazily-loaded system font database. A fresh
         // backend is built every frame, so re-scanning system fonts here (the old
@@ -48,8 +61,7 @@ impl SoftwareBackend {
         let font_database = Arc::new(fontdb::Database::new());
 
         #[cfg(not(feature = "nostd"))]
-        let scratch =
-            Pixmap::new(context.width(), context.height()).ok_or(RenderError::InvalidDimensions)?;
+        let scratch = new_pixmap(context.width(), context.height())?;
 
         Ok(Self {
             pixmap,
@@ -64,10 +76,10 @@ impl SoftwareBackend {
 
     /// Resize the backend pixmap
     pub fn resize(&mut self, width: u32, height: u32) -> Result<(), RenderError> {
-        self.pixmap = Pixmap::new(width, height).ok_or(RenderError::InvalidDimensions)?;
+        self.pixmap = new_pixmap(width, height)?;
         #[cfg(not(feature = "nostd"))]
         {
-            self.scratch = Pixmap::new(width, height).ok_or(RenderError::InvalidDimensions)?;
+            self.scratch = new_pixmap(width, height)?;
         }
         Ok(())
     }
@@ -240,6 +252,21 @@ impl RenderBackend for SoftwareBackend {
         }
     }
 
+    fn capabilities(&self) -> super::BackendCapabilities {
+        super::BackendCapabilities {
+            threading: if cfg!(feature = "rayon") {
+                super::ThreadingModel::Parallel
+            } else {
+                super::ThreadingModel::SingleThreaded
+            },
+            ..super::BackendCapabilities::default()
+        }
+    }
+
+    fn load_font_data(&mut self, data: Vec<u8>) {
+        Arc::make_mut(&mut self.font_database).load_font_data(data);
+    }
+
     #[cfg(feature = "backend-metrics")]
     fn metrics(&self) -> Option<super::BackendMetrics> {
         Some(self.metrics.clone())
@@ -34,12 +34,15 @@ impl super::super::SoftwareBackend {
         // The blurred bitmap is a pure function of the glyph outlines, blur
         // radius and baked colours (screen position is applied at composite),
         // so identical blurred glyphs reuse one bitmap. A positional `\clip`
-        // makes the result position-dependent, so it is not cached.
-        let cache_key = clip_mask.is_none().then(|| BlurTileKey {
+        // makes the result position-dependent, so it is not cached; neither are
+        // underlined or struck-through runs, whose lines the key does not cover.
+        let cacheable = clip_mask.is_none() && !run.underline && !run.strikethrough;
+        let cache_key = cacheable.then(|| BlurTileKey {
             text: data.text.clone(),
             font: data.font_family.clone(),
             size: data.font_size.to_bits(),
             spacing: data.spacing.to_bits(),
+            baseline_offset: data.baseline_offset.to_bits(),
             bold: run.bold,
             italic: run.italic,
             blur: radius.to_bits(),
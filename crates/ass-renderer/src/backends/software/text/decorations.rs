@@ -1,10 +1,12 @@
 //! Decoration passes for the software text layer: sharp shadow, opaque box,
-//! outline (with optional `\be` edge blur) and underline/strikethrough.
+//! outline (with optional `\be` edge blur), plus the underline/strikethrough
+//! rectangles that join the glyph outlines.
 
 use tiny_skia::{Pixmap, Transform};
 
 use crate::backends::blur::apply_gaussian_blur;
 use crate::backends::geometry::{merge_transformed, stroke_outline};
+use crate::pipeline::shaping::ShapedText;
 use crate::pipeline::TextData;
 
 use super::TextRun;
@@ -206,64 +208,96 @@ impl super::super::SoftwareBackend {
             }
         }
     }
+}
 
-    /// Underline / strikethrough decoration pass, positioned from the baseline
-    /// using libass's offsets and stroked in the primary colour.
-    pub(super) fn draw_text_decorations(&mut self, data: &TextData, run: &TextRun) {
-        let shaped = &run.shaped;
-        let baseline_y = run.baseline_y;
-        let text_paint = &run.text_paint;
-        let clip_mask = run.clip_mask.as_ref();
-
-        // Draw underline if present
-        if run.underline {
-            // Position underline according to libass formula: baseline + descent/2
-            // baseline_y is already calculated as data.y + (*shaped).baseline
-            // descent is negative, so we need to subtract half of its absolute value
-            let underline_y = baseline_y - shaped.descent / 2.0;
+/// Underline (`\u1`) and strikethrough (`\s1`) rectangles in the run's local,
+/// baseline-origin space, spanning every glyph advance including `\fsp`.
+///
+/// Like libass, the lines become part of the glyph outline: they are appended
+/// to the run's paths so the outline, shadow, blur, karaoke and transforms
+/// apply to them exactly as to the glyphs. Each rectangle winds the same way
+/// as `glyphs` so the nonzero fill never cuts holes where it crosses them.
+pub(super) fn decoration_paths(
+    shaped: &ShapedText,
+    glyphs: &[tiny_skia::Path],
+    underline: bool,
+    strikethrough: bool,
+) -> Vec<tiny_skia::Path> {
+    let width = shaped.width;
+    if width <= 0.0 {
+        return Vec::new();
+    }
+    let clockwise = glyphs.iter().map(signed_area).sum::<f32>() >= 0.0;
+    let metrics = &shaped.metrics;
+    let lines = [
+        (
+            underline,
+            metrics.underline_offset_px,
+            metrics.underline_thickness_px,
+        ),
+        (
+            strikethrough,
+            metrics.strikethrough_offset_px,
+            metrics.strikethrough_thickness_px,
+        ),
+    ];
+    lines
+        .into_iter()
+        .filter(|(enabled, _, _)| *enabled)
+        .filter_map(|(_, offset, thickness)| {
+            // Offsets point up from the baseline; local y points down.
+            let top = -offset - thickness / 2.0;
+            let bottom = top + thickness;
             let mut builder = tiny_skia::PathBuilder::new();
-            builder.move_to(data.x, underline_y);
-            builder.line_to(data.x + shaped.width, underline_y);
-
-            if let Some(underline_path) = builder.finish() {
-                let stroke = tiny_skia::Stroke {
-                    width: data.font_size * 0.08,
-                    line_cap: tiny_skia::LineCap::Round,
-                    ..Default::default()
-                };
-                self.pixmap.stroke_path(
-                    &underline_path,
-                    text_paint,
-                    &stroke,
-                    Transform::identity(),
-                    clip_mask,
-                );
+            builder.move_to(0.0, top);
+            if clockwise {
+                builder.line_to(width, top);
+                builder.line_to(width, bottom);
+                builder.line_to(0.0, bottom);
+            } else {
+                builder.line_to(0.0, bottom);
+                builder.line_to(width, bottom);
+                builder.line_to(width, top);
             }
-        }
+            builder.close();
+            builder.finish()
+        })
+        .collect()
+}
 
-        // Draw strikethrough if present
-        if run.strikethrough {
-            // Position strikethrough according to libass formula: baseline - ascent/3
-            // baseline_y is already calculated as data.y + (*shaped).baseline
-            let strike_y = baseline_y - shaped.ascent / 3.0;
-            let mut builder = tiny_skia::PathBuilder::new();
-            builder.move_to(data.x, strike_y);
-            builder.line_to(data.x + shaped.width, strike_y);
+/// Shoelace area of a path's contours (positive when clockwise on screen),
+/// taking curve control points as polygon vertices
+fn signed_area(path: &tiny_skia::Path) -> f32 {
+    use tiny_skia::{PathSegment, Point};
 
-            if let Some(strike_path) = builder.finish() {
-                let stroke = tiny_skia::Stroke {
-                    width: data.font_size * 0.06,
-                    line_cap: tiny_skia::LineCap::Round,
-                    ..Default::default()
-                };
-                self.pixmap.stroke_path(
-                    &strike_path,
-                    text_paint,
-                    &stroke,
-                    Transform::identity(),
-                    clip_mask,
-                );
+    let edge = |from: Point, to: Point| from.x * to.y - to.x * from.y;
+    let mut area = 0.0;
+    let mut start = Point::zero();
+    let mut last = start;
+    for segment in path.segments() {
+        match segment {
+            PathSegment::MoveTo(point) => {
+                area += edge(last, start);
+                start = point;
+                last = point;
+            }
+            PathSegment::LineTo(point) => {
+                area += edge(last, point);
+                last = point;
+            }
+            PathSegment::QuadTo(control, point) => {
+                area += edge(last, control) + edge(control, point);
+                last = point;
+            }
+            PathSegment::CubicTo(control1, control2, point) => {
+                area += edge(last, control1) + edge(control1, control2) + edge(control2, point);
+                last = point;
+            }
+            PathSegment::Close => {
+                area += edge(last, start);
+                last = start;
             }
         }
     }
+    (area + edge(last, start)) / 2.0
 }
@@ -3,8 +3,9 @@
 //! A text layer is rendered in passes: a shared per-run setup
 //! ([`SoftwareBackend::prepare_text_run`]) resolves the positioned glyph
 //! outlines, transform, colours and effect parameters (and takes the cached
-//! coverage / blur-tile fast paths), then the sharp shadow, opaque box, outline,
-//! main fill and decorations are drawn in order. The main fill dispatches to one
+//! coverage / blur-tile fast paths), then the sharp shadow, opaque box, outline
+//! and main fill are drawn in order. Underline and strikethrough are part of
+//! the glyph outlines, so every pass draws them. The main fill dispatches to one
 //! of three paths — blurred text (temp pixmap + blur + composite), swept/binary
 //! karaoke, or plain sharp text — kept separate here from the parent module's
 //! layer compositing.
@@ -20,8 +21,8 @@ mod decorations;
 mod fills;
 mod prepare;
 
-/// Resolved per-run drawing state shared by the shadow, opaque-box, outline,
-/// main-fill (blur / karaoke / sharp) and decoration passes. Built once by
+/// Resolved per-run drawing state shared by the shadow, opaque-box, outline
+/// and main-fill (blur / karaoke / sharp) passes. Built once by
 /// [`SoftwareBackend::prepare_text_run`] so every pass reads the same positioned
 /// glyph outlines, transform, colours and effect parameters.
 struct TextRun {
@@ -51,7 +52,9 @@ struct TextRun {
     text_paint: tiny_skia::Paint<'static>,
     bold: bool,
     italic: bool,
+    /// `\u1`: the run's paths include an underline rectangle.
     underline: bool,
+    /// `\s1`: the run's paths include a strikethrough rectangle.
     strikethrough: bool,
 }
 
@@ -82,8 +85,6 @@ impl super::SoftwareBackend {
             self.draw_plain_text(&run);
         }
 
-        self.draw_text_decorations(data, &run);
-
         Ok(())
     }
 }
@@ -6,11 +6,13 @@
 use tiny_skia::Transform;
 
 use crate::backends::geometry::{merge_transformed, project_path_3d};
+use crate::layout::Rotation3D;
 use crate::pipeline::TextData;
 use crate::utils::RenderError;
 
 #[cfg(not(feature = "nostd"))]
 use super::super::dirty::{note_dirty_bbox, text_vector_dirty_bbox};
+use super::decorations::decoration_paths;
 use super::TextRun;
 
 impl super::super::SoftwareBackend {
@@ -46,7 +48,7 @@ impl super::super::SoftwareBackend {
 
         // Shape the text via the shared per-thread cache (persists across frames
         // and reuses the run the pipeline already shaped for layout).
-        let shaped = shape_text_cached(
+        let mut shaped = shape_text_cached(
             &data.text,
             &data.font_family,
             data.font_size,
@@ -54,6 +56,8 @@ impl super::super::SoftwareBackend {
             italic,
             &self.font_database,
         )?;
+        shaped.apply_fsp(data.spacing);
+        shaped.apply_pbo(data.baseline_offset);
 
         // Build base transform with rotation and scaling
         // The data.x and data.y are the top-left corner of the text box
@@ -62,14 +66,21 @@ impl super::super::SoftwareBackend {
 
         let mut base_transform = Transform::from_translate(data.x, baseline_y);
 
-        // \frx/\fry need a true projective transform; record (frx_rad, fry_rad,
-        // local rotation centre) here and project the glyph paths per-point below.
-        let mut rot3d: Option<(f32, f32, f32, f32)> = None;
+        // \frx/\fry need a true projective transform; record the tilt, the local
+        // rotation centre and the camera distance here and project the glyph paths
+        // per-point below.
+        let mut rot3d: Option<(Rotation3D, f32, f32, f32)> = None;
 
         // Check for rotation, scaling, and shear effects
         for effect in &data.effects {
             match effect {
-                crate::pipeline::TextEffect::Rotation { x, y, z, origin } => {
+                crate::pipeline::TextEffect::Rotation {
+                    x,
+                    y,
+                    z,
+                    origin,
+                    focal_length,
+                } => {
                     // Rotations are applied around a centre in local space. By
                     // default that is the text's own centre; `\org` overrides it with
                     // an explicit screen-space point (converted to local coords).
@@ -101,12 +112,12 @@ impl super::super::SoftwareBackend {
                     // glyph paths are projected per-point below. \frz stays affine and
                     // is applied first, matching libass's RZ->RX->RY order.
                     if *x != 0.0 || *y != 0.0 {
-                        rot3d = Some((
-                            x * core::f32::consts::PI / 180.0,
-                            y * core::f32::consts::PI / 180.0,
-                            text_center_x,
-                            text_center_y,
-                        ));
+                        let tilt = Rotation3D {
+                            x: *x,
+                            y: *y,
+                            z: 0.0,
+                        };
+                        rot3d = Some((tilt, text_center_x, text_center_y, *focal_length));
                     }
                 }
                 crate::pipeline::TextEffect::Scale { x, y } => {
@@ -169,27 +180,27 @@ impl super::super::SoftwareBackend {
             italic,
             &data.text,
         )?;
-        let paths = self.glyph_renderer.render_shaped_text(
-            &shaped,
-            font_id,
-            &self.font_database,
-            data.spacing,
-        )?;
+        let mut paths =
+            self.glyph_renderer
+                .render_shaped_text(&shaped, font_id, &self.font_database)?;
+        if underline || strikethrough {
+            let decorations = decoration_paths(&shaped, &paths, underline, strikethrough);
+            paths.extend(decorations);
+        }
 
         // For \frx/\fry, project the positioned glyph paths through the perspective
         // transform once and switch to an identity base transform, so the vector
         // fills below operate on the already-projected screen-space outlines. The
-        // perspective camera distance is libass's 20000 in 1/64-px units.
+        // camera distance scales with PlayResY (see `Rotation3D::focal_length`).
         #[cfg(not(feature = "nostd"))]
-        let (paths, base_transform) = if let Some((frx, fry, lcx, lcy)) = rot3d {
+        let (paths, base_transform) = if let Some((tilt, lcx, lcy, focal_length)) = rot3d {
             let mut center = [tiny_skia::Point::from_xy(lcx, lcy)];
             base_transform.map_points(&mut center);
-            let dist = 20000.0 / 64.0;
             let projected: Vec<tiny_skia::Path> = paths
                 .iter()
                 .filter_map(|p| {
                     let screen = p.clone().transform(base_transform)?;
-                    project_path_3d(&screen, frx, fry, center[0].x, center[0].y, dist)
+                    project_path_3d(&screen, tilt, center[0].x, center[0].y, focal_length)
                 })
                 .collect();
             (projected, Transform::identity())
@@ -0,0 +1,115 @@
+//! Eviction policies, invalidation and statistics of the timed frame cache
+
+use std::ops::RangeBounds;
+use std::time::Duration;
+use std::vec::Vec;
+
+use super::{FrameCache, FrameKey};
+
+/// Limit enforced by a [`FrameCache`]; the least recently used frames are
+/// evicted first
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EvictionPolicy {
+    /// Keep at most this many frames
+    MaxEntries(usize),
+    /// Keep at most this many bytes of pixel data
+    MaxBytes(usize),
+    /// Drop frames inserted longer ago than this
+    MaxAge(Duration),
+}
+
+/// Frame cache statistics for monitoring
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct FrameCacheStats {
+    /// Number of lookups that returned a frame
+    pub hits: usize,
+    /// Number of lookups that found no (live) frame
+    pub misses: usize,
+    /// Number of frames inserted
+    pub insertions: usize,
+    /// Number of frames evicted by a policy or expired
+    pub evictions: usize,
+    /// Number of frames currently cached
+    pub entries: usize,
+    /// Pixel bytes currently cached
+    pub bytes: usize,
+}
+
+impl FrameCache {
+    /// Current statistics
+    pub fn stats(&self) -> FrameCacheStats {
+        FrameCacheStats {
+            entries: self.entries.len(),
+            bytes: self.bytes,
+            ..self.stats
+        }
+    }
+
+    /// Drop every cached frame rendered at `time_cs`
+    pub fn invalidate_at(&mut self, time_cs: u32) {
+        self.invalidate_range(time_cs..=time_cs);
+    }
+
+    /// Drop every cached frame whose time falls within `times` (centiseconds)
+    pub fn invalidate_range<R: RangeBounds<u32>>(&mut self, times: R) {
+        let keys: Vec<FrameKey> = self
+            .entries
+            .keys()
+            .filter(|key| times.contains(&key.time_cs))
+            .copied()
+            .collect();
+        for key in keys {
+            self.remove(&key);
+        }
+    }
+
+    /// Drop every cached frame
+    pub fn invalidate_all(&mut self) {
+        self.entries.clear();
+        self.recency.clear();
+        self.bytes = 0;
+    }
+
+    /// Tightest `MaxAge` policy, if any
+    pub(super) fn max_age(&self) -> Option<Duration> {
+        self.policies
+            .iter()
+            .filter_map(|policy| match policy {
+                EvictionPolicy::MaxAge(age) => Some(*age),
+                _ => None,
+            })
+            .min()
+    }
+
+    /// Whether the cache exceeds any entry or byte limit
+    fn over_limit(&self) -> bool {
+        self.policies.iter().any(|policy| match *policy {
+            EvictionPolicy::MaxEntries(max) => self.entries.len() > max,
+            EvictionPolicy::MaxBytes(max) => self.bytes > max,
+            EvictionPolicy::MaxAge(_) => false,
+        })
+    }
+
+    /// Drop expired frames, then least recently used frames while over a limit
+    pub(super) fn evict(&mut self) {
+        if let Some(age) = self.max_age() {
+            let expired: Vec<FrameKey> = self
+                .entries
+                .iter()
+                .filter(|(_, entry)| entry.inserted.elapsed() > age)
+                .map(|(key, _)| *key)
+                .collect();
+            for key in expired {
+                self.remove(&key);
+                self.stats.evictions += 1;
+            }
+        }
+        while self.over_limit() {
+            let Some((_, key)) = self.recency.pop_first() else {
+                break;
+            };
+            self.remove(&key);
+            self.stats.evictions += 1;
+        }
+    }
+}
@@ -0,0 +1,179 @@
+//! Size-bounded LRU cache of rendered frames
+
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
+use std::sync::Arc;
+use std::time::Instant;
+use std::vec::Vec;
+
+use crate::renderer::Frame;
+
+mod eviction;
+
+#[cfg(test)]
+mod tests;
+
+pub use eviction::{EvictionPolicy, FrameCacheStats};
+
+/// Default byte budget of a [`FrameCache`] (about four 1080p RGBA frames)
+pub const DEFAULT_FRAME_CACHE_BYTES: usize = 32 * 1024 * 1024;
+
+/// Identity of a cached frame
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+struct FrameKey {
+    time_cs: u32,
+    width: u32,
+    height: u32,
+    font_size_bits: u32,
+}
+
+struct Entry {
+    frame: Arc<Frame>,
+    bytes: usize,
+    inserted: Instant,
+    tick: u64,
+}
+
+/// LRU cache of rendered frames keyed by time, output size and font scale
+///
+/// Every configured [`EvictionPolicy`] is enforced after each insertion;
+/// expired entries are also dropped lazily on lookup.
+pub struct FrameCache {
+    policies: Vec<EvictionPolicy>,
+    entries: HashMap<FrameKey, Entry>,
+    /// Use tick to key, oldest first
+    recency: BTreeMap<u64, FrameKey>,
+    tick: u64,
+    bytes: usize,
+    stats: FrameCacheStats,
+}
+
+impl Default for FrameCache {
+    fn default() -> Self {
+        Self::new(EvictionPolicy::MaxBytes(DEFAULT_FRAME_CACHE_BYTES))
+    }
+}
+
+impl fmt::Debug for FrameCache {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FrameCache")
+            .field("policies", &self.policies)
+            .field("stats", &self.stats())
+            .finish_non_exhaustive()
+    }
+}
+
+impl FrameCache {
+    /// Create a cache enforcing `policy`
+    pub fn new(policy: EvictionPolicy) -> Self {
+        Self {
+            policies: vec![policy],
+            entries: HashMap::new(),
+            recency: BTreeMap::new(),
+            tick: 0,
+            bytes: 0,
+            stats: FrameCacheStats::default(),
+        }
+    }
+
+    /// Also enforce `policy`
+    #[must_use]
+    pub fn with_policy(mut self, policy: EvictionPolicy) -> Self {
+        self.policies.push(policy);
+        self
+    }
+
+    /// Frame rendered at `time_cs` for a `width`x`height` output and font
+    /// scale `font_size`, marking it most recently used
+    pub fn get(
+        &mut self,
+        time_cs: u32,
+        width: u32,
+        height: u32,
+        font_size: f32,
+    ) -> Option<Arc<Frame>> {
+        let key = FrameKey::new(time_cs, width, height, font_size);
+        let max_age = self.max_age();
+        let Some(entry) = self.entries.get_mut(&key) else {
+            self.stats.misses += 1;
+            return None;
+        };
+        if max_age.is_some_and(|age| entry.inserted.elapsed() > age) {
+            self.remove(&key);
+            self.stats.evictions += 1;
+            self.stats.misses += 1;
+            return None;
+        }
+
+        self.tick += 1;
+        self.recency.remove(&entry.tick);
+        self.recency.insert(self.tick, key);
+        entry.tick = self.tick;
+        self.stats.hits += 1;
+        Some(Arc::clone(&entry.frame))
+    }
+
+    /// Cache `frame` as rendered at `time_cs` for a `width`x`height` output
+    /// and font scale `font_size`, then evict down to the configured limits
+    ///
+    /// A frame larger than a `MaxBytes` budget is evicted immediately.
+    pub fn insert(
+        &mut self,
+        time_cs: u32,
+        width: u32,
+        height: u32,
+        font_size: f32,
+        frame: Frame,
+    ) -> Arc<Frame> {
+        let key = FrameKey::new(time_cs, width, height, font_size);
+        self.remove(&key);
+
+        let frame = Arc::new(frame);
+        let bytes = frame.data().len();
+        self.tick += 1;
+        self.entries.insert(
+            key,
+            Entry {
+                frame: Arc::clone(&frame),
+                bytes,
+                inserted: Instant::now(),
+                tick: self.tick,
+            },
+        );
+        self.recency.insert(self.tick, key);
+        self.bytes += bytes;
+        self.stats.insertions += 1;
+
+        self.evict();
+        frame
+    }
+
+    /// Number of cached frames
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Whether no frame is cached
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Remove `key` from the cache, if present
+    fn remove(&mut self, key: &FrameKey) {
+        if let Some(entry) = self.entries.remove(key) {
+            self.recency.remove(&entry.tick);
+            self.bytes -= entry.bytes;
+        }
+    }
+}
+
+impl FrameKey {
+    fn new(time_cs: u32, width: u32, height: u32, font_size: f32) -> Self {
+        Self {
+            time_cs,
+            width,
+            height,
+            font_size_bits: font_size.to_bits(),
+        }
+    }
+}
@@ -0,0 +1,73 @@
+//! Tests for the timed frame cache.
+
+use super::*;
+use std::time::Duration;
+
+fn frame(width: u32, height: u32) -> Frame {
+    Frame::from_rgba(vec![0; (width * height * 4) as usize], width, height)
+}
+
+#[test]
+fn returns_inserted_frame_for_matching_key_only() {
+    let mut cache = FrameCache::default();
+    cache.insert(100, 4, 4, 1.0, frame(4, 4));
+    assert!(cache.get(100, 4, 4, 1.0).is_some());
+    assert!(cache.get(100, 4, 4, 2.0).is_none());
+    assert!(cache.get(101, 4, 4, 1.0).is_none());
+    let stats = cache.stats();
+    assert_eq!((stats.hits, stats.misses, stats.entries), (1, 2, 1));
+}
+
+#[test]
+fn max_entries_evicts_least_recently_used() {
+    let mut cache = FrameCache::new(EvictionPolicy::MaxEntries(2));
+    cache.insert(1, 2, 2, 1.0, frame(2, 2));
+    cache.insert(2, 2, 2, 1.0, frame(2, 2));
+    assert!(cache.get(1, 2, 2, 1.0).is_some());
+    cache.insert(3, 2, 2, 1.0, frame(2, 2));
+
+    assert!(cache.get(2, 2, 2, 1.0).is_none());
+    assert!(cache.get(1, 2, 2, 1.0).is_some());
+    assert!(cache.get(3, 2, 2, 1.0).is_some());
+    assert_eq!(cache.stats().evictions, 1);
+}
+
+#[test]
+fn max_bytes_bounds_pixel_data() {
+    let mut cache = FrameCache::new(EvictionPolicy::MaxBytes(100));
+    cache.insert(1, 4, 4, 1.0, frame(4, 4));
+    assert_eq!(cache.stats().bytes, 64);
+    cache.insert(2, 4, 4, 1.0, frame(4, 4));
+    assert_eq!(cache.len(), 1);
+    assert!(cache.get(2, 4, 4, 1.0).is_some());
+
+    cache.insert(3, 8, 8, 1.0, frame(8, 8));
+    assert!(cache.is_empty());
+}
+
+#[test]
+fn max_age_expires_frames() {
+    let mut cache = FrameCache::new(EvictionPolicy::MaxAge(Duration::ZERO));
+    cache.insert(1, 2, 2, 1.0, frame(2, 2));
+    std::thread::sleep(Duration::from_millis(1));
+    assert!(cache.get(1, 2, 2, 1.0).is_none());
+    assert!(cache.is_empty());
+}
+
+#[test]
+fn invalidation_targets_times() {
+    let mut cache = FrameCache::default();
+    for t in [10, 20, 30] {
+        cache.insert(t, 2, 2, 1.0, frame(2, 2));
+        cache.insert(t, 4, 4, 1.0, frame(4, 4));
+    }
+    cache.invalidate_at(20);
+    assert_eq!(cache.len(), 4);
+    assert!(cache.get(20, 4, 4, 1.0).is_none());
+
+    cache.invalidate_range(..=10);
+    assert_eq!(cache.len(), 2);
+    cache.invalidate_all();
+    assert!(cache.is_empty());
+    assert_eq!(cache.stats().bytes, 0);
+}
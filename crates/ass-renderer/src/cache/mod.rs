@@ -13,6 +13,11 @@ use alloc::collections::BTreeMap as HashMap;
 #[cfg(feature = "nostd")]
 use alloc::{string::String, sync::Arc};
 
+#[cfg(not(feature = "nostd"))]
+mod frame;
+#[cfg(not(feature = "nostd"))]
+pub use frame::{EvictionPolicy, FrameCache, FrameCacheStats, DEFAULT_FRAME_CACHE_BYTES};
+
 /// Cache key for shaped text
 #[derive(Debug, Clone, Hash, Eq, PartialEq, Ord, PartialOrd)]
 pub struct TextCacheKey {
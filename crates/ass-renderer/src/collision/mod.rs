@@ -1,8 +1,10 @@
 //! Collision detection for subtitle positioning
 
 mod resolver;
+mod sweep;
 
 pub use resolver::{CollisionResolver, SmartCollisionResolver};
+pub use sweep::{LayoutEvent, NudgePolicy, SweepCollisionDetector};
 
 /// Trait for collision detection strategies
 pub trait CollisionDetector: Send + Sync {
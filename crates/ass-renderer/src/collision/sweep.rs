@@ -0,0 +1,275 @@
+//! Policy-driven collision detection over a frame's laid-out events
+
+#[cfg(feature = "nostd")]
+use alloc::vec::Vec;
+#[cfg(not(feature = "nostd"))]
+use std::vec::Vec;
+
+use super::{BoundingBox, CollisionDetector};
+
+/// How a lower-priority event is moved out of an overlap
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum NudgePolicy {
+    /// Move the event below everything it overlaps
+    #[default]
+    NudgeDown,
+    /// Move the event above everything it overlaps
+    NudgeUp,
+    /// Move the event in whichever direction needs the smaller shift
+    NudgeClosestEdge,
+    /// Hide the event instead of moving it (for very crowded frames)
+    Hide,
+}
+
+/// Laid-out event taking part in collision detection
+#[derive(Debug, Clone, Copy)]
+pub struct LayoutEvent {
+    /// Rendered bounding box, updated in place when the event is moved
+    pub bbox: BoundingBox,
+    /// Event layer; higher layers win overlaps
+    pub layer: i32,
+    /// Position in script order; earlier events win overlaps within a layer
+    pub order: usize,
+    /// Set when the `Hide` policy suppresses the event
+    pub hidden: bool,
+}
+
+impl LayoutEvent {
+    /// Create a visible layout event
+    pub fn new(bbox: BoundingBox, layer: i32, order: usize) -> Self {
+        Self {
+            bbox,
+            layer,
+            order,
+            hidden: false,
+        }
+    }
+}
+
+/// Collision detector resolving overlaps with a `NudgePolicy`
+///
+/// Events are placed from highest to lowest priority into a list of boxes
+/// kept sorted by top edge. Each new box only tests the Y-interval window
+/// found by binary search, so a frame resolves in O(n log n) for the usual
+/// case of a handful of overlaps per event.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SweepCollisionDetector {
+    policy: NudgePolicy,
+}
+
+impl SweepCollisionDetector {
+    /// Create a detector using `policy`
+    pub fn new(policy: NudgePolicy) -> Self {
+        Self { policy }
+    }
+
+    /// Get the active policy
+    pub fn policy(&self) -> NudgePolicy {
+        self.policy
+    }
+
+    /// Resolve overlaps in place, returning how many events were moved or hidden
+    pub fn detect_and_resolve(&self, events: &mut [LayoutEvent]) -> usize {
+        let mut by_priority: Vec<usize> = (0..events.len()).collect();
+        by_priority.sort_by(|&a, &b| {
+            events[b]
+                .layer
+                .cmp(&events[a].layer)
+                .then(events[a].order.cmp(&events[b].order))
+        });
+
+        let mut placed = Placed::default();
+        let mut moved = 0;
+        for index in by_priority {
+            let event = &mut events[index];
+            event.hidden = false;
+            let original = event.bbox;
+            if placed.overlapping(&original).next().is_some() {
+                moved += 1;
+                match self.policy {
+                    NudgePolicy::Hide => {
+                        event.hidden = true;
+                        continue;
+                    }
+                    NudgePolicy::NudgeDown => event.bbox.y = placed.free_y(original, true),
+                    NudgePolicy::NudgeUp => event.bbox.y = placed.free_y(original, false),
+                    NudgePolicy::NudgeClosestEdge => {
+                        let down = placed.free_y(original, true);
+                        let up = placed.free_y(original, false);
+                        event.bbox.y = if down - original.y <= original.y - up {
+                            down
+                        } else {
+                            up
+                        };
+                    }
+                }
+            }
+            placed.insert(event.bbox);
+        }
+        moved
+    }
+}
+
+/// Placed boxes sorted by top edge, with the tallest height for window queries
+#[derive(Default)]
+struct Placed {
+    boxes: Vec<BoundingBox>,
+    max_height: f32,
+}
+
+impl Placed {
+    fn insert(&mut self, bbox: BoundingBox) {
+        let at = self.boxes.partition_point(|b| b.y < bbox.y);
+        self.boxes.insert(at, bbox);
+        self.max_height = self.max_height.max(bbox.height);
+    }
+
+    /// Placed boxes intersecting `bbox`, found within its Y-interval window
+    fn overlapping<'a>(&'a self, bbox: &'a BoundingBox) -> impl Iterator<Item = &'a BoundingBox> {
+        let start = self
+            .boxes
+            .partition_point(|b| b.y <= bbox.y - self.max_height);
+        let end = self.boxes.partition_point(|b| b.y < bbox.y + bbox.height);
+        self.boxes[start..end.max(start)]
+            .iter()
+            .filter(move |b| b.intersects(bbox))
+    }
+
+    /// Nearest Y in one direction where `bbox` overlaps no placed box
+    fn free_y(&self, mut bbox: BoundingBox, down: bool) -> f32 {
+        // Every step clears at least one placed box, bounding the iterations.
+        for _ in 0..=self.boxes.len() {
+            let hits = self.overlapping(&bbox);
+            let next = if down {
+                hits.map(|b| b.y + b.height)
+                    .fold(None, |acc: Option<f32>, y| {
+                        Some(acc.map_or(y, |a| a.max(y)))
+                    })
+            } else {
+                hits.map(|b| b.y - bbox.height)
+                    .fold(None, |acc: Option<f32>, y| {
+                        Some(acc.map_or(y, |a| a.min(y)))
+                    })
+            };
+            match next {
+                Some(y) => bbox.y = y,
+                None => break,
+            }
+        }
+        bbox.y
+    }
+}
+
+impl CollisionDetector for SweepCollisionDetector {
+    fn check_collision(&self, bbox: &BoundingBox, existing: &[BoundingBox]) -> bool {
+        existing.iter().any(|e| bbox.intersects(e))
+    }
+
+    fn find_free_position(
+        &self,
+        bbox: &BoundingBox,
+        existing: &[BoundingBox],
+        bounds: &BoundingBox,
+    ) -> Option<(f32, f32)> {
+        let mut placed = Placed::default();
+        for b in existing {
+            placed.insert(*b);
+        }
+        let y = match self.policy {
+            NudgePolicy::Hide => {
+                return (!self.check_collision(bbox, existing)).then_some((bbox.x, bbox.y));
+            }
+            NudgePolicy::NudgeDown => placed.free_y(*bbox, true),
+            NudgePolicy::NudgeUp => placed.free_y(*bbox, false),
+            NudgePolicy::NudgeClosestEdge => {
+                let down = placed.free_y(*bbox, true);
+                let up = placed.free_y(*bbox, false);
+                if down - bbox.y <= bbox.y - up {
+                    down
+                } else {
+                    up
+                }
+            }
+        };
+        (y >= bounds.y && y + bbox.height <= bounds.y + bounds.height).then_some((bbox.x, y))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Two 40px-tall bottom-aligned lines sharing the bottom margin.
+    fn bottom_pair() -> [LayoutEvent; 2] {
+        [
+            LayoutEvent::new(BoundingBox::new(100.0, 1000.0, 400.0, 40.0), 0, 0),
+            LayoutEvent::new(BoundingBox::new(150.0, 990.0, 300.0, 40.0), 0, 1),
+        ]
+    }
+
+    #[test]
+    fn bottom_aligned_overlap_is_resolved() {
+        for policy in [
+            NudgePolicy::NudgeDown,
+            NudgePolicy::NudgeUp,
+            NudgePolicy::NudgeClosestEdge,
+        ] {
+            let mut events = bottom_pair();
+            let moved = SweepCollisionDetector::new(policy).detect_and_resolve(&mut events);
+            assert_eq!(moved, 1, "{policy:?}");
+            assert!(!events[0].bbox.intersects(&events[1].bbox), "{policy:?}");
+            // The earlier event keeps its place.
+            assert_eq!(events[0].bbox.y, 1000.0);
+        }
+    }
+
+    #[test]
+    fn nudge_directions() {
+        let mut events = bottom_pair();
+        SweepCollisionDetector::new(NudgePolicy::NudgeUp).detect_and_resolve(&mut events);
+        assert_eq!(events[1].bbox.y, 960.0);
+
+        let mut events = bottom_pair();
+        SweepCollisionDetector::new(NudgePolicy::NudgeDown).detect_and_resolve(&mut events);
+        assert_eq!(events[1].bbox.y, 1040.0);
+
+        // 30px up beats 50px down.
+        let mut events = bottom_pair();
+        SweepCollisionDetector::new(NudgePolicy::NudgeClosestEdge).detect_and_resolve(&mut events);
+        assert_eq!(events[1].bbox.y, 960.0);
+    }
+
+    #[test]
+    fn higher_layer_wins_over_script_order() {
+        let mut events = bottom_pair();
+        events[1].layer = 1;
+        SweepCollisionDetector::new(NudgePolicy::NudgeUp).detect_and_resolve(&mut events);
+        assert_eq!(events[1].bbox.y, 990.0);
+        assert_eq!(events[0].bbox.y, 950.0);
+    }
+
+    #[test]
+    fn hide_policy_suppresses_lower_priority() {
+        let mut events = bottom_pair();
+        let moved = SweepCollisionDetector::new(NudgePolicy::Hide).detect_and_resolve(&mut events);
+        assert_eq!(moved, 1);
+        assert!(!events[0].hidden);
+        assert!(events[1].hidden);
+        assert_eq!(events[1].bbox.y, 990.0);
+    }
+
+    #[test]
+    fn stacked_events_cascade_without_overlap() {
+        let mut events: Vec<LayoutEvent> = (0..5)
+            .map(|i| LayoutEvent::new(BoundingBox::new(0.0, 1000.0, 200.0, 40.0), 0, i))
+            .collect();
+        let moved =
+            SweepCollisionDetector::new(NudgePolicy::NudgeUp).detect_and_resolve(&mut events);
+        assert_eq!(moved, 4);
+        for (i, a) in events.iter().enumerate() {
+            for b in &events[i + 1..] {
+                assert!(!a.bbox.intersects(&b.bbox));
+            }
+        }
+    }
+}
@@ -0,0 +1,151 @@
+//! Pixel-level comparison of two rendered frames
+
+use crate::Frame;
+
+#[cfg(feature = "nostd")]
+use alloc::{vec, vec::Vec};
+
+/// Side length of the square windows SSIM is computed over
+const SSIM_WINDOW: usize = 8;
+/// SSIM luminance stabilizer `(0.01 * 255)^2`
+const SSIM_C1: f64 = 6.5025;
+/// SSIM contrast stabilizer `(0.03 * 255)^2`
+const SSIM_C2: f64 = 58.5225;
+
+/// Difference between two frames in straight RGBA space
+///
+/// When the frames differ in size, both are compared over the larger extent
+/// with missing pixels treated as fully transparent black.
+#[derive(Debug, Clone)]
+pub struct FrameDiff {
+    /// Width of the compared area
+    pub width: u32,
+    /// Height of the compared area
+    pub height: u32,
+    /// Largest absolute difference of any channel of any pixel
+    pub max_diff: u8,
+    /// Mean absolute difference over every channel of every pixel
+    pub mean_diff: f64,
+    /// Peak signal-to-noise ratio in decibels; infinite for identical frames
+    pub psnr_db: f64,
+    /// Mean structural similarity over 8x8 windows and all four channels
+    /// (1.0 for identical frames)
+    pub ssim: f64,
+    /// RGBA heat map: each pixel's largest channel difference scaled so that
+    /// `max_diff` maps to white, drawn opaque over black
+    pub diff_bitmap: Vec<u8>,
+}
+
+impl FrameDiff {
+    /// Whether the frames are pixel-identical
+    pub fn is_identical(&self) -> bool {
+        self.max_diff == 0
+    }
+
+    /// Compute the difference between `frame_a` and `frame_b`
+    pub(super) fn compute(frame_a: &Frame, frame_b: &Frame) -> Self {
+        let width = frame_a.width().max(frame_b.width());
+        let height = frame_a.height().max(frame_b.height());
+        let a = padded_pixels(frame_a, width, height);
+        let b = padded_pixels(frame_b, width, height);
+
+        let mut max_diff = 0u8;
+        let mut abs_sum = 0u64;
+        let mut sq_sum = 0u64;
+        let mut pixel_diffs = Vec::with_capacity(a.len() / 4);
+        for (pa, pb) in a.chunks_exact(4).zip(b.chunks_exact(4)) {
+            let mut worst = 0u8;
+            for (&ca, &cb) in pa.iter().zip(pb) {
+                let d = ca.abs_diff(cb);
+                abs_sum += u64::from(d);
+                sq_sum += u64::from(d) * u64::from(d);
+                worst = worst.max(d);
+            }
+            max_diff = max_diff.max(worst);
+            pixel_diffs.push(worst);
+        }
+
+        let samples = a.len().max(1) as f64;
+        let mse = sq_sum as f64 / samples;
+        let psnr_db = if mse == 0.0 {
+            f64::INFINITY
+        } else {
+            10.0 * (255.0 * 255.0 / mse).log10()
+        };
+
+        let mut diff_bitmap = Vec::with_capacity(a.len());
+        for d in pixel_diffs {
+            let v = if max_diff == 0 {
+                0
+            } else {
+                (u32::from(d) * 255 / u32::from(max_diff)) as u8
+            };
+            diff_bitmap.extend_from_slice(&[v, v, v, 255]);
+        }
+
+        Self {
+            width,
+            height,
+            max_diff,
+            mean_diff: abs_sum as f64 / samples,
+            psnr_db,
+            ssim: ssim(&a, &b, width as usize, height as usize),
+            diff_bitmap,
+        }
+    }
+}
+
+/// Frame pixels as RGBA, padded with transparent pixels to `width`x`height`
+fn padded_pixels(frame: &Frame, width: u32, height: u32) -> Vec<u8> {
+    let rgba = frame.clone().to_rgba();
+    if rgba.width() == width && rgba.height() == height {
+        return rgba.into_buffer();
+    }
+    let (src_w, dst_w) = (rgba.width() as usize * 4, width as usize * 4);
+    let mut out = vec![0u8; dst_w * height as usize];
+    for (dst, src) in out
+        .chunks_exact_mut(dst_w)
+        .zip(rgba.data().chunks_exact(src_w))
+    {
+        dst[..src_w].copy_from_slice(src);
+    }
+    out
+}
+
+/// Mean SSIM over non-overlapping windows, per channel
+fn ssim(a: &[u8], b: &[u8], width: usize, height: usize) -> f64 {
+    let mut total = 0.0;
+    let mut windows = 0usize;
+    for wy in (0..height).step_by(SSIM_WINDOW) {
+        for wx in (0..width).step_by(SSIM_WINDOW) {
+            let (w, h) = (SSIM_WINDOW.min(width - wx), SSIM_WINDOW.min(height - wy));
+            let n = (w * h) as f64;
+            for channel in 0..4 {
+                let (mut sa, mut sb, mut saa, mut sbb, mut sab) = (0.0, 0.0, 0.0, 0.0, 0.0);
+                for y in wy..wy + h {
+                    for x in wx..wx + w {
+                        let idx = (y * width + x) * 4 + channel;
+                        let (va, vb) = (f64::from(a[idx]), f64::from(b[idx]));
+                        sa += va;
+                        sb += vb;
+                        saa += va * va;
+                        sbb += vb * vb;
+                        sab += va * vb;
+                    }
+                }
+                let (ma, mb) = (sa / n, sb / n);
+                let var_a = saa / n - ma * ma;
+                let var_b = sbb / n - mb * mb;
+                let cov = sab / n - ma * mb;
+                total += ((2.0 * ma * mb + SSIM_C1) * (2.0 * cov + SSIM_C2))
+                    / ((ma * ma + mb * mb + SSIM_C1) * (var_a + var_b + SSIM_C2));
+                windows += 1;
+            }
+        }
+    }
+    if windows == 0 {
+        1.0
+    } else {
+        total / windows as f64
+    }
+}
@@ -8,8 +8,10 @@ use crate::Frame;
 #[cfg(feature = "nostd")]
 use alloc::vec::Vec;
 
+mod diff;
 mod report;
 
+pub use diff::FrameDiff;
 pub use report::{AnalysisReport, PixelHistogram, Region};
 
 /// Frame analyzer for detailed text-based debugging
@@ -55,6 +57,11 @@ impl FrameAnalyzer {
         report
     }
 
+    /// Compare two frames pixel by pixel in RGBA space
+    pub fn diff(frame_a: &Frame, frame_b: &Frame) -> FrameDiff {
+        FrameDiff::compute(frame_a, frame_b)
+    }
+
     fn calculate_pixel_histogram(&self, frame: &Frame) -> PixelHistogram {
         let pixels = frame.pixels();
         let mut histogram = PixelHistogram::default();
@@ -10,6 +10,15 @@ use alloc::vec::Vec;
 #[cfg(feature = "nostd")]
 use core::fmt;
 
+/// A straight-alpha RGBA color sample
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Rgba {
+    pub r: u8,
+    pub g: u8,
+    pub b: u8,
+    pub a: u8,
+}
+
 #[derive(Debug, Clone, Copy)]
 pub struct PixelInfo {
     pub x: u32,
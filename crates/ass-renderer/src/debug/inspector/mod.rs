@@ -6,7 +6,7 @@ use alloc::vec::Vec;
 mod info;
 
 pub use info::{
-    BoundsInfo, ColorHistogram, LineScanSegment, PixelComparison, PixelInfo, RegionInfo,
+    BoundsInfo, ColorHistogram, LineScanSegment, PixelComparison, PixelInfo, RegionInfo, Rgba,
 };
 
 /// Frame inspector for detailed pixel-level debugging
@@ -85,6 +85,29 @@ impl FrameInspector {
         })
     }
 
+    /// Color of `frame` at `(x, y)`; transparent outside the frame
+    pub fn sample_at(frame: &Frame, x: u32, y: u32) -> Rgba {
+        if x >= frame.width() || y >= frame.height() {
+            return Rgba::default();
+        }
+        let idx = (y as usize * frame.width() as usize + x as usize) * 4;
+        match frame.pixels().get(idx..idx + 4) {
+            Some(&[r, g, b, a]) => Rgba { r, g, b, a },
+            _ => Rgba::default(),
+        }
+    }
+
+    /// Colors of row `y` of the loaded frame; empty when no frame is loaded
+    /// or `y` is outside it
+    pub fn sample_line(&self, y: u32) -> Vec<Rgba> {
+        match self.frame.as_ref() {
+            Some(frame) if y < frame.height() => (0..frame.width())
+                .map(|x| Self::sample_at(frame, x, y))
+                .collect(),
+            _ => Vec::new(),
+        }
+    }
+
     pub fn get_region(&self, x: u32, y: u32, width: u32, height: u32) -> RegionInfo {
         let frame = match self.frame.as_ref() {
             Some(f) => f,
@@ -10,7 +10,11 @@
 
 mod sys;
 
+use crate::backends::BackendType;
+use crate::debug::{FrameAnalyzer, FrameDiff};
+use crate::renderer::{Frame, RenderContext, Renderer};
 use crate::utils::RenderError;
+use ass_core::parser::Script;
 use core::ffi::{c_int, c_void};
 use std::ffi::CString;
 
@@ -153,6 +157,22 @@ impl Libass {
         Ok(frame)
     }
 
+    /// Render `ass_text` at `time_cs` through both libass and the software
+    /// backend, and diff the two frames (libass as `frame_a`).
+    ///
+    /// Both renders use this instance's frame size; configure fonts with
+    /// [`Libass::set_fonts`] first so the two renderers pick the same faces.
+    pub fn compare_frame(&self, ass_text: &str, time_cs: u32) -> Result<FrameDiff, RenderError> {
+        let reference = self.render(ass_text, i64::from(time_cs) * 10)?;
+        let reference = Frame::new(reference.rgba, self.width, self.height, time_cs);
+
+        let script = Script::parse(ass_text)?;
+        let context = RenderContext::new(self.width, self.height);
+        let mut renderer = Renderer::new(BackendType::Software, context)?;
+        let ours = renderer.render_frame(&script, time_cs)?;
+        Ok(FrameAnalyzer::diff(&reference, &ours))
+    }
+
     /// Parse an `.ass` document into a reusable track (for benchmarking, so the
     /// parse cost is not paid per frame).
     pub fn read_track(&self, ass_text: &str) -> Result<LibassTrack, RenderError> {
@@ -40,12 +40,12 @@ pub mod benchmarking;
 #[cfg(feature = "libass-compare")]
 pub mod libass;
 
-pub use analyzer::{AnalysisReport, FrameAnalyzer};
+pub use analyzer::{AnalysisReport, FrameAnalyzer, FrameDiff};
 pub use benchmarking::{
     quick_benchmark, BenchmarkConfig, BenchmarkResult, PerformanceBenchmark, PerformanceMetrics,
 };
 pub use info::{BoundingBoxInfo, DirtyRegionInfo, FrameComparison, FrameDebugInfo};
-pub use inspector::FrameInspector;
+pub use inspector::{FrameInspector, Rgba};
 pub use player::{DebugPlayer, PlayerFrame};
 
 use util::{calculate_checksum, draw_rectangle, draw_text_overlay, save_frame_as_png};
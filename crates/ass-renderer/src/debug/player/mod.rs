@@ -1,13 +1,10 @@
-use crate::{BackendType, Frame, RenderContext, RenderError, Renderer};
-use ass_core::parser::Script;
+use crate::cache::{EvictionPolicy, FrameCache};
+use crate::{BackendType, RenderContext, RenderError, Renderer};
+use ass_core::parser::{OwnedScript, Script};
 
-#[cfg(not(feature = "nostd"))]
-use std::collections::HashMap;
 #[cfg(not(feature = "nostd"))]
 use std::time::Instant;
 
-#[cfg(feature = "nostd")]
-use alloc::collections::BTreeMap as HashMap;
 #[cfg(feature = "nostd")]
 use alloc::string::String;
 
@@ -21,22 +18,23 @@ pub use report::{PlayerFrame, TestPoint, TestReport};
 /// Debug player for visual verification of subtitle rendering
 pub struct DebugPlayer {
     renderer: Renderer,
-    script_content: Option<String>,
-    #[allow(dead_code)] // Debug feature - may be used for future player functionality
-    parsed_script: Option<Script<'static>>,
+    script: Option<OwnedScript<'static>>,
     current_time_ms: u32,
     playback_speed: f32,
     is_playing: bool,
-    frame_interval_ms: u32,
+    /// Frames per second used for frame stepping and numbering
+    fps: f64,
     output_dir: String,
     save_frames: bool,
     show_stats: bool,
     loop_playback: bool,
     start_time_ms: u32,
     end_time_ms: u32,
-    frame_cache: HashMap<u32, Frame>,
+    frame_cache: FrameCache,
     cache_enabled: bool,
     max_cache_size: usize,
+    /// Last rendered frame and the time it was rendered at
+    current: Option<(u32, PlayerFrame)>,
     // Timing fields for proper synchronization
     playback_start_instant: Option<Instant>,
     playback_start_time_ms: u32,
@@ -52,29 +50,56 @@ impl DebugPlayer {
 
         let renderer = Renderer::new(backend_type, context)?;
 
-        Ok(Self {
+        Ok(Self::with_renderer(renderer, 25.0, width, height))
+    }
+
+    /// Create a player for `script` at `fps` frames per second
+    ///
+    /// Renders on the software backend at the script's play resolution, with
+    /// every entry of `fonts` (TTF/OTF bytes) registered alongside the system
+    /// fonts. Stats printing is off, and the first frame is rendered so
+    /// [`DebugPlayer::current_frame`] is ready.
+    pub fn from_script(script: &Script, fonts: &[&[u8]], fps: f64) -> Result<Self, RenderError> {
+        if !fps.is_finite() || fps <= 0.0 {
+            return Err(RenderError::InvalidInput(format!(
+                "Invalid frame rate {fps}"
+            )));
+        }
+        let renderer = Renderer::from_script(script, fonts, BackendType::Software)?;
+        let (width, height) = script.info().play_resolution();
+
+        let mut player = Self::with_renderer(renderer, fps, width, height);
+        player.show_stats = false;
+        player.set_script(script.clone().into_owned());
+        player.render_current_frame()?;
+        Ok(player)
+    }
+
+    fn with_renderer(renderer: Renderer, fps: f64, width: u32, height: u32) -> Self {
+        let max_cache_size = 100; // Cache up to 100 frames
+        Self {
             renderer,
-            script_content: None,
-            parsed_script: None,
+            script: None,
             current_time_ms: 0,
             playback_speed: 1.0,
             is_playing: false,
-            frame_interval_ms: 40, // ~25 FPS for subtitle granularity
+            fps,
             output_dir: "debug_player_output".to_string(),
             save_frames: false,
             show_stats: true,
             loop_playback: false,
             start_time_ms: 0,
             end_time_ms: 0, // Default to 0, will be set when script is loaded
-            frame_cache: HashMap::new(),
+            frame_cache: FrameCache::new(EvictionPolicy::MaxEntries(max_cache_size)),
             cache_enabled: true,
-            max_cache_size: 100, // Cache up to 100 frames
+            max_cache_size,
+            current: None,
             playback_start_instant: None,
             playback_start_time_ms: 0,
             accumulated_time_ms: 0.0,
             width,
             height,
-        })
+        }
     }
 
     pub fn width(&self) -> u32 {
@@ -85,9 +110,14 @@ impl DebugPlayer {
         self.height
     }
 
+    /// Frames per second used for frame stepping
+    pub fn fps(&self) -> f64 {
+        self.fps
+    }
+
     /// Check if a script is currently loaded
     pub fn has_script(&self) -> bool {
-        self.script_content.is_some()
+        self.script.is_some()
     }
 
     /// Get the current end time in milliseconds
@@ -96,18 +126,37 @@ impl DebugPlayer {
     }
 
     pub fn load_script(&mut self, script_content: &str) -> Result<(), RenderError> {
-        // Parse and store the script to avoid re-parsing on every frame
-        let owned_content = script_content.to_string();
-        let script = Script::parse(&owned_content)
+        // Parse once and keep an owned copy to avoid re-parsing on every frame
+        let script = OwnedScript::parse(script_content.to_string())
             .map_err(|e| RenderError::ParseError(format!("Failed to parse script: {e:?}")))?;
+        let event_count = script
+            .script()
+            .sections()
+            .iter()
+            .map(|section| match section {
+                ass_core::parser::Section::Events(events) => events.len(),
+                _ => 0,
+            })
+            .sum::<usize>();
+
+        self.set_script(script);
+
+        println!(
+            "Script loaded. Duration: {duration}ms, Events: {events}",
+            duration = self.end_time_ms,
+            events = event_count
+        );
 
+        Ok(())
+    }
+
+    /// Replace the loaded script, resetting the duration and cached frames
+    fn set_script(&mut self, script: OwnedScript<'static>) {
         // Calculate actual end time from events
         let mut max_time = 0u32;
-        let mut event_count = 0;
-        for section in script.sections() {
+        for section in script.script().sections() {
             if let ass_core::parser::Section::Events(events) = section {
                 for event in events.iter() {
-                    event_count += 1;
                     if let Ok(end) = event.end_time_cs() {
                         max_time = max_time.max(end * 10); // Convert centiseconds to milliseconds
                     }
@@ -124,19 +173,8 @@ impl DebugPlayer {
         }
 
         // Clear cache when loading new script
-        self.frame_cache.clear();
-
-        // Store both the content and parsed script
-        self.script_content = Some(owned_content);
-        // Note: We can't store the parsed script due to lifetime issues
-        // We'll need to parse on demand but cache rendered frames
-
-        println!(
-            "Script loaded. Duration: {duration}ms, Events: {events}",
-            duration = self.end_time_ms,
-            events = event_count
-        );
-
-        Ok(())
+        self.frame_cache.invalidate_all();
+        self.current = None;
+        self.script = Some(script);
     }
 }
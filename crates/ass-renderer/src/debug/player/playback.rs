@@ -1,3 +1,7 @@
+use super::PlayerFrame;
+use crate::cache::{EvictionPolicy, FrameCache, FrameCacheStats};
+use crate::RenderError;
+
 #[cfg(not(feature = "nostd"))]
 use std::time::Instant;
 
@@ -5,19 +9,19 @@ impl super::DebugPlayer {
     pub fn set_cache_enabled(&mut self, enabled: bool) {
         self.cache_enabled = enabled;
         if !enabled {
-            self.frame_cache.clear();
+            self.frame_cache.invalidate_all();
         }
     }
 
+    /// Limit the cache to `size` frames, dropping every cached frame
     pub fn set_max_cache_size(&mut self, size: usize) {
         self.max_cache_size = size;
-        // Trim cache if needed
-        while self.frame_cache.len() > size {
-            // Remove oldest entries (not optimal but simple)
-            if let Some(&min_key) = self.frame_cache.keys().min() {
-                self.frame_cache.remove(&min_key);
-            }
-        }
+        self.frame_cache = FrameCache::new(EvictionPolicy::MaxEntries(size));
+    }
+
+    /// Hit, miss and eviction counts of the frame cache
+    pub fn cache_stats(&self) -> FrameCacheStats {
+        self.frame_cache.stats()
     }
 
     pub fn play(&mut self) {
@@ -55,17 +59,21 @@ impl super::DebugPlayer {
         println!("⏹️  Playback stopped");
     }
 
-    pub fn seek(&mut self, time_ms: u32) {
-        self.current_time_ms = time_ms.min(self.end_time_ms);
-        if self.is_playing {
-            self.playback_start_instant = Some(Instant::now());
-            self.playback_start_time_ms = self.current_time_ms;
-            self.accumulated_time_ms = 0.0;
+    /// Move to the frame shown at `time_sec` seconds and render it
+    ///
+    /// Times past the last frame seek to the last frame; non-finite times
+    /// are rejected with [`RenderError::InvalidTimestamp`].
+    pub fn seek(&mut self, time_sec: f64) -> Result<&PlayerFrame, RenderError> {
+        if !time_sec.is_finite() {
+            return Err(RenderError::InvalidTimestamp {
+                value: time_sec,
+                reason: "seek time must be finite".to_string(),
+            });
         }
-        println!(
-            "⏩ Seeked to {current_time}ms",
-            current_time = self.current_time_ms
-        );
+        let time_ms = (time_sec.max(0.0) * 1000.0).min(f64::from(u32::MAX)) as u32;
+        let index = self.frame_index_at(time_ms).min(self.total_frames() - 1);
+        self.move_to(self.frame_start_ms(index));
+        self.render_current_frame()
     }
 
     pub fn set_speed(&mut self, speed: f32) {
@@ -82,31 +90,63 @@ impl super::DebugPlayer {
         println!("🎚️  Playback speed: {speed}x", speed = self.playback_speed);
     }
 
-    pub fn step_forward(&mut self) {
-        self.current_time_ms =
-            (self.current_time_ms + self.frame_interval_ms).min(self.end_time_ms);
-        if self.is_playing {
-            self.playback_start_instant = Some(Instant::now());
-            self.playback_start_time_ms = self.current_time_ms;
-            self.accumulated_time_ms = 0.0;
+    /// Move to the next frame and render it, staying on the last frame
+    pub fn step_forward(&mut self) -> Result<&PlayerFrame, RenderError> {
+        let index = (self.frame_index_at(self.current_time_ms) + 1).min(self.total_frames() - 1);
+        self.move_to(self.frame_start_ms(index));
+        self.render_current_frame()
+    }
+
+    /// Move to the previous frame and render it, staying on the first frame
+    ///
+    /// Frames already rendered come from the frame cache unless evicted.
+    pub fn step_backward(&mut self) -> Result<&PlayerFrame, RenderError> {
+        let index = self.frame_index_at(self.current_time_ms).saturating_sub(1);
+        self.move_to(self.frame_start_ms(index));
+        self.render_current_frame()
+    }
+
+    /// The frame at the current position, rendering it if the position
+    /// changed since the last render
+    pub fn current_frame(&mut self) -> Result<&PlayerFrame, RenderError> {
+        if self
+            .current
+            .as_ref()
+            .is_none_or(|(time_ms, _)| *time_ms != self.current_time_ms)
+        {
+            self.render_current_frame()?;
         }
-        println!(
-            "⏭️  Step forward to {current_time}ms",
-            current_time = self.current_time_ms
-        );
+        self.current
+            .as_ref()
+            .map(|(_, frame)| frame)
+            .ok_or_else(|| RenderError::InvalidInput("No script loaded".into()))
+    }
+
+    /// Number of frames starting before the end of the script, at least one
+    pub fn total_frames(&self) -> usize {
+        ((f64::from(self.end_time_ms) * self.fps / 1000.0 - 1e-9).ceil() as usize).max(1)
+    }
+
+    /// Index of the frame shown at `time_ms`
+    pub(super) fn frame_index_at(&self, time_ms: u32) -> usize {
+        (f64::from(time_ms) * self.fps / 1000.0 + 1e-9).floor() as usize
+    }
+
+    /// First whole millisecond of frame `index`
+    pub(super) fn frame_start_ms(&self, index: usize) -> u32 {
+        (index as f64 * 1000.0 / self.fps - 1e-9)
+            .ceil()
+            .clamp(0.0, f64::from(u32::MAX)) as u32
     }
 
-    pub fn step_backward(&mut self) {
-        self.current_time_ms = self.current_time_ms.saturating_sub(self.frame_interval_ms);
+    /// Jump to `time_ms`, restarting the playback clock if playing
+    fn move_to(&mut self, time_ms: u32) {
+        self.current_time_ms = time_ms;
         if self.is_playing {
             self.playback_start_instant = Some(Instant::now());
             self.playback_start_time_ms = self.current_time_ms;
             self.accumulated_time_ms = 0.0;
         }
-        println!(
-            "⏮️  Step backward to {current_time}ms",
-            current_time = self.current_time_ms
-        );
     }
 
     pub fn toggle_stats(&mut self) {
@@ -1,72 +1,65 @@
 use super::PlayerFrame;
 use crate::RenderError;
-use ass_core::parser::Script;
+use ass_core::parser::{Script, Section};
 
+#[cfg(not(feature = "nostd"))]
+use std::path::Path;
+#[cfg(not(feature = "nostd"))]
+use std::sync::Arc;
 #[cfg(not(feature = "nostd"))]
 use std::time::{Duration, Instant};
 
 #[cfg(feature = "nostd")]
 use alloc::vec::Vec;
 
-impl super::DebugPlayer {
-    pub fn render_current_frame(&mut self) -> Result<PlayerFrame, RenderError> {
-        // Check cache first if enabled
-        if self.cache_enabled {
-            // Round to nearest frame interval for better cache hits
-            let cache_key =
-                (self.current_time_ms / self.frame_interval_ms) * self.frame_interval_ms;
-
-            if let Some(cached_frame) = self.frame_cache.get(&cache_key) {
-                let player_frame = PlayerFrame {
-                    frame: cached_frame.clone(),
-                    timestamp_ms: self.current_time_ms,
-                    render_time: Duration::from_micros(100), // Cached, so very fast
-                    frame_number: (self.current_time_ms / self.frame_interval_ms),
-                };
-
-                if self.show_stats {
-                    println!("📦 Using cached frame for {cache_key}ms");
-                }
-
-                return Ok(player_frame);
-            }
-        }
+/// Font scale the player's cached frames are keyed by
+const CACHE_FONT_SCALE: f32 = 1.0;
 
-        let script_content = self
-            .script_content
+impl super::DebugPlayer {
+    /// Render the frame at the current position, reusing a cached frame
+    /// when there is one
+    pub fn render_current_frame(&mut self) -> Result<&PlayerFrame, RenderError> {
+        let script = self
+            .script
             .as_ref()
-            .ok_or_else(|| RenderError::InvalidInput("No script loaded".into()))?;
-
-        let script = Script::parse(script_content)
-            .map_err(|e| RenderError::ParseError(format!("Failed to parse script: {e:?}")))?;
+            .ok_or_else(|| RenderError::InvalidInput("No script loaded".into()))?
+            .script();
 
         let start = Instant::now();
         // Convert milliseconds to centiseconds for the renderer
         let time_cs = self.current_time_ms / 10;
-        let frame = self.renderer.render_frame(&script, time_cs)?;
-        let render_time = start.elapsed();
-
-        // Cache the frame if enabled
-        if self.cache_enabled {
-            let cache_key =
-                (self.current_time_ms / self.frame_interval_ms) * self.frame_interval_ms;
-
-            // Maintain cache size limit
-            if self.frame_cache.len() >= self.max_cache_size {
-                // Remove oldest entry
-                if let Some(&min_key) = self.frame_cache.keys().min() {
-                    self.frame_cache.remove(&min_key);
+        let cached = if self.cache_enabled {
+            self.frame_cache
+                .get(time_cs, self.width, self.height, CACHE_FONT_SCALE)
+        } else {
+            None
+        };
+        let frame = match cached {
+            Some(frame) => frame,
+            None => {
+                let frame = self.renderer.render_frame(&script, time_cs)?;
+                if self.cache_enabled {
+                    self.frame_cache.insert(
+                        time_cs,
+                        self.width,
+                        self.height,
+                        CACHE_FONT_SCALE,
+                        frame,
+                    )
+                } else {
+                    Arc::new(frame)
                 }
             }
-
-            self.frame_cache.insert(cache_key, frame.clone());
-        }
+        };
 
         let player_frame = PlayerFrame {
-            frame: frame.clone(),
-            timestamp_ms: self.current_time_ms,
-            render_time,
-            frame_number: (self.current_time_ms / self.frame_interval_ms),
+            rgba_data: frame.pixels().to_vec(),
+            width: frame.width(),
+            height: frame.height(),
+            timestamp_sec: f64::from(self.current_time_ms) / 1000.0,
+            frame_number: self.frame_index_at(self.current_time_ms),
+            visible_events: visible_events(&script, time_cs),
+            render_time_us: u64::try_from(start.elapsed().as_micros()).unwrap_or(u64::MAX),
         };
 
         if self.show_stats {
@@ -74,13 +67,14 @@ impl super::DebugPlayer {
         }
 
         if self.save_frames {
-            self.save_frame(&player_frame)?;
+            self.save_frame(&player_frame, Path::new(&self.output_dir))?;
         }
 
-        Ok(player_frame)
+        let (_, frame) = self.current.insert((self.current_time_ms, player_frame));
+        Ok(frame)
     }
 
-    pub fn update(&mut self, _delta_time: Duration) -> Result<Option<PlayerFrame>, RenderError> {
+    pub fn update(&mut self, _delta_time: Duration) -> Result<Option<&PlayerFrame>, RenderError> {
         if !self.is_playing {
             return Ok(None);
         }
@@ -111,15 +105,42 @@ impl super::DebugPlayer {
         self.render_current_frame().map(Some)
     }
 
-    fn print_frame_stats(&self, player_frame: &PlayerFrame) {
-        let pixels = player_frame.frame.pixels();
-        let mut non_transparent = 0;
-
-        for chunk in pixels.chunks(4) {
-            if chunk.len() == 4 && chunk[3] > 0 {
-                non_transparent += 1;
-            }
+    /// Render every frame starting within `start..=end` seconds and save
+    /// each as a PNG file in `dir`, returning how many were written
+    ///
+    /// `dir` is created if missing. Files are named like frames saved during
+    /// playback, and the player returns to its previous position afterwards.
+    #[cfg(feature = "image")]
+    pub fn dump_frames(&mut self, start: f64, end: f64, dir: &Path) -> Result<usize, RenderError> {
+        if !start.is_finite() || !end.is_finite() || start > end {
+            return Err(RenderError::InvalidInput(format!(
+                "Invalid frame range {start}..{end}"
+            )));
+        }
+        std::fs::create_dir_all(dir).map_err(|e| {
+            RenderError::BackendError(format!("Failed to create {}: {e}", dir.display()))
+        })?;
+
+        let first = (start.max(0.0) * self.fps - 1e-9).ceil() as usize;
+        let last = ((end * self.fps + 1e-9).floor() as usize).min(self.total_frames() - 1);
+        let position = self.current_time_ms;
+        let mut written = 0;
+        for index in first..=last {
+            self.current_time_ms = self.frame_start_ms(index);
+            let frame = self.render_current_frame()?.clone();
+            self.save_frame(&frame, dir)?;
+            written += 1;
         }
+        self.current_time_ms = position;
+        Ok(written)
+    }
+
+    fn print_frame_stats(&self, player_frame: &PlayerFrame) {
+        let non_transparent = player_frame
+            .rgba_data
+            .chunks_exact(4)
+            .filter(|pixel| pixel[3] > 0)
+            .count();
 
         println!("┌────────────────────────────────────┐");
         println!(
@@ -132,7 +153,7 @@ impl super::DebugPlayer {
         println!("├────────────────────────────────────┤");
         println!(
             "│ Render: {:.2}ms                    │",
-            player_frame.render_time.as_secs_f64() * 1000.0
+            player_frame.render_time_us as f64 / 1000.0
         );
         println!("│ Visible pixels: {non_transparent:6}            │");
         println!(
@@ -143,27 +164,52 @@ impl super::DebugPlayer {
         println!("└────────────────────────────────────┘");
     }
 
-    fn save_frame(&self, player_frame: &PlayerFrame) -> Result<(), RenderError> {
-        let path = format!(
-            "{}/frame_{:06}_{:06}ms.png",
-            self.output_dir, player_frame.frame_number, player_frame.timestamp_ms
-        );
+    fn save_frame(&self, player_frame: &PlayerFrame, dir: &Path) -> Result<(), RenderError> {
+        let path = dir.join(format!(
+            "frame_{:06}_{:06}ms.png",
+            player_frame.frame_number,
+            (player_frame.timestamp_sec * 1000.0).round() as u64
+        ));
 
         #[cfg(feature = "image")]
         {
             use image::{ImageBuffer, Rgba};
 
             let img = ImageBuffer::<Rgba<u8>, Vec<u8>>::from_raw(
-                player_frame.frame.width(),
-                player_frame.frame.height(),
-                player_frame.frame.pixels().to_vec(),
+                player_frame.width,
+                player_frame.height,
+                player_frame.rgba_data.clone(),
             )
             .ok_or_else(|| RenderError::BackendError("Failed to create image buffer".into()))?;
 
             img.save(&path)
                 .map_err(|e| RenderError::BackendError(format!("Failed to save frame: {e}")))?;
         }
+        #[cfg(not(feature = "image"))]
+        let _ = path;
 
         Ok(())
     }
 }
+
+/// Indices of the dialogue events of `script` shown at `time_cs`
+fn visible_events(script: &Script, time_cs: u32) -> Vec<usize> {
+    script
+        .sections()
+        .iter()
+        .filter_map(|section| match section {
+            Section::Events(events) => Some(events.iter()),
+            _ => None,
+        })
+        .flatten()
+        .enumerate()
+        .filter(|(_, event)| {
+            event.is_dialogue()
+                && matches!(
+                    (event.start_time_cs(), event.end_time_cs()),
+                    (Ok(start), Ok(end)) if start <= time_cs && time_cs < end
+                )
+        })
+        .map(|(index, _)| index)
+        .collect()
+}
@@ -1,16 +1,25 @@
-use crate::Frame;
-
 #[cfg(not(feature = "nostd"))]
 use std::time::Duration;
 
 #[cfg(feature = "nostd")]
 use alloc::vec::Vec;
 
+/// A frame rendered by the [`DebugPlayer`](super::DebugPlayer)
+#[derive(Debug, Clone, PartialEq)]
 pub struct PlayerFrame {
-    pub frame: Frame,
-    pub timestamp_ms: u32,
-    pub render_time: Duration,
-    pub frame_number: u32,
+    /// RGBA pixels, row by row
+    pub rgba_data: Vec<u8>,
+    pub width: u32,
+    pub height: u32,
+    /// Playback position of the frame in seconds
+    pub timestamp_sec: f64,
+    /// Index of the frame at the player's frame rate
+    pub frame_number: usize,
+    /// Indices of the dialogue events shown, counting every event of the
+    /// script in document order
+    pub visible_events: Vec<usize>,
+    /// Time taken to produce the frame, including cache lookups
+    pub render_time_us: u64,
 }
 
 #[derive(Debug)]
@@ -1,6 +1,5 @@
 use super::{TestPoint, TestReport};
 use crate::RenderError;
-use ass_core::parser::Script;
 
 #[cfg(not(feature = "nostd"))]
 use std::time::{Duration, Instant};
@@ -10,13 +9,11 @@ use alloc::vec::Vec;
 
 impl super::DebugPlayer {
     pub fn run_automatic_test(&mut self, test_points: Vec<u32>) -> Result<TestReport, RenderError> {
-        let script_content = self
-            .script_content
+        let script = self
+            .script
             .as_ref()
-            .ok_or_else(|| RenderError::InvalidInput("No script loaded".into()))?;
-
-        let script = Script::parse(script_content)
-            .map_err(|e| RenderError::ParseError(format!("Failed to parse script: {e:?}")))?;
+            .ok_or_else(|| RenderError::InvalidInput("No script loaded".into()))?
+            .script();
 
         let mut report = TestReport {
             test_points: Vec::new(),
@@ -5,9 +5,15 @@ pub mod context;
 pub mod metrics;
 pub mod multiline;
 pub mod positioning;
+pub mod rotation;
+pub mod transform;
+pub mod wrap;
 
 pub use alignment::Alignment;
 pub use context::LayoutContext;
 pub use metrics::TextMetrics;
 pub use multiline::{LineLayout, MultiLineLayout};
 pub use positioning::{convert_ssa_alignment, scale_coordinates, BoundingBox, PositionInfo};
+pub use rotation::Rotation3D;
+pub use transform::CoordinateTransform;
+pub use wrap::{TextDirection, TextLayoutEngine, WrapMode, WrappedLine};
@@ -0,0 +1,111 @@
+//! `\frx`/`\fry`/`\frz` rotation and perspective projection
+
+/// Camera distance per script pixel of `PlayResY`
+///
+/// Rotated text is viewed through a pinhole camera this many `PlayResY`
+/// heights away from the screen plane, so perspective looks the same at
+/// every script resolution.
+pub const FOCAL_LENGTH_RATIO: f32 = 0.886;
+
+/// Rotation of an event around the three screen axes, in degrees
+///
+/// `z` turns counter-clockwise in the screen plane (`\frz`, `\fr`, the
+/// style's `Angle`); `x` tilts around the horizontal axis (`\frx`) and `y`
+/// around the vertical one (`\fry`). Rotations compose in libass order:
+/// Z first, then X, then Y.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Rotation3D {
+    pub x: f32,
+    pub y: f32,
+    pub z: f32,
+}
+
+impl Rotation3D {
+    /// Rotation by `z` degrees in the screen plane only
+    pub const fn z(z: f32) -> Self {
+        Self { x: 0.0, y: 0.0, z }
+    }
+
+    /// Whether the rotation leaves the screen plane and needs a perspective
+    /// projection rather than an affine transform
+    pub fn is_perspective(&self) -> bool {
+        self.x != 0.0 || self.y != 0.0
+    }
+
+    /// Camera distance for a script with the given `PlayResY`, in the same
+    /// units as `play_res_y`
+    pub fn focal_length(play_res_y: f32) -> f32 {
+        play_res_y * FOCAL_LENGTH_RATIO
+    }
+
+    /// Project the offset `(dx, dy)` from the rotation centre onto the
+    /// screen through a camera `focal_length` away
+    ///
+    /// Offsets use screen orientation (y grows downwards). Points that end
+    /// up behind the camera are clamped just in front of it.
+    pub fn project(&self, dx: f32, dy: f32, focal_length: f32) -> (f32, f32) {
+        let (sin_z, cos_z) = self.z.to_radians().sin_cos();
+        let x1 = dx * cos_z + dy * sin_z;
+        let y1 = dy * cos_z - dx * sin_z;
+
+        // The text starts in the z = 0 plane; libass tilts it with
+        // sx = -sin(frx) and sy = sin(fry)
+        let (sin_x, cos_x) = self.x.to_radians().sin_cos();
+        let y2 = y1 * cos_x;
+        let z2 = -y1 * sin_x;
+
+        let (sin_y, cos_y) = self.y.to_radians().sin_cos();
+        let x3 = x1 * cos_y - z2 * sin_y;
+        let z3 = x1 * sin_y + z2 * cos_y;
+
+        let scale = focal_length / (z3 + focal_length).max(0.1);
+        (x3 * scale, y2 * scale)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_close(actual: (f32, f32), expected: (f32, f32)) {
+        assert!(
+            (actual.0 - expected.0).abs() < 1e-3 && (actual.1 - expected.1).abs() < 1e-3,
+            "{actual:?} != {expected:?}"
+        );
+    }
+
+    #[test]
+    fn identity_keeps_points() {
+        assert_close(
+            Rotation3D::default().project(12.0, -4.0, 500.0),
+            (12.0, -4.0),
+        );
+        assert!(!Rotation3D::z(30.0).is_perspective());
+    }
+
+    #[test]
+    fn z_rotation_is_counter_clockwise() {
+        // A point to the right of the centre ends up above it
+        assert_close(Rotation3D::z(90.0).project(10.0, 0.0, 500.0), (0.0, -10.0));
+    }
+
+    #[test]
+    fn x_rotation_foreshortens_vertically() {
+        let rotation = Rotation3D {
+            x: 60.0,
+            ..Rotation3D::default()
+        };
+        assert!(rotation.is_perspective());
+        let (x, y) = rotation.project(0.0, 100.0, 1000.0);
+        assert_eq!(x, 0.0);
+        assert!(y > 0.0 && y < 100.0);
+        // Perspective makes the near and far halves differ in height
+        let (_, up) = rotation.project(0.0, -100.0, 1000.0);
+        assert!((up + y).abs() > 1.0);
+    }
+
+    #[test]
+    fn focal_length_follows_play_res_y() {
+        assert_eq!(Rotation3D::focal_length(1000.0), 886.0);
+    }
+}
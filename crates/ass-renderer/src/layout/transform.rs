@@ -0,0 +1,124 @@
+//! Script (`PlayRes`) to output pixel coordinate transform
+
+/// Maps `PlayResX`/`PlayResY` coordinates to physical output pixels
+///
+/// The subtitle area keeps the script's aspect ratio: it is scaled uniformly
+/// to fit the output and centered, leaving letterbox (top/bottom) or
+/// pillarbox (left/right) bars when the aspect ratios differ. The display
+/// scale factor is folded into the scales and offsets, so on a 2x HiDPI
+/// display every position comes out doubled.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CoordinateTransform {
+    /// Physical pixels per script unit horizontally
+    pub scale_x: f64,
+    /// Physical pixels per script unit vertically
+    pub scale_y: f64,
+    /// Physical left edge of the subtitle area
+    pub offset_x: f64,
+    /// Physical top edge of the subtitle area
+    pub offset_y: f64,
+    /// Physical pixels per logical output pixel
+    pub pixel_ratio: f64,
+}
+
+impl CoordinateTransform {
+    /// Fit a `play_res_x` x `play_res_y` script into a logical
+    /// `output_width` x `output_height` area on a display scaled by
+    /// `display_scale` (non-positive or non-finite factors mean 1.0)
+    pub fn new(
+        play_res_x: f64,
+        play_res_y: f64,
+        output_width: f64,
+        output_height: f64,
+        display_scale: f64,
+    ) -> Self {
+        let pixel_ratio = if display_scale.is_finite() && display_scale > 0.0 {
+            display_scale
+        } else {
+            1.0
+        };
+        let play_res_x = play_res_x.max(1.0);
+        let play_res_y = play_res_y.max(1.0);
+        let scale = (output_width / play_res_x).min(output_height / play_res_y);
+
+        Self {
+            scale_x: scale * pixel_ratio,
+            scale_y: scale * pixel_ratio,
+            offset_x: (output_width - play_res_x * scale) / 2.0 * pixel_ratio,
+            offset_y: (output_height - play_res_y * scale) / 2.0 * pixel_ratio,
+            pixel_ratio,
+        }
+    }
+
+    /// Convert a point in script coordinates to physical output pixels
+    pub fn to_output(&self, x: f64, y: f64) -> (f64, f64) {
+        (
+            self.offset_x + x * self.scale_x,
+            self.offset_y + y * self.scale_y,
+        )
+    }
+
+    /// Convert a point in physical output pixels back to script coordinates
+    pub fn to_script(&self, x: f64, y: f64) -> (f64, f64) {
+        (
+            (x - self.offset_x) / self.scale_x,
+            (y - self.offset_y) / self.scale_y,
+        )
+    }
+}
+
+impl Default for CoordinateTransform {
+    fn default() -> Self {
+        Self {
+            scale_x: 1.0,
+            scale_y: 1.0,
+            offset_x: 0.0,
+            offset_y: 0.0,
+            pixel_ratio: 1.0,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn matching_aspect_scales_without_bars() {
+        let transform = CoordinateTransform::new(640.0, 360.0, 1280.0, 720.0, 1.0);
+        assert_eq!(transform.scale_x, 2.0);
+        assert_eq!(transform.scale_y, 2.0);
+        assert_eq!(transform.to_output(320.0, 180.0), (640.0, 360.0));
+    }
+
+    #[test]
+    fn narrower_script_is_pillarboxed() {
+        let transform = CoordinateTransform::new(384.0, 288.0, 1920.0, 1080.0, 1.0);
+        assert_eq!(transform.scale_x, 3.75);
+        assert_eq!(transform.offset_x, 240.0);
+        assert_eq!(transform.offset_y, 0.0);
+        assert_eq!(transform.to_output(384.0, 288.0), (1680.0, 1080.0));
+    }
+
+    #[test]
+    fn wider_script_is_letterboxed() {
+        let transform = CoordinateTransform::new(1920.0, 1080.0, 640.0, 480.0, 1.0);
+        assert_eq!(transform.offset_x, 0.0);
+        assert_eq!(transform.offset_y, 60.0);
+    }
+
+    #[test]
+    fn display_scale_doubles_positions() {
+        let base = CoordinateTransform::new(640.0, 360.0, 320.0, 240.0, 1.0);
+        let hidpi = CoordinateTransform::new(640.0, 360.0, 320.0, 240.0, 2.0);
+        let (x, y) = base.to_output(100.0, 200.0);
+        assert_eq!(hidpi.to_output(100.0, 200.0), (x * 2.0, y * 2.0));
+        assert_eq!(hidpi.to_script(x * 2.0, y * 2.0), (100.0, 200.0));
+    }
+
+    #[test]
+    fn invalid_display_scale_falls_back_to_one() {
+        let transform = CoordinateTransform::new(640.0, 360.0, 640.0, 360.0, f64::NAN);
+        assert_eq!(transform, CoordinateTransform::default());
+    }
+}
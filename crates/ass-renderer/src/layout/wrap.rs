@@ -0,0 +1,488 @@
+//! Glyph-level line wrapping for the `\q` / `WrapStyle` modes
+
+#[cfg(feature = "nostd")]
+use alloc::vec::Vec;
+#[cfg(not(feature = "nostd"))]
+use std::vec::Vec;
+
+use crate::pipeline::shaping::ShapedGlyph;
+
+pub use ass_core::parser::ast::WrapMode;
+
+/// Inline direction of laid-out text
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TextDirection {
+    /// Horizontal lines, glyphs advancing along `x`
+    #[default]
+    LeftToRight,
+    /// Vertical lines, glyphs advancing along `y`
+    ///
+    /// The layout axes are rotated by 90°: each glyph's horizontal advance
+    /// becomes its vertical advance and its shaping offsets are swapped.
+    /// Not yet selected by the render pipeline.
+    TopToBottom,
+}
+
+/// One wrapped line of glyphs
+#[derive(Debug, Clone)]
+pub struct WrappedLine {
+    /// Glyphs on this line, re-positioned so the line starts at `x = 0`
+    /// (`y = 0` for vertical text)
+    pub glyphs: Vec<ShapedGlyph>,
+    /// Line extent along the inline axis in pixels, including letter
+    /// spacing between glyphs
+    pub width: f32,
+}
+
+/// Breaks a shaped logical line into wrapped lines for a given `WrapMode`
+///
+/// Break opportunities are the space glyphs of the source text; a single
+/// word wider than `max_width` overflows rather than being split. With CJK
+/// line breaking enabled, breaks are also permitted inside words wherever
+/// the Unicode line-breaking rules allow, such as between two ideographs,
+/// but never before closing punctuation like `、` or `。`. Explicit `\N`
+/// breaks are expected to have been split into separate logical lines
+/// before layout.
+#[derive(Debug, Clone, Copy)]
+pub struct TextLayoutEngine {
+    max_width: f32,
+    mode: WrapMode,
+    letter_spacing: f32,
+    cjk_line_breaking: bool,
+    direction: TextDirection,
+}
+
+impl TextLayoutEngine {
+    /// Create an engine wrapping to `max_width` pixels
+    pub fn new(max_width: f32, mode: WrapMode) -> Self {
+        Self {
+            max_width,
+            mode,
+            letter_spacing: 0.0,
+            cjk_line_breaking: false,
+            direction: TextDirection::LeftToRight,
+        }
+    }
+
+    /// Set the `\fsp` letter spacing (pixels added between glyphs)
+    pub fn with_letter_spacing(mut self, spacing: f32) -> Self {
+        self.letter_spacing = spacing;
+        self
+    }
+
+    /// Allow breaks between CJK characters, not only at spaces
+    ///
+    /// Break opportunities follow UAX #14 when the `unicode-wrap` feature is
+    /// enabled, and a built-in subset of its CJK rules otherwise. Combined
+    /// with [`WrapMode::Smart`] this balances CJK text evenly across lines.
+    pub fn with_cjk_line_breaking(mut self, enabled: bool) -> Self {
+        self.cjk_line_breaking = enabled;
+        self
+    }
+
+    /// Set the inline direction lines are laid out in
+    pub fn with_direction(mut self, direction: TextDirection) -> Self {
+        self.direction = direction;
+        self
+    }
+
+    /// Lay out `glyphs` shaped from `text` (clusters index into `text`)
+    pub fn layout(&self, text: &str, glyphs: &[ShapedGlyph]) -> Vec<WrappedLine> {
+        let words = Self::words(text, glyphs, &self.break_offsets(text));
+        if words.len() <= 1 || !self.mode.wraps() {
+            return vec![self.build_line(glyphs)];
+        }
+
+        let starts = self.break_lines(words.len(), |a, b| {
+            self.run_width(&glyphs[words[a].0..words[b].1])
+        });
+        let mut lines = Vec::with_capacity(starts.len());
+        for (k, &first) in starts.iter().enumerate() {
+            let last = starts.get(k + 1).map_or(words.len(), |&next| next) - 1;
+            lines.push(self.build_line(&glyphs[words[first].0..words[last].1]));
+        }
+        lines
+    }
+
+    /// Glyph index ranges `(start, end)` of each unbreakable segment
+    ///
+    /// Segments are separated by spaces and split further at the glyph
+    /// starting each cluster listed in `breaks` (byte offsets, ascending).
+    fn words(text: &str, glyphs: &[ShapedGlyph], breaks: &[usize]) -> Vec<(usize, usize)> {
+        let is_space = |g: &ShapedGlyph| {
+            text.get(g.cluster as usize..)
+                .and_then(|rest| rest.chars().next())
+                .is_some_and(|c| c == ' ')
+        };
+        let mut words = Vec::new();
+        let mut start = None;
+        for (i, glyph) in glyphs.iter().enumerate() {
+            let breaks_before = i > 0
+                && glyphs[i - 1].cluster != glyph.cluster
+                && breaks.binary_search(&(glyph.cluster as usize)).is_ok();
+            match (is_space(glyph), start) {
+                (true, Some(s)) => {
+                    words.push((s, i));
+                    start = None;
+                }
+                (false, None) => start = Some(i),
+                (false, Some(s)) if breaks_before => {
+                    words.push((s, i));
+                    start = Some(i);
+                }
+                _ => {}
+            }
+        }
+        if let Some(s) = start {
+            words.push((s, glyphs.len()));
+        }
+        words
+    }
+
+    /// Width of `glyphs` with spacing applied between (not after) glyphs
+    fn run_width(&self, glyphs: &[ShapedGlyph]) -> f32 {
+        let advance: f32 = glyphs.iter().map(|g| g.x_advance).sum();
+        advance + self.letter_spacing * glyphs.len().saturating_sub(1) as f32
+    }
+
+    /// Byte offsets inside `text` where a line may break besides its spaces
+    ///
+    /// Empty unless CJK line breaking is enabled.
+    pub(crate) fn break_offsets(&self, text: &str) -> Vec<usize> {
+        if self.cjk_line_breaking {
+            cjk_break_offsets(text)
+        } else {
+            Vec::new()
+        }
+    }
+
+    /// Indices of the words starting each line for the engine's mode
+    ///
+    /// `span(a, b)` is the width of words `a..=b` set on one line. Callers
+    /// measuring lines their own way, like the render pipeline's ink extents,
+    /// share the engine's break selection through this.
+    pub(crate) fn break_lines(
+        &self,
+        word_count: usize,
+        span: impl Fn(usize, usize) -> f32,
+    ) -> Vec<usize> {
+        if word_count <= 1 || !self.mode.wraps() {
+            return vec![0];
+        }
+        let greedy = |limit: f32| -> Vec<usize> {
+            let mut line_start = 0;
+            let mut starts = vec![0];
+            for i in 1..word_count {
+                if span(line_start, i) > limit {
+                    starts.push(i);
+                    line_start = i;
+                }
+            }
+            starts
+        };
+
+        let base = greedy(self.max_width);
+        if !self.mode.balances() || base.len() == 1 {
+            return base;
+        }
+
+        // Smallest limit that keeps the greedy line count spreads the text as
+        // evenly as possible, leaving any slack on the lower lines.
+        let target = base.len();
+        let widest_word = (0..word_count).map(|i| span(i, i)).fold(0.0_f32, f32::max);
+        let (mut lo, mut hi) = (widest_word, self.max_width.max(widest_word));
+        for _ in 0..24 {
+            let mid = (lo + hi) / 2.0;
+            if greedy(mid).len() <= target {
+                hi = mid;
+            } else {
+                lo = mid;
+            }
+        }
+        let mut starts = greedy(hi);
+
+        // `SmartBottom` moves the slack up: wherever the upper line is the
+        // wider one, its last word moves down if the lower line then fits and
+        // becomes the wider one.
+        if self.mode == WrapMode::SmartBottom {
+            for k in (1..starts.len()).rev() {
+                let moved = starts[k] - 1;
+                if moved == starts[k - 1] {
+                    continue;
+                }
+                let lower_end = starts.get(k + 1).map_or(word_count, |&next| next) - 1;
+                let upper = span(starts[k - 1], moved);
+                let lower = span(moved, lower_end);
+                if upper > span(starts[k], lower_end)
+                    && lower <= self.max_width
+                    && lower >= span(starts[k - 1], moved - 1)
+                {
+                    starts[k] = moved;
+                }
+            }
+        }
+        starts
+    }
+
+    /// Build a line from a glyph run, rebasing positions to the line start
+    fn build_line(&self, run: &[ShapedGlyph]) -> WrappedLine {
+        let mut pen = 0.0;
+        let glyphs = run
+            .iter()
+            .map(|g| {
+                let mut glyph = g.clone();
+                match self.direction {
+                    TextDirection::LeftToRight => glyph.x_position = pen + g.x_offset,
+                    TextDirection::TopToBottom => {
+                        glyph.x_position = g.y_offset;
+                        glyph.y_position = pen + g.x_offset;
+                        glyph.x_offset = g.y_offset;
+                        glyph.y_offset = g.x_offset;
+                        glyph.x_advance = 0.0;
+                        glyph.y_advance = g.x_advance;
+                    }
+                }
+                pen += g.x_advance + self.letter_spacing;
+                glyph
+            })
+            .collect();
+        WrappedLine {
+            glyphs,
+            width: self.run_width(run),
+        }
+    }
+}
+
+/// Byte offsets at which a line may break inside `text` (ascending)
+#[cfg(feature = "unicode-wrap")]
+fn cjk_break_offsets(text: &str) -> Vec<usize> {
+    ass_core::analysis::events::soft_wrap_offsets(text)
+}
+
+/// Byte offsets at which a line may break inside `text` (ascending)
+///
+/// Permits a break next to any CJK character unless the following character
+/// may not start a line or the preceding one may not end a line.
+#[cfg(not(feature = "unicode-wrap"))]
+fn cjk_break_offsets(text: &str) -> Vec<usize> {
+    let mut offsets = Vec::new();
+    let mut chars = text.char_indices().peekable();
+    while let Some((_, prev)) = chars.next() {
+        let Some(&(offset, next)) = chars.peek() else {
+            break;
+        };
+        if (is_cjk(prev) || is_cjk(next))
+            && !prev.is_whitespace()
+            && !next.is_whitespace()
+            && !no_line_start(next)
+            && !no_line_end(prev)
+        {
+            offsets.push(offset);
+        }
+    }
+    offsets
+}
+
+/// Ideographs, kana and Hangul syllables, which break without spaces
+#[cfg(not(feature = "unicode-wrap"))]
+fn is_cjk(c: char) -> bool {
+    matches!(
+        c,
+        '\u{3040}'..='\u{30FF}'
+            | '\u{3400}'..='\u{4DBF}'
+            | '\u{4E00}'..='\u{9FFF}'
+            | '\u{AC00}'..='\u{D7AF}'
+            | '\u{F900}'..='\u{FAFF}'
+            | '\u{20000}'..='\u{3FFFF}'
+    )
+}
+
+/// Closing punctuation that may not begin a line (kinsoku shori)
+#[cfg(not(feature = "unicode-wrap"))]
+fn no_line_start(c: char) -> bool {
+    matches!(
+        c,
+        '、' | '。'
+            | '，'
+            | '．'
+            | '：'
+            | '；'
+            | '！'
+            | '？'
+            | '・'
+            | '）'
+            | '」'
+            | '』'
+            | '】'
+            | '〕'
+            | '〉'
+            | '》'
+            | '〙'
+            | '〛'
+            | ')'
+            | ']'
+            | '}'
+            | ','
+            | '.'
+            | ':'
+            | ';'
+            | '!'
+            | '?'
+    )
+}
+
+/// Opening punctuation that may not end a line
+#[cfg(not(feature = "unicode-wrap"))]
+fn no_line_end(c: char) -> bool {
+    matches!(
+        c,
+        '（' | '「' | '『' | '【' | '〔' | '〈' | '《' | '〘' | '〚' | '(' | '[' | '{'
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEXT: &str = "aa bb cc dd ee ff gg";
+
+    /// Fixed-advance glyphs: 10px per character, one glyph per byte.
+    fn glyphs(text: &str) -> Vec<ShapedGlyph> {
+        text.char_indices()
+            .map(|(i, _)| ShapedGlyph {
+                glyph_id: 1,
+                x_position: i as f32 * 10.0,
+                y_position: 0.0,
+                x_offset: 0.0,
+                y_offset: 0.0,
+                x_advance: 10.0,
+                y_advance: 0.0,
+                cluster: i as u32,
+            })
+            .collect()
+    }
+
+    fn widths(lines: &[WrappedLine]) -> Vec<f32> {
+        lines.iter().map(|l| l.width).collect()
+    }
+
+    #[test]
+    fn smart_wrap_balances_with_upper_line_wider() {
+        let lines = TextLayoutEngine::new(170.0, WrapMode::Smart).layout(TEXT, &glyphs(TEXT));
+        assert_eq!(widths(&lines), vec![110.0, 80.0]);
+    }
+
+    #[test]
+    fn end_of_line_wrap_fills_greedily() {
+        let lines = TextLayoutEngine::new(170.0, WrapMode::EndOfLine).layout(TEXT, &glyphs(TEXT));
+        assert_eq!(widths(&lines), vec![170.0, 20.0]);
+        assert!(lines.iter().all(|l| l.width <= 170.0));
+    }
+
+    #[test]
+    fn no_wrap_keeps_single_overflowing_line() {
+        let lines = TextLayoutEngine::new(170.0, WrapMode::NoWrap).layout(TEXT, &glyphs(TEXT));
+        assert_eq!(lines.len(), 1);
+        assert_eq!(lines[0].width, 200.0);
+        assert_eq!(lines[0].glyphs.len(), TEXT.len());
+    }
+
+    #[test]
+    fn smart_bottom_wrap_makes_lower_line_wider() {
+        let lines = TextLayoutEngine::new(170.0, WrapMode::SmartBottom).layout(TEXT, &glyphs(TEXT));
+        assert_eq!(widths(&lines), vec![80.0, 110.0]);
+    }
+
+    #[test]
+    fn letter_spacing_counts_toward_wrap_width() {
+        let text = "aaaa bbbb";
+        let fits = TextLayoutEngine::new(90.0, WrapMode::EndOfLine).layout(text, &glyphs(text));
+        assert_eq!(fits.len(), 1);
+
+        let spaced = TextLayoutEngine::new(90.0, WrapMode::EndOfLine)
+            .with_letter_spacing(2.0)
+            .layout(text, &glyphs(text));
+        assert_eq!(widths(&spaced), vec![46.0, 46.0]);
+        assert_eq!(spaced[1].glyphs[1].x_position, 12.0);
+    }
+
+    #[test]
+    fn wrapped_lines_drop_break_spaces_and_rebase_positions() {
+        let lines = TextLayoutEngine::new(170.0, WrapMode::Smart).layout(TEXT, &glyphs(TEXT));
+        let total: usize = lines.iter().map(|l| l.glyphs.len()).sum();
+        assert_eq!(total, TEXT.len() - 1);
+        assert_eq!(lines[1].glyphs[0].x_position, 0.0);
+    }
+
+    fn line_text<'a>(text: &'a str, line: &WrappedLine) -> &'a str {
+        let start = line.glyphs[0].cluster as usize;
+        let last = line.glyphs[line.glyphs.len() - 1].cluster as usize;
+        let end = last + text[last..].chars().next().map_or(0, char::len_utf8);
+        &text[start..end]
+    }
+
+    #[test]
+    fn cjk_breaks_between_ideographs_without_spaces() {
+        let text = "日本語字幕です";
+        let plain = TextLayoutEngine::new(30.0, WrapMode::EndOfLine).layout(text, &glyphs(text));
+        assert_eq!(plain.len(), 1);
+
+        let lines = TextLayoutEngine::new(30.0, WrapMode::EndOfLine)
+            .with_cjk_line_breaking(true)
+            .layout(text, &glyphs(text));
+        let texts: Vec<&str> = lines.iter().map(|l| line_text(text, l)).collect();
+        assert_eq!(texts, vec!["日本語", "字幕で", "す"]);
+    }
+
+    #[test]
+    fn cjk_never_starts_a_line_with_closing_punctuation() {
+        // A greedy fill at 40px would start the second line with "、".
+        let text = "今日は雨、明日は晴れ。「傘」は要らない。";
+        for mode in [WrapMode::EndOfLine, WrapMode::Smart] {
+            let lines = TextLayoutEngine::new(40.0, mode)
+                .with_cjk_line_breaking(true)
+                .layout(text, &glyphs(text));
+            assert!(lines.len() > 1);
+            for line in &lines {
+                let line = line_text(text, line);
+                assert!(!line.starts_with(['、', '。', '」']), "{line:?}");
+                assert!(!line.ends_with('「'), "{line:?}");
+            }
+            let total: usize = lines.iter().map(|l| l.glyphs.len()).sum();
+            assert_eq!(total, text.chars().count());
+        }
+    }
+
+    #[test]
+    fn cjk_smart_wrap_balances_lines() {
+        let text = "あいうえおかきくけこ";
+        let lines = TextLayoutEngine::new(80.0, WrapMode::Smart)
+            .with_cjk_line_breaking(true)
+            .layout(text, &glyphs(text));
+        assert_eq!(widths(&lines), vec![50.0, 50.0]);
+    }
+
+    #[test]
+    fn top_to_bottom_advances_along_y() {
+        let text = "縦書き";
+        let lines = TextLayoutEngine::new(100.0, WrapMode::Smart)
+            .with_direction(TextDirection::TopToBottom)
+            .layout(text, &glyphs(text));
+        assert_eq!(lines[0].width, 30.0);
+        let positions: Vec<(f32, f32)> = lines[0]
+            .glyphs
+            .iter()
+            .map(|g| (g.x_position, g.y_position))
+            .collect();
+        assert_eq!(positions, vec![(0.0, 0.0), (0.0, 10.0), (0.0, 20.0)]);
+        assert!(lines[0].glyphs.iter().all(|g| g.y_advance == 10.0));
+    }
+
+    #[test]
+    fn wrap_mode_from_value() {
+        assert_eq!(WrapMode::from_value(3), Some(WrapMode::SmartBottom));
+        assert_eq!(WrapMode::from_value(4), None);
+        assert!(WrapMode::EndOfLine.wraps() && !WrapMode::EndOfLine.balances());
+        assert!(!WrapMode::NoWrap.wraps());
+    }
+}
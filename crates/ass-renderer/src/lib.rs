@@ -22,6 +22,8 @@ pub mod collision;
 /// Debug and analysis tools for subtitle rendering
 pub mod debug;
 pub mod layout;
+#[cfg(feature = "analysis-integration")]
+pub mod model;
 pub mod pipeline;
 pub mod plugin;
 pub mod renderer;
@@ -30,13 +32,19 @@ pub mod utils;
 pub use backends::{BackendType, RenderBackend};
 #[cfg(not(feature = "nostd"))]
 pub use debug::{DebugPlayer, FrameAnalyzer, FrameInspector, PlayerFrame};
-pub use pipeline::{Pipeline, PipelineStage};
-pub use plugin::{EffectPlugin, PluginRegistry};
+#[cfg(feature = "analysis-integration")]
+pub use model::{BorderStyle, ClipRegion, KaraokeState, RenderModel, Transform};
+pub use pipeline::{Pipeline, PipelineStage, StageName};
+pub use plugin::{EffectContext, EffectPlugin, PluginId, PluginRegistry};
+#[cfg(not(feature = "nostd"))]
+pub use renderer::RenderAheadQueue;
 pub use renderer::{Frame, RenderContext, Renderer};
 pub use utils::RenderError;
 
 #[cfg(feature = "backend-metrics")]
 pub use backends::BackendMetrics;
+#[cfg(all(feature = "backend-metrics", not(feature = "nostd")))]
+pub use pipeline::PipelineProfiler;
 
 #[cfg(feature = "analysis-integration")]
 pub use ass_core::analysis::styles::ResolvedStyle;
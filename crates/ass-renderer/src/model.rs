@@ -0,0 +1,484 @@
+//! Typed rendering state of an event at a point in time
+//!
+//! [`RenderModel`] is a fully resolved snapshot of everything needed to
+//! draw one event: the `[V4+ Styles]` entry with an override block layered
+//! on top and its `\t` animations interpolated, so rendering code reads
+//! typed fields instead of re-parsing tag strings every frame.
+
+use crate::layout::{Alignment, Rotation3D};
+use crate::pipeline::tag_processor::KaraokeStyle;
+#[cfg(feature = "nostd")]
+use alloc::{string::String, vec::Vec};
+use ass_core::analysis::styles::ResolvedStyle;
+use ass_core::override_parser::{DrawingMode, OverrideBlock, OverrideTag};
+#[cfg(not(feature = "nostd"))]
+use std::{string::String, vec::Vec};
+
+/// How the outline and shadow of an event are drawn
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum BorderStyle {
+    /// Outline around the glyphs with a drop shadow (`BorderStyle` 1)
+    #[default]
+    Outline,
+    /// Opaque box behind the text (`BorderStyle` 3)
+    OpaqueBox,
+}
+
+impl BorderStyle {
+    /// Convert from the numeric `BorderStyle` style field
+    pub fn from_value(value: u8) -> Self {
+        if value == 3 {
+            Self::OpaqueBox
+        } else {
+            Self::Outline
+        }
+    }
+}
+
+/// Region an event is clipped to by `\clip` or `\iclip`
+#[derive(Debug, Clone, PartialEq)]
+pub enum ClipRegion {
+    /// Axis-aligned rectangle in script coordinates
+    Rect {
+        x1: f32,
+        y1: f32,
+        x2: f32,
+        y2: f32,
+        /// Whether the outside of the rectangle is kept (`\iclip`)
+        inverse: bool,
+    },
+    /// Vector shape in drawing commands
+    Drawing {
+        /// Drawing scale, commands are at `2^(scale - 1)` resolution
+        scale: u32,
+        /// Drawing commands as written
+        commands: String,
+        /// Whether the outside of the shape is kept (`\iclip`)
+        inverse: bool,
+    },
+}
+
+/// A `\t` animation of the event
+#[derive(Debug, Clone, PartialEq)]
+pub struct Transform {
+    /// Start time in milliseconds relative to the event start
+    pub start_ms: u32,
+    /// End time in milliseconds, `None` for the end of the event
+    pub end_ms: Option<u32>,
+    /// Acceleration exponent (1.0 is linear)
+    pub accel: f32,
+    /// Tags animated towards
+    pub tags: Vec<OverrideTag>,
+    /// Fraction of the animation applied at the model's time, after
+    /// acceleration
+    pub progress: f32,
+}
+
+impl Transform {
+    /// Animation progress at `time` milliseconds into an event lasting
+    /// `duration_ms`
+    ///
+    /// Without an end time the animation runs to the end of the event, or
+    /// completes at its start when the duration is unknown.
+    fn progress_at(&self, time: f64, duration_ms: Option<u32>) -> f32 {
+        let start = f64::from(self.start_ms);
+        let end = f64::from(self.end_ms.or(duration_ms).unwrap_or(self.start_ms));
+        let linear = if end <= start {
+            if time >= start {
+                1.0
+            } else {
+                0.0
+            }
+        } else {
+            ((time - start) / (end - start)).clamp(0.0, 1.0)
+        };
+        (linear as f32).powf(self.accel)
+    }
+}
+
+/// Karaoke syllable timing set by the override block
+#[derive(Debug, Clone)]
+pub struct KaraokeState {
+    /// How the syllable is highlighted
+    pub style: KaraokeStyle,
+    /// Syllable duration in centiseconds
+    pub duration_cs: u32,
+}
+
+/// Fully resolved rendering properties of one event at one time
+///
+/// Colors are RGBA with ASS alpha semantics (`0` is opaque). Margins are
+/// `[left, right, vertical]` in script pixels, the vertical margin being
+/// the top one for top-aligned events and the bottom one otherwise.
+#[derive(Debug, Clone)]
+pub struct RenderModel {
+    pub font_family: String,
+    pub font_size_px: f32,
+    pub primary_color: [u8; 4],
+    pub secondary_color: [u8; 4],
+    pub outline_color: [u8; 4],
+    pub back_color: [u8; 4],
+    pub bold: bool,
+    pub italic: bool,
+    pub underline: bool,
+    pub strikeout: bool,
+    /// Horizontal scale in percent
+    pub scale_x: f32,
+    /// Vertical scale in percent
+    pub scale_y: f32,
+    pub spacing: f32,
+    /// Rotation around the screen axes, `z` starting at the style's `Angle`
+    pub rotation: Rotation3D,
+    pub border_style: BorderStyle,
+    pub outline: f32,
+    pub shadow: f32,
+    pub alignment: Alignment,
+    pub margins: [u32; 3],
+    pub clip: Option<ClipRegion>,
+    pub transforms: Vec<Transform>,
+    pub karaoke: Option<KaraokeState>,
+}
+
+impl RenderModel {
+    /// Resolve `overrides` on top of `style` at `time` milliseconds after
+    /// the event start
+    ///
+    /// `\t` animations without an end time complete at their start; use
+    /// [`RenderModel::resolve`] to run them to the end of the event.
+    pub fn from_resolved_style_and_overrides(
+        style: &ResolvedStyle,
+        overrides: &OverrideBlock,
+        time: f64,
+    ) -> Self {
+        Self::resolve(style, overrides, time, None)
+    }
+
+    /// Resolve `overrides` on top of `style` at `time` milliseconds into an
+    /// event lasting `duration_ms`
+    ///
+    /// Static tags are applied in order, the last one winning. Each `\t`
+    /// then moves the properties its tags set from their current value
+    /// towards the target by its progress; `\t` tags nested inside another
+    /// `\t` are ignored.
+    pub fn resolve(
+        style: &ResolvedStyle,
+        overrides: &OverrideBlock,
+        time: f64,
+        duration_ms: Option<u32>,
+    ) -> Self {
+        let mut resolved = style.clone();
+        // `\frx`/`\fry` have no style field, so they are tracked here
+        let mut tilt = (0.0, 0.0);
+        let mut clip = None;
+        let mut karaoke = None;
+        let mut transforms = Vec::new();
+        for tag in &overrides.tags {
+            match tag {
+                OverrideTag::Clip {
+                    x1,
+                    y1,
+                    x2,
+                    y2,
+                    inverse,
+                } => {
+                    clip = Some(ClipRegion::Rect {
+                        x1: *x1,
+                        y1: *y1,
+                        x2: *x2,
+                        y2: *y2,
+                        inverse: *inverse,
+                    });
+                }
+                OverrideTag::Drawing(DrawingMode::Clip(scale), commands) => {
+                    clip = Some(ClipRegion::Drawing {
+                        scale: *scale,
+                        commands: commands.clone(),
+                        inverse: false,
+                    });
+                }
+                OverrideTag::Drawing(DrawingMode::InverseClip(scale), commands) => {
+                    clip = Some(ClipRegion::Drawing {
+                        scale: *scale,
+                        commands: commands.clone(),
+                        inverse: true,
+                    });
+                }
+                OverrideTag::RotationX(degrees) => tilt.0 = *degrees,
+                OverrideTag::RotationY(degrees) => tilt.1 = *degrees,
+                OverrideTag::Karaoke(duration_cs) => {
+                    karaoke = Some(KaraokeState {
+                        style: KaraokeStyle::Basic,
+                        duration_cs: *duration_cs,
+                    });
+                }
+                OverrideTag::KaraokeFill(duration_cs) => {
+                    karaoke = Some(KaraokeState {
+                        style: KaraokeStyle::Fill,
+                        duration_cs: *duration_cs,
+                    });
+                }
+                OverrideTag::KaraokeOutline(duration_cs) => {
+                    karaoke = Some(KaraokeState {
+                        style: KaraokeStyle::Outline,
+                        duration_cs: *duration_cs,
+                    });
+                }
+                OverrideTag::Transform {
+                    t1,
+                    t2,
+                    accel,
+                    tags,
+                } => {
+                    let mut transform = Transform {
+                        start_ms: t1.unwrap_or(0),
+                        end_ms: *t2,
+                        accel: accel.unwrap_or(1.0),
+                        tags: tags.clone(),
+                        progress: 0.0,
+                    };
+                    transform.progress = transform.progress_at(time, duration_ms);
+                    transforms.push(transform);
+                }
+                _ => resolved.apply_override(tag),
+            }
+        }
+
+        let mut model = Self::from_style(&resolved, tilt);
+        // Targets build on each other so a later `\t` starts from where an
+        // earlier one ends, while only the properties it sets move
+        for transform in &transforms {
+            let before = Self::from_style(&resolved, tilt);
+            for tag in &transform.tags {
+                match tag {
+                    OverrideTag::RotationX(degrees) => tilt.0 = *degrees,
+                    OverrideTag::RotationY(degrees) => tilt.1 = *degrees,
+                    _ => resolved.apply_override(tag),
+                }
+            }
+            let after = Self::from_style(&resolved, tilt);
+            model.animate(&before, &after, transform.progress);
+        }
+
+        model.clip = clip;
+        model.karaoke = karaoke;
+        model.transforms = transforms;
+        model
+    }
+
+    /// Model of `style` tilted by `(\frx, \fry)`, without other overrides
+    fn from_style(style: &ResolvedStyle, (tilt_x, tilt_y): (f32, f32)) -> Self {
+        let alignment = Alignment::from_value(style.alignment());
+        let margin_v = if matches!(
+            alignment,
+            Alignment::TopLeft | Alignment::TopCenter | Alignment::TopRight
+        ) {
+            style.margin_t()
+        } else {
+            style.margin_b()
+        };
+        Self {
+            font_family: style.font_name().into(),
+            font_size_px: style.font_size(),
+            primary_color: style.primary_color(),
+            secondary_color: style.secondary_color(),
+            outline_color: style.outline_color(),
+            back_color: style.back_color(),
+            bold: style.is_bold(),
+            italic: style.is_italic(),
+            underline: style.is_underline(),
+            strikeout: style.is_strike_out(),
+            scale_x: style.scale_x(),
+            scale_y: style.scale_y(),
+            spacing: style.spacing(),
+            rotation: Rotation3D {
+                x: tilt_x,
+                y: tilt_y,
+                z: style.angle(),
+            },
+            border_style: BorderStyle::from_value(style.border_style()),
+            outline: style.outline(),
+            shadow: style.shadow(),
+            alignment,
+            margins: [
+                u32::from(style.margin_l()),
+                u32::from(style.margin_r()),
+                u32::from(margin_v),
+            ],
+            clip: None,
+            transforms: Vec::new(),
+            karaoke: None,
+        }
+    }
+
+    /// Move every animatable property that differs between `before` and
+    /// `after` towards its `after` value by `progress`
+    fn animate(&mut self, before: &Self, after: &Self, progress: f32) {
+        let scalars = [
+            (
+                &mut self.font_size_px,
+                before.font_size_px,
+                after.font_size_px,
+            ),
+            (&mut self.scale_x, before.scale_x, after.scale_x),
+            (&mut self.scale_y, before.scale_y, after.scale_y),
+            (&mut self.spacing, before.spacing, after.spacing),
+            (&mut self.rotation.x, before.rotation.x, after.rotation.x),
+            (&mut self.rotation.y, before.rotation.y, after.rotation.y),
+            (&mut self.rotation.z, before.rotation.z, after.rotation.z),
+            (&mut self.outline, before.outline, after.outline),
+            (&mut self.shadow, before.shadow, after.shadow),
+        ];
+        for (value, from, to) in scalars {
+            if from != to {
+                *value += (to - *value) * progress;
+            }
+        }
+
+        let colors = [
+            (
+                &mut self.primary_color,
+                before.primary_color,
+                after.primary_color,
+            ),
+            (
+                &mut self.secondary_color,
+                before.secondary_color,
+                after.secondary_color,
+            ),
+            (
+                &mut self.outline_color,
+                before.outline_color,
+                after.outline_color,
+            ),
+            (&mut self.back_color, before.back_color, after.back_color),
+        ];
+        for (color, from, to) in colors {
+            for channel in 0..4 {
+                if from[channel] != to[channel] {
+                    let current = f32::from(color[channel]);
+                    let target = f32::from(to[channel]);
+                    color[channel] = (current + (target - current) * progress).round() as u8;
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ass_core::parser::Script;
+
+    const SCRIPT: &str = "[V4+ Styles]
+Format: Name, Fontname, Fontsize, PrimaryColour, SecondaryColour, OutlineColour, BackColour, Bold, Italic, Underline, StrikeOut, ScaleX, ScaleY, Spacing, Angle, BorderStyle, Outline, Shadow, Alignment, MarginL, MarginR, MarginV, Encoding
+Style: Default,Arial,20,&H00FFFFFF,&H000000FF,&H00000000,&H80000000,0,0,0,0,100,100,0,0,1,2,1,2,10,20,30,1
+Style: Boxed,Verdana,32,&H00FFFFFF,&H000000FF,&H00000000,&H80000000,1,0,0,0,100,100,0,0,3,2,1,8,10,20,40,1
+";
+
+    /// Resolve the named style of [`SCRIPT`] and hand it to `f`
+    fn with_style<R>(name: &str, f: impl FnOnce(&ResolvedStyle) -> R) -> R {
+        let script = Script::parse(SCRIPT).unwrap();
+        let style = script
+            .sections()
+            .iter()
+            .find_map(|section| match section {
+                ass_core::parser::Section::Styles(styles) => {
+                    styles.iter().find(|style| style.name == name)
+                }
+                _ => None,
+            })
+            .unwrap();
+        f(&ResolvedStyle::from_style(style).unwrap())
+    }
+
+    #[test]
+    fn style_without_overrides() {
+        let model = with_style("Boxed", |style| {
+            RenderModel::from_resolved_style_and_overrides(style, &OverrideBlock::default(), 0.0)
+        });
+        assert_eq!(model.font_family, "Verdana");
+        assert_eq!(model.font_size_px, 32.0);
+        assert!(model.bold);
+        assert_eq!(model.border_style, BorderStyle::OpaqueBox);
+        assert_eq!(model.alignment, Alignment::TopCenter);
+        assert_eq!(model.margins, [10, 20, 40]);
+        assert!(model.clip.is_none() && model.karaoke.is_none());
+    }
+
+    #[test]
+    fn static_overrides_apply_in_order() {
+        let block = OverrideBlock::parse(r"{\fnGeorgia\fs30\i1\fs40\an8\clip(0,0,100,50)\kf25}");
+        let model = with_style("Default", |style| {
+            RenderModel::from_resolved_style_and_overrides(style, &block, 0.0)
+        });
+        assert_eq!(model.font_family, "Georgia");
+        assert_eq!(model.font_size_px, 40.0);
+        assert!(model.italic);
+        assert_eq!(model.alignment, Alignment::TopCenter);
+        assert_eq!(
+            model.clip,
+            Some(ClipRegion::Rect {
+                x1: 0.0,
+                y1: 0.0,
+                x2: 100.0,
+                y2: 50.0,
+                inverse: false,
+            })
+        );
+        let karaoke = model.karaoke.unwrap();
+        assert!(matches!(karaoke.style, KaraokeStyle::Fill));
+        assert_eq!(karaoke.duration_cs, 25);
+    }
+
+    #[test]
+    fn transforms_interpolate_at_time() {
+        let block = OverrideBlock::parse(r"{\t(0,1000,\fs40\1c&H0000FF&)\t(500,1000,\bord6)}");
+        let at = |time| {
+            with_style("Default", |style| {
+                RenderModel::from_resolved_style_and_overrides(style, &block, time)
+            })
+        };
+
+        let start = at(0.0);
+        assert_eq!(start.font_size_px, 20.0);
+        assert_eq!(start.outline, 2.0);
+
+        let middle = at(500.0);
+        assert_eq!(middle.font_size_px, 30.0);
+        assert_eq!(middle.primary_color, [255, 128, 128, 0]);
+        assert_eq!(middle.outline, 2.0);
+        assert_eq!(middle.transforms.len(), 2);
+        assert_eq!(middle.transforms[0].progress, 0.5);
+
+        let end = at(1000.0);
+        assert_eq!(end.font_size_px, 40.0);
+        assert_eq!(end.primary_color, [255, 0, 0, 0]);
+        assert_eq!(end.outline, 6.0);
+    }
+
+    #[test]
+    fn open_ended_transform_uses_event_duration() {
+        let block = OverrideBlock::parse(r"{\t(\fscx200)}");
+        let model = with_style("Default", |style| {
+            RenderModel::resolve(style, &block, 1000.0, Some(4000))
+        });
+        assert_eq!(model.scale_x, 125.0);
+    }
+
+    #[test]
+    fn rotation_composes_static_and_animated_tags() {
+        let block = OverrideBlock::parse(r"{\frx10\fr30\t(0,1000,\fry40\frz90)}");
+        let model = with_style("Default", |style| {
+            RenderModel::from_resolved_style_and_overrides(style, &block, 500.0)
+        });
+        assert_eq!(
+            model.rotation,
+            Rotation3D {
+                x: 10.0,
+                y: 20.0,
+                z: 60.0,
+            }
+        );
+        assert!(model.rotation.is_perspective());
+    }
+}
@@ -0,0 +1,51 @@
+//! Policy-driven collision resolution for the software pipeline.
+
+#[cfg(feature = "nostd")]
+use alloc::vec::Vec;
+#[cfg(not(feature = "nostd"))]
+use std::vec::Vec;
+
+use ass_core::parser::Event;
+
+use crate::collision::{LayoutEvent, NudgePolicy, SweepCollisionDetector};
+use crate::pipeline::IntermediateLayer;
+use crate::renderer::RenderContext;
+use crate::utils::RenderError;
+
+impl super::SoftwarePipeline {
+    /// Lay out every event, then resolve overlaps between non-positioned
+    /// events in one `SweepCollisionDetector` pass.
+    pub(super) fn process_events_with_policy(
+        &mut self,
+        sorted_events: &[&Event],
+        policy: NudgePolicy,
+        time_cs: u32,
+        context: &RenderContext,
+    ) -> Result<Vec<IntermediateLayer>, RenderError> {
+        let mut per_event = Vec::with_capacity(sorted_events.len());
+        let mut layout_events = Vec::new();
+        for (order, event) in sorted_events.iter().enumerate() {
+            let event_layers = self.process_event(event, time_cs, context)?;
+            if !Self::event_is_positioned(event) {
+                if let Some(bbox) = self.event_bounding_box(&event_layers) {
+                    let layer = event.layer.parse::<i32>().unwrap_or(0);
+                    layout_events.push(LayoutEvent::new(bbox, layer, order));
+                }
+            }
+            per_event.push(event_layers);
+        }
+
+        let original: Vec<f32> = layout_events.iter().map(|e| e.bbox.y).collect();
+        SweepCollisionDetector::new(policy).detect_and_resolve(&mut layout_events);
+        for (resolved, y) in layout_events.iter().zip(original) {
+            let layers = &mut per_event[resolved.order];
+            if resolved.hidden {
+                layers.clear();
+            } else if (resolved.bbox.y - y).abs() > 0.5 {
+                Self::offset_layers_y(layers, resolved.bbox.y - y);
+            }
+        }
+
+        Ok(per_event.into_iter().flatten().collect())
+    }
+}
@@ -61,8 +61,9 @@ impl super::SoftwarePipeline {
             }
 
             // Calculate scaling factors
-            let scale_x = context.width() as f32 / self.play_res_x;
-            let scale_y = context.height() as f32 / self.play_res_y;
+            let transform = self.coordinate_transform(context);
+            let scale_x = transform.scale_x as f32;
+            let scale_y = transform.scale_y as f32;
 
             // Drawing geometry is in script (PlayRes) units, exactly like `\pos`,
             // so it must be scaled to the render resolution. Previously only the
@@ -77,8 +78,8 @@ impl super::SoftwarePipeline {
 
             // Calculate position with proper scaling
             let (x, y) = if let Some((px, py)) = tags.position {
-                // Scale from script coordinates to render coordinates
-                (px * scale_x, py * scale_y)
+                // Map from script coordinates to render coordinates
+                Self::to_output(&transform, px, py)
             } else if let Some((x1, y1, x2, y2, t1, t2)) = tags.movement {
                 // Movement times are relative to event start
                 let event_start_cs = _event.start_time_cs().unwrap_or(0);
@@ -94,11 +95,11 @@ impl super::SoftwarePipeline {
                 let progress = calculate_move_progress(time_cs, move_start_cs, move_end_cs);
                 let x = x1 + (x2 - x1) * progress;
                 let y = y1 + (y2 - y1) * progress;
-                // Scale from script coordinates to render coordinates
-                (x * scale_x, y * scale_y)
+                // Map from script coordinates to render coordinates
+                Self::to_output(&transform, x, y)
             } else {
-                // Default center (in render coordinates)
-                (context.width() as f32 / 2.0, context.height() as f32 / 2.0)
+                // Default center of the subtitle area
+                Self::to_output(&transform, self.play_res_x / 2.0, self.play_res_y / 2.0)
             };
 
             // Get path bounds to calculate proper alignment offset
@@ -147,15 +148,11 @@ impl super::SoftwarePipeline {
             ));
 
             // `\clip` / `\iclip` coordinates are in script space (like `\pos`),
-            // so scale them into render space the same way the text path does.
+            // so map them into render space the same way the text path does.
             let clip = tags.clip.as_ref().map(|c| {
-                (
-                    c.x1 * scale_x,
-                    c.y1 * scale_y,
-                    c.x2 * scale_x,
-                    c.y2 * scale_y,
-                    c.inverse,
-                )
+                let (x1, y1) = Self::to_output(&transform, c.x1, c.y1);
+                let (x2, y2) = Self::to_output(&transform, c.x2, c.y2);
+                (x1, y1, x2, y2, c.inverse)
             });
 
             // `\blur` on a drawing softens the filled shape exactly like text:
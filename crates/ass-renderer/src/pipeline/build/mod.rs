@@ -11,6 +11,7 @@ use fontdb::Database as FontDatabase;
 use crate::pipeline::shaping::GlyphRenderer;
 
 mod animation;
+mod collide;
 mod drawing;
 mod position;
 mod run;
@@ -28,6 +29,9 @@ pub struct SoftwarePipeline {
     glyph_renderer: GlyphRenderer,
     /// Collision resolver for subtitle positioning
     collision_resolver: crate::collision::CollisionResolver,
+    /// Optional nudge policy replacing libass-style stacking with a
+    /// post-layout sweep (`None` keeps libass behaviour)
+    collision_policy: Option<crate::collision::NudgePolicy>,
     /// Render cache for performance
     cache: crate::cache::RenderCache,
     /// Current script styles map for quick lookup
@@ -77,6 +81,7 @@ impl SoftwarePipeline {
             scaled_border_and_shadow: true, // Default to true per ASS spec
             dpi_scale: 0.9,                 // Adjusted for better libass compatibility (was 0.75)
             wrap_style: 0,
+            collision_policy: None,
         }
     }
 
@@ -99,9 +104,16 @@ impl SoftwarePipeline {
             scaled_border_and_shadow: true, // Default to true per ASS spec
             dpi_scale: 0.9,                 // Adjusted for better libass compatibility (was 0.75)
             wrap_style: 0,
+            collision_policy: None,
         }
     }
 
+    /// Resolve collisions with a `NudgePolicy` after the whole frame is laid
+    /// out instead of libass-style stacking; `None` restores the default
+    pub fn set_collision_policy(&mut self, policy: Option<crate::collision::NudgePolicy>) {
+        self.collision_policy = policy;
+    }
+
     /// Set DPI scale factor (default is 0.9 for libass compatibility)
     /// Use 1.0 for 96 DPI, 0.9 for empirically matched libass rendering
     pub fn set_dpi_scale(&mut self, scale: f32) {
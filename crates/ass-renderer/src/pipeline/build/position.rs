@@ -1,6 +1,7 @@
 //! Position, alignment, and margin resolution for the software pipeline.
 
 use crate::collision::BoundingBox;
+use crate::layout::CoordinateTransform;
 use crate::pipeline::{
     animation::calculate_move_progress, tag_processor::ProcessedTags, IntermediateLayer,
 };
@@ -98,6 +99,24 @@ impl super::SoftwarePipeline {
         }
     }
 
+    /// Transform from the script's PlayRes to the physical output pixels of
+    /// `context`, letterboxed to keep the script aspect ratio
+    pub(super) fn coordinate_transform(&self, context: &RenderContext) -> CoordinateTransform {
+        CoordinateTransform::new(
+            f64::from(self.play_res_x),
+            f64::from(self.play_res_y),
+            f64::from(context.logical_width()),
+            f64::from(context.logical_height()),
+            context.display_scale(),
+        )
+    }
+
+    /// Map a script-space point through `transform` into render pixels
+    pub(super) fn to_output(transform: &CoordinateTransform, x: f32, y: f32) -> (f32, f32) {
+        let (x, y) = transform.to_output(f64::from(x), f64::from(y));
+        (x as f32, y as f32)
+    }
+
     pub(super) fn calculate_position_from_tags(
         &self,
         tags: &ProcessedTags,
@@ -106,9 +125,7 @@ impl super::SoftwarePipeline {
         time_cs: u32,
         default_alignment: u8,
     ) -> (f32, f32) {
-        // Calculate scaling factors
-        let scale_x = context.width() as f32 / self.play_res_x;
-        let scale_y = context.height() as f32 / self.play_res_y;
+        let transform = self.coordinate_transform(context);
 
         // Check for explicit position
         if let Some((mut px, mut py)) = tags.position {
@@ -118,8 +135,8 @@ impl super::SoftwarePipeline {
                 px *= self.play_res_x / layout_x;
                 py *= self.play_res_y / layout_y;
             }
-            // Then scale from script (PlayRes) coordinates to render coordinates
-            return (px * scale_x, py * scale_y);
+            // Then map from script (PlayRes) coordinates to render coordinates
+            return Self::to_output(&transform, px, py);
         }
 
         // Check for movement
@@ -149,8 +166,8 @@ impl super::SoftwarePipeline {
             let x = x1 + (x2 - x1) * progress;
             let y = y1 + (y2 - y1) * progress;
 
-            // Scale from script (PlayRes) coordinates to render coordinates
-            return (x * scale_x, y * scale_y);
+            // Map from script (PlayRes) coordinates to render coordinates
+            return Self::to_output(&transform, x, y);
         }
 
         // Calculate based on alignment
@@ -164,13 +181,6 @@ impl super::SoftwarePipeline {
         event: &Event,
         context: &RenderContext,
     ) -> (f32, f32) {
-        let width = context.width() as f32;
-        let height = context.height() as f32;
-
-        // Calculate scaling factors for margins
-        let scale_x = width / self.play_res_x;
-        let scale_y = height / self.play_res_y;
-
         // Parse margins - use style margins if event margins are 0 or empty
         // Get margins in script coordinates first
         let style_margin_l = self
@@ -206,10 +216,6 @@ impl super::SoftwarePipeline {
             event.margin_v.parse::<f32>().unwrap_or(style_margin_v)
         };
 
-        // Scale margins to screen coordinates
-        let margin_l = margin_l_script * scale_x;
-        let margin_r = margin_r_script * scale_x;
-
         // ASS alignment uses numpad layout
         // SubStation numpad-style alignment:
         // 7 8 9  (top-left, top-center, top-right)
@@ -233,11 +239,11 @@ impl super::SoftwarePipeline {
         // Return the anchor point based on alignment and margins
         // This is where the aligned point of the text box should be placed
         // ASS alignment uses numpad layout: 1,4,7 = left; 2,5,8 = center; 3,6,9 = right
-        let x = match mapped_alignment {
-            1 | 4 | 7 => margin_l,         // Left column
-            2 | 5 | 8 => width / 2.0,      // Center column
-            3 | 6 | 9 => width - margin_r, // Right column
-            _ => width / 2.0,              // Default center
+        let x_script = match mapped_alignment {
+            1 | 4 | 7 => margin_l_script,                   // Left column
+            2 | 5 | 8 => self.play_res_x / 2.0,             // Center column
+            3 | 6 | 9 => self.play_res_x - margin_r_script, // Right column
+            _ => self.play_res_x / 2.0,                     // Default center
         };
 
         // Position calculation following libass approach
@@ -251,9 +257,7 @@ impl super::SoftwarePipeline {
         };
 
         // Transform from script coordinates to screen coordinates
-        let y = y_script * scale_y;
-
-        (x, y)
+        Self::to_output(&self.coordinate_transform(context), x_script, y_script)
     }
 
     /// Apply alignment offset to convert from anchor point to top-left corner
@@ -11,19 +11,21 @@ use ass_core::parser::{Event, Script};
 
 use super::OwnedStyle;
 use crate::collision::PositionedEvent;
-use crate::pipeline::{text_segmenter::segment_text_with_tags, IntermediateLayer, Pipeline};
+use crate::layout::WrapMode;
+use crate::pipeline::{text_segmenter::segment_text_with_wrap_style, IntermediateLayer, Pipeline};
 use crate::renderer::RenderContext;
 use crate::utils::{DirtyRegion, RenderError};
 
 impl super::SoftwarePipeline {
-    fn process_event(
+    pub(super) fn process_event(
         &mut self,
         event: &Event,
         time_cs: u32,
         context: &RenderContext,
     ) -> Result<Vec<IntermediateLayer>, RenderError> {
         // Get text segments with their individual tags
-        let segments = segment_text_with_tags(event.text, None)?;
+        let wrap_style = WrapMode::from_value(self.wrap_style).unwrap_or_default();
+        let segments = segment_text_with_wrap_style(event.text, None, wrap_style)?;
 
         if segments.is_empty() {
             return Ok(Vec::new());
@@ -94,19 +96,22 @@ impl Pipeline for super::SoftwarePipeline {
         #[cfg(not(feature = "analysis-integration"))]
         let _use_resolved_styles = false;
 
-        // Extract script info and styles from the script
+        // PlayRes (with libass defaults for missing values), WrapStyle
+        // (0 smart / 1 greedy / 2 none / 3 smart) and ScaledBorderAndShadow,
+        // which defaults to "yes" per ASS spec but can be "no" to disable scaling
+        let info = script.info();
+        let (res_x, res_y) = info.play_resolution();
+        self.play_res_x = res_x as f32;
+        self.play_res_y = res_y as f32;
+        self.wrap_style = info.wrap_style.unwrap_or_default().value();
+        if let Some(scaled) = info.scaled_border_and_shadow {
+            self.scaled_border_and_shadow = scaled;
+        }
+
+        // Extract layout resolution and styles from the script
         for section in script.sections() {
             match section {
                 ass_core::parser::Section::ScriptInfo(info) => {
-                    // Extract PlayResX and PlayResY from script info
-                    if let Some((res_x, res_y)) = info.play_resolution() {
-                        self.play_res_x = res_x as f32;
-                        self.play_res_y = res_y as f32;
-                    }
-
-                    // Extract WrapStyle (0 smart / 1 greedy / 2 none / 3 smart)
-                    self.wrap_style = info.wrap_style();
-
                     // Extract LayoutResX and LayoutResY if present
                     if let Some((layout_x, layout_y)) = info.layout_resolution() {
                         self.layout_res_x = Some(layout_x as f32);
@@ -115,12 +120,6 @@ impl Pipeline for super::SoftwarePipeline {
                         // If LayoutRes differs from PlayRes, we need to scale styles
                         // This is done later when processing styles
                     }
-
-                    // Extract ScaledBorderAndShadow setting
-                    // Default is "yes" per ASS spec, but can be "no" to disable scaling
-                    if let Some(scaled_value) = info.get_field("ScaledBorderAndShadow") {
-                        self.scaled_border_and_shadow = scaled_value.to_lowercase() != "no";
-                    }
                 }
                 ass_core::parser::Section::Styles(styles) => {
                     // Calculate LayoutRes->PlayRes scaling factors if LayoutRes is present
@@ -239,7 +238,13 @@ impl Pipeline for super::SoftwarePipeline {
             layer_a.cmp(&layer_b).then(start_a.cmp(&start_b))
         });
 
-        let scale_y = context.height() as f32 / self.play_res_y;
+        let scale_y = self.coordinate_transform(context).scale_y as f32;
+
+        // With a nudge policy set, events are laid out first and resolved
+        // together afterwards.
+        if let Some(policy) = self.collision_policy {
+            return self.process_events_with_policy(&sorted_events, policy, time_cs, context);
+        }
 
         // Process each event, applying collision resolution so simultaneous
         // non-positioned events stack instead of overlapping (libass "Normal"
@@ -296,4 +301,8 @@ impl Pipeline for super::SoftwarePipeline {
 
         Ok(regions)
     }
+
+    fn load_font_data(&mut self, data: Vec<u8>) {
+        self.font_database.load_font_data(data);
+    }
 }
@@ -5,12 +5,12 @@ use alloc::string::{String, ToString};
 #[cfg(not(feature = "nostd"))]
 use std::string::{String, ToString};
 
+use ass_core::analysis::styles::ResolvedStyle;
 use ass_core::parser::Style;
 
 /// Owned style for storing in pipeline
 #[derive(Clone)]
 pub(super) struct OwnedStyle {
-    #[allow(dead_code)] // Style identifier - stored for completeness
     name: String,
     pub(super) fontname: String,
     pub(super) fontsize: String,
@@ -25,9 +25,7 @@ pub(super) struct OwnedStyle {
     pub(super) scale_x: String,
     pub(super) scale_y: String,
     pub(super) spacing: String,
-    #[allow(dead_code)] // Text rotation angle - stored for completeness
     angle: String,
-    #[allow(dead_code)] // Border rendering style - stored for completeness
     pub(super) border_style: String,
     pub(super) outline: String,
     pub(super) shadow: String,
@@ -35,7 +33,6 @@ pub(super) struct OwnedStyle {
     pub(super) margin_l: String,
     pub(super) margin_r: String,
     pub(super) margin_v: String,
-    #[allow(dead_code)] // Text encoding specification - stored for completeness
     encoding: String,
 }
 
@@ -67,6 +64,42 @@ impl OwnedStyle {
             encoding: style.encoding.to_string(),
         }
     }
+
+    /// Borrow the stored fields as a parser style
+    fn as_style(&self) -> Style<'_> {
+        Style {
+            name: &self.name,
+            fontname: &self.fontname,
+            fontsize: &self.fontsize,
+            primary_colour: &self.primary_colour,
+            secondary_colour: &self.secondary_colour,
+            outline_colour: &self.outline_colour,
+            back_colour: &self.back_colour,
+            bold: &self.bold,
+            italic: &self.italic,
+            underline: &self.underline,
+            strikeout: &self.strikeout,
+            scale_x: &self.scale_x,
+            scale_y: &self.scale_y,
+            spacing: &self.spacing,
+            angle: &self.angle,
+            border_style: &self.border_style,
+            outline: &self.outline,
+            shadow: &self.shadow,
+            alignment: &self.alignment,
+            margin_l: &self.margin_l,
+            margin_r: &self.margin_r,
+            margin_v: &self.margin_v,
+            encoding: &self.encoding,
+            ..Style::default()
+        }
+    }
+
+    /// Resolve the stored fields over the built-in default style, the single
+    /// source of style-derived render parameters
+    pub(super) fn resolved(&self) -> ResolvedStyle<'_> {
+        ResolvedStyle::cascade(&self.as_style(), &ResolvedStyle::default())
+    }
 }
 
 impl super::SoftwarePipeline {
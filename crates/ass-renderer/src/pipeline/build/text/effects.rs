@@ -1,8 +1,8 @@
 //! Per-run text effects for the software pipeline: formatting, outline/shadow/
 //! blur/opaque-box, rotation, shear, scale, clip, and baseline offset.
 
-use super::super::OwnedStyle;
 use super::types::{EffectColors, RunCtx, TextDefaults};
+use crate::layout::Rotation3D;
 use crate::pipeline::{tag_processor::ProcessedTags, TextData, TextEffect};
 
 impl super::super::SoftwarePipeline {
@@ -15,7 +15,6 @@ impl super::super::SoftwarePipeline {
         colors: EffectColors,
         ctx: &RunCtx,
         defaults: &TextDefaults,
-        style: Option<&OwnedStyle>,
     ) {
         let scale_x = ctx.scale_x;
         let scale_y = ctx.scale_y;
@@ -72,10 +71,7 @@ impl super::super::SoftwarePipeline {
                 .or(tags.formatting.border)
                 .unwrap_or(default_outline)
         };
-        let border_style = style
-            .and_then(|s| s.border_style.trim().parse::<u8>().ok())
-            .unwrap_or(1);
-        if border_style == 3 {
+        if defaults.border_style == 3 {
             // BorderStyle 3: opaque box behind the text in the outline colour,
             // padded per-axis (\xbord/\ybord).
             layer.effects.push(TextEffect::OpaqueBox {
@@ -151,14 +147,17 @@ impl super::super::SoftwarePipeline {
         let rotation_y = tags.font.rotation_y.unwrap_or(0.0);
         let rotation_z = tags.font.rotation_z.or(tags.font.angle).unwrap_or(0.0);
         if rotation_x != 0.0 || rotation_y != 0.0 || rotation_z != 0.0 {
-            // `\org` sets the rotation centre in script coordinates; scale
+            // `\org` sets the rotation centre in script coordinates; map
             // it to screen space for the backend.
-            let origin = tags.origin.map(|(ox, oy)| (ox * scale_x, oy * scale_y));
+            let origin = tags
+                .origin
+                .map(|(ox, oy)| Self::to_output(&ctx.transform, ox, oy));
             layer.effects.push(TextEffect::Rotation {
                 x: rotation_x,
                 y: rotation_y,
                 z: rotation_z,
                 origin,
+                focal_length: Rotation3D::focal_length(self.play_res_y) * scale_y,
             });
         }
 
@@ -185,20 +184,17 @@ impl super::super::SoftwarePipeline {
             });
         }
 
-        // Add clip region if present (scale from script coordinates)
+        // Add clip region if present (mapped from script coordinates)
         if let Some(clip) = &tags.clip {
+            let (x1, y1) = Self::to_output(&ctx.transform, clip.x1, clip.y1);
+            let (x2, y2) = Self::to_output(&ctx.transform, clip.x2, clip.y2);
             layer.effects.push(TextEffect::Clip {
-                x1: clip.x1 * scale_x,
-                y1: clip.y1 * scale_y,
-                x2: clip.x2 * scale_x,
-                y2: clip.y2 * scale_y,
+                x1,
+                y1,
+                x2,
+                y2,
                 inverse: clip.inverse,
             });
         }
-
-        // Handle baseline offset
-        if let Some(baseline_offset) = tags.baseline_offset {
-            layer.y += baseline_offset;
-        }
     }
 }
@@ -10,6 +10,7 @@ use ass_core::parser::Event;
 use smallvec::SmallVec;
 
 use super::OwnedStyle;
+use crate::layout::WrapMode;
 use crate::pipeline::{
     shaping::shape_text_cached, tag_processor::KaraokeStyle, text_segmenter::TextSegment,
     IntermediateLayer, TextData, TextEffect,
@@ -38,8 +39,9 @@ impl super::SoftwarePipeline {
         let mut all_layers = Vec::new();
 
         // Calculate scaling factors for sizes
-        let scale_x = context.width() as f32 / self.play_res_x;
-        let scale_y = context.height() as f32 / self.play_res_y;
+        let transform = self.coordinate_transform(context);
+        let scale_x = transform.scale_x as f32;
+        let scale_y = transform.scale_y as f32;
 
         // Resolve the style-derived defaults (font/size/colours/formatting) once.
         let defaults = self.resolve_text_defaults(style, scale_y);
@@ -50,11 +52,12 @@ impl super::SoftwarePipeline {
         // Effective wrap style: a `\q` override (clamped to 0..=3, like libass which
         // falls back to the track style on an invalid value) takes precedence over
         // the script's WrapStyle header.
-        let event_wrap_style = segments
+        let wrap_mode = segments
             .iter()
             .find_map(|s| s.tags.formatting.wrap_style)
-            .filter(|&q| q <= 3)
-            .unwrap_or(self.wrap_style);
+            .and_then(WrapMode::from_value)
+            .or_else(|| WrapMode::from_value(self.wrap_style))
+            .unwrap_or_default();
 
         // Restructure segments into logical lines
         let mut logical_lines = split_into_logical_lines(segments);
@@ -62,19 +65,19 @@ impl super::SoftwarePipeline {
         // Width-based auto-wrap. WrapStyle 2 disables width wrapping entirely (only
         // explicit \N breaks, already split into logical_lines); positioned events
         // (\pos/\move) keep their explicit layout. WrapStyle 1 wraps greedily with
-        // no balancing; 0/3 balance the lines (libass smart wrapping).
-        if !Self::event_is_positioned(event) && event_wrap_style != 2 {
+        // no balancing; 0/3 balance the lines (libass smart wrapping), 3 with the
+        // wider line at the bottom.
+        if !Self::event_is_positioned(event) && wrap_mode.wraps() {
             let margin_l =
                 Self::margin_or_style(event.margin_l, style.map(|s| s.margin_l.as_str()));
             let margin_r =
                 Self::margin_or_style(event.margin_r, style.map(|s| s.margin_r.as_str()));
-            let available = (context.width() as f32 - (margin_l + margin_r) * scale_x).max(1.0);
-            let balance = event_wrap_style != 1;
+            let available = ((self.play_res_x - margin_l - margin_r) * scale_x).max(1.0);
             let mut wrapped: Vec<Vec<TextSegment>> = Vec::with_capacity(logical_lines.len());
             for line in logical_lines {
                 wrapped.extend(self.wrap_segments(
                     &line,
-                    defaults.font_name,
+                    &defaults.font_name,
                     defaults.font_size_base,
                     defaults.scale_y,
                     defaults.bold,
@@ -83,7 +86,7 @@ impl super::SoftwarePipeline {
                     defaults.scale_x,
                     scale_y,
                     available,
-                    balance,
+                    wrap_mode,
                 ));
             }
             logical_lines = wrapped;
@@ -120,6 +123,7 @@ impl super::SoftwarePipeline {
             time_cs,
             scale_x,
             scale_y,
+            transform,
         };
 
         for (line_index, line_segments) in logical_lines.into_iter().enumerate() {
@@ -137,7 +141,7 @@ impl super::SoftwarePipeline {
                             * (seg.tags.font.scale_y.unwrap_or(defaults.scale_y) / 100.0)
                             * self.dpi_scale;
                         let fsx = seg.tags.font.scale_x.unwrap_or(defaults.scale_x) / 100.0;
-                        let font = seg.tags.font.name.as_deref().unwrap_or(defaults.font_name);
+                        let font = seg.tags.font.name.as_deref().unwrap_or(&defaults.font_name);
                         let bold = seg.tags.formatting.bold.unwrap_or(defaults.bold);
                         let italic = seg.tags.formatting.italic.unwrap_or(defaults.italic);
                         shape_text_cached(&seg.text, font, size, bold, italic, &self.font_database)
@@ -207,7 +211,7 @@ impl super::SoftwarePipeline {
                 // Apply both resolution scaling, percentage scaling, and DPI scaling
                 let actual_font_size = base_font_size * scale_y * font_scale_y * self.dpi_scale;
 
-                let font_to_use = tags.font.name.as_deref().unwrap_or(defaults.font_name);
+                let font_to_use = tags.font.name.as_deref().unwrap_or(&defaults.font_name);
 
                 let shaped = shape_text_cached(
                     line_text,
@@ -229,7 +233,7 @@ impl super::SoftwarePipeline {
                 );
 
                 // Get font information with proper inheritance
-                let font_family = tags.font.name.as_deref().unwrap_or(defaults.font_name);
+                let font_family = tags.font.name.as_deref().unwrap_or(&defaults.font_name);
                 // Font size was already calculated above for shaping
                 // Use the same value here for consistency
                 let font_size = actual_font_size;
@@ -256,6 +260,7 @@ impl super::SoftwarePipeline {
                     y: segment_y,
                     effects: SmallVec::new(),
                     spacing,
+                    baseline_offset: tags.baseline_offset.unwrap_or(0.0) * scale_y,
                 };
 
                 // Add effects (formatting, outline/shadow/blur/opaque-box, rotation,
@@ -269,7 +274,6 @@ impl super::SoftwarePipeline {
                     },
                     &run_ctx,
                     &defaults,
-                    style,
                 );
 
                 // Handle karaoke - track per-syllable timing
@@ -3,14 +3,20 @@
 //! colours. Consumed by the descendant `defaults`/`position`/`effects` modules
 //! and the orchestrator in the parent module.
 
+#[cfg(feature = "nostd")]
+use alloc::string::String;
+#[cfg(not(feature = "nostd"))]
+use std::string::String;
+
 use ass_core::parser::Event;
 
+use crate::layout::CoordinateTransform;
 use crate::renderer::RenderContext;
 
 /// Style-derived defaults (font, size, colours, formatting) resolved once per
-/// event. Borrows the run's resolved style for its zero-copy font name.
-pub(super) struct TextDefaults<'a> {
-    pub(super) font_name: &'a str,
+/// event from the style's `ResolvedStyle`.
+pub(super) struct TextDefaults {
+    pub(super) font_name: String,
     pub(super) font_size_base: f32,
     pub(super) bold: bool,
     pub(super) italic: bool,
@@ -26,6 +32,7 @@ pub(super) struct TextDefaults<'a> {
     pub(super) scale_y: f32,
     pub(super) spacing: f32,
     pub(super) alignment: u8,
+    pub(super) border_style: u8,
 }
 
 /// Per-call rendering context shared by the per-segment helpers (constant
@@ -36,6 +43,8 @@ pub(super) struct RunCtx<'a, 'b> {
     pub(super) time_cs: u32,
     pub(super) scale_x: f32,
     pub(super) scale_y: f32,
+    /// Script to render coordinates, for points such as `\clip` and `\org`
+    pub(super) transform: CoordinateTransform,
 }
 
 /// Per-line layout constants consumed when positioning each segment.
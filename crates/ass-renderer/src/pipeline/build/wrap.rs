@@ -5,12 +5,14 @@ use alloc::{string::String, vec, vec::Vec};
 #[cfg(not(feature = "nostd"))]
 use std::{string::String, vec::Vec};
 
+use crate::layout::{TextLayoutEngine, WrapMode};
 use crate::pipeline::{shaping::shape_text_cached, text_segmenter::TextSegment};
 
 impl super::SoftwarePipeline {
     /// Word-wrap a logical line (one or more tagged segments) to fit `max_width`
-    /// (render pixels), preserving per-segment tags and balancing line widths to
-    /// approximate libass smart wrapping (WrapStyle 0). Width is measured with the
+    /// (render pixels), preserving per-segment tags. Breaks are chosen by
+    /// [`TextLayoutEngine`] for `mode`, at spaces and CJK break opportunities,
+    /// on the ink widths libass wraps by. Width is measured with the
     /// line's leading font — exact for the common single-font / mid-line
     /// colour-change case; mixed font SIZES within one line are approximated.
     /// Returns one entry per wrapped line (a line that already fits is one entry).
@@ -27,7 +29,7 @@ impl super::SoftwarePipeline {
         default_scale_x: f32,
         scale_y: f32,
         max_width: f32,
-        balance: bool,
+        mode: WrapMode,
     ) -> Vec<Vec<TextSegment>> {
         let Some(lead) = line.first() else {
             return vec![line.to_vec()];
@@ -43,12 +45,30 @@ impl super::SoftwarePipeline {
             }
         }
         let full: String = chars.iter().collect();
-        let mut words: Vec<&str> = full.split(' ').collect();
+        let layout = TextLayoutEngine::new(max_width, mode).with_cjk_line_breaking(true);
+        let cjk_breaks = layout.break_offsets(&full);
+
+        // Words as (byte start, byte end, preceded by a break space): split at
+        // spaces, then at the CJK break opportunities inside each token.
+        let mut words: Vec<(usize, usize, bool)> = Vec::new();
+        let mut token_start = 0usize;
+        for (k, token) in full.split(' ').enumerate() {
+            let token_end = token_start + token.len();
+            let mut word_start = token_start;
+            for &offset in &cjk_breaks {
+                if offset > token_start && offset < token_end {
+                    words.push((word_start, offset, word_start == token_start && k > 0));
+                    word_start = offset;
+                }
+            }
+            words.push((word_start, token_end, word_start == token_start && k > 0));
+            token_start = token_end + 1;
+        }
         // Drop empty tokens from a trailing space (e.g. "...land, \N"): libass trims
         // trailing whitespace before measuring, so it must not count toward the wrap
         // width or it can force a spurious extra break. The space stays in the
         // rendered (invisible) line tail via the char-range rebuild below.
-        while words.len() > 1 && words.last().is_some_and(|w| w.is_empty()) {
+        while words.len() > 1 && words.last().is_some_and(|w| w.0 == w.1) {
             words.pop();
         }
         if words.len() <= 1 {
@@ -88,97 +108,59 @@ impl super::SoftwarePipeline {
             shape_text_cached(s, font, size, bold, italic, &self.font_database)
                 .map_or((0.0, 0.0, 0.0), |sh| (sh.width, sh.ink_min, sh.ink_max))
         };
-        let word_m: Vec<(f32, f32, f32)> = words.iter().map(|w| measure(w)).collect();
-        let word_adv: Vec<f32> = word_m.iter().map(|m| m.0).collect();
+        let word_m: Vec<(f32, f32, f32)> = words
+            .iter()
+            .map(|&(a, b, _)| measure(&full[a..b]))
+            .collect();
         // Per-word side bearings: lead = ink left edge, trail = advance - ink right.
         // libass measures a line on ink width (x_max - x_min), which is the advance
         // box minus the first word's lead and the last word's trail.
         let word_lead: Vec<f32> = word_m.iter().map(|m| m.1).collect();
         let word_trail: Vec<f32> = word_m.iter().map(|m| (m.0 - m.2).max(0.0)).collect();
-        let word_glyphs: Vec<usize> = words.iter().map(|w| w.chars().count()).collect();
         // Advance of one space glyph, isolated from neighbouring side bearings.
         let space_adv = (measure("x x").0 - measure("xx").0).max(0.0);
 
+        // Prefix sums of advance and glyph count, each word counting the break
+        // space before it; a line drops the space before its first word.
+        let mut prefix_adv = vec![0.0_f32];
+        let mut prefix_glyphs = vec![0usize];
+        for (&(a, b, spaced), m) in words.iter().zip(&word_m) {
+            let space = usize::from(spaced);
+            prefix_adv.push(prefix_adv[prefix_adv.len() - 1] + m.0 + space_adv * space as f32);
+            prefix_glyphs
+                .push(prefix_glyphs[prefix_glyphs.len() - 1] + full[a..b].chars().count() + space);
+        }
+
         // Ink width of a contiguous run of words [start..=end]: advances plus the
         // inter-glyph spacing, scaled by `\fscx`, minus the run's leading/trailing
         // side bearings (so it matches libass's ink-extent wrap threshold).
-        let line_ink = |adv: f32, glyphs: usize, start: usize, end: usize| -> f32 {
+        let line_ink = |start: usize, end: usize| -> f32 {
+            let space = usize::from(words[start].2);
+            let adv = prefix_adv[end + 1] - prefix_adv[start] - space_adv * space as f32;
+            let glyphs = prefix_glyphs[end + 1] - prefix_glyphs[start] - space;
             let advance_box = adv + spacing * glyphs.saturating_sub(1) as f32;
             sx * (advance_box - word_lead[start] - word_trail[end])
         };
 
-        // Greedily pack words under `limit`, returning word indices that start a line.
-        let fill = |limit: f32| -> Vec<usize> {
-            let mut starts: Vec<usize> = Vec::new();
-            let mut line_start = 0usize;
-            let mut cur_adv = 0.0;
-            let mut cur_glyphs = 0usize;
-            let mut started = false;
-            for i in 0..words.len() {
-                let (add_adv, add_glyphs) = if started {
-                    (space_adv + word_adv[i], 1 + word_glyphs[i])
-                } else {
-                    (word_adv[i], word_glyphs[i])
-                };
-                if started
-                    && line_ink(cur_adv + add_adv, cur_glyphs + add_glyphs, line_start, i) > limit
-                {
-                    starts.push(i);
-                    line_start = i;
-                    cur_adv = word_adv[i];
-                    cur_glyphs = word_glyphs[i];
-                } else {
-                    cur_adv += add_adv;
-                    cur_glyphs += add_glyphs;
-                    started = true;
-                }
-            }
-            starts
-        };
-        let base = fill(max_width);
-        if base.is_empty() {
+        // WrapStyle 0/3 balance the lines, biasing the upper (0) or lower (3)
+        // lines wider like libass smart wrapping; WrapStyle 1 keeps the raw
+        // greedy (end-of-line) breaks.
+        let line_starts = layout.break_lines(words.len(), line_ink);
+        if line_starts.len() <= 1 {
             return vec![line.to_vec()];
         }
 
-        // WrapStyle 0/3 (`balance`): find the smallest width limit that still yields
-        // the greedy break count, biasing earlier lines wider like libass smart
-        // wrapping. WrapStyle 1 keeps the raw greedy (end-of-line) breaks.
-        let line_starts: Vec<usize> = if balance {
-            let target = base.len();
-            let max_word = (0..words.len())
-                .map(|i| line_ink(word_adv[i], word_glyphs[i], i, i))
-                .fold(0.0_f32, f32::max);
-            let (mut lo, mut hi) = (max_word, max_width);
-            for _ in 0..24 {
-                let mid = (lo + hi) / 2.0;
-                if fill(mid).len() <= target {
-                    hi = mid;
-                } else {
-                    lo = mid;
-                }
-            }
-            core::iter::once(0).chain(fill(hi)).collect()
-        } else {
-            core::iter::once(0).chain(base).collect()
-        };
-
-        // Character offset where each word begins (words are space-separated).
-        let mut word_char_start = Vec::with_capacity(words.len());
-        let mut pos = 0usize;
-        for (i, word) in words.iter().enumerate() {
-            word_char_start.push(pos);
-            pos += word.chars().count();
-            if i + 1 < words.len() {
-                pos += 1; // the separating space
-            }
-        }
+        // Character offset where each word begins.
+        let char_index = |byte: usize| full[..byte].chars().count();
+        let word_char_start: Vec<usize> = words.iter().map(|w| char_index(w.0)).collect();
 
         // Slice the flattened line into per-wrap char ranges and rebuild segments.
         let mut out: Vec<Vec<TextSegment>> = Vec::with_capacity(line_starts.len());
         for (k, &start_word) in line_starts.iter().enumerate() {
             let start_char = word_char_start[start_word];
-            let end_char = if k + 1 < line_starts.len() {
-                word_char_start[line_starts[k + 1]] - 1 // drop the break space
+            let end_char = if let Some(&next) = line_starts.get(k + 1) {
+                // Drop the break space, if any
+                word_char_start[next] - usize::from(words[next].2)
             } else {
                 chars.len()
             };
@@ -43,27 +43,14 @@ pub fn shared_system_fonts() -> std::sync::Arc<FontDatabase> {
 }
 
 /// Load embedded fonts from ASS script into font database
+///
+/// Registers every decodable `[Fonts]` entry from [`Script::embedded_fonts`]
+/// alongside the system fonts, so styles naming an embedded family resolve
+/// without the caller supplying separate font bytes.
 pub fn load_embedded_fonts(script: &Script, font_database: &mut FontDatabase) {
-    // Check if the script has a Fonts section
-    if let Some(fonts) = script.sections().iter().find_map(|section| {
-        if let ass_core::parser::ast::Section::Fonts(fonts) = section {
-            Some(fonts)
-        } else {
-            None
-        }
-    }) {
-        // Process each embedded font
-        for font in fonts {
-            // Decode the UU-encoded font data
-            match font.decode_data() {
-                Ok(font_data) => {
-                    // Load the font into the database
-                    // fontdb's load_font_data takes ownership of the data
-                    font_database.load_font_data(font_data);
-                }
-                Err(_e) => {}
-            }
-        }
+    for font in script.embedded_fonts() {
+        // fontdb's load_font_data takes ownership of the data
+        font_database.load_font_data(font.data);
     }
 }
 
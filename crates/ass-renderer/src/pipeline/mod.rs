@@ -25,6 +25,11 @@ pub mod validation;
 mod build;
 pub use build::SoftwarePipeline;
 
+#[cfg(all(feature = "backend-metrics", not(feature = "nostd")))]
+mod profiler;
+#[cfg(all(feature = "backend-metrics", not(feature = "nostd")))]
+pub use profiler::PipelineProfiler;
+
 /// Pipeline trait for processing events
 pub trait Pipeline: Send + Sync {
     /// Prepare the pipeline with a script
@@ -53,6 +58,13 @@ pub trait Pipeline: Send + Sync {
         time_cs: u32,
         prev_time_cs: u32,
     ) -> Result<Vec<DirtyRegion>, RenderError>;
+
+    /// Register font data (TTF/OTF bytes) used for shaping and layout
+    ///
+    /// Pipelines without a font database ignore it.
+    fn load_font_data(&mut self, data: Vec<u8>) {
+        let _ = data;
+    }
 }
 
 /// Pipeline stage for processing
@@ -68,6 +80,31 @@ pub enum PipelineStage {
     Compositing,
 }
 
+/// Timed stage of a profiled frame render
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum StageName {
+    /// Selecting the events active at the frame time
+    EventSelection,
+    /// Preparing the pipeline for the script
+    Preparation,
+    /// Shaping, drawing and effects (`Pipeline::process_events`)
+    Processing,
+    /// Compositing layers into the frame buffer
+    Compositing,
+}
+
+impl StageName {
+    /// Get stage name as string
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::EventSelection => "EventSelection",
+            Self::Preparation => "Preparation",
+            Self::Processing => "Processing",
+            Self::Compositing => "Compositing",
+        }
+    }
+}
+
 /// Intermediate layer representation
 pub enum IntermediateLayer {
     /// Rasterized bitmap layer
@@ -168,6 +205,8 @@ pub struct TextData {
     pub effects: SmallVec<[TextEffect; 4]>,
     /// Letter spacing in pixels
     pub spacing: f32,
+    /// Baseline offset (`\pbo`) in pixels; positive moves the glyphs down
+    pub baseline_offset: f32,
 }
 
 /// Text effect enumeration
@@ -207,11 +246,13 @@ pub enum TextEffect {
     },
     /// 3D rotation (in degrees). `origin`, when set, is the rotation centre in
     /// screen-space pixels (`\org`); otherwise the text's own centre is used.
+    /// `focal_length` is the perspective camera distance in screen pixels.
     Rotation {
         x: f32,
         y: f32,
         z: f32,
         origin: Option<(f32, f32)>,
+        focal_length: f32,
     },
     /// Shear/skew transformation
     Shear { x: f32, y: f32 },
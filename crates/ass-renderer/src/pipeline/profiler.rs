@@ -0,0 +1,150 @@
+//! Per-stage wall-clock profiling of frame renders
+
+use std::boxed::Box;
+use std::collections::{HashMap, VecDeque};
+use std::time::{Duration, Instant};
+use std::vec::Vec;
+
+use ass_core::parser::{Script, Section};
+
+use super::{Pipeline, StageName};
+use crate::backends::{BackendMetrics, RenderBackend};
+use crate::renderer::{EventSelector, Frame, RenderContext};
+use crate::utils::RenderError;
+
+/// Number of frames whose stage timings are kept for averaging
+const HISTORY_LEN: usize = 1024;
+
+/// Renders frames through a `Pipeline` while timing each stage
+///
+/// Unlike `Renderer`, every call runs the full pipeline: there is no static
+/// frame cache, so the timings reflect the real cost of each frame.
+pub struct PipelineProfiler {
+    context: RenderContext,
+    backend: Box<dyn RenderBackend>,
+    pipeline: Box<dyn Pipeline>,
+    event_selector: EventSelector,
+    history: VecDeque<Vec<(StageName, Duration)>>,
+    metrics: BackendMetrics,
+}
+
+impl PipelineProfiler {
+    /// Create a profiler using the pipeline of `backend`
+    pub fn new(
+        context: RenderContext,
+        backend: Box<dyn RenderBackend>,
+    ) -> Result<Self, RenderError> {
+        let pipeline = backend.create_pipeline()?;
+        Ok(Self::from_parts(context, backend, pipeline))
+    }
+
+    /// Create a profiler around an existing pipeline and backend
+    pub fn from_parts(
+        context: RenderContext,
+        backend: Box<dyn RenderBackend>,
+        pipeline: Box<dyn Pipeline>,
+    ) -> Self {
+        Self {
+            context,
+            backend,
+            pipeline,
+            event_selector: EventSelector::new(),
+            history: VecDeque::new(),
+            metrics: BackendMetrics::new(),
+        }
+    }
+
+    /// Render a frame for `script` at `time_cs`, recording stage timings
+    pub fn render_frame(&mut self, script: &Script, time_cs: u32) -> Result<Frame, RenderError> {
+        let mut stages = Vec::with_capacity(4);
+        let mut timed = |stage: StageName, start: Instant| stages.push((stage, start.elapsed()));
+
+        let start = Instant::now();
+        self.apply_script_resolution(script);
+        let active = self.event_selector.select_active(script, time_cs)?;
+        timed(StageName::EventSelection, start);
+
+        let frame_data = if active.events.is_empty() {
+            None
+        } else {
+            let start = Instant::now();
+            self.pipeline.prepare_script(script, None)?;
+            timed(StageName::Preparation, start);
+
+            let start = Instant::now();
+            let layers = self
+                .pipeline
+                .process_events(&active.events, time_cs, &self.context)?;
+            timed(StageName::Processing, start);
+
+            let start = Instant::now();
+            let data = self.backend.composite_layers(&layers, &self.context)?;
+            timed(StageName::Compositing, start);
+            Some(data)
+        };
+
+        self.metrics.record_frame(&stages);
+        if self.history.len() == HISTORY_LEN {
+            self.history.pop_front();
+        }
+        self.history.push_back(stages);
+
+        let (width, height) = (self.context.width(), self.context.height());
+        Ok(match frame_data {
+            Some(data) => Frame::new(data, width, height, time_cs),
+            None => Frame::empty(width, height, time_cs),
+        })
+    }
+
+    /// Stage timings of the most recent frame, in execution order
+    ///
+    /// Frames without active events only record `EventSelection`.
+    pub fn last_frame_stages(&self) -> &[(StageName, Duration)] {
+        self.history.back().map_or(&[], Vec::as_slice)
+    }
+
+    /// Mean time per stage over the last `n_frames` frames
+    ///
+    /// Averages over the frames actually kept (at most 1024); a stage that
+    /// did not run in some of those frames counts as zero for them.
+    pub fn average_stage_times(&self, n_frames: usize) -> HashMap<StageName, Duration> {
+        let frames = n_frames.min(self.history.len());
+        let mut totals: HashMap<StageName, Duration> = HashMap::new();
+        for stages in self.history.iter().rev().take(frames) {
+            for (stage, time) in stages {
+                *totals.entry(*stage).or_default() += *time;
+            }
+        }
+        let divisor = u32::try_from(frames).unwrap_or(u32::MAX).max(1);
+        for time in totals.values_mut() {
+            *time /= divisor;
+        }
+        totals
+    }
+
+    /// Aggregate metrics over every frame rendered by this profiler
+    pub fn metrics(&self) -> &BackendMetrics {
+        &self.metrics
+    }
+
+    /// Forget recorded timings and metrics
+    pub fn reset(&mut self) {
+        self.history.clear();
+        self.metrics = BackendMetrics::new();
+    }
+
+    /// Apply the script's `PlayRes` and `LayoutRes` to the render context
+    fn apply_script_resolution(&mut self, script: &Script) {
+        let info = script.sections().iter().find_map(|section| match section {
+            Section::ScriptInfo(info) => Some(info),
+            _ => None,
+        });
+        if let Some(info) = info {
+            let (play_x, play_y) = script.info().play_resolution();
+            self.context.set_playback_resolution(play_x, play_y);
+            if let Some((layout_x, layout_y)) = info.layout_resolution() {
+                self.context.set_storage_resolution(layout_x, layout_y);
+            }
+        }
+    }
+}
@@ -0,0 +1,79 @@
+//! OpenType feature selection for text shaping.
+
+#[cfg(feature = "nostd")]
+use alloc::{collections::BTreeMap as HashMap, vec::Vec};
+#[cfg(not(feature = "nostd"))]
+use std::{collections::HashMap, vec::Vec};
+
+use rustybuzz::{Feature, Tag};
+
+/// Standard ligatures
+pub const LIGA: [u8; 4] = *b"liga";
+/// Contextual ligatures
+pub const CLIG: [u8; 4] = *b"clig";
+/// Kerning
+pub const KERN: [u8; 4] = *b"kern";
+
+/// OpenType feature overrides applied while shaping
+///
+/// Maps a 4-byte feature tag (`liga`, `smcp`, `tnum`, ...) to its value:
+/// `0` disables the feature, `1` enables it, and larger values select an
+/// alternate for features such as `salt`. Tags not present keep the
+/// shaper's default for the script.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct FontFeatures {
+    /// Feature tag to value
+    pub tags: HashMap<[u8; 4], u32>,
+}
+
+impl FontFeatures {
+    /// Create an empty set that leaves every feature at its default
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Default features for an ASS style `Encoding` (GDI charset) value
+    ///
+    /// Charset 0 (ANSI, Windows codepage 1252) explicitly enables the usual
+    /// Latin features: kerning and standard and contextual ligatures. Every
+    /// other charset adds no overrides, so the font's own defaults apply.
+    pub fn from_encoding(encoding: u8) -> Self {
+        match encoding {
+            0 => Self::new().with(KERN, 1).with(LIGA, 1).with(CLIG, 1),
+            _ => Self::new(),
+        }
+    }
+
+    /// Set `tag` to `value`, returning the updated set
+    #[must_use]
+    pub fn with(mut self, tag: [u8; 4], value: u32) -> Self {
+        self.set(tag, value);
+        self
+    }
+
+    /// Set `tag` to `value`
+    pub fn set(&mut self, tag: [u8; 4], value: u32) {
+        self.tags.insert(tag, value);
+    }
+
+    /// Value set for `tag`, if any
+    pub fn get(&self, tag: [u8; 4]) -> Option<u32> {
+        self.tags.get(&tag).copied()
+    }
+
+    /// Whether no feature is overridden
+    pub fn is_empty(&self) -> bool {
+        self.tags.is_empty()
+    }
+
+    /// Convert to rustybuzz features covering the whole buffer
+    pub(crate) fn to_rustybuzz(&self) -> Vec<Feature> {
+        let mut features: Vec<Feature> = self
+            .tags
+            .iter()
+            .map(|(tag, value)| Feature::new(Tag::from_bytes(tag), *value, ..))
+            .collect();
+        features.sort_by_key(|feature| feature.tag);
+        features
+    }
+}
@@ -80,4 +80,61 @@ impl FontMetrics {
         // ASS font spacing is in pixels, added to each character's advance
         advance + spacing
     }
+
+    /// Scale these metrics and the face's decoration lines to `font_size`
+    pub fn to_pixels(&self, face: &Face, font_size: f32) -> PixelFontMetrics {
+        let scale = font_size / self.units_per_em;
+        let ascender_px = self.ascender * scale;
+        let descender_px = self.descender * scale;
+
+        // libass takes the underline from the `post` table and the strikeout
+        // from OS/2 (yStrikeoutPosition/yStrikeoutSize). Fonts lacking them
+        // fall back to half the descent below and a third of the ascent above
+        // the baseline.
+        let underline = face.underline_metrics();
+        let strikeout = face.strikeout_metrics();
+        let line = |metrics: Option<ttf_parser::LineMetrics>, offset: f32, thickness: f32| {
+            metrics
+                .filter(|m| m.thickness > 0)
+                .map_or((offset, thickness), |m| {
+                    (
+                        f32::from(m.position) * scale,
+                        f32::from(m.thickness) * scale,
+                    )
+                })
+        };
+        let (underline_offset_px, underline_thickness_px) =
+            line(underline, descender_px / 2.0, font_size * 0.08);
+        let (strikethrough_offset_px, strikethrough_thickness_px) =
+            line(strikeout, ascender_px / 3.0, font_size * 0.06);
+
+        PixelFontMetrics {
+            ascender_px,
+            descender_px,
+            underline_offset_px,
+            underline_thickness_px: underline_thickness_px.max(1.0),
+            strikethrough_offset_px,
+            strikethrough_thickness_px: strikethrough_thickness_px.max(1.0),
+        }
+    }
+}
+
+/// Font metrics in pixels at the size a run was shaped at
+///
+/// Offsets are measured upwards from the baseline to the centre of the line,
+/// so an underline usually has a negative offset.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct PixelFontMetrics {
+    /// Ascender height above the baseline
+    pub ascender_px: f32,
+    /// Descender depth (negative, below the baseline)
+    pub descender_px: f32,
+    /// Underline centre relative to the baseline
+    pub underline_offset_px: f32,
+    /// Underline thickness, at least one pixel
+    pub underline_thickness_px: f32,
+    /// Strikethrough centre relative to the baseline
+    pub strikethrough_offset_px: f32,
+    /// Strikethrough thickness, at least one pixel
+    pub strikethrough_thickness_px: f32,
 }
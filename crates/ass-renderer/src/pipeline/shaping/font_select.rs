@@ -1,7 +1,7 @@
 //! Font selection and coverage matching for shaping.
 
 #[cfg(feature = "nostd")]
-use alloc::{format, string::ToString, sync::Arc};
+use alloc::{string::ToString, sync::Arc};
 #[cfg(not(feature = "nostd"))]
 use std::{string::ToString, sync::Arc};
 
@@ -104,10 +104,8 @@ fn find_font(
         .faces()
         .next()
         .map(|face| face.id)
-        .ok_or_else(|| {
-            RenderError::FontError(format!(
-                "Font '{family}' not found and no fallback available"
-            ))
+        .ok_or_else(|| RenderError::FontNotFound {
+            font_name: family.to_string(),
         })
 }
 
@@ -129,7 +127,7 @@ fn text_has_cjk_or_hangul(text: &str) -> bool {
 }
 
 /// Check how many characters of `text` are supported by the font face
-fn font_support_count(
+pub(super) fn font_support_count(
     font_database: &FontDatabase,
     font_id: FontId,
     text: &str,
@@ -43,13 +43,15 @@ impl GlyphRenderer {
         }
     }
 
-    /// Render shaped text to paths
+    /// Render shaped text to paths, one per glyph at its shaped position
+    ///
+    /// Letter spacing and baseline offsets are part of the glyph positions;
+    /// see [`ShapedText::apply_fsp`] and [`ShapedText::apply_pbo`].
     pub fn render_shaped_text(
         &mut self,
         shaped: &ShapedText,
         font_id: FontId,
         font_database: &FontDatabase,
-        spacing: f32,
     ) -> Result<Vec<Path>, RenderError> {
         // Get or cache font data and face index
         let (font_data, face_index) = if let Some(data) = self.font_cache.get(&font_id) {
@@ -89,10 +91,9 @@ impl GlyphRenderer {
             .map_err(|_| RenderError::FontError("Failed to parse font".to_string()))?;
 
         let mut paths = Vec::new();
-        let mut accumulated_spacing = 0.0;
 
         // Render each glyph
-        for (i, glyph) in shaped.glyphs.iter().enumerate() {
+        for glyph in &shaped.glyphs {
             let size_fixed = (shaped.font_size * 65536.0) as u32; // Convert to 16.16 fixed point
             let key = GlyphKey {
                 font_id,
@@ -100,21 +101,15 @@ impl GlyphRenderer {
                 size: size_fixed,
             };
 
-            // Apply spacing to x position (accumulated for all previous glyphs)
-            let adjusted_x = glyph.x_position + accumulated_spacing;
-
             // Check cache first
             if let Some(cached_path) = self.glyph_cache.get(&key) {
                 // Transform cached path to glyph position
                 // y_position is already the baseline position
-                let transform = tiny_skia::Transform::from_translate(adjusted_x, glyph.y_position);
+                let transform =
+                    tiny_skia::Transform::from_translate(glyph.x_position, glyph.y_position);
                 if let Some(transformed) = cached_path.clone().transform(transform) {
                     paths.push(transformed);
                 }
-                // Add spacing for next glyph (spacing is added after each character)
-                if i < shaped.glyphs.len() - 1 {
-                    accumulated_spacing += spacing;
-                }
                 continue;
             }
 
@@ -175,18 +170,14 @@ impl GlyphRenderer {
                 // Cache the base glyph path
                 self.glyph_cache.insert(key, path.clone());
 
-                // Transform to position with spacing adjustment
+                // Transform to position
                 // y_position is already the baseline position
-                let transform = tiny_skia::Transform::from_translate(adjusted_x, glyph.y_position);
+                let transform =
+                    tiny_skia::Transform::from_translate(glyph.x_position, glyph.y_position);
                 if let Some(transformed) = path.transform(transform) {
                     paths.push(transformed);
                 }
             }
-
-            // Add spacing for next glyph (spacing is added after each character)
-            if i < shaped.glyphs.len() - 1 {
-                accumulated_spacing += spacing;
-            }
         }
 
         Ok(paths)
@@ -1,14 +1,24 @@
 //! Text shaping module using rustybuzz
 
+mod emoji;
+mod features;
 mod font_metrics;
 mod font_select;
 mod glyph_renderer;
 mod shape;
+mod shaper;
+#[cfg(not(feature = "nostd"))]
+mod static_cache;
 
-pub use font_metrics::FontMetrics;
+pub use emoji::{is_emoji_cluster, EmojiHandling};
+pub use features::{FontFeatures, CLIG, KERN, LIGA};
+pub use font_metrics::{FontMetrics, PixelFontMetrics};
 pub use font_select::find_font_for_text;
 pub use glyph_renderer::GlyphRenderer;
-pub use shape::{shape_text, shape_text_cached, shape_text_with_style};
+pub use shape::{shape_text, shape_text_cached, shape_text_with_features, shape_text_with_style};
+pub use shaper::{TextShaper, EMOJI_ADVANCE_EMS};
+#[cfg(not(feature = "nostd"))]
+pub use static_cache::{ShapingCacheStats, StaticShapingCache};
 
 #[cfg(feature = "nostd")]
 use alloc::vec::Vec;
@@ -59,6 +69,9 @@ pub struct ShapedText {
     pub ink_min: f32,
     /// Right edge of the inked glyph outlines.
     pub ink_max: f32,
+    /// Pixel metrics of the run's font, including the underline and
+    /// strikethrough lines.
+    pub metrics: PixelFontMetrics,
 }
 
 impl ShapedText {
@@ -75,4 +88,43 @@ impl ShapedText {
         }
         Some(total)
     }
+
+    /// Add `\fsp` letter spacing of `fsp` pixels after every glyph but the
+    /// last, moving later glyphs right and widening the run
+    pub fn apply_fsp(&mut self, fsp: f32) {
+        if fsp == 0.0 || self.glyphs.is_empty() {
+            return;
+        }
+        let last = self.glyphs.len() - 1;
+        for (index, glyph) in self.glyphs.iter_mut().enumerate() {
+            glyph.x_position += fsp * index as f32;
+            if index < last {
+                glyph.x_advance += fsp;
+            }
+        }
+        let added = fsp * last as f32;
+        self.width += added;
+        self.ink_max += added;
+    }
+
+    /// Move every glyph `pbo` pixels down from the baseline (`\pbo`; negative
+    /// moves up)
+    ///
+    /// The ascent or descent grows by the offset so the run's box still
+    /// contains the glyphs. The font `metrics`, and with them underline and
+    /// strikethrough, stay on the original baseline.
+    pub fn apply_pbo(&mut self, pbo: f32) {
+        if pbo == 0.0 {
+            return;
+        }
+        for glyph in &mut self.glyphs {
+            glyph.y_position += pbo;
+        }
+        if pbo > 0.0 {
+            self.descent -= pbo;
+        } else {
+            self.ascent -= pbo;
+        }
+        self.height += pbo.abs();
+    }
 }
@@ -6,11 +6,11 @@ use alloc::{string::ToString, vec::Vec};
 use std::{string::ToString, vec::Vec};
 
 use crate::utils::RenderError;
-use fontdb::Database as FontDatabase;
-use rustybuzz::{Face, Feature, UnicodeBuffer, Variation};
+use fontdb::{Database as FontDatabase, ID as FontId};
+use rustybuzz::{Face, UnicodeBuffer};
 
 use super::font_select::find_font_for_text;
-use super::{FontMetrics, ShapedGlyph, ShapedText};
+use super::{FontFeatures, FontMetrics, ShapedGlyph, ShapedText};
 
 /// Shape text into glyphs
 pub fn shape_text(
@@ -116,10 +116,48 @@ pub fn shape_text_with_style(
     bold: bool,
     italic: bool,
     font_database: &FontDatabase,
+) -> Result<ShapedText, RenderError> {
+    shape_text_with_features(
+        text,
+        font_family,
+        font_size,
+        bold,
+        italic,
+        &FontFeatures::new(),
+        font_database,
+    )
+}
+
+/// Shape text with style options and OpenType feature overrides
+///
+/// `font_size` (the `\fs` value) is also passed to the shaper as the nominal
+/// point size, which optical-size-sensitive features such as `trak` use.
+pub fn shape_text_with_features(
+    text: &str,
+    font_family: &str,
+    font_size: f32,
+    bold: bool,
+    italic: bool,
+    features: &FontFeatures,
+    font_database: &FontDatabase,
 ) -> Result<ShapedText, RenderError> {
     // Find best matching font, taking the input text into account for coverage (CJK/Hangul, etc.)
     let font_id = find_font_for_text(font_database, font_family, bold, italic, text)?;
+    shape_with_font(text, font_id, font_size, features, None, font_database)
+}
 
+/// Shape text with an already selected font face
+///
+/// `language` is a BCP 47 tag selecting language-specific shaping (e.g.
+/// localized forms); unparsable tags are ignored.
+pub(super) fn shape_with_font(
+    text: &str,
+    font_id: FontId,
+    font_size: f32,
+    features: &FontFeatures,
+    language: Option<&str>,
+    font_database: &FontDatabase,
+) -> Result<ShapedText, RenderError> {
     // Get font data using face_source
     let (source, index) = font_database
         .face_source(font_id)
@@ -146,8 +184,9 @@ pub fn shape_text_with_style(
     };
 
     // Create rustybuzz face
-    let rb_face = Face::from_slice(font_data.as_ref().as_ref(), index)
+    let mut rb_face = Face::from_slice(font_data.as_ref().as_ref(), index)
         .ok_or_else(|| RenderError::FontError("Failed to create font face".to_string()))?;
+    rb_face.set_points_per_em(Some(font_size));
 
     // Parse with ttf-parser for OS/2 table access
     let ttf_face = ttf_parser::Face::parse(font_data.as_ref().as_ref(), index)
@@ -159,11 +198,12 @@ pub fn shape_text_with_style(
     // Create buffer and add text
     let mut buffer = UnicodeBuffer::new();
     buffer.push_str(text);
+    if let Some(language) = language.and_then(|tag| tag.parse().ok()) {
+        buffer.set_language(language);
+    }
 
     // Shape the text
-    let features: Vec<Feature> = Vec::new();
-    let _variations: Vec<Variation> = Vec::new();
-    let output = rustybuzz::shape(&rb_face, &features, buffer);
+    let output = rustybuzz::shape(&rb_face, &features.to_rustybuzz(), buffer);
 
     // Get glyph positions and convert to our format
     let positions = output.glyph_positions();
@@ -227,5 +267,6 @@ pub fn shape_text_with_style(
         descent,
         ink_min,
         ink_max,
+        metrics: metrics.to_pixels(&ttf_face, font_size),
     })
 }
@@ -0,0 +1,352 @@
+//! Configurable text shaper combining feature overrides and emoji handling.
+
+#[cfg(feature = "nostd")]
+use alloc::{string::String, vec::Vec};
+#[cfg(not(feature = "nostd"))]
+use std::{string::String, sync::Arc, vec::Vec};
+
+use crate::utils::RenderError;
+use ass_core::parser::Script;
+use fontdb::{Database as FontDatabase, ID as FontId};
+
+use super::emoji::{emoji_runs, Run};
+use super::font_select::{find_font_for_text, font_support_count};
+use super::shape::shape_with_font;
+#[cfg(not(feature = "nostd"))]
+use super::static_cache::{ShapingCacheStats, StaticShapingCache, StaticShapingKey};
+use super::{EmojiHandling, FontFeatures, PixelFontMetrics, ShapedText};
+
+/// Advance of an emoji cluster, in multiples of the font size (em-square)
+pub const EMOJI_ADVANCE_EMS: f32 = 2.0;
+
+/// Text shaper with OpenType feature overrides and emoji handling
+///
+/// The default configuration shapes exactly like [`super::shape_text_with_style`].
+/// Shaped text is kept in a [`StaticShapingCache`] so static subtitle text is
+/// shaped once rather than on every frame; clones share the cache.
+///
+/// Fonts registered with [`TextShaper::load_fonts_from_script`] are kept in
+/// the shaper's own database, which [`TextShaper::has_glyph`] and
+/// [`TextShaper::missing_glyphs`] query for coverage.
+#[derive(Debug, Clone)]
+pub struct TextShaper {
+    /// OpenType feature overrides applied to every run
+    pub features: FontFeatures,
+    /// How emoji grapheme clusters are shaped
+    pub emoji: EmojiHandling,
+    /// BCP 47 language tag for language-specific shaping
+    pub language: Option<String>,
+    #[cfg(not(feature = "nostd"))]
+    cache: Option<Arc<StaticShapingCache>>,
+    /// Fonts loaded into the shaper
+    fonts: FontDatabase,
+}
+
+impl Default for TextShaper {
+    fn default() -> Self {
+        Self {
+            features: FontFeatures::default(),
+            emoji: EmojiHandling::default(),
+            language: None,
+            #[cfg(not(feature = "nostd"))]
+            cache: Some(Arc::new(StaticShapingCache::new())),
+            fonts: FontDatabase::new(),
+        }
+    }
+}
+
+impl TextShaper {
+    /// Create a shaper with default features that ignores emoji
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Shape with language-specific rules for the BCP 47 tag `language`
+    #[must_use]
+    pub fn with_language(mut self, language: impl Into<String>) -> Self {
+        self.language = Some(language.into());
+        self
+    }
+
+    /// Enable or disable the static shaping cache
+    #[cfg(not(feature = "nostd"))]
+    #[must_use]
+    pub fn with_shaping_cache(mut self, enabled: bool) -> Self {
+        self.cache = enabled.then(|| Arc::new(StaticShapingCache::new()));
+        self
+    }
+
+    /// Hit and miss counts of the static shaping cache (zero when disabled)
+    #[cfg(not(feature = "nostd"))]
+    pub fn cache_stats(&self) -> ShapingCacheStats {
+        self.cache
+            .as_ref()
+            .map(|cache| cache.stats())
+            .unwrap_or_default()
+    }
+
+    /// Drop every cached shaping result, e.g. after replacing a font file
+    /// in place
+    #[cfg(not(feature = "nostd"))]
+    pub fn invalidate_cache(&self) {
+        if let Some(cache) = &self.cache {
+            cache.clear();
+        }
+    }
+
+    /// Use `features` for every shaped run
+    #[must_use]
+    pub fn with_features(mut self, features: FontFeatures) -> Self {
+        self.features = features;
+        self
+    }
+
+    /// Use `emoji` handling for emoji grapheme clusters
+    #[must_use]
+    pub fn with_emoji_handling(mut self, emoji: EmojiHandling) -> Self {
+        self.emoji = emoji;
+        self
+    }
+
+    /// Fonts loaded into this shaper, ready to pass to
+    /// [`TextShaper::shape_text`]
+    pub fn fonts(&self) -> &FontDatabase {
+        &self.fonts
+    }
+
+    /// Register every font embedded in the `[Fonts]` sections of `script`
+    ///
+    /// Returns how many embedded fonts yielded at least one usable face;
+    /// entries that are not valid font files are skipped.
+    pub fn load_fonts_from_script(&mut self, script: &Script) -> usize {
+        script
+            .embedded_fonts()
+            .into_iter()
+            .filter(|font| {
+                let before = self.fonts.len();
+                self.fonts.load_font_data(font.data.clone());
+                self.fonts.len() > before
+            })
+            .count()
+    }
+
+    /// Whether any loaded font has a glyph for `codepoint`
+    pub fn has_glyph(&self, codepoint: char) -> bool {
+        self.uncovered(Vec::from([codepoint])).is_empty()
+    }
+
+    /// Distinct characters of `text` that no loaded font has a glyph for,
+    /// in order of first appearance
+    ///
+    /// Control characters such as line breaks are never reported, as they
+    /// are not drawn.
+    pub fn missing_glyphs(&self, text: &str) -> Vec<char> {
+        let mut chars = Vec::new();
+        for ch in text.chars().filter(|ch| !ch.is_control()) {
+            if !chars.contains(&ch) {
+                chars.push(ch);
+            }
+        }
+        self.uncovered(chars)
+    }
+
+    /// The subset of `chars` that no loaded font covers
+    fn uncovered(&self, mut chars: Vec<char>) -> Vec<char> {
+        for face in self.fonts.faces() {
+            if chars.is_empty() {
+                break;
+            }
+            self.fonts.with_face_data(face.id, |data, index| {
+                if let Ok(face) = ttf_parser::Face::parse(data, index) {
+                    chars.retain(|&ch| face.glyph_index(ch).is_none());
+                }
+            });
+        }
+        chars
+    }
+
+    /// Shape `text` with this shaper's configuration
+    ///
+    /// Unless emoji are ignored, each emoji grapheme cluster (flags, ZWJ and
+    /// skin-tone sequences included) is shaped on its own and given a single
+    /// advance of [`EMOJI_ADVANCE_EMS`] times the font size, with every glyph
+    /// of the cluster reporting the cluster's byte offset.
+    ///
+    /// Results are served from the static shaping cache when the same text
+    /// was shaped before with the same font, size and language.
+    pub fn shape_text(
+        &self,
+        text: &str,
+        font_family: &str,
+        font_size: f32,
+        bold: bool,
+        italic: bool,
+        font_database: &FontDatabase,
+    ) -> Result<ShapedText, RenderError> {
+        #[cfg(not(feature = "nostd"))]
+        if let Some(cache) = &self.cache {
+            let font_id = find_font_for_text(font_database, font_family, bold, italic, text)?;
+            let key = StaticShapingKey::new(text, font_id, font_size, self.language.as_deref());
+            if let Some(shaped) = cache.get(&key, text, font_database, &self.features, self.emoji) {
+                return Ok(shaped);
+            }
+            let shaped =
+                self.shape_uncached(text, font_family, font_size, bold, italic, font_database)?;
+            cache.insert(key, text, shaped.clone());
+            return Ok(shaped);
+        }
+        self.shape_uncached(text, font_family, font_size, bold, italic, font_database)
+    }
+
+    /// Shape `text` without consulting the cache
+    fn shape_uncached(
+        &self,
+        text: &str,
+        font_family: &str,
+        font_size: f32,
+        bold: bool,
+        italic: bool,
+        font_database: &FontDatabase,
+    ) -> Result<ShapedText, RenderError> {
+        let language = self.language.as_deref();
+        let shape_run = |run: &str| {
+            let font_id = find_font_for_text(font_database, font_family, bold, italic, run)?;
+            shape_with_font(
+                run,
+                font_id,
+                font_size,
+                &self.features,
+                language,
+                font_database,
+            )
+        };
+
+        let runs = emoji_runs(text);
+        if self.emoji == EmojiHandling::IgnoreEmoji || !runs.iter().any(|run| run.emoji) {
+            return shape_run(text);
+        }
+
+        let mut pieces = Vec::with_capacity(runs.len());
+        for Run { range, emoji } in runs {
+            let run = &text[range.clone()];
+            let shaped = if emoji {
+                let shaped =
+                    self.shape_emoji(run, font_family, font_size, bold, italic, font_database)?;
+                square_cluster(shaped, font_size, self.emoji == EmojiHandling::SquareBlock)
+            } else {
+                shape_run(run)?
+            };
+            pieces.push((range.start, shaped));
+        }
+        Ok(join_pieces(pieces))
+    }
+
+    /// Shape one emoji cluster, switching to the emoji face when configured
+    /// and the primary font lacks any of its codepoints
+    fn shape_emoji(
+        &self,
+        cluster: &str,
+        font_family: &str,
+        font_size: f32,
+        bold: bool,
+        italic: bool,
+        font_database: &FontDatabase,
+    ) -> Result<ShapedText, RenderError> {
+        let primary = find_font_for_text(font_database, font_family, bold, italic, cluster)?;
+        let font_id = match self.emoji {
+            EmojiHandling::UseEmojiFont(index) => {
+                let covered = font_support_count(font_database, primary, cluster)?;
+                if covered < cluster.chars().count() {
+                    emoji_face(font_database, index).unwrap_or(primary)
+                } else {
+                    primary
+                }
+            }
+            EmojiHandling::IgnoreEmoji | EmojiHandling::SquareBlock => primary,
+        };
+        shape_with_font(
+            cluster,
+            font_id,
+            font_size,
+            &self.features,
+            self.language.as_deref(),
+            font_database,
+        )
+    }
+}
+
+/// Face at `index` in the database's face list
+fn emoji_face(font_database: &FontDatabase, index: usize) -> Option<FontId> {
+    font_database.faces().nth(index).map(|face| face.id)
+}
+
+/// Give a shaped emoji cluster a single square advance
+///
+/// With `single_glyph`, only the first glyph is kept; otherwise the glyph
+/// advances are scaled so that they add up to the square advance.
+fn square_cluster(mut shaped: ShapedText, font_size: f32, single_glyph: bool) -> ShapedText {
+    let advance = font_size * EMOJI_ADVANCE_EMS;
+    if single_glyph {
+        shaped.glyphs.truncate(1);
+    }
+    let natural: f32 = shaped.glyphs.iter().map(|glyph| glyph.x_advance).sum();
+    let scale = if natural > 0.0 {
+        advance / natural
+    } else {
+        0.0
+    };
+    let mut pen = 0.0;
+    for (i, glyph) in shaped.glyphs.iter_mut().enumerate() {
+        glyph.cluster = 0;
+        glyph.x_advance = if natural > 0.0 {
+            glyph.x_advance * scale
+        } else if i == 0 {
+            advance
+        } else {
+            0.0
+        };
+        glyph.x_position = pen + glyph.x_offset;
+        pen += glyph.x_advance;
+    }
+    shaped.width = advance;
+    shaped.ink_min = 0.0;
+    shaped.ink_max = advance;
+    shaped
+}
+
+/// Concatenate shaped runs, offsetting positions and cluster indices
+fn join_pieces(pieces: Vec<(usize, ShapedText)>) -> ShapedText {
+    let mut pieces = pieces.into_iter();
+    let Some((_, mut joined)) = pieces.next() else {
+        return ShapedText {
+            glyphs: Vec::new(),
+            width: 0.0,
+            height: 0.0,
+            baseline: 0.0,
+            font_size: 0.0,
+            ascent: 0.0,
+            descent: 0.0,
+            ink_min: 0.0,
+            ink_max: 0.0,
+            metrics: PixelFontMetrics::default(),
+        };
+    };
+    for (offset, piece) in pieces {
+        let pen = joined.width;
+        let cluster_offset = u32::try_from(offset).unwrap_or(u32::MAX);
+        joined
+            .glyphs
+            .extend(piece.glyphs.into_iter().map(|mut glyph| {
+                glyph.x_position += pen;
+                glyph.cluster += cluster_offset;
+                glyph
+            }));
+        joined.ink_min = joined.ink_min.min(pen + piece.ink_min);
+        joined.ink_max = joined.ink_max.max(pen + piece.ink_max);
+        joined.width += piece.width;
+        joined.height = joined.height.max(piece.height);
+        joined.ascent = joined.ascent.max(piece.ascent);
+        joined.descent = joined.descent.min(piece.descent);
+    }
+    joined
+}
@@ -0,0 +1,159 @@
+//! Cross-frame cache of shaped static text for `TextShaper`.
+
+use std::collections::HashMap;
+use std::hash::{Hash, Hasher};
+use std::string::String;
+use std::sync::Mutex;
+
+use fontdb::{Database as FontDatabase, ID as FontId};
+
+use super::{EmojiHandling, FontFeatures, ShapedText};
+
+/// Entries kept before the cache is cleared; re-shaping a cold run is cheap
+/// next to the savings on hot ones
+const CAPACITY: usize = 8192;
+
+/// Hit and miss counts of a [`StaticShapingCache`]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ShapingCacheStats {
+    /// Lookups served from the cache
+    pub hits: u64,
+    /// Lookups that had to shape
+    pub misses: u64,
+}
+
+impl ShapingCacheStats {
+    /// Fraction of lookups served from the cache (0 when none were made)
+    pub fn hit_rate(&self) -> f64 {
+        let total = self.hits + self.misses;
+        if total == 0 {
+            0.0
+        } else {
+            self.hits as f64 / total as f64
+        }
+    }
+}
+
+/// Identity of a shaped run
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub(super) struct StaticShapingKey {
+    text_hash: u64,
+    font_id: FontId,
+    font_size_bits: u32,
+    language_hash: u64,
+}
+
+impl StaticShapingKey {
+    pub(super) fn new(text: &str, font_id: FontId, font_size: f32, language: Option<&str>) -> Self {
+        Self {
+            text_hash: hash_of(text),
+            font_id,
+            font_size_bits: font_size.to_bits(),
+            language_hash: hash_of(&language),
+        }
+    }
+}
+
+fn hash_of<T: Hash + ?Sized>(value: &T) -> u64 {
+    let mut hasher = ahash::AHasher::default();
+    value.hash(&mut hasher);
+    hasher.finish()
+}
+
+#[derive(Debug, Default)]
+struct Inner {
+    /// Shaped runs with their text, compared on lookup to rule out hash
+    /// collisions
+    entries: HashMap<StaticShapingKey, (String, ShapedText)>,
+    /// Address and face count of the font database the entries came from
+    database: Option<(usize, usize)>,
+    /// Shaper configuration the entries were shaped with
+    config: Option<(FontFeatures, EmojiHandling)>,
+    stats: ShapingCacheStats,
+}
+
+/// Shaped text reused across frames while an event's text stays unchanged
+///
+/// Keyed by text hash, selected font, font size and language. Entries are
+/// dropped when a different font database is used, faces are loaded into
+/// it (font selection may change), or the shaper's features or emoji
+/// handling change.
+#[derive(Debug, Default)]
+pub struct StaticShapingCache {
+    inner: Mutex<Inner>,
+}
+
+impl StaticShapingCache {
+    /// Create an empty cache
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Shaped run for `key`, counting a hit or miss
+    pub(super) fn get(
+        &self,
+        key: &StaticShapingKey,
+        text: &str,
+        font_database: &FontDatabase,
+        features: &FontFeatures,
+        emoji: EmojiHandling,
+    ) -> Option<ShapedText> {
+        let mut inner = self.lock();
+        let database = (
+            font_database as *const FontDatabase as usize,
+            font_database.len(),
+        );
+        let config_changed = inner
+            .config
+            .as_ref()
+            .is_none_or(|(cached, handling)| cached != features || *handling != emoji);
+        if inner.database != Some(database) || config_changed {
+            inner.entries.clear();
+            inner.database = Some(database);
+            inner.config = Some((features.clone(), emoji));
+        }
+        let shaped = inner
+            .entries
+            .get(key)
+            .filter(|(cached, _)| cached == text)
+            .map(|(_, shaped)| shaped.clone());
+        match shaped {
+            Some(_) => inner.stats.hits += 1,
+            None => inner.stats.misses += 1,
+        }
+        shaped
+    }
+
+    /// Store the shaped run for `key`
+    pub(super) fn insert(&self, key: StaticShapingKey, text: &str, shaped: ShapedText) {
+        let mut inner = self.lock();
+        if inner.entries.len() >= CAPACITY {
+            inner.entries.clear();
+        }
+        inner.entries.insert(key, (text.into(), shaped));
+    }
+
+    /// Drop every cached run, keeping the statistics
+    pub fn clear(&self) {
+        self.lock().entries.clear();
+    }
+
+    /// Hit and miss counts so far
+    pub fn stats(&self) -> ShapingCacheStats {
+        self.lock().stats
+    }
+
+    /// Number of cached runs
+    pub fn len(&self) -> usize {
+        self.lock().entries.len()
+    }
+
+    /// Whether no run is cached
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, Inner> {
+        self.inner.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
@@ -121,6 +121,11 @@ pub(super) fn process_tag_block(
                     current.font.spacing = Some(spacing);
                 }
             }
+            "pbo" => {
+                if let Ok(offset) = args.parse::<f32>() {
+                    current.baseline_offset = Some(offset);
+                }
+            }
             "blur" => {
                 if let Ok(radius) = args.parse::<f32>() {
                     current.formatting.blur = Some(radius);
@@ -5,8 +5,9 @@ mod split;
 
 use apply::process_tag_block;
 
+use crate::layout::WrapMode;
 use crate::utils::RenderError;
-use ass_core::analysis::events::TextAnalysis;
+use ass_core::analysis::events::{TextAnalysis, TextRun};
 use ass_core::ExtensionRegistry;
 
 #[cfg(feature = "nostd")]
@@ -35,9 +36,25 @@ pub struct TextSegment {
 }
 
 /// Process text with inline tag changes into segments
+///
+/// Every `\n` soft break becomes a line break, as under `WrapStyle: 2`; see
+/// [`segment_text_with_wrap_style`].
 pub fn segment_text_with_tags(
+    text: &str,
+    registry: Option<&ExtensionRegistry>,
+) -> Result<Vec<TextSegment>, RenderError> {
+    segment_text_with_wrap_style(text, registry, WrapMode::NoWrap)
+}
+
+/// Process text with inline tag changes into segments for a script whose
+/// `WrapStyle` header is `wrap_style`
+///
+/// `\n` soft breaks become line breaks only when the header or a preceding
+/// `\q` selects [`WrapMode::NoWrap`] and are spaces otherwise, as in libass.
+pub fn segment_text_with_wrap_style(
     text: &str,
     _registry: Option<&ExtensionRegistry>,
+    wrap_style: WrapMode,
 ) -> Result<Vec<TextSegment>, RenderError> {
     // Analyze text to get tags and their positions
     #[cfg(feature = "plugins")]
@@ -51,8 +68,16 @@ pub fn segment_text_with_tags(
     let tags = analysis.override_tags();
     if tags.is_empty() {
         // No tags, return single segment with plain text
+        let plain = if wrap_style == WrapMode::NoWrap {
+            analysis.plain_text().to_string()
+        } else {
+            TextRun::parse(text)
+                .iter()
+                .map(|run| run.as_str(wrap_style))
+                .collect()
+        };
         return Ok(vec![TextSegment {
-            text: analysis.plain_text().to_string(),
+            text: plain,
             start: 0,
             end: text.len(),
             tags: super::tag_processor::ProcessedTags::default(),
@@ -123,13 +148,17 @@ pub fn segment_text_with_tags(
                 // Check for escape sequences
                 if let Some(next) = chars.next() {
                     pos += next.len_utf8();
-                    match next {
-                        'N' | 'n' => current_text.push('\n'),
-                        'h' => current_text.push('\u{00A0}'),
-                        _ => {
-                            current_text.push(ch);
-                            current_text.push(next);
-                        }
+                    if let Some(run) = TextRun::from_escape(next) {
+                        // libass honours `\n` when either the header or the
+                        // current `\q` is 2
+                        let mode = match current_tags.formatting.wrap_style {
+                            Some(2) => WrapMode::NoWrap,
+                            _ => wrap_style,
+                        };
+                        current_text.push_str(run.as_str(mode));
+                    } else {
+                        current_text.push(ch);
+                        current_text.push(next);
                     }
                 } else {
                     current_text.push(ch);
@@ -1,9 +1,11 @@
 //! Parsing and easing helpers for transform (`\t`) animations.
 
 #[cfg(feature = "nostd")]
-use alloc::{string::String, vec::Vec};
+use alloc::vec::Vec;
 #[cfg(not(feature = "nostd"))]
-use std::{string::String, vec::Vec};
+use std::vec::Vec;
+
+use ass_core::override_parser::{OverrideBlock, OverrideTag};
 
 use super::AnimatableTag;
 
@@ -44,89 +46,34 @@ pub(super) fn apply_acceleration_curve(t: f32, accel: f32) -> f32 {
 
 /// Parse tags that can be animated
 pub(super) fn parse_animatable_tags(tag_string: &str) -> Vec<AnimatableTag> {
-    let mut tags = Vec::new();
-    let mut pos = 0;
-    let chars: Vec<char> = tag_string.chars().collect();
-
-    while pos < chars.len() {
-        if chars[pos] == '\\' {
-            pos += 1;
-            let tag_start = pos;
-
-            // Parse tag name - stop at first non-letter character after initial letter(s)
-            // unless it's a tag that starts with a digit (like 1c, 2c, 3a, 4a)
-            let first_char = if tag_start < chars.len() {
-                chars[tag_start]
-            } else {
-                ' '
-            };
-            if first_char.is_ascii_digit() {
-                // Tags like 1c, 2c, 3a, 4a
-                pos += 1; // Skip the digit
-                while pos < chars.len() && chars[pos].is_ascii_alphabetic() {
-                    pos += 1;
-                }
-            } else {
-                // Normal tags - only letters
-                while pos < chars.len() && chars[pos].is_ascii_alphabetic() {
-                    pos += 1;
-                }
-            }
-
-            if pos > tag_start {
-                let tag_name = chars[tag_start..pos].iter().collect::<String>();
-
-                // Parse tag arguments
-                let arg_start = pos;
-                while pos < chars.len() && chars[pos] != '\\' {
-                    pos += 1;
-                }
-
-                let args = chars[arg_start..pos].iter().collect::<String>();
-
-                // Create animatable tag
-                if let Some(tag) = create_animatable_tag(&tag_name, &args) {
-                    tags.push(tag);
-                }
-            }
-        } else {
-            pos += 1;
-        }
-    }
-
-    tags
-}
-
-fn create_animatable_tag(name: &str, args: &str) -> Option<AnimatableTag> {
-    match name {
-        "fs" => args.parse::<f32>().ok().map(AnimatableTag::FontSize),
-        "fscx" => args.parse::<f32>().ok().map(AnimatableTag::FontScaleX),
-        "fscy" => args.parse::<f32>().ok().map(AnimatableTag::FontScaleY),
-        "fsp" => args.parse::<f32>().ok().map(AnimatableTag::FontSpacing),
-        "frz" | "fr" => args.parse::<f32>().ok().map(AnimatableTag::FontRotationZ),
-        "frx" => args.parse::<f32>().ok().map(AnimatableTag::FontRotationX),
-        "fry" => args.parse::<f32>().ok().map(AnimatableTag::FontRotationY),
-        "c" | "1c" => parse_color_arg(args).map(AnimatableTag::PrimaryColor),
-        "2c" => parse_color_arg(args).map(AnimatableTag::SecondaryColor),
-        "3c" => parse_color_arg(args).map(AnimatableTag::OutlineColor),
-        "4c" => parse_color_arg(args).map(AnimatableTag::ShadowColor),
-        "alpha" => parse_alpha_arg(args).map(AnimatableTag::Alpha),
-        "bord" => args.parse::<f32>().ok().map(AnimatableTag::BorderWidth),
-        "shad" => args.parse::<f32>().ok().map(AnimatableTag::ShadowDepth),
-        "blur" | "be" => args.parse::<f32>().ok().map(AnimatableTag::Blur),
-        _ => None,
-    }
-}
-
-fn parse_color_arg(args: &str) -> Option<[u8; 4]> {
-    ass_core::utils::parse_bgr_color(args).ok()
+    OverrideBlock::parse(tag_string)
+        .tags
+        .into_iter()
+        .filter_map(animatable_tag)
+        .collect()
 }
 
-fn parse_alpha_arg(args: &str) -> Option<u8> {
-    let hex = args.trim_start_matches("&H").trim_start_matches("&h");
-    // ASS uses inverted alpha: 0 = opaque, 255 = transparent
-    // We need to invert it to match standard RGBA: 255 = opaque, 0 = transparent
-    u8::from_str_radix(hex, 16)
-        .ok()
-        .map(|ass_alpha| 255 - ass_alpha)
+fn animatable_tag(tag: OverrideTag) -> Option<AnimatableTag> {
+    Some(match tag {
+        OverrideTag::FontSize(size) => AnimatableTag::FontSize(size),
+        OverrideTag::FontScaleX(scale) => AnimatableTag::FontScaleX(scale),
+        OverrideTag::FontScaleY(scale) => AnimatableTag::FontScaleY(scale),
+        OverrideTag::Spacing(spacing) => AnimatableTag::FontSpacing(spacing),
+        OverrideTag::RotationZ(degrees) => AnimatableTag::FontRotationZ(degrees),
+        OverrideTag::RotationX(degrees) => AnimatableTag::FontRotationX(degrees),
+        OverrideTag::RotationY(degrees) => AnimatableTag::FontRotationY(degrees),
+        OverrideTag::PrimaryColor(rgba) => AnimatableTag::PrimaryColor(rgba),
+        OverrideTag::SecondaryColor(rgba) => AnimatableTag::SecondaryColor(rgba),
+        OverrideTag::OutlineColor(rgba) => AnimatableTag::OutlineColor(rgba),
+        OverrideTag::BackColor(rgba) => AnimatableTag::ShadowColor(rgba),
+        // ASS uses inverted alpha: 0 = opaque, 255 = transparent
+        // We need to invert it to match standard RGBA: 255 = opaque, 0 = transparent
+        OverrideTag::Alpha(alpha) => AnimatableTag::Alpha(255 - alpha),
+        OverrideTag::Border(width) => AnimatableTag::BorderWidth(width),
+        OverrideTag::Shadow(depth) => AnimatableTag::ShadowDepth(depth),
+        OverrideTag::Blur(strength) | OverrideTag::BlurEdges(strength) => {
+            AnimatableTag::Blur(strength)
+        }
+        _ => return None,
+    })
 }
@@ -3,6 +3,10 @@
 use crate::plugin::{EffectParams, EffectPlugin};
 use crate::utils::RenderError;
 
+mod noise;
+
+pub use noise::GaussianNoisePlugin;
+
 /// Example custom glow effect
 pub struct GlowEffect {
     radius: f32,
@@ -0,0 +1,145 @@
+//! Film-grain style Gaussian noise effect
+
+use crate::plugin::{EffectContext, EffectParams, EffectPlugin, EffectResult};
+use crate::renderer::Frame;
+use crate::utils::RenderError;
+
+/// Adds Gaussian noise to the colour of visible subtitle pixels
+///
+/// Fully transparent pixels and alpha are left untouched. Noise is seeded
+/// from the plugin seed and the frame time, so a frame renders identically
+/// every time it is produced.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GaussianNoisePlugin {
+    /// Standard deviation of the noise in 8-bit colour levels
+    pub sigma: f32,
+    /// Seed mixed with the frame time
+    pub seed: u64,
+}
+
+impl GaussianNoisePlugin {
+    /// Create a noise effect with the given standard deviation
+    pub fn new(sigma: f32) -> Self {
+        Self { sigma, seed: 0 }
+    }
+
+    /// Use `seed` for the noise sequence
+    #[must_use]
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.seed = seed;
+        self
+    }
+
+    /// Add noise scaled by `strength` to premultiplied RGBA `pixels`
+    fn add_noise(&self, pixels: &mut [u8], strength: f32, time_cs: u32) {
+        let sigma = self.sigma * strength;
+        if sigma <= 0.0 {
+            return;
+        }
+        let mut rng = XorShift::new(self.seed ^ (u64::from(time_cs) << 32));
+        for pixel in pixels.chunks_exact_mut(4) {
+            let alpha = pixel[3];
+            if alpha == 0 {
+                continue;
+            }
+            for channel in &mut pixel[..3] {
+                let noisy = f32::from(*channel) + rng.gaussian() * sigma;
+                // Premultiplied colour never exceeds alpha
+                *channel = noisy.round().clamp(0.0, f32::from(alpha)) as u8;
+            }
+        }
+    }
+}
+
+impl EffectPlugin for GaussianNoisePlugin {
+    fn name(&self) -> &str {
+        "GaussianNoise"
+    }
+
+    fn version(&self) -> &str {
+        "1.0.0"
+    }
+
+    fn apply_cpu(
+        &self,
+        pixels: &mut [u8],
+        width: u32,
+        height: u32,
+        params: &EffectParams,
+    ) -> Result<(), RenderError> {
+        let _ = (width, height);
+        self.add_noise(pixels, params.strength, 0);
+        Ok(())
+    }
+
+    fn apply_post(&self, frame: &mut Frame, ctx: &EffectContext) -> EffectResult {
+        self.add_noise(frame.data_mut(), 1.0, ctx.time_cs);
+        Ok(())
+    }
+}
+
+/// Small xorshift generator; quality is ample for grain
+struct XorShift(u64);
+
+impl XorShift {
+    fn new(seed: u64) -> Self {
+        // Zero is a fixed point of xorshift
+        Self(seed ^ 0x9E37_79B9_7F4A_7C15)
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        let mut x = self.0;
+        x ^= x << 13;
+        x ^= x >> 7;
+        x ^= x << 17;
+        self.0 = x;
+        x
+    }
+
+    /// Uniform sample in (0, 1]
+    fn uniform(&mut self) -> f32 {
+        ((self.next_u64() >> 40) as f32 + 1.0) / (1u64 << 24) as f32
+    }
+
+    /// Standard normal sample (Box-Muller)
+    fn gaussian(&mut self) -> f32 {
+        let (u1, u2) = (self.uniform(), self.uniform());
+        (-2.0 * u1.ln()).sqrt() * (core::f32::consts::TAU * u2).cos()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn noise_is_deterministic_and_respects_alpha() {
+        let mut pixels = vec![0u8; 64 * 4];
+        for (i, pixel) in pixels.chunks_exact_mut(4).enumerate() {
+            let alpha = if i % 2 == 0 { 200 } else { 0 };
+            pixel.copy_from_slice(&[100, 100, 100, alpha]);
+        }
+        let mut frame = Frame::from_rgba(pixels.clone(), 8, 8);
+        let mut again = Frame::from_rgba(pixels, 8, 8);
+        let plugin = GaussianNoisePlugin::new(20.0).with_seed(7);
+        let ctx = EffectContext::new(150, 8, 8);
+        plugin.apply_post(&mut frame, &ctx).unwrap();
+        plugin.apply_post(&mut again, &ctx).unwrap();
+
+        assert_eq!(frame.data(), again.data());
+        let changed = frame
+            .data()
+            .chunks_exact(4)
+            .filter(|pixel| pixel[..3] != [100, 100, 100])
+            .count();
+        assert!(changed > 0);
+        for (i, pixel) in frame.data().chunks_exact(4).enumerate() {
+            if i % 2 == 0 {
+                assert_eq!(pixel[3], 200);
+                assert!(pixel[..3].iter().all(|&c| c <= 200));
+            } else {
+                assert_eq!(pixel, [100, 100, 100, 0]);
+            }
+        }
+    }
+}
@@ -1,16 +1,19 @@
 //! Plugin system for custom effects and backends
 
+use crate::renderer::Frame;
 use crate::utils::RenderError;
 use ahash::AHashMap;
 
 #[cfg(feature = "nostd")]
 use alloc::{
+    boxed::Box,
     string::{String, ToString},
     sync::Arc,
     vec::Vec,
 };
 #[cfg(not(feature = "nostd"))]
 use std::{
+    boxed::Box,
     string::{String, ToString},
     sync::Arc,
     vec::Vec,
@@ -44,8 +47,96 @@ pub trait EffectPlugin: Send + Sync {
     fn supports_gpu(&self) -> bool {
         self.shader_code().is_some()
     }
+
+    /// Order of this plugin in the effect pipeline; lower values run first
+    fn priority(&self) -> i32 {
+        0
+    }
+
+    /// Pre-process the script source before it is parsed
+    ///
+    /// The default leaves the script unchanged.
+    fn apply_pre(&self, script: &mut ScriptMut, ctx: &EffectContext) -> EffectResult {
+        let _ = (script, ctx);
+        Ok(())
+    }
+
+    /// Post-process a rendered frame
+    ///
+    /// The default runs [`EffectPlugin::apply_cpu`] over the frame's pixels
+    /// at full strength.
+    fn apply_post(&self, frame: &mut Frame, ctx: &EffectContext) -> EffectResult {
+        let _ = ctx;
+        let (width, height) = (frame.width(), frame.height());
+        self.apply_cpu(frame.data_mut(), width, height, &EffectParams::new(1.0))
+    }
 }
 
+/// Result of running an effect stage
+pub type EffectResult = Result<(), RenderError>;
+
+/// Frame information passed to effect stages
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct EffectContext {
+    /// Time of the frame in centiseconds (0 when pre-processing)
+    pub time_cs: u32,
+    /// Output width in pixels
+    pub width: u32,
+    /// Output height in pixels
+    pub height: u32,
+    /// Video frame rate
+    pub frame_rate: f32,
+}
+
+impl EffectContext {
+    /// Create a context for a `width`x`height` frame at `time_cs`
+    pub fn new(time_cs: u32, width: u32, height: u32) -> Self {
+        Self {
+            time_cs,
+            width,
+            height,
+            frame_rate: 24.0,
+        }
+    }
+}
+
+/// Editable script source handed to pre-processing effects
+///
+/// A parsed `Script` borrows its source, so pre-processing works on the text
+/// and the caller parses the result.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ScriptMut {
+    source: String,
+}
+
+impl ScriptMut {
+    /// Wrap script source text
+    pub fn new(source: impl Into<String>) -> Self {
+        Self {
+            source: source.into(),
+        }
+    }
+
+    /// Current source text
+    pub fn source(&self) -> &str {
+        &self.source
+    }
+
+    /// Mutable source text
+    pub fn source_mut(&mut self) -> &mut String {
+        &mut self.source
+    }
+
+    /// Take the source text
+    pub fn into_source(self) -> String {
+        self.source
+    }
+}
+
+/// Handle of a plugin registered in the effect pipeline
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct PluginId(u64);
+
 /// Effect parameters
 #[derive(Debug, Clone)]
 pub struct EffectParams {
@@ -124,8 +215,14 @@ impl EffectParams {
 }
 
 /// Plugin registry for managing plugins
+///
+/// Plugins added with [`PluginRegistry::register`] form the effect pipeline
+/// run by the renderer; named effects are a separate lookup table.
 pub struct PluginRegistry {
     effects: AHashMap<String, Arc<dyn EffectPlugin>>,
+    /// Effect pipeline, sorted by priority then registration order
+    pipeline: Vec<(PluginId, Arc<dyn EffectPlugin>)>,
+    next_id: u64,
 }
 
 impl PluginRegistry {
@@ -133,7 +230,61 @@ impl PluginRegistry {
     pub fn new() -> Self {
         Self {
             effects: AHashMap::new(),
+            pipeline: Vec::new(),
+            next_id: 0,
+        }
+    }
+
+    /// Add a plugin to the effect pipeline
+    pub fn register(&mut self, plugin: Box<dyn EffectPlugin>) -> PluginId {
+        let id = PluginId(self.next_id);
+        self.next_id += 1;
+        let plugin: Arc<dyn EffectPlugin> = Arc::from(plugin);
+        let index = self
+            .pipeline
+            .partition_point(|(_, other)| other.priority() <= plugin.priority());
+        self.pipeline.insert(index, (id, plugin));
+        id
+    }
+
+    /// Remove a plugin from the effect pipeline
+    pub fn deregister(&mut self, id: PluginId) -> Option<Arc<dyn EffectPlugin>> {
+        let index = self.pipeline.iter().position(|(other, _)| *other == id)?;
+        Some(self.pipeline.remove(index).1)
+    }
+
+    /// Whether the effect pipeline is empty
+    pub fn is_empty(&self) -> bool {
+        self.pipeline.is_empty()
+    }
+
+    /// Plugins of the effect pipeline in the order they run
+    pub fn plugins(&self) -> impl Iterator<Item = (PluginId, &dyn EffectPlugin)> + '_ {
+        self.pipeline
+            .iter()
+            .map(|(id, plugin)| (*id, plugin.as_ref()))
+    }
+
+    /// Run every pipeline plugin's pre-processing over `source`, returning
+    /// the source to parse
+    pub fn apply_pre(&self, source: &str, ctx: &EffectContext) -> Result<String, RenderError> {
+        let mut script = ScriptMut::new(source);
+        for (_, plugin) in &self.pipeline {
+            plugin
+                .apply_pre(&mut script, ctx)
+                .map_err(|e| plugin_failure(plugin.as_ref(), e))?;
         }
+        Ok(script.into_source())
+    }
+
+    /// Run every pipeline plugin's post-processing over `frame`
+    pub fn apply_post(&self, frame: &mut Frame, ctx: &EffectContext) -> EffectResult {
+        for (_, plugin) in &self.pipeline {
+            plugin
+                .apply_post(frame, ctx)
+                .map_err(|e| plugin_failure(plugin.as_ref(), e))?;
+        }
+        Ok(())
     }
 
     /// Register an effect plugin
@@ -159,6 +310,18 @@ impl PluginRegistry {
     /// Clear all plugins
     pub fn clear(&mut self) {
         self.effects.clear();
+        self.pipeline.clear();
+    }
+}
+
+/// Attribute an effect stage failure to the plugin that raised it
+fn plugin_failure(plugin: &dyn EffectPlugin, error: RenderError) -> RenderError {
+    match error {
+        RenderError::PluginError { .. } => error,
+        other => RenderError::PluginError {
+            plugin_name: plugin.name().to_string(),
+            message: other.to_string(),
+        },
     }
 }
 
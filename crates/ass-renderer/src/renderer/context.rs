@@ -2,6 +2,8 @@
 
 use fontdb::Database as FontDatabase;
 
+use crate::layout::CoordinateTransform;
+
 #[cfg(feature = "nostd")]
 use alloc::sync::Arc;
 #[cfg(not(feature = "nostd"))]
@@ -19,6 +21,7 @@ pub struct RenderContext {
     storage_res_y: u32,
     frame_rate: f32,
     par: f32,
+    display_scale: f64,
 }
 
 impl RenderContext {
@@ -37,6 +40,7 @@ impl RenderContext {
             storage_res_y: height,
             frame_rate: 24.0,
             par: 1.0,
+            display_scale: 1.0,
         }
     }
 
@@ -52,9 +56,16 @@ impl RenderContext {
             storage_res_y: height,
             frame_rate: 24.0,
             par: 1.0,
+            display_scale: 1.0,
         }
     }
 
+    /// Set render size in logical pixels
+    pub fn set_size(&mut self, width: u32, height: u32) {
+        self.width = width;
+        self.height = height;
+    }
+
     /// Set playback resolution (from script info)
     pub fn set_playback_resolution(&mut self, x: u32, y: u32) {
         self.playback_res_x = x;
@@ -77,24 +88,65 @@ impl RenderContext {
         self.par = par;
     }
 
-    /// Get render width
+    /// Set the HiDPI display scale factor (physical pixels per logical pixel)
+    ///
+    /// Frames are rendered at the logical size times `factor`, with every
+    /// position scaled to match. Non-positive or non-finite factors reset it
+    /// to 1.0.
+    pub fn set_display_scale(&mut self, factor: f64) {
+        self.display_scale = if factor.is_finite() && factor > 0.0 {
+            factor
+        } else {
+            1.0
+        };
+    }
+
+    /// Get render width in physical pixels (logical width times display scale)
     pub fn width(&self) -> u32 {
-        self.width
+        Self::physical(self.width, self.display_scale)
     }
 
-    /// Get render height
+    /// Get render height in physical pixels (logical height times display scale)
     pub fn height(&self) -> u32 {
+        Self::physical(self.height, self.display_scale)
+    }
+
+    /// Get render width in logical pixels
+    pub fn logical_width(&self) -> u32 {
+        self.width
+    }
+
+    /// Get render height in logical pixels
+    pub fn logical_height(&self) -> u32 {
         self.height
     }
 
+    /// Get HiDPI display scale factor
+    pub fn display_scale(&self) -> f64 {
+        self.display_scale
+    }
+
+    /// Transform from playback resolution coordinates to physical output pixels
+    pub fn coordinate_transform(&self) -> CoordinateTransform {
+        CoordinateTransform::new(
+            f64::from(self.playback_res_x),
+            f64::from(self.playback_res_y),
+            f64::from(self.width),
+            f64::from(self.height),
+            self.display_scale,
+        )
+    }
+
     /// Get font database
     pub fn font_database(&self) -> &FontDatabase {
         &self.font_database
     }
 
     /// Get mutable font database
+    ///
+    /// The database is copied first when another context still shares it.
     pub fn font_database_mut(&mut self) -> &mut FontDatabase {
-        Arc::get_mut(&mut self.font_database).expect("Font database has multiple references")
+        Arc::make_mut(&mut self.font_database)
     }
 
     /// Get playback resolution X
@@ -139,12 +191,16 @@ impl RenderContext {
 
     /// Calculate X scale factor from playback to render resolution
     pub fn render_scale_x(&self) -> f32 {
-        self.width as f32 / self.playback_res_x.max(1) as f32
+        self.width() as f32 / self.playback_res_x.max(1) as f32
     }
 
     /// Calculate Y scale factor from playback to render resolution
     pub fn render_scale_y(&self) -> f32 {
-        self.height as f32 / self.playback_res_y.max(1) as f32
+        self.height() as f32 / self.playback_res_y.max(1) as f32
+    }
+
+    fn physical(logical: u32, display_scale: f64) -> u32 {
+        (f64::from(logical) * display_scale).round() as u32
     }
 }
 
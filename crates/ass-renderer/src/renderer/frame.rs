@@ -29,6 +29,57 @@ pub enum PixelFormat {
     Bgra8,
     /// RGB with 8 bits per channel
     Rgb8,
+    /// ARGB with 8 bits per channel
+    Argb8,
+    /// ABGR with 8 bits per channel
+    Abgr8,
+}
+
+/// Colour channel of a pixel
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Channel {
+    /// Red
+    Red,
+    /// Green
+    Green,
+    /// Blue
+    Blue,
+    /// Alpha
+    Alpha,
+}
+
+impl PixelFormat {
+    /// Bytes per pixel
+    pub const fn bytes_per_pixel(self) -> usize {
+        self.channel_order().len()
+    }
+
+    /// Channels in memory order, one per byte of a pixel
+    pub const fn channel_order(self) -> &'static [Channel] {
+        use Channel::{Alpha, Blue, Green, Red};
+        match self {
+            Self::Rgba8 => &[Red, Green, Blue, Alpha],
+            Self::Bgra8 => &[Blue, Green, Red, Alpha],
+            Self::Rgb8 => &[Red, Green, Blue],
+            Self::Argb8 => &[Alpha, Red, Green, Blue],
+            Self::Abgr8 => &[Alpha, Blue, Green, Red],
+        }
+    }
+
+    /// Byte offset of `channel` within a pixel, if the format has it
+    pub fn offset_of(self, channel: Channel) -> Option<usize> {
+        self.channel_order().iter().position(|&c| c == channel)
+    }
+}
+
+/// RGBA index of a channel
+const fn rgba_index(channel: Channel) -> usize {
+    match channel {
+        Channel::Red => 0,
+        Channel::Green => 1,
+        Channel::Blue => 2,
+        Channel::Alpha => 3,
+    }
 }
 
 impl Frame {
@@ -123,10 +174,7 @@ impl Frame {
 
     /// Get bytes per pixel
     pub fn bytes_per_pixel(&self) -> usize {
-        match self.format {
-            PixelFormat::Rgba8 | PixelFormat::Bgra8 => 4,
-            PixelFormat::Rgb8 => 3,
-        }
+        self.format.bytes_per_pixel()
     }
 
     /// Get stride (bytes per row)
@@ -135,35 +183,56 @@ impl Frame {
     }
 
     /// Convert to RGBA format if not already
-    pub fn to_rgba(mut self) -> Self {
-        match self.format {
-            PixelFormat::Rgba8 => self,
-            PixelFormat::Bgra8 => {
-                for chunk in Arc::make_mut(&mut self.buffer).chunks_exact_mut(4) {
-                    chunk.swap(0, 2);
-                }
-                self.format = PixelFormat::Rgba8;
-                self
+    pub fn to_rgba(self) -> Self {
+        self.to_format(PixelFormat::Rgba8)
+    }
+
+    /// Convert to `format`, reordering channels in place when the pixel size
+    /// is unchanged
+    ///
+    /// Converting to [`PixelFormat::Rgb8`] drops alpha; converting from it
+    /// makes every pixel opaque.
+    pub fn to_format(mut self, format: PixelFormat) -> Self {
+        if self.format == format {
+            return self;
+        }
+        let from = self.format.channel_order();
+        let to = format.channel_order();
+        let read = |pixel: &[u8]| {
+            let mut rgba = [0, 0, 0, 255];
+            for (&channel, &value) in from.iter().zip(pixel) {
+                rgba[rgba_index(channel)] = value;
             }
-            PixelFormat::Rgb8 => {
-                let mut rgba = Vec::with_capacity((self.width * self.height * 4) as usize);
-                for chunk in self.buffer.chunks_exact(3) {
-                    rgba.extend_from_slice(&[chunk[0], chunk[1], chunk[2], 255]);
+            rgba
+        };
+
+        if from.len() == to.len() {
+            for pixel in Arc::make_mut(&mut self.buffer).chunks_exact_mut(from.len()) {
+                let rgba = read(pixel);
+                for (&channel, byte) in to.iter().zip(pixel.iter_mut()) {
+                    *byte = rgba[rgba_index(channel)];
                 }
-                self.buffer = Arc::new(rgba);
-                self.format = PixelFormat::Rgba8;
-                self
             }
+        } else {
+            let mut converted = Vec::with_capacity(self.buffer.len() / from.len() * to.len());
+            for pixel in self.buffer.chunks_exact(from.len()) {
+                let rgba = read(pixel);
+                converted.extend(to.iter().map(|&channel| rgba[rgba_index(channel)]));
+            }
+            self.buffer = Arc::new(converted);
         }
+        self.format = format;
+        self
     }
 
     /// Check if frame is empty (all transparent)
     pub fn is_empty(&self) -> bool {
-        match self.format {
-            PixelFormat::Rgba8 | PixelFormat::Bgra8 => {
-                self.buffer.chunks_exact(4).all(|pixel| pixel[3] == 0)
-            }
-            PixelFormat::Rgb8 => false,
+        match self.format.offset_of(Channel::Alpha) {
+            Some(alpha) => self
+                .buffer
+                .chunks_exact(self.bytes_per_pixel())
+                .all(|pixel| pixel[alpha] == 0),
+            None => false,
         }
     }
 }
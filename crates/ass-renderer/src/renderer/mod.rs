@@ -15,13 +15,21 @@ mod event_selector;
 mod frame;
 mod metrics;
 mod probing;
+#[cfg(not(feature = "nostd"))]
+mod render_ahead;
 mod time_index;
+#[cfg(not(feature = "nostd"))]
+mod timed_cache;
+mod yuv;
 
 pub use context::RenderContext;
 pub use event_selector::{ActiveEvents, DirtyRegion, EventSelector};
-pub use frame::Frame;
+pub use frame::{Channel, Frame, PixelFormat};
 pub use metrics::{CacheStatistics, PerformanceMetrics};
 pub use probing::BackendProber;
+#[cfg(not(feature = "nostd"))]
+pub use render_ahead::RenderAheadQueue;
+pub use yuv::{rgb_to_yuv, yuv_to_rgb, Nv12Frame, YuvFrame};
 
 /// Main renderer that coordinates rendering pipeline
 pub struct Renderer {
@@ -34,6 +42,16 @@ pub struct Renderer {
     /// animated and the active set is unchanged — the common case of a subtitle
     /// shown across many frames. Animated frames (`\t`/`\move`/`\k`/`\fad`) skip it.
     frame_cache: Option<(Vec<(usize, usize)>, Frame)>,
+    /// Frames already rendered by [`Renderer::render_frame`], keyed by time,
+    /// output size and font scale. Cleared whenever the script content
+    /// changes.
+    #[cfg(not(feature = "nostd"))]
+    timed_frames: crate::cache::FrameCache,
+    /// Hash of the sections of the script the timed frames came from
+    #[cfg(not(feature = "nostd"))]
+    timed_frames_script: Option<u64>,
+    /// Effect plugins run over every frame from `render_frame`
+    plugins: crate::plugin::PluginRegistry,
 }
 
 impl Renderer {
@@ -52,6 +70,11 @@ impl Renderer {
             pipeline,
             event_selector: event_selector::EventSelector::new(),
             frame_cache: None,
+            #[cfg(not(feature = "nostd"))]
+            timed_frames: crate::cache::FrameCache::default(),
+            #[cfg(not(feature = "nostd"))]
+            timed_frames_script: None,
+            plugins: crate::plugin::PluginRegistry::new(),
         })
     }
 
@@ -68,9 +91,58 @@ impl Renderer {
             pipeline,
             event_selector: event_selector::EventSelector::new(),
             frame_cache: None,
+            #[cfg(not(feature = "nostd"))]
+            timed_frames: crate::cache::FrameCache::default(),
+            #[cfg(not(feature = "nostd"))]
+            timed_frames_script: None,
+            plugins: crate::plugin::PluginRegistry::new(),
         })
     }
 
+    /// Create a renderer for `script` on the given backend type
+    ///
+    /// The render context takes the script's `PlayResX`/`PlayResY` (derived
+    /// as in libass when missing, see `ScriptMetadata::play_resolution`), and
+    /// every entry of `fonts` (TTF/OTF bytes) and every font embedded in the
+    /// script's `[Fonts\]` section is registered with both the backend and
+    /// its pipeline, alongside the system fonts.
+    pub fn from_script(
+        script: &Script,
+        fonts: &[&[u8]],
+        backend_type: crate::backends::BackendType,
+    ) -> Result<Self, RenderError> {
+        let (width, height) = script.info().play_resolution();
+        let context = RenderContext::new(width, height);
+        let mut backend = crate::backends::create_backend(backend_type, width, height)?;
+        let mut pipeline = backend.create_pipeline()?;
+        for font in fonts {
+            backend.load_font_data(font.to_vec());
+            pipeline.load_font_data(font.to_vec());
+        }
+        for font in script.embedded_fonts() {
+            backend.load_font_data(font.data.clone());
+            pipeline.load_font_data(font.data);
+        }
+
+        Ok(Self {
+            context,
+            backend,
+            pipeline,
+            event_selector: event_selector::EventSelector::new(),
+            frame_cache: None,
+            #[cfg(not(feature = "nostd"))]
+            timed_frames: crate::cache::FrameCache::default(),
+            #[cfg(not(feature = "nostd"))]
+            timed_frames_script: None,
+            plugins: crate::plugin::PluginRegistry::new(),
+        })
+    }
+
+    /// Describe the capabilities of the active backend
+    pub fn backend_capabilities(&self) -> crate::backends::BackendCapabilities {
+        self.backend.capabilities()
+    }
+
     /// Create renderer with automatic backend detection
     #[cfg(feature = "backend-probing")]
     pub fn with_auto_backend(context: RenderContext) -> Result<Self, RenderError> {
@@ -80,25 +152,26 @@ impl Renderer {
     }
 
     /// Render a frame for the given script at the specified time
+    ///
+    /// Frames are served from the timed frame cache, see
+    /// [`Renderer::render_frame_cached`]: a time already rendered for the same
+    /// script content, output size and font scale is copied from the cache
+    /// instead of being rendered again.
     pub fn render_frame(&mut self, script: &Script, time_cs: u32) -> Result<Frame, RenderError> {
-        // Extract script resolution and update context
-        for section in script.sections() {
-            if let ass_core::parser::Section::ScriptInfo(info) = section {
-                if let Some((play_x, play_y)) = info.play_resolution() {
-                    self.context.set_playback_resolution(play_x, play_y);
-                }
-                if let Some((layout_x, layout_y)) = info.layout_resolution() {
-                    self.context.set_storage_resolution(layout_x, layout_y);
-                }
-                break; // Only need first ScriptInfo section
-            }
-        }
+        #[cfg(not(feature = "nostd"))]
+        return Ok(Frame::clone(&*self.render_frame_cached(script, time_cs)?));
+        #[cfg(feature = "nostd")]
+        self.render_uncached(script, time_cs)
+    }
 
+    /// Render a frame without consulting the timed frame cache
+    fn render_uncached(&mut self, script: &Script, time_cs: u32) -> Result<Frame, RenderError> {
+        self.apply_script_resolution(script);
         let active = self.event_selector.select_active(script, time_cs)?;
         let events = active.events;
 
         if events.is_empty() {
-            return Ok(Frame::empty(
+            return self.apply_post_effects(Frame::empty(
                 self.context.width(),
                 self.context.height(),
                 time_cs,
@@ -120,7 +193,8 @@ impl Renderer {
             (cache_key.as_ref(), self.frame_cache.as_ref())
         {
             if cached_key == key {
-                return Ok(cached.with_timestamp(time_cs));
+                let frame = cached.with_timestamp(time_cs);
+                return self.apply_post_effects(frame);
             }
         }
 
@@ -140,7 +214,84 @@ impl Renderer {
             time_cs,
         );
         self.frame_cache = cache_key.map(|key| (key, frame.clone()));
-        Ok(frame)
+        self.apply_post_effects(frame)
+    }
+
+    /// Render a frame in the given pixel format
+    ///
+    /// Frames are composited as RGBA and reordered in a single pass over the
+    /// output buffer.
+    pub fn render_with_format(
+        &mut self,
+        script: &Script,
+        time_cs: u32,
+        format: PixelFormat,
+    ) -> Result<Frame, RenderError> {
+        Ok(self.render_frame(script, time_cs)?.to_format(format))
+    }
+
+    /// Render a frame as a BGRA buffer, the byte order of Windows GDI bitmaps
+    pub fn render_bgra(&mut self, script: &Script, time_cs: u32) -> Result<Vec<u8>, RenderError> {
+        Ok(self
+            .render_with_format(script, time_cs, PixelFormat::Bgra8)?
+            .into_buffer())
+    }
+
+    /// Render a frame and convert it to planar BT.601 YUV 4:2:0
+    ///
+    /// Subtitles are composited over black; see [`Frame::to_yuv420`].
+    pub fn render_yuv420(
+        &mut self,
+        script: &Script,
+        time_cs: u32,
+    ) -> Result<YuvFrame, RenderError> {
+        Ok(self.render_frame(script, time_cs)?.to_yuv420())
+    }
+
+    /// Render a frame and convert it to NV12 (interleaved chroma)
+    pub fn render_nv12(&mut self, script: &Script, time_cs: u32) -> Result<Nv12Frame, RenderError> {
+        Ok(self.render_frame(script, time_cs)?.to_nv12())
+    }
+
+    /// Render a frame as RGBA into a caller-provided buffer
+    ///
+    /// The first `width * height * 4` bytes of `dst` receive the frame, the
+    /// rest is left untouched. Fails with [`RenderError::InvalidBufferSize`]
+    /// before rendering when `dst` is too small.
+    pub fn render_to_slice(
+        &mut self,
+        script: &Script,
+        time_cs: u32,
+        dst: &mut [u8],
+    ) -> Result<(), RenderError> {
+        let expected = self.context.width() as usize * self.context.height() as usize * 4;
+        if dst.len() < expected {
+            return Err(RenderError::InvalidBufferSize {
+                expected,
+                actual: dst.len(),
+            });
+        }
+
+        let frame = self.render_frame(script, time_cs)?;
+        let data = frame.data();
+        dst[..data.len()].copy_from_slice(data);
+        Ok(())
+    }
+
+    /// Render a frame and write its RGBA bytes to `out`
+    ///
+    /// Writes the rendered buffer straight to the writer, e.g. the stdin of
+    /// an encoder process, without copying it into another allocation.
+    #[cfg(not(feature = "nostd"))]
+    pub fn render_to_writer<W: std::io::Write>(
+        &mut self,
+        script: &Script,
+        time_cs: u32,
+        out: &mut W,
+    ) -> Result<(), RenderError> {
+        let frame = self.render_frame(script, time_cs)?;
+        out.write_all(frame.data())
+            .map_err(|e| RenderError::IOError(e.to_string()))
     }
 
     /// Render the active subtitles at `time_cs` to a positioned bitmap list
@@ -158,9 +309,8 @@ impl Renderer {
     ) -> Result<Vec<crate::backends::coverage::RenderBitmap>, RenderError> {
         for section in script.sections() {
             if let ass_core::parser::Section::ScriptInfo(info) = section {
-                if let Some((play_x, play_y)) = info.play_resolution() {
-                    self.context.set_playback_resolution(play_x, play_y);
-                }
+                let (play_x, play_y) = script.info().play_resolution();
+                self.context.set_playback_resolution(play_x, play_y);
                 if let Some((layout_x, layout_y)) = info.layout_resolution() {
                     self.context.set_storage_resolution(layout_x, layout_y);
                 }
@@ -182,6 +332,32 @@ impl Renderer {
             .render_layers_to_bitmaps(&layers, &self.context)
     }
 
+    /// Take the playback and layout resolution from the script's first
+    /// `[Script Info]` section
+    fn apply_script_resolution(&mut self, script: &Script) {
+        for section in script.sections() {
+            if let ass_core::parser::Section::ScriptInfo(info) = section {
+                let (play_x, play_y) = script.info().play_resolution();
+                self.context.set_playback_resolution(play_x, play_y);
+                if let Some((layout_x, layout_y)) = info.layout_resolution() {
+                    self.context.set_storage_resolution(layout_x, layout_y);
+                }
+                break; // Only need first ScriptInfo section
+            }
+        }
+    }
+
+    /// Run the registered effect plugins over a rendered frame
+    fn apply_post_effects(&self, mut frame: Frame) -> Result<Frame, RenderError> {
+        if !self.plugins.is_empty() {
+            let mut ctx =
+                crate::plugin::EffectContext::new(frame.timestamp(), frame.width(), frame.height());
+            ctx.frame_rate = self.context.frame_rate();
+            self.plugins.apply_post(&mut frame, &ctx)?;
+        }
+        Ok(frame)
+    }
+
     /// Whether an event's text carries a time-dependent override (`\t`, `\move`,
     /// karaoke `\k`/`\K`, or `\fad`), meaning its output changes between frames
     /// and must not be served from the static frame cache.
@@ -241,8 +417,58 @@ impl Renderer {
     }
 
     /// Update render context
+    ///
+    /// Cached frames are dropped, as the new context may render differently.
     pub fn set_context(&mut self, context: RenderContext) {
         self.context = context;
+        self.invalidate_context_frames();
+    }
+
+    /// Change the output size, e.g. after the video surface was resized
+    ///
+    /// Keeps the backend, pipeline, fonts and plugins and only drops the
+    /// frames rendered at the old size; the backend reallocates its buffer
+    /// on the next render. Does nothing when the size is unchanged.
+    pub fn notify_resize(&mut self, width: u32, height: u32) {
+        if (self.context.logical_width(), self.context.logical_height()) == (width, height) {
+            return;
+        }
+        self.context.set_size(width, height);
+        self.invalidate_context_frames();
+    }
+
+    /// Set the HiDPI display scale factor
+    ///
+    /// Frames are rendered at the context size times `factor` (so 2.0 on a
+    /// 2x display doubles every dimension and position), see
+    /// [`RenderContext::set_display_scale`].
+    pub fn set_display_scale(&mut self, factor: f64) {
+        self.context.set_display_scale(factor);
+        self.invalidate_context_frames();
+    }
+
+    /// Add an effect plugin run over every frame from `render_frame`
+    ///
+    /// Cached frames are dropped so that they pick up the new effect.
+    pub fn register_plugin(
+        &mut self,
+        plugin: Box<dyn crate::plugin::EffectPlugin>,
+    ) -> crate::plugin::PluginId {
+        #[cfg(not(feature = "nostd"))]
+        self.timed_frames.invalidate_all();
+        self.plugins.register(plugin)
+    }
+
+    /// Remove an effect plugin added with [`Renderer::register_plugin`]
+    pub fn deregister_plugin(&mut self, id: crate::plugin::PluginId) -> bool {
+        #[cfg(not(feature = "nostd"))]
+        self.timed_frames.invalidate_all();
+        self.plugins.deregister(id).is_some()
+    }
+
+    /// Registered effect plugins
+    pub fn plugins(&self) -> &crate::plugin::PluginRegistry {
+        &self.plugins
     }
 
     /// Get render context
@@ -251,10 +477,21 @@ impl Renderer {
     }
 
     /// Get mutable render context
+    ///
+    /// Cached frames are dropped, as changes to the context, such as loading
+    /// fonts, may render differently.
     pub fn context_mut(&mut self) -> &mut RenderContext {
+        self.invalidate_context_frames();
         &mut self.context
     }
 
+    /// Drop the frames rendered under the previous render context
+    fn invalidate_context_frames(&mut self) {
+        #[cfg(not(feature = "nostd"))]
+        self.timed_frames.invalidate_all();
+        self.frame_cache = None;
+    }
+
     /// Set collision resolver for subtitle positioning
     pub fn set_collision_resolver(
         &mut self,
@@ -74,6 +74,8 @@ impl BackendProber {
                 )?))
             }
 
+            BackendType::Null => Ok(Box::new(crate::backends::NullBackend::new())),
+
             _ => Err(RenderError::UnsupportedBackend(backend_type.as_str())),
         }
     }
@@ -87,6 +89,8 @@ impl BackendProber {
             #[cfg(feature = "gpu")]
             BackendType::Gpu => true,
 
+            BackendType::Null => true,
+
             _ => false,
         }
     }
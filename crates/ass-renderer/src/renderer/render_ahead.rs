@@ -0,0 +1,229 @@
+//! Render-ahead prefetch queue for live playback
+//!
+//! A background thread renders the frames just after the playback position,
+//! so a transient CPU spike while presenting does not drop a frame.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::sync::{Arc, Condvar, Mutex, MutexGuard, PoisonError};
+use std::thread::JoinHandle;
+
+use super::{Frame, Renderer};
+use crate::utils::RenderError;
+use ass_core::parser::Script;
+
+/// Frames rendered ahead of the playback position on a background thread
+///
+/// Frame `i` is displayed at `i / fps` seconds. The worker keeps the
+/// `lookahead_frames` frames starting at the playback position rendered,
+/// and sleeps while they all are. [`get_frame`](Self::get_frame) moves the
+/// playback position forward; [`seek`](Self::seek) jumps it anywhere. Either
+/// way, frames more than `lookahead_frames` away from the new position are
+/// flushed, and a frame rendered for the old position is discarded.
+///
+/// Dropping the queue stops and joins the worker.
+pub struct RenderAheadQueue {
+    shared: Arc<Shared>,
+    fps: f64,
+    worker: Option<JoinHandle<()>>,
+}
+
+/// State shared with the worker thread
+struct Shared {
+    state: Mutex<QueueState>,
+    wake: Condvar,
+}
+
+/// Queue contents and metrics
+#[derive(Default)]
+struct QueueState {
+    /// Frame index at the playback position
+    position: u64,
+    /// Number of frames kept rendered from the position on
+    lookahead: u64,
+    /// Pre-rendered frames by index
+    frames: BTreeMap<u64, Arc<Frame>>,
+    /// Frames that failed to render, so the worker does not retry them
+    failed: BTreeSet<u64>,
+    /// `get_frame` calls answered from the queue
+    hits: u64,
+    /// `get_frame` calls that found no frame
+    misses: u64,
+    /// Set when the queue is dropped
+    shutdown: bool,
+}
+
+impl QueueState {
+    /// Move the playback position to `index`, flushing frames out of reach
+    fn move_to(&mut self, index: u64) {
+        self.position = index;
+        let lookahead = self.lookahead;
+        self.frames
+            .retain(|&frame, _| frame.abs_diff(index) <= lookahead);
+        self.failed
+            .retain(|&frame| frame.abs_diff(index) <= lookahead);
+    }
+
+    /// Whether frame `index` belongs to the window ahead of the position
+    fn in_window(&self, index: u64) -> bool {
+        (self.position..self.position + self.lookahead).contains(&index)
+    }
+
+    /// First frame ahead of the position still to be rendered
+    fn next_missing(&self) -> Option<u64> {
+        (self.position..self.position + self.lookahead)
+            .find(|index| !self.frames.contains_key(index) && !self.failed.contains(index))
+    }
+}
+
+impl RenderAheadQueue {
+    /// Start prefetching frames of `script` with `renderer`
+    ///
+    /// Rendering starts at frame 0 right away. Returns
+    /// [`RenderError::InvalidInput`] unless `fps` is positive and finite.
+    pub fn new(
+        mut renderer: Renderer,
+        script: &Script,
+        fps: f64,
+        lookahead_frames: usize,
+    ) -> Result<Self, RenderError> {
+        if !fps.is_finite() || fps <= 0.0 {
+            return Err(RenderError::InvalidInput(format!(
+                "Frame rate must be positive, got {fps}"
+            )));
+        }
+
+        let shared = Arc::new(Shared {
+            state: Mutex::new(QueueState {
+                lookahead: lookahead_frames as u64,
+                ..QueueState::default()
+            }),
+            wake: Condvar::new(),
+        });
+        let script = script.clone().into_owned();
+        let worker = {
+            let shared = Arc::clone(&shared);
+            std::thread::Builder::new()
+                .name("ass-render-ahead".into())
+                .spawn(move || shared.run(&mut renderer, &script.script(), fps))
+                .map_err(|e| {
+                    RenderError::BackendError(format!("Failed to spawn render-ahead thread: {e}"))
+                })?
+        };
+
+        Ok(Self {
+            shared,
+            fps,
+            worker: Some(worker),
+        })
+    }
+
+    /// Pre-rendered frame displayed at `t` seconds
+    ///
+    /// Also moves the playback position to `t`. Returns `None` when the frame
+    /// is not ready yet; render it synchronously instead.
+    pub fn get_frame(&self, t: f64) -> Option<Arc<Frame>> {
+        let index = self.frame_index(t);
+        let mut state = self.shared.lock();
+        if state.position != index {
+            state.move_to(index);
+            self.shared.wake.notify_one();
+        }
+        let frame = state.frames.get(&index).cloned();
+        if frame.is_some() {
+            state.hits += 1;
+        } else {
+            state.misses += 1;
+        }
+        frame
+    }
+
+    /// Jump the playback position to `new_t` seconds
+    ///
+    /// Frames more than `lookahead_frames` away from `new_t` are flushed and
+    /// the worker starts rendering ahead of the new position.
+    pub fn seek(&self, new_t: f64) {
+        let index = self.frame_index(new_t);
+        self.shared.lock().move_to(index);
+        self.shared.wake.notify_one();
+    }
+
+    /// Number of pre-rendered frames held
+    pub fn queue_depth(&self) -> usize {
+        self.shared.lock().frames.len()
+    }
+
+    /// Share of `get_frame` calls answered from the queue, 0.0 before any
+    pub fn hit_rate(&self) -> f64 {
+        let state = self.shared.lock();
+        let total = state.hits + state.misses;
+        if total == 0 {
+            0.0
+        } else {
+            state.hits as f64 / total as f64
+        }
+    }
+
+    /// Frame rate the queue renders at
+    pub fn fps(&self) -> f64 {
+        self.fps
+    }
+
+    /// Index of the frame displayed at `t` seconds
+    fn frame_index(&self, t: f64) -> u64 {
+        // Negative and NaN times saturate to frame 0
+        (t * self.fps + 1e-9).floor() as u64
+    }
+}
+
+impl Drop for RenderAheadQueue {
+    fn drop(&mut self) {
+        self.shared.lock().shutdown = true;
+        self.shared.wake.notify_one();
+        if let Some(worker) = self.worker.take() {
+            let _ = worker.join();
+        }
+    }
+}
+
+impl Shared {
+    /// Lock the state, ignoring poisoning: it holds no invariants a panic
+    /// could break
+    fn lock(&self) -> MutexGuard<'_, QueueState> {
+        self.state.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Worker loop: render missing frames ahead of the position until shutdown
+    fn run(&self, renderer: &mut Renderer, script: &Script, fps: f64) {
+        let mut state = self.lock();
+        loop {
+            if state.shutdown {
+                return;
+            }
+            let Some(index) = state.next_missing() else {
+                state = self
+                    .wake
+                    .wait(state)
+                    .unwrap_or_else(PoisonError::into_inner);
+                continue;
+            };
+            drop(state);
+
+            let time_cs = (index as f64 * 100.0 / fps).round() as u32;
+            let rendered = renderer.render_frame(script, time_cs);
+
+            state = self.lock();
+            // The position may have moved while rendering
+            if !state.in_window(index) {
+                continue;
+            }
+            match rendered {
+                Ok(frame) => {
+                    state.frames.insert(index, Arc::new(frame));
+                }
+                Err(_) => {
+                    state.failed.insert(index);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,74 @@
+//! Timed frame cache for [`Renderer`]
+//!
+//! [`Renderer::render_frame`] and [`Renderer::render_frame_cached`] keep
+//! rendered frames keyed by time, output size and font scale, so scrubbing
+//! back to a time already shown skips shaping and rasterizing.
+
+use std::sync::Arc;
+
+use ass_core::parser::Script;
+
+use super::{Frame, Renderer};
+use crate::cache::{FrameCache, FrameCacheStats};
+use crate::utils::RenderError;
+
+impl Renderer {
+    /// Render a frame through the timed frame cache
+    ///
+    /// A time already rendered for the same script content, output size and
+    /// font scale is returned from the cache; otherwise the frame is rendered
+    /// and cached. Unlike [`Renderer::render_frame`], the cached frame is
+    /// shared rather than copied. The cache is emptied
+    /// whenever the script's content changes, which is detected by hashing
+    /// its sections on every call.
+    pub fn render_frame_cached(
+        &mut self,
+        script: &Script,
+        time_cs: u32,
+    ) -> Result<Arc<Frame>, RenderError> {
+        let fingerprint = Self::script_fingerprint(script);
+        if self.timed_frames_script != Some(fingerprint) {
+            self.timed_frames.invalidate_all();
+            self.timed_frames_script = Some(fingerprint);
+        }
+
+        self.apply_script_resolution(script);
+        let (width, height) = (self.context.width(), self.context.height());
+        let font_scale = self.context.render_scale_y();
+        if let Some(frame) = self.timed_frames.get(time_cs, width, height, font_scale) {
+            return Ok(frame);
+        }
+        let frame = self.render_uncached(script, time_cs)?;
+        Ok(self
+            .timed_frames
+            .insert(time_cs, width, height, font_scale, frame))
+    }
+
+    /// Replace the timed frame cache, e.g. to change its eviction policy
+    pub fn set_frame_cache(&mut self, cache: FrameCache) {
+        self.timed_frames = cache;
+        self.timed_frames_script = None;
+    }
+
+    /// Statistics of the timed frame cache
+    pub fn frame_cache_stats(&self) -> FrameCacheStats {
+        self.timed_frames.stats()
+    }
+
+    /// Drop cached frames at `time_cs`
+    pub fn invalidate_frames_at(&mut self, time_cs: u32) {
+        self.timed_frames.invalidate_at(time_cs);
+    }
+
+    /// Drop every cached frame, e.g. after loading fonts that change the
+    /// rendered output
+    pub fn invalidate_frames(&mut self) {
+        self.timed_frames.invalidate_all();
+        self.frame_cache = None;
+    }
+
+    /// Hash of the script's sections, identifying its content
+    fn script_fingerprint(script: &Script) -> u64 {
+        ahash::RandomState::with_seeds(0, 0, 0, 0).hash_one(script.sections())
+    }
+}
@@ -0,0 +1,185 @@
+//! BT.601 YUV 4:2:0 frame conversion
+//!
+//! Converts premultiplied RGBA frames to limited-range BT.601 YUV, the
+//! layout most video encoders and hardware decoders consume. Alpha is
+//! dropped, which composites the subtitles over black.
+
+#[cfg(feature = "nostd")]
+use alloc::{vec, vec::Vec};
+#[cfg(not(feature = "nostd"))]
+use std::vec::Vec;
+
+use super::Frame;
+
+/// Planar YUV 4:2:0 frame (I420)
+///
+/// The chroma planes are `ceil(width / 2)` by `ceil(height / 2)`, each sample
+/// averaging a 2x2 block of pixels.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct YuvFrame {
+    /// Luma plane, `width * height` bytes
+    pub y_plane: Vec<u8>,
+    /// Cb plane
+    pub u_plane: Vec<u8>,
+    /// Cr plane
+    pub v_plane: Vec<u8>,
+    /// Frame width in pixels
+    pub width: u32,
+    /// Frame height in pixels
+    pub height: u32,
+    /// Frame timestamp in centiseconds
+    pub timestamp: u32,
+}
+
+/// Semi-planar YUV 4:2:0 frame with interleaved chroma (NV12)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Nv12Frame {
+    /// Luma plane, `width * height` bytes
+    pub y_plane: Vec<u8>,
+    /// Interleaved Cb/Cr samples, two bytes per chroma sample
+    pub uv_plane: Vec<u8>,
+    /// Frame width in pixels
+    pub width: u32,
+    /// Frame height in pixels
+    pub height: u32,
+    /// Frame timestamp in centiseconds
+    pub timestamp: u32,
+}
+
+/// Convert an RGB colour to limited-range BT.601 `[Y, U, V]`
+pub fn rgb_to_yuv(r: u8, g: u8, b: u8) -> [u8; 3] {
+    let [y, u, v] = rgb_to_yuv_f32(r, g, b);
+    [quantize(y), quantize(u), quantize(v)]
+}
+
+/// Convert limited-range BT.601 `Y`, `U`, `V` back to RGB
+pub fn yuv_to_rgb(y: u8, u: u8, v: u8) -> [u8; 3] {
+    let y = 1.164 * (f32::from(y) - 16.0);
+    let u = f32::from(u) - 128.0;
+    let v = f32::from(v) - 128.0;
+    [
+        quantize(y + 1.596 * v),
+        quantize(y - 0.392 * u - 0.813 * v),
+        quantize(y + 2.017 * u),
+    ]
+}
+
+fn rgb_to_yuv_f32(r: u8, g: u8, b: u8) -> [f32; 3] {
+    let (r, g, b) = (f32::from(r), f32::from(g), f32::from(b));
+    [
+        0.257 * r + 0.504 * g + 0.098 * b + 16.0,
+        -0.148 * r - 0.291 * g + 0.439 * b + 128.0,
+        0.439 * r - 0.368 * g - 0.071 * b + 128.0,
+    ]
+}
+
+fn quantize(value: f32) -> u8 {
+    value.round().clamp(0.0, 255.0) as u8
+}
+
+impl YuvFrame {
+    /// Convert back to an opaque RGBA frame, upsampling chroma by
+    /// nearest neighbour
+    pub fn to_rgba(&self) -> Frame {
+        let (width, height) = (self.width as usize, self.height as usize);
+        let chroma_width = width.div_ceil(2);
+        let mut rgba = vec![0; width * height * 4];
+        for (i, pixel) in rgba.chunks_exact_mut(4).enumerate() {
+            let (x, y) = (i % width, i / width);
+            let chroma = (y / 2) * chroma_width + x / 2;
+            let [r, g, b] = yuv_to_rgb(self.y_plane[i], self.u_plane[chroma], self.v_plane[chroma]);
+            pixel.copy_from_slice(&[r, g, b, 255]);
+        }
+        Frame::new(rgba, self.width, self.height, self.timestamp)
+    }
+
+    /// Interleave the chroma planes into an NV12 frame
+    pub fn to_nv12(&self) -> Nv12Frame {
+        let uv_plane = self
+            .u_plane
+            .iter()
+            .zip(&self.v_plane)
+            .flat_map(|(&u, &v)| [u, v])
+            .collect();
+        Nv12Frame {
+            y_plane: self.y_plane.clone(),
+            uv_plane,
+            width: self.width,
+            height: self.height,
+            timestamp: self.timestamp,
+        }
+    }
+}
+
+impl Frame {
+    /// Convert to planar BT.601 YUV 4:2:0
+    ///
+    /// Colour is taken as premultiplied, so transparent areas become black.
+    pub fn to_yuv420(&self) -> YuvFrame {
+        let rgba = self.clone().to_rgba();
+        let (width, height) = (self.width() as usize, self.height() as usize);
+        let (chroma_width, chroma_height) = (width.div_ceil(2), height.div_ceil(2));
+        let pixels = rgba.data();
+
+        let mut y_plane = Vec::with_capacity(width * height);
+        let mut u_sum = vec![0.0f32; chroma_width * chroma_height];
+        let mut v_sum = vec![0.0f32; chroma_width * chroma_height];
+        let mut count = vec![0u8; chroma_width * chroma_height];
+        for (i, pixel) in pixels.chunks_exact(4).enumerate() {
+            let [y, u, v] = rgb_to_yuv_f32(pixel[0], pixel[1], pixel[2]);
+            y_plane.push(quantize(y));
+            let chroma = (i / width / 2) * chroma_width + (i % width) / 2;
+            u_sum[chroma] += u;
+            v_sum[chroma] += v;
+            count[chroma] += 1;
+        }
+
+        let average = |sums: Vec<f32>| -> Vec<u8> {
+            sums.iter()
+                .zip(&count)
+                .map(|(&sum, &n)| quantize(sum / f32::from(n.max(1))))
+                .collect()
+        };
+        YuvFrame {
+            y_plane,
+            u_plane: average(u_sum),
+            v_plane: average(v_sum),
+            width: self.width(),
+            height: self.height(),
+            timestamp: self.timestamp(),
+        }
+    }
+
+    /// Convert to semi-planar BT.601 YUV 4:2:0 (NV12)
+    pub fn to_nv12(&self) -> Nv12Frame {
+        self.to_yuv420().to_nv12()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reference_colours_match_bt601() {
+        assert_eq!(rgb_to_yuv(0, 0, 0), [16, 128, 128]);
+        assert_eq!(rgb_to_yuv(255, 255, 255), [235, 128, 128]);
+        assert_eq!(rgb_to_yuv(255, 0, 0), [82, 90, 240]);
+    }
+
+    #[test]
+    fn odd_sizes_average_partial_blocks() {
+        let mut data = vec![0; 3 * 3 * 4];
+        for pixel in data.chunks_exact_mut(4) {
+            pixel.copy_from_slice(&[255, 255, 255, 255]);
+        }
+        let yuv = Frame::from_rgba(data, 3, 3).to_yuv420();
+        assert_eq!(yuv.y_plane.len(), 9);
+        assert_eq!(yuv.u_plane.len(), 4);
+        assert!(yuv.u_plane.iter().chain(&yuv.v_plane).all(|&c| c == 128));
+
+        let nv12 = yuv.to_nv12();
+        assert_eq!(nv12.uv_plane.len(), 8);
+        assert_eq!(nv12.y_plane, yuv.y_plane);
+    }
+}
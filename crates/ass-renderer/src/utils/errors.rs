@@ -46,8 +46,16 @@ pub enum RenderError {
     UnsupportedBackend(&'static str),
 
     /// Backend initialization failed
-    #[cfg_attr(not(feature = "nostd"), error("Backend initialization failed: {0}"))]
-    BackendInitFailed(String),
+    #[cfg_attr(
+        not(feature = "nostd"),
+        error("Backend initialization failed for {backend}: {message}")
+    )]
+    BackendInitFailed {
+        /// Backend that failed to start
+        backend: String,
+        /// Failure reason
+        message: String,
+    },
 
     /// Generic backend error
     #[cfg_attr(not(feature = "nostd"), error("Backend error: {0}"))]
@@ -81,10 +89,34 @@ pub enum RenderError {
     #[cfg_attr(not(feature = "nostd"), error("Font error: {0}"))]
     FontError(String),
 
+    /// Requested font is not available and no fallback could be used
+    #[cfg_attr(not(feature = "nostd"), error("Font not found: {font_name}"))]
+    FontNotFound {
+        /// Requested font family
+        font_name: String,
+    },
+
+    /// Font has no glyph for a character
+    #[cfg_attr(
+        not(feature = "nostd"),
+        error("Glyph U+{codepoint:04X} not found in font {font_name}")
+    )]
+    GlyphNotFound {
+        /// Unicode scalar value of the missing character
+        codepoint: u32,
+        /// Font searched for the glyph
+        font_name: String,
+    },
+
     /// GPU error
     #[cfg_attr(not(feature = "nostd"), error("GPU error: {0}"))]
     GpuError(String),
 
+    /// Structured GPU backend failure
+    #[cfg(feature = "gpu")]
+    #[cfg_attr(not(feature = "nostd"), error("GPU error: {0}"))]
+    Gpu(crate::backends::gpu::GpuBackendError),
+
     /// WASM error
     #[cfg(target_arch = "wasm32")]
     #[cfg_attr(not(feature = "nostd"), error("WASM error: {0}"))]
@@ -94,6 +126,49 @@ pub enum RenderError {
     #[cfg_attr(not(feature = "nostd"), error("Resource limit exceeded: {0}"))]
     ResourceLimitExceeded(String),
 
+    /// Output frame larger than the backend supports
+    #[cfg_attr(
+        not(feature = "nostd"),
+        error("Frame size {}x{} exceeds maximum {}x{}", requested.0, requested.1, maximum.0, maximum.1)
+    )]
+    FrameSizeTooLarge {
+        /// Requested width and height
+        requested: (u32, u32),
+        /// Largest supported width and height
+        maximum: (u32, u32),
+    },
+
+    /// Allocation of a render buffer failed
+    #[cfg_attr(
+        not(feature = "nostd"),
+        error("Out of memory allocating {requested_bytes} bytes")
+    )]
+    OutOfMemory {
+        /// Size of the failed allocation
+        requested_bytes: usize,
+    },
+
+    /// Time outside the range a render call accepts
+    #[cfg_attr(not(feature = "nostd"), error("Invalid timestamp {value}: {reason}"))]
+    InvalidTimestamp {
+        /// Rejected time value
+        value: f64,
+        /// Why it was rejected
+        reason: String,
+    },
+
+    /// Effect plugin failed
+    #[cfg_attr(
+        not(feature = "nostd"),
+        error("Plugin {plugin_name} failed: {message}")
+    )]
+    PluginError {
+        /// Name of the failing plugin
+        plugin_name: String,
+        /// Failure reason
+        message: String,
+    },
+
     /// Invalid script
     #[cfg_attr(not(feature = "nostd"), error("Invalid script: {0}"))]
     InvalidScript(String),
@@ -130,18 +205,31 @@ pub enum RenderError {
 impl RenderError {
     /// Check if error is recoverable
     pub fn is_recoverable(&self) -> bool {
-        matches!(
-            self,
+        match self {
             Self::ShapingError(_)
-                | Self::DrawingError(_)
-                | Self::EffectError(_)
-                | Self::FontError(_)
-        )
+            | Self::DrawingError(_)
+            | Self::EffectError(_)
+            | Self::FontError(_)
+            | Self::FontNotFound { .. }
+            | Self::GlyphNotFound { .. }
+            | Self::PluginError { .. } => true,
+            #[cfg(feature = "gpu")]
+            Self::Gpu(error) => error.is_recoverable(),
+            _ => false,
+        }
     }
 
     /// Check if error indicates missing resources
     pub fn is_resource_error(&self) -> bool {
-        matches!(self, Self::FontError(_) | Self::ResourceLimitExceeded(_))
+        matches!(
+            self,
+            Self::FontError(_)
+                | Self::FontNotFound { .. }
+                | Self::GlyphNotFound { .. }
+                | Self::ResourceLimitExceeded(_)
+                | Self::FrameSizeTooLarge { .. }
+                | Self::OutOfMemory { .. }
+        )
     }
 }
 
@@ -163,7 +251,9 @@ impl fmt::Display for RenderError {
             Self::InvalidPixmap => write!(f, "Failed to create pixmap"),
             Self::NoBackendAvailable => write!(f, "No rendering backend available"),
             Self::UnsupportedBackend(s) => write!(f, "Unsupported backend: {s}"),
-            Self::BackendInitFailed(s) => write!(f, "Backend initialization failed: {s}"),
+            Self::BackendInitFailed { backend, message } => {
+                write!(f, "Backend initialization failed for {backend}: {message}")
+            }
             Self::BackendError(s) => write!(f, "Backend error: {s}"),
             Self::PipelineError(s) => write!(f, "Pipeline error: {s}"),
             Self::ShapingError(s) => write!(f, "Text shaping failed: {s}"),
@@ -172,10 +262,32 @@ impl fmt::Display for RenderError {
             Self::EffectError(s) => write!(f, "Effect application failed: {s}"),
             Self::CompositingError(s) => write!(f, "Compositing failed: {s}"),
             Self::FontError(s) => write!(f, "Font error: {s}"),
+            Self::FontNotFound { font_name } => write!(f, "Font not found: {font_name}"),
+            Self::GlyphNotFound {
+                codepoint,
+                font_name,
+            } => write!(f, "Glyph U+{codepoint:04X} not found in font {font_name}"),
             Self::GpuError(s) => write!(f, "GPU error: {s}"),
+            #[cfg(feature = "gpu")]
+            Self::Gpu(e) => write!(f, "GPU error: {e}"),
             #[cfg(target_arch = "wasm32")]
             Self::WasmError(s) => write!(f, "WASM error: {s}"),
             Self::ResourceLimitExceeded(s) => write!(f, "Resource limit exceeded: {s}"),
+            Self::FrameSizeTooLarge { requested, maximum } => write!(
+                f,
+                "Frame size {}x{} exceeds maximum {}x{}",
+                requested.0, requested.1, maximum.0, maximum.1
+            ),
+            Self::OutOfMemory { requested_bytes } => {
+                write!(f, "Out of memory allocating {requested_bytes} bytes")
+            }
+            Self::InvalidTimestamp { value, reason } => {
+                write!(f, "Invalid timestamp {value}: {reason}")
+            }
+            Self::PluginError {
+                plugin_name,
+                message,
+            } => write!(f, "Plugin {plugin_name} failed: {message}"),
             Self::InvalidScript(s) => write!(f, "Invalid script: {s}"),
             Self::ParseError(s) => write!(f, "Parse error: {s}"),
             Self::InvalidInput(s) => write!(f, "Invalid input: {s}"),
@@ -191,3 +303,88 @@ impl fmt::Display for RenderError {
 // Manual Error implementation for nostd
 #[cfg(feature = "nostd")]
 impl core::error::Error for RenderError {}
+
+#[cfg(test)]
+mod tests {
+    use super::RenderError;
+
+    #[cfg(feature = "nostd")]
+    use alloc::string::{String, ToString};
+
+    fn text(s: &str) -> String {
+        s.to_string()
+    }
+
+    #[test]
+    fn missing_fonts_and_glyphs_are_recoverable() {
+        let font = RenderError::FontNotFound {
+            font_name: text("Missing Sans"),
+        };
+        assert!(font.is_recoverable());
+        assert!(font.is_resource_error());
+
+        let glyph = RenderError::GlyphNotFound {
+            codepoint: 0x1F600,
+            font_name: text("Arial"),
+        };
+        assert!(glyph.is_recoverable());
+        assert!(glyph.is_resource_error());
+    }
+
+    #[test]
+    fn plugin_errors_are_recoverable() {
+        let error = RenderError::PluginError {
+            plugin_name: text("glow"),
+            message: text("bad radius"),
+        };
+        assert!(error.is_recoverable());
+        assert!(!error.is_resource_error());
+    }
+
+    #[test]
+    fn fatal_variants_are_not_recoverable() {
+        let backend = RenderError::BackendInitFailed {
+            backend: text("software"),
+            message: text("no surface"),
+        };
+        assert!(!backend.is_recoverable());
+
+        let oom = RenderError::OutOfMemory {
+            requested_bytes: 1 << 40,
+        };
+        assert!(!oom.is_recoverable());
+        assert!(oom.is_resource_error());
+
+        let size = RenderError::FrameSizeTooLarge {
+            requested: (100_000, 10),
+            maximum: (16_384, 16_384),
+        };
+        assert!(!size.is_recoverable());
+        assert!(size.is_resource_error());
+
+        let time = RenderError::InvalidTimestamp {
+            value: f64::NAN,
+            reason: text("not finite"),
+        };
+        assert!(!time.is_recoverable());
+        assert!(!time.is_resource_error());
+    }
+
+    #[test]
+    fn structured_variants_display_their_fields() {
+        let glyph = RenderError::GlyphNotFound {
+            codepoint: 0x41,
+            font_name: text("Arial"),
+        };
+        assert_eq!(glyph.to_string(), "Glyph U+0041 not found in font Arial");
+
+        let size = RenderError::FrameSizeTooLarge {
+            requested: (20_000, 1080),
+            maximum: (16_384, 16_384),
+        };
+        assert_eq!(
+            size.to_string(),
+            "Frame size 20000x1080 exceeds maximum 16384x16384"
+        );
+    }
+}
@@ -0,0 +1,96 @@
+//! Backend selection through `BackendType` and `Renderer::from_script`.
+#![cfg(all(feature = "software-backend", feature = "analysis-integration"))]
+
+use ass_core::parser::Script;
+use ass_renderer::backends::{BackendType, ThreadingModel};
+use ass_renderer::debug::FrameAnalyzer;
+use ass_renderer::renderer::{PixelFormat, Renderer};
+
+const SCRIPT: &str = "[Script Info]\nPlayResX: 640\nPlayResY: 360\n\n[V4+ Styles]\nFormat: Name, Fontname, Fontsize, PrimaryColour, SecondaryColour, OutlineColour, BackColour, Bold, Italic, Underline, StrikeOut, ScaleX, ScaleY, Spacing, Angle, BorderStyle, Outline, Shadow, Alignment, MarginL, MarginR, MarginV, Encoding\nStyle: Default,DejaVu Sans,32,&H00FFFFFF,&H000000FF,&H00000000,&H00000000,0,0,0,0,100,100,0,0,1,2,0,2,20,20,20,1\n\n[Events]\nFormat: Layer, Start, End, Style, Name, MarginL, MarginR, MarginV, Effect, Text\nDialogue: 0,0:00:01.00,0:00:05.00,Default,,0,0,0,,Backend selection\n";
+
+#[test]
+fn null_backend_renders_transparent_frames_at_play_res() {
+    let script = Script::parse(SCRIPT).expect("parse");
+    let mut renderer = Renderer::from_script(&script, &[], BackendType::Null).expect("renderer");
+    assert_eq!(renderer.backend_type(), BackendType::Null);
+
+    let frame = renderer.render_frame(&script, 200).expect("render");
+    assert_eq!((frame.width(), frame.height()), (640, 360));
+    assert!(frame.data().iter().all(|&byte| byte == 0));
+}
+
+#[test]
+fn software_capabilities_describe_rgba_output() {
+    let script = Script::parse(SCRIPT).expect("parse");
+    let renderer = Renderer::from_script(&script, &[], BackendType::Software).expect("renderer");
+    let capabilities = renderer.backend_capabilities();
+    assert_eq!(capabilities.pixel_formats, vec![PixelFormat::Rgba8]);
+    assert!(capabilities.max_resolution.0 >= 1920 && capabilities.max_resolution.1 >= 1080);
+    assert_eq!(capabilities.threading, ThreadingModel::Parallel);
+}
+
+#[test]
+fn software_and_null_backends_agree_without_active_events() {
+    let script = Script::parse(SCRIPT).expect("parse");
+    let mut software =
+        Renderer::from_script(&script, &[], BackendType::Software).expect("software");
+    let mut null = Renderer::from_script(&script, &[], BackendType::Null).expect("null");
+
+    let a = software.render_frame(&script, 0).expect("render");
+    let b = null.render_frame(&script, 0).expect("render");
+    assert!(FrameAnalyzer::diff(&a, &b).is_identical());
+
+    let a = software.render_frame(&script, 200).expect("render");
+    let b = null.render_frame(&script, 200).expect("render");
+    assert!(!FrameAnalyzer::diff(&a, &b).is_identical());
+}
+
+#[test]
+fn supplied_fonts_render_like_system_fonts() {
+    let Ok(font) = std::fs::read("/usr/share/fonts/truetype/dejavu/DejaVuSans.ttf") else {
+        eprintln!("skipping supplied font test (DejaVu Sans not installed)");
+        return;
+    };
+    let script = Script::parse(SCRIPT).expect("parse");
+    let mut system = Renderer::from_script(&script, &[], BackendType::Software).expect("system");
+    let mut supplied =
+        Renderer::from_script(&script, &[&font], BackendType::Software).expect("supplied");
+
+    let diff = FrameAnalyzer::diff(
+        &system.render_frame(&script, 200).expect("render"),
+        &supplied.render_frame(&script, 200).expect("render"),
+    );
+    assert!(diff.psnr_db > 40.0, "psnr {}", diff.psnr_db);
+}
+
+#[cfg(feature = "gpu")]
+#[test]
+fn gpu_and_software_backends_render_close_frames() {
+    let script = Script::parse(SCRIPT).expect("parse");
+    let Ok(mut gpu) = Renderer::from_script(&script, &[], BackendType::Gpu) else {
+        eprintln!("skipping GPU backend comparison (no usable adapter)");
+        return;
+    };
+    let mut software =
+        Renderer::from_script(&script, &[], BackendType::Software).expect("software");
+
+    let diff = FrameAnalyzer::diff(
+        &software.render_frame(&script, 200).expect("render"),
+        &gpu.render_frame(&script, 200).expect("render"),
+    );
+    assert!(diff.psnr_db > 40.0, "psnr {}", diff.psnr_db);
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn backend_type_round_trips_through_config_names() {
+    let parse = |name: &str| serde_json::from_str::<BackendType>(name).expect("backend");
+    assert_eq!(parse("\"software\""), BackendType::Software);
+    assert_eq!(parse("\"hardware\""), BackendType::Gpu);
+    assert_eq!(parse("\"gpu\""), BackendType::Gpu);
+    assert_eq!(parse("\"null\""), BackendType::Null);
+    assert_eq!(
+        serde_json::to_string(&BackendType::Software).expect("serialize"),
+        "\"software\""
+    );
+}
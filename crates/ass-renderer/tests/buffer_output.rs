@@ -0,0 +1,62 @@
+//! Rendering into caller-provided buffers and writers.
+#![cfg(all(feature = "software-backend", feature = "analysis-integration"))]
+
+use ass_core::parser::Script;
+use ass_renderer::backends::BackendType;
+use ass_renderer::renderer::Renderer;
+use ass_renderer::utils::RenderError;
+
+const SCRIPT: &str = "[Script Info]\nPlayResX: 320\nPlayResY: 180\n\n[V4+ Styles]\nFormat: Name, Fontname, Fontsize, PrimaryColour, SecondaryColour, OutlineColour, BackColour, Bold, Italic, Underline, StrikeOut, ScaleX, ScaleY, Spacing, Angle, BorderStyle, Outline, Shadow, Alignment, MarginL, MarginR, MarginV, Encoding\nStyle: Default,DejaVu Sans,32,&H0030A0F0,&H000000FF,&H00800000,&H00000000,0,0,0,0,100,100,0,0,1,2,0,2,10,10,10,1\n\n[Events]\nFormat: Layer, Start, End, Style, Name, MarginL, MarginR, MarginV, Effect, Text\nDialogue: 0,0:00:00.00,0:00:05.00,Default,,0,0,0,,Zero copy\n";
+
+#[test]
+fn slice_output_matches_frame() {
+    let script = Script::parse(SCRIPT).expect("parse");
+    let mut renderer =
+        Renderer::from_script(&script, &[], BackendType::Software).expect("renderer");
+    let frame = renderer.render_frame(&script, 100).expect("render");
+
+    // Bytes past the frame are left as they were
+    let mut dst = vec![0xAB; 320 * 180 * 4 + 8];
+    renderer
+        .render_to_slice(&script, 100, &mut dst)
+        .expect("render to slice");
+    assert_eq!(&dst[..frame.data().len()], frame.data());
+    assert!(dst[frame.data().len()..].iter().all(|&b| b == 0xAB));
+}
+
+#[test]
+fn slice_output_rejects_short_buffer() {
+    let script = Script::parse(SCRIPT).expect("parse");
+    let mut renderer =
+        Renderer::from_script(&script, &[], BackendType::Software).expect("renderer");
+
+    let mut dst = vec![0; 320 * 180 * 4 - 1];
+    let err = renderer
+        .render_to_slice(&script, 100, &mut dst)
+        .expect_err("short buffer");
+    assert!(matches!(
+        err,
+        RenderError::InvalidBufferSize {
+            expected: 230_400,
+            actual: 230_399
+        }
+    ));
+}
+
+#[test]
+fn writer_output_matches_frame() {
+    let script = Script::parse(SCRIPT).expect("parse");
+    let mut renderer =
+        Renderer::from_script(&script, &[], BackendType::Software).expect("renderer");
+    let frame = renderer.render_frame(&script, 100).expect("render");
+
+    let mut out = Vec::new();
+    renderer
+        .render_to_writer(&script, 100, &mut out)
+        .expect("render to writer");
+    renderer
+        .render_to_writer(&script, 200, &mut out)
+        .expect("render to writer");
+    assert_eq!(out.len(), frame.data().len() * 2);
+    assert_eq!(&out[..frame.data().len()], frame.data());
+}
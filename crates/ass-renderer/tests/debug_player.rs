@@ -0,0 +1,111 @@
+//! Frame stepping through `DebugPlayer`.
+#![cfg(all(feature = "software-backend", feature = "analysis-integration"))]
+
+use ass_core::parser::Script;
+use ass_renderer::{DebugPlayer, RenderError};
+
+const SCRIPT: &str = "[Script Info]\nPlayResX: 320\nPlayResY: 180\n\n[V4+ Styles]\nFormat: Name, Fontname, Fontsize, PrimaryColour, SecondaryColour, OutlineColour, BackColour, Bold, Italic, Underline, StrikeOut, ScaleX, ScaleY, Spacing, Angle, BorderStyle, Outline, Shadow, Alignment, MarginL, MarginR, MarginV, Encoding\nStyle: Default,DejaVu Sans,24,&H00FFFFFF,&H000000FF,&H00000000,&H00000000,0,0,0,0,100,100,0,0,1,2,0,2,10,10,10,1\n\n[Events]\nFormat: Layer, Start, End, Style, Name, MarginL, MarginR, MarginV, Effect, Text\nDialogue: 0,0:00:00.00,0:00:01.00,Default,,0,0,0,,First line\nComment: 0,0:00:00.00,0:00:02.00,Default,,0,0,0,,Never shown\nDialogue: 0,0:00:00.20,0:00:02.00,Default,,0,0,0,,{\\an8}Second line\n";
+
+fn player() -> DebugPlayer {
+    let script = Script::parse(SCRIPT).expect("parse");
+    DebugPlayer::from_script(&script, &[], 25.0).expect("player")
+}
+
+#[test]
+fn stepping_forward_advances_one_frame_at_a_time() {
+    let mut player = player();
+    assert_eq!(player.total_frames(), 50);
+    assert_eq!((player.width(), player.height()), (320, 180));
+
+    let first = player.current_frame().expect("frame").clone();
+    assert_eq!(first.frame_number, 0);
+    assert_eq!(first.timestamp_sec, 0.0);
+    assert_eq!(first.visible_events, [0]);
+    assert_eq!(first.rgba_data.len(), 320 * 180 * 4);
+
+    let mut timestamps = vec![first.timestamp_sec];
+    for step in 1..=10 {
+        let frame = player.step_forward().expect("step");
+        assert_eq!(frame.frame_number, step);
+        timestamps.push(frame.timestamp_sec);
+    }
+    assert!(timestamps.windows(2).all(|pair| pair[0] < pair[1]));
+    assert!((timestamps[10] - 0.4).abs() < 1e-9);
+
+    // Frame 10 is past the second event's start
+    let frame = player.current_frame().expect("frame");
+    assert_eq!(frame.visible_events, [0, 2]);
+    assert_ne!(frame.rgba_data, first.rgba_data);
+}
+
+#[test]
+fn stepping_backward_reuses_cached_frames() {
+    let mut player = player();
+    for _ in 0..5 {
+        player.step_forward().expect("step");
+    }
+    let rendered = player.cache_stats();
+    assert_eq!(rendered.misses, 6);
+
+    for expected in (0..5).rev() {
+        assert_eq!(player.step_backward().expect("step").frame_number, expected);
+    }
+    let stats = player.cache_stats();
+    assert_eq!(stats.misses, rendered.misses);
+    assert_eq!(stats.hits, rendered.hits + 5);
+
+    // The first frame is a hard stop
+    assert_eq!(player.step_backward().expect("step").frame_number, 0);
+}
+
+#[test]
+fn seeking_snaps_to_frames() {
+    let mut player = player();
+
+    let frame = player.seek(1.01).expect("seek");
+    assert_eq!(frame.frame_number, 25);
+    assert!((frame.timestamp_sec - 1.0).abs() < 1e-9);
+    assert_eq!(frame.visible_events, [2]);
+
+    // Seeking past the end lands on the last frame, which stepping keeps
+    assert_eq!(player.seek(60.0).expect("seek").frame_number, 49);
+    assert_eq!(player.step_forward().expect("step").frame_number, 49);
+    assert!(matches!(
+        player.seek(f64::NAN),
+        Err(RenderError::InvalidTimestamp { .. })
+    ));
+
+    let script = Script::parse(SCRIPT).expect("parse");
+    assert!(DebugPlayer::from_script(&script, &[], 0.0).is_err());
+    assert!(DebugPlayer::from_script(&script, &[], f64::NAN).is_err());
+}
+
+#[cfg(feature = "image")]
+#[test]
+fn dump_frames_writes_pngs() {
+    let dir = std::env::temp_dir().join(format!("ass-debug-player-{}", std::process::id()));
+    let mut player = player();
+    player.step_forward().expect("step");
+
+    let written = player.dump_frames(0.5, 0.7, &dir).expect("dump");
+    let mut files: Vec<String> = std::fs::read_dir(&dir)
+        .expect("dir")
+        .map(|entry| {
+            entry
+                .expect("entry")
+                .file_name()
+                .into_string()
+                .expect("name")
+        })
+        .collect();
+    files.sort();
+    std::fs::remove_dir_all(&dir).ok();
+
+    // Frames 13 to 17 start within 0.5..=0.7 seconds
+    assert_eq!(written, 5);
+    assert_eq!(files.len(), 5);
+    assert_eq!(files[0], "frame_000013_000520ms.png");
+    assert_eq!(files[4], "frame_000017_000680ms.png");
+    // The player is back where it was
+    assert_eq!(player.current_frame().expect("frame").frame_number, 1);
+}
@@ -0,0 +1,76 @@
+//! HiDPI display scaling and aspect-preserving PlayRes placement.
+#![cfg(feature = "software-backend")]
+
+use ass_core::parser::Script;
+use ass_renderer::backends::BackendType;
+use ass_renderer::renderer::{RenderContext, Renderer};
+
+const SQUARE: &str = "{\\an7\\pos(100,50)\\bord0\\shad0\\p1}m 0 0 l 40 0 40 40 0 40{\\p0}";
+
+fn script_text(play_res: (u32, u32)) -> String {
+    format!(
+        "[Script Info]\nPlayResX: {}\nPlayResY: {}\n\n[V4+ Styles]\nFormat: Name, Fontname, Fontsize, PrimaryColour, SecondaryColour, OutlineColour, BackColour, Bold, Italic, Underline, StrikeOut, ScaleX, ScaleY, Spacing, Angle, BorderStyle, Outline, Shadow, Alignment, MarginL, MarginR, MarginV, Encoding\nStyle: Default,Arial,32,&H00FFFFFF,&H000000FF,&H00000000,&H00000000,0,0,0,0,100,100,0,0,1,0,0,7,0,0,0,1\n\n[Events]\nFormat: Layer, Start, End, Style, Name, MarginL, MarginR, MarginV, Effect, Text\nDialogue: 0,0:00:00.00,0:00:10.00,Default,,0,0,0,,{SQUARE}\n",
+        play_res.0, play_res.1
+    )
+}
+
+/// Render the square and return the frame size and the opaque bounding box
+/// as `(min_x, min_y, max_x, max_y)`.
+fn render(
+    play_res: (u32, u32),
+    output: (u32, u32),
+    display_scale: f64,
+) -> ((u32, u32), (usize, usize, usize, usize)) {
+    let text = script_text(play_res);
+    let script = Script::parse(&text).expect("parse");
+    let mut renderer = Renderer::new(
+        BackendType::Software,
+        RenderContext::new(output.0, output.1),
+    )
+    .expect("renderer");
+    renderer.set_display_scale(display_scale);
+    let frame = renderer.render_frame(&script, 100).expect("render");
+
+    let width = frame.width() as usize;
+    let mut bbox = (usize::MAX, usize::MAX, 0, 0);
+    for (i, px) in frame.data().chunks_exact(4).enumerate() {
+        if px[3] >= 128 {
+            let (x, y) = (i % width, i / width);
+            bbox = (bbox.0.min(x), bbox.1.min(y), bbox.2.max(x), bbox.3.max(y));
+        }
+    }
+    assert!(bbox.0 != usize::MAX, "square was not rendered");
+    ((frame.width(), frame.height()), bbox)
+}
+
+fn close(a: usize, b: usize) -> bool {
+    a.abs_diff(b) <= 2
+}
+
+#[test]
+fn double_display_scale_doubles_pixel_positions() {
+    let (size_1x, (x0, y0, x1, y1)) = render((640, 360), (640, 360), 1.0);
+    let (size_2x, (hx0, hy0, hx1, hy1)) = render((640, 360), (640, 360), 2.0);
+
+    assert_eq!(size_1x, (640, 360));
+    assert_eq!(size_2x, (1280, 720));
+    assert!(close(x0, 100) && close(y0, 50), "1x origin at ({x0}, {y0})");
+    assert!(
+        close(hx0, x0 * 2) && close(hy0, y0 * 2),
+        "2x origin ({hx0}, {hy0}) is not double ({x0}, {y0})"
+    );
+    assert!(
+        close(hx1 - hx0, (x1 - x0) * 2) && close(hy1 - hy0, (y1 - y0) * 2),
+        "2x square is not twice as large"
+    );
+}
+
+#[test]
+fn narrower_play_res_is_pillarboxed() {
+    // A 4:3 script on a 16:9 output is scaled by 360/288 = 1.25 and
+    // centered with (640 - 480) / 2 = 80 px bars on each side.
+    let (_, (x0, y0, x1, _)) = render((384, 288), (640, 360), 1.0);
+    assert!(close(x0, 80 + 125), "left edge at {x0}");
+    assert!(close(y0, 63), "top edge at {y0}");
+    assert!(close(x1 - x0, 50), "square width {}", x1 - x0);
+}
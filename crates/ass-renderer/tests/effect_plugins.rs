@@ -0,0 +1,203 @@
+//! Effect plugins run through `PluginRegistry` and `Renderer::render_frame`.
+#![cfg(all(feature = "software-backend", feature = "analysis-integration"))]
+
+use std::sync::{Arc, Mutex};
+
+use ass_core::parser::Script;
+use ass_renderer::backends::BackendType;
+use ass_renderer::plugin::effects::GaussianNoisePlugin;
+use ass_renderer::plugin::{
+    EffectContext, EffectParams, EffectPlugin, EffectResult, PluginRegistry, ScriptMut,
+};
+use ass_renderer::renderer::{Frame, Renderer};
+use ass_renderer::RenderError;
+
+const SCRIPT: &str = "[Script Info]\nPlayResX: 320\nPlayResY: 180\n\n[V4+ Styles]\nFormat: Name, Fontname, Fontsize, PrimaryColour, SecondaryColour, OutlineColour, BackColour, Bold, Italic, Underline, StrikeOut, ScaleX, ScaleY, Spacing, Angle, BorderStyle, Outline, Shadow, Alignment, MarginL, MarginR, MarginV, Encoding\nStyle: Default,DejaVu Sans,32,&H000000FF,&H000000FF,&H00FF0000,&H00000000,0,0,0,0,100,100,0,0,1,2,0,2,10,10,10,1\n\n[Events]\nFormat: Layer, Start, End, Style, Name, MarginL, MarginR, MarginV, Effect, Text\nDialogue: 0,0:00:00.00,0:00:05.00,Default,,0,0,0,,Colourful text\n";
+
+/// Post-effect replacing each pixel's colour with its luma
+struct Grayscale;
+
+impl EffectPlugin for Grayscale {
+    fn name(&self) -> &str {
+        "Grayscale"
+    }
+
+    fn version(&self) -> &str {
+        "1.0.0"
+    }
+
+    fn apply_cpu(
+        &self,
+        pixels: &mut [u8],
+        _width: u32,
+        _height: u32,
+        _params: &EffectParams,
+    ) -> Result<(), RenderError> {
+        for pixel in pixels.chunks_exact_mut(4) {
+            let luma =
+                (u32::from(pixel[0]) * 299 + u32::from(pixel[1]) * 587 + u32::from(pixel[2]) * 114)
+                    / 1000;
+            pixel[..3].fill(luma as u8);
+        }
+        Ok(())
+    }
+}
+
+/// Plugin recording the order in which post-effects run
+struct Recorder {
+    label: &'static str,
+    priority: i32,
+    log: Arc<Mutex<Vec<&'static str>>>,
+}
+
+impl EffectPlugin for Recorder {
+    fn name(&self) -> &str {
+        self.label
+    }
+
+    fn version(&self) -> &str {
+        "1.0.0"
+    }
+
+    fn priority(&self) -> i32 {
+        self.priority
+    }
+
+    fn apply_cpu(
+        &self,
+        _pixels: &mut [u8],
+        _width: u32,
+        _height: u32,
+        _params: &EffectParams,
+    ) -> Result<(), RenderError> {
+        Ok(())
+    }
+
+    fn apply_pre(&self, script: &mut ScriptMut, _ctx: &EffectContext) -> EffectResult {
+        let source = script.source().replace("Colourful", "Normalized");
+        *script.source_mut() = source;
+        Ok(())
+    }
+
+    fn apply_post(&self, _frame: &mut Frame, _ctx: &EffectContext) -> EffectResult {
+        self.log.lock().unwrap().push(self.label);
+        Ok(())
+    }
+}
+
+/// Post-effect that always fails
+struct Failing;
+
+impl EffectPlugin for Failing {
+    fn name(&self) -> &str {
+        "Failing"
+    }
+
+    fn version(&self) -> &str {
+        "1.0.0"
+    }
+
+    fn apply_cpu(
+        &self,
+        _pixels: &mut [u8],
+        _width: u32,
+        _height: u32,
+        _params: &EffectParams,
+    ) -> Result<(), RenderError> {
+        Err(RenderError::EffectError("kernel too large".to_string()))
+    }
+}
+
+fn is_colourful(frame: &Frame) -> bool {
+    frame
+        .data()
+        .chunks_exact(4)
+        .any(|pixel| pixel[0] != pixel[1] || pixel[1] != pixel[2])
+}
+
+#[test]
+fn grayscale_post_effect_yields_neutral_pixels() {
+    let script = Script::parse(SCRIPT).expect("parse");
+    let mut renderer =
+        Renderer::from_script(&script, &[], BackendType::Software).expect("renderer");
+    let plain = renderer.render_frame(&script, 100).expect("render");
+    assert!(is_colourful(&plain), "red text with blue outline");
+
+    let id = renderer.register_plugin(Box::new(Grayscale));
+    let gray = renderer.render_frame(&script, 100).expect("render");
+    assert!(gray.data().chunks_exact(4).any(|pixel| pixel[3] > 0));
+    assert!(!is_colourful(&gray));
+
+    assert!(renderer.deregister_plugin(id));
+    let restored = renderer.render_frame(&script, 100).expect("render");
+    assert_eq!(restored.data(), plain.data());
+}
+
+#[test]
+fn plugins_run_in_priority_order() {
+    let log = Arc::new(Mutex::new(Vec::new()));
+    let mut registry = PluginRegistry::new();
+    for (label, priority) in [("late", 10), ("early", -5), ("middle", 0), ("middle2", 0)] {
+        registry.register(Box::new(Recorder {
+            label,
+            priority,
+            log: Arc::clone(&log),
+        }));
+    }
+
+    let mut frame = Frame::from_rgba(vec![0; 16], 2, 2);
+    registry
+        .apply_post(&mut frame, &EffectContext::new(0, 2, 2))
+        .expect("post");
+    assert_eq!(*log.lock().unwrap(), ["early", "middle", "middle2", "late"]);
+
+    let source = registry
+        .apply_pre(SCRIPT, &EffectContext::new(0, 320, 180))
+        .expect("pre");
+    let script = Script::parse(&source).expect("parse");
+    assert!(script.source().contains("Normalized text"));
+}
+
+#[test]
+fn plugin_failures_name_the_plugin() {
+    let mut registry = PluginRegistry::new();
+    registry.register(Box::new(Failing));
+
+    let mut frame = Frame::from_rgba(vec![0; 16], 2, 2);
+    let error = registry
+        .apply_post(&mut frame, &EffectContext::new(0, 2, 2))
+        .expect_err("failing plugin");
+    match &error {
+        RenderError::PluginError {
+            plugin_name,
+            message,
+        } => {
+            assert_eq!(plugin_name, "Failing");
+            assert!(message.contains("kernel too large"));
+        }
+        other => panic!("expected plugin error, got {other:?}"),
+    }
+    assert!(error.is_recoverable());
+}
+
+#[test]
+fn noise_plugin_changes_only_visible_pixels() {
+    let script = Script::parse(SCRIPT).expect("parse");
+    let mut renderer =
+        Renderer::from_script(&script, &[], BackendType::Software).expect("renderer");
+    let plain = renderer.render_frame(&script, 100).expect("render");
+    renderer.register_plugin(Box::new(GaussianNoisePlugin::new(16.0).with_seed(3)));
+    let noisy = renderer.render_frame(&script, 100).expect("render");
+
+    assert_ne!(plain.data(), noisy.data());
+    for (a, b) in plain
+        .data()
+        .chunks_exact(4)
+        .zip(noisy.data().chunks_exact(4))
+    {
+        assert_eq!(a[3], b[3]);
+        if a[3] == 0 {
+            assert_eq!(a, b);
+        }
+    }
+}
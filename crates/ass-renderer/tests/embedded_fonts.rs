@@ -0,0 +1,105 @@
+//! Rendering with fonts embedded in a script's `[Fonts]` section.
+#![cfg(all(feature = "software-backend", feature = "analysis-integration"))]
+
+use ass_core::parser::Script;
+use ass_renderer::backends::BackendType;
+use ass_renderer::renderer::Renderer;
+
+/// Family name the embedded font is renamed to, installed nowhere and as
+/// long as the original name
+const FAMILY: &str = "EmbeddedOnly";
+
+/// Installed font the embedded one is copied from
+const ORIGINAL: &str = "DejaVu Serif";
+
+/// Script drawing a line in the `family` font, followed by `fonts`
+fn script_source(family: &str, fonts: &str) -> String {
+    format!(
+        "[Script Info]\nPlayResX: 320\nPlayResY: 180\n\n[V4+ Styles]\nFormat: Name, Fontname, Fontsize, PrimaryColour, SecondaryColour, OutlineColour, BackColour, Bold, Italic, Underline, StrikeOut, ScaleX, ScaleY, Spacing, Angle, BorderStyle, Outline, Shadow, Alignment, MarginL, MarginR, MarginV, Encoding\nStyle: Default,{family},32,&H00FFFFFF,&H000000FF,&H00000000,&H00000000,0,0,0,0,100,100,0,0,1,0,0,7,10,10,10,1\n\n[Events]\nFormat: Layer, Start, End, Style, Name, MarginL, MarginR, MarginV, Effect, Text\nDialogue: 0,0:00:00.00,0:00:05.00,Default,,0,0,0,,Embedded glyphs\n{fonts}"
+    )
+}
+
+/// `ORIGINAL` with its family renamed to `FAMILY`
+fn renamed_font() -> Vec<u8> {
+    let mut db = fontdb::Database::new();
+    db.load_system_fonts();
+    let id = db
+        .query(&fontdb::Query {
+            families: &[fontdb::Family::Name(ORIGINAL)],
+            ..fontdb::Query::default()
+        })
+        .expect("DejaVu Serif installed");
+    let mut data = db
+        .with_face_data(id, |data, _| data.to_vec())
+        .expect("font data");
+
+    // Same-length replacement keeps every name table offset valid
+    let utf16 =
+        |text: &str| -> Vec<u8> { text.encode_utf16().flat_map(u16::to_be_bytes).collect() };
+    for (from, to) in [
+        (ORIGINAL.as_bytes().to_vec(), FAMILY.as_bytes().to_vec()),
+        (utf16(ORIGINAL), utf16(FAMILY)),
+    ] {
+        let mut pos = 0;
+        while let Some(found) = data[pos..].windows(from.len()).position(|w| w == from) {
+            let at = pos + found;
+            data[at..at + to.len()].copy_from_slice(&to);
+            pos = at + to.len();
+        }
+    }
+    data
+}
+
+/// UU-encode `data` into `[Fonts]` section lines
+fn uu_encode(data: &[u8]) -> String {
+    let encode = |bits: u8| char::from(b' ' + (bits & 0x3F));
+    let mut lines = String::new();
+    for chunk in data.chunks(45) {
+        lines.push(char::from(b' ' + u8::try_from(chunk.len()).unwrap()));
+        for group in chunk.chunks(3) {
+            let b = [
+                group[0],
+                group.get(1).copied().unwrap_or(0),
+                group.get(2).copied().unwrap_or(0),
+            ];
+            lines.push(encode(b[0] >> 2));
+            lines.push(encode((b[0] << 4) | (b[1] >> 4)));
+            lines.push(encode((b[1] << 2) | (b[2] >> 6)));
+            lines.push(encode(b[2]));
+        }
+        lines.push('\n');
+    }
+    lines
+}
+
+/// Render the first frame of `source` through `Renderer::from_script`
+///
+/// Shaped runs are cached per thread by family name, so each render runs on
+/// a fresh thread to resolve the family against its own fonts.
+fn render(source: String) -> Vec<u8> {
+    std::thread::spawn(move || {
+        let script = Script::parse(&source).expect("parse");
+        let mut renderer =
+            Renderer::from_script(&script, &[], BackendType::Software).expect("renderer");
+        renderer
+            .render_frame(&script, 100)
+            .expect("render")
+            .data()
+            .to_vec()
+    })
+    .join()
+    .expect("render thread")
+}
+
+#[test]
+fn from_script_renders_with_embedded_only_font() {
+    let fonts = format!(
+        "\n[Fonts]\nfontname: EmbeddedOnly_0.ttf\n{}",
+        uu_encode(&renamed_font())
+    );
+    let embedded = render(script_source(FAMILY, &fonts));
+
+    assert!(embedded.iter().any(|&b| b != 0));
+    assert_eq!(embedded, render(script_source(ORIGINAL, "")));
+    assert_ne!(embedded, render(script_source(FAMILY, "")));
+}
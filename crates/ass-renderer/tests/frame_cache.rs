@@ -0,0 +1,158 @@
+//! Timed frame cache reuse through `Renderer::render_frame` and
+//! `Renderer::render_frame_cached`.
+#![cfg(all(feature = "software-backend", feature = "analysis-integration"))]
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+use ass_core::parser::Script;
+use ass_renderer::backends::software::SoftwareBackend;
+use ass_renderer::backends::{BackendType, RenderBackend};
+use ass_renderer::cache::{EvictionPolicy, FrameCache};
+use ass_renderer::pipeline::{IntermediateLayer, Pipeline};
+use ass_renderer::renderer::{RenderContext, Renderer};
+use ass_renderer::RenderError;
+
+const SCRIPT: &str = "[Script Info]\nPlayResX: 320\nPlayResY: 180\n\n[V4+ Styles]\nFormat: Name, Fontname, Fontsize, PrimaryColour, SecondaryColour, OutlineColour, BackColour, Bold, Italic, Underline, StrikeOut, ScaleX, ScaleY, Spacing, Angle, BorderStyle, Outline, Shadow, Alignment, MarginL, MarginR, MarginV, Encoding\nStyle: Default,DejaVu Sans,24,&H00FFFFFF,&H000000FF,&H00000000,&H00000000,0,0,0,0,100,100,0,0,1,2,0,2,10,10,10,1\n\n[Events]\nFormat: Layer, Start, End, Style, Name, MarginL, MarginR, MarginV, Effect, Text\nDialogue: 0,0:00:00.00,0:00:05.00,Default,,0,0,0,,{\\move(20,90,300,90)}Moving line\n";
+
+/// Software backend counting how often it rasterizes a frame
+struct CountingBackend {
+    inner: SoftwareBackend,
+    composites: Arc<AtomicUsize>,
+}
+
+impl RenderBackend for CountingBackend {
+    fn backend_type(&self) -> BackendType {
+        self.inner.backend_type()
+    }
+
+    fn create_pipeline(&self) -> Result<Box<dyn Pipeline>, RenderError> {
+        self.inner.create_pipeline()
+    }
+
+    fn composite_layers(
+        &mut self,
+        layers: &[IntermediateLayer],
+        context: &RenderContext,
+    ) -> Result<Vec<u8>, RenderError> {
+        self.composites.fetch_add(1, Ordering::SeqCst);
+        self.inner.composite_layers(layers, context)
+    }
+}
+
+fn counting_renderer() -> (Renderer, Arc<AtomicUsize>) {
+    let composites = Arc::new(AtomicUsize::new(0));
+    let backend = CountingBackend {
+        inner: SoftwareBackend::new(&RenderContext::new(320, 180)).expect("backend"),
+        composites: Arc::clone(&composites),
+    };
+    let renderer =
+        Renderer::with_backend(RenderContext::new(320, 180), Box::new(backend)).expect("renderer");
+    (renderer, composites)
+}
+
+#[test]
+fn repeated_time_skips_rasterizer() {
+    let script = Script::parse(SCRIPT).expect("parse");
+    let (mut renderer, composites) = counting_renderer();
+
+    let first = renderer.render_frame_cached(&script, 100).expect("render");
+    assert_eq!(composites.load(Ordering::SeqCst), 1);
+    let second = renderer.render_frame_cached(&script, 100).expect("render");
+    assert_eq!(composites.load(Ordering::SeqCst), 1);
+    assert!(Arc::ptr_eq(&first, &second));
+    assert_eq!(second.timestamp(), 100);
+
+    // Animated text is re-rendered at a new time
+    renderer.render_frame_cached(&script, 200).expect("render");
+    assert_eq!(composites.load(Ordering::SeqCst), 2);
+
+    let stats = renderer.frame_cache_stats();
+    assert_eq!((stats.hits, stats.entries), (1, 2));
+}
+
+#[test]
+fn invalidation_and_new_script_force_rerender() {
+    let script = Script::parse(SCRIPT).expect("parse");
+    let (mut renderer, composites) = counting_renderer();
+
+    renderer.render_frame_cached(&script, 100).expect("render");
+    renderer.invalidate_frames_at(100);
+    renderer.render_frame_cached(&script, 100).expect("render");
+    assert_eq!(composites.load(Ordering::SeqCst), 2);
+
+    let source = SCRIPT.replace("Moving line", "Edited line");
+    let edited = Script::parse(&source).expect("parse");
+    renderer.render_frame_cached(&edited, 100).expect("render");
+    assert_eq!(composites.load(Ordering::SeqCst), 3);
+}
+
+#[test]
+fn in_place_edit_forces_rerender() {
+    let mut script = Script::parse(SCRIPT).expect("parse");
+    let (mut renderer, composites) = counting_renderer();
+
+    let before = renderer.render_frame_cached(&script, 100).expect("render");
+    for event in script.events_iter_mut() {
+        event.text = "{\\move(20,90,300,90)}Edited in place";
+    }
+    let after = renderer.render_frame_cached(&script, 100).expect("render");
+    assert_eq!(composites.load(Ordering::SeqCst), 2);
+    assert_ne!(before.data(), after.data());
+}
+
+#[test]
+fn plain_render_uses_cache() {
+    let mut script = Script::parse(SCRIPT).expect("parse");
+    let (mut renderer, composites) = counting_renderer();
+
+    let first = renderer.render_frame(&script, 100).expect("render");
+    let second = renderer.render_frame(&script, 100).expect("render");
+    assert_eq!(composites.load(Ordering::SeqCst), 1);
+    assert_eq!(renderer.frame_cache_stats().entries, 1);
+    assert_eq!(first.data(), second.data());
+
+    // Shared with the explicitly cached path
+    renderer.render_frame_cached(&script, 100).expect("render");
+    assert_eq!(composites.load(Ordering::SeqCst), 1);
+
+    for event in script.events_iter_mut() {
+        event.text = "{\\move(20,90,300,90)}Edited in place";
+    }
+    let edited = renderer.render_frame(&script, 100).expect("render");
+    assert_eq!(composites.load(Ordering::SeqCst), 2);
+    assert_ne!(first.data(), edited.data());
+}
+
+#[test]
+fn eviction_policy_bounds_renderer_cache() {
+    let script = Script::parse(SCRIPT).expect("parse");
+    let (mut renderer, composites) = counting_renderer();
+    renderer.set_frame_cache(FrameCache::new(EvictionPolicy::MaxEntries(1)));
+
+    renderer.render_frame_cached(&script, 100).expect("render");
+    renderer.render_frame_cached(&script, 200).expect("render");
+    renderer.render_frame_cached(&script, 100).expect("render");
+    assert_eq!(composites.load(Ordering::SeqCst), 3);
+    assert_eq!(renderer.frame_cache_stats().entries, 1);
+}
+
+#[test]
+fn resize_clears_cache_and_renders_at_new_size() {
+    let script = Script::parse(SCRIPT).expect("parse");
+    let (mut renderer, composites) = counting_renderer();
+    renderer.notify_resize(1280, 720);
+
+    renderer.render_frame_cached(&script, 100).expect("render");
+    assert_eq!(renderer.frame_cache_stats().entries, 1);
+
+    renderer.notify_resize(1920, 1080);
+    assert_eq!(renderer.frame_cache_stats().entries, 0);
+    let frame = renderer.render_frame_cached(&script, 100).expect("render");
+    assert_eq!((frame.width(), frame.height()), (1920, 1080));
+    assert_eq!(composites.load(Ordering::SeqCst), 2);
+
+    // Same size again keeps the cache
+    renderer.notify_resize(1920, 1080);
+    assert_eq!(renderer.frame_cache_stats().entries, 1);
+}
@@ -0,0 +1,82 @@
+//! Frame diff metrics for the debug analyzer.
+#![cfg(all(feature = "software-backend", feature = "analysis-integration"))]
+
+use ass_core::parser::Script;
+use ass_renderer::backends::BackendType;
+use ass_renderer::debug::{FrameAnalyzer, FrameInspector, Rgba};
+use ass_renderer::renderer::{Frame, RenderContext, Renderer};
+
+const HEAD: &str = "[Script Info]\nPlayResX: 640\nPlayResY: 360\n\n[V4+ Styles]\nFormat: Name, Fontname, Fontsize, PrimaryColour, SecondaryColour, OutlineColour, BackColour, Bold, Italic, Underline, StrikeOut, ScaleX, ScaleY, Spacing, Angle, BorderStyle, Outline, Shadow, Alignment, MarginL, MarginR, MarginV, Encoding\nStyle: Default,DejaVu Sans,32,&H00FFFFFF,&H000000FF,&H00000000,&H00000000,0,0,0,0,100,100,0,0,1,2,0,2,20,20,20,1\n\n[Events]\nFormat: Layer, Start, End, Style, Name, MarginL, MarginR, MarginV, Effect, Text\n";
+
+fn render(text: &str) -> Frame {
+    let script_text = format!("{HEAD}Dialogue: 0,0:00:00.00,0:00:05.00,Default,,0,0,0,,{text}\n");
+    let script = Script::parse(&script_text).expect("parse");
+    let mut renderer =
+        Renderer::new(BackendType::Software, RenderContext::new(640, 360)).expect("renderer");
+    renderer.render_frame(&script, 100).expect("render")
+}
+
+#[test]
+fn identical_frames_have_infinite_psnr() {
+    let frame = render("A simple subtitle");
+    let diff = FrameAnalyzer::diff(&frame, &render("A simple subtitle"));
+    assert!(diff.is_identical());
+    assert_eq!(diff.psnr_db, f64::INFINITY);
+    assert!((diff.ssim - 1.0).abs() < 1e-9);
+    assert!(diff
+        .diff_bitmap
+        .chunks_exact(4)
+        .all(|px| px == [0, 0, 0, 255]));
+}
+
+#[test]
+fn small_change_in_one_line_subtitle_keeps_psnr_above_40db() {
+    let a = render("A simple subtitle");
+    let b = render("A simple subtitlе"); // Cyrillic "е"
+    let diff = FrameAnalyzer::diff(&a, &b);
+    assert!(diff.psnr_db > 40.0, "psnr {}", diff.psnr_db);
+    assert!(diff.ssim > 0.95, "ssim {}", diff.ssim);
+    assert!(diff.mean_diff < 1.0);
+    assert_eq!(diff.diff_bitmap.len(), 640 * 360 * 4);
+}
+
+#[test]
+fn different_text_scores_lower_than_identical() {
+    let a = render("A simple subtitle");
+    let diff = FrameAnalyzer::diff(&a, &render("Something else entirely, and longer"));
+    assert!(diff.max_diff > 0);
+    assert!(diff.psnr_db.is_finite());
+    assert!(diff.ssim < 1.0);
+    assert!(diff.diff_bitmap.chunks_exact(4).any(|px| px[0] == 255));
+}
+
+#[test]
+fn inspector_samples_pixels_and_lines() {
+    let frame = Frame::from_rgba(vec![10, 20, 30, 40, 50, 60, 70, 80], 2, 1);
+    assert_eq!(
+        FrameInspector::sample_at(&frame, 1, 0),
+        Rgba {
+            r: 50,
+            g: 60,
+            b: 70,
+            a: 80
+        }
+    );
+    assert_eq!(FrameInspector::sample_at(&frame, 2, 0), Rgba::default());
+
+    let mut inspector = FrameInspector::new();
+    inspector.load_frame(frame);
+    assert_eq!(inspector.sample_line(0).len(), 2);
+    assert!(inspector.sample_line(1).is_empty());
+}
+
+#[cfg(feature = "libass-compare")]
+#[test]
+fn one_line_subtitle_matches_libass_above_40db() {
+    let script =
+        format!("{HEAD}Dialogue: 0,0:00:00.00,0:00:05.00,Default,,0,0,0,,A simple subtitle\n");
+    let libass = ass_renderer::debug::libass::Libass::new(640, 360).expect("libass");
+    libass.set_fonts(None, "DejaVu Sans", true).expect("fonts");
+    let diff = libass.compare_frame(&script, 100).expect("compare");
+    assert!(diff.psnr_db > 40.0, "psnr {}", diff.psnr_db);
+}
@@ -0,0 +1,64 @@
+//! `\pbo` baseline offset and `\fsp` letter spacing in the software backend.
+#![cfg(all(feature = "software-backend", feature = "analysis-integration"))]
+
+use ass_core::parser::Script;
+use ass_renderer::backends::BackendType;
+use ass_renderer::renderer::{RenderContext, Renderer};
+
+const HEAD: &str = "[Script Info]\nPlayResX: 640\nPlayResY: 360\n\n[V4+ Styles]\nFormat: Name, Fontname, Fontsize, PrimaryColour, SecondaryColour, OutlineColour, BackColour, Bold, Italic, Underline, StrikeOut, ScaleX, ScaleY, Spacing, Angle, BorderStyle, Outline, Shadow, Alignment, MarginL, MarginR, MarginV, Encoding\nStyle: Default,Arial,48,&H00FFFFFF,&H000000FF,&H00000000,&H00000000,0,0,0,0,100,100,0,0,1,0,0,7,30,30,30,1\n\n[Events]\nFormat: Layer, Start, End, Style, Name, MarginL, MarginR, MarginV, Effect, Text\n";
+
+/// Bounding box `(min_x, min_y, max_x, max_y)` of the opaque pixels of a
+/// single dialogue line rendered at `width` x `height`
+fn opaque_bbox(dialogue_text: &str, width: u32, height: u32) -> (usize, usize, usize, usize) {
+    let script_text =
+        format!("{HEAD}Dialogue: 0,0:00:00.00,0:00:10.00,Default,,0,0,0,,{dialogue_text}\n");
+    let script = Script::parse(&script_text).expect("parse");
+    let ctx = RenderContext::new(width, height);
+    let mut renderer = Renderer::new(BackendType::Software, ctx).expect("renderer");
+    let frame = renderer.render_frame(&script, 100).expect("render");
+
+    let mut bbox = (usize::MAX, usize::MAX, 0, 0);
+    for (i, px) in frame.data().chunks_exact(4).enumerate() {
+        if px[3] >= 128 {
+            let (x, y) = (i % width as usize, i / width as usize);
+            bbox = (bbox.0.min(x), bbox.1.min(y), bbox.2.max(x), bbox.3.max(y));
+        }
+    }
+    assert!(bbox.0 <= bbox.2, "nothing rendered for {dialogue_text}");
+    bbox
+}
+
+#[test]
+fn pbo_shifts_glyphs_down_by_its_value() {
+    let plain = opaque_bbox(r"{\pos(100,100)}Hello", 640, 360);
+    let lowered = opaque_bbox(r"{\pos(100,100)\pbo10}Hello", 640, 360);
+    assert_eq!(lowered.1, plain.1 + 10);
+    assert_eq!(lowered.3, plain.3 + 10);
+    assert_eq!((lowered.0, lowered.2), (plain.0, plain.2));
+
+    let raised = opaque_bbox(r"{\pos(100,100)\pbo-10}Hello", 640, 360);
+    assert_eq!(raised.1 + 10, plain.1);
+}
+
+#[test]
+fn pbo_scales_with_the_render_resolution() {
+    let plain = opaque_bbox(r"{\pos(100,100)}Hello", 1280, 720);
+    let lowered = opaque_bbox(r"{\pos(100,100)\pbo10}Hello", 1280, 720);
+    assert_eq!(lowered.1, plain.1 + 20);
+}
+
+#[test]
+fn pbo_applies_from_its_override_block_onwards() {
+    let plain = opaque_bbox(r"{\pos(100,100)}HH", 640, 360);
+    let split = opaque_bbox(r"{\pos(100,100)}H{\pbo10}H", 640, 360);
+    assert_eq!(split.1, plain.1, "the first H stays on the baseline");
+    assert_eq!(split.3, plain.3 + 10, "the second H is lowered");
+}
+
+#[test]
+fn fsp_adds_spacing_between_characters() {
+    let plain = opaque_bbox(r"{\pos(100,100)}Hello", 640, 360);
+    let spaced = opaque_bbox(r"{\pos(100,100)\fsp10}Hello", 640, 360);
+    // Four gaps between five characters
+    assert_eq!(spaced.2 - spaced.0, plain.2 - plain.0 + 40);
+}
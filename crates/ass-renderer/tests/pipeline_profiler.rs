@@ -0,0 +1,73 @@
+//! Stage timing tests for `PipelineProfiler`.
+//!
+//! The timing budget only applies to optimized builds, so that test is
+//! ignored without `--release`.
+#![cfg(all(feature = "backend-metrics", feature = "software-backend"))]
+
+use std::time::Duration;
+
+use ass_core::parser::Script;
+use ass_renderer::backends::{create_backend, BackendType};
+use ass_renderer::renderer::RenderContext;
+use ass_renderer::{PipelineProfiler, StageName};
+
+const SCRIPT: &str = "[Script Info]\nPlayResX: 1280\nPlayResY: 720\n\n[V4+ Styles]\nFormat: Name, Fontname, Fontsize, PrimaryColour, SecondaryColour, OutlineColour, BackColour, Bold, Italic, Underline, StrikeOut, ScaleX, ScaleY, Spacing, Angle, BorderStyle, Outline, Shadow, Alignment, MarginL, MarginR, MarginV, Encoding\nStyle: Default,DejaVu Sans,48,&H00FFFFFF,&H000000FF,&H00000000,&H00000000,0,0,0,0,100,100,0,0,1,2,0,2,30,30,30,1\n\n[Events]\nFormat: Layer, Start, End, Style, Name, MarginL, MarginR, MarginV, Effect, Text\nDialogue: 0,0:00:00.00,0:00:10.00,Default,,0,0,0,,A simple subtitle line\n";
+
+fn profiler() -> PipelineProfiler {
+    let backend = create_backend(BackendType::Software, 1280, 720).expect("backend");
+    PipelineProfiler::new(RenderContext::new(1280, 720), backend).expect("profiler")
+}
+
+#[test]
+fn records_every_stage_of_a_frame() {
+    let script = Script::parse(SCRIPT).expect("parse");
+    let mut profiler = profiler();
+
+    profiler.render_frame(&script, 100).expect("render");
+    let stages: Vec<StageName> = profiler
+        .last_frame_stages()
+        .iter()
+        .map(|(stage, _)| *stage)
+        .collect();
+    assert_eq!(
+        stages,
+        vec![
+            StageName::EventSelection,
+            StageName::Preparation,
+            StageName::Processing,
+            StageName::Compositing,
+        ]
+    );
+
+    profiler.render_frame(&script, 2000).expect("render");
+    assert_eq!(profiler.last_frame_stages().len(), 1);
+
+    let metrics = profiler.metrics();
+    assert_eq!(metrics.frames_rendered, 2);
+    assert!(metrics.stage_times.contains_key(&StageName::Compositing));
+    assert!(metrics.total_render_time >= metrics.stage_times[&StageName::Processing]);
+}
+
+#[test]
+#[cfg_attr(debug_assertions, ignore = "timing budget applies to release builds")]
+fn simple_script_stages_stay_under_budget_at_720p() {
+    let script = Script::parse(SCRIPT).expect("parse");
+    let mut profiler = profiler();
+
+    // Warm up the shared font database and glyph caches.
+    profiler.render_frame(&script, 0).expect("render");
+    profiler.reset();
+
+    for frame in 0..100 {
+        profiler.render_frame(&script, frame * 4).expect("render");
+        for (stage, time) in profiler.last_frame_stages() {
+            assert!(
+                *time < Duration::from_millis(10),
+                "{} took {time:?} in frame {frame}",
+                stage.as_str()
+            );
+        }
+    }
+    assert_eq!(profiler.metrics().frames_rendered, 100);
+    assert_eq!(profiler.average_stage_times(100).len(), 4);
+}
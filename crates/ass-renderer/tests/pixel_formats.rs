@@ -0,0 +1,69 @@
+//! Pixel format output through `Renderer::render_with_format`.
+#![cfg(all(feature = "software-backend", feature = "analysis-integration"))]
+
+use ass_core::parser::Script;
+use ass_renderer::backends::BackendType;
+use ass_renderer::renderer::{Channel, PixelFormat, Renderer};
+
+const SCRIPT: &str = "[Script Info]\nPlayResX: 320\nPlayResY: 180\n\n[V4+ Styles]\nFormat: Name, Fontname, Fontsize, PrimaryColour, SecondaryColour, OutlineColour, BackColour, Bold, Italic, Underline, StrikeOut, ScaleX, ScaleY, Spacing, Angle, BorderStyle, Outline, Shadow, Alignment, MarginL, MarginR, MarginV, Encoding\nStyle: Default,DejaVu Sans,32,&H0030A0F0,&H000000FF,&H00800000,&H00000000,0,0,0,0,100,100,0,0,1,2,0,2,10,10,10,1\n\n[Events]\nFormat: Layer, Start, End, Style, Name, MarginL, MarginR, MarginV, Effect, Text\nDialogue: 0,0:00:00.00,0:00:05.00,Default,,0,0,0,,Byte order\n";
+
+#[test]
+fn bgra_output_swaps_red_and_blue() {
+    let script = Script::parse(SCRIPT).expect("parse");
+    let mut renderer =
+        Renderer::from_script(&script, &[], BackendType::Software).expect("renderer");
+    let rgba = renderer.render_frame(&script, 100).expect("render");
+    let bgra = renderer.render_bgra(&script, 100).expect("bgra");
+
+    assert_eq!(bgra.len(), rgba.data().len());
+    assert!(rgba
+        .data()
+        .chunks_exact(4)
+        .any(|pixel| pixel[0] != pixel[2]));
+    for (a, b) in rgba.data().chunks_exact(4).zip(bgra.chunks_exact(4)) {
+        assert_eq!([a[2], a[1], a[0], a[3]], b);
+    }
+}
+
+#[test]
+fn every_format_round_trips_to_rgba() {
+    let script = Script::parse(SCRIPT).expect("parse");
+    let mut renderer =
+        Renderer::from_script(&script, &[], BackendType::Software).expect("renderer");
+    let rgba = renderer.render_frame(&script, 100).expect("render");
+
+    for format in [PixelFormat::Bgra8, PixelFormat::Argb8, PixelFormat::Abgr8] {
+        let frame = renderer
+            .render_with_format(&script, 100, format)
+            .expect("render");
+        assert_eq!(frame.format(), format);
+        let alpha = format.offset_of(Channel::Alpha).expect("alpha");
+        for (a, b) in rgba
+            .data()
+            .chunks_exact(4)
+            .zip(frame.data().chunks_exact(4))
+        {
+            assert_eq!(a[3], b[alpha]);
+        }
+        assert_eq!(frame.to_rgba().data(), rgba.data());
+    }
+
+    let rgb = rgba.clone().to_format(PixelFormat::Rgb8);
+    assert_eq!(rgb.data().len(), 320 * 180 * 3);
+    assert!(!rgb.is_empty());
+}
+
+#[test]
+fn format_metadata_describes_layout() {
+    assert_eq!(PixelFormat::Rgb8.bytes_per_pixel(), 3);
+    assert_eq!(PixelFormat::Argb8.bytes_per_pixel(), 4);
+    assert_eq!(
+        PixelFormat::Bgra8.channel_order(),
+        [Channel::Blue, Channel::Green, Channel::Red, Channel::Alpha]
+    );
+    assert_eq!(
+        PixelFormat::Abgr8.channel_order(),
+        [Channel::Alpha, Channel::Blue, Channel::Green, Channel::Red]
+    );
+    assert_eq!(PixelFormat::Rgb8.offset_of(Channel::Alpha), None);
+}
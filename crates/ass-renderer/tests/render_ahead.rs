@@ -0,0 +1,85 @@
+//! Background prefetching through `RenderAheadQueue`.
+#![cfg(all(feature = "software-backend", feature = "analysis-integration"))]
+
+use std::thread;
+use std::time::{Duration, Instant};
+
+use ass_core::parser::Script;
+use ass_renderer::backends::BackendType;
+use ass_renderer::{RenderAheadQueue, RenderError, Renderer};
+
+const SCRIPT: &str = "[Script Info]\nPlayResX: 160\nPlayResY: 90\n\n[V4+ Styles]\nFormat: Name, Fontname, Fontsize, PrimaryColour, SecondaryColour, OutlineColour, BackColour, Bold, Italic, Underline, StrikeOut, ScaleX, ScaleY, Spacing, Angle, BorderStyle, Outline, Shadow, Alignment, MarginL, MarginR, MarginV, Encoding\nStyle: Default,DejaVu Sans,16,&H00FFFFFF,&H000000FF,&H00000000,&H00000000,0,0,0,0,100,100,0,0,1,1,0,2,10,10,10,1\n\n[Events]\nFormat: Layer, Start, End, Style, Name, MarginL, MarginR, MarginV, Effect, Text\nDialogue: 0,0:00:00.00,0:02:00.00,Default,,0,0,0,,{\\move(10,45,150,45)}Moving line\n";
+
+const FPS: f64 = 20.0;
+const LOOKAHEAD: usize = 6;
+
+fn queue() -> RenderAheadQueue {
+    let script = Script::parse(SCRIPT).expect("parse");
+    let renderer = Renderer::from_script(&script, &[], BackendType::Software).expect("renderer");
+    RenderAheadQueue::new(renderer, &script, FPS, LOOKAHEAD).expect("queue")
+}
+
+/// Poll `ready` until it holds, failing after a generous deadline
+fn wait_until(mut ready: impl FnMut() -> bool) {
+    let deadline = Instant::now() + Duration::from_secs(30);
+    while !ready() {
+        assert!(Instant::now() < deadline, "render-ahead queue stalled");
+        thread::sleep(Duration::from_millis(2));
+    }
+}
+
+#[test]
+fn frames_are_ready_before_display_time() {
+    let queue = queue();
+    wait_until(|| queue.queue_depth() == LOOKAHEAD);
+
+    let start = Instant::now();
+    for index in 0..20 {
+        let display = Duration::from_secs_f64(f64::from(index) / FPS);
+        if let Some(wait) = display.checked_sub(start.elapsed()) {
+            thread::sleep(wait);
+        }
+        let frame = queue
+            .get_frame(f64::from(index) / FPS)
+            .unwrap_or_else(|| panic!("frame {index} not pre-rendered"));
+        assert_eq!(frame.timestamp(), (f64::from(index) * 100.0 / FPS) as u32);
+        assert_eq!((frame.width(), frame.height()), (160, 90));
+    }
+    assert_eq!(queue.hit_rate(), 1.0);
+    assert!(queue.queue_depth() <= 2 * LOOKAHEAD + 1);
+}
+
+#[test]
+fn seek_flushes_distant_frames() {
+    let queue = queue();
+    assert_eq!(queue.hit_rate(), 0.0);
+    wait_until(|| queue.queue_depth() == LOOKAHEAD);
+
+    queue.seek(60.0);
+    assert!(
+        queue.queue_depth() <= 1,
+        "frames near the old position kept"
+    );
+
+    // Until prefetching catches up the caller renders synchronously
+    wait_until(|| queue.get_frame(60.0).is_some());
+    wait_until(|| queue.queue_depth() == LOOKAHEAD);
+    assert!(queue.hit_rate() > 0.0 && queue.hit_rate() <= 1.0);
+
+    // A short seek back keeps the frames still within reach
+    queue.seek(60.0 - 2.0 / FPS);
+    assert!(queue.queue_depth() >= LOOKAHEAD - 2);
+}
+
+#[test]
+fn rejects_invalid_frame_rate() {
+    let script = Script::parse(SCRIPT).expect("parse");
+    for fps in [0.0, -24.0, f64::NAN] {
+        let renderer =
+            Renderer::from_script(&script, &[], BackendType::Software).expect("renderer");
+        assert!(matches!(
+            RenderAheadQueue::new(renderer, &script, fps, LOOKAHEAD),
+            Err(RenderError::InvalidInput(_))
+        ));
+    }
+}
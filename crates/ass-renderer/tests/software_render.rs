@@ -174,6 +174,24 @@ fn frz_rotation_changes_geometry() {
     );
 }
 
+#[test]
+fn frz90_swaps_bbox_axes() {
+    // A quarter turn stands the line on its end: the bounding box keeps its
+    // size with width and height exchanged.
+    let (pw, _, plain) = render("ROTATME");
+    let (rw, _, rot) = render("{\\frz90}ROTATME");
+    let (plain_w, plain_h) = (
+        opaque_bbox_width(&plain, pw),
+        opaque_bbox_height(&plain, pw),
+    );
+    let (rot_w, rot_h) = (opaque_bbox_width(&rot, rw), opaque_bbox_height(&rot, rw));
+    assert!(plain_w > plain_h, "unrotated line should be wide");
+    assert!(
+        rot_h.abs_diff(plain_w) <= 2 && rot_w.abs_diff(plain_h) <= 2,
+        "expected \\frz90 bbox {plain_h}x{plain_w}, got {rot_w}x{rot_h}"
+    );
+}
+
 #[test]
 fn karaoke_uses_primary_and_secondary_not_yellow() {
     // Default style: primary white, secondary red (&H000000FF). A `\k` syllable
@@ -835,26 +853,43 @@ fn letter_spacing_scales_with_resolution() {
     );
 }
 
+/// Row ranges of the separate horizontal bands of opaque rows (rendered lines).
+fn bands(data: &[u8], w: usize, h: usize) -> Vec<core::ops::Range<usize>> {
+    let mut bands = Vec::new();
+    let mut band_start = None;
+    for y in 0..h {
+        let lit = (0..w).filter(|x| data[(y * w + x) * 4 + 3] >= 128).count();
+        let on = lit >= 3;
+        match (on, band_start) {
+            (true, None) => band_start = Some(y),
+            (false, Some(start)) => {
+                bands.push(start..y);
+                band_start = None;
+            }
+            _ => {}
+        }
+    }
+    if let Some(start) = band_start {
+        bands.push(start..h);
+    }
+    bands
+}
+
+/// Number of separate horizontal bands of opaque rows (rendered lines).
+fn count_bands(data: &[u8], w: usize, h: usize) -> usize {
+    bands(data, w, h).len()
+}
+
+/// Width of the opaque bounding box within the rows `rows`.
+fn band_width(data: &[u8], w: usize, rows: core::ops::Range<usize>) -> usize {
+    opaque_bbox_width(&data[rows.start * w * 4..rows.end * w * 4], w)
+}
+
 #[test]
 fn wrap_style_2_disables_wrapping() {
     // WrapStyle 2 / `\q2`: no width-based wrapping. A line that the smart default
     // wraps must stay a single (overflowing) line under `\q2`, breaking only on
     // explicit `\N`.
-    let count_bands = |data: &[u8], w: usize, h: usize| -> usize {
-        let mut bands = 0;
-        let mut in_band = false;
-        for y in 0..h {
-            let lit = (0..w).filter(|x| data[(y * w + x) * 4 + 3] >= 128).count();
-            let on = lit >= 3;
-            if on && !in_band {
-                bands += 1;
-                in_band = true;
-            } else if !on && in_band {
-                in_band = false;
-            }
-        }
-        bands
-    };
     let long =
         "This is a very long subtitle line that certainly exceeds the available width and must wrap";
 
@@ -872,6 +907,41 @@ fn wrap_style_2_disables_wrapping() {
     );
 }
 
+#[test]
+fn q3_puts_the_wider_line_at_the_bottom() {
+    // Smart wrapping balances the two lines; `\q0` leaves the extra word on the
+    // upper line and `\q3` on the lower one.
+    let long = "alpha bravo delta gamma kappa omega sigma theta lambda";
+    let line_widths = |text: &str| -> Vec<usize> {
+        let (w, h, data) = render(text);
+        bands(&data, w, h)
+            .into_iter()
+            .map(|rows| band_width(&data, w, rows))
+            .collect()
+    };
+
+    let top = line_widths(&format!("{{\\q0}}{long}"));
+    assert_eq!(top.len(), 2, "\\q0 must wrap into two lines");
+    assert!(top[0] > top[1], "\\q0 widths {top:?}");
+
+    let bottom = line_widths(&format!("{{\\q3}}{long}"));
+    assert_eq!(bottom.len(), 2, "\\q3 must wrap into two lines");
+    assert!(bottom[1] > bottom[0], "\\q3 widths {bottom:?}");
+}
+
+#[test]
+fn soft_break_only_breaks_under_q2() {
+    // `\n` is a space unless wrapping is disabled, while `\N` always breaks.
+    let (w, h, smart) = render("Soft\\nbreak");
+    assert_eq!(count_bands(&smart, w, h), 1, "\\n is a space by default");
+
+    let (w, h, no_wrap) = render("{\\q2}Soft\\nbreak");
+    assert_eq!(count_bands(&no_wrap, w, h), 2, "\\q2 honours \\n");
+
+    let (w, h, hard) = render("Hard\\Nbreak");
+    assert_eq!(count_bands(&hard, w, h), 2, "\\N always breaks");
+}
+
 #[test]
 fn t_animates_font_size_from_base() {
     // Regression: `\t(\fs..)` with no preceding `\fs` must interpolate from the
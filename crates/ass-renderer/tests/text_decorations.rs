@@ -0,0 +1,139 @@
+//! Underline (`\u1`) and strikethrough (`\s1`) rendering in the software backend.
+//!
+//! Decorations are solid lines spanning the whole run, so they show up as
+//! rows (or, when rotated, columns) that are opaque across the full width of
+//! the text. Glyphs alone never fill such a row.
+#![cfg(all(feature = "software-backend", feature = "analysis-integration"))]
+
+use ass_core::parser::Script;
+use ass_renderer::backends::BackendType;
+use ass_renderer::renderer::{RenderContext, Renderer};
+
+const HEAD: &str = "[Script Info]\nPlayResX: 640\nPlayResY: 360\n\n[V4+ Styles]\nFormat: Name, Fontname, Fontsize, PrimaryColour, SecondaryColour, OutlineColour, BackColour, Bold, Italic, Underline, StrikeOut, ScaleX, ScaleY, Spacing, Angle, BorderStyle, Outline, Shadow, Alignment, MarginL, MarginR, MarginV, Encoding\nStyle: Default,Arial,48,&H00FFFFFF,&H000000FF,&H00000000,&H00000000,0,0,0,0,100,100,0,0,1,0,0,5,30,30,30,1\n\n[Events]\nFormat: Layer, Start, End, Style, Name, MarginL, MarginR, MarginV, Effect, Text\n";
+
+const WIDTH: usize = 640;
+const HEIGHT: usize = 360;
+
+/// Render a single dialogue line at t=1s and return its RGBA bytes
+fn render(dialogue_text: &str) -> Vec<u8> {
+    let script_text =
+        format!("{HEAD}Dialogue: 0,0:00:00.00,0:00:10.00,Default,,0,0,0,,{dialogue_text}\n");
+    let script = Script::parse(&script_text).expect("parse");
+    let ctx = RenderContext::new(WIDTH as u32, HEIGHT as u32);
+    let mut renderer = Renderer::new(BackendType::Software, ctx).expect("renderer");
+    let frame = renderer.render_frame(&script, 100).expect("render");
+    frame.data().to_vec()
+}
+
+/// Whether the pixel at `(x, y)` is opaque and satisfies `pred(r, g, b)`
+fn is_opaque<P: Fn(u8, u8, u8) -> bool>(data: &[u8], x: usize, y: usize, pred: &P) -> bool {
+    let px = &data[(y * WIDTH + x) * 4..][..4];
+    px[3] >= 128 && pred(px[0], px[1], px[2])
+}
+
+/// Horizontal extent `(min_x, max_x)` of all opaque pixels
+fn opaque_columns(data: &[u8]) -> (usize, usize) {
+    let xs = (0..HEIGHT)
+        .flat_map(|y| (0..WIDTH).map(move |x| (x, y)))
+        .filter(|&(x, y)| is_opaque(data, x, y, &|_, _, _| true))
+        .map(|(x, _)| x);
+    xs.fold((usize::MAX, 0), |(lo, hi), x| (lo.min(x), hi.max(x)))
+}
+
+/// Rows in which at least 90% of the text's opaque width satisfies `pred`
+fn full_rows<P: Fn(u8, u8, u8) -> bool>(data: &[u8], pred: P) -> Vec<usize> {
+    let (min_x, max_x) = opaque_columns(data);
+    if min_x > max_x {
+        return Vec::new();
+    }
+    let span = max_x - min_x + 1;
+    (0..HEIGHT)
+        .filter(|&y| {
+            let filled = (min_x..=max_x)
+                .filter(|&x| is_opaque(data, x, y, &pred))
+                .count();
+            filled * 10 >= span * 9
+        })
+        .collect()
+}
+
+fn any_color(_: u8, _: u8, _: u8) -> bool {
+    true
+}
+
+#[test]
+fn plain_text_has_no_full_rows() {
+    assert!(full_rows(&render("Wave"), any_color).is_empty());
+}
+
+#[test]
+fn underline_is_a_thin_line_below_the_text() {
+    let rows = full_rows(&render(r"{\u1}Wave"), any_color);
+    assert!(
+        (1..=3).contains(&rows.len()),
+        "expected a 1-3 px underline, got rows {rows:?}"
+    );
+
+    // Descenders crossing the line must not punch holes into it.
+    let rows = full_rows(&render(r"{\u1}gypsy"), any_color);
+    assert!(!rows.is_empty(), "underline broken by descenders");
+}
+
+#[test]
+fn strikethrough_sits_above_the_underline() {
+    let strike = full_rows(&render(r"{\s1}Wave"), any_color);
+    let under = full_rows(&render(r"{\u1}Wave"), any_color);
+    assert!((1..=3).contains(&strike.len()), "strike rows {strike:?}");
+    assert!(
+        strike.iter().max() < under.iter().min(),
+        "strike {strike:?} should be above underline {under:?}"
+    );
+    let both = full_rows(&render(r"{\u1\s1}Wave"), any_color);
+    assert_eq!(both.len(), strike.len() + under.len(), "rows {both:?}");
+}
+
+#[test]
+fn decorations_use_the_primary_color() {
+    let red = |r: u8, g: u8, b: u8| r > 200 && g < 60 && b < 60;
+    let rows = full_rows(&render(r"{\1c&H0000FF&\u1}Wave"), red);
+    assert!(!rows.is_empty(), "no red underline row");
+}
+
+#[test]
+fn border_outlines_the_decoration() {
+    // Green fill, red 3 px border: the underline gains red rows above and below.
+    let green = |r: u8, g: u8, b: u8| g > 200 && r < 60 && b < 60;
+    let red = |r: u8, g: u8, b: u8| r > 200 && g < 60 && b < 60;
+    let data = render(r"{\1c&H00FF00&\3c&H0000FF&\bord3\u1}Wave");
+    let fill = full_rows(&data, green);
+    let border = full_rows(&data, red);
+    assert!(!fill.is_empty(), "no filled underline row");
+    assert!(
+        border.iter().any(|y| y < fill.iter().min().unwrap()),
+        "no border above the underline: fill {fill:?}, border {border:?}"
+    );
+    assert!(
+        border.iter().any(|y| y > fill.iter().max().unwrap()),
+        "no border below the underline: fill {fill:?}, border {border:?}"
+    );
+}
+
+#[test]
+fn rotated_underline_turns_into_a_column() {
+    let data = render(r"{\frz90\u1}Wave");
+    assert!(full_rows(&data, any_color).is_empty());
+
+    // Transpose the check: some column is opaque over 90% of the text height.
+    let ys: Vec<usize> = (0..HEIGHT)
+        .filter(|&y| (0..WIDTH).any(|x| is_opaque(&data, x, y, &any_color)))
+        .collect();
+    let (top, bottom) = (ys[0], ys[ys.len() - 1]);
+    let span = bottom - top + 1;
+    let full_column = (0..WIDTH).any(|x| {
+        let filled = (top..=bottom)
+            .filter(|&y| is_opaque(&data, x, y, &any_color))
+            .count();
+        filled * 10 >= span * 9
+    });
+    assert!(full_column, "rotated underline should span the text height");
+}
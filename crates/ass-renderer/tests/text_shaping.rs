@@ -0,0 +1,244 @@
+//! Text shaping tests against the system font database.
+//!
+//! Require a DejaVu Sans install (CI installs `fonts-dejavu-core`).
+
+use ass_renderer::pipeline::shaping::{
+    is_emoji_cluster, shape_text_with_features, EmojiHandling, FontFeatures, ShapingCacheStats,
+    TextShaper, EMOJI_ADVANCE_EMS, LIGA,
+};
+use fontdb::Database;
+
+fn font_database() -> Database {
+    let mut db = Database::new();
+    db.load_system_fonts();
+    db
+}
+
+#[test]
+fn disabling_ligatures_shapes_fi_as_two_glyphs() {
+    let db = font_database();
+    let shape = |features: &FontFeatures| {
+        shape_text_with_features("fi", "DejaVu Sans", 48.0, false, false, features, &db)
+            .expect("shape")
+    };
+
+    let ligated = shape(&FontFeatures::new());
+    assert_eq!(
+        ligated.glyphs.len(),
+        1,
+        "default shaping forms the fi ligature"
+    );
+
+    let separate = shape(&FontFeatures::new().with(LIGA, 0));
+    assert_eq!(separate.glyphs.len(), 2);
+    assert!(separate.glyphs[0].glyph_id != ligated.glyphs[0].glyph_id);
+}
+
+#[test]
+fn encoding_selects_default_features() {
+    let latin = FontFeatures::from_encoding(0);
+    assert_eq!(latin.get(LIGA), Some(1));
+    assert!(FontFeatures::from_encoding(1).is_empty());
+    assert!(FontFeatures::from_encoding(128).is_empty());
+}
+
+#[test]
+fn flag_emoji_is_one_square_cluster() {
+    let db = font_database();
+    let text = "Hi \u{1F1EF}\u{1F1F5}!";
+    assert!(is_emoji_cluster("\u{1F1EF}\u{1F1F5}"));
+    assert!(!is_emoji_cluster("\u{2764}\u{FE0E}"));
+
+    let shaper = TextShaper::new().with_emoji_handling(EmojiHandling::SquareBlock);
+    let shaped = shaper
+        .shape_text(text, "DejaVu Sans", 40.0, false, false, &db)
+        .expect("shape");
+
+    let flag: Vec<_> = shaped.glyphs.iter().filter(|g| g.cluster == 3).collect();
+    assert_eq!(flag.len(), 1, "the flag is a single cluster");
+    assert_eq!(flag[0].x_advance, 40.0 * EMOJI_ADVANCE_EMS);
+
+    let last = shaped.glyphs.last().expect("glyphs");
+    assert_eq!(
+        last.cluster, 11,
+        "clusters after the flag keep their byte offsets"
+    );
+    assert!(last.x_position >= flag[0].x_position + flag[0].x_advance);
+
+    let plain = TextShaper::new()
+        .shape_text(text, "DejaVu Sans", 40.0, false, false, &db)
+        .expect("shape");
+    assert!(
+        plain
+            .glyphs
+            .iter()
+            .filter(|g| g.cluster >= 3 && g.cluster < 11)
+            .count()
+            >= 1
+    );
+}
+
+#[test]
+fn static_text_hits_shaping_cache_across_frames() {
+    let db = font_database();
+    let shaper = TextShaper::new();
+    // 100 dialogue lines of 3 s each, shaped once per frame at 30 fps
+    let lines: Vec<String> = (0..100)
+        .map(|i| format!("Dialogue line number {i}"))
+        .collect();
+    for frame in 0..(100 * 3 * 30) {
+        let line = &lines[frame / 90];
+        let shaped = shaper
+            .shape_text(line, "DejaVu Sans", 40.0, false, false, &db)
+            .expect("shape");
+        assert!(!shaped.glyphs.is_empty());
+    }
+
+    let stats = shaper.cache_stats();
+    assert_eq!(stats.misses, 100);
+    assert!(stats.hit_rate() > 0.95, "hit rate {}", stats.hit_rate());
+}
+
+#[test]
+fn shaping_cache_invalidates_on_configuration_change() {
+    let mut db = font_database();
+    let mut shaper = TextShaper::new();
+    let shape = |shaper: &TextShaper, db: &Database| {
+        shaper
+            .shape_text("fi", "DejaVu Sans", 48.0, false, false, db)
+            .expect("shape")
+    };
+
+    assert_eq!(shape(&shaper, &db).glyphs.len(), 1);
+    assert_eq!(shape(&shaper, &db).glyphs.len(), 1);
+    assert_eq!(
+        shaper.cache_stats(),
+        ShapingCacheStats { hits: 1, misses: 1 }
+    );
+
+    shaper.features = FontFeatures::new().with(LIGA, 0);
+    assert_eq!(shape(&shaper, &db).glyphs.len(), 2, "new features reshape");
+
+    let font = std::fs::read(
+        db.faces()
+            .find_map(|face| match &face.source {
+                fontdb::Source::File(path) => Some(path.clone()),
+                _ => None,
+            })
+            .expect("system font file"),
+    )
+    .expect("read font");
+    db.load_font_data(font);
+    shape(&shaper, &db);
+    assert_eq!(shaper.cache_stats().misses, 3, "loading a font reshapes");
+
+    let uncached = TextShaper::new().with_shaping_cache(false);
+    shape(&uncached, &db);
+    assert_eq!(uncached.cache_stats(), ShapingCacheStats::default());
+}
+
+#[test]
+fn apply_fsp_spreads_glyphs_and_widens_the_run() {
+    let db = font_database();
+    let plain = shape_text_with_features(
+        "abc",
+        "DejaVu Sans",
+        48.0,
+        false,
+        false,
+        &FontFeatures::new(),
+        &db,
+    )
+    .expect("shape");
+    let mut spaced = plain.clone();
+    spaced.apply_fsp(5.0);
+
+    for (index, (before, after)) in plain.glyphs.iter().zip(&spaced.glyphs).enumerate() {
+        assert_eq!(after.x_position, before.x_position + 5.0 * index as f32);
+    }
+    assert_eq!(spaced.glyphs[0].x_advance, plain.glyphs[0].x_advance + 5.0);
+    assert_eq!(spaced.glyphs[2].x_advance, plain.glyphs[2].x_advance);
+    assert_eq!(spaced.width, plain.width + 10.0);
+}
+
+#[test]
+fn apply_pbo_moves_glyphs_below_the_baseline() {
+    let db = font_database();
+    let plain = shape_text_with_features(
+        "abc",
+        "DejaVu Sans",
+        48.0,
+        false,
+        false,
+        &FontFeatures::new(),
+        &db,
+    )
+    .expect("shape");
+    let mut lowered = plain.clone();
+    lowered.apply_pbo(10.0);
+
+    for (before, after) in plain.glyphs.iter().zip(&lowered.glyphs) {
+        assert_eq!(after.y_position, before.y_position + 10.0);
+    }
+    assert_eq!(lowered.descent, plain.descent - 10.0);
+    assert_eq!(lowered.height, plain.height + 10.0);
+    assert_eq!(lowered.baseline, plain.baseline);
+    assert_eq!(lowered.metrics, plain.metrics);
+}
+
+/// UU-encode `data` into `[Fonts]` section lines
+fn uu_encode(data: &[u8]) -> String {
+    let encode = |bits: u8| char::from(b' ' + (bits & 0x3F));
+    let mut lines = String::new();
+    for chunk in data.chunks(45) {
+        lines.push(char::from(b' ' + u8::try_from(chunk.len()).unwrap()));
+        for group in chunk.chunks(3) {
+            let b = [
+                group[0],
+                group.get(1).copied().unwrap_or(0),
+                group.get(2).copied().unwrap_or(0),
+            ];
+            lines.push(encode(b[0] >> 2));
+            lines.push(encode((b[0] << 4) | (b[1] >> 4)));
+            lines.push(encode((b[1] << 2) | (b[2] >> 6)));
+            lines.push(encode(b[2]));
+        }
+        lines.push('\n');
+    }
+    lines
+}
+
+#[test]
+fn embedded_font_covers_its_glyphs() {
+    let db = font_database();
+    let id = db
+        .query(&fontdb::Query {
+            families: &[fontdb::Family::Name("DejaVu Sans")],
+            ..fontdb::Query::default()
+        })
+        .expect("DejaVu Sans installed");
+    let font_bytes = db
+        .with_face_data(id, |data, _| data.to_vec())
+        .expect("font data");
+
+    let source = format!(
+        "[Script Info]\nTitle: Fonts\n\n[Fonts]\nfontname: DejaVuSans_0.ttf\n{}\n[Fonts]\nfontname: junk.ttf\n#0V%T\n",
+        uu_encode(&font_bytes)
+    );
+    let script = ass_core::parser::Script::parse(&source).expect("parse");
+
+    let mut shaper = TextShaper::new();
+    assert!(!shaper.has_glyph('A'));
+    assert_eq!(shaper.load_fonts_from_script(&script), 1);
+
+    assert!(shaper.has_glyph('A'));
+    assert!(shaper.has_glyph('\u{00E9}'));
+    assert!(!shaper.has_glyph('\u{6F22}'));
+    assert_eq!(
+        shaper.missing_glyphs("Caf\u{00E9} \u{6F22}\u{5B57}\u{6F22}\n"),
+        ['\u{6F22}', '\u{5B57}']
+    );
+    assert!(shaper
+        .shape_text("Hi", "DejaVu Sans", 20.0, false, false, shaper.fonts())
+        .is_ok());
+}
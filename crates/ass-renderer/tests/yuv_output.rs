@@ -0,0 +1,76 @@
+//! BT.601 YUV 4:2:0 and NV12 output.
+#![cfg(all(feature = "software-backend", feature = "analysis-integration"))]
+
+use ass_core::parser::Script;
+use ass_renderer::backends::BackendType;
+use ass_renderer::renderer::{Frame, Renderer};
+
+const SCRIPT: &str = "[Script Info]\nPlayResX: 64\nPlayResY: 32\n\n[V4+ Styles]\nFormat: Name, Fontname, Fontsize, PrimaryColour, SecondaryColour, OutlineColour, BackColour, Bold, Italic, Underline, StrikeOut, ScaleX, ScaleY, Spacing, Angle, BorderStyle, Outline, Shadow, Alignment, MarginL, MarginR, MarginV, Encoding\nStyle: Default,DejaVu Sans,20,&H00FFFFFF,&H000000FF,&H00000000,&H00000000,0,0,0,0,100,100,0,0,1,0,0,7,0,0,0,1\n\n[Events]\nFormat: Layer, Start, End, Style, Name, MarginL, MarginR, MarginV, Effect, Text\nDialogue: 0,0:00:00.00,0:00:05.00,Default,,0,0,0,,{\\pos(0,0)\\c&H3080E0&\\p1}m 0 0 l 64 0 64 32 0 32{\\p0}\n";
+
+fn assert_close(expected: &[u8], actual: &[u8]) {
+    for (channel, (&a, &b)) in expected.iter().zip(actual).enumerate() {
+        assert!(
+            a.abs_diff(b) < 4,
+            "channel {channel}: {a} round-tripped to {b}"
+        );
+    }
+}
+
+#[test]
+fn known_colours_round_trip_within_quantization() {
+    let colours: [[u8; 3]; 6] = [
+        [0, 0, 0],
+        [255, 255, 255],
+        [224, 128, 48],
+        [12, 200, 90],
+        [40, 60, 230],
+        [128, 128, 128],
+    ];
+    // One colour per 2x2 block so chroma subsampling is lossless
+    let (width, height) = (colours.len() as u32 * 2, 2);
+    let mut data = Vec::new();
+    for _ in 0..height {
+        for colour in colours {
+            for _ in 0..2 {
+                data.extend_from_slice(&colour);
+                data.push(255);
+            }
+        }
+    }
+    let frame = Frame::from_rgba(data, width, height);
+    let restored = frame.to_yuv420().to_rgba();
+    for (original, round_trip) in frame
+        .data()
+        .chunks_exact(4)
+        .zip(restored.data().chunks_exact(4))
+    {
+        assert_close(&original[..3], &round_trip[..3]);
+    }
+}
+
+#[test]
+fn rendered_frame_round_trips_through_yuv420() {
+    let script = Script::parse(SCRIPT).expect("parse");
+    let mut renderer =
+        Renderer::from_script(&script, &[], BackendType::Software).expect("renderer");
+    let rgba = renderer.render_frame(&script, 100).expect("render");
+    let yuv = renderer.render_yuv420(&script, 100).expect("yuv");
+    assert_eq!((yuv.width, yuv.height, yuv.timestamp), (64, 32, 100));
+    assert_eq!((yuv.y_plane.len(), yuv.u_plane.len()), (64 * 32, 32 * 16));
+
+    // Interior of the filled box: RGB 224,128,48 from &H3080E0&
+    let restored = yuv.to_rgba();
+    for y in 4..28 {
+        for x in 4..60 {
+            let i = (y * 64 + x) * 4;
+            assert_eq!(&rgba.data()[i..i + 3], &[224, 128, 48]);
+            assert_close(&rgba.data()[i..i + 3], &restored.data()[i..i + 3]);
+        }
+    }
+
+    let nv12 = renderer.render_nv12(&script, 100).expect("nv12");
+    assert_eq!(nv12.y_plane, yuv.y_plane);
+    assert_eq!(nv12.uv_plane[0], yuv.u_plane[0]);
+    assert_eq!(nv12.uv_plane[1], yuv.v_plane[0]);
+    assert_eq!(nv12.uv_plane.len(), yuv.u_plane.len() * 2);
+}